@@ -0,0 +1,52 @@
+use serde::Serialize;
+use shrinkwrap::{ToWrappedWith, Transform, TransformToNest, Wrap};
+
+// !- Data definition
+
+#[derive(Debug, Clone, Serialize, Wrap)]
+#[shrinkwrap(transform = MyTransform)]
+// `usd_value` only groups its children, and is populated by invoking their transforms
+#[shrinkwrap(nest(id = "usd_value", auto_parent))]
+#[shrinkwrap(nest(id = "usd_text", field_name = "text", field_type = String, chain_from = "usd_value"))]
+#[shrinkwrap(nest(id = "usd_cents", field_name = "cents", field_type = i64, chain_from = "usd_value"))]
+pub struct Account {
+    pub id: u32,
+    #[shrinkwrap(nest(id = "usd_text"), nest(id = "usd_cents"))]
+    pub balance: f64,
+}
+
+// !- Transform
+
+struct MyTransform;
+impl Transform for MyTransform {
+    type Options = ();
+}
+
+// children of an auto parent are built from the auto parent's source data, i.e. `Account`
+
+impl TransformToNest<AccountNestedUsdValueText> for MyTransform {
+    type Data = Account;
+
+    fn transform_to_nest(&self, data: &Account, _: &()) -> AccountNestedUsdValueText {
+        AccountNestedUsdValueText { balance: format!("${:.2}", data.balance) }
+    }
+}
+
+impl TransformToNest<AccountNestedUsdValueCents> for MyTransform {
+    type Data = Account;
+
+    fn transform_to_nest(&self, data: &Account, _: &()) -> AccountNestedUsdValueCents {
+        AccountNestedUsdValueCents { balance: (data.balance * 100.0).round() as i64 }
+    }
+}
+
+// !- Usage
+
+pub fn main() -> Result<(), serde_json::Error> {
+    println!("Starting example: auto-parent");
+
+    let wrapper = Account { id: 3, balance: 12.5 }.to_wrapped_with(&MyTransform, &());
+    println!("Output:\n{}", serde_json::to_string_pretty(&wrapper)?);
+
+    Ok(())
+}
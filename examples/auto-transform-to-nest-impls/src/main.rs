@@ -1,6 +1,6 @@
-use chrono::DateTime;
 use schemars::JsonSchema;
 use serde::Serialize;
+use shrinkwrap::datetime::{DateTimeFormat, FormatDateTime};
 use shrinkwrap::{Transform, TryBuildNestValue, TryToWrappedWith, Wrap};
 
 // !- Transform
@@ -9,6 +9,7 @@ use shrinkwrap::{Transform, TryBuildNestValue, TryToWrappedWith, Wrap};
 // are rendered.
 //
 // Field option names can be renamed with `derive_to_nest(options_field="..")`
+#[derive(Default)]
 struct MyTransformOpts {
     with_text: bool,
     with_value: bool,
@@ -49,6 +50,7 @@ pub struct MyTimestampNewtype(u128);
 #[shrinkwrap(schema, inline, transform = MyTransform, all_optional, fallible(error = MyError))]
 #[shrinkwrap(nest(id = "text", derive_to_nest(value = NestedTextVariant)))] // <---- replace `field_type=..` with `derive_to_nest(value = TypeWithImplForNestValueType)`
 #[shrinkwrap(nest(id = "value", derive_to_nest(value = NestedUsdValueVariant)))] // use the usd value variant for value fields
+#[shrinkwrap(extra(annotate(key = "x-display-only", value = "true")))] // adds `"x-display-only": true` to the extra struct schemas
 #[shrinkwrap(
     nest(id = "value_text", derive_to_nest(value = NestedTextVariant), // reuse the text value again here, we just need to define
     field_name = "text", chain_from = "value"                          // `NestedUsdValueVariant` -> `TestedTextVariant` conversion once
@@ -81,13 +83,19 @@ impl TryBuildNestValue<f32, NestedTextVariant> for MyTransform {
     }
 }
 
+/// Timestamp format shared by all text nests, matches the documented `'YYYY-MM-DD hh:MM:SS'`
+struct TextTimestamp;
+impl DateTimeFormat for TextTimestamp {
+    const PATTERN: &'static str = "%Y-%m-%d %H:%M:%S";
+}
+
 /// Also add conversion for MyTimestampNewtype -> text to handle `last_modified`
 impl TryBuildNestValue<MyTimestampNewtype, NestedTextVariant> for MyTransform {
     type Error = MyError;
 
     fn try_build_nest_value(&self, source: &MyTimestampNewtype, _options: &Self::Options) -> Result<NestedTextVariant, Self::Error> {
-        let datetime = DateTime::from_timestamp(source.0 as i64, 0).unwrap();
-        let datetime_txt = datetime.to_string();
+        let secs = i64::try_from(source.0).map_err(|_| MyError)?;
+        let datetime_txt = FormatDateTime::<TextTimestamp>::format_timestamp(secs).ok_or(MyError)?;
         Ok(NestedTextVariant(datetime_txt))
     }
 }
@@ -155,8 +163,10 @@ pub fn main() -> Result<(), serde_json::Error> {
 //
 // (in fact, this is actually be called from main)
 
+//
+// `options_builder` generates `ReusedApiConvDataWrapOptionsBuilder`, with a toggle for each gated nest
 #[derive(Debug, Clone, Serialize, JsonSchema, PartialEq, Wrap)]
-#[shrinkwrap(schema, inline, transform = MyTransform, all_optional, fallible(error = MyError))]
+#[shrinkwrap(schema, inline, transform = MyTransform, all_optional, options_builder, fallible(error = MyError))]
 #[shrinkwrap(nest(id = "text", derive_to_nest(value = NestedTextVariant)))]
 #[shrinkwrap(nest(id = "value", derive_to_nest(value = NestedUsdValueVariant)))]
 #[shrinkwrap(nest(id = "value_text", derive_to_nest(value = NestedTextVariant), field_name = "text", chain_from = "value"))]
@@ -191,10 +201,10 @@ fn test_reusable_conversions() {
         payment_received_at: MyTimestampNewtype(1777689504),
     };
     let global_transform = MyTransform {};
-    let transform_opts = MyTransformOpts {
-        with_value: true,
-        with_text: true,
-    };
+    let transform_opts = ReusedApiConvDataWrapOptionsBuilder::default()
+        .with_value(true)
+        .with_text(true)
+        .build();
 
     // all field conversions automatically wired up
     let wrapped = data.try_to_wrapped_with(&global_transform, &transform_opts).unwrap();
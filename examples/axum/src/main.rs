@@ -0,0 +1,162 @@
+use axum::body::{Body, to_bytes};
+use axum::extract::Request;
+use axum::http::{StatusCode, header};
+use axum::middleware::{self, Next};
+use axum::response::{AppendHeaders, IntoResponse, Response};
+use axum::routing::get;
+use axum::{Extension, Router};
+use serde::Serialize;
+use shrinkwrap::axum::{TryWrappedJson, WrapContext, WrappedJson, wrap_responses};
+use shrinkwrap::{Transform, TransformToNest, TryTransformToNest, Wrap};
+use tower::ServiceExt;
+
+// !- Data definition
+
+#[derive(Debug, Clone, Serialize, Wrap)]
+#[shrinkwrap(transform = MyTransform)]
+#[shrinkwrap(assert_threadsafe)] // wrappers are returned from handlers, reports thread-unsafe field types at the derive
+#[shrinkwrap(nest(id = "text", field_type = String))]
+pub struct Account {
+    pub id: u32,
+
+    #[shrinkwrap(nest(id = "text"))]
+    pub balance: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Wrap)]
+#[shrinkwrap(transform = MyTransform, fallible(error = TransformError))]
+#[shrinkwrap(nest(id = "text", field_type = String))]
+pub struct Transfer {
+    #[shrinkwrap(nest(id = "text"))]
+    pub amount: i64,
+}
+
+// !- Transform
+
+#[derive(Debug, Clone, Default)]
+pub struct MyOptions {
+    pub currency: &'static str,
+}
+
+struct MyTransform;
+impl Transform for MyTransform {
+    type Options = MyOptions;
+}
+impl TransformToNest<AccountNestedText> for MyTransform {
+    type Data = Account;
+
+    fn transform_to_nest(&self, data: &Account, options: &MyOptions) -> AccountNestedText {
+        AccountNestedText { balance: format!("{} {}", data.balance, options.currency) }
+    }
+}
+
+#[derive(Debug)]
+pub struct TransformError(&'static str);
+impl IntoResponse for TransformError {
+    fn into_response(self) -> Response {
+        (StatusCode::UNPROCESSABLE_ENTITY, self.0).into_response()
+    }
+}
+impl TryTransformToNest<TransferNestedText> for MyTransform {
+    type Data = Transfer;
+    type Error = TransformError;
+
+    fn try_transform_to_nest(&self, data: &Transfer, options: &MyOptions) -> Result<TransferNestedText, TransformError> {
+        if data.amount < 0 {
+            return Err(TransformError("negative transfer amount"));
+        }
+        Ok(TransferNestedText { amount: format!("{} {}", data.amount, options.currency) })
+    }
+}
+
+// !- Handlers
+
+async fn account() -> WrappedJson<Account, MyTransform> {
+    WrappedJson::new(Account { id: 1, balance: 1250 })
+}
+
+async fn create_account() -> impl IntoResponse {
+    let cookies = AppendHeaders([(header::SET_COOKIE, "session=1"), (header::SET_COOKIE, "theme=dark")]);
+    (StatusCode::CREATED, cookies, WrappedJson::<_, MyTransform>::new(Account { id: 2, balance: 0 }))
+}
+
+async fn failed_account() -> impl IntoResponse {
+    (StatusCode::INTERNAL_SERVER_ERROR, WrappedJson::<_, MyTransform>::new(Account { id: 3, balance: 0 }))
+}
+
+async fn transfer() -> TryWrappedJson<Transfer, MyTransform> {
+    TryWrappedJson::new(Transfer { amount: -5 })
+}
+
+/// Per-request options, e.g. derived from the authenticated user
+async fn currency_from_header(Extension(context): Extension<WrapContext<MyTransform>>, mut request: Request, next: Next) -> Response {
+    if request.headers().get("x-currency").is_some_and(|value| value == "EUR") {
+        let context = context.with_options(MyOptions { currency: "EUR" });
+        request.extensions_mut().insert(context);
+    }
+    next.run(request).await
+}
+
+// !- Usage
+
+async fn call(app: &Router, request: Request) -> (StatusCode, String) {
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, String::from_utf8(body.to_vec()).unwrap())
+}
+
+fn get_request(uri: &str) -> Request {
+    Request::get(uri).body(Body::empty()).unwrap()
+}
+
+pub fn main() {
+    println!("Starting example: axum");
+
+    // the wrap context layer must wrap the middleware, so it's added last
+    let app = Router::new()
+        .route("/account", get(account).post(create_account))
+        .route("/account/failed", get(failed_account))
+        .route("/transfer", get(transfer))
+        .layer(middleware::from_fn(wrap_responses))
+        .layer(middleware::from_fn(currency_from_header))
+        .layer(Extension(WrapContext::new(MyTransform, MyOptions { currency: "USD" })));
+
+    futures::executor::block_on(async {
+        let (status, body) = call(&app, get_request("/account")).await;
+        println!("GET /account: {status} {body}");
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, r#"{"extra":{"text":{"balance":"1250 USD"}},"id":1,"balance":1250}"#);
+
+        let request = Request::get("/account").header("x-currency", "EUR").body(Body::empty()).unwrap();
+        let (_, body) = call(&app, request).await;
+        println!("GET /account (EUR): {body}");
+        assert_eq!(body, r#"{"extra":{"text":{"balance":"1250 EUR"}},"id":1,"balance":1250}"#);
+
+        // status set alongside the response is retained
+        let request = Request::post("/account").body(Body::empty()).unwrap();
+        let response = app.clone().oneshot(request).await.unwrap();
+        println!("POST /account: {}", response.status());
+        assert_eq!(response.status(), StatusCode::CREATED);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "application/json");
+        // as are all values of repeated headers
+        let cookies = response.headers().get_all(header::SET_COOKIE).iter().collect::<Vec<_>>();
+        assert_eq!(cookies, ["session=1", "theme=dark"]);
+
+        // including error statuses
+        let (status, body) = call(&app, get_request("/account/failed")).await;
+        println!("GET /account/failed: {status} {body}");
+        assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(body, r#"{"extra":{"text":{"balance":"0 USD"}},"id":3,"balance":0}"#);
+
+        // transform errors are rendered via their `IntoResponse` impl
+        let (status, body) = call(&app, get_request("/transfer")).await;
+        println!("GET /transfer: {status} {body}");
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+        // without the middleware, wrapped responses aren't silently sent empty
+        let unwrapped = Router::new().route("/account", get(account));
+        let (status, _) = call(&unwrapped, get_request("/account")).await;
+        assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+    });
+}
@@ -0,0 +1,65 @@
+use serde::Serialize;
+use shrinkwrap::{ToWrappedWith, Transform2, TransformToNest2, Wrap};
+
+// !- Data definition
+
+#[derive(Debug, Clone, Serialize, Wrap)]
+#[shrinkwrap(transform = MyTransform)]
+#[shrinkwrap(nest(id = "text", field_type = String))]
+pub struct Invoice {
+    #[shrinkwrap(nest(id = "text"))]
+    amount_cents: i64,
+}
+
+// !- Request-scoped state
+
+struct User {
+    name: String,
+    currency_symbol: String,
+}
+
+// !- Transform
+
+/// Options borrowing request-scoped data, nothing is cloned per request
+struct MyTransformOpts<'a> {
+    locale: &'a str,
+    user: &'a User,
+}
+
+struct MyTransform;
+// `Transform2` allows the options type to carry a lifetime
+impl Transform2 for MyTransform {
+    type Options<'a> = MyTransformOpts<'a>;
+}
+impl TransformToNest2<InvoiceNestedText> for MyTransform {
+    type Data = Invoice;
+
+    fn transform_to_nest(&self, data: &Invoice, options: &MyTransformOpts<'_>) -> InvoiceNestedText {
+        let separator = match options.locale {
+            "de-DE" => ",",
+            _ => ".",
+        };
+        let amount = format!("{}{separator}{:02}", data.amount_cents / 100, data.amount_cents % 100);
+        InvoiceNestedText {
+            amount_cents: format!("{amount} {} (billed to {})", options.user.currency_symbol, options.user.name),
+        }
+    }
+}
+
+// !- Usage
+
+pub fn main() -> Result<(), serde_json::Error> {
+    println!("Starting example: borrowed-options");
+
+    let locale = String::from("de-DE");
+    let user = User { name: "Ada".to_string(), currency_symbol: "€".to_string() };
+    let options = MyTransformOpts { locale: &locale, user: &user };
+
+    let wrapped = Invoice { amount_cents: 1250 }.to_wrapped_with(&MyTransform, &options);
+
+    let output = serde_json::to_string(&wrapped)?;
+    println!("Serialized wrapper: {output}");
+    assert_eq!(output, r#"{"extra":{"text":{"amount_cents":"12,50 € (billed to Ada)"}},"amount_cents":1250}"#);
+
+    Ok(())
+}
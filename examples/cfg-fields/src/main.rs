@@ -0,0 +1,80 @@
+use serde::Serialize;
+use serde_json::json;
+use shrinkwrap::{BuildNestValue, NestValueType, ToWrappedWith, Transform, TransformToNest, Wrap};
+
+// !- Data definition
+
+// `#[cfg(..)]` attributes on origin fields are carried over onto the field within each of its nests, along with
+// the field assignments of generated transforms (`derive_to_nest`, `identity`, and `sources`).
+// Run with `--features audit` to include `audited_by` in each nest.
+#[derive(Debug, Clone, Serialize, Wrap)]
+#[shrinkwrap(transform = MyTransform)]
+#[shrinkwrap(nest(id = "text", derive_to_nest(value = Text)))]
+#[shrinkwrap(nest(id = "copy", identity))]
+#[shrinkwrap(nest(id = "value", field_type = f64))]
+pub struct Account {
+    #[shrinkwrap(nest(id = "text"), nest(id = "copy"), nest(id = "value"))]
+    pub balance: i64,
+
+    #[cfg(feature = "audit")]
+    #[shrinkwrap(nest(id = "text"), nest(id = "copy"), nest(id = "value"))]
+    pub audited_by: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Text(String);
+
+impl NestValueType for Text {}
+
+// !- Transform
+
+struct MyTransform;
+impl Transform for MyTransform {
+    type Options = ();
+}
+impl BuildNestValue<i64, Text> for MyTransform {
+    fn build_nest_value(&self, source: &i64, _: &()) -> Text {
+        Text(format!("#{source}"))
+    }
+}
+
+// hand-written transforms gate the field the same way
+impl TransformToNest<AccountNestedValue> for MyTransform {
+    type Data = Account;
+
+    fn transform_to_nest(&self, data: &Account, _: &()) -> AccountNestedValue {
+        AccountNestedValue {
+            balance: data.balance as f64 / 100.0,
+            #[cfg(feature = "audit")]
+            audited_by: data.audited_by as f64,
+        }
+    }
+}
+
+// !- Usage
+
+pub fn main() {
+    println!("Starting example: cfg-fields");
+
+    let account = Account {
+        balance: 1250,
+        #[cfg(feature = "audit")]
+        audited_by: 7,
+    };
+    let wrapper = account.to_wrapped_with(&MyTransform, &());
+    let json = serde_json::to_value(&wrapper).unwrap();
+    println!("Wrapped output:\n{json:#}");
+
+    #[cfg(not(feature = "audit"))]
+    assert_eq!(json["extra"], json!({
+        "text": { "balance": "#1250" },
+        "copy": { "balance": 1250 },
+        "value": { "balance": 12.5 },
+    }));
+    #[cfg(feature = "audit")]
+    assert_eq!(json["extra"], json!({
+        "text": { "balance": "#1250", "audited_by": "#7" },
+        "copy": { "balance": 1250, "audited_by": 7 },
+        "value": { "balance": 12.5, "audited_by": 7.0 },
+    }));
+}
@@ -0,0 +1,86 @@
+use serde::Serialize;
+use std::collections::HashMap;
+use shrinkwrap::{ToWrappedWith, Transform, TransformToNest, Wrap};
+
+// !- Data definition
+
+// `csv` generates `csv_headers()` and `to_csv_record()` on the primary wrapper,
+// restricted here to the `text` and `value_text` nests (`value` is omitted)
+#[derive(Debug, Clone, Serialize, Wrap)]
+#[shrinkwrap(transform = MyTransform, csv(nests("text", "value_text")))]
+#[shrinkwrap(nest(id = "text", field_type = String, optional))]
+#[shrinkwrap(nest(id = "value", field_type = f32))]
+#[shrinkwrap(nest(id = "value_text", field_name = "text", field_type = String, chain_from = "value"))]
+pub struct Account {
+    // `id_key` generates `identity()` on the wrapper, returning `&u32`
+    #[shrinkwrap(id_key)]
+    pub id: u32,
+
+    #[shrinkwrap(nest(id = "text"), nest(id = "value"), nest(id = "value_text"))]
+    pub balance: i64,
+}
+
+// !- Transform
+
+struct MyTransform;
+impl Transform for MyTransform {
+    type Options = MyTransformOpts;
+}
+
+struct MyTransformOpts {
+    with_text: bool,
+}
+
+impl TransformToNest<Option<AccountNestedText>> for MyTransform {
+    type Data = Account;
+
+    fn transform_to_nest(&self, data: &Account, options: &MyTransformOpts) -> Option<AccountNestedText> {
+        options.with_text.then(|| AccountNestedText {
+            balance: format!("{} credits", data.balance),
+        })
+    }
+}
+impl TransformToNest<AccountNestedValue> for MyTransform {
+    type Data = Account;
+
+    fn transform_to_nest(&self, data: &Account, _: &MyTransformOpts) -> AccountNestedValue {
+        AccountNestedValue { balance: data.balance as f32 / 100.0 }
+    }
+}
+impl TransformToNest<AccountNestedValueText> for MyTransform {
+    type Data = AccountNestedValue;
+
+    fn transform_to_nest(&self, data: &AccountNestedValue, _: &MyTransformOpts) -> AccountNestedValueText {
+        AccountNestedValueText { balance: format!("${:.2}", data.balance) }
+    }
+}
+
+// !- Usage
+
+pub fn main() {
+    println!("Starting example: csv-projection");
+
+    let headers = AccountWrapper::csv_headers();
+    assert_eq!(headers, ["id", "balance", "text.balance", "value.text.balance"]);
+
+    let accounts = [Account { id: 1, balance: 1250 }, Account { id: 2, balance: 75 }];
+    let mut output = headers.join(",") + "\n";
+    for (index, account) in accounts.into_iter().enumerate() {
+        // optional nests which aren't rendered produce empty values
+        let options = MyTransformOpts { with_text: index == 0 };
+        let record = account.to_wrapped_with(&MyTransform, &options).to_csv_record().expect("account fields serialize");
+        output += &(record.join(",") + "\n");
+    }
+    println!("CSV output:\n{output}");
+    assert_eq!(output, "id,balance,text.balance,value.text.balance\n1,1250,1250 credits,$12.50\n2,75,,$0.75\n");
+
+    // wrappers can be keyed by their identity without peeling them
+    let options = MyTransformOpts { with_text: false };
+    let keyed = [Account { id: 1, balance: 10 }, Account { id: 1, balance: 20 }, Account { id: 3, balance: 30 }]
+        .into_iter()
+        .map(|account| account.to_wrapped_with(&MyTransform, &options))
+        .map(|wrapper| (*wrapper.identity(), wrapper))
+        .collect::<HashMap<_, _>>();
+    assert_eq!(keyed.len(), 2);
+    assert_eq!(keyed[&1].data.balance, 20);
+}
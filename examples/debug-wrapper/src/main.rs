@@ -0,0 +1,59 @@
+use serde::Serialize;
+use shrinkwrap::{ToWrappedWith, Transform, TransformToNest, Wrap};
+
+// !- Data definition
+
+#[derive(Debug, Clone, Serialize, Wrap)]
+#[shrinkwrap(transform = MyTransform, debug_wrapper)]
+#[shrinkwrap(nest(id = "text", field_type = String))]
+#[shrinkwrap(nest(id = "cents", field_type = i64, optional))]
+pub struct Account {
+    #[shrinkwrap(nest(id = "text"), nest(id = "cents"))]
+    balance: f64,
+}
+
+// !- Transform
+
+struct MyTransform;
+impl Transform for MyTransform {
+    type Options = ();
+}
+impl TransformToNest<AccountNestedText> for MyTransform {
+    type Data = Account;
+
+    fn transform_to_nest(&self, data: &Account, _: &()) -> AccountNestedText {
+        AccountNestedText { balance: format!("${:.2}", data.balance) }
+    }
+}
+impl TransformToNest<Option<AccountNestedCents>> for MyTransform {
+    type Data = Account;
+
+    fn transform_to_nest(&self, data: &Account, _: &()) -> Option<AccountNestedCents> {
+        (data.balance >= 0.0).then(|| AccountNestedCents { balance: (data.balance * 100.0).round() as i64 })
+    }
+}
+
+// !- Usage
+
+pub fn main() -> Result<(), serde_json::Error> {
+    println!("Starting example: debug-wrapper");
+
+    // the standard wrapper stays lean
+    let wrapper = Account { balance: -2.5 }.to_wrapped_with(&MyTransform, &());
+    println!("Serialized: {}", serde_json::to_string(&wrapper)?);
+
+    // staging builds the debug wrapper instead, with timings recorded via the `instrument` feature
+    let debug = AccountDebugWrapper::wrap_with(Account { balance: -2.5 }, &MyTransform, &());
+    let nests = &debug.diagnostics.nests;
+    assert_eq!((nests[0].id, nests[0].present), ("text", true));
+    assert_eq!((nests[1].id, nests[1].present), ("cents", false));
+    assert!(nests[0].elapsed_us.is_some());
+    assert!(nests[1].elapsed_us.is_some());
+    assert!(debug.diagnostics.elapsed_us.is_some());
+    println!("Serialized (debug): {}", serde_json::to_string(&debug)?);
+
+    let wrapper = debug.into_wrapper();
+    assert_eq!(wrapper.extra.text.balance, "$-2.50");
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use serde::Serialize;
+use shrinkwrap::diff;
+use shrinkwrap::{Transform, TransformToNest, Wrap, WrapAllWith};
+
+// !- Data definition
+
+#[derive(Debug, Clone, Serialize, Wrap)]
+#[shrinkwrap(transform = MyTransform)]
+#[shrinkwrap(nest(id = "value", field_type = f64))]
+#[shrinkwrap(nest(id = "value_text", field_name = "text", field_type = String, chain_from = "value"))]
+pub struct Position {
+    pub symbol: String,
+    #[shrinkwrap(nest(id = "value"), nest(id = "value_text"))]
+    pub holdings: u32,
+}
+
+// !- Transform
+
+struct MyTransform {
+    price: f64,
+}
+impl Transform for MyTransform {
+    type Options = ();
+}
+
+impl TransformToNest<PositionNestedValue> for MyTransform {
+    type Data = Position;
+
+    fn transform_to_nest(&self, data: &Position, _: &()) -> PositionNestedValue {
+        PositionNestedValue { holdings: f64::from(data.holdings) * self.price }
+    }
+}
+
+impl TransformToNest<PositionNestedValueText> for MyTransform {
+    type Data = PositionNestedValue;
+
+    fn transform_to_nest(&self, data: &PositionNestedValue, _: &()) -> PositionNestedValueText {
+        PositionNestedValueText { holdings: format!("${:.2}", data.holdings) }
+    }
+}
+
+// !- Usage
+
+pub fn main() -> Result<(), shrinkwrap::Error> {
+    println!("Starting example: diff");
+
+    let positions = || vec![
+        Position { symbol: "ABC".to_string(), holdings: 10 },
+        Position { symbol: "XYZ".to_string(), holdings: 4 },
+    ];
+
+    // a change-detection job comparing the previous run against the current one
+    let previous = Position::wrap_all_with(positions(), &MyTransform { price: 2.5 }, &());
+    let current = Position::wrap_all_with(positions(), &MyTransform { price: 3.0 }, &());
+
+    let diff = diff::diff_wrapped(&previous, &current)?;
+    println!("Changed nests: {:?}", diff.nests());
+    println!("Data changed: {}", diff.data().next().is_some());
+    print!("Changes:\n{diff}");
+
+    Ok(())
+}
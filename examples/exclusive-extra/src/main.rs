@@ -0,0 +1,70 @@
+use serde::Serialize;
+use shrinkwrap::{ToWrappedWith, Transform, TransformToNest, Wrap};
+
+// !- Data definition
+
+// `extra(exclusive)` generates `PaymentExtra` as an enum, at most a single nest is populated per wrapper
+#[derive(Debug, Clone, Serialize, Wrap)]
+#[shrinkwrap(transform = MyTransform, all_optional)]
+#[shrinkwrap(extra(exclusive(tag = "method", content = "details")))]
+#[shrinkwrap(nest(id = "card", field_type = String))]
+#[shrinkwrap(nest(id = "bank", field_type = String))]
+pub struct Payment {
+    #[shrinkwrap(nest(id = "card"), nest(id = "bank"))]
+    account: u64,
+
+    amount: i64,
+}
+
+// !- Transform
+
+struct MyTransform;
+impl Transform for MyTransform {
+    /// Whether to include payment method details
+    type Options = bool;
+}
+impl TransformToNest<Option<PaymentNestedCard>> for MyTransform {
+    type Data = Payment;
+
+    fn transform_to_nest(&self, data: &Payment, details: &bool) -> Option<PaymentNestedCard> {
+        (*details && data.account < 10_000).then(|| PaymentNestedCard {
+            account: format!("**** {:04}", data.account),
+        })
+    }
+}
+impl TransformToNest<Option<PaymentNestedBank>> for MyTransform {
+    type Data = Payment;
+
+    fn transform_to_nest(&self, data: &Payment, details: &bool) -> Option<PaymentNestedBank> {
+        (*details && data.account >= 10_000).then(|| PaymentNestedBank {
+            account: format!("acct #{}", data.account),
+        })
+    }
+}
+
+// !- Usage
+
+pub fn main() -> Result<(), serde_json::Error> {
+    println!("Starting example: exclusive-extra");
+
+    let card = Payment { account: 4242, amount: 1250 }.to_wrapped_with(&MyTransform, &true);
+    // nests are computed in declaration order, `bank` is never built once `card` is populated
+    assert!(matches!(card.extra, PaymentExtra::Card(..)));
+
+    let output = serde_json::to_string(&card)?;
+    println!("Serialized card payment: {output}");
+    assert_eq!(output, r#"{"extra":{"method":"card","details":{"account":"**** 4242"}},"account":4242,"amount":1250}"#);
+
+    let bank = Payment { account: 123_456, amount: 90 }.to_wrapped_with(&MyTransform, &true);
+    let output = serde_json::to_string(&bank)?;
+    println!("Serialized bank payment: {output}");
+    assert_eq!(output, r#"{"extra":{"method":"bank","details":{"account":"acct #123456"}},"account":123456,"amount":90}"#);
+
+    // `None` is used if no nest is populated
+    let redacted = Payment { account: 4242, amount: 1250 }.to_wrapped_with(&MyTransform, &false);
+    let output = serde_json::to_string(&redacted)?;
+    println!("Serialized redacted payment: {output}");
+    assert_eq!(output, r#"{"extra":{"method":"none"},"account":4242,"amount":1250}"#);
+
+    Ok(())
+}
@@ -1,6 +1,6 @@
 use schemars::JsonSchema;
 use serde::Serialize;
-use shrinkwrap::{Transform, TryToWrappedWith, TryTransformToNest, Wrap};
+use shrinkwrap::prelude::*;
 
 // !- Transform
 
@@ -14,14 +14,15 @@ impl Transform for MyTransform {
     type Options = MyTransformOpts;
 }
 
-#[derive(Debug, Copy, Clone, Serialize)]
+#[derive(Debug, Copy, Clone, Serialize, PartialEq)]
 pub struct MyError;
 
 // !- Data definition
 
 /// Docs on origin `TestData`
 #[derive(Debug, Clone, Serialize, JsonSchema, PartialEq, Wrap)]
-#[shrinkwrap(schema, inline, transform = MyTransform, all_optional, fallible(error = MyError))]
+#[shrinkwrap(schema, inline, transform = MyTransform, all_optional, fallible(error = MyError, accumulate_errors))]
+#[shrinkwrap(assert_optional_impls)] // reports missing `TryTransformToNest<Option<..>>` impls at the nest definitions
 #[shrinkwrap(nest(id = "text", field_type = String))]
 #[shrinkwrap(nest(id = "value", field_type = f32))]
 // value_text is branched under the value nest. we manually rename the nest key to text for consistency (see json output)
@@ -57,6 +58,9 @@ impl TryTransformToNest<Option<ApiDataNestedText>> for MyTransform {
         data: &ApiData,
         options: &MyTransformOpts,
     ) -> Result<Option<ApiDataNestedText>, MyError> {
+        if data.balance < 0.0 {
+            return Err(MyError);
+        }
         Ok(options.with_text.then_some(ApiDataNestedText {
             balance: format!("{:.2} credits", data.balance),
             last_modified: "2025-02-24 12:20:49".into(), // used as an example, fake impl
@@ -123,5 +127,19 @@ pub fn main() -> Result<(), serde_json::Error> {
     let wrapped_json = serde_json::to_string_pretty(&wrapped)?;
     println!("Serialized wrapper: {wrapped_json}");
 
+    // with `accumulate_errors`, a failed optional nest is omitted rather than failing the whole wrapper
+    let overdrawn = ApiData {
+        balance: -4.5,
+        last_modified: 1754443805,
+    };
+    assert!(overdrawn.clone().try_to_wrapped_with(&global_transform, &transform_opts).is_err());
+
+    let (partial, errors) = overdrawn.try_to_wrapped_with_errors(&global_transform, &transform_opts).unwrap();
+    println!("Partial wrapper: {}, errors: {errors:?}", serde_json::to_string(&partial)?);
+    assert!(partial.extra.text.is_none());
+    assert!(partial.extra.value.is_some());
+    assert_eq!(errors.len(), 1);
+    assert_eq!((errors[0].nest, errors[0].error), ("text", MyError));
+
     Ok(())
 }
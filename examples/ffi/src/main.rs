@@ -0,0 +1,59 @@
+#![deny(improper_ctypes_definitions)]
+
+use serde::Serialize;
+use shrinkwrap::{ToWrappedWith, Transform, TransformToNest, Wrap};
+
+// !- Data definition
+
+#[derive(Debug, Clone, Serialize, Wrap)]
+#[repr(C)]
+#[shrinkwrap(transform = MyTransform, ffi)]
+#[shrinkwrap(nest(id = "text", field_type = String))]
+pub struct Reading {
+    sensor_id: u32,
+    #[shrinkwrap(nest(id = "text"))]
+    celsius: f64,
+}
+
+// !- Transform
+
+struct MyTransform;
+impl Transform for MyTransform {
+    type Options = ();
+}
+impl TransformToNest<ReadingNestedText> for MyTransform {
+    type Data = Reading;
+
+    fn transform_to_nest(&self, data: &Reading, _: &()) -> ReadingNestedText {
+        ReadingNestedText { celsius: format!("{:.1} °C", data.celsius) }
+    }
+}
+
+// !- FFI surface
+
+/// Only the C-compatible data is read across the boundary, the extra struct stays opaque
+pub extern "C" fn reading_sensor_id(reading: &ReadingWrapperFfi) -> u32 {
+    reading.data.sensor_id
+}
+
+pub extern "C" fn reading_celsius(reading: &ReadingWrapperFfi) -> f64 {
+    reading.data.celsius
+}
+
+// !- Usage
+
+pub fn main() -> Result<(), serde_json::Error> {
+    println!("Starting example: ffi");
+
+    let wrapper = Reading { sensor_id: 7, celsius: 21.54 }.to_wrapped_with(&MyTransform, &());
+    let envelope = ReadingWrapperFfi::from(wrapper);
+    assert_eq!(reading_sensor_id(&envelope), 7);
+    assert_eq!(reading_celsius(&envelope), 21.54);
+    assert_eq!(envelope.extra().text.celsius, "21.5 °C");
+
+    // back on the Rust side, the envelope converts into the serializable wrapper
+    let wrapper = envelope.into_wrapper();
+    println!("Serialized: {}", serde_json::to_string(&wrapper)?);
+
+    Ok(())
+}
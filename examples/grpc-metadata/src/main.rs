@@ -0,0 +1,49 @@
+use serde::Serialize;
+use shrinkwrap::grpc::prost_types::value::Kind;
+use shrinkwrap::{ToWrappedWith, Transform, TransformToNest, Wrap};
+
+// !- Data definition
+
+#[derive(Debug, Clone, Serialize, Wrap)]
+#[shrinkwrap(transform = MyTransform, grpc)]
+#[shrinkwrap(nest(id = "text", field_type = String))]
+pub struct Invoice {
+    pub id: u32,
+    #[shrinkwrap(nest(id = "text"))]
+    pub total_cents: i64,
+}
+
+// !- Transform
+
+struct MyTransform;
+impl Transform for MyTransform {
+    type Options = ();
+}
+
+impl TransformToNest<InvoiceNestedText> for MyTransform {
+    type Data = Invoice;
+
+    fn transform_to_nest(&self, data: &Invoice, _: &()) -> InvoiceNestedText {
+        InvoiceNestedText {
+            total_cents: format!("${}.{:02}", data.total_cents / 100, data.total_cents % 100),
+        }
+    }
+}
+
+// !- Usage
+
+pub fn main() -> Result<(), shrinkwrap::Error> {
+    println!("Starting example: grpc-metadata");
+
+    let wrapper = Invoice { id: 7, total_cents: 12_950 }.to_wrapped_with(&MyTransform, &());
+
+    // the nests are carried separately from the response message, e.g. as gRPC trailers
+    let metadata = wrapper.to_grpc_metadata()?;
+    let Some(Kind::StructValue(text)) = &metadata.fields["text"].kind else {
+        unreachable!("nests are represented as structs");
+    };
+    println!("Metadata nests: {:?}", metadata.fields.keys().collect::<Vec<_>>());
+    println!("Text nest: {:?}", text.fields["total_cents"].kind);
+
+    Ok(())
+}
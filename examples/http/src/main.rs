@@ -0,0 +1,73 @@
+use bytes::Bytes;
+use http::{Request, Response, StatusCode, header};
+use serde::Serialize;
+use shrinkwrap::{ToWrappedWith, Transform, TransformToNest, Wrap};
+use std::convert::Infallible;
+use tower::{ServiceExt, service_fn};
+
+// !- Data definition
+
+// `http` implements `From<AccountWrapper>` for `http::Response<B>`, the status is resolved per wrapper
+#[derive(Debug, Clone, Serialize, Wrap)]
+#[shrinkwrap(transform = MyTransform, http(content_type = "application/vnd.accounts+json", status = account_status))]
+#[shrinkwrap(nest(id = "text", field_type = String))]
+pub struct Account {
+    pub id: u32,
+
+    #[shrinkwrap(nest(id = "text"))]
+    pub balance: i64,
+}
+
+/// Overdrawn accounts are still returned, but flagged via the status
+fn account_status(wrapper: &AccountWrapper) -> StatusCode {
+    match wrapper.data.balance < 0 {
+        true => StatusCode::PAYMENT_REQUIRED,
+        false => StatusCode::OK,
+    }
+}
+
+// !- Transform
+
+struct MyTransform;
+impl Transform for MyTransform {
+    type Options = ();
+}
+impl TransformToNest<AccountNestedText> for MyTransform {
+    type Data = Account;
+
+    fn transform_to_nest(&self, data: &Account, _options: &()) -> AccountNestedText {
+        AccountNestedText { balance: format!("{:.2} USD", data.balance as f64 / 100.0) }
+    }
+}
+
+// !- Usage
+
+/// A raw tower service, without any framework layer
+async fn handle(request: Request<()>) -> Result<Response<Bytes>, Infallible> {
+    let balance = match request.uri().path() {
+        "/accounts/overdrawn" => -1250,
+        _ => 1250,
+    };
+    let wrapper = Account { id: 1, balance }.to_wrapped_with(&MyTransform, &());
+    Ok(wrapper.into())
+}
+
+pub fn main() {
+    println!("Starting example: {}", env!("CARGO_PKG_NAME"));
+
+    futures::executor::block_on(async {
+        let service = service_fn(handle);
+
+        let request = Request::get("/accounts/1").body(()).unwrap();
+        let response = service.oneshot(request).await.unwrap();
+        println!("GET /accounts/1: {} {:?}", response.status(), response.body());
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "application/vnd.accounts+json");
+        assert_eq!(response.body(), r#"{"extra":{"text":{"balance":"12.50 USD"}},"id":1,"balance":1250}"#);
+
+        let request = Request::get("/accounts/overdrawn").body(()).unwrap();
+        let response = service.oneshot(request).await.unwrap();
+        println!("GET /accounts/overdrawn: {} {:?}", response.status(), response.body());
+        assert_eq!(response.status(), StatusCode::PAYMENT_REQUIRED);
+    });
+}
@@ -19,6 +19,7 @@ impl Transform for MyTransform {
 /// Docs on origin `TestData`
 #[derive(Debug, Clone, Serialize, JsonSchema, PartialEq, Wrap)]
 #[shrinkwrap(schema, inline, transform = MyTransform, all_optional)]
+#[shrinkwrap(wrapper(enrich))]
 #[shrinkwrap(nest(id = "text", field_type = String))]
 #[shrinkwrap(nest(id = "value", field_type = f32))]
 // value_text is branched under the value nest. we manually rename the nest key to text for consistency (see json output)
@@ -106,8 +107,14 @@ pub fn main() -> Result<(), serde_json::Error> {
         with_text: true,
     };
 
+    // `enrich` allows the wrapper to be built up-front, with the nests populated later on
+    let mut staged = ApiDataWrapper::with_empty_extra(data.clone());
+    assert!(staged.extra.text.is_none() && staged.extra.value.is_none());
+    staged.enrich_with(&global_transform, &transform_opts);
+
     let wrapped = data.to_wrapped_with(&global_transform, &transform_opts);
     println!("Generated wrapper via transform: {wrapped:#?}");
+    assert_eq!(serde_json::to_value(&staged)?, serde_json::to_value(&wrapped)?);
 
     // note: shrinkwrap inline flag has renamded the wrapper and inlined all child data
     let wrapped_schema = schemars::schema_for_value!(wrapped).to_value();
@@ -0,0 +1,52 @@
+use serde::Serialize;
+use shrinkwrap::{ToWrappedWith, Transform, TransformToNest, Wrap};
+
+// !- Data definition
+
+// `naming(inherit_rename_all)` applies the origin's `camelCase` to the extra and nest structs
+#[derive(Debug, Clone, Serialize, Wrap)]
+#[serde(rename_all = "camelCase")]
+#[shrinkwrap(transform = MyTransform, naming(inherit_rename_all))]
+#[shrinkwrap(nest(id = "usd_text", field_type = String))]
+pub struct Balance {
+    #[shrinkwrap(nest(id = "usd_text"))]
+    account_balance: i64,
+
+    #[shrinkwrap(nest(id = "usd_text"))]
+    pending_balance: i64,
+}
+
+// !- Transform
+
+struct MyTransform;
+impl Transform for MyTransform {
+    type Options = ();
+}
+impl TransformToNest<BalanceNestedUsdText> for MyTransform {
+    type Data = Balance;
+
+    fn transform_to_nest(&self, data: &Balance, _: &()) -> BalanceNestedUsdText {
+        BalanceNestedUsdText {
+            account_balance: format!("${}.00", data.account_balance),
+            pending_balance: format!("${}.00", data.pending_balance),
+        }
+    }
+}
+
+// !- Usage
+
+pub fn main() -> Result<(), serde_json::Error> {
+    println!("Starting example: inherit-rename-all");
+
+    let wrapped = Balance { account_balance: 10, pending_balance: 2 }.to_wrapped_with_t(&MyTransform);
+
+    // nest ids and nest fields match the origin's casing
+    let output = serde_json::to_string(&wrapped)?;
+    println!("Serialized wrapper: {output}");
+    assert_eq!(
+        output,
+        r#"{"extra":{"usdText":{"accountBalance":"$10.00","pendingBalance":"$2.00"}},"accountBalance":10,"pendingBalance":2}"#
+    );
+
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use serde::Serialize;
+use shrinkwrap::integrity;
+use shrinkwrap::{ToWrappedWith, Transform, TransformToNest, Wrap};
+
+// !- Data definition
+
+#[derive(Debug, Clone, Serialize, Wrap)]
+#[shrinkwrap(transform = MyTransform)]
+// the signature covers the nested `data` block, as emitted
+#[shrinkwrap(wrapper(flatten = false, integrity(algo = "hmac-sha256", field = "signature", key = "crate::signing_key")))]
+#[shrinkwrap(nest(id = "text", field_type = String))]
+pub struct Invoice {
+    pub id: u32,
+    #[shrinkwrap(nest(id = "text"))]
+    pub total_cents: i64,
+}
+
+/// Typically loaded from configuration or a secret store
+fn signing_key() -> &'static [u8] {
+    b"example-secret"
+}
+
+// !- Transform
+
+struct MyTransform;
+impl Transform for MyTransform {
+    type Options = ();
+}
+
+impl TransformToNest<InvoiceNestedText> for MyTransform {
+    type Data = Invoice;
+
+    fn transform_to_nest(&self, data: &Invoice, _: &()) -> InvoiceNestedText {
+        InvoiceNestedText {
+            total_cents: format!("${}.{:02}", data.total_cents / 100, data.total_cents % 100),
+        }
+    }
+}
+
+// !- Usage
+
+pub fn main() -> Result<(), shrinkwrap::Error> {
+    println!("Starting example: integrity");
+
+    let wrapper = Invoice { id: 7, total_cents: 12_950 }.to_wrapped_with(&MyTransform, &());
+    let json = serde_json::to_string_pretty(&wrapper)?;
+    println!("Signed output:\n{json}");
+
+    // consumers holding the key verify the signature against the raw bytes of the received `data` block
+    let signature = integrity::signature(signing_key(), &wrapper.data)?;
+    let data_bytes = br#"{"id":7,"total_cents":12950}"#;
+    println!("Signature verified: {}", signature == integrity::hmac_sha256_hex(signing_key(), data_bytes));
+
+    let tampered_bytes = br#"{"id":7,"total_cents":1295}"#;
+    println!("Tampered signature verified: {}", signature == integrity::hmac_sha256_hex(signing_key(), tampered_bytes));
+
+    Ok(())
+}
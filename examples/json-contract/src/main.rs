@@ -0,0 +1,108 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use shrinkwrap::shape::ShapeMismatch;
+use shrinkwrap::{ToWrappedWith, Transform, TransformToNest, Wrap};
+
+// !- Data definition
+
+// `json` generates `matches_shape`, `to_value_filtered`, and `MAX_NEST_COUNT` on the wrapper, `wrapper(deserialize)` adds `TryFrom<serde_json::Value>`
+#[derive(Debug, Clone, Serialize, Deserialize, Wrap)]
+#[shrinkwrap(transform = MyTransform, json, topology)]
+#[shrinkwrap(wrapper(deserialize))]
+#[shrinkwrap(nest(id = "text", field_type = String))]
+#[shrinkwrap(nest(id = "localized", field_type = String, chain_from = "text", optional))]
+#[serde(rename_all = "camelCase")]
+pub struct MyData {
+    #[shrinkwrap(nest(id = "text"), nest(id = "localized"))]
+    uptime_sec: i64,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    label: Option<String>,
+}
+
+// !- Transform
+
+struct MyTransformOpts {
+    with_localized: bool,
+}
+
+struct MyTransform;
+impl Transform for MyTransform {
+    type Options = MyTransformOpts;
+}
+impl TransformToNest<MyDataNestedText> for MyTransform {
+    type Data = MyData;
+
+    fn transform_to_nest(&self, data: &MyData, _: &MyTransformOpts) -> MyDataNestedText {
+        MyDataNestedText {
+            uptime_sec: format!("{} seconds", data.uptime_sec),
+        }
+    }
+}
+impl TransformToNest<Option<MyDataNestedTextLocalized>> for MyTransform {
+    type Data = MyDataNestedText;
+
+    fn transform_to_nest(&self, data: &MyDataNestedText, options: &MyTransformOpts) -> Option<MyDataNestedTextLocalized> {
+        options.with_localized.then(|| MyDataNestedTextLocalized {
+            uptime_sec: data.uptime_sec.replace("seconds", "secondes"),
+        })
+    }
+}
+
+// !- Usage
+
+pub fn main() -> Result<(), shrinkwrap::Error> {
+    println!("Starting example: json-contract");
+
+    let transform = MyTransform;
+    let transform_opts = MyTransformOpts { with_localized: true };
+    let data = MyData { uptime_sec: 10, label: None };
+
+    // a freshly serialized wrapper always matches its own shape
+    let recorded = serde_json::to_value(data.to_wrapped_with(&transform, &transform_opts))?;
+    println!("Recorded response: {recorded:#}");
+    assert!(MyDataWrapper::matches_shape(&recorded).is_empty());
+
+    // and can be parsed back into the wrapper
+    let parsed = MyDataWrapper::try_from(recorded)?;
+    println!("Parsed wrapper: {parsed:#?}");
+
+    // projections retain only the requested nests (and their parents), e.g. for `?nests=..` query filters
+    let projected = parsed.to_value_filtered(&["localized"])?;
+    assert_eq!(projected["extra"], json!({ "text": { "uptime_sec": "10 seconds", "extra": { "localized": { "uptime_sec": "10 secondes" } } } }));
+    assert_eq!(parsed.to_value_filtered(&[])?["extra"], json!({}));
+
+    // payload budgets can be checked without buffering the serialized output
+    assert_eq!(MyDataWrapper::MAX_NEST_COUNT, 2);
+    assert_eq!(shrinkwrap::size::estimate_size(&parsed)?, serde_json::to_vec(&parsed)?.len());
+
+    // drifted responses are reported per nest
+    let drifted = json!({
+        "uptimeSec": 10,
+        "extra": {
+            "text": {
+                "uptime_sec": "10 seconds",
+                "unit": "s",
+                "extra": { "localized": null },
+            },
+        },
+    });
+    let mismatches = MyDataWrapper::matches_shape(&drifted);
+    for mismatch in &mismatches {
+        println!("Mismatch in nest {:?}: {mismatch}", mismatch.nest());
+    }
+    assert_eq!(mismatches, vec![
+        ShapeMismatch::UnknownKey { path: "$.extra.text".to_string(), nest: Some("text"), key: "unit".to_string() },
+    ]);
+
+    // the nest hierarchy, e.g. for rendering in API docs
+    for node in MyData::WRAP_TOPOLOGY {
+        let parent = node.parent.unwrap_or("<data>");
+        let optional = if node.optional { " (optional)" } else { "" };
+        println!("Nest {} -> {}.extra.{}: {}{optional}", node.id, parent, node.field_name, node.struct_name);
+    }
+    let ids = MyData::WRAP_TOPOLOGY.iter().map(|node| (node.id, node.parent)).collect::<Vec<_>>();
+    assert_eq!(ids, [("text", None), ("localized", Some("text"))]);
+
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use serde::Serialize;
+use shrinkwrap::{BuildNestValue, Transform, ToWrappedWith, Wrap};
+
+// !- Value types
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Transaction {
+    cents: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TextValue(String);
+impl shrinkwrap::NestValueType for TextValue {}
+
+// !- Data definition
+
+#[derive(Debug, Clone, Serialize, Wrap)]
+#[shrinkwrap(transform = MyTransform)]
+#[shrinkwrap(nest(id = "text", derive_to_nest(value = TextValue)))]
+pub struct Account {
+    #[shrinkwrap(nest(id = "text"))]
+    balance: i64,
+
+    // generated as `transactions: Vec<TextValue>` within the text nest, built element-wise
+    #[shrinkwrap(nest(id = "text", map_elements))]
+    transactions: Vec<Transaction>,
+}
+
+// !- Transform
+
+struct MyTransform;
+impl Transform for MyTransform {
+    type Options = ();
+}
+impl BuildNestValue<i64, TextValue> for MyTransform {
+    fn build_nest_value(&self, cents: &i64, _: &()) -> TextValue {
+        TextValue(format!("${}.{:02}", cents / 100, cents % 100))
+    }
+}
+// mapped fields only require a conversion for a single element
+impl BuildNestValue<Transaction, TextValue> for MyTransform {
+    fn build_nest_value(&self, transaction: &Transaction, options: &()) -> TextValue {
+        self.build_nest_value(&transaction.cents, options)
+    }
+}
+
+// !- Usage
+
+pub fn main() -> Result<(), serde_json::Error> {
+    println!("Starting example: map-elements");
+
+    let account = Account {
+        balance: 1250,
+        transactions: vec![Transaction { cents: 1000 }, Transaction { cents: 250 }],
+    };
+    let wrapper = account.to_wrapped_with(&MyTransform, &());
+    assert_eq!(wrapper.extra.text.transactions.len(), 2);
+
+    let output = serde_json::to_string(&wrapper)?;
+    println!("Serialized account: {output}");
+    assert_eq!(
+        output,
+        r#"{"extra":{"text":{"balance":"$12.50","transactions":["$10.00","$2.50"]}},"balance":1250,"transactions":[{"cents":1000},{"cents":250}]}"#,
+    );
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use serde::Serialize;
+use shrinkwrap::{ToWrappedWith, Transform, TransformToNest, Wrap};
+use std::collections::BTreeMap;
+
+// !- Data definition
+
+// `layout = "map"` nests are keyed lookups rather than structs with fixed fields
+#[derive(Debug, Clone, Serialize, Wrap)]
+#[shrinkwrap(transform = MyTransform)]
+#[shrinkwrap(nest(id = "prices", layout = "map", key_type = String, field_type = f64))]
+#[shrinkwrap(nest(id = "text", field_type = String))]
+pub struct Product {
+    #[shrinkwrap(nest(id = "text"))]
+    usd: f64,
+}
+
+// !- Transform
+
+struct MyTransform {
+    rates: Vec<(&'static str, f64)>,
+}
+impl Transform for MyTransform {
+    type Options = ();
+}
+// `ProductNestedPrices` is an alias of `BTreeMap<String, f64>`
+impl TransformToNest<BTreeMap<String, f64>> for MyTransform {
+    type Data = Product;
+
+    fn transform_to_nest(&self, data: &Product, _: &()) -> BTreeMap<String, f64> {
+        self.rates.iter().map(|(currency, rate)| (currency.to_string(), data.usd * rate)).collect()
+    }
+}
+impl TransformToNest<ProductNestedText> for MyTransform {
+    type Data = Product;
+
+    fn transform_to_nest(&self, data: &Product, _: &()) -> ProductNestedText {
+        ProductNestedText { usd: format!("${:.2}", data.usd) }
+    }
+}
+
+// !- Usage
+
+pub fn main() -> Result<(), serde_json::Error> {
+    println!("Starting example: map-nest");
+
+    let transform = MyTransform { rates: vec![("eur", 0.5), ("cad", 2.0)] };
+    let wrapped = Product { usd: 10.0 }.to_wrapped_with_t(&transform);
+    let prices: &ProductNestedPrices = &wrapped.extra.prices;
+    assert_eq!(prices.get("cad"), Some(&20.0));
+
+    let output = serde_json::to_string(&wrapped)?;
+    println!("Serialized wrapper: {output}");
+    assert_eq!(output, r#"{"extra":{"prices":{"cad":20.0,"eur":5.0},"text":{"usd":"$10.00"}},"usd":10.0}"#);
+
+    Ok(())
+}
@@ -1,5 +1,5 @@
 use serde::Serialize;
-use shrinkwrap::{ToWrappedWith, Transform, TransformToNest, Wrap};
+use shrinkwrap::prelude::*;
 
 // !- Data definition
 
@@ -73,9 +73,8 @@ pub fn main() -> Result<(), serde_json::Error> {
 
     // - the wrapper can also be generated by the various alternative traits
     //   (blanket implementations handle supported conversions automatically)
-    // - just don't forget to bring the extra traits into scope as well.
+    // - all traits are brought into scope by the prelude
     //
-    // use shrinkwrap::WrapDataWith;
     // let wrapped = MyDataWrapper::wrap_data_with(data, &transform, &transform_opts);
 
     println!("Generated wrapper via transform: {wrapped:#?}");
@@ -0,0 +1,50 @@
+use serde::Serialize;
+use shrinkwrap::{ToWrappedWith, Transform, TransformToNest, Wrap};
+
+// !- Data definition
+
+// `nest_here` declares the nest alongside the field assignment, no struct-level `nest(..)` is required
+#[derive(Debug, Clone, Serialize, Wrap)]
+#[shrinkwrap(transform = MyTransform)]
+pub struct MyData {
+    #[shrinkwrap(nest_here(id = "text", field_type = String))]
+    uptime_sec: i64,
+
+    // declaring the same nest again is merged, provided `field_type` matches
+    #[shrinkwrap(nest_here(id = "text", field_type = String))]
+    sessions: i64,
+}
+
+// !- Transform
+
+struct MyTransform;
+impl Transform for MyTransform {
+    type Options = ();
+}
+impl TransformToNest<MyDataNestedText> for MyTransform {
+    type Data = MyData;
+
+    fn transform_to_nest(&self, data: &MyData, _: &()) -> MyDataNestedText {
+        MyDataNestedText {
+            uptime_sec: format!("{} seconds", data.uptime_sec),
+            sessions: format!("{} sessions", data.sessions),
+        }
+    }
+}
+
+// !- Usage
+
+pub fn main() -> Result<(), serde_json::Error> {
+    println!("Starting example: nest-here");
+
+    let wrapped = MyData { uptime_sec: 10, sessions: 2 }.to_wrapped_with_t(&MyTransform);
+
+    let output = serde_json::to_string(&wrapped)?;
+    println!("Serialized wrapper: {output}");
+    assert_eq!(
+        output,
+        r#"{"extra":{"text":{"uptime_sec":"10 seconds","sessions":"2 sessions"}},"uptime_sec":10,"sessions":2}"#
+    );
+
+    Ok(())
+}
@@ -0,0 +1,87 @@
+use serde::Serialize;
+use shrinkwrap::{ToWrappedWith, Transform, TransformToNest, Wrap};
+
+// !- Data definition
+
+#[derive(Debug, Clone, Serialize, Wrap)]
+#[shrinkwrap(transform = MyTransform)]
+// `usd` is held as a raw nest struct, its children are hoisted alongside it into `extra`
+#[shrinkwrap(nest(id = "usd", field_type = f64, no_intermediate_wrapper))]
+#[shrinkwrap(nest(id = "usd_text", field_type = String, chain_from = "usd"))]
+pub struct Account {
+    pub id: u32,
+    #[shrinkwrap(nest(id = "usd"), nest(id = "usd_text"))]
+    pub balance_cents: i64,
+}
+
+// `eur` is optional, its non-optional children are lifted to `Option` alongside it (present whenever `eur` is)
+#[derive(Debug, Clone, Serialize, Wrap)]
+#[shrinkwrap(transform = MyTransform)]
+#[shrinkwrap(nest(id = "eur", field_type = f64, optional, no_intermediate_wrapper))]
+#[shrinkwrap(nest(id = "eur_text", field_type = String, chain_from = "eur"))]
+pub struct Invoice {
+    pub id: u32,
+    #[shrinkwrap(nest(id = "eur"), nest(id = "eur_text"))]
+    pub total_cents: i64,
+}
+
+// !- Transform
+
+struct MyTransform;
+impl Transform for MyTransform {
+    type Options = ();
+}
+
+impl TransformToNest<AccountNestedUsd> for MyTransform {
+    type Data = Account;
+
+    fn transform_to_nest(&self, data: &Account, _: &()) -> AccountNestedUsd {
+        AccountNestedUsd { balance_cents: data.balance_cents as f64 / 100.0 }
+    }
+}
+
+// hoisted nests are still built from their parent nest
+impl TransformToNest<AccountNestedUsdUsdText> for MyTransform {
+    type Data = AccountNestedUsd;
+
+    fn transform_to_nest(&self, data: &AccountNestedUsd, _: &()) -> AccountNestedUsdUsdText {
+        AccountNestedUsdUsdText { balance_cents: format!("${:.2}", data.balance_cents) }
+    }
+}
+
+impl TransformToNest<Option<InvoiceNestedEur>> for MyTransform {
+    type Data = Invoice;
+
+    fn transform_to_nest(&self, data: &Invoice, _: &()) -> Option<InvoiceNestedEur> {
+        // only invoices with a total are converted
+        (data.total_cents != 0).then(|| InvoiceNestedEur { total_cents: data.total_cents as f64 / 100.0 * 0.92 })
+    }
+}
+
+// lifted nests don't handle `Option`, they're only built when the parent nest is present
+impl TransformToNest<InvoiceNestedEurEurText> for MyTransform {
+    type Data = InvoiceNestedEur;
+
+    fn transform_to_nest(&self, data: &InvoiceNestedEur, _: &()) -> InvoiceNestedEurEurText {
+        InvoiceNestedEurEurText { total_cents: format!("€{:.2}", data.total_cents) }
+    }
+}
+
+// !- Usage
+
+pub fn main() -> Result<(), serde_json::Error> {
+    println!("Starting example: no-intermediate-wrapper");
+
+    let wrapper = Account { id: 3, balance_cents: 1250 }.to_wrapped_with(&MyTransform, &());
+    assert_eq!(wrapper.extra.usd.balance_cents, 12.5);
+    assert_eq!(wrapper.extra.usd_text.balance_cents, "$12.50");
+    println!("Output:\n{}", serde_json::to_string_pretty(&wrapper)?);
+
+    let wrapper = Invoice { id: 4, total_cents: 5000 }.to_wrapped_with(&MyTransform, &());
+    assert_eq!(wrapper.extra.eur_text.as_ref().map(|text| text.total_cents.as_str()), Some("€46.00"));
+    let wrapper = Invoice { id: 5, total_cents: 0 }.to_wrapped_with(&MyTransform, &());
+    assert!(wrapper.extra.eur.is_none() && wrapper.extra.eur_text.is_none());
+    println!("Output:\n{}", serde_json::to_string_pretty(&wrapper)?);
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use shrinkwrap::datetime::{FormatDateTime, Rfc3339};
+use shrinkwrap::{BuildNestValue, NestValueType, ToWrappedWith, Transform, Wrap};
+
+// !- Data definition
+
+#[derive(Debug, Clone, Serialize, Wrap)]
+#[shrinkwrap(transform = MyTransform)]
+#[shrinkwrap(nest(id = "text", derive_to_nest(value = DateText)))]
+pub struct Order {
+    #[shrinkwrap(nest(id = "text"))]
+    created_at: DateTime<Utc>,
+
+    // `Option<DateText>` in the nest, omitted while `None`
+    #[shrinkwrap(nest(id = "text", none_as = "skip"))]
+    shipped_at: Option<DateTime<Utc>>,
+
+    // `Option<DateText>` in the nest, serialized as `null` while `None`
+    #[shrinkwrap(nest(id = "text", none_as = "null"))]
+    cancelled_at: Option<DateTime<Utc>>,
+
+    // `DateText` in the nest, `DateText::default()` while `None`
+    #[shrinkwrap(nest(id = "text", none_as = "default"))]
+    delivered_at: Option<DateTime<Utc>>,
+}
+
+// !- Nest value
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DateText(String);
+impl NestValueType for DateText {}
+impl Default for DateText {
+    fn default() -> Self {
+        Self("pending".to_string())
+    }
+}
+
+// !- Transform
+
+struct MyTransform;
+impl Transform for MyTransform {
+    type Options = ();
+}
+// only `Some` values are built, `None` is handled per field via `none_as`
+impl BuildNestValue<DateTime<Utc>, DateText> for MyTransform {
+    fn build_nest_value(&self, source: &DateTime<Utc>, _: &()) -> DateText {
+        DateText(FormatDateTime::<Rfc3339>::format(source))
+    }
+}
+
+// !- Usage
+
+pub fn main() -> Result<(), serde_json::Error> {
+    println!("Starting example: none-as");
+
+    let created_at = DateTime::from_timestamp(1754497944, 0).expect("valid timestamp");
+    let order = Order { created_at, shipped_at: None, cancelled_at: None, delivered_at: None };
+    let wrapped = order.to_wrapped_with_t(&MyTransform);
+
+    let output = serde_json::to_string(&wrapped.extra)?;
+    println!("Serialized extra: {output}");
+    assert_eq!(
+        output,
+        r#"{"text":{"created_at":"2025-08-06T16:32:24+00:00","cancelled_at":null,"delivered_at":"pending"}}"#
+    );
+
+    Ok(())
+}
@@ -0,0 +1,11 @@
+//! Org-wide presets, shared across crates via `#[macro_export]`
+
+shrinkwrap::define_preset! {
+    /// Wrapper and extra naming used by all of our APIs
+    #[macro_export]
+    org_defaults {
+        wrapper(struct_suffix = Envelope, derive(PartialEq)),
+        extra(struct_suffix = Meta, derive(PartialEq)),
+        derive_all(Default),
+    }
+}
@@ -0,0 +1,64 @@
+use serde::Serialize;
+use shrinkwrap::{ToWrappedWith, Transform, TransformToNest, Wrap};
+
+// !- Data definition
+
+// options are merged with the `org_defaults` preset (declared in this example's lib crate)
+#[shrinkwrap::preset(example_preset::org_defaults)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Wrap)]
+#[shrinkwrap(transform = MyTransform)]
+#[shrinkwrap(nest(id = "text", field_type = String, derive(PartialEq)))]
+pub struct Balance {
+    #[shrinkwrap(nest(id = "text"))]
+    usd: i64,
+}
+
+// own options take precedence, the remaining preset options still apply
+#[shrinkwrap::preset(example_preset::org_defaults)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Wrap)]
+#[shrinkwrap(transform = MyTransform, wrapper(struct_suffix = Response))]
+#[shrinkwrap(nest(id = "text", field_type = String, derive(PartialEq)))]
+pub struct Session {
+    #[shrinkwrap(nest(id = "text"))]
+    uptime_sec: i64,
+}
+
+// !- Transform
+
+struct MyTransform;
+impl Transform for MyTransform {
+    type Options = ();
+}
+impl TransformToNest<BalanceNestedText> for MyTransform {
+    type Data = Balance;
+
+    fn transform_to_nest(&self, data: &Balance, _: &()) -> BalanceNestedText {
+        BalanceNestedText { usd: format!("${}.00", data.usd) }
+    }
+}
+impl TransformToNest<SessionNestedText> for MyTransform {
+    type Data = Session;
+
+    fn transform_to_nest(&self, data: &Session, _: &()) -> SessionNestedText {
+        SessionNestedText { uptime_sec: format!("{} seconds", data.uptime_sec) }
+    }
+}
+
+// !- Usage
+
+pub fn main() -> Result<(), serde_json::Error> {
+    println!("Starting example: preset");
+
+    let balance: BalanceEnvelope = Balance { usd: 10 }.to_wrapped_with_t(&MyTransform);
+    let extra: &BalanceMeta = &balance.extra;
+    assert_eq!(extra.text.usd, "$10.00");
+    println!("Serialized balance: {}", serde_json::to_string(&balance)?);
+
+    let session: SessionResponse = Session { uptime_sec: 10 }.to_wrapped_with_t(&MyTransform);
+    assert_eq!(session.extra, SessionMeta { text: SessionNestedText { uptime_sec: "10 seconds".to_string() } });
+
+    // `derive_all(Default)` from the preset
+    assert_eq!(SessionResponse::default().data, Session::default());
+
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use serde::Serialize;
+use shrinkwrap::{ToWrappedWith, Transform, TransformToNest, Wrap};
+
+// !- Data definition
+
+// The legacy API contract expects `balance_text` next to `balance`, the `legacy` nest is promoted to the root of
+// the wrapper while remaining a regular nest in Rust
+#[derive(Debug, Clone, Serialize, Wrap)]
+#[shrinkwrap(transform = MyTransform)]
+#[shrinkwrap(nest(id = "legacy", field_type = String, promote))]
+#[shrinkwrap(nest(id = "text", field_type = String))]
+pub struct Account {
+    #[shrinkwrap(nest(id = "text"))]
+    balance: i64,
+
+    #[shrinkwrap(nest(id = "legacy"))]
+    #[shrinkwrap_attr(attr(serde(rename = "balance_text")), limit(nests("legacy")))]
+    balance_legacy: i64,
+}
+
+// !- Transform
+
+struct MyTransform;
+impl Transform for MyTransform {
+    type Options = ();
+}
+impl TransformToNest<AccountNestedText> for MyTransform {
+    type Data = Account;
+
+    fn transform_to_nest(&self, data: &Account, _: &()) -> AccountNestedText {
+        AccountNestedText { balance: format!("${}", data.balance) }
+    }
+}
+impl TransformToNest<AccountNestedLegacy> for MyTransform {
+    type Data = Account;
+
+    fn transform_to_nest(&self, data: &Account, _: &()) -> AccountNestedLegacy {
+        AccountNestedLegacy { balance_legacy: format!("{} USD", data.balance_legacy) }
+    }
+}
+
+// !- Usage
+
+pub fn main() -> Result<(), serde_json::Error> {
+    println!("Starting example: promote");
+
+    let wrapper = Account { balance: 10, balance_legacy: 10 }.to_wrapped_with_t(&MyTransform);
+    assert_eq!(wrapper.extra.legacy.balance_legacy, "10 USD");
+
+    let output = serde_json::to_string(&wrapper)?;
+    println!("Serialized account: {output}");
+    assert_eq!(
+        output,
+        r#"{"extra":{"text":{"balance":"$10"}},"balance":10,"balance_legacy":10,"balance_text":"10 USD"}"#,
+    );
+
+    Ok(())
+}
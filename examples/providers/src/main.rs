@@ -0,0 +1,65 @@
+use serde::Serialize;
+use shrinkwrap::providers::{Checksum, Timestamps};
+use shrinkwrap::{TryToWrappedWith, Transform, TryTransformToNest, Wrap};
+use std::time::Instant;
+
+// !- Data definition
+
+// `provider` nests are built by the provider itself, no `TryTransformToNest` impl is required
+// - `meta` records when the wrapper was generated, and the time elapsed since the start given to `Timestamps::since`
+// - `value_checksum` hashes its source data (the `value` nest), it is chained from `value` so it appears in `extra.value.extra`
+#[derive(Debug, Clone, Serialize, Wrap)]
+#[shrinkwrap(transform = MyTransform, fallible(error = MyError), json)]
+#[shrinkwrap(nest(id = "value", field_type = f32))]
+#[shrinkwrap(nest(id = "meta", provider = shrinkwrap::providers::Timestamps, optional))]
+#[shrinkwrap(nest(id = "value_checksum", field_name = "checksum", chain_from = "value", provider = shrinkwrap::providers::Checksum))]
+pub struct Account {
+    pub id: u32,
+
+    #[shrinkwrap(nest(id = "value"))]
+    pub balance: i64,
+}
+
+#[derive(Debug)]
+pub struct MyError;
+
+// !- Transform
+
+struct MyTransform;
+impl Transform for MyTransform {
+    type Options = ();
+}
+impl TryTransformToNest<AccountNestedValue> for MyTransform {
+    type Data = Account;
+    type Error = MyError;
+
+    fn try_transform_to_nest(&self, data: &Account, _: &()) -> Result<AccountNestedValue, MyError> {
+        Ok(AccountNestedValue { balance: data.balance as f32 / 100.0 })
+    }
+}
+
+// !- Usage
+
+pub fn main() {
+    println!("Starting example: providers");
+
+    // `Timestamps::since` records the time elapsed since the given start (e.g. the receipt of a request)
+    let received_at = Instant::now();
+    let account = Account { id: 1, balance: 1250 };
+    let wrapper = Timestamps::since(received_at, || account.try_to_wrapped_with_t(&MyTransform)).unwrap();
+    println!("Wrapped output:\n{}", serde_json::to_string_pretty(&wrapper).unwrap());
+
+    // optional provider nests are always populated
+    let meta = wrapper.extra.meta.expect("meta is always provided");
+    assert!(meta.generated_at > 0);
+    assert!(meta.elapsed_ms.is_some_and(|elapsed| u128::from(elapsed) <= received_at.elapsed().as_millis()));
+
+    // the checksum is computed over the serialized source data (the `value` nest)
+    let value = &wrapper.extra.value;
+    assert_eq!(value.extra.checksum, Checksum::of(&value.data));
+    assert_eq!(value.extra.checksum, Checksum::of(&serde_json::json!({ "balance": 12.5 })));
+
+    // provider types are reflected in the json contract
+    let json = serde_json::to_value(&wrapper).unwrap();
+    assert!(AccountWrapper::matches_shape(&json).is_empty());
+}
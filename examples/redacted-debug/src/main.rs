@@ -0,0 +1,63 @@
+use serde::Serialize;
+use shrinkwrap::{ToWrappedWith, Transform, TransformToNest, Wrap};
+
+// !- Data definition
+
+// `no_debug` replaces the derived `Debug` with a redacted impl, only the `contact` nest holds PII here
+#[derive(Clone, Serialize, Wrap)]
+#[shrinkwrap(transform = MyTransform)]
+#[shrinkwrap(nest(id = "contact", field_type = String, no_debug))]
+#[shrinkwrap(nest(id = "text", field_type = String))]
+pub struct Customer {
+    #[shrinkwrap(nest(id = "contact"))]
+    email: String,
+
+    #[shrinkwrap(nest(id = "text"))]
+    balance: i64,
+}
+
+// the origin data holds PII as well, implement `Debug` manually
+impl std::fmt::Debug for Customer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Customer").field("balance", &self.balance).finish_non_exhaustive()
+    }
+}
+
+// !- Transform
+
+struct MyTransform;
+impl Transform for MyTransform {
+    type Options = ();
+}
+impl TransformToNest<CustomerNestedContact> for MyTransform {
+    type Data = Customer;
+
+    fn transform_to_nest(&self, data: &Customer, _: &()) -> CustomerNestedContact {
+        CustomerNestedContact { email: format!("mailto:{}", data.email) }
+    }
+}
+impl TransformToNest<CustomerNestedText> for MyTransform {
+    type Data = Customer;
+
+    fn transform_to_nest(&self, data: &Customer, _: &()) -> CustomerNestedText {
+        CustomerNestedText { balance: format!("${}.00", data.balance) }
+    }
+}
+
+// !- Usage
+
+pub fn main() {
+    println!("Starting example: redacted-debug");
+
+    let data = Customer { email: "jane@example.com".to_string(), balance: 10 };
+    let wrapped = data.to_wrapped_with_t(&MyTransform);
+
+    // nest field values are redacted, other nests use the derived impl
+    let output = format!("{:?}", wrapped.extra);
+    println!("Debug extra: {output}");
+    assert_eq!(
+        output,
+        r#"CustomerExtra { contact: CustomerNestedContact { email: <redacted> }, text: CustomerNestedText { balance: "$10.00" } }"#
+    );
+    assert!(!format!("{wrapped:?}").contains("jane"));
+}
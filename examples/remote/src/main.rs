@@ -0,0 +1,55 @@
+use serde::Serialize;
+use shrinkwrap::{Transform, TransformToNest, Wrap};
+
+// !- Vendor SDK (stands in for a third-party crate)
+
+mod vendor_sdk {
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Invoice {
+        pub number: u64,
+        pub amount_cents: i64,
+    }
+}
+
+// !- Data definition
+
+/// Local mirror of `vendor_sdk::Invoice`, which can't derive `Wrap` itself
+#[derive(Debug, Clone, Serialize, Wrap)]
+#[shrinkwrap(transform = MyTransform, remote = "vendor_sdk::Invoice")]
+#[shrinkwrap(nest(id = "text", field_type = String))]
+pub struct InvoiceDef {
+    number: u64,
+    #[shrinkwrap(nest(id = "text"))]
+    amount_cents: i64,
+}
+
+// !- Transform
+
+struct MyTransform;
+impl Transform for MyTransform {
+    type Options = ();
+}
+impl TransformToNest<InvoiceDefNestedText> for MyTransform {
+    type Data = InvoiceDef;
+
+    fn transform_to_nest(&self, data: &InvoiceDef, _: &()) -> InvoiceDefNestedText {
+        InvoiceDefNestedText { amount_cents: format!("${:.2}", data.amount_cents as f64 / 100.0) }
+    }
+}
+
+// !- Usage
+
+pub fn main() -> Result<(), serde_json::Error> {
+    println!("Starting example: remote");
+
+    let invoice = vendor_sdk::Invoice { number: 1042, amount_cents: 129_900 };
+    let wrapper = InvoiceDef::wrap_remote(invoice.clone(), &MyTransform, &());
+    assert_eq!(wrapper.extra.text.amount_cents, "$1299.00");
+    println!("Serialized: {}", serde_json::to_string(&wrapper)?);
+
+    // the wrapped data converts back into the vendor type
+    let unwrapped = vendor_sdk::Invoice::from(wrapper.data);
+    assert_eq!(unwrapped, invoice);
+
+    Ok(())
+}
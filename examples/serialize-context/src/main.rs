@@ -0,0 +1,77 @@
+use serde::{Serialize, Serializer};
+use shrinkwrap::context::{self, Contextual, ContextualSerialize};
+use shrinkwrap::{ToWrappedWith, Transform, TransformToNest, Wrap};
+
+// !- Data definition
+
+// the `text` nest holds `Contextual` values, which are rendered using the locale set at serialization time
+#[derive(Debug, Clone, Serialize, Wrap)]
+#[shrinkwrap(transform = MyTransform)]
+#[shrinkwrap(nest(id = "text", field_type = "Contextual<Amount>"))]
+pub struct Invoice {
+    pub id: u32,
+
+    #[shrinkwrap(nest(id = "text"))]
+    pub total: f64,
+}
+
+// !- Contextual serialization
+
+#[derive(Debug, Clone)]
+pub enum Locale {
+    EnUs,
+    DeDe,
+}
+
+#[derive(Debug, Clone)]
+pub struct Amount(f64);
+impl ContextualSerialize for Amount {
+    type Context = Locale;
+
+    fn serialize_in_context<S: Serializer>(&self, locale: Option<&Locale>, serializer: S) -> Result<S::Ok, S::Error> {
+        match locale.unwrap_or(&Locale::EnUs) {
+            Locale::EnUs => serializer.serialize_str(&format!("${:.2}", self.0)),
+            Locale::DeDe => serializer.serialize_str(&format!("{:.2} $", self.0).replace('.', ",")),
+        }
+    }
+}
+
+// !- Transform
+
+struct MyTransform;
+impl Transform for MyTransform {
+    type Options = ();
+}
+impl TransformToNest<InvoiceNestedText> for MyTransform {
+    type Data = Invoice;
+
+    fn transform_to_nest(&self, data: &Invoice, _: &()) -> InvoiceNestedText {
+        InvoiceNestedText { total: Amount(data.total).into() }
+    }
+}
+
+// !- Usage
+
+pub fn main() {
+    println!("Starting example: serialize-context");
+
+    // the wrapper is built once, independent of the requesting locale
+    let wrapper = Invoice { id: 7, total: 1234.5 }.to_wrapped_with(&MyTransform, &());
+
+    // no context set, falls back to the default locale
+    let json = serde_json::to_string(&wrapper).unwrap();
+    println!("Default: {json}");
+    assert_eq!(json, r#"{"extra":{"text":{"total":"$1234.50"}},"id":7,"total":1234.5}"#);
+
+    // e.g. set per request, from the `Accept-Language` header
+    let json = context::with(Locale::DeDe, || serde_json::to_string(&wrapper).unwrap());
+    println!("de-DE: {json}");
+    assert_eq!(json, r#"{"extra":{"text":{"total":"1234,50 $"}},"id":7,"total":1234.5}"#);
+
+    // the context is cleared once the guard is dropped
+    {
+        let _guard = context::set(Locale::DeDe);
+        assert!(context::current::<Locale>().is_some());
+    }
+    assert!(context::current::<Locale>().is_none());
+}
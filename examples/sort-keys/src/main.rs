@@ -0,0 +1,58 @@
+use serde::Serialize;
+use shrinkwrap::{ToWrappedWith, Transform, TransformToNest, Wrap};
+
+// !- Data definition
+
+// `sort_keys` replaces the derived `Serialize` impls with ones that emit keys in lexicographic order
+#[derive(Debug, Clone, Serialize, Wrap)]
+#[shrinkwrap(transform = MyTransform)]
+#[shrinkwrap(wrapper(sort_keys))]
+#[shrinkwrap(nest(id = "text", field_type = String))]
+pub struct MyData {
+    #[shrinkwrap(nest(id = "text"))]
+    uptime_sec: i64,
+
+    #[shrinkwrap(nest(id = "text"))]
+    #[serde(rename = "active_sessions")]
+    sessions: i64,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    label: Option<String>,
+}
+
+// !- Transform
+
+struct MyTransform;
+impl Transform for MyTransform {
+    type Options = ();
+}
+impl TransformToNest<MyDataNestedText> for MyTransform {
+    type Data = MyData;
+
+    fn transform_to_nest(&self, data: &MyData, _: &()) -> MyDataNestedText {
+        MyDataNestedText {
+            uptime_sec: format!("{} seconds", data.uptime_sec),
+            sessions: format!("{} sessions", data.sessions),
+        }
+    }
+}
+
+// !- Usage
+
+pub fn main() -> Result<(), serde_json::Error> {
+    println!("Starting example: sort-keys");
+
+    let data = MyData { uptime_sec: 10, sessions: 2, label: None };
+    // `MyTransform` has no options, the `&()` argument can be omitted
+    let wrapped = data.to_wrapped_with_t(&MyTransform);
+
+    // flattened data fields are sorted alongside `extra`, nest fields are sorted within their own object
+    let output = serde_json::to_string(&wrapped)?;
+    println!("Serialized wrapper: {output}");
+    assert_eq!(
+        output,
+        r#"{"active_sessions":2,"extra":{"text":{"sessions":"2 sessions","uptime_sec":"10 seconds"}},"uptime_sec":10}"#
+    );
+
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use futures::{StreamExt, executor::block_on, stream};
+use serde::Serialize;
+use shrinkwrap::stream::WrapStreamExt;
+use shrinkwrap::{Transform, TransformToNest, Wrap};
+use std::sync::Arc;
+
+// !- Data definition
+
+#[derive(Debug, Clone, Serialize, Wrap)]
+#[shrinkwrap(transform = MyTransform)]
+#[shrinkwrap(nest(id = "text", field_type = String))]
+pub struct MyData {
+    #[shrinkwrap(nest(id = "text"))]
+    uptime_sec: i64,
+}
+
+// !- Transform
+
+struct MyTransform;
+impl Transform for MyTransform {
+    type Options = MyTransformOpts;
+}
+
+struct MyTransformOpts {
+    suffix: &'static str,
+}
+
+impl TransformToNest<MyDataNestedText> for MyTransform {
+    type Data = MyData;
+
+    fn transform_to_nest(&self, data: &MyData, options: &MyTransformOpts) -> MyDataNestedText {
+        MyDataNestedText {
+            uptime_sec: format!("{} {}", data.uptime_sec, options.suffix),
+        }
+    }
+}
+
+/// Simulates loading a single record asynchronously
+async fn load(uptime_sec: i64) -> MyData {
+    MyData { uptime_sec }
+}
+
+// !- Usage
+
+pub fn main() -> Result<(), serde_json::Error> {
+    println!("Starting example: stream");
+
+    let options = MyTransformOpts { suffix: "seconds" };
+
+    // borrowed transform/options, items are wrapped as they are polled
+    let records = stream::iter((1..=3).map(|uptime_sec| MyData { uptime_sec }));
+    let ndjson = block_on(
+        records
+            .wrap_with(&MyTransform, &options)
+            .map(|wrapper| serde_json::to_string(&wrapper).map(|line| line + "\n"))
+            .collect::<Vec<_>>(),
+    )
+    .into_iter()
+    .collect::<Result<String, _>>()?;
+    println!("NDJSON output:\n{ndjson}");
+    assert_eq!(ndjson.lines().count(), 3);
+    assert_eq!(ndjson.lines().next(), Some(r#"{"extra":{"text":{"uptime_sec":"1 seconds"}},"uptime_sec":1}"#));
+
+    // owned (shared) transform/options produce a `'static` stream, records are loaded 2 at a time
+    let transform = Arc::new(MyTransform);
+    let options = Arc::new(options);
+    let loads = stream::iter((1..=5).map(load));
+    let wrapped = block_on(loads.buffered_wrap_with(2, transform, options).collect::<Vec<_>>());
+    assert_eq!(wrapped.len(), 5);
+    assert_eq!(wrapped[4].extra.text.uptime_sec, "5 seconds");
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use serde::{Deserialize, Serialize};
+use shrinkwrap::{ToWrappedWith, Transform, TransformToNest, Wrap};
+
+// !- Data definition
+
+// Clients send wrappers back to us, unknown keys are rejected and missing (optional) nests are defaulted
+#[derive(Debug, Clone, Serialize, Deserialize, Wrap)]
+#[shrinkwrap(transform = MyTransform)]
+#[shrinkwrap(wrapper(deserialize(deny_unknown_fields, default_extras)))]
+#[shrinkwrap(nest(id = "text", field_type = String, optional))]
+#[shrinkwrap(nest(id = "value", field_type = f64, optional))]
+pub struct Balance {
+    #[shrinkwrap(nest(id = "text"), nest(id = "value"))]
+    usd: i64,
+}
+
+// !- Transform
+
+struct MyTransform;
+impl Transform for MyTransform {
+    type Options = ();
+}
+impl TransformToNest<Option<BalanceNestedText>> for MyTransform {
+    type Data = Balance;
+
+    fn transform_to_nest(&self, data: &Balance, _: &()) -> Option<BalanceNestedText> {
+        Some(BalanceNestedText { usd: format!("${}", data.usd) })
+    }
+}
+impl TransformToNest<Option<BalanceNestedValue>> for MyTransform {
+    type Data = Balance;
+
+    fn transform_to_nest(&self, _: &Balance, _: &()) -> Option<BalanceNestedValue> {
+        None
+    }
+}
+
+// !- Usage
+
+pub fn main() -> Result<(), serde_json::Error> {
+    println!("Starting example: strict-deserialize");
+
+    // serialized wrappers deserialize back as-is
+    let output = serde_json::to_string(&Balance { usd: 10 }.to_wrapped_with_t(&MyTransform))?;
+    println!("Serialized wrapper: {output}");
+    let wrapper: BalanceWrapper = serde_json::from_str(&output)?;
+    assert_eq!(serde_json::to_string(&wrapper)?, output);
+
+    // missing optional nests are `None`
+    let wrapper: BalanceWrapper = serde_json::from_str(r#"{"extra":{"text":{"usd":"$10"}},"usd":10}"#)?;
+    assert_eq!(wrapper.extra.text.as_ref().map(|text| text.usd.as_str()), Some("$10"));
+    assert!(wrapper.extra.value.is_none());
+
+    // the extra struct is defaulted as all nests are optional
+    let wrapper: BalanceWrapper = serde_json::from_str(r#"{"usd":10}"#)?;
+    assert!(wrapper.extra.text.is_none() && wrapper.extra.value.is_none());
+    assert_eq!(wrapper.data.usd, 10);
+
+    // unknown keys are rejected at every level
+    let error = serde_json::from_str::<BalanceWrapper>(r#"{"usd":10,"eur":9}"#).unwrap_err();
+    println!("Unknown wrapper key: {error}");
+    assert!(error.to_string().starts_with("unknown field `eur`"));
+
+    let error = serde_json::from_str::<BalanceWrapper>(r#"{"extra":{"txt":null},"usd":10}"#).unwrap_err();
+    println!("Unknown extra key: {error}");
+    assert!(error.to_string().starts_with("unknown field `txt`"));
+
+    Ok(())
+}
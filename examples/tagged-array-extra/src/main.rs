@@ -0,0 +1,58 @@
+use serde::Serialize;
+use shrinkwrap::{ToWrappedWith, Transform, TransformToNest, Wrap};
+
+// !- Data definition
+
+// `extra(layout = "tagged_array")` generates `BalanceExtra` as a `Vec<BalanceExtraItem>`, one item per populated nest
+#[derive(Debug, Clone, Serialize, Wrap)]
+#[shrinkwrap(transform = MyTransform)]
+#[shrinkwrap(extra(layout = "tagged_array", tag = "type"))]
+#[shrinkwrap(nest(id = "text", field_type = String))]
+#[shrinkwrap(nest(id = "local", field_type = f64, optional))]
+pub struct Balance {
+    #[shrinkwrap(nest(id = "text"), nest(id = "local"))]
+    usd: f64,
+}
+
+// !- Transform
+
+struct MyTransform;
+impl Transform for MyTransform {
+    /// USD exchange rate of the user's local currency, if any
+    type Options = Option<f64>;
+}
+impl TransformToNest<BalanceNestedText> for MyTransform {
+    type Data = Balance;
+
+    fn transform_to_nest(&self, data: &Balance, _: &Option<f64>) -> BalanceNestedText {
+        BalanceNestedText { usd: format!("${:.2}", data.usd) }
+    }
+}
+impl TransformToNest<Option<BalanceNestedLocal>> for MyTransform {
+    type Data = Balance;
+
+    fn transform_to_nest(&self, data: &Balance, rate: &Option<f64>) -> Option<BalanceNestedLocal> {
+        rate.map(|rate| BalanceNestedLocal { usd: data.usd * rate })
+    }
+}
+
+// !- Usage
+
+pub fn main() -> Result<(), serde_json::Error> {
+    println!("Starting example: tagged-array-extra");
+
+    let local = Balance { usd: 10.0 }.to_wrapped_with(&MyTransform, &Some(1.5));
+    assert!(matches!(local.extra.as_slice(), [BalanceExtraItem::Text(..), BalanceExtraItem::Local(..)]));
+
+    let output = serde_json::to_string(&local)?;
+    println!("Serialized local balance: {output}");
+    assert_eq!(output, r#"{"extra":[{"type":"text","usd":"$10.00"},{"type":"local","usd":15.0}],"usd":10.0}"#);
+
+    // nests which are `None` are omitted from the array
+    let usd = Balance { usd: 10.0 }.to_wrapped_with(&MyTransform, &None);
+    let output = serde_json::to_string(&usd)?;
+    println!("Serialized usd balance: {output}");
+    assert_eq!(output, r#"{"extra":[{"type":"text","usd":"$10.00"}],"usd":10.0}"#);
+
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use arbitrary::{Arbitrary, Unstructured};
+use proptest::arbitrary::any;
+use proptest::strategy::{Strategy, ValueTree};
+use proptest::test_runner::TestRunner;
+use serde::Serialize;
+use shrinkwrap::{ToWrappedWith, Transform, TransformToNest, Wrap};
+
+// !- Data definition
+
+// `testing(arbitrary, proptest)` derives `arbitrary::Arbitrary` and `proptest_derive::Arbitrary` on the wrapper, extra,
+// and nest structs, allowing fuzzers and property tests to build random wrappers directly.
+// The origin data struct must implement the same traits.
+#[derive(Debug, Clone, Serialize, Wrap, arbitrary::Arbitrary, proptest_derive::Arbitrary)]
+#[shrinkwrap(transform = MyTransform, testing(arbitrary, proptest))]
+#[shrinkwrap(nest(id = "text", field_type = String))]
+#[shrinkwrap(nest(id = "value", field_type = f64, optional))]
+#[shrinkwrap(nest(id = "value_text", field_type = String, chain_from = "value"))]
+pub struct Account {
+    #[shrinkwrap(nest(id = "text"), nest(id = "value"), nest(id = "value_text"))]
+    pub balance: i64,
+}
+
+// !- Transform
+
+struct MyTransform;
+impl Transform for MyTransform {
+    type Options = ();
+}
+impl TransformToNest<AccountNestedText> for MyTransform {
+    type Data = Account;
+
+    fn transform_to_nest(&self, data: &Account, _: &()) -> AccountNestedText {
+        AccountNestedText { balance: format!("{} credits", data.balance) }
+    }
+}
+impl TransformToNest<Option<AccountNestedValue>> for MyTransform {
+    type Data = Account;
+
+    fn transform_to_nest(&self, data: &Account, _: &()) -> Option<AccountNestedValue> {
+        Some(AccountNestedValue { balance: data.balance as f64 / 100.0 })
+    }
+}
+impl TransformToNest<AccountNestedValueValueText> for MyTransform {
+    type Data = AccountNestedValue;
+
+    fn transform_to_nest(&self, data: &AccountNestedValue, _: &()) -> AccountNestedValueValueText {
+        AccountNestedValueValueText { balance: format!("${:.2}", data.balance) }
+    }
+}
+
+// !- Usage
+
+pub fn main() {
+    println!("Starting example: testing-arbitrary");
+
+    // fuzzing input, e.g. provided by `cargo fuzz`
+    let bytes = (0..=u8::MAX).cycle().take(1024).collect::<Vec<_>>();
+    let mut input = Unstructured::new(&bytes);
+    let wrapper = AccountWrapper::arbitrary(&mut input).expect("enough input for a wrapper");
+    println!("Arbitrary wrapper: {wrapper:#?}");
+    serde_json::to_value(&wrapper).expect("random wrappers serialize");
+
+    // random nest structs are generated independently of the origin data
+    let text = AccountNestedText::arbitrary(&mut input).expect("enough input for a nest");
+    println!("Arbitrary nest: {text:?}");
+
+    // proptest strategies, e.g. for `proptest!` blocks
+    let mut runner = TestRunner::deterministic();
+    for _ in 0..16 {
+        let wrapper = any::<AccountWrapper>().new_tree(&mut runner).expect("strategy yields a wrapper").current();
+        serde_json::to_value(&wrapper).expect("random wrappers serialize");
+
+        // random origin data always wraps consistently
+        let account = any::<Account>().new_tree(&mut runner).expect("strategy yields data").current();
+        let wrapped = account.to_wrapped_with(&MyTransform, &());
+        assert_eq!(wrapped.extra.text.balance, format!("{} credits", wrapped.data.balance));
+        let value = wrapped.extra.value.expect("value is always provided");
+        assert_eq!(value.extra.value_text.balance, format!("${:.2}", value.data.balance));
+    }
+}
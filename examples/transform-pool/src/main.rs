@@ -0,0 +1,83 @@
+use serde::Serialize;
+use shrinkwrap::pool::{self, TransformPool};
+use shrinkwrap::{Transform, TransformToNest, Wrap};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::thread;
+
+// !- Data definition
+
+#[derive(Debug, Clone, Serialize, Wrap)]
+#[shrinkwrap(transform = MyTransform)]
+#[shrinkwrap(nest(id = "text", field_type = String))]
+pub struct MyData {
+    #[shrinkwrap(nest(id = "text"))]
+    uptime_sec: i64,
+}
+
+// !- Transform
+
+/// Holds a per-instance cache of formatted values, which isn't `Sync`
+struct MyTransform {
+    cache: RefCell<HashMap<(i64, &'static str), String>>,
+}
+impl MyTransform {
+    /// Stands in for an expensive setup (e.g. compiling templates)
+    fn new() -> Self {
+        Self { cache: RefCell::new(HashMap::new()) }
+    }
+}
+impl Transform for MyTransform {
+    type Options = MyTransformOpts;
+}
+
+struct MyTransformOpts {
+    suffix: &'static str,
+}
+
+impl TransformToNest<MyDataNestedText> for MyTransform {
+    type Data = MyData;
+
+    fn transform_to_nest(&self, data: &MyData, options: &MyTransformOpts) -> MyDataNestedText {
+        let uptime_sec = self.cache
+            .borrow_mut()
+            .entry((data.uptime_sec, options.suffix))
+            .or_insert_with(|| format!("{} {}", data.uptime_sec, options.suffix))
+            .clone();
+        MyDataNestedText { uptime_sec }
+    }
+}
+
+// !- Usage
+
+pub fn main() -> Result<(), serde_json::Error> {
+    println!("Starting example: transform-pool");
+
+    let options = MyTransformOpts { suffix: "seconds" };
+    // at most 2 instances are retained between bursts of work
+    let pool = TransformPool::new(MyTransform::new).prefilled(2).with_max_idle(2);
+
+    // each worker checks out its own instance, new instances are built only while the pool is empty
+    let lines = thread::scope(|scope| {
+        let workers: Vec<_> = (0..4)
+            .map(|worker| {
+                let (pool, options) = (&pool, &options);
+                scope.spawn(move || {
+                    let records = (1..=2).map(|index| MyData { uptime_sec: worker * 10 + index });
+                    records
+                        .map(|data| serde_json::to_string(&pool::wrap_with_pooled(data, pool, options)))
+                        .collect::<Result<Vec<_>, _>>()
+                })
+            })
+            .collect();
+        workers.into_iter().map(|worker| worker.join().expect("worker panicked")).collect::<Result<Vec<_>, _>>()
+    })?;
+    println!("Output (by worker):\n{}", lines.concat().join("\n"));
+    println!("Idle instances: {}", pool.idle());
+
+    // a whole collection shares a single checked out instance
+    let batch = pool::wrap_all_with_pooled((1..=3).map(|uptime_sec| MyData { uptime_sec }).collect(), &pool, &options);
+    println!("Batch output: {}", serde_json::to_string(&batch)?);
+
+    Ok(())
+}
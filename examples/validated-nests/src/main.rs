@@ -0,0 +1,45 @@
+use serde::Serialize;
+use shrinkwrap::validator::Validate;
+use shrinkwrap::{ToWrappedWith, Transform, TransformToNest, Wrap};
+
+// !- Data definition
+
+#[derive(Debug, Clone, Serialize, Wrap)]
+#[shrinkwrap(transform = MyTransform)]
+#[shrinkwrap(nest(id = "text", field_type = String, validate))]
+pub struct Profile {
+    #[shrinkwrap(nest(id = "text", validate(length(min = 1, max = 16))))]
+    pub display_name: String,
+}
+
+// !- Transform
+
+struct MyTransform;
+impl Transform for MyTransform {
+    type Options = ();
+}
+
+impl TransformToNest<ProfileNestedText> for MyTransform {
+    type Data = Profile;
+
+    fn transform_to_nest(&self, data: &Profile, _: &()) -> ProfileNestedText {
+        ProfileNestedText {
+            display_name: data.display_name.trim().to_string(),
+        }
+    }
+}
+
+// !- Usage
+
+pub fn main() {
+    println!("Starting example: validated-nests");
+
+    // transform outputs are validated before being returned
+    for display_name in ["  Ada  ", "   "] {
+        let wrapper = Profile { display_name: display_name.into() }.to_wrapped_with(&MyTransform, &());
+        match wrapper.extra.text.validate() {
+            Ok(()) => println!("Valid nest: {:?}", wrapper.extra.text),
+            Err(errors) => println!("Invalid nest for {display_name:?}: {errors}"),
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use schemars::JsonSchema;
+use serde::Serialize;
+use shrinkwrap::{DeprecatedNestGate, ToWrappedWith, Transform, TransformToNest, Wrap};
+
+// !- Data definition
+
+#[derive(Debug, Clone, Serialize, JsonSchema, Wrap)]
+#[shrinkwrap(transform = MyTransform, schema)]
+#[shrinkwrap(nest(id = "text", field_type = String, optional))]
+#[shrinkwrap(nest(id = "text_v2", field_type = String, deprecates = "text", gate_deprecated))]
+pub struct Download {
+    #[shrinkwrap(nest(id = "text"), nest(id = "text_v2"))]
+    bytes: u64,
+}
+
+// !- Transform
+
+/// Clients released before `text_v2` still expect `text`
+struct MyTransformOpts {
+    client_version: u32,
+}
+impl DeprecatedNestGate for MyTransformOpts {
+    fn emit_deprecated(&self, nest: &str) -> bool {
+        match nest {
+            "text" => self.client_version < 2,
+            _ => true,
+        }
+    }
+}
+
+struct MyTransform;
+impl Transform for MyTransform {
+    type Options = MyTransformOpts;
+}
+impl TransformToNest<Option<DownloadNestedText>> for MyTransform {
+    type Data = Download;
+
+    fn transform_to_nest(&self, data: &Download, _: &MyTransformOpts) -> Option<DownloadNestedText> {
+        Some(DownloadNestedText { bytes: format!("{} KB", data.bytes / 1000) })
+    }
+}
+impl TransformToNest<DownloadNestedTextV2> for MyTransform {
+    type Data = Download;
+
+    fn transform_to_nest(&self, data: &Download, _: &MyTransformOpts) -> DownloadNestedTextV2 {
+        DownloadNestedTextV2 { bytes: format!("{:.1} KiB", data.bytes as f64 / 1024.0) }
+    }
+}
+
+// !- Usage
+
+pub fn main() -> Result<(), serde_json::Error> {
+    println!("Starting example: versioned-nests");
+
+    let download = Download { bytes: 52_000 };
+
+    // both versions are emitted for older clients
+    let legacy = download.clone().to_wrapped_with(&MyTransform, &MyTransformOpts { client_version: 1 });
+    #[allow(deprecated)]
+    let text = legacy.extra.text().map(|text| text.bytes.as_str());
+    assert_eq!(text, Some("52 KB"));
+    assert_eq!(legacy.extra.text_v2.bytes, "50.8 KiB");
+    println!("Legacy client: {}", serde_json::to_string(&legacy)?);
+
+    // the deprecated nest is skipped for current clients
+    let current = download.to_wrapped_with(&MyTransform, &MyTransformOpts { client_version: 2 });
+    assert!(current.extra.text.is_none());
+    println!("Current client: {}", serde_json::to_string(&current)?);
+
+    // the schema documents the relationship
+    let schema = schemars::schema_for!(DownloadWrapper).to_value();
+    let extra = &schema["$defs"]["DownloadExtra"]["properties"];
+    assert_eq!(extra["text"]["deprecated"], true);
+    assert_eq!(extra["text"]["x-superseded-by"], "text_v2");
+    assert_eq!(extra["text_v2"]["x-supersedes"], "text");
+    println!("Extra schema: {}", serde_json::to_string_pretty(extra)?);
+
+    Ok(())
+}
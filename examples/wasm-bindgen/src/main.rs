@@ -0,0 +1,59 @@
+use serde::Serialize;
+use shrinkwrap::{ToWrappedWith, Transform, TransformToNest, Wrap};
+use wasm_bindgen::prelude::wasm_bindgen;
+
+// !- Data definition
+
+// the data struct is exported to JS itself, the generated structs follow via `wasm_bindgen`
+#[wasm_bindgen(getter_with_clone)]
+#[derive(Debug, Clone, Serialize, Wrap)]
+#[shrinkwrap(transform = MyTransform, wasm_bindgen)]
+#[shrinkwrap(nest(id = "text", field_type = String))]
+#[shrinkwrap(nest(id = "value", field_type = f64, optional))]
+pub struct Reading {
+    pub sensor_id: u32,
+    #[shrinkwrap(nest(id = "text"), nest(id = "value"))]
+    pub celsius: f64,
+}
+
+// !- Transform
+
+struct MyTransform;
+impl Transform for MyTransform {
+    type Options = ();
+}
+impl TransformToNest<ReadingNestedText> for MyTransform {
+    type Data = Reading;
+
+    fn transform_to_nest(&self, data: &Reading, _: &()) -> ReadingNestedText {
+        ReadingNestedText { celsius: format!("{:.1} °C", data.celsius) }
+    }
+}
+impl TransformToNest<Option<ReadingNestedValue>> for MyTransform {
+    type Data = Reading;
+
+    fn transform_to_nest(&self, data: &Reading, _: &()) -> Option<ReadingNestedValue> {
+        Some(ReadingNestedValue { celsius: data.celsius * 1.8 + 32.0 })
+    }
+}
+
+// !- JS surface
+
+/// Returned to JS as a `ReadingWrapper` class, e.g. `wrapped.extra.text.celsius`
+#[wasm_bindgen]
+pub fn wrap_reading(sensor_id: u32, celsius: f64) -> ReadingWrapper {
+    Reading { sensor_id, celsius }.to_wrapped_with(&MyTransform, &())
+}
+
+// !- Usage
+
+pub fn main() -> Result<(), serde_json::Error> {
+    println!("Starting example: wasm-bindgen");
+
+    let wrapper = wrap_reading(7, 21.5);
+    assert_eq!(wrapper.extra.text.celsius, "21.5 °C");
+    assert_eq!(wrapper.extra.value.as_ref().map(|value| value.celsius), Some(70.7));
+    println!("Serialized: {}", serde_json::to_string(&wrapper)?);
+
+    Ok(())
+}
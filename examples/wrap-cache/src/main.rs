@@ -0,0 +1,60 @@
+use serde::Serialize;
+use shrinkwrap::cache::WrapCache;
+use shrinkwrap::{Transform, TransformToNest, Wrap};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// !- Data definition
+
+#[derive(Debug, Clone, Serialize, Wrap)]
+#[shrinkwrap(transform = MyTransform)]
+#[shrinkwrap(nest(id = "text", field_type = String))]
+pub struct Product {
+    id: u64,
+    revision: u32,
+    #[shrinkwrap(nest(id = "text"))]
+    price_cents: i64,
+}
+
+// !- Transform
+
+/// Counts invocations to show cache hits
+#[derive(Default)]
+struct MyTransform {
+    calls: AtomicUsize,
+}
+impl Transform for MyTransform {
+    type Options = ();
+}
+impl TransformToNest<ProductNestedText> for MyTransform {
+    type Data = Product;
+
+    fn transform_to_nest(&self, data: &Product, _: &()) -> ProductNestedText {
+        self.calls.fetch_add(1, Ordering::Relaxed);
+        ProductNestedText { price_cents: format!("${}.{:02}", data.price_cents / 100, data.price_cents % 100) }
+    }
+}
+
+// !- Usage
+
+pub fn main() -> Result<(), serde_json::Error> {
+    println!("Starting example: wrap-cache");
+
+    let transform = MyTransform::default();
+    // keyed by id and revision, an updated product is never served stale
+    let cache = WrapCache::new(|product: &Product| (product.id, product.revision));
+
+    for _ in 0..3 {
+        let wrapper = cache.get_or_wrap(Product { id: 1, revision: 1, price_cents: 1999 }, &transform, &());
+        println!("Served: {}", serde_json::to_string(&*wrapper)?);
+    }
+    assert_eq!(transform.calls.load(Ordering::Relaxed), 1);
+
+    // a new revision is a new key, the previous one is invalidated explicitly
+    let wrapper = cache.get_or_wrap(Product { id: 1, revision: 2, price_cents: 1499 }, &transform, &());
+    println!("Served: {}", serde_json::to_string(&*wrapper)?);
+    cache.invalidate(&(1, 1));
+    assert_eq!(cache.len(), 1);
+    assert_eq!(transform.calls.load(Ordering::Relaxed), 2);
+
+    Ok(())
+}
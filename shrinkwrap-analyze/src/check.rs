@@ -0,0 +1,133 @@
+use heck::AsUpperCamelCase;
+use std::collections::{HashMap, HashSet};
+
+use crate::parse::{NestDecl, Spanned, WrapItem};
+use crate::{Diagnostic, DiagnosticKind};
+
+/// Runs each check against the `Wrap` structs of a file, `type_names` being the types declared within the file
+pub(crate) fn check_file(items: &[WrapItem], type_names: &[Spanned]) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    let mut struct_names = Vec::new();
+    for item in items {
+        check_duplicate_nests(item, &mut diagnostics);
+        check_unknown_nests(item, &mut diagnostics);
+        check_unreferenced_nests(item, &mut diagnostics);
+        check_field_names(item, &mut diagnostics);
+        struct_names.extend(nest_struct_names(item));
+    }
+    check_struct_names(&struct_names, type_names, &mut diagnostics);
+
+    diagnostics.sort_by_key(|diagnostic| (diagnostic.line, diagnostic.column));
+    diagnostics
+}
+
+fn check_duplicate_nests(item: &WrapItem, diagnostics: &mut Vec<Diagnostic>) {
+    let mut seen = HashSet::new();
+    for id in item.nests.iter().filter_map(|nest| nest.id.as_ref()) {
+        if !seen.insert(id.value.as_str()) {
+            let message = format!("Nest `{}` is declared multiple times", id.value);
+            diagnostics.push(Diagnostic::new(DiagnosticKind::DuplicateNest, item, id, message));
+        }
+    }
+}
+
+fn check_unknown_nests(item: &WrapItem, diagnostics: &mut Vec<Diagnostic>) {
+    // nests declared by a preset cannot be resolved
+    if item.has_preset {
+        return;
+    }
+    let declared = declared_ids(item);
+    let references = item.assignments.iter().map(|id| (id, "Field is assigned to"))
+        .chain(item.nests.iter().filter_map(|nest| nest.chain_from.as_ref()).map(|id| (id, "`chain_from` references")))
+        .chain(item.nests.iter().filter_map(|nest| nest.needs.as_ref()).map(|id| (id, "`needs` references")))
+        .chain(item.nests.iter().filter_map(|nest| nest.deprecates.as_ref()).map(|id| (id, "`deprecates` references")));
+    for (id, context) in references.filter(|(id, _)| !declared.contains(id.value.as_str())) {
+        let message = format!("{context} unknown nest `{}`", id.value);
+        diagnostics.push(Diagnostic::new(DiagnosticKind::UnknownNest, item, id, message));
+    }
+}
+
+fn check_unreferenced_nests(item: &WrapItem, diagnostics: &mut Vec<Diagnostic>) {
+    let referenced: HashSet<&str> = item.assignments.iter()
+        .chain(item.nests.iter().filter_map(|nest| nest.chain_from.as_ref()))
+        .map(|id| id.value.as_str())
+        .collect();
+    for nest in item.nests.iter().filter(|nest| !nest.self_populated) {
+        let Some(id) = &nest.id else {
+            continue;
+        };
+        if !referenced.contains(id.value.as_str()) {
+            let message = format!("Nest `{}` has no fields assigned to it", id.value);
+            diagnostics.push(Diagnostic::new(DiagnosticKind::UnreferencedNest, item, id, message));
+        }
+    }
+}
+
+fn check_field_names(item: &WrapItem, diagnostics: &mut Vec<Diagnostic>) {
+    let mut seen = HashSet::new();
+    for nest in &item.nests {
+        let (Some(id), Some(field_name)) = (&nest.id, nest.field_name()) else {
+            continue;
+        };
+        let parent = nest.chain_from.as_ref().map(|parent| parent.value.as_str());
+        if !seen.insert((parent, field_name.value.as_str())) {
+            let message = format!("Nest `{}` uses field name `{}`, which is already used by a sibling nest", id.value, field_name.value);
+            diagnostics.push(Diagnostic::new(DiagnosticKind::FieldNameCollision, item, field_name, message));
+        }
+    }
+}
+
+fn check_struct_names(struct_names: &[(&WrapItem, Spanned)], type_names: &[Spanned], diagnostics: &mut Vec<Diagnostic>) {
+    let mut seen = HashSet::new();
+    for (item, name) in struct_names {
+        if !seen.insert(name.value.as_str()) {
+            let message = format!("Nest struct `{}` is generated multiple times", name.value);
+            diagnostics.push(Diagnostic::new(DiagnosticKind::StructNameCollision, item, name, message));
+        } else if type_names.iter().any(|type_name| type_name.value == name.value) {
+            let message = format!("Nest struct `{}` collides with a type declared in the same file", name.value);
+            diagnostics.push(Diagnostic::new(DiagnosticKind::StructNameCollision, item, name, message));
+        }
+    }
+}
+
+fn declared_ids(item: &WrapItem) -> HashSet<&str> {
+    item.nests.iter().filter_map(|nest| nest.id.as_ref())
+        .chain(&item.inline_nests)
+        .map(|id| id.value.as_str())
+        .collect()
+}
+
+/// Generated struct name of each struct-level nest, spanned by `rename` when set, or the nest ID otherwise.
+///
+/// Default names follow the derive, i.e. `{Data}Nested{UpperCamel(field_name)}` for root nests and
+/// `{Parent}{UpperCamel(field_name)}` for chained nests.
+fn nest_struct_names(item: &WrapItem) -> Vec<(&WrapItem, Spanned)> {
+    let nests: HashMap<&str, &NestDecl> = item.nests.iter()
+        .filter_map(|nest| Some((nest.id.as_ref()?.value.as_str(), nest)))
+        .collect();
+    let mut names = Vec::new();
+    for nest in &item.nests {
+        if let Some(name) = nest_struct_name(item, &nests, nest, 0) {
+            names.push((item, name));
+        }
+    }
+    names
+}
+
+fn nest_struct_name(item: &WrapItem, nests: &HashMap<&str, &NestDecl>, nest: &NestDecl, depth: usize) -> Option<Spanned> {
+    if let Some(rename) = &nest.rename {
+        return Some(rename.clone());
+    }
+    let id = nest.id.as_ref()?;
+    let suffix = AsUpperCamelCase(&nest.field_name()?.value);
+    let value = match &nest.chain_from {
+        None => format!("{}Nested{suffix}", item.ident.value),
+        Some(parent_id) => {
+            // unknown parents and cycles are reported by the derive
+            let parent = nests.get(parent_id.value.as_str()).filter(|_| depth < nests.len())?;
+            let parent_name = nest_struct_name(item, nests, parent, depth + 1)?;
+            format!("{}{suffix}", parent_name.value)
+        },
+    };
+    Some(Spanned { value, span: id.span })
+}
@@ -0,0 +1,198 @@
+//! Static analysis of `shrinkwrap` derive inputs.
+//!
+//! Parses the `#[shrinkwrap(..)]` attributes of each struct deriving `Wrap` within a source file, reporting
+//! configuration problems without compiling the crate, e.g. for embedding checks in custom tooling. The
+//! `shrinkwrap-check` binary runs the analysis against the files given as arguments.
+//!
+//! Reported problems (see [`DiagnosticKind`]):
+//! - fields, `chain_from`, `needs`, or `deprecates` referencing undeclared nests
+//! - nests without any fields assigned to them
+//! - nests declared multiple times
+//! - sibling nests sharing a field name
+//! - generated nest struct names colliding with each other, or with types declared within the same file
+//!
+//! Nests declared by presets (`#[shrinkwrap::preset(..)]`) aren't resolved, references to unknown nests aren't
+//! reported for structs using a preset.
+//!
+//! ```
+//! use shrinkwrap_analyze::{DiagnosticKind, Severity, analyze_source};
+//!
+//! let source = r#"
+//!     #[derive(Wrap)]
+//!     #[shrinkwrap(transform = MyTransform)]
+//!     #[shrinkwrap(nest(id = "text", field_type = String))]
+//!     #[shrinkwrap(nest(id = "cents", field_type = i64))]
+//!     pub struct Account {
+//!         #[shrinkwrap(nest(id = "txt"))]
+//!         balance: f64,
+//!     }
+//! "#;
+//!
+//! let diagnostics = analyze_source(source).unwrap();
+//! let kinds: Vec<_> = diagnostics.iter().map(|diagnostic| (diagnostic.severity, diagnostic.kind)).collect();
+//! assert_eq!(kinds, [
+//!     (Severity::Warning, DiagnosticKind::UnreferencedNest),
+//!     (Severity::Warning, DiagnosticKind::UnreferencedNest),
+//!     (Severity::Error, DiagnosticKind::UnknownNest),
+//! ]);
+//! assert_eq!(diagnostics[2].to_string(), "error[Account] 7:32: Field is assigned to unknown nest `txt`");
+//! ```
+
+use std::fmt::{self, Display};
+use std::path::Path;
+
+mod check;
+mod parse;
+
+use parse::{Spanned, WrapItem};
+
+/// Analyzes the `Wrap` structs of a source file's contents, see the [crate docs](crate)
+///
+/// ```
+/// use shrinkwrap_analyze::{DiagnosticKind, analyze_source};
+///
+/// let source = r#"
+///     #[derive(Wrap)]
+///     #[shrinkwrap(transform = MyTransform)]
+///     #[shrinkwrap(nest(id = "text", field_type = String))]
+///     #[shrinkwrap(nest(id = "label", field_name = text, field_type = String))]
+///     pub struct Account {
+///         #[shrinkwrap(nest(id = "text"), nest(id = "label"))]
+///         balance: f64,
+///     }
+///
+///     pub struct AccountNestedText;
+/// "#;
+///
+/// let kinds: Vec<_> = analyze_source(source).unwrap().into_iter().map(|diagnostic| diagnostic.kind).collect();
+/// assert_eq!(kinds, [
+///     DiagnosticKind::StructNameCollision,
+///     DiagnosticKind::StructNameCollision,
+///     DiagnosticKind::FieldNameCollision,
+/// ]);
+/// ```
+pub fn analyze_source(source: &str) -> syn::Result<Vec<Diagnostic>> {
+    let file = syn::parse_file(source)?;
+    let mut items = Vec::new();
+    parse::collect_wrap_items(&file.items, &mut items)?;
+    let mut type_names = Vec::new();
+    parse::collect_type_names(&file.items, &mut type_names);
+
+    Ok(check::check_file(&items, &type_names))
+}
+
+/// Reads and analyzes the `Wrap` structs of the source file at `path`, see the [crate docs](crate)
+pub fn analyze_file(path: impl AsRef<Path>) -> Result<Vec<Diagnostic>, AnalyzeError> {
+    let source = std::fs::read_to_string(path).map_err(AnalyzeError::Io)?;
+    analyze_source(&source).map_err(AnalyzeError::Parse)
+}
+
+/// Error returned by [`analyze_file`]
+#[derive(Debug)]
+pub enum AnalyzeError {
+    /// The file could not be read
+    Io(std::io::Error),
+
+    /// The file, or a `shrinkwrap` attribute within it, could not be parsed
+    Parse(syn::Error),
+}
+impl Display for AnalyzeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "failed to read file: {err}"),
+            Self::Parse(err) => {
+                let start = err.span().start();
+                write!(f, "failed to parse file at {}:{}: {err}", start.line, start.column + 1)
+            },
+        }
+    }
+}
+impl std::error::Error for AnalyzeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            Self::Parse(err) => Some(err),
+        }
+    }
+}
+
+/// A single problem found by the analysis
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub kind: DiagnosticKind,
+
+    /// Ident of the `Wrap` struct the problem was found in
+    pub item: String,
+
+    pub message: String,
+
+    /// Line of the problem's location (1-indexed)
+    pub line: usize,
+
+    /// Column of the problem's location (1-indexed)
+    pub column: usize,
+}
+impl Diagnostic {
+    fn new(kind: DiagnosticKind, item: &WrapItem, at: &Spanned, message: String) -> Self {
+        let start = at.span.start();
+        Self {
+            severity: kind.severity(),
+            kind,
+            item: item.ident.value.clone(),
+            message,
+            line: start.line,
+            column: start.column + 1,
+        }
+    }
+}
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}[{}] {}:{}: {}", self.severity, self.item, self.line, self.column, self.message)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    /// The derive will fail to compile, or the generated code will collide with existing items
+    Error,
+
+    /// The configuration is likely unintended
+    Warning,
+}
+impl Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Error => f.write_str("error"),
+            Self::Warning => f.write_str("warning"),
+        }
+    }
+}
+
+/// Kind of problem reported by a [`Diagnostic`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosticKind {
+    /// A field, `chain_from`, `needs`, or `deprecates` references an undeclared nest
+    UnknownNest,
+
+    /// A nest has no fields assigned to it (nor nests chained from it), and isn't populated otherwise (`provider`,
+    /// `layout = "map"`, `auto_parent`, `fields`, or `all_fields`)
+    UnreferencedNest,
+
+    /// A nest ID is declared multiple times at struct level
+    DuplicateNest,
+
+    /// Sibling nests share a field name
+    FieldNameCollision,
+
+    /// A generated nest struct name is generated multiple times, or collides with a type declared within the same file
+    StructNameCollision,
+}
+impl DiagnosticKind {
+    pub fn severity(&self) -> Severity {
+        match self {
+            Self::UnreferencedNest => Severity::Warning,
+            _ => Severity::Error,
+        }
+    }
+}
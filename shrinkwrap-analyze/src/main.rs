@@ -0,0 +1,36 @@
+//! `shrinkwrap-check <FILE>..`
+//!
+//! Analyzes the `Wrap` structs of each file, printing any problems found. Exits with a non-zero status if any errors
+//! were found, or any file could not be analyzed.
+
+use shrinkwrap_analyze::{Severity, analyze_file};
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let paths: Vec<String> = std::env::args().skip(1).collect();
+    if paths.is_empty() {
+        eprintln!("usage: shrinkwrap-check <FILE>..");
+        return ExitCode::from(2);
+    }
+
+    let mut failed = false;
+    for path in &paths {
+        match analyze_file(path) {
+            Ok(diagnostics) => {
+                for diagnostic in &diagnostics {
+                    println!("{path}: {diagnostic}");
+                }
+                failed |= diagnostics.iter().any(|diagnostic| diagnostic.severity == Severity::Error);
+            },
+            Err(err) => {
+                eprintln!("{path}: {err}");
+                failed = true;
+            },
+        }
+    }
+
+    match failed {
+        true => ExitCode::FAILURE,
+        false => ExitCode::SUCCESS,
+    }
+}
@@ -0,0 +1,220 @@
+use proc_macro2::{Spacing, Span, TokenTree};
+use syn::meta::ParseNestedMeta;
+use syn::parse::discouraged::Speculative;
+use syn::{Attribute, Expr, ExprLit, Fields, Item, ItemStruct, Lit, Token};
+
+/// A string option value along with its location
+#[derive(Debug, Clone)]
+pub(crate) struct Spanned {
+    pub value: String,
+    pub span: Span,
+}
+
+/// A struct deriving `Wrap`, reduced to the options relevant to analysis
+#[derive(Debug, Clone)]
+pub(crate) struct WrapItem {
+    pub ident: Spanned,
+
+    /// Struct-level nest declarations, in declaration order
+    pub nests: Vec<NestDecl>,
+
+    /// Nests declared inline via field-level `nest_here`
+    pub inline_nests: Vec<Spanned>,
+
+    /// Field-level nest assignments (including those of `nest_here`)
+    pub assignments: Vec<Spanned>,
+
+    /// Whether the struct applies a preset, whose nests cannot be resolved
+    pub has_preset: bool,
+}
+
+/// A struct-level `nest(..)` declaration
+#[derive(Debug, Clone, Default)]
+pub(crate) struct NestDecl {
+    pub id: Option<Spanned>,
+    pub field_name: Option<Spanned>,
+    pub rename: Option<Spanned>,
+    pub chain_from: Option<Spanned>,
+    pub needs: Option<Spanned>,
+    pub deprecates: Option<Spanned>,
+    /// Uses `provider`, `layout = "map"`, `auto_parent`, `fields(..)` or `all_fields`, i.e. doesn't require assigned fields
+    pub self_populated: bool,
+}
+impl NestDecl {
+    /// Serialized field name of the nest, defaults to the nest ID
+    pub fn field_name(&self) -> Option<&Spanned> {
+        self.field_name.as_ref().or(self.id.as_ref())
+    }
+}
+
+/// Collects each struct deriving `Wrap` within `items`, including those of inline modules
+pub(crate) fn collect_wrap_items(items: &[Item], out: &mut Vec<WrapItem>) -> syn::Result<()> {
+    for item in items {
+        match item {
+            Item::Struct(item) if derives_wrap(&item.attrs) => out.push(parse_wrap_item(item)?),
+            Item::Mod(item) => {
+                if let Some((_, items)) = &item.content {
+                    collect_wrap_items(items, out)?;
+                }
+            },
+            _ => {},
+        }
+    }
+    Ok(())
+}
+
+/// Collects the names of the types declared within `items`, including those of inline modules
+pub(crate) fn collect_type_names(items: &[Item], out: &mut Vec<Spanned>) {
+    for item in items {
+        let ident = match item {
+            Item::Struct(item) => &item.ident,
+            Item::Enum(item) => &item.ident,
+            Item::Union(item) => &item.ident,
+            Item::Type(item) => &item.ident,
+            Item::Trait(item) => &item.ident,
+            Item::Mod(item) => {
+                if let Some((_, items)) = &item.content {
+                    collect_type_names(items, out);
+                }
+                continue;
+            },
+            _ => continue,
+        };
+        out.push(Spanned { value: ident.to_string(), span: ident.span() });
+    }
+}
+
+fn derives_wrap(attrs: &[Attribute]) -> bool {
+    let mut found = false;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("derive")) {
+        // unparseable derives are left for the compiler to report
+        let _ = attr.parse_nested_meta(|meta| {
+            found |= meta.path.segments.last().is_some_and(|segment| segment.ident == "Wrap");
+            Ok(())
+        });
+    }
+    found
+}
+
+fn is_preset_attr(attr: &Attribute) -> bool {
+    attr.path().segments.last().is_some_and(|segment| segment.ident == "preset")
+}
+
+fn parse_wrap_item(item: &ItemStruct) -> syn::Result<WrapItem> {
+    let mut wrap_item = WrapItem {
+        ident: Spanned { value: item.ident.to_string(), span: item.ident.span() },
+        nests: Vec::new(),
+        inline_nests: Vec::new(),
+        assignments: Vec::new(),
+        has_preset: item.attrs.iter().any(is_preset_attr),
+    };
+    for attr in item.attrs.iter().filter(|attr| attr.path().is_ident("shrinkwrap")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("nest") {
+                wrap_item.nests.push(parse_nest_decl(&meta)?);
+                Ok(())
+            } else {
+                skip_meta_value(&meta)
+            }
+        })?;
+    }
+    if let Fields::Named(fields) = &item.fields {
+        for attr in fields.named.iter().flat_map(|field| &field.attrs).filter(|attr| attr.path().is_ident("shrinkwrap")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("nest") {
+                    if let Some(id) = parse_nest_id(&meta)? {
+                        wrap_item.assignments.push(id);
+                    }
+                    Ok(())
+                } else if meta.path.is_ident("nest_here") {
+                    if let Some(id) = parse_nest_id(&meta)? {
+                        wrap_item.inline_nests.push(id.clone());
+                        wrap_item.assignments.push(id);
+                    }
+                    Ok(())
+                } else {
+                    skip_meta_value(&meta)
+                }
+            })?;
+        }
+    }
+    Ok(wrap_item)
+}
+
+fn parse_nest_decl(meta: &ParseNestedMeta) -> syn::Result<NestDecl> {
+    let mut nest = NestDecl::default();
+    meta.parse_nested_meta(|inner| {
+        let Some(key) = inner.path.get_ident().map(ToString::to_string) else {
+            return skip_meta_value(&inner);
+        };
+        match key.as_str() {
+            "id" => nest.id = parse_str_value(&inner)?,
+            "field_name" => nest.field_name = parse_str_value(&inner)?,
+            "rename" => nest.rename = parse_str_value(&inner)?,
+            "chain_from" => nest.chain_from = parse_str_value(&inner)?,
+            "needs" => nest.needs = parse_str_value(&inner)?,
+            "deprecates" => nest.deprecates = parse_str_value(&inner)?,
+            "layout" => nest.self_populated |= parse_str_value(&inner)?.is_some_and(|layout| layout.value == "map"),
+            "provider" | "auto_parent" | "fields" | "all_fields" => {
+                nest.self_populated = true;
+                skip_meta_value(&inner)?;
+            },
+            _ => skip_meta_value(&inner)?,
+        }
+        Ok(())
+    })?;
+    Ok(nest)
+}
+
+/// Parses the `id` of a field-level `nest(..)` or `nest_here(..)`
+fn parse_nest_id(meta: &ParseNestedMeta) -> syn::Result<Option<Spanned>> {
+    let mut id = None;
+    meta.parse_nested_meta(|inner| {
+        if inner.path.is_ident("id") {
+            id = parse_str_value(&inner)?;
+            Ok(())
+        } else {
+            skip_meta_value(&inner)
+        }
+    })?;
+    Ok(id)
+}
+
+/// Parses a `key = "value"` or `key = value` option, returning `None` for other value expressions
+fn parse_str_value(meta: &ParseNestedMeta) -> syn::Result<Option<Spanned>> {
+    let value = match meta.value()?.parse::<Expr>()? {
+        Expr::Lit(ExprLit { lit: Lit::Str(lit), .. }) => Some(Spanned { value: lit.value(), span: lit.span() }),
+        Expr::Path(path) => path.path.get_ident().map(|ident| Spanned { value: ident.to_string(), span: ident.span() }),
+        _ => None,
+    };
+    Ok(value)
+}
+
+/// Skips the value of an option which isn't relevant to analysis (flags, `key = value`, or `key(..)`)
+///
+/// Commas within balanced angle brackets are part of the value, e.g. `field_type = BTreeMap<String, String>`.
+fn skip_meta_value(meta: &ParseNestedMeta) -> syn::Result<()> {
+    let fork = meta.input.fork();
+    let mut depth = 0usize;
+    let mut after_dash = false;
+    while !fork.is_empty() && (depth > 0 || !fork.peek(Token![,])) {
+        let token = fork.parse::<TokenTree>()?;
+        match &token {
+            TokenTree::Punct(punct) if punct.as_char() == '<' => depth += 1,
+            // the arrow of `fn(..) -> T`
+            TokenTree::Punct(punct) if punct.as_char() == '>' && !after_dash => depth = depth.saturating_sub(1),
+            _ => {},
+        }
+        after_dash = matches!(&token, TokenTree::Punct(punct) if punct.as_char() == '-' && punct.spacing() == Spacing::Joint);
+    }
+    if depth == 0 {
+        meta.input.advance_to(&fork);
+        return Ok(());
+    }
+
+    // unbalanced, e.g. a comparison
+    while !meta.input.is_empty() && !meta.input.peek(Token![,]) {
+        meta.input.parse::<TokenTree>()?;
+    }
+    Ok(())
+}
@@ -1,7 +1,9 @@
-use proc_macro_error2::OptionExt;
-use proc_macro2::TokenStream;
-use quote::{ToTokens, quote};
-use syn::{Attribute, Ident, Path, Type, parse_quote};
+use darling::error::Accumulator;
+use darling::util::Override;
+use heck::AsSnakeCase;
+use proc_macro2::{Span, TokenStream};
+use quote::{ToTokens, format_ident, quote};
+use syn::{Attribute, Ident, LitStr, Path, Type, ext::IdentExt, parse_quote};
 use std::collections::HashMap;
 use std::rc::Rc;
 
@@ -18,24 +20,274 @@ use crate::{
     parse::{
         FieldResolver,
         NestHierarchy,
+        ParsedField,
         StructAttrResolver,
-        types::{DeriveItemOpts, NestOpts, StructClass},
+        serde_attrs::{SerdeContainerAttrs, SerdeFieldAttrs, is_option},
+        types::{DeriveItemOpts, GlobalCsvOpts, GlobalHttpOpts, IntegrityAlgo, NestEncode, NestOpts, NoneAs, RowRender, StructClass, TransformOptionsOpts, WrapperIntegrityOpts},
     },
+    util::{ExpandStage, cfg_attrs, expand_stage, expand_trace, span_error},
 };
 
 pub(crate) mod state;
 use state::State;
 
 pub(crate) mod structs;
-use structs::GenStructField;
+use structs::{GenAliasModule, GenEnum, GenStruct, GenStructField};
+
+mod trace;
 
 mod trait_impl;
 use trait_impl::{
     Fallibility,
-    GenToWrappedWith, GenTransformToDeepNest, GenTransformToNest, GenTransformToNestOptional
+    GenAnyTransform,
+    GenAssertOptionalNest,
+    GenAssertThreadsafe,
+    GenAssertTransform,
+    GenAudit,
+    GenBorrowData,
+    GenCsvColumn, GenCsvRecord,
+    GenDebugWrapper, GenDiagnosedNest,
+    GenDelegateAccessor, GenDelegateAccessors,
+    GenDeprecatedAccessor, GenDeprecatedAccessors,
+    GenDeserialize, GenDeserializeField,
+    GenFfiEnvelope,
+    GenGrpcMetadata,
+    GenHttpResponse,
+    GenIdentity,
+    GenJsonHelpers, GenJsonShape, GenNestKey, GenNestTree, GenShape, GenShapeKey,
+    GenOptionsBuilder,
+    GenRedactedDebug,
+    GenRemoteConversions,
+    GenRowView,
+    GenBatchField,
+    GenHoistedParent,
+    GenComputedField,
+    GenEnrich,
+    GenSerialize, GenSerializeField, GenSerializePromoted,
+    GenNestNode, GenTopology,
+    GenNestSources, GenToWrappedWith, GenTransformToAutoParent, GenTransformToDeepNest, GenTransformToNest, GenTransformToNestFromIdentity,
+    GenTransformToNestFromProvider,
+    GenTransformOptions,
+    GenTransformToNestFromSources,
+    GenTransformToNestOptional
 };
 
-pub(crate) fn generate(derive_opts: DeriveItemOpts, tokens: &mut TokenStream) {
+/// Writes all generated items to `tokens`.
+///
+/// Errors are accumulated wherever possible, items unaffected by an error are still written to `tokens`.
+pub(crate) fn generate(derive_opts: DeriveItemOpts, tokens: &mut TokenStream) -> darling::Result<()> {
+    let mut errors = darling::Error::accumulator();
+    let Some(state) = gen_state(derive_opts, &mut errors) else {
+        return errors.finish();
+    };
+
+    // stage 2 - models
+    //           construct primary generators
+    // store required trait values
+    let fallibility = match &state.global.fallible {
+        Some(opts) => Fallibility::Fallible { error_type: opts.error.clone() },
+        None => Fallibility::Infallible,
+    };
+    let accumulate_errors = state.global.fallible.as_ref().is_some_and(|opts| opts.accumulate_errors.is_present());
+    let enrich = state.wrapper_opts.enrich.is_present();
+    let borrow = state.wrapper_opts.borrow.is_present();
+    let transform_type = state.global.transform.clone();
+    let transform_bounds = state.global.transform_generic_params.clone();
+    let json = state.global.json.is_present();
+    let csv = state.global.csv();
+    let http = state.global.http();
+    let row_view = state.global.row_view();
+    let topology = state.global.topology.is_present();
+    let audit = state.global.audit.is_present().then(|| state.audit_fields());
+    let transform_alias = state.global.transform_alias.is_present();
+    let transforms = state.global.transforms.iter()
+        .filter_map(|path| path.segments.last().map(|segment| (segment.ident.clone(), path.clone())))
+        .collect::<Vec<_>>();
+    let ffi = state.global.ffi.is_present();
+    let grpc = state.global.grpc.is_present();
+    let remote = state.global.remote.clone();
+    let debug_wrapper = state.global.debug_wrapper.is_present();
+    let alias_only = state.global.docs.alias_only.is_present();
+    let assert_optional_impls = state.global.assert_optional_impls.is_present() && transform_bounds.is_none();
+    let assert_threadsafe = state.global.assert_threadsafe.is_present().then(|| state.global.assert_threadsafe.span());
+    let concrete_impls = state.global.concrete_impls.is_present();
+    let options_builder = state.global.options_builder.is_present().then(|| gen_options_builder(&state, &mut errors)).flatten();
+    if let Some(csv_nests) = csv.as_ref().and_then(|csv| csv.nests.as_ref()) {
+        let nest_ids = state.nest_hierarchy.all_nest_ids();
+        for id in csv_nests.iter().filter(|id| !nest_ids.contains(&id.value())) {
+            errors.push(darling::Error::custom(format!("Unknown nest ID `{}`", id.value())).with_span(id));
+        }
+    }
+    if state.global.naming.inherit_rename_all.is_present() && state.struct_attr_resolver.origin_serde.rename_all.is_none() {
+        errors.push(span_error(state.global.naming.inherit_rename_all.span(), "`inherit_rename_all` requires `#[serde(rename_all = \"..\")]` on the origin struct"));
+    }
+    let deserialize = state.wrapper_opts.deserialize();
+    let manual_serialize = state.wrapper_opts.manual_serialize();
+    let sort_keys = state.wrapper_opts.sort_keys();
+    let binary_safe = state.wrapper_opts.binary_safe();
+    let integrity = state.wrapper_opts.integrity.clone();
+    let manual_deserialize = state.wrapper_opts.manual_deserialize();
+    let deny_unknown_fields = state.wrapper_opts.deny_unknown_fields();
+    let id_key = state.field_resolver.origin_fields().into_iter()
+        .find(|field| field.id_key)
+        .map(|field| (field.name.clone(), field.ty.clone()));
+    // names of other generated methods on the primary wrapper, accessors cannot shadow them
+    let delegate_accessors = state.wrapper_opts.delegate_accessors.is_present().then(|| {
+        [
+            ("identity", id_key.is_some()),
+            ("to_owned_data", borrow),
+            ("enrich_with", enrich),
+            ("with_empty_extra", enrich),
+            ("matches_shape", json),
+            ("to_value_filtered", json),
+            ("csv_headers", csv.is_some()),
+            ("to_csv_record", csv.is_some()),
+            ("to_grpc_metadata", grpc),
+        ].into_iter().filter(|(_, present)| *present).map(|(name, _)| name).collect::<Vec<_>>()
+    });
+    // generate model tree
+    let models = gen_models(state);
+    expand_trace(|| trace::render_trace(&models), &models.origin.ident);
+
+    // stage 3 - codegen
+    //           run struct + trait gen from models
+    let mut assertions = TokenStream::default();
+    if transform_bounds.is_none() {
+        GenAssertTransform { transform_type: transform_type.clone() }.to_tokens(&mut assertions);
+    }
+    let mut structs = TokenStream::default();
+    gen_structs(&models, &mut structs);
+    if alias_only {
+        let module = format_ident!("__shrinkwrap_gen_{}", AsSnakeCase(models.origin.ident.to_string()).to_string());
+        match GenAliasModule::new(module, structs.clone()) {
+            Ok(alias_module) => structs = alias_module.into_token_stream(),
+            Err(err) => errors.push(err.into()),
+        }
+    }
+    let mut impls = TokenStream::default();
+    let transform_alias = transform_alias.then(|| format_ident!("{}Transforms", models.origin.ident));
+    gen_traits(&models, &fallibility, accumulate_errors, enrich, concrete_impls, debug_wrapper, transform_alias, &transform_type, &transform_bounds, &mut impls);
+    if manual_serialize {
+        gen_serialize(&models.origin_wrapper, sort_keys, binary_safe, integrity.as_ref(), &mut errors, &mut impls);
+    } else {
+        gen_serialize_aliases(&models.origin_wrapper, &mut impls);
+    }
+    if models.origin_wrapper.extra.fields.iter().any(|field| field.promote) {
+        gen_serialize_promoted(&models.origin_wrapper, &mut impls);
+    }
+    if manual_deserialize {
+        gen_deserialize(&models.origin_wrapper, sort_keys, deny_unknown_fields, &mut errors, &mut impls);
+    }
+    if json {
+        gen_json(&models, deserialize, &mut impls);
+    }
+    if let Some(csv) = csv {
+        gen_csv(&models, &csv, &mut impls);
+    }
+    if let Some(http) = http {
+        gen_http(&models, http, &mut impls);
+    }
+    if let Some(options_builder) = options_builder {
+        options_builder.to_tokens(&mut impls);
+    }
+    if let Some((key_name, key_type)) = id_key {
+        gen_identity(&models.origin_wrapper, key_name, key_type, &mut impls);
+    }
+    if borrow {
+        GenBorrowData {
+            ident: models.origin_wrapper.ident.clone(),
+            data_ident: models.origin.ident.clone(),
+            data_name: models.origin_wrapper.data_name.clone(),
+        }.to_tokens(&mut impls);
+    }
+    if ffi {
+        GenFfiEnvelope {
+            ident: models.origin_wrapper.ident.clone(),
+            ffi_ident: format_ident!("{}Ffi", models.origin_wrapper.ident),
+            data_ident: models.origin.ident.clone(),
+            data_name: models.origin_wrapper.data_name.clone(),
+            extra_ident: models.origin_wrapper.extra.ident.clone(),
+            extra_name: models.origin_wrapper.extra_name.clone(),
+        }.to_tokens(&mut impls);
+    }
+    if grpc {
+        GenGrpcMetadata {
+            ident: models.origin_wrapper.ident.clone(),
+            extra_ident: models.origin_wrapper.extra.ident.clone(),
+            extra_name: models.origin_wrapper.extra_name.clone(),
+        }.to_tokens(&mut impls);
+    }
+    if let Some(remote) = remote {
+        GenRemoteConversions {
+            variant: fallibility.clone().into(),
+            ident: models.origin.ident.clone(),
+            remote,
+            fields: models.origin.fields.iter().map(|field| field.name.clone()).collect(),
+        }.to_tokens(&mut impls);
+    }
+    if debug_wrapper {
+        GenDebugWrapper {
+            variant: fallibility.clone().into(),
+            ident: format_ident!("{}DebugWrapper", models.origin.ident),
+            wrapper_ident: models.origin_wrapper.ident.clone(),
+            data_ident: models.origin.ident.clone(),
+            extra_name: models.origin_wrapper.extra_name.clone(),
+            nests: models.origin_wrapper.extra.fields.iter()
+                .map(|field| GenDiagnosedNest {
+                    name: field.name.clone(),
+                    id: field.nest_id().to_string(),
+                    optional: field.optional,
+                })
+                .collect(),
+        }.to_tokens(&mut impls);
+    }
+    if let Some(reserved) = delegate_accessors {
+        gen_delegate_accessors(&models.origin_wrapper, &reserved, &mut errors, &mut impls);
+    }
+    if assert_optional_impls {
+        gen_assert_optional_nests(&models.origin_wrapper, &fallibility, &transform_type, &mut impls);
+    }
+    if let Some(span) = assert_threadsafe {
+        gen_assert_threadsafe(&models.origin_wrapper, span, &mut impls);
+    }
+    if topology {
+        gen_topology(&models, &mut impls);
+    }
+    if !transforms.is_empty() {
+        GenAnyTransform {
+            ident: format_ident!("{}AnyTransform", models.origin.ident),
+            transforms,
+            fallible: matches!(fallibility, Fallibility::Fallible { .. }),
+        }.to_tokens(&mut impls);
+    }
+    if let Some((mapped, unmapped)) = audit {
+        GenAudit { data_ident: models.origin.ident.clone(), mapped, unmapped }.to_tokens(&mut impls);
+    }
+    if let Some(row_view) = row_view {
+        gen_row_views(&models.origin_wrapper, row_view.render, &mut impls);
+    }
+    gen_deprecated_accessors(&models.origin_wrapper, &mut impls);
+    gen_redacted_debug(&models.origin_wrapper, &mut impls);
+
+    expand_stage(&structs, &models.origin.ident, ExpandStage::Structs);
+    expand_stage(&quote!(#assertions #impls), &models.origin.ident, ExpandStage::Impls);
+    tokens.extend([assertions, structs, impls]);
+
+    errors.finish()
+}
+
+/// Renders the `SHRINKWRAP_TRACE` output of the derive, in place of the generated items
+#[cfg(test)]
+pub(crate) fn generate_trace(derive_opts: DeriveItemOpts) -> darling::Result<String> {
+    let mut errors = darling::Error::accumulator();
+    let state = gen_state(derive_opts, &mut errors);
+    let state = errors.finish_with(state)?.expect("state is built when no errors are reported");
+    Ok(trace::render_trace(&gen_models(state)))
+}
+
+/// Builds the generation state from the derive options, `None` if it couldn't be built (with the errors pushed to
+/// `errors`)
+fn gen_state(derive_opts: DeriveItemOpts, errors: &mut Accumulator) -> Option<State> {
     // destructure input opts
     let DeriveItemOpts {
         ident: root_ident,
@@ -52,42 +304,45 @@ pub(crate) fn generate(derive_opts: DeriveItemOpts, tokens: &mut TokenStream) {
     //             - nest hierarchy
     //             - field resolver
     //             - struct attr resolver
+
     // build nest nest_hierarchy
-    let nest_hierarchy = NestHierarchy::from_nest_opts(nest_opts);
+    let nest_hierarchy = errors.handle(NestHierarchy::from_nest_opts(nest_opts));
 
     // build map of nest fields
-    let origin_fields = data.take_struct().expect_or_abort("couldnt get root fields").fields;
-    let field_resolver = FieldResolver::from_opt_fields(origin_fields);
-    field_resolver.validate_parent_field_propagation(&nest_hierarchy);
+    let Some(origin_fields) = data.take_struct() else {
+        errors.push(darling::Error::custom("Only named structs are supported"));
+        return None;
+    };
+    let field_resolver = errors.handle(FieldResolver::from_opt_fields(origin_fields.fields));
 
     // build struct attrs
-    let struct_attr_resolver = StructAttrResolver::from_attrs(attrs.iter().collect());
+    let struct_attr_resolver = errors.handle(StructAttrResolver::from_attrs(attrs.iter().collect()));
+
+    let (Some(nest_hierarchy), Some(field_resolver), Some(struct_attr_resolver)) = (nest_hierarchy, field_resolver, struct_attr_resolver) else {
+        return None;
+    };
+    errors.handle(field_resolver.validate_parent_field_propagation(&nest_hierarchy));
 
     // init state
-    let state = State::new(
+    Some(State::new(
         global_opts, wrapper_opts, extra_opts,
-        root_ident.clone(),
+        root_ident,
         nest_hierarchy,
         struct_attr_resolver,
         field_resolver,
-    );
-
-    // stage 2 - models
-    //           construct primary generators
-    // store required trait values
-    let fallibility = match &state.global.fallible {
-        Some(opts) => Fallibility::Fallible { error_type: opts.error.clone() },
-        None => Fallibility::Infallible,
-    };
-    let transform_type = state.global.transform.clone();
-    let transform_bounds = state.global.transform_generic_params.clone();
-    // generate model tree
-    let models = gen_models(state);
+    ))
+}
 
-    // stage 3 - codegen
-    //           run struct + trait gen from models
-    gen_structs(&models, tokens);
-    gen_traits(&models, &fallibility, &transform_type, &transform_bounds, tokens);
+/// Writes the items generated by `#[derive(TransformOptions)]` to `tokens`
+pub(crate) fn generate_transform_options(opts: TransformOptionsOpts, tokens: &mut TokenStream) -> darling::Result<()> {
+    let gates = opts.gates()?;
+    GenTransformOptions {
+        ident: opts.ident,
+        generics: opts.generics,
+        gates,
+        deprecated_gate: !opts.no_deprecated_gate.is_present(),
+    }.to_tokens(tokens);
+    Ok(())
 }
 
 // !- Models
@@ -97,15 +352,7 @@ fn gen_models(state: State) -> ModelTree {
     let mut deep_models = Vec::new();
     let children = state.nest_hierarchy.get_children(None);
     for root_child in children {
-        let root_child = root_child.as_str();
-        let root_child_opts = state.nest_hierarchy.get_nest_opts(root_child);
-        let child_extra_field_obj = gen_models_dfs(&state, root_child);
-        let child_extra_field = ExtraField {
-            name: root_child_opts.field_name(),
-            object: child_extra_field_obj,
-            optional: root_child_opts.optional() || state.global.all_optional.is_present(),
-        };
-        deep_models.push(child_extra_field);
+        deep_models.extend(gen_models_dfs(&state, root_child.as_str(), false));
     }
 
     let data = origin_data.clone().into();
@@ -114,40 +361,95 @@ fn gen_models(state: State) -> ModelTree {
     ModelTree::new(wrapper, origin_data)
 }
 
-fn gen_models_dfs(state: &State, nest_id: &str) -> ExtraChildVariant {
+/// Returns the fields of `nest_id` suitable for assignment within its parent's Extra, i.e. the nest itself followed
+/// by any children hoisted via `no_intermediate_wrapper`
+///
+/// `lift` is set for nests hoisted from an optional parent, non-optional nests are then lifted to `Option` (present
+/// whenever the parent is)
+fn gen_models_dfs(state: &State, nest_id: &str, lift: bool) -> Vec<ExtraField> {
+    let nest_opts = state.nest_hierarchy.get_nest_opts(nest_id);
+    let hoist = nest_opts.no_intermediate_wrapper.is_present();
+    let lifted = lift && !nest_opts.is_optional(state.global.all_optional.is_present());
+    let optional = lifted || nest_opts.is_optional(state.global.all_optional.is_present());
+
     let children = state.nest_hierarchy.get_children(Some(nest_id));
     let mut extra_children = Vec::new();
     // first generate any children
     for child in children {
-        let child = child.as_str();
-        // build child object suitable for assignment as a field in Extra
-        let child_opts = state.nest_hierarchy.get_nest_opts(child);
-        let child_extra_field_obj = gen_models_dfs(state, child);
-
-        // build the extra field and append to fields list
-        let child_extra_field = ExtraField {
-            name: child_opts.field_name(),
-            object: child_extra_field_obj,
-            optional: child_opts.optional() || state.global.all_optional.is_present(),
-        };
-        extra_children.push(child_extra_field);
+        extra_children.extend(gen_models_dfs(state, child.as_str(), hoist && optional));
     }
 
     // generate nest struct for current nest id / level
-    let nest_opts = state.nest_hierarchy.get_nest_opts(nest_id);
     let nest = Rc::new(gen_nest(state, nest_opts));
-    // no sub-nests, just return nest as extra child
-    if extra_children.is_empty() {
+    // no sub-nests (or hoisted sub-nests), just return nest as extra child
+    let object = if extra_children.is_empty() || hoist {
         ExtraChildVariant::Nest(nest)
     } else {
         // generate dedicated extra/wrappper type
         let data = DataVariant::Nest(nest);
-        let extra = Rc::new(gen_extra(state, extra_children, &data));
+        let extra = Rc::new(gen_extra(state, std::mem::take(&mut extra_children), &data));
         let wrapper = Rc::new(gen_wrapper(state, data, extra));
         ExtraChildVariant::Wrapper(wrapper)
+    };
+
+    // build the extra field, followed by any hoisted children which are built from it
+    let name = nest_opts.field_name();
+    for hoisted in &mut extra_children {
+        hoisted.from_parent.get_or_insert_with(|| name.clone());
+    }
+    let encode = nest_opts.encode.as_deref().copied();
+    let extra_field = ExtraField {
+        name,
+        object,
+        optional,
+        needs: state.nest_sibling_field_name(nest_opts),
+        promote: nest_opts.promote.is_present(),
+        from_parent: None,
+        lifted,
+        superseded_by: state.nest_superseded_by(nest_opts).map(NestOpts::field_name),
+        supersedes: nest_opts.deprecates.as_ref().map(|deprecated_id| state.nest_hierarchy.get_nest_opts(deprecated_id.as_str()).field_name()),
+        gated: state.nest_superseded_by(nest_opts).is_some_and(|superseding| superseding.gate_deprecated.is_present()),
+        encode,
+        alias: nest_opts.alias.as_ref().map(|alias| alias.as_str().to_string()),
+        emit_alias: nest_opts.emit_alias.is_present(),
+        attrs: encode.map(|encode| nest_encode_attrs(encode, optional, state.global.schema())).into_iter()
+            .chain(nest_opts.alias.as_ref().map(|alias| nest_alias_attrs(alias.as_str(), state.global.schema())))
+            .flatten()
+            .chain((nest_opts.schema_skip.is_present() && state.global.schema()).then(|| parse_quote!(#[schemars(skip)])))
+            .collect(),
+    };
+    let mut fields = vec![extra_field];
+    fields.extend(extra_children);
+    fields
+}
+
+/// Path of the serde `with` module of an extra field using `encode`, along with the type of the encoded value
+fn nest_encode_with(encode: NestEncode, optional: bool) -> (&'static str, &'static str) {
+    match (encode, optional) {
+        (NestEncode::JsonString, false) => ("::shrinkwrap::__private::json_string", "String"),
+        (NestEncode::JsonString, true) => ("::shrinkwrap::__private::json_string_option", "Option<String>"),
     }
 }
 
+/// Serde attrs of an extra field using `encode`, the schema describes the encoded value
+fn nest_encode_attrs(encode: NestEncode, optional: bool, schema: bool) -> Vec<Attribute> {
+    let (with, schema_type) = nest_encode_with(encode, optional);
+    let mut attrs = vec![parse_quote!(#[serde(with = #with)])];
+    if schema {
+        attrs.push(parse_quote!(#[schemars(with = #schema_type)]));
+    }
+    attrs
+}
+
+/// Attrs of an extra field using `alias`, accepting the previous key when deserializing
+fn nest_alias_attrs(alias: &str, schema: bool) -> Vec<Attribute> {
+    let mut attrs = vec![parse_quote!(#[serde(alias = #alias)])];
+    if schema {
+        attrs.push(parse_quote!(#[schemars(extend("x-aliases" = [#alias]))]));
+    }
+    attrs
+}
+
 // !- Output structs
 
 fn gen_structs(models: &ModelTree, tokens: &mut TokenStream) {
@@ -161,19 +463,53 @@ fn gen_origin_data(state: &State) -> OriginData {
     OriginData {
         ident: state.root_ident.clone(),
         fields,
+        serde: state.struct_attr_resolver.origin_serde.clone(),
     }
 }
 
 // fixme: drop state, pass in wrapper_opts
 fn gen_wrapper(state: &State, data: DataVariant, extra: Rc<Extra>) -> Wrapper {
+    let mut derives = state.wrapper_derives();
+    state.apply_no_debug(&mut derives, &state.wrapper_opts.no_debug);
+    // `Serialize` is implemented by `trait_impl::GenSerializePromoted` instead
+    if extra.fields.iter().any(|field| field.promote) {
+        let serialize: Path = parse_quote!(::serde::Serialize);
+        derives.retain(|derive| derive != &serialize);
+    }
+    let mut extra_attrs = state.struct_attr_resolver.resolve(data.nest_id(), StructClass::ExtraField);
+    if state.wrapper_opts.default_extras() && extra.all_optional() {
+        extra_attrs.push(parse_quote!(#[serde(default)]));
+    }
+    let ident = state.wrapper_opts.struct_name(data.ident());
+    let mut attrs = state.full_struct_attrs(&ident, data.nest_id(), StructClass::Wrapper, &state.wrapper_opts.cfg_derive, &state.wrapper_opts.annotate, state.wrapper_opts.serde_bound.as_ref());
+    // if flatten is enabled, add #[serde(flatten)] to data field
+    let mut data_attrs: Vec<Attribute> = match state.wrapper_opts.flatten() {
+        true => vec![parse_quote!(#[serde(flatten)])],
+        false => Vec::new(),
+    };
+    data_attrs.extend(state.struct_attr_resolver.resolve(data.nest_id(), StructClass::DataField));
+    if data.is_origin() && state.global.audit.is_present() {
+        attrs.extend(audit_doc_attrs(state));
+    }
+    state.apply_schema_opts(data.nest_id(), &mut derives, [&mut attrs, &mut data_attrs, &mut extra_attrs]);
     Wrapper {
-        ident: state.wrapper_opts.struct_name(data.ident()),
-        derives: state.full_derives(state.wrapper_opts.derive.clone()).into(),
-        attrs: state.full_struct_attrs(data.nest_id(), StructClass::Wrapper),
+        ident,
+        derives: derives.into(),
+        attrs,
         doc: state.wrapper_opts.struct_doc.clone().into(),
         data_name: state.wrapper_opts.data_field_name.clone(),
         data_doc: state.wrapper_opts.data_field_doc.clone().into(),
+        data_attrs,
         data_flatten: state.wrapper_opts.flatten(),
+        extra_attrs,
+        computed: match data.is_origin() {
+            true => state.wrapper_opts.computed.clone(),
+            false => Vec::new(),
+        },
+        echo_options: state.wrapper_opts.echo_options_field().filter(|_| data.is_origin()).map(|field| {
+            let transform = &state.global.transform;
+            (field, parse_quote!(<<#transform as ::shrinkwrap::__private::Transform2>::Options<'static> as ::shrinkwrap::__private::SerializableOptions>::Echo))
+        }),
         data,
         extra_name: state.wrapper_opts.extra_field_name.clone(),
         extra_doc: state.wrapper_opts.extra_field_doc.clone().into(),
@@ -182,13 +518,51 @@ fn gen_wrapper(state: &State, data: DataVariant, extra: Rc<Extra>) -> Wrapper {
 }
 
 // fixme: drop state, pass in extra_opts
-fn gen_extra(state: &State, fields: Vec<ExtraField>, data: &DataVariant) -> Extra {
+fn gen_extra(state: &State, mut fields: Vec<ExtraField>, data: &DataVariant) -> Extra {
+    // fields follow the nest declaration order (unless overridden via `order`), regardless of nesting depth
+    fields.sort_by_key(|field| state.nest_hierarchy.get_field_order(field.nest_id()));
+    let default_optional = state.wrapper_opts.default_extras();
+    let mut derives = state.full_derives(state.extra_opts.derive.clone());
+    state.apply_no_debug(&mut derives, &state.extra_opts.no_debug);
+    // `Serialize` is implemented by `gen_serialize_extra` instead
+    if fields.iter().any(|field| field.emit_alias) {
+        let serialize: Path = parse_quote!(::serde::Serialize);
+        derives.retain(|derive| derive != &serialize);
+    }
+    // missing extras are defaulted when all nests are optional
+    let has_default = derives.iter().any(|derive| derive.segments.last().is_some_and(|segment| segment.ident == "Default"));
+    if default_optional && fields.iter().all(|field| field.optional) && !has_default {
+        derives.push(parse_quote!(::std::default::Default));
+    }
+    let ident = state.extra_opts.struct_name(data.ident());
+    let mut attrs = state.full_struct_attrs(&ident, data.nest_id(), StructClass::Extra, &state.extra_opts.cfg_derive, &state.extra_opts.annotate, state.extra_opts.serde_bound.as_ref());
+    if state.global.schema() {
+        gen_deprecation_schema_attrs(&mut fields, &attrs);
+    }
+    state.apply_schema_opts(data.nest_id(), &mut derives, std::iter::once(&mut attrs).chain(fields.iter_mut().map(|field| &mut field.attrs)));
     Extra {
-        ident: state.extra_opts.struct_name(data.ident()),
-        derives: state.full_derives(state.extra_opts.derive.clone()).into(),
-        attrs: state.full_struct_attrs(data.nest_id(), StructClass::Extra),
+        ident,
+        derives: derives.into(),
+        attrs,
         doc: state.extra_opts.struct_doc.clone().into(),
         fields,
+        exclusive: data.is_origin().then(|| state.extra_opts.exclusive()).flatten(),
+        tagged_array: data.is_origin().then(|| state.extra_opts.tagged_array()).flatten(),
+        default_optional,
+    }
+}
+
+/// Documents the nests superseded via `deprecates` within the extra's schema, referencing the serialized field names
+fn gen_deprecation_schema_attrs(fields: &mut [ExtraField], extra_attrs: &[Attribute]) {
+    let container = SerdeContainerAttrs::from_attrs(extra_attrs);
+    let key = |name: &Ident| SerdeFieldAttrs::default().serialized_name(name.to_string().as_str(), &container);
+    for field in fields.iter_mut() {
+        if let Some(superseded_by) = field.superseded_by.as_ref().map(key) {
+            field.attrs.push(parse_quote!(#[schemars(extend("deprecated" = true, "x-superseded-by" = #superseded_by))]));
+        }
+        if let Some(supersedes) = field.supersedes.as_ref().map(key) {
+            field.attrs.push(parse_quote!(#[schemars(extend("x-supersedes" = #supersedes))]));
+        }
     }
 }
 
@@ -196,7 +570,13 @@ fn gen_extra(state: &State, fields: Vec<ExtraField>, data: &DataVariant) -> Extr
 fn gen_nest(state: &State, nest_opts: &NestOpts) -> NestData {
     let nest_id_str = nest_opts.id_str();
     let source_ident = state.nest_source_ident(nest_id_str);
-    let optional = state.global.all_optional.is_present() || nest_opts.optional();
+    let optional = nest_opts.is_optional(state.global.all_optional.is_present());
+
+    let mut derives = state.full_derives(nest_opts.derive.clone());
+    state.apply_no_debug(&mut derives, &nest_opts.no_debug);
+    if nest_opts.validate.is_present() {
+        derives.push(parse_quote!(::shrinkwrap::__private::validator::Validate));
+    }
 
     let derive_to_nest = nest_opts.derive_to_nest.is_some().then(||
         NestAutoDeriveToNest {
@@ -204,69 +584,194 @@ fn gen_nest(state: &State, nest_opts: &NestOpts) -> NestData {
             options_field_if_optional: optional.then(|| nest_opts.derive_to_nest_options_field_name()).flatten(),
         }
     );
+    let ident = nest_opts.struct_name(source_ident);
+    let mut attrs = state.full_struct_attrs(&ident, Some(nest_id_str), StructClass::Nest, &nest_opts.cfg_derive, &nest_opts.annotate, nest_opts.serde_bound.as_ref());
+    if nest_opts.validate.is_present() {
+        attrs.push(parse_quote!(#[validate(crate = "::shrinkwrap::__private::validator")]));
+    }
+    let mut fields = gen_nest_fields(state, nest_opts);
+    state.apply_schema_opts(Some(nest_id_str), &mut derives, std::iter::once(&mut attrs).chain(fields.iter_mut().map(|field| &mut field.attrs)));
     NestData {
         id: nest_id_str.to_string(),
-        ident: nest_opts.struct_name(source_ident),
-        derives: state.full_derives(nest_opts.derive.clone()).into(),
-        attrs: state.full_struct_attrs(Some(nest_id_str), StructClass::Nest),
+        id_span: nest_opts.id.span(),
+        ident,
+        derives: derives.into(),
+        attrs,
         doc: nest_opts.struct_doc.clone().into(),
-        fields: gen_nest_fields(state, nest_opts),
+        fields,
         derive_to_nest,
+        sources: nest_opts.sources.is_present(),
+        provider: nest_opts.provider.clone(),
+        map: nest_opts.map_type(),
+        identity: nest_opts.identity.is_present(),
+        batch: nest_opts.batch.is_present(),
+        auto_parent: nest_opts.auto_parent.is_present(),
+        source_ident: state.nest_data_source_ident(nest_opts).clone(),
     }
 }
 
 fn gen_nest_fields(state: &State, nest_opts: &NestOpts) -> Vec<NestDataField> {
+    // provider and map nests never contain fields (validated prior)
+    if nest_opts.provider.is_some() || nest_opts.map_type().is_some() {
+        return Vec::new();
+    }
     let nest_id_str = nest_opts.id_str();
     let filtered_origin_fields = state.field_resolver.nest_fields(nest_id_str);
-    let default_field_type = nest_opts.resolve_field_type();
 
     let mut out = Vec::new();
     for field in filtered_origin_fields {
-        let field_type = state.field_resolver.nest_field_type_override(nest_id_str.to_string(), field.name.clone()).unwrap_or(default_field_type);
-        let parent_nest_field_type: Option<Type> = nest_opts.chain_from.as_ref().map(|parent_id| {
-            let path = state.field_resolver.nest_field_type_override(parent_id.to_string(), field.name.clone()).unwrap_or_else(|| {
-               // fallback to default
-               state.nest_hierarchy.get_nest_opts(parent_id.to_string().as_str()).resolve_field_type()
-            });
+        let field_type = nest_field_type(state, nest_opts, field);
+        let source_type = nest_source_field_type(state, nest_opts, field).unwrap_or_else(|| field.ty.clone());
+        let none_as = state.field_resolver.nest_field_none_as(nest_id_str.to_string(), field.name.clone());
+        // `Option<T>` iterates over its value, optional fields are built as a single element
+        let element_types = (none_as.is_some() || state.field_resolver.nest_field_maps_elements(nest_id_str.to_string(), field.name.clone()))
+            .then(|| (
+                nest_field_element_type(state, nest_opts, field),
+                parse_quote!(<#source_type as ::std::iter::IntoIterator>::Item),
+            ));
 
-            parse_quote! { #path }
-        });
-
-        let attrs = state.field_resolver.attrs(nest_id_str, &field.name);
+        let mut attrs = field.cfg.clone();
+        attrs.extend(state.field_resolver.attrs(nest_id_str, &field.name));
+        if let Some(doc) = state.field_resolver.nest_field_doc(nest_id_str.to_string(), field.name.clone()) {
+            attrs.insert(0, parse_quote!(#[doc = #doc]));
+        }
+        if let Some(validate) = state.field_resolver.nest_field_validation(nest_id_str.to_string(), field.name.clone()) {
+            attrs.push(validate.to_attr());
+        }
+        let skip_empty = state.field_resolver.nest_field_skip_empty_override(nest_id_str.to_string(), field.name.clone())
+            .or(nest_opts.skip_empty.as_ref())
+            .or((none_as == Some(NoneAs::Skip)).then_some(&Override::Inherit));
+        if let Some(skip_empty) = skip_empty {
+            attrs.extend(skip_empty_attrs(skip_empty, &field_type, state.wrapper_opts.deserialize()));
+        }
         out.push(NestDataField {
             name: field.name.clone(),
-            ty: field_type.clone(),
-            source_type: parent_nest_field_type.clone().unwrap_or_else(|| field.ty.clone()),
+            ty: field_type,
+            source_type,
+            element_types,
+            none_as,
             attrs,
         });
     }
+    // selected fields are only supported for nests with a `field_type` (validated prior), whose fields are
+    // never built from their source type, the source type is therefore unknown and left as the field type
+    for selector in &nest_opts.fields {
+        let field_type = nest_opts.resolve_field_type().clone();
+        out.push(NestDataField {
+            name: selector.name.clone(),
+            attrs: nest_opts.skip_empty.as_ref()
+                .map(|skip_empty| skip_empty_attrs(skip_empty, &field_type, state.wrapper_opts.deserialize()))
+                .unwrap_or_default(),
+            ty: field_type.clone(),
+            source_type: field_type,
+            element_types: None,
+            none_as: None,
+        });
+    }
     out
 }
 
+/// Serde attrs of a nest field using `skip_empty`, `default` is only required when deserializing
+fn skip_empty_attrs(skip_empty: &Override<Path>, ty: &Type, deserialize: bool) -> Vec<Attribute> {
+    let predicate = match skip_empty {
+        Override::Explicit(path) => quote!(#path).to_string().replace(' ', ""),
+        Override::Inherit => skip_empty_default_predicate(ty),
+    };
+    let predicate = LitStr::new(&predicate, Span::call_site());
+    let mut attrs = vec![parse_quote!(#[serde(skip_serializing_if = #predicate)])];
+    if deserialize {
+        attrs.push(parse_quote!(#[serde(default)]));
+    }
+    attrs
+}
+
+/// `Option::is_none` for options, otherwise the type's `is_empty`, e.g. `Vec::is_empty` for `Vec<T>`
+fn skip_empty_default_predicate(ty: &Type) -> String {
+    match ty {
+        Type::Path(type_path) if type_path.qself.is_none() => {
+            let mut path = type_path.path.clone();
+            let last = path.segments.last_mut().expect("type paths contain at least a single segment");
+            let method = if last.ident == "Option" { "is_none" } else { "is_empty" };
+            last.arguments = syn::PathArguments::None;
+            format!("{}::{method}", quote!(#path).to_string().replace(' ', ""))
+        },
+        _ => format!("<{}>::is_empty", quote!(#ty)),
+    }
+}
+
+/// Resolves the type of `field` within the data `nest_opts` is built from, `None` for the origin
+fn nest_source_field_type(state: &State, nest_opts: &NestOpts, field: &ParsedField) -> Option<Type> {
+    let parent_opts = state.nest_hierarchy.get_nest_opts(nest_opts.chain_from.as_ref()?.as_str());
+    match parent_opts.auto_parent.is_present() {
+        true => nest_source_field_type(state, parent_opts, field),
+        false => Some(nest_field_type(state, parent_opts, field)),
+    }
+}
+
+/// Resolves the type of `field` within a nest, identity nests use the type of the field within their source
+fn nest_field_type(state: &State, nest_opts: &NestOpts, field: &ParsedField) -> Type {
+    if nest_opts.identity.is_present() {
+        return nest_source_field_type(state, nest_opts, field).unwrap_or_else(|| field.ty.clone());
+    }
+    let ty = nest_field_element_type(state, nest_opts, field);
+    if state.field_resolver.nest_field_none_as(nest_opts.id_str().to_string(), field.name.clone()).is_some_and(NoneAs::is_optional) {
+        return parse_quote!(::std::option::Option<#ty>);
+    }
+    match state.field_resolver.nest_field_maps_elements(nest_opts.id_str().to_string(), field.name.clone()) {
+        true => parse_quote!(::std::vec::Vec<#ty>),
+        false => ty,
+    }
+}
+
+/// Resolves the type of `field` within a nest, or of its elements for fields mapped element-wise
+fn nest_field_element_type(state: &State, nest_opts: &NestOpts, field: &ParsedField) -> Type {
+    state.field_resolver.nest_field_type_override(nest_opts.id_str().to_string(), field.name.clone())
+        // fallback to default
+        .unwrap_or_else(|| nest_opts.resolve_field_type())
+        .clone()
+}
+
 // !- Output trait impls
 
 /// Recurse through models, calling trait genarators as seen fit
+#[allow(clippy::too_many_arguments)]
 fn gen_traits(
     models: &ModelTree,
     fallibility: &Fallibility,
+    accumulate_errors: bool,
+    enrich: bool,
+    concrete: bool,
+    instrument: bool,
+    transform_alias: Option<Ident>,
     transform: &Path,
     transform_bounds: &Option<TokenStream>,
     tokens: &mut TokenStream,
 ) {
-    gen_to_wrapped_with(models.origin_wrapper.clone(), fallibility, transform, transform_bounds, tokens);
-    gen_transform_to_deep_nest(models.origin_wrapper.clone(), None, false, fallibility, transform, transform_bounds, tokens);
+    gen_to_wrapped_with(models.origin_wrapper.clone(), fallibility, accumulate_errors, enrich, true, concrete, instrument, transform_alias, transform, transform_bounds, tokens);
+    gen_transform_to_deep_nest(models.origin_wrapper.clone(), None, fallibility, transform, transform_bounds, tokens);
     gen_transform_to_nest(models.origin_wrapper.clone(), fallibility, transform, transform_bounds, tokens);
 }
 
 /// Recursively generate to wrapped with impls for the assiciated data struct and for any of the wrapper supported children
+///
+/// `enrich`, `wrap_all`, `instrument` and `transform_alias` only apply to `wrapper` itself, not its children
+#[allow(clippy::too_many_arguments)]
 fn gen_to_wrapped_with(
     wrapper: Rc<Wrapper>,
     fallibility: &Fallibility,
+    accumulate_errors: bool,
+    enrich: bool,
+    wrap_all: bool,
+    concrete: bool,
+    instrument: bool,
+    transform_alias: Option<Ident>,
     transform: &Path,
     transform_bounds: &Option<TokenStream>,
     tokens: &mut TokenStream,
 ) {
-    let to_wrapped_with = GenToWrappedWith {
+    // auto parents are built via `GenTransformToAutoParent` instead
+    let auto_parent = matches!(&wrapper.data, DataVariant::Nest(nest_data) if nest_data.auto_parent);
+    let to_wrapped_with = (!auto_parent).then(|| GenToWrappedWith {
         variant: fallibility.clone().into(),
         transform_type: transform.clone(),
         transform_generic_bounds: transform_bounds.clone(),
@@ -274,42 +779,140 @@ fn gen_to_wrapped_with(
         wrapper_ident: wrapper.ident.clone(),
         extra_struct_ident: wrapper.extra.ident.clone(),
         extra_struct_fields: wrapper.extra.fields.iter().map(GenStructField::from).collect(),
-    };
+        extra_struct_field_needs: wrapper.extra.fields.iter()
+            .filter_map(|field| field.needs.clone().map(|sibling| (field.name.clone(), sibling)))
+            .collect(),
+        extra_struct_field_parents: gen_hoisted_parents(&wrapper.extra),
+        extra_struct_field_gates: wrapper.extra.fields.iter()
+            .filter(|field| field.gated)
+            .map(|field| (field.name.clone(), field.nest_id().to_string()))
+            .collect(),
+        extra_struct_field_timings: wrapper.extra.fields.iter()
+            .filter(|_| instrument)
+            .map(|field| (field.name.clone(), field.nest_id().to_string()))
+            .collect(),
+        accumulate_errors,
+        extra_struct_optional_fields: wrapper.extra.fields.iter()
+            .filter(|field| field.optional)
+            .map(|field| field.name.clone())
+            .collect(),
+        enrich: enrich.then(|| GenEnrich {
+            data_name: wrapper.data_name.clone(),
+            extra_name: wrapper.extra_name.clone(),
+        }),
+        wrap_all: wrap_all.then(|| gen_batch_fields(&wrapper)),
+        exclusive: wrapper.extra.exclusive.is_some()
+            .then(|| wrapper.extra.fields.iter().map(ExtraField::variant_name).collect()),
+        tagged_array: wrapper.extra.tagged_array.is_some()
+            .then(|| (wrapper.extra.item_ident(), wrapper.extra.fields.iter().map(ExtraField::variant_name).collect())),
+        computed: wrapper.computed.iter()
+            .map(|computed| GenComputedField { name: computed.field.clone(), with: computed.with.clone() })
+            .collect(),
+        echo_options: wrapper.echo_options.clone(),
+        transform_alias,
+        concrete,
+    });
     to_wrapped_with.to_tokens(tokens);
 
     for extra_field in &wrapper.extra.fields {
         if let ExtraChildVariant::Wrapper(child_wrapper) = &extra_field.object {
-            gen_to_wrapped_with(child_wrapper.clone(), fallibility, transform, transform_bounds, tokens);
+            gen_to_wrapped_with(child_wrapper.clone(), fallibility, accumulate_errors, false, false, concrete, false, None, transform, transform_bounds, tokens);
         }
     }
 }
 
+/// Collects the parents of the `extra` fields hoisted via `no_intermediate_wrapper`
+fn gen_hoisted_parents(extra: &Extra) -> HashMap<Ident, GenHoistedParent> {
+    extra.fields.iter().filter_map(|extra_field| {
+        let parent_name = extra_field.from_parent.as_ref()?;
+        let parent = extra.fields.iter().find(|field| &field.name == parent_name)?;
+        let hoisted = GenHoistedParent {
+            name: parent_name.clone(),
+            nest_ident: parent.object.ident().clone(),
+            optional: parent.optional,
+            lifted_ident: extra_field.lifted.then(|| extra_field.object.ident().clone()),
+        };
+        Some((extra_field.name.clone(), hoisted))
+    }).collect()
+}
+
+/// Collects the `extra` fields of `wrapper` whose nests use `batch`
+fn gen_batch_fields(wrapper: &Wrapper) -> Vec<GenBatchField> {
+    wrapper.extra.fields.iter().filter_map(|extra_field| {
+        let (nest_data, nest_wrapper) = match &extra_field.object {
+            ExtraChildVariant::Nest(nest_data) => (nest_data, None),
+            ExtraChildVariant::Wrapper(nest_wrapper) => match &nest_wrapper.data {
+                DataVariant::Nest(nest_data) => (nest_data, Some(nest_wrapper.ident.clone())),
+                DataVariant::Origin(..) => unreachable!("nested wrappers always wrap nest data"),
+            },
+        };
+        nest_data.batch.then(|| {
+            let nest_ident = &nest_data.ident;
+            GenBatchField {
+                name: extra_field.name.clone(),
+                nest_type: match extra_field.optional {
+                    true => parse_quote!(Option<#nest_ident>),
+                    false => parse_quote!(#nest_ident),
+                },
+                wrapper: nest_wrapper.map(|wrapper_ident| (nest_ident.clone(), wrapper_ident)),
+                optional: extra_field.optional,
+            }
+        })
+    }).collect()
+}
+
 /// Recursively generate transform to nest impls from source data to nested wrapper
+///
+/// `parent` provides the parent wrapper along with the extra field holding `wrapper`, None for the origin wrapper
 fn gen_transform_to_deep_nest(
     wrapper: Rc<Wrapper>,
-    wrapper_origin: Option<Ident>,
-    optional: bool,
+    parent: Option<(&Wrapper, &ExtraField)>,
     fallibility: &Fallibility,
     transform: &Path,
     transform_bounds: &Option<TokenStream>,
     tokens: &mut TokenStream,
 ) {
-    if let Some(source_ident) = wrapper_origin {
-        // implement whenever a child wrapper is discovered
-        let transform_to_deep_nest = GenTransformToDeepNest {
-            variant: fallibility.clone().into(),
-            transform_type: transform.clone(),
-            transform_generic_bounds: transform_bounds.clone(),
-            data_ident: source_ident,
-            nest_wrapper_ident: wrapper.ident.clone(),
-            nest_ident: wrapper.data.ident().clone(),
-            optional,
+    if let Some((parent_wrapper, extra_field)) = parent {
+        let DataVariant::Nest(nest_data) = &wrapper.data else {
+            unreachable!("nested wrappers always wrap nest data");
         };
-        transform_to_deep_nest.to_tokens(tokens);
+        // auto parents are built from their children rather than their nest struct
+        if nest_data.auto_parent {
+            GenTransformToAutoParent {
+                variant: fallibility.clone().into(),
+                transform_type: transform.clone(),
+                transform_generic_bounds: transform_bounds.clone(),
+                data_ident: nest_data.source_ident.clone(),
+                nest_wrapper_ident: wrapper.ident.clone(),
+                nest_ident: nest_data.ident.clone(),
+                extra_ident: wrapper.extra.ident.clone(),
+                data_name: wrapper.data_name.clone(),
+                extra_name: wrapper.extra_name.clone(),
+                children: wrapper.extra.fields.iter().map(GenStructField::from).collect(),
+                optional_children: wrapper.extra.fields.iter().filter(|field| field.optional).map(|field| field.name.clone()).collect(),
+                optional: extra_field.transform_optional(),
+            }.to_tokens(tokens);
+        } else {
+            let sibling_type = extra_field.needs.as_ref().and_then(|sibling_name| {
+                parent_wrapper.extra.fields.iter().find(|field| &field.name == sibling_name).map(ExtraField::ty)
+            });
+            // implement whenever a child wrapper is discovered
+            let transform_to_deep_nest = GenTransformToDeepNest {
+                variant: fallibility.clone().into(),
+                transform_type: transform.clone(),
+                transform_generic_bounds: transform_bounds.clone(),
+                data_ident: nest_data.source_ident.clone(),
+                nest_wrapper_ident: wrapper.ident.clone(),
+                nest_ident: nest_data.ident.clone(),
+                optional: extra_field.transform_optional(),
+                sibling_type,
+            };
+            transform_to_deep_nest.to_tokens(tokens);
+        }
     }
     for extra_field in &wrapper.extra.fields {
         if let ExtraChildVariant::Wrapper(child_wrapper) = &extra_field.object {
-            gen_transform_to_deep_nest(child_wrapper.clone(), Some(wrapper.data.ident().clone()), extra_field.optional, fallibility, transform, transform_bounds, tokens);
+            gen_transform_to_deep_nest(child_wrapper.clone(), Some((&wrapper, extra_field)), fallibility, transform, transform_bounds, tokens);
         }
     }
 }
@@ -322,8 +925,6 @@ fn gen_transform_to_nest(
     transform_bounds: &Option<TokenStream>,
     tokens: &mut TokenStream,
 ) {
-    let source_ident = wrapper.data.ident();
-
     // generate for data -> extra.[*]
     for extra_field in &wrapper.extra.fields {
         let nest_data = match extra_field.object.clone() {
@@ -336,7 +937,11 @@ fn gen_transform_to_nest(
             }
         };
 
-        gen_transform_to_nest_node(nest_data.clone(), source_ident, fallibility, transform, transform_bounds, tokens);
+        let source_ident = &nest_data.source_ident;
+        gen_transform_to_nest_node(nest_data.clone(), source_ident, extra_field.transform_optional(), fallibility, transform, transform_bounds, tokens);
+        if nest_data.sources {
+            gen_transform_from_sources_node(&nest_data, source_ident, extra_field.transform_optional(), fallibility, transform, transform_bounds, tokens);
+        }
 
         // recurse through all nested wrappers
         if let ExtraChildVariant::Wrapper(nest_wrapper) = extra_field.object.clone() {
@@ -348,11 +953,36 @@ fn gen_transform_to_nest(
 fn gen_transform_to_nest_node(
     nest_data: Rc<NestData>,
     source_ident: &Ident,
+    optional: bool,
     fallibility: &Fallibility,
     transform: &Path,
     transform_bounds: &Option<TokenStream>,
     tokens: &mut TokenStream,
 ) {
+    if let Some(provider) = nest_data.provider.as_ref() {
+        let transform_to_nest = GenTransformToNestFromProvider {
+            variant: fallibility.clone().into(),
+            transform_type: transform.clone(),
+            transform_generic_bounds: transform_bounds.clone(),
+            data_ident: source_ident.clone(),
+            provider: provider.clone(),
+            nest_struct_ident: nest_data.ident.clone(),
+            optional,
+        };
+        transform_to_nest.to_tokens(tokens);
+    }
+    if nest_data.identity {
+        let transform_to_nest = GenTransformToNestFromIdentity {
+            variant: fallibility.clone().into(),
+            transform_type: transform.clone(),
+            transform_generic_bounds: transform_bounds.clone(),
+            data_ident: source_ident.clone(),
+            nest_struct_ident: nest_data.ident.clone(),
+            fields: nest_data.fields.iter().map(|field| (field.name.clone(), cfg_attrs(&field.attrs))).collect(),
+            optional,
+        };
+        transform_to_nest.to_tokens(tokens);
+    }
     if let Some(derive_to_nest) = nest_data.derive_to_nest.as_ref() {
         let transform_to_nest = GenTransformToNest {
             variant: fallibility.clone().into(),
@@ -361,9 +991,793 @@ fn gen_transform_to_nest_node(
             data_ident: source_ident.clone(),
             nest_fields: nest_data.fields.iter().map(|f| f.into()).collect(),
             field_source_type_pairings: nest_data.nest_source_type_pairings(),
+            mapped_fields: nest_data.fields.iter().filter(|f| f.element_types.is_some() && f.none_as.is_none()).map(|f| f.name.clone()).collect(),
+            none_as_fields: nest_data.fields.iter().filter_map(|f| Some((f.name.clone(), f.none_as?))).collect(),
             nest_struct_ident: nest_data.ident.clone(),
             optional: derive_to_nest.options_field_if_optional.clone().map(|options_field_name | GenTransformToNestOptional { options_field_name }),
         };
         transform_to_nest.to_tokens(tokens);
     }
 }
+
+/// Generate the source view struct and the delegating transform to nest impl for a nest with `sources` enabled
+fn gen_transform_from_sources_node(
+    nest_data: &NestData,
+    source_ident: &Ident,
+    optional: bool,
+    fallibility: &Fallibility,
+    transform: &Path,
+    transform_bounds: &Option<TokenStream>,
+    tokens: &mut TokenStream,
+) {
+    let sources_ident = format_ident!("{}Sources", nest_data.ident);
+    GenNestSources {
+        ident: sources_ident.clone(),
+        data_ident: source_ident.clone(),
+        fields: nest_data.fields.iter().map(|field| (field.name.clone(), field.source_type.clone(), cfg_attrs(&field.attrs))).collect(),
+    }.to_tokens(tokens);
+
+    let nest_ident = &nest_data.ident;
+    let nest_full_type: Type = match optional {
+        true => parse_quote!(Option<#nest_ident>),
+        false => parse_quote!(#nest_ident),
+    };
+    GenTransformToNestFromSources {
+        variant: fallibility.clone().into(),
+        transform_type: transform.clone(),
+        transform_generic_bounds: transform_bounds.clone(),
+        data_ident: source_ident.clone(),
+        sources_ident,
+        nest_full_type,
+    }.to_tokens(tokens);
+}
+
+// !- Redacted debug
+
+/// Recursively generates redacted `Debug` impls for structs without a `Debug` derive (see `no_debug`)
+fn gen_redacted_debug(wrapper: &Wrapper, tokens: &mut TokenStream) {
+    if !wrapper.derives.has_debug() {
+        GenRedactedDebug::from(GenStruct::from(wrapper)).to_tokens(tokens);
+    }
+    if let DataVariant::Nest(nest) = &wrapper.data {
+        gen_redacted_debug_nest(nest, tokens);
+    }
+    let extra = &wrapper.extra;
+    if !extra.derives.has_debug() {
+        match extra.exclusive.is_some() || extra.tagged_array.is_some() {
+            true => GenRedactedDebug::from(GenEnum::from(&**extra)).to_tokens(tokens),
+            false => GenRedactedDebug::from(GenStruct::from(&**extra)).to_tokens(tokens),
+        }
+    }
+    for field in &extra.fields {
+        match &field.object {
+            ExtraChildVariant::Nest(nest) => gen_redacted_debug_nest(nest, tokens),
+            ExtraChildVariant::Wrapper(child_wrapper) => gen_redacted_debug(child_wrapper, tokens),
+        }
+    }
+}
+
+fn gen_redacted_debug_nest(nest: &NestData, tokens: &mut TokenStream) {
+    // providers and maps are emitted as type aliases
+    if !nest.is_alias() && !nest.derives.has_debug() {
+        GenRedactedDebug::from(GenStruct::from(nest)).to_tokens(tokens);
+    }
+}
+
+// !- Row views
+
+/// Recursively generate `RowView` impls for each nest struct beneath a wrapper
+fn gen_row_views(wrapper: &Wrapper, render: RowRender, tokens: &mut TokenStream) {
+    if let DataVariant::Nest(nest) = &wrapper.data {
+        gen_row_view_nest(nest, render, tokens);
+    }
+    for field in &wrapper.extra.fields {
+        match &field.object {
+            ExtraChildVariant::Nest(nest) => gen_row_view_nest(nest, render, tokens),
+            ExtraChildVariant::Wrapper(child_wrapper) => gen_row_views(child_wrapper, render, tokens),
+        }
+    }
+}
+
+fn gen_row_view_nest(nest: &Rc<NestData>, render: RowRender, tokens: &mut TokenStream) {
+    // providers and maps are emitted as type aliases
+    if nest.is_alias() {
+        return;
+    }
+    let (container, fields) = data_serde_fields(&DataVariant::Nest(nest.clone()));
+    let fields = fields.into_iter()
+        .filter(|(_, _, serde)| !serde.skip_serializing)
+        .map(|(name, _, serde)| (serde.serialized_name(name.to_string().as_str(), &container), name))
+        .collect();
+    GenRowView { ident: nest.ident.clone(), fields, render }.to_tokens(tokens);
+}
+
+// !- Deprecated accessors
+
+/// Recursively generate accessors for the nests superseded via `deprecates` within each extra struct
+fn gen_deprecated_accessors(wrapper: &Wrapper, tokens: &mut TokenStream) {
+    let accessors = wrapper.extra.fields.iter()
+        .filter_map(|field| {
+            let superseded_by = field.superseded_by.clone()?;
+            Some(GenDeprecatedAccessor {
+                name: field.name.clone(),
+                ty: field.object.ident().clone(),
+                superseded_by,
+                optional: field.optional,
+            })
+        })
+        .collect::<Vec<_>>();
+    if !accessors.is_empty() {
+        GenDeprecatedAccessors { ident: wrapper.extra.ident.clone(), accessors }.to_tokens(tokens);
+    }
+    for field in &wrapper.extra.fields {
+        if let ExtraChildVariant::Wrapper(child_wrapper) = &field.object {
+            gen_deprecated_accessors(child_wrapper, tokens);
+        }
+    }
+}
+
+// !- Impl assertions
+
+/// Recursively generate `Option<Nest>` impl assertions for optional nests with user-defined transform impls
+fn gen_assert_optional_nests(wrapper: &Wrapper, fallibility: &Fallibility, transform: &Path, tokens: &mut TokenStream) {
+    for extra_field in &wrapper.extra.fields {
+        let nest_data = match &extra_field.object {
+            ExtraChildVariant::Nest(nest_data) => nest_data,
+            ExtraChildVariant::Wrapper(nest_wrapper) => match &nest_wrapper.data {
+                DataVariant::Nest(nest_data) => nest_data,
+                DataVariant::Origin(..) => unreachable!("nested wrappers always wrap nest data"),
+            },
+        };
+        let generated = nest_data.derive_to_nest.is_some() || nest_data.sources || nest_data.provider.is_some() || nest_data.identity;
+        if extra_field.transform_optional() && !generated {
+            let sibling_type = extra_field.needs.as_ref().and_then(|sibling_name| {
+                wrapper.extra.fields.iter().find(|field| &field.name == sibling_name).map(ExtraField::ty)
+            });
+            GenAssertOptionalNest {
+                fallibility: fallibility.clone(),
+                transform_type: transform.clone(),
+                nest_struct_ident: nest_data.ident.clone(),
+                sibling_type,
+                span: nest_data.id_span,
+            }.to_tokens(tokens);
+        }
+
+        if let ExtraChildVariant::Wrapper(nest_wrapper) = &extra_field.object {
+            gen_assert_optional_nests(nest_wrapper, fallibility, transform, tokens);
+        }
+    }
+}
+
+/// Recursively generate `Send + Sync + 'static` assertions for a wrapper, its extra struct, and all nests beneath it
+fn gen_assert_threadsafe(wrapper: &Wrapper, span: Span, tokens: &mut TokenStream) {
+    GenAssertThreadsafe { ident: wrapper.ident.clone(), span }.to_tokens(tokens);
+    GenAssertThreadsafe { ident: wrapper.extra.ident.clone(), span }.to_tokens(tokens);
+    for extra_field in &wrapper.extra.fields {
+        match &extra_field.object {
+            ExtraChildVariant::Nest(nest_data) => GenAssertThreadsafe { ident: nest_data.ident.clone(), span }.to_tokens(tokens),
+            ExtraChildVariant::Wrapper(nest_wrapper) => {
+                if let DataVariant::Nest(nest_data) = &nest_wrapper.data {
+                    GenAssertThreadsafe { ident: nest_data.ident.clone(), span }.to_tokens(tokens);
+                }
+                gen_assert_threadsafe(nest_wrapper, span, tokens);
+            },
+        }
+    }
+}
+
+// !- Options builder
+
+/// Collects the gate of each optional nest using `derive_to_nest`, emits an error if no gates are found
+fn gen_options_builder(state: &State, errors: &mut Accumulator) -> Option<GenOptionsBuilder> {
+    let span = state.global.options_builder.span();
+    if state.global.transform_generic_params.is_some() {
+        errors.push(span_error(span, "`options_builder` cannot be used alongside `transform_generic_params`"));
+        return None;
+    }
+
+    // walk the hierarchy in declaration order
+    let mut gates: Vec<(String, Ident)> = Vec::new();
+    let mut pending = state.nest_hierarchy.get_children(None).iter().rev().cloned().collect::<Vec<_>>();
+    while let Some(nest_id) = pending.pop() {
+        let nest_opts = state.nest_hierarchy.get_nest_opts(&nest_id);
+        let optional = nest_opts.is_optional(state.global.all_optional.is_present());
+        pending.extend(state.nest_hierarchy.get_children(Some(&nest_id)).iter().rev().cloned());
+        if let Some(gate) = optional.then(|| nest_opts.derive_to_nest_options_field_name()).flatten()
+            && !gates.iter().any(|(_, existing)| existing == &gate)
+        {
+            gates.push((nest_id, gate));
+        }
+    }
+    if gates.is_empty() {
+        errors.push(span_error(span, "`options_builder` requires at least one optional nest using `derive_to_nest`"));
+        return None;
+    }
+
+    Some(GenOptionsBuilder {
+        ident: format_ident!("{}WrapOptionsBuilder", state.root_ident),
+        wrapper_ident: state.wrapper_opts.struct_name(&state.root_ident),
+        transform_type: state.global.transform.clone(),
+        gates,
+    })
+}
+
+// !- Identity key
+
+/// Generate the identity key accessor on the primary wrapper
+fn gen_identity(wrapper: &Rc<Wrapper>, key_name: Ident, key_type: Type, tokens: &mut TokenStream) {
+    GenIdentity {
+        ident: wrapper.ident.clone(),
+        data_name: wrapper.data_name.clone(),
+        key_name,
+        key_type,
+    }.to_tokens(tokens);
+}
+
+// !- Delegate accessors
+
+/// Generate accessors on the primary wrapper for each nest, and each field within them
+///
+/// Errors if an accessor name is shared with another accessor, or with a method in `reserved`
+fn gen_delegate_accessors(wrapper: &Wrapper, reserved: &[&str], errors: &mut Accumulator, tokens: &mut TokenStream) {
+    let mut accessors = Vec::new();
+    let mut spans = Vec::new();
+    for extra_field in &wrapper.extra.fields {
+        // fields of chained nests are accessed through the nest wrapper data
+        let (nest, data_name) = match &extra_field.object {
+            ExtraChildVariant::Nest(nest) => (nest, None),
+            ExtraChildVariant::Wrapper(child_wrapper) => match &child_wrapper.data {
+                DataVariant::Nest(nest) => (nest, Some(child_wrapper.data_name.clone())),
+                DataVariant::Origin(..) => unreachable!("extra fields never contain the origin data"),
+            },
+        };
+        let extra_ident = extra_field.object.ident();
+        accessors.push(GenDelegateAccessor {
+            name: extra_field.name.clone(),
+            ty: parse_quote!(#extra_ident),
+            extra_field: extra_field.name.clone(),
+            projection: Vec::new(),
+            optional: extra_field.optional,
+        });
+        spans.push(nest.id_span);
+
+        // provider nests are opaque, their fields aren't known
+        if nest.is_alias() {
+            continue;
+        }
+        for nest_field in &nest.fields {
+            accessors.push(GenDelegateAccessor {
+                name: format_ident!("{}_{}", nest_field.name.unraw(), extra_field.name.unraw()),
+                ty: nest_field.ty.clone(),
+                extra_field: extra_field.name.clone(),
+                projection: data_name.iter().chain([&nest_field.name]).cloned().collect(),
+                optional: extra_field.optional,
+            });
+            spans.push(nest.id_span);
+        }
+    }
+
+    for (index, (accessor, span)) in accessors.iter().zip(&spans).enumerate() {
+        let name = accessor.name.unraw().to_string();
+        if reserved.contains(&name.as_str()) {
+            errors.push(span_error(*span, format!("Accessor `{name}` conflicts with the generated wrapper method `{name}`")));
+        } else if accessors[..index].iter().any(|other| other.name.unraw() == accessor.name.unraw()) {
+            errors.push(span_error(*span, format!("Accessor `{name}` is generated more than once, rename a nest via `field_name`")));
+        }
+    }
+
+    GenDelegateAccessors {
+        ident: wrapper.ident.clone(),
+        extra_name: wrapper.extra_name.clone(),
+        accessors,
+    }.to_tokens(tokens);
+}
+
+// !- Audit
+
+/// Doc attrs listing the audited origin fields, appended to the primary wrapper's docs
+fn audit_doc_attrs(state: &State) -> Vec<Attribute> {
+    let (mapped, unmapped) = state.audit_fields();
+    let list = |fields: Vec<Ident>| match fields.is_empty() {
+        true => "(none)".to_string(),
+        false => fields.iter().map(|field| format!("`{}`", field.unraw())).collect::<Vec<_>>().join(", "),
+    };
+    let mut note = format!(" **Audit**: origin fields mapped to nests: {}.", list(mapped));
+    if !unmapped.is_empty() {
+        note.push_str(&format!(" Not yet mapped: {}.", list(unmapped)));
+    }
+    vec![parse_quote!(#[doc = ""]), parse_quote!(#[doc = #note])]
+}
+
+// !- Topology
+
+/// Generate the nest topology constant on the origin data struct
+fn gen_topology(models: &ModelTree, tokens: &mut TokenStream) {
+    let mut nodes = Vec::new();
+    gen_topology_nodes(&models.origin_wrapper, &mut nodes);
+    GenTopology { data_ident: models.origin.ident.clone(), nodes }.to_tokens(tokens);
+}
+
+/// Recursively collect a node for each nest beneath a wrapper, parents first
+fn gen_topology_nodes(wrapper: &Wrapper, nodes: &mut Vec<GenNestNode>) {
+    let parent = wrapper.data.nest_id().map(str::to_string);
+    for extra_field in &wrapper.extra.fields {
+        let (nest, child_wrapper) = match &extra_field.object {
+            ExtraChildVariant::Nest(nest) => (nest.clone(), None),
+            ExtraChildVariant::Wrapper(child_wrapper) => match &child_wrapper.data {
+                DataVariant::Nest(nest) => (nest.clone(), Some(child_wrapper)),
+                DataVariant::Origin(..) => unreachable!("extra fields never contain the origin data"),
+            },
+        };
+        nodes.push(GenNestNode {
+            id: nest.id.clone(),
+            parent: parent.clone(),
+            field_name: extra_field.name.clone(),
+            optional: extra_field.optional,
+            struct_name: nest.ident.clone(),
+            wrapper_name: child_wrapper.map(|child_wrapper| child_wrapper.ident.clone()),
+        });
+        if let Some(child_wrapper) = child_wrapper {
+            gen_topology_nodes(child_wrapper, nodes);
+        }
+    }
+}
+
+// !- CSV projection
+
+/// Generate CSV helpers on the primary wrapper, origin data columns first, followed by nest columns
+fn gen_csv(models: &ModelTree, csv: &GlobalCsvOpts, tokens: &mut TokenStream) {
+    let wrapper = &models.origin_wrapper;
+    let data_name = &wrapper.data_name;
+
+    let mut columns = Vec::new();
+    if let DataVariant::Origin(origin) = &wrapper.data {
+        for field in origin.fields.iter().filter(|field| !field.serde.skip_serializing) {
+            columns.push(GenCsvColumn {
+                header: field.serde.serialized_name(field.name.to_string().as_str(), &origin.serde),
+                parent: quote!(::std::option::Option::Some(&self.#data_name)),
+                field: field.name.clone(),
+            });
+        }
+    }
+    gen_csv_extra_columns(wrapper, quote!(::std::option::Option::Some(self)), None, csv, &mut columns);
+
+    GenCsvRecord { ident: wrapper.ident.clone(), columns }.to_tokens(tokens);
+}
+
+/// Recursively collect the columns of all selected nests beneath a wrapper
+///
+/// `wrapper_expr` is an expression of type `Option<&Wrapper>`, `path` is the dotted nest path of the wrapper (None for the primary wrapper)
+fn gen_csv_extra_columns(
+    wrapper: &Wrapper,
+    wrapper_expr: TokenStream,
+    path: Option<&str>,
+    csv: &GlobalCsvOpts,
+    columns: &mut Vec<GenCsvColumn>,
+) {
+    let extra_name = &wrapper.extra_name;
+    for extra_field in &wrapper.extra.fields {
+        let name = &extra_field.name;
+        let field_path = match path {
+            Some(path) => format!("{path}.{name}"),
+            None => name.to_string(),
+        };
+        let child_expr = match extra_field.optional {
+            true => quote!(#wrapper_expr.and_then(|parent| parent.#extra_name.#name.as_ref())),
+            false => quote!(#wrapper_expr.map(|parent| &parent.#extra_name.#name)),
+        };
+        match &extra_field.object {
+            ExtraChildVariant::Nest(nest) => gen_csv_nest_columns(nest, child_expr, &field_path, csv, columns),
+            ExtraChildVariant::Wrapper(child_wrapper) => {
+                if let DataVariant::Nest(nest) = &child_wrapper.data {
+                    let data_name = &child_wrapper.data_name;
+                    let nest_expr = quote!(#child_expr.map(|parent| &parent.#data_name));
+                    gen_csv_nest_columns(nest, nest_expr, &field_path, csv, columns);
+                }
+                gen_csv_extra_columns(child_wrapper, child_expr, Some(&field_path), csv, columns);
+            },
+        }
+    }
+}
+
+/// `nest_expr` is an expression of type `Option<&Nest>`
+fn gen_csv_nest_columns(nest: &NestData, nest_expr: TokenStream, path: &str, csv: &GlobalCsvOpts, columns: &mut Vec<GenCsvColumn>) {
+    if !csv.includes(&nest.id) {
+        return;
+    }
+    let container = SerdeContainerAttrs::from_attrs(&nest.attrs);
+    for field in &nest.fields {
+        let serde = SerdeFieldAttrs::from_attrs(&field.attrs);
+        if serde.skip_serializing {
+            continue;
+        }
+        let key = serde.serialized_name(field.name.to_string().as_str(), &container);
+        columns.push(GenCsvColumn {
+            header: format!("{path}.{key}"),
+            parent: nest_expr.clone(),
+            field: field.name.clone(),
+        });
+    }
+}
+
+// !- HTTP responses
+
+/// Generate the `http::Response` conversion for the primary wrapper
+fn gen_http(models: &ModelTree, http: GlobalHttpOpts, tokens: &mut TokenStream) {
+    GenHttpResponse {
+        ident: models.origin_wrapper.ident.clone(),
+        content_type: http.content_type.unwrap_or_else(|| LitStr::new("application/json", Span::call_site())),
+        status: http.status,
+    }.to_tokens(tokens);
+}
+
+// !- Manual serialize impls
+
+/// Recursively generate `Serialize` impls for a wrapper, its extra struct, and all nests beneath it
+///
+/// `integrity` only applies to the given wrapper, child wrappers are serialized without a checksum
+fn gen_serialize(
+    wrapper: &Rc<Wrapper>,
+    sort_keys: bool,
+    binary_safe: bool,
+    integrity: Option<&WrapperIntegrityOpts>,
+    errors: &mut Accumulator,
+    tokens: &mut TokenStream,
+) {
+    let container = SerdeContainerAttrs::from_attrs(&wrapper.attrs);
+    let no_serde = SerdeFieldAttrs::default();
+    let extra_name = &wrapper.extra_name;
+    let data_name = &wrapper.data_name;
+
+    let mut fields = Vec::new();
+    fields.push(GenSerializeField {
+        key: SerdeFieldAttrs::from_attrs(&wrapper.extra_attrs).serialized_name(extra_name.to_string().as_str(), &container),
+        access: quote!(self.#extra_name),
+        skip_if: None,
+        alias: None,
+    });
+    if wrapper.data_flatten {
+        fields.extend(gen_serialize_data_fields(&wrapper.data, quote!(self.#data_name), binary_safe, errors));
+    } else {
+        fields.push(GenSerializeField {
+            key: SerdeFieldAttrs::from_attrs(&wrapper.data_attrs).serialized_name(data_name.to_string().as_str(), &container),
+            access: quote!(self.#data_name),
+            skip_if: None,
+            alias: None,
+        });
+    }
+    if let Some(integrity) = integrity {
+        let digest = match (&integrity.algo, &integrity.key) {
+            (IntegrityAlgo::HmacSha256, Some(key)) => quote! {
+                ::shrinkwrap::__private::integrity_signature(#key().as_ref(), &self.#data_name)
+            },
+            _ => quote!(::shrinkwrap::__private::integrity_checksum(&self.#data_name)),
+        };
+        fields.push(GenSerializeField {
+            key: no_serde.serialized_name(integrity.field.to_string().as_str(), &container),
+            access: quote!(#digest.map_err(<__S::Error as ::serde::ser::Error>::custom)?),
+            skip_if: None,
+            alias: None,
+        });
+    }
+    GenSerialize { ident: wrapper.ident.clone(), fields, sort_keys }.to_tokens(tokens);
+
+    gen_serialize_extra(&wrapper.extra, sort_keys, tokens);
+
+    // data (nests only, origin data provides its own impl)
+    if let DataVariant::Nest(nest) = &wrapper.data {
+        gen_serialize_nest(nest, sort_keys, binary_safe, errors, tokens);
+    }
+
+    // children
+    for field in &wrapper.extra.fields {
+        match &field.object {
+            ExtraChildVariant::Nest(nest) => gen_serialize_nest(nest, sort_keys, binary_safe, errors, tokens),
+            ExtraChildVariant::Wrapper(child_wrapper) => gen_serialize(child_wrapper, sort_keys, binary_safe, None, errors, tokens),
+        }
+    }
+}
+
+/// Generates `Serialize` for an extra struct, `emit_alias` nests are serialized under their alias as well while
+/// aliases are enabled at runtime
+fn gen_serialize_extra(extra: &Extra, sort_keys: bool, tokens: &mut TokenStream) {
+    let container = SerdeContainerAttrs::from_attrs(&extra.attrs);
+    let fields = extra.fields.iter().map(|field| {
+        let name = &field.name;
+        // encoded nests are serialized via the `with` module of their derived impl
+        let access = match field.encode {
+            Some(encode) => {
+                let with: Path = syn::parse_str(nest_encode_with(encode, field.optional).0).expect("valid module path");
+                quote!(#with::Encoded(&self.#name))
+            },
+            None => quote!(self.#name),
+        };
+        GenSerializeField {
+            key: SerdeFieldAttrs::default().serialized_name(name.to_string().as_str(), &container),
+            access,
+            skip_if: None,
+            alias: field.alias.clone().filter(|_| field.emit_alias),
+        }
+    }).collect();
+    GenSerialize { ident: extra.ident.clone(), fields, sort_keys }.to_tokens(tokens);
+}
+
+/// Recursively generate `Serialize` for the extra structs holding `emit_alias` nests, for wrappers which derive it
+/// (see `gen_serialize` otherwise)
+fn gen_serialize_aliases(wrapper: &Rc<Wrapper>, tokens: &mut TokenStream) {
+    if wrapper.extra.emits_aliases() {
+        gen_serialize_extra(&wrapper.extra, false, tokens);
+    }
+    for field in &wrapper.extra.fields {
+        if let ExtraChildVariant::Wrapper(child_wrapper) = &field.object {
+            gen_serialize_aliases(child_wrapper, tokens);
+        }
+    }
+}
+
+/// Generates `Serialize` for a wrapper with `promote` nests (only root nests can be promoted)
+fn gen_serialize_promoted(wrapper: &Rc<Wrapper>, tokens: &mut TokenStream) {
+    let promoted = wrapper.extra.fields.iter()
+        .filter(|field| field.promote)
+        .map(|field| (field.name.clone(), field.ty()))
+        .collect();
+    GenSerializePromoted {
+        ident: wrapper.ident.clone(),
+        attrs: wrapper.attrs.clone(),
+        fields: GenStruct::from(&**wrapper).fields,
+        extra_name: wrapper.extra_name.clone(),
+        promoted,
+    }.to_tokens(tokens);
+}
+
+fn gen_serialize_nest(nest: &Rc<NestData>, sort_keys: bool, binary_safe: bool, errors: &mut Accumulator, tokens: &mut TokenStream) {
+    // providers supply their own impl, maps serialize with sorted keys already
+    if nest.is_alias() {
+        return;
+    }
+    GenSerialize {
+        ident: nest.ident.clone(),
+        fields: gen_serialize_data_fields(&DataVariant::Nest(nest.clone()), quote!(self), binary_safe, errors),
+        sort_keys,
+    }.to_tokens(tokens);
+}
+
+/// Builds the serialized fields of the data held by a wrapper, `base` being the expression for the data struct
+fn gen_serialize_data_fields(data: &DataVariant, base: TokenStream, binary_safe: bool, errors: &mut Accumulator) -> Vec<GenSerializeField> {
+    let (container, fields) = data_serde_fields(data);
+    let mut out = Vec::new();
+    for (name, _, serde) in fields {
+        if serde.skip_serializing {
+            continue;
+        }
+        if serde.flatten || serde.custom_serialize {
+            errors.push(darling::Error::custom(
+                "`#[serde(flatten)]`, `#[serde(with)]` and `#[serde(serialize_with)]` are not supported alongside `wrapper(sort_keys)`, `wrapper(binary_safe)` or `wrapper(integrity)`"
+            ).with_span(&name));
+            continue;
+        }
+        if binary_safe && serde.skip_serializing_if.is_some() {
+            errors.push(darling::Error::custom("`#[serde(skip_serializing_if)]` is not supported alongside `wrapper(binary_safe)`").with_span(&name));
+            continue;
+        }
+        out.push(GenSerializeField {
+            key: serde.serialized_name(name.to_string().as_str(), &container),
+            access: quote!(#base.#name),
+            skip_if: serde.skip_serializing_if.clone(),
+            alias: None,
+        });
+    }
+    out
+}
+
+/// Serde container attrs of the data held by a wrapper, along with the (name, type, serde attrs) of each field
+fn data_serde_fields(data: &DataVariant) -> (SerdeContainerAttrs, Vec<(Ident, Type, SerdeFieldAttrs)>) {
+    match data {
+        DataVariant::Origin(origin) => (
+            origin.serde.clone(),
+            origin.fields.iter().map(|field| (field.name.clone(), field.ty.clone(), field.serde.clone())).collect(),
+        ),
+        DataVariant::Nest(nest) => (
+            SerdeContainerAttrs::from_attrs(&nest.attrs),
+            nest.fields.iter().map(|field| (field.name.clone(), field.ty.clone(), SerdeFieldAttrs::from_attrs(&field.attrs))).collect(),
+        ),
+    }
+}
+
+// !- Manual deserialize impls
+
+/// Recursively generate `Deserialize` impls for a wrapper and all wrappers beneath it
+///
+/// Extra and nest structs retain their derived impls, as they never contain flattened fields
+fn gen_deserialize(wrapper: &Rc<Wrapper>, sort_keys: bool, deny_unknown_fields: bool, errors: &mut Accumulator, tokens: &mut TokenStream) {
+    let container = SerdeContainerAttrs::from_attrs(&wrapper.attrs);
+    let (data_container, fields) = data_serde_fields(&wrapper.data);
+    let data_fields = fields.into_iter().filter_map(|(name, ty, serde)| {
+        if serde.custom_deserialize {
+            errors.push(darling::Error::custom(
+                "`#[serde(with)]` and `#[serde(deserialize_with)]` are not supported alongside `wrapper(binary_safe)` or `deserialize(deny_unknown_fields)`"
+            ).with_span(&name));
+            return None;
+        }
+        Some(GenDeserializeField {
+            key: serde.serialized_name(name.to_string().as_str(), &data_container),
+            default: serde.default || is_option(&ty),
+            name,
+            ty,
+            serialized: !serde.skip_serializing,
+            deserialized: !serde.skip_deserializing,
+            default_fn: serde.default_fn,
+        })
+    }).collect();
+
+    let extra_serde = SerdeFieldAttrs::from_attrs(&wrapper.extra_attrs);
+    GenDeserialize {
+        ident: wrapper.ident.clone(),
+        extra_name: wrapper.extra_name.clone(),
+        extra_ident: wrapper.extra.ident.clone(),
+        extra_key: extra_serde.serialized_name(wrapper.extra_name.to_string().as_str(), &container),
+        extra_default: extra_serde.default,
+        data_name: wrapper.data_name.clone(),
+        data_ident: wrapper.data.ident().clone(),
+        data_fields,
+        sort_keys,
+        deny_unknown_fields,
+    }.to_tokens(tokens);
+
+    for field in &wrapper.extra.fields {
+        if let ExtraChildVariant::Wrapper(child_wrapper) = &field.object {
+            gen_deserialize(child_wrapper, sort_keys, deny_unknown_fields, errors, tokens);
+        }
+    }
+}
+
+// !- JSON contract helpers
+
+fn gen_json(models: &ModelTree, deserialize: bool, tokens: &mut TokenStream) {
+    gen_json_shapes(&models.origin_wrapper, tokens);
+    GenJsonHelpers {
+        wrapper_ident: models.origin_wrapper.ident.clone(),
+        deserialize,
+        nests: gen_nest_tree(&models.origin_wrapper),
+    }.to_tokens(tokens);
+}
+
+/// Recursively builds the serialized location of the nests within a wrapper, used by `to_value_filtered`
+fn gen_nest_tree(wrapper: &Wrapper) -> GenNestTree {
+    let container = SerdeContainerAttrs::from_attrs(&wrapper.attrs);
+    let extra_serde = SerdeFieldAttrs::from_attrs(&wrapper.extra_attrs);
+    let extra_container = SerdeContainerAttrs::from_attrs(&wrapper.extra.attrs);
+    let nests = wrapper.extra.fields.iter().map(|field| {
+        let (nest_id, children) = match &field.object {
+            ExtraChildVariant::Nest(nest) => (nest.id.clone(), None),
+            ExtraChildVariant::Wrapper(child_wrapper) => match &child_wrapper.data {
+                DataVariant::Nest(nest) => (nest.id.clone(), Some(gen_nest_tree(child_wrapper))),
+                DataVariant::Origin(..) => unreachable!("nested wrappers always wrap nest data"),
+            },
+        };
+        GenNestKey {
+            id: nest_id,
+            key: SerdeFieldAttrs::default().serialized_name(field.name.to_string().as_str(), &extra_container),
+            children,
+        }
+    }).collect();
+    GenNestTree {
+        extra: (!extra_serde.flatten).then(|| extra_serde.serialized_name(wrapper.extra_name.to_string().as_str(), &container)),
+        nests,
+    }
+}
+
+/// Recursively generate `JsonShape` impls for a wrapper, its extra struct, and all nests beneath it
+fn gen_json_shapes(wrapper: &Rc<Wrapper>, tokens: &mut TokenStream) {
+    let container = SerdeContainerAttrs::from_attrs(&wrapper.attrs);
+    let no_serde = SerdeFieldAttrs::default();
+    let extra_ident = &wrapper.extra.ident;
+
+    let mut keys = Vec::new();
+    keys.extend(GenShapeKey::from_field(
+        &wrapper.extra_name, false, &SerdeFieldAttrs::from_attrs(&wrapper.extra_attrs), &container,
+        Some(quote!(&<#extra_ident as ::shrinkwrap::__private::JsonShape>::SHAPE)),
+    ));
+    let mut open = false;
+    if wrapper.data_flatten {
+        let data_shape = gen_data_variant_shape(&wrapper.data);
+        keys.extend(data_shape.keys);
+        open = data_shape.open;
+    } else {
+        let data_shape = match &wrapper.data {
+            DataVariant::Origin(..) => {
+                let shape = gen_data_variant_shape(&wrapper.data);
+                quote!(&#shape)
+            },
+            DataVariant::Nest(nest) => {
+                let nest_ident = &nest.ident;
+                quote!(&<#nest_ident as ::shrinkwrap::__private::JsonShape>::SHAPE)
+            },
+        };
+        let data_serde = SerdeFieldAttrs::from_attrs(&wrapper.data_attrs);
+        keys.extend(GenShapeKey::from_field(&wrapper.data_name, false, &data_serde, &container, Some(data_shape)));
+    }
+    GenJsonShape {
+        ident: wrapper.ident.clone(),
+        shape: GenShape {
+            name: wrapper.ident.to_string(),
+            nest_id: wrapper.data.nest_id().map(str::to_string),
+            keys,
+            open,
+        },
+    }.to_tokens(tokens);
+
+    // extra
+    let extra_container = SerdeContainerAttrs::from_attrs(&wrapper.extra.attrs);
+    let extra_keys = wrapper.extra.fields.iter().filter_map(|field| {
+        let object_ident = field.object.ident();
+        // encoded nests are serialized as strings, whose shape isn't known
+        let shape = field.encode.is_none().then(|| quote!(&<#object_ident as ::shrinkwrap::__private::JsonShape>::SHAPE));
+        GenShapeKey::from_field(&field.name, field.optional, &no_serde, &extra_container, shape)
+    }).collect();
+    GenJsonShape {
+        ident: extra_ident.clone(),
+        shape: GenShape {
+            name: extra_ident.to_string(),
+            nest_id: wrapper.data.nest_id().map(str::to_string),
+            keys: extra_keys,
+            open: false,
+        },
+    }.to_tokens(tokens);
+
+    // data (nests only, origin data shapes are always inlined)
+    if let DataVariant::Nest(nest) = &wrapper.data {
+        gen_json_nest_shape(nest, tokens);
+    }
+
+    // children
+    for field in &wrapper.extra.fields {
+        match &field.object {
+            ExtraChildVariant::Nest(nest) => gen_json_nest_shape(nest, tokens),
+            ExtraChildVariant::Wrapper(child_wrapper) => gen_json_shapes(child_wrapper, tokens),
+        }
+    }
+}
+
+fn gen_json_nest_shape(nest: &Rc<NestData>, tokens: &mut TokenStream) {
+    // providers supply their own impl, maps use the runtime's open shape
+    if nest.is_alias() {
+        return;
+    }
+    GenJsonShape {
+        ident: nest.ident.clone(),
+        shape: gen_data_variant_shape(&DataVariant::Nest(nest.clone())),
+    }.to_tokens(tokens);
+}
+
+/// Builds the shape of the data held by a wrapper
+fn gen_data_variant_shape(data: &DataVariant) -> GenShape {
+    match data {
+        DataVariant::Origin(origin) => {
+            let keys = origin.fields.iter().filter_map(|field| {
+                GenShapeKey::from_field(&field.name, is_option(&field.ty), &field.serde, &origin.serde, None)
+            }).collect();
+            GenShape {
+                name: origin.ident.to_string(),
+                nest_id: None,
+                keys,
+                open: origin.fields.iter().any(|field| field.serde.flatten && !field.serde.skip_serializing),
+            }
+        },
+        DataVariant::Nest(nest) => {
+            let container = SerdeContainerAttrs::from_attrs(&nest.attrs);
+            let mut open = false;
+            let keys = nest.fields.iter().filter_map(|field| {
+                let serde = SerdeFieldAttrs::from_attrs(&field.attrs);
+                open |= serde.flatten && !serde.skip_serializing;
+                GenShapeKey::from_field(&field.name, is_option(&field.ty), &serde, &container, None)
+            }).collect();
+            GenShape {
+                name: nest.ident.to_string(),
+                nest_id: Some(nest.id.clone()),
+                keys,
+                open,
+            }
+        },
+    }
+}
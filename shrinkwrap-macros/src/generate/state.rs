@@ -1,6 +1,8 @@
 use super::*;
-use darling::util::PathList;
-use crate::parse::types::{ExtraOpts, GlobalOpts, WrapperOpts};
+use darling::util::{Flag, PathList};
+use crate::parse::serde_attrs::{RenameRule, skip_meta_value};
+use super::structs::Derives;
+use crate::parse::types::{CfgDerive, ExtraOpts, GlobalOpts, SchemaAnnotation, WrapperOpts};
 
 pub(crate) struct State {
     pub global: GlobalOpts,
@@ -31,7 +33,7 @@ impl State {
     ) -> Self {
         let mut state = Self {
             root_ident: root_ident.clone(),
-            default_derives: Self::init_default_derives(&global),
+            default_derives: Self::init_default_derives(&global, &wrapper),
             global,
             wrapper_opts: wrapper,
             extra_opts: extra,
@@ -49,43 +51,186 @@ impl State {
         vec![
             parse_quote!(::std::fmt::Debug),
             parse_quote!(::std::clone::Clone),
-            parse_quote!(::serde::Serialize),
         ]
     }
-    fn init_default_derives(global_opts: &GlobalOpts) -> Vec<Path> {
+    fn init_default_derives(global_opts: &GlobalOpts, wrapper_opts: &WrapperOpts) -> Vec<Path> {
         let mut derives = Self::base_derives();
 
+        // `Serialize` is implemented by `trait_impl::GenSerialize` instead
+        if !wrapper_opts.manual_serialize() {
+            derives.push(parse_quote!(::serde::Serialize));
+        }
+
+        if wrapper_opts.deserialize() {
+            derives.push(parse_quote!(::serde::Deserialize));
+        }
+
         // derive `JsonSchema` if either schema or inline attribute flags are set
         if global_opts.schema() {
             derives.push(parse_quote!(::schemars::JsonSchema));
         }
 
+        if global_opts.testing.arbitrary.is_present() {
+            derives.push(parse_quote!(::arbitrary::Arbitrary));
+        }
+        if global_opts.testing.proptest.is_present() {
+            derives.push(parse_quote!(::proptest_derive::Arbitrary));
+        }
+
         // add derives defined in global opts
-        derives.extend(global_opts.derive_all.to_vec());
+        merge_derives(&mut derives, global_opts.derive_all.to_vec());
 
         derives
     }
 
+    /// Default derives merged with the struct class derives `custom_derives`, without duplicates
     pub(crate) fn full_derives(&self, custom_derives: PathList) -> Vec<Path> {
         let mut base = self.default_derives.clone();
-        base.extend((*custom_derives).clone());
+        merge_derives(&mut base, custom_derives.to_vec());
         base
     }
 
-    pub(crate) fn full_struct_attrs(&self, nest_id: Option<&str>, class: StructClass) -> Vec<Attribute> {
-        let mut base = Vec::new();
+    /// Full derives for wrapper structs, `Deserialize` is implemented by `trait_impl::GenDeserialize` when required
+    pub(crate) fn wrapper_derives(&self) -> Vec<Path> {
+        let mut derives = self.full_derives(self.wrapper_opts.derive.clone());
+        if self.wrapper_opts.manual_deserialize() {
+            let deserialize: Path = parse_quote!(::serde::Deserialize);
+            derives.retain(|derive| derive != &deserialize);
+        }
+        derives
+    }
+
+    /// Removes the `Debug` derive if `no_debug` is set globally or for the struct class (`class_no_debug`)
+    pub(crate) fn apply_no_debug(&self, derives: &mut Vec<Path>, class_no_debug: &Flag) {
+        if self.global.no_debug.is_present() || class_no_debug.is_present() {
+            let debug: Path = parse_quote!(::std::fmt::Debug);
+            derives.retain(|derive| derive != &debug);
+        }
+    }
+
+    /// Applies the schema options to a struct's derives and attrs (`attrs` being those of the struct and its fields):
+    /// - removes `JsonSchema` derives and `#[schemars(..)]` attrs if the nest with ID `nest_id` is excluded from the
+    ///   schema via `schema_skip`
+    /// - otherwise gates them behind the consumer crate's feature if set via `schema(feature = "..")`
+    pub(crate) fn apply_schema_opts<'a>(&self, nest_id: Option<&str>, derives: &mut Vec<Path>, attrs: impl IntoIterator<Item = &'a mut Vec<Attribute>>) {
+        let is_schema_derive = |derive: &Path| derive.segments.last().is_some_and(|segment| segment.ident == "JsonSchema");
+        if self.nest_schema_skipped(nest_id) {
+            derives.retain(|derive| !is_schema_derive(derive));
+            for attrs in attrs {
+                attrs.retain(|attr| !attr.path().is_ident("schemars"));
+            }
+            return;
+        }
+        let Some(feature) = self.global.schema_feature() else {
+            return;
+        };
+        let (schema_derives, other_derives): (Vec<Path>, Vec<Path>) = derives.drain(..).partition(is_schema_derive);
+        *derives = other_derives;
+        // `#[serde(..)]` attrs are otherwise stripped when the schema derive is the only one registering serde
+        let gate_serde = !schema_derives.is_empty() && !Derives::from(derives.clone()).has_serde_helper();
+        for (index, attrs) in attrs.into_iter().enumerate() {
+            for attr in attrs.iter_mut().filter(|attr| attr.path().is_ident("schemars") || (gate_serde && attr.path().is_ident("serde"))) {
+                let meta = &attr.meta;
+                *attr = parse_quote!(#[cfg_attr(feature = #feature, #meta)]);
+            }
+            // the first attr list is that of the struct itself
+            if index == 0 && !schema_derives.is_empty() {
+                attrs.insert(0, parse_quote!(#[cfg_attr(feature = #feature, derive(#(#schema_derives),*))]));
+            }
+        }
+    }
+
+    pub(crate) fn full_struct_attrs(
+        &self,
+        ident: &Ident,
+        nest_id: Option<&str>,
+        class: StructClass,
+        cfg_derives: &[CfgDerive],
+        annotations: &[SchemaAnnotation],
+        serde_bound: Option<&LitStr>,
+    ) -> Vec<Attribute> {
+        let mut base: Vec<Attribute> = cfg_derives.iter().map(CfgDerive::to_attr).collect();
+        if self.global.wasm_bindgen.is_present() {
+            base.push(parse_quote!(#[::shrinkwrap::__private::wasm_bindgen::prelude::wasm_bindgen(getter_with_clone, wasm_bindgen = ::shrinkwrap::__private::wasm_bindgen)]));
+        }
         if self.global.inline() {
             base.push(parse_quote!(#[schemars(inline)]));
         }
+        if let Some(serde_bound) = serde_bound {
+            base.push(parse_quote!(#[serde(bound = #serde_bound)]));
+        }
+        // flattened wrappers reject unknown fields via `trait_impl::GenDeserialize` instead
+        let flattened = class == StructClass::Wrapper && self.wrapper_opts.flatten();
+        if self.wrapper_opts.deny_unknown_fields() && !flattened {
+            base.push(parse_quote!(#[serde(deny_unknown_fields)]));
+        }
+        base.extend(annotations.iter().map(SchemaAnnotation::to_attr));
         let custom_attrs = self.struct_attr_resolver.resolve(nest_id, class);
+        if let Some(rule) = self.inherited_rename_all(class) && SerdeContainerAttrs::from_attrs(&custom_attrs).rename_all.is_none() {
+            let rule = rule.as_str();
+            base.push(parse_quote!(#[serde(rename_all = #rule)]));
+        }
+        if let Some(schema_naming) = &self.global.schema_naming && !has_schemars_rename(&custom_attrs) {
+            let schema_name = schema_naming.schema_name(ident);
+            base.push(parse_quote!(#[schemars(rename = #schema_name)]));
+        }
         base.extend(custom_attrs);
         base
     }
 
+    /// Origin `rename_all` rule applied to `class` via `naming(inherit_rename_all)`
+    fn inherited_rename_all(&self, class: StructClass) -> Option<RenameRule> {
+        let inherit = self.global.naming.inherit_rename_all.is_present() && matches!(class, StructClass::Extra | StructClass::Nest);
+        inherit.then_some(self.struct_attr_resolver.origin_serde.rename_all).flatten()
+    }
+
+    /// Whether the nest with ID `nest_id` is excluded from the schema, via `schema_skip` on the nest or any ancestor
+    pub(crate) fn nest_schema_skipped(&self, nest_id: Option<&str>) -> bool {
+        let Some(nest_id) = nest_id else {
+            return false;
+        };
+        let nest_opts = self.nest_hierarchy.get_nest_opts(nest_id);
+        nest_opts.schema_skip.is_present() || self.nest_schema_skipped(nest_opts.chain_from.as_ref().map(|parent_id| parent_id.as_str()))
+    }
+
+    /// Origin fields mapped to a nest (assigned, or selected via `fields(..)`) followed by the fields which aren't,
+    /// for `audit`. Fields marked `unmapped` are omitted from both.
+    pub(crate) fn audit_fields(&self) -> (Vec<Ident>, Vec<Ident>) {
+        let selected = self.nest_hierarchy.all_nest_ids().into_iter()
+            .flat_map(|nest_id| self.nest_hierarchy.get_nest_opts(&nest_id).fields.iter().map(|selector| selector.path[0].clone()).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let (mapped, unmapped): (Vec<_>, Vec<_>) = self.field_resolver.origin_fields().into_iter()
+            .filter(|field| !field.unmapped)
+            .partition(|field| !field.nest_assignments.is_empty() || selected.contains(&field.name));
+        let names = |fields: Vec<&ParsedField>| fields.into_iter().map(|field| field.name.clone()).collect();
+        (names(mapped), names(unmapped))
+    }
+
+    /// Field name of the sibling nest required by `nest_opts`, if any
+    pub(crate) fn nest_sibling_field_name(&self, nest_opts: &NestOpts) -> Option<Ident> {
+        nest_opts.needs.as_ref().map(|sibling_id| self.nest_hierarchy.get_nest_opts(sibling_id.as_str()).field_name())
+    }
+
+    /// Sibling nest superseding `nest_opts` via `deprecates`, if any
+    pub(crate) fn nest_superseded_by(&self, nest_opts: &NestOpts) -> Option<&NestOpts> {
+        let siblings = self.nest_hierarchy.get_children(nest_opts.chain_from.as_ref().map(|parent_id| parent_id.as_str()));
+        siblings.iter()
+            .map(|sibling_id| self.nest_hierarchy.get_nest_opts(sibling_id))
+            .find(|sibling| sibling.deprecates.as_ref().is_some_and(|deprecated_id| deprecated_id.as_str() == nest_opts.id_str()))
+    }
+
     pub(crate) fn nest_source_ident(&self, nest_id: &str) -> &Ident {
         self.nest_source_ident
         .get(nest_id)
-        .expect_or_abort(format!("Internal macro error - nest_source_ident map missing ID: {nest_id}").as_str())
+        .unwrap_or_else(|| panic!("Internal macro error - nest_source_ident map missing ID: {nest_id}"))
+    }
+
+    /// Ident of the data `nest_opts` is built from, nests chained from an auto parent are built from its source
+    pub(crate) fn nest_data_source_ident(&self, nest_opts: &NestOpts) -> &Ident {
+        match nest_opts.chain_from.as_ref().map(|parent_id| self.nest_hierarchy.get_nest_opts(parent_id.as_str())) {
+            Some(parent_opts) if parent_opts.auto_parent.is_present() => self.nest_data_source_ident(parent_opts),
+            _ => self.nest_source_ident(nest_opts.id_str()),
+        }
     }
 
     fn build_source_idents_map(&self, origin_ident: &Ident) -> HashMap<String, Ident> {
@@ -108,3 +253,36 @@ impl State {
         }
     }
 }
+
+/// Appends each of `extra` to `derives` unless the same trait is already derived
+fn merge_derives(derives: &mut Vec<Path>, extra: Vec<Path>) {
+    for derive in extra {
+        if !derives.iter().any(|existing| same_derive(existing, &derive)) {
+            derives.push(derive);
+        }
+    }
+}
+
+/// Whether `a` and `b` name the same derive, e.g. `Clone` and `::std::clone::Clone`
+///
+/// Paths are equal ignoring a leading `::`. A single-segment path (i.e. an imported name) matches any path ending with
+/// the same name, as does a path within `std`/`core`.
+fn same_derive(a: &Path, b: &Path) -> bool {
+    let segments = |path: &Path| path.segments.iter().map(|segment| segment.ident.to_string()).collect::<Vec<_>>();
+    let (a, b) = (segments(a), segments(b));
+    let is_std = |segments: &[String]| segments.len() == 1 || segments.first().is_some_and(|root| root == "std" || root == "core");
+    a == b || (a.last() == b.last() && (is_std(&a) || is_std(&b)))
+}
+
+/// Whether `attrs` contain an explicit `#[schemars(rename = "..")]`
+fn has_schemars_rename(attrs: &[Attribute]) -> bool {
+    let mut found = false;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("schemars")) {
+        // unparseable schemars attrs are left for schemars to report
+        let _ = attr.parse_nested_meta(|meta| {
+            found |= meta.path.is_ident("rename");
+            skip_meta_value(&meta)
+        });
+    }
+    found
+}
@@ -0,0 +1,76 @@
+use super::*;
+use syn::{GenericParam, Generics, Item, Visibility};
+
+// !- Alias-only module generator
+
+/// Generator for `docs(alias_only)`, placing the generated structs in a `#[doc(hidden)]` module and exposing each via
+/// a type alias of the same name
+#[derive(Debug, Clone)]
+pub(crate) struct GenAliasModule {
+    /// Name of the hidden module
+    pub module: Ident,
+
+    /// The generated structs (and any items emitted alongside them)
+    pub items: Vec<Item>,
+}
+impl GenAliasModule {
+    pub(crate) fn new(module: Ident, structs: TokenStream) -> syn::Result<Self> {
+        let file: syn::File = syn::parse2(structs)?;
+        Ok(Self { module, items: file.items })
+    }
+}
+impl ToTokens for GenAliasModule {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self { module, items } = &self;
+        let aliases = items.iter().filter_map(|item| {
+            let (vis, ident, generics, attrs) = match item {
+                Item::Struct(item) => (&item.vis, &item.ident, &item.generics, &item.attrs),
+                Item::Enum(item) => (&item.vis, &item.ident, &item.generics, &item.attrs),
+                Item::Type(item) => (&item.vis, &item.ident, &item.generics, &item.attrs),
+                _ => return None,
+            };
+            if !matches!(vis, Visibility::Public(_)) {
+                return None;
+            }
+            // docs are carried over to the alias, the struct itself is hidden
+            let docs = attrs.iter().filter(|attr| attr.path().is_ident("doc"));
+            let alias_generics = alias_generics(generics);
+            let (_, ty_generics, _) = generics.split_for_impl();
+            Some(quote! {
+                #( #docs )*
+                pub type #ident #alias_generics = #module::#ident #ty_generics;
+            })
+        });
+
+        tokens.extend(quote! {
+            #[doc(hidden)]
+            pub mod #module {
+                #[allow(unused_imports)]
+                use super::*;
+
+                #( #items )*
+            }
+            #( #aliases )*
+        });
+    }
+}
+
+/// Generic params of an alias, bounds aren't enforced on type aliases and are therefore dropped
+fn alias_generics(generics: &Generics) -> Generics {
+    let mut generics = generics.clone();
+    generics.where_clause = None;
+    for param in &mut generics.params {
+        match param {
+            GenericParam::Type(param) => {
+                param.bounds.clear();
+                param.colon_token = None;
+            },
+            GenericParam::Lifetime(param) => {
+                param.bounds.clear();
+                param.colon_token = None;
+            },
+            GenericParam::Const(_) => {},
+        }
+    }
+    generics
+}
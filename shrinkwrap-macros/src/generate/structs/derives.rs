@@ -3,6 +3,28 @@ use super::*;
 #[derive(Debug, Clone)]
 pub(crate) struct Derives(Vec<Path>);
 
+impl Derives {
+    /// Whether any of the derives register `serde` as a helper attribute
+    pub(crate) fn has_serde_helper(&self) -> bool {
+        self.0.iter().any(|path| {
+            path.segments.last().is_some_and(|segment| {
+                segment.ident == "Serialize" || segment.ident == "Deserialize" || segment.ident == "JsonSchema"
+            })
+        })
+    }
+}
+impl Derives {
+    /// Whether `Debug` is derived (see `no_debug`)
+    pub(crate) fn has_debug(&self) -> bool {
+        self.0.iter().any(|path| path.segments.last().is_some_and(|segment| segment.ident == "Debug"))
+    }
+}
+impl Derives {
+    /// Paths of the derived traits, in declaration order
+    pub(crate) fn paths(&self) -> &[Path] {
+        &self.0
+    }
+}
 impl ToTokens for Derives {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         if !self.0.is_empty() {
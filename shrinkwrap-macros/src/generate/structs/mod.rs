@@ -1,5 +1,7 @@
 use super::*;
 
+mod alias_module;
+pub(crate) use alias_module::GenAliasModule;
 mod derives;
 pub(crate) use derives::Derives;
 mod doc;
@@ -42,6 +44,18 @@ impl ToTokens for GenStruct {
         // destructure self
         let Self { vis, ty, attrs, derives, doc, fields, .. } = &self;
 
+        // `#[serde(..)]` is only valid if a derive registers it (e.g. with manual Serialize impls)
+        let (attrs, fields) = if derives.has_serde_helper() {
+            (attrs.clone(), fields.clone())
+        } else {
+            let attrs = strip_serde_attrs(attrs);
+            let fields = fields.iter().map(|field| GenStructField {
+                attrs: strip_serde_attrs(&field.attrs),
+                ..field.clone()
+            }).collect();
+            (attrs, fields)
+        };
+
         // build attribute list
         let attrs = quote! { #( #attrs )* };
 
@@ -57,6 +71,79 @@ impl ToTokens for GenStruct {
     }
 }
 
+// !- Enum generator
+
+/// Generator for an enum of unit or single-field tuple variants
+#[derive(Debug, Clone)]
+pub(crate) struct GenEnum {
+    pub vis: GenVisibility,
+    pub ty: Path,
+    pub derives: Derives,
+    pub attrs: Vec<Attribute>,
+    pub doc: Doc,
+    pub variants: Vec<GenEnumVariant>,
+}
+impl ToTokens for GenEnum {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        // destructure self
+        let Self { vis, ty, attrs, derives, doc, variants, .. } = &self;
+
+        // `#[serde(..)]` is only valid if a derive registers it (e.g. with manual Serialize impls)
+        let (attrs, variants) = if derives.has_serde_helper() {
+            (attrs.clone(), variants.clone())
+        } else {
+            let attrs = strip_serde_attrs(attrs);
+            let variants = variants.iter().map(|variant| GenEnumVariant {
+                attrs: strip_serde_attrs(&variant.attrs),
+                ..variant.clone()
+            }).collect();
+            (attrs, variants)
+        };
+
+        // build attribute list
+        let attrs = quote! { #( #attrs )* };
+
+        tokens.extend(quote! {
+            #[automatically_derived]
+            #doc
+            #derives
+            #attrs
+            #vis enum #ty {
+                #( #variants )*
+            }
+        });
+    }
+}
+
+/// Generator for a single variant within an enum, a unit variant if `ty` is `None`
+#[derive(Debug, Clone)]
+pub(crate) struct GenEnumVariant {
+    pub name: Ident,
+    pub ty: Option<Type>,
+    pub attrs: Vec<Attribute>,
+    pub doc: Doc,
+}
+impl ToTokens for GenEnumVariant {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        // destructure self
+        let Self { name, ty, attrs, doc, .. } = &self;
+
+        // build attribute list
+        let attrs = quote! { #( #attrs )* };
+        let ty = ty.as_ref().map(|ty| quote!((#ty)));
+
+        tokens.extend(quote! {
+            #doc
+            #attrs
+            #name #ty,
+        });
+    }
+}
+
+fn strip_serde_attrs(attrs: &[Attribute]) -> Vec<Attribute> {
+    attrs.iter().filter(|attr| !attr.path().is_ident("serde")).cloned().collect()
+}
+
 // !- Named struct field generator
 
 /// Generator for a single field within a named struct
@@ -64,7 +151,7 @@ impl ToTokens for GenStruct {
 pub(crate) struct GenStructField {
     pub vis: GenVisibility,
     pub name: Ident,
-    pub ty: Path,
+    pub ty: Type,
     pub attrs: Vec<Attribute>,
     pub doc: Doc,
 }
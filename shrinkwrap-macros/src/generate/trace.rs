@@ -0,0 +1,138 @@
+//! Readable summary of the resolved derive configuration, dumped via `SHRINKWRAP_TRACE` (see `util::expand_trace`)
+//!
+//! The trace is only dumped when the `expand` feature is enabled, `SHRINKWRAP_TRACE` is ignored otherwise.
+
+use quote::ToTokens;
+use std::fmt::Write;
+use syn::{Attribute, Ident};
+
+use crate::model::{DataVariant, Extra, ExtraChildVariant, ModelTree, NestData, Wrapper};
+
+use super::structs::Derives;
+
+/// Rows of each table, collected while walking the model tree
+#[derive(Default)]
+struct TraceTables {
+    /// `[class, struct, derives]`
+    structs: Vec<[String; 3]>,
+    /// `[id, field, struct, extra, optional]`
+    nests: Vec<[String; 5]>,
+    /// `[target, attribute]`
+    attrs: Vec<[String; 2]>,
+}
+
+/// Renders the resolved configuration of `models` as tables of the generated structs (with derives per class), the
+/// final nest list (with optionality), and the passthrough attributes routed to each struct and field
+pub(crate) fn render_trace(models: &ModelTree) -> String {
+    let mut tables = TraceTables::default();
+    walk_wrapper(&models.origin_wrapper, &mut tables);
+
+    let mut out = String::new();
+    let _ = writeln!(out, "Structs");
+    render_table(&["class", "struct", "derives"], &tables.structs, &mut out);
+    let _ = writeln!(out, "\nNests");
+    if tables.nests.is_empty() {
+        let _ = writeln!(out, "  (none)");
+    } else {
+        render_table(&["id", "field", "struct", "extra", "optional"], &tables.nests, &mut out);
+    }
+    let _ = writeln!(out, "\nAttributes");
+    if tables.attrs.is_empty() {
+        let _ = writeln!(out, "  (none)");
+    } else {
+        render_table(&["target", "attribute"], &tables.attrs, &mut out);
+    }
+    out
+}
+
+fn walk_wrapper(wrapper: &Wrapper, tables: &mut TraceTables) {
+    push_struct(&mut tables.structs, "wrapper", &wrapper.ident, &wrapper.derives);
+    push_attrs(&mut tables.attrs, wrapper.ident.to_string(), &wrapper.attrs);
+    push_attrs(&mut tables.attrs, format!("{}.{}", wrapper.ident, wrapper.data_name), &wrapper.data_attrs);
+    push_attrs(&mut tables.attrs, format!("{}.{}", wrapper.ident, wrapper.extra_name), &wrapper.extra_attrs);
+    if let DataVariant::Nest(nest) = &wrapper.data {
+        walk_nest(nest, tables);
+    }
+    walk_extra(&wrapper.extra, tables);
+}
+
+fn walk_extra(extra: &Extra, tables: &mut TraceTables) {
+    push_struct(&mut tables.structs, "extra", &extra.ident, &extra.derives);
+    push_attrs(&mut tables.attrs, extra.ident.to_string(), &extra.attrs);
+    for field in &extra.fields {
+        // chained nests are held through their own wrapper
+        let nest_ident = match &field.object {
+            ExtraChildVariant::Nest(nest) => &nest.ident,
+            ExtraChildVariant::Wrapper(wrapper) => wrapper.data.ident(),
+        };
+        tables.nests.push([
+            field.nest_id().to_string(),
+            field.name.to_string(),
+            nest_ident.to_string(),
+            extra.ident.to_string(),
+            yes_no(field.optional),
+        ]);
+        push_attrs(&mut tables.attrs, format!("{}.{}", extra.ident, field.name), &field.attrs);
+        match &field.object {
+            ExtraChildVariant::Nest(nest) => walk_nest(nest, tables),
+            ExtraChildVariant::Wrapper(wrapper) => walk_wrapper(wrapper, tables),
+        }
+    }
+}
+
+fn walk_nest(nest: &NestData, tables: &mut TraceTables) {
+    push_struct(&mut tables.structs, "nest", &nest.ident, &nest.derives);
+    push_attrs(&mut tables.attrs, nest.ident.to_string(), &nest.attrs);
+    for field in &nest.fields {
+        push_attrs(&mut tables.attrs, format!("{}.{}", nest.ident, field.name), &field.attrs);
+    }
+}
+
+fn push_struct(rows: &mut Vec<[String; 3]>, class: &str, ident: &Ident, derives: &Derives) {
+    let derives = derives.paths().iter().map(tokens_string).collect::<Vec<_>>().join(", ");
+    rows.push([class.to_string(), ident.to_string(), derives]);
+}
+
+fn push_attrs(rows: &mut Vec<[String; 2]>, target: String, attrs: &[Attribute]) {
+    for attr in attrs {
+        rows.push([target.clone(), format!("#[{}]", tokens_string(&attr.meta))]);
+    }
+}
+
+fn yes_no(value: bool) -> String {
+    match value {
+        true => "yes".to_string(),
+        false => "no".to_string(),
+    }
+}
+
+/// Stringifies tokens, dropping the whitespace `TokenStream`'s Display inserts around punctuation
+fn tokens_string(tokens: &impl ToTokens) -> String {
+    tokens.to_token_stream().to_string()
+        .replace(" :: ", "::")
+        .replace(":: ", "::")
+        .replace(" (", "(")
+        .replace("( ", "(")
+        .replace(" )", ")")
+        .replace(" ,", ",")
+        .replace(" < ", "<")
+        .replace(" >", ">")
+}
+
+/// Writes `rows` below `header` as left-aligned columns, each line indented by two spaces
+fn render_table<const N: usize>(header: &[&str; N], rows: &[[String; N]], out: &mut String) {
+    let mut widths = header.map(str::len);
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+    let mut write_row = |cells: [&str; N]| {
+        let line = cells.iter().zip(widths).map(|(cell, width)| format!("{cell:width$}")).collect::<Vec<_>>().join("  ");
+        let _ = writeln!(out, "  {}", line.trim_end());
+    };
+    write_row(*header);
+    for row in rows {
+        write_row(row.each_ref().map(String::as_str));
+    }
+}
@@ -0,0 +1,94 @@
+use super::*;
+
+// !- GenAnyTransform
+
+/// Generates the `transforms(..)` enum, dispatching the nest traits to the transform selected at runtime
+#[derive(Debug, Clone)]
+pub(crate) struct GenAnyTransform {
+    /// Ident of the generated enum
+    pub(crate) ident: Ident,
+
+    /// Variant ident -> transform type, the first transform determines the options (and error) type
+    pub(crate) transforms: Vec<(Ident, Path)>,
+
+    /// Whether `TryTransformToNest` is forwarded as well
+    pub(crate) fallible: bool,
+}
+impl ToTokens for GenAnyTransform {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self { ident, transforms, fallible } = &self;
+        let Some((_, first)) = transforms.first() else {
+            return;
+        };
+        let others = transforms.iter().skip(1).map(|(_, path)| path).collect::<Vec<_>>();
+        let variants = transforms.iter().map(|(variant, _)| variant).collect::<Vec<_>>();
+        let paths = transforms.iter().map(|(_, path)| path).collect::<Vec<_>>();
+        let doc = format!(" Transform selected at runtime, dispatching to one of {}", transforms.iter()
+            .map(|(variant, _)| format!("`{variant}`"))
+            .collect::<Vec<_>>()
+            .join(", "));
+
+        tokens.extend(quote! {
+            #[doc = #doc]
+            pub enum #ident {
+                #( #variants(#paths), )*
+            }
+            #(
+                #[automatically_derived]
+                impl ::std::convert::From<#paths> for #ident {
+                    fn from(transform: #paths) -> Self {
+                        Self::#variants(transform)
+                    }
+                }
+            )*
+            #[automatically_derived]
+            impl ::shrinkwrap::__private::Transform2 for #ident {
+                type Options<'a> = <#first as ::shrinkwrap::__private::Transform2>::Options<'a>;
+            }
+            #[automatically_derived]
+            impl<N> ::shrinkwrap::__private::TransformToNest2<N> for #ident
+            where
+                #first: ::shrinkwrap::__private::TransformToNest2<N>,
+                #(
+                    #others: ::shrinkwrap::__private::TransformToNest2<N, Data = <#first as ::shrinkwrap::__private::TransformToNest2<N>>::Data>
+                        + for<'a> ::shrinkwrap::__private::Transform2<Options<'a> = <#first as ::shrinkwrap::__private::Transform2>::Options<'a>>,
+                )*
+            {
+                type Data = <#first as ::shrinkwrap::__private::TransformToNest2<N>>::Data;
+
+                fn transform_to_nest(&self, data: &Self::Data, options: &Self::Options<'_>) -> N {
+                    match self {
+                        #( Self::#variants(transform) => ::shrinkwrap::__private::TransformToNest2::transform_to_nest(transform, data, options), )*
+                    }
+                }
+            }
+        });
+        if *fallible {
+            tokens.extend(quote! {
+                #[automatically_derived]
+                impl<N> ::shrinkwrap::__private::TryTransformToNest2<N> for #ident
+                where
+                    #first: ::shrinkwrap::__private::TryTransformToNest2<N>,
+                    #(
+                        #others: ::shrinkwrap::__private::TryTransformToNest2<
+                            N,
+                            Data = <#first as ::shrinkwrap::__private::TryTransformToNest2<N>>::Data,
+                            Error = <#first as ::shrinkwrap::__private::TryTransformToNest2<N>>::Error,
+                        > + for<'a> ::shrinkwrap::__private::Transform2<Options<'a> = <#first as ::shrinkwrap::__private::Transform2>::Options<'a>>,
+                    )*
+                {
+                    type Data = <#first as ::shrinkwrap::__private::TryTransformToNest2<N>>::Data;
+                    type Error = <#first as ::shrinkwrap::__private::TryTransformToNest2<N>>::Error;
+
+                    fn try_transform_to_nest(&self, data: &Self::Data, options: &Self::Options<'_>) -> ::std::result::Result<N, Self::Error> {
+                        match self {
+                            #( Self::#variants(transform) => {
+                                ::shrinkwrap::__private::TryTransformToNest2::try_transform_to_nest(transform, data, options)
+                            }, )*
+                        }
+                    }
+                }
+            });
+        }
+    }
+}
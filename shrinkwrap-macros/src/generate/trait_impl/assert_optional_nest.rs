@@ -0,0 +1,48 @@
+use super::*;
+use proc_macro2::Span;
+
+// !- GenAssertOptionalNest
+
+/// Generates a definition-site assertion that the transform implements the `Option<Nest>` variant of the nest trait
+#[derive(Debug, Clone)]
+pub(crate) struct GenAssertOptionalNest {
+    /// Fallibility of the nest trait
+    pub(crate) fallibility: Fallibility,
+
+    /// The type of the user-defined struct implementing [`shrinkwrap::Transform`]
+    pub(crate) transform_type: Path,
+
+    /// Ident of the nest struct
+    pub(crate) nest_struct_ident: Ident,
+
+    /// Full type of the sibling nest required to build the nest, if any
+    pub(crate) sibling_type: Option<Type>,
+
+    /// Span of the nest definition, errors are reported here
+    pub(crate) span: Span,
+}
+impl ToTokens for GenAssertOptionalNest {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self { transform_type, nest_struct_ident, .. } = &self;
+        let assert_trait = match &self.sibling_type {
+            Some(sibling_type) => {
+                let trait_name = self.fallibility.trait_name(format_ident!("ImplementsOptionalNestWithSiblings"));
+                quote!(#trait_name<#nest_struct_ident, #sibling_type>)
+            },
+            None => {
+                let trait_name = self.fallibility.trait_name(format_ident!("ImplementsOptionalNest"));
+                quote!(#trait_name<#nest_struct_ident>)
+            },
+        };
+        // alias the transform so the error is reported at the nest definition rather than the transform type
+        let transform_alias = Ident::new("AssertedTransform", self.span);
+
+        tokens.extend(quote! {
+            const _: () = {
+                type #transform_alias = #transform_type;
+                fn assert_optional_nest<T: ?Sized + ::shrinkwrap::__private::#assert_trait>() {}
+                let _ = assert_optional_nest::<#transform_alias>;
+            };
+        });
+    }
+}
@@ -0,0 +1,29 @@
+use super::*;
+use proc_macro2::Span;
+
+// !- GenAssertThreadsafe
+
+/// Generates a definition-site assertion that a generated struct is `Send + Sync + 'static`
+#[derive(Debug, Clone)]
+pub(crate) struct GenAssertThreadsafe {
+    /// Ident of the generated struct
+    pub(crate) ident: Ident,
+
+    /// Span of the `assert_threadsafe` flag, errors are reported here
+    pub(crate) span: Span,
+}
+impl ToTokens for GenAssertThreadsafe {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self { ident, .. } = &self;
+        // alias the struct so the error is reported at the `assert_threadsafe` flag rather than the derive
+        let alias = Ident::new("AssertedThreadsafe", self.span);
+
+        tokens.extend(quote! {
+            const _: () = {
+                type #alias = #ident;
+                fn assert_threadsafe<T: ?Sized + ::shrinkwrap::__private::ImplementsThreadsafe>() {}
+                let _ = assert_threadsafe::<#alias>;
+            };
+        });
+    }
+}
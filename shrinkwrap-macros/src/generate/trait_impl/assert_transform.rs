@@ -0,0 +1,26 @@
+use super::*;
+use syn::spanned::Spanned;
+
+// !- GenAssertTransform
+
+/// Generates a definition-site assertion that the transform type implements [`shrinkwrap::Transform`]
+#[derive(Debug, Clone)]
+pub(crate) struct GenAssertTransform {
+    /// The type of the user-defined struct implementing [`shrinkwrap::Transform`]
+    pub(crate) transform_type: Path,
+}
+impl ToTokens for GenAssertTransform {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self { transform_type } = &self;
+        // alias the transform so the error is reported at the `transform` attribute
+        let transform_alias = Ident::new("AssertedTransform", transform_type.span());
+
+        tokens.extend(quote! {
+            const _: () = {
+                type #transform_alias = #transform_type;
+                fn assert_transform<T: ?Sized + ::shrinkwrap::__private::ImplementsTransform>() {}
+                let _ = assert_transform::<#transform_alias>;
+            };
+        });
+    }
+}
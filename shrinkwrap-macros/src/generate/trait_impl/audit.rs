@@ -0,0 +1,47 @@
+use super::*;
+
+// !- GenAudit
+
+/// Generates the `WRAP_MAPPED_FIELDS` constant on the origin data struct, along with a deprecation warning for each
+/// origin field which isn't mapped to any nest
+#[derive(Debug, Clone)]
+pub(crate) struct GenAudit {
+    /// Ident of the origin data struct
+    pub(crate) data_ident: Ident,
+
+    /// Origin fields assigned to (or selected by) at least one nest
+    pub(crate) mapped: Vec<Ident>,
+
+    /// Origin fields neither mapped nor marked `unmapped`, warnings are reported at each field
+    pub(crate) unmapped: Vec<Ident>,
+}
+impl ToTokens for GenAudit {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self { data_ident, mapped, unmapped } = &self;
+        let mapped = mapped.iter().map(|field| field.unraw().to_string());
+
+        tokens.extend(quote! {
+            #[automatically_derived]
+            impl #data_ident {
+                /// Origin fields mapped to nests
+                #[doc(hidden)]
+                pub const WRAP_MAPPED_FIELDS: &'static [&'static str] = &[ #( #mapped ),* ];
+            }
+        });
+        for field in unmapped {
+            let note = format!(
+                "field `{}` of `{data_ident}` isn't mapped to any nest, assign it to a nest or mark it `#[shrinkwrap(unmapped)]`",
+                field.unraw(),
+            );
+            // the unit struct is named (and used) at the field so that the warning is reported there
+            tokens.extend(quote! {
+                const _: () = {
+                    #[deprecated(note = #note)]
+                    #[allow(non_camel_case_types, dead_code)]
+                    struct #field;
+                    let _ = #field;
+                };
+            });
+        }
+    }
+}
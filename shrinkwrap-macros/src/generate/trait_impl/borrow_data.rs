@@ -0,0 +1,39 @@
+use super::*;
+
+// !- GenBorrowData
+
+/// Generates `Borrow<Data>` for the primary wrapper, along with a `to_owned_data` helper
+#[derive(Debug, Clone)]
+pub(crate) struct GenBorrowData {
+    /// Ident of the primary wrapper
+    pub(crate) ident: Ident,
+
+    /// Ident of the origin data struct
+    pub(crate) data_ident: Ident,
+
+    /// Name of the wrapper's data field
+    pub(crate) data_name: Ident,
+}
+impl ToTokens for GenBorrowData {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self { ident, data_ident, data_name } = &self;
+
+        tokens.extend(quote! {
+            #[automatically_derived]
+            impl ::std::borrow::Borrow<#data_ident> for #ident {
+                fn borrow(&self) -> &#data_ident {
+                    &self.#data_name
+                }
+            }
+
+            #[automatically_derived]
+            #[allow(private_interfaces)]
+            impl #ident {
+                /// Returns a clone of the wrapped data
+                pub fn to_owned_data(&self) -> #data_ident {
+                    ::std::clone::Clone::clone(&self.#data_name)
+                }
+            }
+        });
+    }
+}
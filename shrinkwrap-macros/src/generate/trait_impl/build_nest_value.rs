@@ -6,7 +6,7 @@ use super::*;
 pub(crate) struct BuildNestValueTrait;
 
 impl TransformTrait for BuildNestValueTrait {
-    fn trait_name() -> Ident { format_ident!("BuildNestValue") }
+    fn trait_name() -> Ident { format_ident!("BuildNestValue2") }
     fn trait_fn() -> Ident { format_ident!("build_nest_value") }
 }
 
@@ -0,0 +1,64 @@
+use super::*;
+
+// !- GenCsvRecord
+
+/// Generates inherent CSV row projection helpers on the primary wrapper
+#[derive(Debug, Clone)]
+pub(crate) struct GenCsvRecord {
+    /// Ident of the primary wrapper
+    pub(crate) ident: Ident,
+
+    /// Columns, in output order
+    pub(crate) columns: Vec<GenCsvColumn>,
+}
+impl ToTokens for GenCsvRecord {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let ident = &self.ident;
+        let headers = self.columns.iter().map(|column| &column.header);
+        let values = &self.columns;
+
+        tokens.extend(quote! {
+            #[automatically_derived]
+            impl #ident {
+                /// CSV column names, matching the order of [`Self::to_csv_record`]
+                pub const fn csv_headers() -> &'static [&'static str] {
+                    &[ #( #headers ),* ]
+                }
+
+                /// Flattens the wrapper into a single CSV record, missing (optional) nests are
+                /// represented by empty values
+                ///
+                /// Fails if a value cannot be serialized, e.g. a map with non-string keys.
+                pub fn to_csv_record(
+                    &self,
+                ) -> ::std::result::Result<::std::vec::Vec<::std::string::String>, ::shrinkwrap::__private::Error> {
+                    ::std::result::Result::Ok(::std::vec![ #( #values ),* ])
+                }
+            }
+        });
+    }
+}
+
+/// A single column within a [`GenCsvRecord`]
+#[derive(Debug, Clone)]
+pub(crate) struct GenCsvColumn {
+    /// Column name
+    pub(crate) header: String,
+
+    /// Expression of type `Option<&Struct>` for the struct containing the field
+    pub(crate) parent: TokenStream,
+
+    /// Field name within the parent struct
+    pub(crate) field: Ident,
+}
+impl ToTokens for GenCsvColumn {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self { parent, field, .. } = &self;
+        tokens.extend(quote! {
+            match #parent {
+                ::std::option::Option::Some(parent) => ::shrinkwrap::__private::render_value(&parent.#field)?,
+                ::std::option::Option::None => ::std::string::String::new(),
+            }
+        });
+    }
+}
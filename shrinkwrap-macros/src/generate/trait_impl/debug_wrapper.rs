@@ -0,0 +1,101 @@
+use super::*;
+
+// !- GenDebugWrapper
+
+/// Generates the debug wrapper of the primary wrapper (see `debug_wrapper`), holding the wrapper along with its
+/// wrapping diagnostics
+#[derive(Debug, Clone)]
+pub(crate) struct GenDebugWrapper {
+    pub(crate) variant: ToWrappedWithVariant,
+
+    /// Ident of the generated debug wrapper
+    pub(crate) ident: Ident,
+
+    /// Ident of the primary wrapper
+    pub(crate) wrapper_ident: Ident,
+
+    /// Ident of the origin data struct
+    pub(crate) data_ident: Ident,
+
+    /// Name of the wrapper's extra field
+    pub(crate) extra_name: Ident,
+
+    /// Nests of the primary extra struct, in field order
+    pub(crate) nests: Vec<GenDiagnosedNest>,
+}
+
+/// A nest of the primary extra struct listed within the diagnostics
+#[derive(Debug, Clone)]
+pub(crate) struct GenDiagnosedNest {
+    /// Name of the nest's field within the extra struct
+    pub(crate) name: Ident,
+
+    /// Nest ID
+    pub(crate) id: String,
+
+    pub(crate) optional: bool,
+}
+impl ToTokens for GenDebugWrapper {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self { variant, ident, wrapper_ident, data_ident, extra_name, nests } = &self;
+        let trait_name = variant.trait_name();
+        let trait_fn = variant.trait_fn();
+        let wrap_fn = variant.fallibility.trait_fn(format_ident!("wrap_with"));
+        let (return_type, wrapper, debug_wrapper) = match variant.is_fallible() {
+            true => (
+                quote!(::std::result::Result<Self, <#data_ident as ::shrinkwrap::__private::#trait_name<T>>::Error>),
+                quote!(let wrapper = wrapper?;),
+                quote!(::std::result::Result::Ok(Self { wrapper, diagnostics })),
+            ),
+            false => (quote!(Self), quote!(), quote!(Self { wrapper, diagnostics })),
+        };
+        let presence = nests.iter().map(|GenDiagnosedNest { name, id, optional }| match optional {
+            true => quote!((#id, wrapper.#extra_name.#name.is_some())),
+            false => quote!((#id, true)),
+        });
+        let doc = format!(" [`{wrapper_ident}`] along with its wrapping diagnostics, see `shrinkwrap::diagnostics`");
+        let wrap_doc = format!(" Wraps `data`, recording diagnostics of each nest within [`{wrapper_ident}`]'s extra struct");
+
+        tokens.extend(quote! {
+            #[automatically_derived]
+            #[doc = #doc]
+            #[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+            pub struct #ident {
+                /// The primary wrapper
+                #[serde(flatten)]
+                pub wrapper: #wrapper_ident,
+                /// Diagnostics of the wrapping
+                pub diagnostics: ::shrinkwrap::__private::WrapDiagnostics,
+            }
+
+            #[automatically_derived]
+            impl #ident {
+                #[doc = #wrap_doc]
+                pub fn #wrap_fn<T>(data: #data_ident, transform: &T, options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>) -> #return_type
+                where
+                    T: ::shrinkwrap::__private::Transform2,
+                    #data_ident: ::shrinkwrap::__private::#trait_name<T, Wrapper = #wrapper_ident>,
+                {
+                    let (wrapper, timings) = ::shrinkwrap::__private::collect_timings(|| {
+                        ::shrinkwrap::__private::#trait_name::#trait_fn(data, transform, options)
+                    });
+                    #wrapper
+                    let diagnostics = ::shrinkwrap::__private::WrapDiagnostics::new([#( #presence ),*], &timings);
+                    #debug_wrapper
+                }
+
+                /// Discards the diagnostics
+                pub fn into_wrapper(self) -> #wrapper_ident {
+                    self.wrapper
+                }
+            }
+
+            #[automatically_derived]
+            impl ::std::convert::From<#ident> for #wrapper_ident {
+                fn from(debug_wrapper: #ident) -> Self {
+                    debug_wrapper.wrapper
+                }
+            }
+        });
+    }
+}
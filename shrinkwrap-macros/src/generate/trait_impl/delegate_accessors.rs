@@ -0,0 +1,70 @@
+use super::*;
+use syn::ext::IdentExt;
+
+// !- GenDelegateAccessors
+
+/// Generates accessors on the primary wrapper, delegating through its `extra` struct to each nest and nest field
+#[derive(Debug, Clone)]
+pub(crate) struct GenDelegateAccessors {
+    /// Ident of the primary wrapper
+    pub(crate) ident: Ident,
+
+    /// Name of the wrapper's extra field
+    pub(crate) extra_name: Ident,
+
+    pub(crate) accessors: Vec<GenDelegateAccessor>,
+}
+impl ToTokens for GenDelegateAccessors {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self { ident, extra_name, .. } = &self;
+        let accessors = self.accessors.iter().map(|accessor| {
+            let GenDelegateAccessor { name, ty, extra_field, projection, optional } = accessor;
+            let path = [extra_name, extra_field].into_iter().chain(projection)
+                .map(|segment| segment.unraw().to_string())
+                .collect::<Vec<_>>();
+            let doc = format!("Returns `{}`", path.join("."));
+
+            let (return_type, body) = match (optional, projection.is_empty()) {
+                (true, false) => (
+                    quote!(Option<&#ty>),
+                    quote!(self.#extra_name.#extra_field.as_ref().map(|nest| &nest #( .#projection )*)),
+                ),
+                (true, true) => (quote!(Option<&#ty>), quote!(self.#extra_name.#extra_field.as_ref())),
+                (false, _) => (quote!(&#ty), quote!(&self.#extra_name.#extra_field #( .#projection )*)),
+            };
+            quote! {
+                #[doc = #doc]
+                pub fn #name(&self) -> #return_type {
+                    #body
+                }
+            }
+        });
+
+        tokens.extend(quote! {
+            #[automatically_derived]
+            #[allow(private_interfaces)]
+            impl #ident {
+                #( #accessors )*
+            }
+        });
+    }
+}
+
+/// A single accessor, returning either an `extra` field or a field within it
+#[derive(Debug, Clone)]
+pub(crate) struct GenDelegateAccessor {
+    /// Name of the accessor
+    pub(crate) name: Ident,
+
+    /// Type of the returned value, excluding `Option`
+    pub(crate) ty: Type,
+
+    /// Name of the field within `extra`
+    pub(crate) extra_field: Ident,
+
+    /// Path of the returned field within the `extra` field, empty to return the `extra` field itself
+    pub(crate) projection: Vec<Ident>,
+
+    /// Whether the `extra` field is optional
+    pub(crate) optional: bool,
+}
@@ -0,0 +1,58 @@
+use super::*;
+
+// !- GenDeprecatedAccessors
+
+/// Generates `#[deprecated]` accessors on an extra struct for the nests superseded via `deprecates`
+#[derive(Debug, Clone)]
+pub(crate) struct GenDeprecatedAccessors {
+    /// Ident of the extra struct
+    pub(crate) ident: Ident,
+
+    pub(crate) accessors: Vec<GenDeprecatedAccessor>,
+}
+impl ToTokens for GenDeprecatedAccessors {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self { ident, accessors } = &self;
+        let accessors = accessors.iter().map(|accessor| {
+            let GenDeprecatedAccessor { name, ty, superseded_by, optional } = accessor;
+            let doc = format!("Returns the `{}` nest", name.unraw());
+            let note = format!("superseded by `{}`", superseded_by.unraw());
+
+            let (return_type, body) = match optional {
+                true => (quote!(Option<&#ty>), quote!(self.#name.as_ref())),
+                false => (quote!(&#ty), quote!(&self.#name)),
+            };
+            quote! {
+                #[doc = #doc]
+                #[deprecated(note = #note)]
+                pub fn #name(&self) -> #return_type {
+                    #body
+                }
+            }
+        });
+
+        tokens.extend(quote! {
+            #[automatically_derived]
+            #[allow(private_interfaces)]
+            impl #ident {
+                #( #accessors )*
+            }
+        });
+    }
+}
+
+/// Accessor of a single superseded nest
+#[derive(Debug, Clone)]
+pub(crate) struct GenDeprecatedAccessor {
+    /// Name of the extra field holding the superseded nest, also used for the accessor
+    pub(crate) name: Ident,
+
+    /// Type of the nest, excluding `Option`
+    pub(crate) ty: Ident,
+
+    /// Name of the extra field holding the superseding nest
+    pub(crate) superseded_by: Ident,
+
+    /// Whether the extra field is optional
+    pub(crate) optional: bool,
+}
@@ -0,0 +1,205 @@
+use super::*;
+
+use syn::ext::IdentExt;
+
+// !- GenDeserialize
+
+/// Generates a `serde::Deserialize` impl for a wrapper with flattened data,
+/// used in place of the derived impl (which relies on `#[serde(flatten)]`)
+///
+/// Supports both sequence (fields in serialized order) and map
+/// representations, matching the output of [`GenSerialize`](super::GenSerialize).
+#[derive(Debug, Clone)]
+pub(crate) struct GenDeserialize {
+    /// Ident of the wrapper struct
+    pub(crate) ident: Ident,
+
+    /// Name and type of the wrapper's extra field
+    pub(crate) extra_name: Ident,
+    pub(crate) extra_ident: Ident,
+    /// Serialized key of the extra field
+    pub(crate) extra_key: String,
+    /// A missing extra field is defaulted rather than an error
+    pub(crate) extra_default: bool,
+
+    /// Name and type of the wrapper's data field
+    pub(crate) data_name: Ident,
+    pub(crate) data_ident: Ident,
+    /// Data fields
+    pub(crate) data_fields: Vec<GenDeserializeField>,
+
+    /// Serialized fields are expected in lexicographic order of their keys
+    pub(crate) sort_keys: bool,
+
+    /// Unknown keys are rejected rather than ignored
+    pub(crate) deny_unknown_fields: bool,
+}
+impl GenDeserialize {
+    /// (key, binding, type, missing value) for each serialized field, in serialized order
+    fn entries(&self) -> Vec<(String, Ident, TokenStream, TokenStream)> {
+        let extra_ident = &self.extra_ident;
+        let extra_key = &self.extra_key;
+        let extra_missing = match self.extra_default {
+            true => quote!(::std::default::Default::default()),
+            false => quote!(return ::std::result::Result::Err(::serde::de::Error::missing_field(#extra_key))),
+        };
+        let mut entries = vec![(
+            self.extra_key.clone(),
+            format_ident!("__field_{}", self.extra_name.unraw()),
+            quote!(#extra_ident),
+            extra_missing,
+        )];
+        for field in self.data_fields.iter().filter(|field| field.serialized) {
+            let (ty, missing) = match field.deserialized {
+                true => (field.ty.to_token_stream(), field.missing_value()),
+                false => (quote!(::serde::de::IgnoredAny), quote!(::serde::de::IgnoredAny)),
+            };
+            entries.push((field.key.clone(), field.binding(), ty, missing));
+        }
+        if self.sort_keys {
+            entries.sort_by(|a, b| a.0.cmp(&b.0));
+        }
+        entries
+    }
+}
+impl ToTokens for GenDeserialize {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self { ident, extra_name, data_name, data_ident, .. } = &self;
+        let name = ident.to_string();
+        let expecting = format!("struct {name}");
+        let entries = self.entries();
+
+        let keys = entries.iter().map(|(key, ..)| key).collect::<Vec<_>>();
+        let bindings = entries.iter().map(|(_, binding, ..)| binding).collect::<Vec<_>>();
+        let types = entries.iter().map(|(_, _, ty, _)| ty).collect::<Vec<_>>();
+        let missing = entries.iter().map(|(.., missing)| missing);
+        let indexes = 0..entries.len();
+
+        let extra_binding = format_ident!("__field_{}", extra_name.unraw());
+        let unknown_key = match self.deny_unknown_fields {
+            true => quote!(return ::std::result::Result::Err(::serde::de::Error::unknown_field(&key, FIELDS))),
+            false => quote!(map.next_value::<::serde::de::IgnoredAny>()?;),
+        };
+        let data_assignments = self.data_fields.iter().map(|field| {
+            let field_name = &field.name;
+            match field.serialized && field.deserialized {
+                true => {
+                    let binding = field.binding();
+                    quote!(#field_name: #binding,)
+                },
+                false => {
+                    let missing_value = field.missing_value();
+                    quote!(#field_name: #missing_value,)
+                },
+            }
+        });
+        let construct = quote! {
+            #ident {
+                #extra_name: #extra_binding,
+                #data_name: #data_ident {
+                    #( #data_assignments )*
+                },
+            }
+        };
+
+        tokens.extend(quote! {
+            #[automatically_derived]
+            impl<'de> ::serde::Deserialize<'de> for #ident {
+                fn deserialize<__D>(deserializer: __D) -> ::std::result::Result<Self, __D::Error>
+                where
+                    __D: ::serde::Deserializer<'de>,
+                {
+                    struct __Visitor;
+                    impl<'de> ::serde::de::Visitor<'de> for __Visitor {
+                        type Value = #ident;
+
+                        fn expecting(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                            formatter.write_str(#expecting)
+                        }
+
+                        fn visit_seq<__A>(self, mut seq: __A) -> ::std::result::Result<Self::Value, __A::Error>
+                        where
+                            __A: ::serde::de::SeqAccess<'de>,
+                        {
+                            #(
+                                let #bindings = seq.next_element::<#types>()?
+                                    .ok_or_else(|| ::serde::de::Error::invalid_length(#indexes, &self))?;
+                            )*
+                            ::std::result::Result::Ok(#construct)
+                        }
+
+                        fn visit_map<__A>(self, mut map: __A) -> ::std::result::Result<Self::Value, __A::Error>
+                        where
+                            __A: ::serde::de::MapAccess<'de>,
+                        {
+                            #( let mut #bindings: ::std::option::Option<#types> = ::std::option::Option::None; )*
+                            while let ::std::option::Option::Some(key) = map.next_key::<::std::string::String>()? {
+                                match key.as_str() {
+                                    #(
+                                        #keys => {
+                                            if #bindings.is_some() {
+                                                return ::std::result::Result::Err(::serde::de::Error::duplicate_field(#keys));
+                                            }
+                                            #bindings = ::std::option::Option::Some(map.next_value()?);
+                                        },
+                                    )*
+                                    _ => {
+                                        #unknown_key
+                                    },
+                                }
+                            }
+                            #(
+                                let #bindings = match #bindings {
+                                    ::std::option::Option::Some(value) => value,
+                                    ::std::option::Option::None => #missing,
+                                };
+                            )*
+                            ::std::result::Result::Ok(#construct)
+                        }
+                    }
+
+                    const FIELDS: &[&str] = &[ #( #keys ),* ];
+                    deserializer.deserialize_struct(#name, FIELDS, __Visitor)
+                }
+            }
+        });
+    }
+}
+
+/// A single data field within a [`GenDeserialize`] impl
+#[derive(Debug, Clone)]
+pub(crate) struct GenDeserializeField {
+    /// Field name within the data struct
+    pub(crate) name: Ident,
+
+    /// Serialized key name
+    pub(crate) key: String,
+
+    /// Field type
+    pub(crate) ty: Type,
+
+    /// Whether the field is present in the serialized output
+    pub(crate) serialized: bool,
+
+    /// Whether the serialized value is used, if unset (or not serialized) the field is defaulted
+    pub(crate) deserialized: bool,
+
+    /// Whether a missing key falls back to the default value (`#[serde(default)]` or `Option<T>`)
+    pub(crate) default: bool,
+
+    /// Path provided via `#[serde(default = "..")]`
+    pub(crate) default_fn: Option<Path>,
+}
+impl GenDeserializeField {
+    fn binding(&self) -> Ident {
+        format_ident!("__field_{}", self.name.unraw())
+    }
+    fn missing_value(&self) -> TokenStream {
+        let key = &self.key;
+        match (&self.default_fn, self.default || !self.deserialized) {
+            (Some(default_fn), _) => quote!(#default_fn()),
+            (None, true) => quote!(::std::default::Default::default()),
+            (None, false) => quote!(return ::std::result::Result::Err(::serde::de::Error::missing_field(#key))),
+        }
+    }
+}
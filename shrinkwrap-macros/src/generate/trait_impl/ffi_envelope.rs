@@ -0,0 +1,77 @@
+use super::*;
+
+// !- GenFfiEnvelope
+
+/// Generates a `#[repr(C)]` envelope of the primary wrapper, along with conversions to and from the wrapper
+#[derive(Debug, Clone)]
+pub(crate) struct GenFfiEnvelope {
+    /// Ident of the primary wrapper
+    pub(crate) ident: Ident,
+
+    /// Ident of the generated envelope
+    pub(crate) ffi_ident: Ident,
+
+    /// Ident of the origin data struct
+    pub(crate) data_ident: Ident,
+
+    /// Name of the wrapper's data field
+    pub(crate) data_name: Ident,
+
+    /// Ident of the primary extra struct
+    pub(crate) extra_ident: Ident,
+
+    /// Name of the wrapper's extra field
+    pub(crate) extra_name: Ident,
+}
+impl ToTokens for GenFfiEnvelope {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self { ident, ffi_ident, data_ident, data_name, extra_ident, extra_name } = &self;
+        let doc = format!(" `#[repr(C)]` envelope of [`{ident}`] for FFI boundaries, the extra struct is held behind an owned opaque pointer");
+
+        tokens.extend(quote! {
+            #[automatically_derived]
+            #[doc = #doc]
+            #[repr(C)]
+            pub struct #ffi_ident {
+                /// The wrapped data
+                pub #data_name: #data_ident,
+                /// Non-null, owned pointer to the extra struct, opaque to foreign code
+                #extra_name: ::std::boxed::Box<#extra_ident>,
+            }
+
+            #[automatically_derived]
+            #[allow(private_interfaces)]
+            impl #ffi_ident {
+                /// Returns the extra struct
+                pub fn #extra_name(&self) -> &#extra_ident {
+                    &self.#extra_name
+                }
+
+                /// Converts the envelope back into the serializable wrapper
+                pub fn into_wrapper(self) -> #ident {
+                    #ident {
+                        #data_name: self.#data_name,
+                        #extra_name: *self.#extra_name,
+                    }
+                }
+            }
+
+            #[automatically_derived]
+            impl ::std::convert::From<#ident> for #ffi_ident {
+                fn from(wrapper: #ident) -> Self {
+                    Self {
+                        #data_name: wrapper.#data_name,
+                        #extra_name: ::std::boxed::Box::new(wrapper.#extra_name),
+                    }
+                }
+            }
+
+            #[automatically_derived]
+            impl ::std::convert::From<#ffi_ident> for #ident {
+                fn from(envelope: #ffi_ident) -> Self {
+                    envelope.into_wrapper()
+                }
+            }
+        });
+    }
+}
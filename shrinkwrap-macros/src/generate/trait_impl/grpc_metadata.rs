@@ -0,0 +1,61 @@
+use super::*;
+
+// !- GenGrpcMetadata
+
+/// Generates the `prost_types::Struct` conversion of the primary extra struct, along with `to_grpc_metadata` on the
+/// primary wrapper
+#[derive(Debug, Clone)]
+pub(crate) struct GenGrpcMetadata {
+    /// Ident of the primary wrapper
+    pub(crate) ident: Ident,
+
+    /// Ident of the primary extra struct
+    pub(crate) extra_ident: Ident,
+
+    /// Name of the wrapper's extra field
+    pub(crate) extra_name: Ident,
+}
+impl ToTokens for GenGrpcMetadata {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self { ident, extra_ident, extra_name } = &self;
+
+        tokens.extend(quote! {
+            #[automatically_derived]
+            impl ::std::convert::TryFrom<&#extra_ident> for ::shrinkwrap::__private::prost_types::Struct {
+                type Error = ::shrinkwrap::__private::Error;
+
+                fn try_from(extra: &#extra_ident) -> ::std::result::Result<Self, Self::Error> {
+                    fn to_value(kind: ::shrinkwrap::__private::ProtoKind) -> ::shrinkwrap::__private::prost_types::Value {
+                        use ::shrinkwrap::__private::prost_types::value::Kind;
+                        use ::shrinkwrap::__private::ProtoKind;
+                        let kind = match kind {
+                            ProtoKind::Null => Kind::NullValue(::shrinkwrap::__private::prost_types::NullValue::NullValue as i32),
+                            ProtoKind::Number(number) => Kind::NumberValue(number),
+                            ProtoKind::String(string) => Kind::StringValue(string),
+                            ProtoKind::Bool(boolean) => Kind::BoolValue(boolean),
+                            ProtoKind::Struct(fields) => Kind::StructValue(::shrinkwrap::__private::prost_types::Struct {
+                                fields: fields.into_iter().map(|(key, value)| (key, to_value(value))).collect(),
+                            }),
+                            ProtoKind::List(values) => Kind::ListValue(::shrinkwrap::__private::prost_types::ListValue {
+                                values: values.into_iter().map(to_value).collect(),
+                            }),
+                        };
+                        ::shrinkwrap::__private::prost_types::Value { kind: ::std::option::Option::Some(kind) }
+                    }
+                    let fields = ::shrinkwrap::__private::proto_fields(extra)?;
+                    ::std::result::Result::Ok(Self {
+                        fields: fields.into_iter().map(|(key, value)| (key, to_value(value))).collect(),
+                    })
+                }
+            }
+
+            #[automatically_derived]
+            impl #ident {
+                /// Converts the extra struct into a `google.protobuf.Struct`, e.g. for gRPC trailers/metadata
+                pub fn to_grpc_metadata(&self) -> ::std::result::Result<::shrinkwrap::__private::prost_types::Struct, ::shrinkwrap::__private::Error> {
+                    ::shrinkwrap::__private::prost_types::Struct::try_from(&self.#extra_name)
+                }
+            }
+        });
+    }
+}
@@ -0,0 +1,36 @@
+use super::*;
+use syn::LitStr;
+
+// !- GenHttpResponse
+
+/// Generates `From<Wrapper>` for `http::Response<B>` on the primary wrapper
+#[derive(Debug, Clone)]
+pub(crate) struct GenHttpResponse {
+    /// Ident of the primary wrapper
+    pub(crate) ident: Ident,
+
+    /// Content type of the response
+    pub(crate) content_type: LitStr,
+
+    /// Path to a `fn(&Wrapper) -> http::StatusCode`, `200 OK` if unset
+    pub(crate) status: Option<Path>,
+}
+impl ToTokens for GenHttpResponse {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self { ident, content_type, .. } = &self;
+        let status = match &self.status {
+            Some(status) => quote!(#status(&wrapper)),
+            None => quote!(::shrinkwrap::__private::HttpStatusCode::OK),
+        };
+
+        tokens.extend(quote! {
+            #[automatically_derived]
+            impl<B: ::std::convert::From<::std::vec::Vec<u8>>> ::std::convert::From<#ident> for ::shrinkwrap::__private::HttpResponse<B> {
+                fn from(wrapper: #ident) -> Self {
+                    let status: ::shrinkwrap::__private::HttpStatusCode = #status;
+                    ::shrinkwrap::__private::http_json_response(&wrapper, #content_type, status)
+                }
+            }
+        });
+    }
+}
@@ -0,0 +1,35 @@
+use super::*;
+
+// !- GenIdentity
+
+/// Generates an identity key accessor on the primary wrapper, delegating to
+/// the data field marked with `#[shrinkwrap(id_key)]`
+#[derive(Debug, Clone)]
+pub(crate) struct GenIdentity {
+    /// Ident of the primary wrapper
+    pub(crate) ident: Ident,
+
+    /// Name of the wrapper's data field
+    pub(crate) data_name: Ident,
+
+    /// Name and type of the identity key field within the data struct
+    pub(crate) key_name: Ident,
+    pub(crate) key_type: Type,
+}
+impl ToTokens for GenIdentity {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self { ident, data_name, key_name, key_type } = &self;
+        let doc = format!("Returns the identity key of the wrapped data (`{data_name}.{key_name}`)");
+
+        tokens.extend(quote! {
+            #[automatically_derived]
+            #[allow(private_interfaces)]
+            impl #ident {
+                #[doc = #doc]
+                pub fn identity(&self) -> &#key_type {
+                    &self.#data_name.#key_name
+                }
+            }
+        });
+    }
+}
@@ -0,0 +1,228 @@
+use super::*;
+
+use crate::parse::serde_attrs::{SerdeContainerAttrs, SerdeFieldAttrs};
+
+// !- GenShape
+
+/// Generates a `shrinkwrap::shape::Shape` expression
+#[derive(Debug, Clone)]
+pub(crate) struct GenShape {
+    /// Name of the struct the shape describes
+    pub(crate) name: String,
+
+    /// Nest ID, None for origin/primary wrapper
+    pub(crate) nest_id: Option<String>,
+
+    /// Known keys
+    pub(crate) keys: Vec<GenShapeKey>,
+
+    /// Tolerate unknown keys
+    pub(crate) open: bool,
+}
+impl ToTokens for GenShape {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self { name, nest_id, keys, open } = &self;
+        let nest_id = match nest_id {
+            Some(id) => quote!(::std::option::Option::Some(#id)),
+            None => quote!(::std::option::Option::None),
+        };
+        tokens.extend(quote! {
+            ::shrinkwrap::__private::Shape {
+                name: #name,
+                nest: #nest_id,
+                keys: &[ #( #keys ),* ],
+                open: #open,
+            }
+        });
+    }
+}
+
+/// Generates a `shrinkwrap::shape::ShapeKey` expression
+#[derive(Debug, Clone)]
+pub(crate) struct GenShapeKey {
+    /// Serialized key name
+    pub(crate) name: String,
+
+    /// Key must be present
+    pub(crate) required: bool,
+
+    /// Value may be null
+    pub(crate) nullable: bool,
+
+    /// Expression evaluating to a `&'static Shape`, if the value's shape is known
+    pub(crate) shape: Option<TokenStream>,
+}
+impl GenShapeKey {
+    /// Builds the key for a struct field, returns None if the field is never serialized
+    pub(crate) fn from_field(
+        name: &Ident,
+        optional: bool,
+        serde: &SerdeFieldAttrs,
+        container: &SerdeContainerAttrs,
+        shape: Option<TokenStream>,
+    ) -> Option<Self> {
+        if serde.skip_serializing || serde.flatten {
+            return None;
+        }
+        Some(Self {
+            name: serde.serialized_name(name.to_string().as_str(), container),
+            required: !(optional || serde.skip_serializing_if.is_some() || serde.default),
+            nullable: optional,
+            shape,
+        })
+    }
+}
+impl ToTokens for GenShapeKey {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self { name, required, nullable, shape } = &self;
+        let shape = match shape {
+            Some(shape) => quote!(::std::option::Option::Some(#shape)),
+            None => quote!(::std::option::Option::None),
+        };
+        tokens.extend(quote! {
+            ::shrinkwrap::__private::ShapeKey {
+                name: #name,
+                required: #required,
+                nullable: #nullable,
+                shape: #shape,
+            }
+        });
+    }
+}
+
+// !- GenJsonShape
+
+/// Generates a `shrinkwrap::shape::JsonShape` trait impl for a generated struct
+#[derive(Debug, Clone)]
+pub(crate) struct GenJsonShape {
+    /// Ident of the generated struct
+    pub(crate) ident: Ident,
+
+    /// The struct's shape
+    pub(crate) shape: GenShape,
+}
+impl ToTokens for GenJsonShape {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self { ident, shape } = &self;
+        tokens.extend(quote! {
+            #[automatically_derived]
+            impl ::shrinkwrap::__private::JsonShape for #ident {
+                const SHAPE: ::shrinkwrap::__private::Shape = #shape;
+            }
+        });
+    }
+}
+
+// !- GenJsonHelpers
+
+/// Generates the JSON contract helpers for the primary wrapper
+#[derive(Debug, Clone)]
+pub(crate) struct GenJsonHelpers {
+    /// Ident of the primary wrapper
+    pub(crate) wrapper_ident: Ident,
+
+    /// Whether `Deserialize` is derived for the wrapper (enables `TryFrom<Value>`)
+    pub(crate) deserialize: bool,
+
+    /// Serialized location of the wrapper's nests
+    pub(crate) nests: GenNestTree,
+}
+impl ToTokens for GenJsonHelpers {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self { wrapper_ident, nests, .. } = &self;
+        let max_nest_count = nests.nest_count();
+        tokens.extend(quote! {
+            #[automatically_derived]
+            impl #wrapper_ident {
+                /// Number of nests the wrapper may hold, at any depth
+                pub const MAX_NEST_COUNT: usize = #max_nest_count;
+
+                /// Compares a JSON value against the serialized shape of this wrapper, reporting any unknown or missing keys (per nest)
+                pub fn matches_shape(value: &::shrinkwrap::__private::JsonValue) -> ::std::vec::Vec<::shrinkwrap::__private::ShapeMismatch> {
+                    ::shrinkwrap::__private::check_shape(value, &<Self as ::shrinkwrap::__private::JsonShape>::SHAPE)
+                }
+
+                /// Serializes this wrapper as a JSON value, retaining only the nests selected by ID (along with their
+                /// parents and children), see `shrinkwrap::filter`
+                pub fn to_value_filtered(&self, nests: &[&str]) -> ::std::result::Result<::shrinkwrap::__private::JsonValue, ::shrinkwrap::__private::Error> {
+                    const NESTS: ::shrinkwrap::__private::NestTree = #nests;
+                    ::shrinkwrap::__private::to_value_filtered(self, &NESTS, nests)
+                }
+            }
+        });
+        if self.deserialize {
+            tokens.extend(quote! {
+                #[automatically_derived]
+                impl ::std::convert::TryFrom<::shrinkwrap::__private::JsonValue> for #wrapper_ident {
+                    type Error = ::shrinkwrap::__private::Error;
+
+                    fn try_from(value: ::shrinkwrap::__private::JsonValue) -> ::std::result::Result<Self, Self::Error> {
+                        ::shrinkwrap::__private::from_json_value(value)
+                    }
+                }
+            });
+        }
+    }
+}
+
+// !- GenNestTree
+
+/// Generates a `shrinkwrap::filter::NestTree` expression
+#[derive(Debug, Clone)]
+pub(crate) struct GenNestTree {
+    /// Serialized key of the extra struct, None if flattened
+    pub(crate) extra: Option<String>,
+
+    /// Nests held by the extra struct
+    pub(crate) nests: Vec<GenNestKey>,
+}
+impl GenNestTree {
+    /// Number of nests within the tree, at any depth
+    fn nest_count(&self) -> usize {
+        self.nests.iter().map(|nest| 1 + nest.children.as_ref().map_or(0, GenNestTree::nest_count)).sum()
+    }
+}
+impl ToTokens for GenNestTree {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self { extra, nests } = &self;
+        let extra = match extra {
+            Some(key) => quote!(::std::option::Option::Some(#key)),
+            None => quote!(::std::option::Option::None),
+        };
+        tokens.extend(quote! {
+            ::shrinkwrap::__private::NestTree {
+                extra: #extra,
+                nests: &[ #( #nests ),* ],
+            }
+        });
+    }
+}
+
+/// Generates a `shrinkwrap::filter::NestKey` expression
+#[derive(Debug, Clone)]
+pub(crate) struct GenNestKey {
+    /// Nest ID
+    pub(crate) id: String,
+
+    /// Serialized key within the extra struct
+    pub(crate) key: String,
+
+    /// Tree of the nest's wrapper, for nests with nests chained from them
+    pub(crate) children: Option<GenNestTree>,
+}
+impl ToTokens for GenNestKey {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self { id, key, children } = &self;
+        let children = match children {
+            Some(children) => quote!(::std::option::Option::Some(&#children)),
+            None => quote!(::std::option::Option::None),
+        };
+        tokens.extend(quote! {
+            ::shrinkwrap::__private::NestKey {
+                id: #id,
+                key: #key,
+                children: #children,
+            }
+        });
+    }
+}
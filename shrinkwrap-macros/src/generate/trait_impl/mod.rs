@@ -3,13 +3,100 @@ use super::*;
 use quote::format_ident;
 use std::marker::PhantomData;
 
+mod any_transform;
+pub(crate) use any_transform::GenAnyTransform;
+
+mod assert_optional_nest;
+pub(crate) use assert_optional_nest::GenAssertOptionalNest;
+
+mod assert_threadsafe;
+pub(crate) use assert_threadsafe::GenAssertThreadsafe;
+
+mod assert_transform;
+pub(crate) use assert_transform::GenAssertTransform;
+
+mod audit;
+pub(crate) use audit::GenAudit;
+
+mod borrow_data;
+pub(crate) use borrow_data::GenBorrowData;
+
 mod build_nest_value;
 #[allow(unused_imports)]
 pub(crate) use build_nest_value::{BuildNestValueTrait, BuildNestValueVariant};
 
+mod csv_record;
+pub(crate) use csv_record::{GenCsvColumn, GenCsvRecord};
+
+mod debug_wrapper;
+pub(crate) use debug_wrapper::{GenDebugWrapper, GenDiagnosedNest};
+
+mod delegate_accessors;
+pub(crate) use delegate_accessors::{GenDelegateAccessor, GenDelegateAccessors};
+
+mod deprecated_accessors;
+pub(crate) use deprecated_accessors::{GenDeprecatedAccessor, GenDeprecatedAccessors};
+
+mod deserialize;
+pub(crate) use deserialize::{GenDeserialize, GenDeserializeField};
+
+mod ffi_envelope;
+pub(crate) use ffi_envelope::GenFfiEnvelope;
+
+mod grpc_metadata;
+pub(crate) use grpc_metadata::GenGrpcMetadata;
+
+mod http_response;
+pub(crate) use http_response::GenHttpResponse;
+
+mod identity;
+pub(crate) use identity::GenIdentity;
+
+mod json_shape;
+pub(crate) use json_shape::{GenJsonHelpers, GenJsonShape, GenNestKey, GenNestTree, GenShape, GenShapeKey};
+
+mod options_builder;
+pub(crate) use options_builder::GenOptionsBuilder;
+
+mod redacted_debug;
+pub(crate) use redacted_debug::GenRedactedDebug;
+
+mod remote;
+pub(crate) use remote::GenRemoteConversions;
+
+mod row_view;
+pub(crate) use row_view::GenRowView;
+
+mod serialize;
+pub(crate) use serialize::{GenSerialize, GenSerializeField};
+
+mod serialize_promoted;
+pub(crate) use serialize_promoted::GenSerializePromoted;
+
+mod topology;
+pub(crate) use topology::{GenNestNode, GenTopology};
+
 mod to_wrapped_with;
 #[allow(unused_imports)]
-pub(crate) use to_wrapped_with::{GenToWrappedWith, ToWrappedWithVariant};
+pub(crate) use to_wrapped_with::{GenBatchField, GenComputedField, GenEnrich, GenHoistedParent, GenToWrappedWith, ToWrappedWithVariant};
+
+mod transform_alias;
+pub(crate) use transform_alias::GenTransformAlias;
+
+mod transform_from_identity;
+pub(crate) use transform_from_identity::GenTransformToNestFromIdentity;
+
+mod transform_from_provider;
+pub(crate) use transform_from_provider::GenTransformToNestFromProvider;
+
+mod transform_from_sources;
+pub(crate) use transform_from_sources::{GenNestSources, GenTransformToNestFromSources};
+
+mod transform_options;
+pub(crate) use transform_options::GenTransformOptions;
+
+mod transform_to_auto_parent;
+pub(crate) use transform_to_auto_parent::GenTransformToAutoParent;
 
 mod transform_to_deep_nest;
 #[allow(unused_imports)]
@@ -0,0 +1,76 @@
+use super::*;
+
+// !- GenOptionsBuilder
+
+/// Generates a builder for the transform's `Options`, exposing a toggle for each gated nest
+#[derive(Debug, Clone)]
+pub(crate) struct GenOptionsBuilder {
+    /// Ident of the builder struct
+    pub(crate) ident: Ident,
+
+    /// Ident of the primary wrapper (used for docs)
+    pub(crate) wrapper_ident: Ident,
+
+    /// The type of the user-defined struct implementing [`shrinkwrap::Transform`]
+    pub(crate) transform_type: Path,
+
+    /// (nest ID, options field name) for each gated nest
+    pub(crate) gates: Vec<(String, Ident)>,
+}
+impl ToTokens for GenOptionsBuilder {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self { ident, wrapper_ident, transform_type, .. } = &self;
+        let options_type = quote!(<#transform_type as ::shrinkwrap::__private::Transform>::Options);
+        let doc = format!("Builds the transform options used to wrap [`{wrapper_ident}`], exposing a toggle for each gated nest");
+        let gate_fields = self.gates.iter().map(|(_, field)| field).collect::<Vec<_>>();
+        let gate_docs = self.gates.iter().map(|(nest_id, field)| format!("Sets `{field}`, toggling rendering of the `{nest_id}` nest"));
+
+        tokens.extend(quote! {
+            #[automatically_derived]
+            #[doc = #doc]
+            #[must_use]
+            pub struct #ident {
+                options: #options_type,
+            }
+            #[automatically_derived]
+            #[allow(private_interfaces)]
+            impl #ident {
+                /// Starts from an existing set of options
+                pub fn new(options: #options_type) -> Self {
+                    Self { options }
+                }
+                #(
+                    #[doc = #gate_docs]
+                    pub fn #gate_fields(mut self, enabled: bool) -> Self {
+                        self.options.#gate_fields = enabled;
+                        self
+                    }
+                )*
+                /// Enables every gated nest
+                pub fn with_all(mut self) -> Self {
+                    #( self.options.#gate_fields = true; )*
+                    self
+                }
+                /// Returns the final options
+                pub fn build(self) -> #options_type {
+                    self.options
+                }
+            }
+            #[automatically_derived]
+            impl ::std::default::Default for #ident
+            where
+                for<'a> #options_type: ::std::default::Default,
+            {
+                fn default() -> Self {
+                    Self { options: ::std::default::Default::default() }
+                }
+            }
+            #[automatically_derived]
+            impl ::std::convert::From<#options_type> for #ident {
+                fn from(options: #options_type) -> Self {
+                    Self { options }
+                }
+            }
+        });
+    }
+}
@@ -0,0 +1,84 @@
+use super::*;
+use syn::ext::IdentExt;
+
+// !- GenRedactedDebug
+
+/// Generates `Debug` for structs using `no_debug`, printing each field name with a `<redacted>` value
+#[derive(Debug, Clone)]
+pub(crate) struct GenRedactedDebug {
+    /// Type of the generated struct or enum
+    pub(crate) ty: Path,
+
+    /// Struct fields or enum variants to print
+    pub(crate) body: RedactedDebugBody,
+}
+
+/// Shape of the type a redacted `Debug` impl is generated for
+#[derive(Debug, Clone)]
+pub(crate) enum RedactedDebugBody {
+    /// Named struct field names
+    Struct(Vec<Ident>),
+
+    /// Enum variant names, and whether the variant holds a value
+    Enum(Vec<(Ident, bool)>),
+}
+impl From<GenStruct> for GenRedactedDebug {
+    fn from(source: GenStruct) -> Self {
+        Self {
+            ty: source.ty,
+            body: RedactedDebugBody::Struct(source.fields.into_iter().map(|field| field.name).collect()),
+        }
+    }
+}
+impl From<GenEnum> for GenRedactedDebug {
+    fn from(source: GenEnum) -> Self {
+        Self {
+            ty: source.ty,
+            body: RedactedDebugBody::Enum(source.variants.into_iter().map(|variant| (variant.name, variant.ty.is_some())).collect()),
+        }
+    }
+}
+impl ToTokens for GenRedactedDebug {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self { ty, body } = &self;
+        let name = ty.segments.last().map(|segment| segment.ident.to_string()).unwrap_or_default();
+
+        let body = match body {
+            RedactedDebugBody::Struct(fields) => {
+                let keys = fields.iter().map(|field| field.unraw().to_string());
+                quote! {
+                    f.debug_struct(#name)
+                        #( .field(#keys, &::std::format_args!("<redacted>")) )*
+                        .finish()
+                }
+            },
+            RedactedDebugBody::Enum(variants) => {
+                let arms = variants.iter().map(|(variant, has_value)| {
+                    let key = variant.unraw().to_string();
+                    match has_value {
+                        true => quote! {
+                            Self::#variant(..) => f.debug_tuple(#key).field(&::std::format_args!("<redacted>")).finish(),
+                        },
+                        false => quote! {
+                            Self::#variant => f.write_str(#key),
+                        },
+                    }
+                });
+                quote! {
+                    match self {
+                        #( #arms )*
+                    }
+                }
+            },
+        };
+
+        tokens.extend(quote! {
+            #[automatically_derived]
+            impl ::std::fmt::Debug for #ty {
+                fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                    #body
+                }
+            }
+        });
+    }
+}
@@ -0,0 +1,65 @@
+use super::*;
+
+// !- GenRemoteConversions
+
+/// Generates conversions between a local mirror struct and the remote type it mirrors (see `remote`), along with a
+/// helper wrapping the remote type directly
+#[derive(Debug, Clone)]
+pub(crate) struct GenRemoteConversions {
+    pub(crate) variant: ToWrappedWithVariant,
+
+    /// Ident of the mirror (origin data) struct
+    pub(crate) ident: Ident,
+
+    /// Path of the remote type
+    pub(crate) remote: Path,
+
+    /// Names of the fields shared by the mirror and the remote type
+    pub(crate) fields: Vec<Ident>,
+}
+impl ToTokens for GenRemoteConversions {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self { variant, ident, remote, fields } = &self;
+        let trait_name = variant.trait_name();
+        let trait_fn = variant.trait_fn();
+        let wrap_fn = variant.fallibility.trait_fn(format_ident!("wrap_remote"));
+        let wrapper = quote!(<Self as ::shrinkwrap::__private::#trait_name<T>>::Wrapper);
+        let return_type = match variant.is_fallible() {
+            true => quote!(::std::result::Result<#wrapper, <Self as ::shrinkwrap::__private::#trait_name<T>>::Error>),
+            false => wrapper,
+        };
+        let doc = format!(" Wraps a [`{}`] via its mirror", remote.to_token_stream().to_string().replace(' ', ""));
+
+        tokens.extend(quote! {
+            #[automatically_derived]
+            impl ::std::convert::From<#remote> for #ident {
+                fn from(remote: #remote) -> Self {
+                    Self {
+                        #( #fields: remote.#fields, )*
+                    }
+                }
+            }
+
+            #[automatically_derived]
+            impl ::std::convert::From<#ident> for #remote {
+                fn from(mirror: #ident) -> Self {
+                    Self {
+                        #( #fields: mirror.#fields, )*
+                    }
+                }
+            }
+
+            #[automatically_derived]
+            impl #ident {
+                #[doc = #doc]
+                pub fn #wrap_fn<T>(remote: #remote, transform: &T, options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>) -> #return_type
+                where
+                    T: ::shrinkwrap::__private::Transform2,
+                    Self: ::shrinkwrap::__private::#trait_name<T>,
+                {
+                    ::shrinkwrap::__private::#trait_name::#trait_fn(Self::from(remote), transform, options)
+                }
+            }
+        });
+    }
+}
@@ -0,0 +1,41 @@
+use super::*;
+
+use crate::parse::types::RowRender;
+
+// !- GenRowView
+
+/// Generates a `RowView` impl for a nest struct
+#[derive(Debug, Clone)]
+pub(crate) struct GenRowView {
+    /// Ident of the nest struct
+    pub(crate) ident: Ident,
+
+    /// (serialized name, field name) of each field, in declaration order
+    pub(crate) fields: Vec<(String, Ident)>,
+
+    pub(crate) render: RowRender,
+}
+impl ToTokens for GenRowView {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let ident = &self.ident;
+        let len = self.fields.len();
+        let keys = self.fields.iter().map(|(key, _)| key);
+        let values = self.fields.iter().map(|(_, name)| match self.render {
+            RowRender::Display => quote!(::std::string::ToString::to_string(&self.#name)),
+            RowRender::Serialize => quote! {
+                ::shrinkwrap::__private::render_value(&self.#name)
+                    .unwrap_or_else(|err| ::std::format!("<error: {err}>"))
+            },
+        });
+
+        tokens.extend(quote! {
+            #[automatically_derived]
+            impl ::shrinkwrap::__private::RowView for #ident {
+                fn fields(&self) -> impl ::std::iter::Iterator<Item = (&'static str, ::std::string::String)> {
+                    let fields: [(&'static str, ::std::string::String); #len] = [ #( (#keys, #values) ),* ];
+                    fields.into_iter()
+                }
+            }
+        });
+    }
+}
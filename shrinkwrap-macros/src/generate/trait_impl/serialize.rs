@@ -0,0 +1,114 @@
+use super::*;
+
+// !- GenSerialize
+
+/// Generates a `serde::Serialize` impl for a generated struct, used in place
+/// of the derived impl when fields must be emitted in a specific order
+#[derive(Debug, Clone)]
+pub(crate) struct GenSerialize {
+    /// Ident of the generated struct
+    pub(crate) ident: Ident,
+
+    /// Serialized fields, flattened data fields included
+    pub(crate) fields: Vec<GenSerializeField>,
+
+    /// Emit fields in lexicographic order of their serialized keys
+    pub(crate) sort_keys: bool,
+}
+impl GenSerialize {
+    /// The serialization of each key in emitted order, sorted keys include aliases (rather than following their field)
+    fn ordered_keys(&self) -> Vec<TokenStream> {
+        if !self.sort_keys {
+            return self.fields.iter().map(ToTokens::to_token_stream).collect();
+        }
+        let mut keys = self.fields.iter()
+            .flat_map(|field| {
+                let alias = field.alias.as_deref().map(|alias| (alias, field.serialize_alias(alias)));
+                std::iter::once((field.key.as_str(), field.serialize_key(&field.key))).chain(alias)
+            })
+            .collect::<Vec<_>>();
+        keys.sort_by_key(|(key, _)| *key);
+        keys.into_iter().map(|(_, serialize)| serialize).collect()
+    }
+}
+impl ToTokens for GenSerialize {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let ident = &self.ident;
+        let name = ident.to_string();
+        let keys = self.ordered_keys();
+        let len = self.fields.len();
+        let aliases = self.fields.iter().filter(|field| field.alias.is_some()).count();
+        let len = match aliases {
+            0 => quote!(#len),
+            aliases => quote!(#len + if ::shrinkwrap::__private::aliases_enabled() { #aliases } else { 0 }),
+        };
+
+        tokens.extend(quote! {
+            #[automatically_derived]
+            impl ::serde::Serialize for #ident {
+                fn serialize<__S>(&self, serializer: __S) -> ::std::result::Result<__S::Ok, __S::Error>
+                where
+                    __S: ::serde::Serializer,
+                {
+                    use ::serde::ser::SerializeStruct;
+
+                    let mut state = serializer.serialize_struct(#name, #len)?;
+                    #( #keys )*
+                    state.end()
+                }
+            }
+        });
+    }
+}
+
+/// A single serialized field within a [`GenSerialize`] impl
+#[derive(Debug, Clone)]
+pub(crate) struct GenSerializeField {
+    /// Serialized key name
+    pub(crate) key: String,
+
+    /// Expression accessing the field value from `self`, e.g. `self.data.id`
+    pub(crate) access: TokenStream,
+
+    /// Path of a `fn(&T) -> bool` which, if true, skips the field
+    pub(crate) skip_if: Option<Path>,
+
+    /// Previous key the field is serialized under as well while aliases are enabled (see `emit_alias`)
+    pub(crate) alias: Option<String>,
+}
+impl GenSerializeField {
+    /// Serializes the field under `key`, unless skipped via `skip_if`
+    fn serialize_key(&self, key: &str) -> TokenStream {
+        let Self { access, skip_if, .. } = &self;
+        match skip_if {
+            Some(skip_if) => quote! {
+                if #skip_if(&#access) {
+                    state.skip_field(#key)?;
+                } else {
+                    state.serialize_field(#key, &#access)?;
+                }
+            },
+            None => quote! {
+                state.serialize_field(#key, &#access)?;
+            },
+        }
+    }
+
+    /// Serializes the field under `alias` while aliases are enabled
+    fn serialize_alias(&self, alias: &str) -> TokenStream {
+        let serialize = self.serialize_key(alias);
+        quote! {
+            if ::shrinkwrap::__private::aliases_enabled() {
+                #serialize
+            }
+        }
+    }
+}
+impl ToTokens for GenSerializeField {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(self.serialize_key(&self.key));
+        if let Some(alias) = &self.alias {
+            tokens.extend(self.serialize_alias(alias));
+        }
+    }
+}
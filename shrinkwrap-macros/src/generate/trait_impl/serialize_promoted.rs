@@ -0,0 +1,67 @@
+use super::*;
+
+// !- GenSerializePromoted
+
+/// Generates a `serde::Serialize` impl for a wrapper with `promote` nests, serializing a borrowed view
+/// of the wrapper in which the promoted nests are flattened into the wrapper root
+#[derive(Debug, Clone)]
+pub(crate) struct GenSerializePromoted {
+    /// Ident of the wrapper
+    pub(crate) ident: Ident,
+
+    /// Serde container attrs of the wrapper
+    pub(crate) attrs: Vec<Attribute>,
+
+    /// Fields of the wrapper, only their serde attrs are retained
+    pub(crate) fields: Vec<GenStructField>,
+
+    /// Name of the wrapper's extra field
+    pub(crate) extra_name: Ident,
+
+    /// (field name, full type) of each promoted nest within the extra struct
+    pub(crate) promoted: Vec<(Ident, Type)>,
+}
+impl ToTokens for GenSerializePromoted {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self { ident, attrs, extra_name, .. } = &self;
+        let attrs = attrs.iter().filter(|attr| attr.path().is_ident("serde"));
+
+        let mut view_fields = Vec::new();
+        let mut view_assignments = Vec::new();
+        for field in &self.fields {
+            let GenStructField { name, ty, .. } = field;
+            let field_attrs = field.attrs.iter().filter(|attr| attr.path().is_ident("serde"));
+            view_fields.push(quote! {
+                #( #field_attrs )*
+                #name: &'a #ty,
+            });
+            view_assignments.push(quote!(#name: &self.#name,));
+        }
+        for (name, ty) in &self.promoted {
+            let view_name = format_ident!("promoted_{}", name.unraw());
+            view_fields.push(quote! {
+                #[serde(flatten)]
+                #view_name: &'a #ty,
+            });
+            view_assignments.push(quote!(#view_name: &self.#extra_name.#name,));
+        }
+
+        tokens.extend(quote! {
+            #[automatically_derived]
+            impl ::serde::Serialize for #ident {
+                fn serialize<__S>(&self, serializer: __S) -> ::std::result::Result<__S::Ok, __S::Error>
+                where
+                    __S: ::serde::Serializer,
+                {
+                    #[derive(::serde::Serialize)]
+                    #( #attrs )*
+                    struct #ident<'a> {
+                        #( #view_fields )*
+                    }
+
+                    ::serde::Serialize::serialize(&#ident { #( #view_assignments )* }, serializer)
+                }
+            }
+        });
+    }
+}
@@ -38,8 +38,119 @@ pub(crate) struct GenToWrappedWith {
 
     /// Fields contained by the associated wrapper's `extra` struct
     pub(crate) extra_struct_fields: Vec<GenStructField>,
+
+    /// `extra` field name -> name of the sibling `extra` field it depends on
+    pub(crate) extra_struct_field_needs: HashMap<Ident, Ident>,
+
+    /// `extra` field name -> the sibling `extra` field holding the parent nest it is built from, for nests hoisted
+    /// via `no_intermediate_wrapper`
+    pub(crate) extra_struct_field_parents: HashMap<Ident, GenHoistedParent>,
+
+    /// `extra` field name -> nest ID, for deprecated nests only built when enabled via
+    /// `DeprecatedNestGate::emit_deprecated` of the transform options
+    pub(crate) extra_struct_field_gates: HashMap<Ident, String>,
+
+    /// `extra` field name -> nest ID, for nests whose build time is recorded via `shrinkwrap::diagnostics::timed_nest`
+    /// (`debug_wrapper` only)
+    pub(crate) extra_struct_field_timings: HashMap<Ident, String>,
+
+    /// Additionally generate a `TryToWrappedWithErrors` impl (fallible variant only)
+    pub(crate) accumulate_errors: bool,
+
+    /// Names of the optional fields of the associated `extra` struct
+    pub(crate) extra_struct_optional_fields: Vec<Ident>,
+
+    /// Generate in-place enrichment helpers on the wrapper
+    pub(crate) enrich: Option<GenEnrich>,
+
+    /// Generate a `WrapAllWith` impl (`TryWrapAllWith` for the fallible variant), along with the
+    /// fields of the associated `extra` struct using `batch`
+    pub(crate) wrap_all: Option<Vec<GenBatchField>>,
+
+    /// Variant names of the `extra` fields if the associated `extra` is an `exclusive` enum, in field order
+    pub(crate) exclusive: Option<Vec<Ident>>,
+
+    /// Ident of the tagged enum and the variant names of the `extra` fields if the associated `extra` is a
+    /// `tagged_array`, in field order
+    pub(crate) tagged_array: Option<(Ident, Vec<Ident>)>,
+
+    /// Wrapper fields computed from the data and extra structs
+    pub(crate) computed: Vec<GenComputedField>,
+
+    /// Name and type of the wrapper field holding the echoed transform options
+    pub(crate) echo_options: Option<(Ident, Type)>,
+
+    /// Ident of the trait aliasing the bounds required of the transform, generated via `transform_alias`
+    pub(crate) transform_alias: Option<Ident>,
+
+    /// Implement the traits for `transform_type` only, rather than for any transform `T` providing the nests
+    pub(crate) concrete: bool,
+}
+
+/// A wrapper field computed via `with(&data, &extra)`
+#[derive(Debug, Clone)]
+pub(crate) struct GenComputedField {
+    pub(crate) name: Ident,
+    pub(crate) with: Path,
+}
+
+/// The parent nest of an `extra` field hoisted via `no_intermediate_wrapper`
+#[derive(Debug, Clone)]
+pub(crate) struct GenHoistedParent {
+    /// Name of the parent's `extra` field
+    pub(crate) name: Ident,
+
+    /// Ident of the parent nest struct, the hoisted nest's data
+    pub(crate) nest_ident: Ident,
+
+    pub(crate) optional: bool,
+
+    /// Ident of the hoisted nest (or its wrapper) if it is lifted to `Option` from the optional parent, i.e. its
+    /// transform builds the nest itself rather than `Option<Nest>`
+    pub(crate) lifted_ident: Option<Ident>,
+}
+
+/// Field names of a wrapper with `enrich` enabled
+#[derive(Debug, Clone)]
+pub(crate) struct GenEnrich {
+    pub(crate) data_name: Ident,
+    pub(crate) extra_name: Ident,
+}
+/// An `extra` field whose nest is computed for all items at once
+#[derive(Debug, Clone)]
+pub(crate) struct GenBatchField {
+    /// Name of the field within `extra`
+    pub(crate) name: Ident,
+
+    /// Full type of the batch nest, i.e. `Option<Nest>` for optional nests
+    pub(crate) nest_type: Type,
+
+    /// Nest struct and its wrapper, for nests with nests chained from them
+    pub(crate) wrapper: Option<(Ident, Ident)>,
+
+    pub(crate) optional: bool,
+}
+impl GenBatchField {
+    fn iter_binding(&self) -> Ident {
+        format_ident!("batch_{}", self.name)
+    }
 }
 impl GenToWrappedWith {
+    /// The transform type the traits are implemented for, `T` unless `concrete`
+    fn transform_param(&self) -> TokenStream {
+        match self.concrete {
+            true => self.transform_type.to_token_stream(),
+            false => quote!(T),
+        }
+    }
+    /// Generic params of the trait impls (and `enrich_with`), the transform's own generic params when `concrete`
+    fn impl_generics(&self) -> TokenStream {
+        match (self.concrete, &self.transform_generic_bounds) {
+            (true, Some(params)) => quote!(<#params>),
+            (true, None) => quote!(),
+            (false, _) => quote!(<T>),
+        }
+    }
     fn associated_types(&self) -> TokenStream {
         let wrapper_type = &self.wrapper_ident;
         let fallibility_associated_types = self.variant.fallibility_associated_types();
@@ -65,29 +176,200 @@ impl GenToWrappedWith {
         }
     }
 
+    /// Returns the sibling field required to build `field`, if any
+    fn sibling_field(&self, field: &GenStructField) -> Option<&GenStructField> {
+        let sibling_name = self.extra_struct_field_needs.get(&field.name)?;
+        self.extra_struct_fields.iter().find(|sibling| &sibling.name == sibling_name)
+    }
+
+    /// Returns the sibling field holding the parent nest of a hoisted `field`, if any
+    fn hoisted_parent_field(&self, field: &GenStructField) -> Option<&GenStructField> {
+        let parent = self.extra_struct_field_parents.get(&field.name)?;
+        self.extra_struct_fields.iter().find(|sibling| sibling.name == parent.name)
+    }
+
+    /// Orders `extra` fields such that any sibling (or hoisted parent) dependencies are computed first
+    fn ordered_extra_struct_fields(&self) -> Vec<&GenStructField> {
+        fn visit<'a>(this: &'a GenToWrappedWith, field: &'a GenStructField, out: &mut Vec<&'a GenStructField>) {
+            if out.iter().any(|visited| visited.name == field.name) {
+                return;
+            }
+            for dependency in [this.sibling_field(field), this.hoisted_parent_field(field)].into_iter().flatten() {
+                visit(this, dependency, out);
+            }
+            out.push(field);
+        }
+        let mut out = Vec::with_capacity(self.extra_struct_fields.len());
+        for field in &self.extra_struct_fields {
+            visit(self, field, &mut out);
+        }
+        out
+    }
+
     /// Generates the `where` conditions used for the blanket impl
     fn gen_where_predicates(&self) -> TokenStream {
-        // always add `shrinkwrap::Transform` bound to implementing type
-        let mut out = quote!(T: ::shrinkwrap::Transform,);
+        let transform = self.transform_param();
+        let transform_bounds = self.gen_transform_bounds();
+        let options_bounds = self.gen_options_bounds();
+
+        quote! {
+            #( #transform: #transform_bounds, )*
+            #( for<'options> <#transform as ::shrinkwrap::__private::Transform2>::Options<'options>: #options_bounds, )*
+        }
+    }
+
+    /// Generates the bounds required of the transform type, i.e. `Transform2` along with the nest trait of each
+    /// `extra` field
+    fn gen_transform_bounds(&self) -> Vec<TokenStream> {
+        // always add `shrinkwrap::Transform2` bound to implementing type
+        let mut out = vec![quote!(::shrinkwrap::__private::Transform2)];
 
         let data_ident = &self.data_ident;
 
         for extra_field in &self.extra_struct_fields {
             // handles wrapping nest type in Option if required
-            let nest_full_type = &extra_field.ty;
+            let parent = self.extra_struct_field_parents.get(&extra_field.name);
+            let nest_full_type: Type = match parent.and_then(|parent| parent.lifted_ident.as_ref()) {
+                Some(lifted_ident) => parse_quote!(#lifted_ident),
+                None => extra_field.ty.clone(),
+            };
+            // hoisted nests are built from their parent nest instead
+            let data_ident = parent.map_or(data_ident, |parent| &parent.nest_ident);
 
-            out.extend(match &self.variant.fallibility {
-                Fallibility::Infallible => quote! {
-                    T: ::shrinkwrap::TransformToNest<#nest_full_type, Data = #data_ident>,
+            out.push(match (&self.variant.fallibility, self.sibling_field(extra_field)) {
+                (Fallibility::Infallible, None) => quote! {
+                    ::shrinkwrap::__private::TransformToNest2<#nest_full_type, Data = #data_ident>
+                },
+                (Fallibility::Fallible { error_type }, None) => quote! {
+                    ::shrinkwrap::__private::TryTransformToNest2<#nest_full_type, Data = #data_ident, Error = #error_type>
                 },
-                Fallibility::Fallible { error_type } => quote! {
-                    T: ::shrinkwrap::TryTransformToNest<#nest_full_type, Data = #data_ident, Error = #error_type>,
+                (Fallibility::Infallible, Some(sibling)) => {
+                    let sibling_type = &sibling.ty;
+                    quote! {
+                        ::shrinkwrap::__private::TransformToNestWithSiblings2<#nest_full_type, #sibling_type, Data = #data_ident>
+                    }
                 },
+                (Fallibility::Fallible { error_type }, Some(sibling)) => {
+                    let sibling_type = &sibling.ty;
+                    quote! {
+                        ::shrinkwrap::__private::TryTransformToNestWithSiblings2<#nest_full_type, #sibling_type, Data = #data_ident, Error = #error_type>
+                    }
+                },
+            });
+        }
+        out
+    }
+
+    /// Generates the bounds required of the transform's options (for any lifetime)
+    fn gen_options_bounds(&self) -> Vec<TokenStream> {
+        let mut out = Vec::new();
+        if !self.extra_struct_field_gates.is_empty() {
+            out.push(quote!(::shrinkwrap::__private::DeprecatedNestGate));
+        }
+        if let Some((_, echo_type)) = &self.echo_options {
+            out.push(quote!(::shrinkwrap::__private::SerializableOptions<Echo = #echo_type>));
+        }
+        out
+    }
+
+    /// Generates the `transform_alias` trait, bounded by the same bounds as the blanket impl
+    fn gen_transform_alias(&self, ident: &Ident) -> GenTransformAlias {
+        let mut bounds = self.gen_transform_bounds();
+        bounds.extend(self.gen_options_bounds().into_iter().map(|options_bound| quote! {
+            for<'options> ::shrinkwrap::__private::Transform2<Options<'options>: #options_bound>
+        }));
+        GenTransformAlias {
+            ident: ident.clone(),
+            data_ident: self.data_ident.clone(),
+            bounds,
+        }
+    }
+
+    /// Generates the additional `where` conditions for `batch` nests
+    fn gen_batch_where_predicates(&self, batch: &[GenBatchField]) -> TokenStream {
+        let data_ident = &self.data_ident;
+        let batch_trait = self.variant.fallibility.trait_name(format_ident!("BatchTransformToNest2"));
+        let wrap_trait = self.variant.trait_name();
+        let error_type = self.variant.error_type().map(|error_type| quote!(, Error = #error_type));
+        let transform = self.transform_param();
+
+        let mut out = quote! {};
+        for batch_field in batch {
+            let nest_type = &batch_field.nest_type;
+            out.extend(quote! {
+                #transform: ::shrinkwrap::__private::#batch_trait<#nest_type, Data = #data_ident #error_type>,
             });
+            if let Some((nest_ident, wrapper_ident)) = &batch_field.wrapper {
+                out.extend(quote! {
+                    #nest_ident: ::shrinkwrap::__private::#wrap_trait<#transform, Wrapper = #wrapper_ident #error_type>,
+                });
+            }
         }
         out
     }
 
+    /// Generates the `WrapAllWith` (or `TryWrapAllWith`) impl for the data struct
+    fn gen_wrap_all(&self, batch: &[GenBatchField]) -> TokenStream {
+        let data_ident = &self.data_ident;
+        let impl_bounds = self.gen_where_predicates();
+        let batch_bounds = self.gen_batch_where_predicates(batch);
+        let extra_binding = self.gen_extra_binding(&quote!(&data), batch);
+        let trait_name = self.variant.fallibility.trait_name(format_ident!("WrapAllWith"));
+        let trait_fn = self.variant.fallibility.trait_fn(format_ident!("wrap_all_with"));
+        let batch_fn = self.variant.fallibility.trait_fn(format_ident!("transform_many"));
+        let batch_trait = self.variant.fallibility.trait_name(format_ident!("BatchTransformToNest2"));
+        let call_suffix = self.variant.trait_fn_call_suffix();
+        let impl_generics = self.impl_generics();
+        let transform_param = self.transform_param();
+
+        let batches = batch.iter().map(|batch_field| {
+            let iter_binding = batch_field.iter_binding();
+            let nest_type = &batch_field.nest_type;
+            let nest_name = batch_field.name.to_string();
+            quote! {
+                let nests = <#transform_param as ::shrinkwrap::__private::#batch_trait<#nest_type>>::#batch_fn(transform, &data, options) #call_suffix;
+                let mut #iter_binding = ::shrinkwrap::__private::batch_nests(nests, data.len(), #nest_name).ok();
+            }
+        });
+        let computed = self.gen_computed_assignments(&quote!(&data), &quote!(&extra), Some(&quote!(options)));
+        let wrapper = self.variant.fallibility.map_return(quote! {
+            Self::Wrapper {
+                #computed
+                extra,
+                data
+            }
+        });
+        let (return_type, collected) = match self.variant.error_type() {
+            Some(_) => (
+                quote!(Result<Vec<Self::Wrapper>, Self::Error>),
+                quote!(wrappers.collect::<Result<Vec<_>, _>>()),
+            ),
+            None => (quote!(Vec<Self::Wrapper>), quote!(wrappers.collect())),
+        };
+
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics ::shrinkwrap::__private::#trait_name<#transform_param> for #data_ident
+            where
+                #impl_bounds
+                #batch_bounds
+            {
+                fn #trait_fn(
+                    data: Vec<Self>,
+                    transform: &#transform_param,
+                    options: &<#transform_param as ::shrinkwrap::__private::Transform2>::Options<'_>,
+                ) -> #return_type {
+                    #( #batches )*
+                    let wrappers = data.into_iter().map(|data| {
+                        #extra_binding
+                        #wrapper
+                    });
+                    #collected
+                }
+            }
+        }
+    }
+
     fn map_opt_helper_return(&self, ret_tokens: TokenStream) -> TokenStream {
         if self.variant.is_fallible() {
             quote! { #ret_tokens.transpose() }
@@ -96,51 +378,334 @@ impl GenToWrappedWith {
         }
     }
 
-    /// Generates the tokens for all field assignments of the associated `extra`
-    /// struct. Each `extra` field is a nest variant struct
-    fn gen_extra_fields_assignments(&self) -> TokenStream {
+    /// Generates a `let` binding for each nest variant struct of the associated
+    /// `extra` struct, ordered by sibling dependencies
+    ///
+    /// `data` is an expression referencing the data struct.
+    ///
+    /// When `accumulate` is set, errors of optional nests are pushed to `errors`
+    /// and the binding set to `None`
+    fn gen_extra_fields_bindings(&self, data: &TokenStream, accumulate: bool) -> TokenStream {
+        self.gen_extra_fields_bindings_with_batch(data, accumulate, &[])
+    }
+
+    /// Same as `gen_extra_fields_bindings`, however fields within `batch` are taken from their batch iterator
+    fn gen_extra_fields_bindings_with_batch(&self, data: &TokenStream, accumulate: bool, batch: &[GenBatchField]) -> TokenStream {
         let mut out = quote! {};
 
         let transform_to_nest_trait = TransformToNestVariant::from(self.variant.fallibility.clone());
         let trait_fn = transform_to_nest_trait.trait_fn();
         let trait_fn_call_suffix = transform_to_nest_trait.trait_fn_call_suffix();
+        let sibling_trait_fn = self.variant.fallibility.trait_fn(format_ident!("transform_to_nest_with_siblings"));
+
+        for extra_field in self.ordered_extra_struct_fields() {
+            let binding = Self::field_binding(&extra_field.name);
+
+            if let Some(batch_field) = batch.iter().find(|batch_field| batch_field.name == extra_field.name) {
+                let iter_binding = batch_field.iter_binding();
+                // batch impls returning the wrong number of nests fall back to per-item nests
+                let nest = quote! {
+                    match #iter_binding.as_mut().and_then(::std::iter::Iterator::next) {
+                        ::std::option::Option::Some(nest) => nest,
+                        ::std::option::Option::None => transform.#trait_fn(#data, options) #trait_fn_call_suffix,
+                    }
+                };
+                out.extend(match &batch_field.wrapper {
+                    Some(_) => {
+                        let wrap_trait = self.variant.trait_name();
+                        let wrap_fn = self.variant.trait_fn();
+                        let call_suffix = self.variant.trait_fn_call_suffix();
+                        match (batch_field.optional, self.variant.is_fallible()) {
+                            (true, true) => quote! {
+                                let #binding = #nest.map(|nest| ::shrinkwrap::__private::#wrap_trait::#wrap_fn(nest, transform, options)).transpose()?;
+                            },
+                            (true, false) => quote! {
+                                let #binding = #nest.map(|nest| ::shrinkwrap::__private::#wrap_trait::#wrap_fn(nest, transform, options));
+                            },
+                            (false, _) => quote! {
+                                let #binding = ::shrinkwrap::__private::#wrap_trait::#wrap_fn(#nest, transform, options) #call_suffix;
+                            },
+                        }
+                    },
+                    None => quote! {
+                        let #binding = #nest;
+                    },
+                });
+                continue;
+            }
+
+            let call = match (self.sibling_field(extra_field), self.extra_struct_field_parents.get(&extra_field.name)) {
+                (_, Some(parent)) => {
+                    let parent_binding = Self::field_binding(&parent.name);
+                    // the parent's type is only inferred from the extra struct afterwards, it's spelled out for the closures
+                    let parent_ident = &parent.nest_ident;
+                    let parent_ref = quote!(::std::option::Option::as_ref(&#parent_binding));
+                    // optional parents build `None` for their (optional) hoisted children, lifted children are built
+                    // whenever the parent is present
+                    match (parent.optional, self.variant.is_fallible()) {
+                        (true, true) if parent.lifted_ident.is_some() => quote! {
+                            #parent_ref.map(|parent: &#parent_ident| transform.#trait_fn(parent, options)).transpose()
+                        },
+                        (true, false) if parent.lifted_ident.is_some() => quote!(#parent_ref.map(|parent: &#parent_ident| transform.#trait_fn(parent, options))),
+                        (true, true) => quote! {
+                            #parent_ref.map(|parent: &#parent_ident| transform.#trait_fn(parent, options)).transpose().map(::std::option::Option::flatten)
+                        },
+                        (true, false) => quote!(#parent_ref.and_then(|parent: &#parent_ident| transform.#trait_fn(parent, options))),
+                        (false, _) => quote!(transform.#trait_fn(&#parent_binding, options)),
+                    }
+                },
+                (Some(sibling), None) => {
+                    let sibling_binding = Self::field_binding(&sibling.name);
+                    quote!(transform.#sibling_trait_fn(#data, &#sibling_binding, options))
+                },
+                (None, None) => quote!(transform.#trait_fn(#data, options)),
+            };
+            let call = match self.extra_struct_field_timings.get(&extra_field.name) {
+                Some(nest_id) => quote!(::shrinkwrap::__private::timed_nest(#nest_id, || #call)),
+                None => call,
+            };
+            // gated nests are `None` unless enabled by the options
+            let call = match self.extra_struct_field_gates.get(&extra_field.name) {
+                Some(nest_id) => {
+                    let none = match self.variant.is_fallible() {
+                        true => quote!(::std::result::Result::Ok(::std::option::Option::None)),
+                        false => quote!(::std::option::Option::None),
+                    };
+                    quote! {
+                        if ::shrinkwrap::__private::DeprecatedNestGate::emit_deprecated(options, #nest_id) { #call } else { #none }
+                    }
+                },
+                None => call,
+            };
+
+            out.extend(match accumulate && self.extra_struct_optional_fields.contains(&extra_field.name) {
+                true => {
+                    let nest_name = extra_field.name.to_string();
+                    quote! {
+                        let #binding = match #call {
+                            Ok(nest) => nest,
+                            Err(error) => {
+                                errors.push(::shrinkwrap::__private::NestError { nest: #nest_name, error });
+                                None
+                            },
+                        };
+                    }
+                },
+                false => quote! {
+                    let #binding = #call #trait_fn_call_suffix;
+                },
+            });
+        }
+
+        out
+    }
+
+    /// Generates the `extra` binding, built from the data struct referenced by `data`
+    ///
+    /// For `exclusive` extras, nests are computed in field order until the first populated nest
+    fn gen_extra_binding(&self, data: &TokenStream, batch: &[GenBatchField]) -> TokenStream {
+        let extra_struct_type = &self.extra_struct_ident;
+        let Some(variants) = &self.exclusive else {
+            let bindings = self.gen_extra_fields_bindings_with_batch(data, false, batch);
+            let extra = self.gen_extra_value();
+            return quote! {
+                #bindings
+                let extra = #extra;
+            };
+        };
+
+        let transform_to_nest_trait = TransformToNestVariant::from(self.variant.fallibility.clone());
+        let trait_fn = transform_to_nest_trait.trait_fn();
+        let trait_fn_call_suffix = transform_to_nest_trait.trait_fn_call_suffix();
+        let nests = self.extra_struct_fields.iter().zip(variants).map(|(extra_field, variant)| {
+            let nest_full_type = &extra_field.ty;
+            quote! {
+                let nest: #nest_full_type = transform.#trait_fn(#data, options) #trait_fn_call_suffix;
+                if let Some(nest) = nest {
+                    break 'exclusive #extra_struct_type::#variant(nest);
+                }
+            }
+        });
+        quote! {
+            let extra = 'exclusive: {
+                #( #nests )*
+                #extra_struct_type::None
+            };
+        }
+    }
+
+    /// Generates the computed field assignments of the wrapper, `data` and `extra` reference the wrapper's values
+    ///
+    /// Echoed options default to `Default::default()` if no `options` are available. Must precede the `extra` and
+    /// `data` assignments, as both are moved into the wrapper
+    fn gen_computed_assignments(&self, data: &TokenStream, extra: &TokenStream, options: Option<&TokenStream>) -> TokenStream {
+        let assignments = self.computed.iter().map(|GenComputedField { name, with }| quote! {
+            #name: #with(#data, #extra),
+        });
+        let echo_options = self.echo_options.as_ref().map(|(name, _)| match options {
+            Some(options) => quote!(#name: ::shrinkwrap::__private::SerializableOptions::echo_options(#options),),
+            None => quote!(#name: ::std::default::Default::default(),),
+        });
+        quote! { #( #assignments )* #echo_options }
+    }
+
+    /// Generates the expression building the associated `extra`, using the bindings from `gen_extra_fields_bindings`
+    ///
+    /// For `tagged_array` extras, each populated nest is pushed in field order
+    fn gen_extra_value(&self) -> TokenStream {
+        let Some((item_ident, variants)) = &self.tagged_array else {
+            let extra_struct_type = &self.extra_struct_ident;
+            let assignments = self.gen_extra_fields_assignments();
+            return quote! {
+                #extra_struct_type {
+                    #assignments
+                }
+            };
+        };
+        let capacity = self.extra_struct_fields.len();
+        let pushes = self.extra_struct_fields.iter().zip(variants).map(|(extra_field, variant)| {
+            let binding = Self::field_binding(&extra_field.name);
+            match self.extra_struct_optional_fields.contains(&extra_field.name) {
+                true => quote! {
+                    if let Some(nest) = #binding {
+                        extra.push(#item_ident::#variant(nest));
+                    }
+                },
+                false => quote! {
+                    extra.push(#item_ident::#variant(#binding));
+                },
+            }
+        });
+        quote! {
+            {
+                let mut extra = ::std::vec::Vec::with_capacity(#capacity);
+                #( #pushes )*
+                extra
+            }
+        }
+    }
+
+    /// Generates the tokens for all field assignments of the associated `extra`
+    /// struct, using the bindings from `gen_extra_fields_bindings`
+    fn gen_extra_fields_assignments(&self) -> TokenStream {
+        let mut out = quote! {};
 
         for extra_field in &self.extra_struct_fields {
             let field_name = &extra_field.name;
+            let binding = Self::field_binding(field_name);
 
             out.extend(quote! {
-                #field_name: transform.#trait_fn(&self, options)#trait_fn_call_suffix,
+                #field_name: #binding,
             });
         }
 
         out
     }
+
+    /// Generates `enrich_with` (and `with_empty_extra` if all `extra` fields are optional) on the wrapper
+    fn gen_enrich(&self, enrich: &GenEnrich) -> TokenStream {
+        let GenEnrich { data_name, extra_name } = enrich;
+        let wrapper_type = &self.wrapper_ident;
+        let data_ident = &self.data_ident;
+        let extra_struct_type = &self.extra_struct_ident;
+        let impl_bounds = self.gen_where_predicates();
+        let bindings = self.gen_extra_fields_bindings(&quote!(&self.#data_name), false);
+        let extra = self.gen_extra_value();
+        let (return_type, return_value) = match self.variant.error_type() {
+            Some(error_type) => (quote!(-> Result<(), #error_type>), quote!(Ok(()))),
+            None => (quote!(), quote!()),
+        };
+        let recomputed = self.computed.iter().map(|GenComputedField { name, with }| quote! {
+            self.#name = #with(&self.#data_name, &self.#extra_name);
+        });
+        let echoed = self.echo_options.as_ref().map(|(name, _)| quote! {
+            self.#name = ::shrinkwrap::__private::SerializableOptions::echo_options(options);
+        });
+        let recomputed = quote! { #( #recomputed )* #echoed };
+        let impl_generics = self.impl_generics();
+        let transform_param = self.transform_param();
+        let errors_doc = self.variant.is_fallible()
+            .then(|| quote!(#[doc = ""] #[doc = "The `extra` struct is left unchanged if any nest fails."]));
+
+        let mut out = quote! {
+            /// Recomputes the `extra` struct in place from the wrapped data
+            #errors_doc
+            pub fn enrich_with #impl_generics(&mut self, transform: &#transform_param, options: &<#transform_param as ::shrinkwrap::__private::Transform2>::Options<'_>) #return_type
+            where
+                #impl_bounds
+            {
+                #bindings
+                self.#extra_name = #extra;
+                #recomputed
+                #return_value
+            }
+        };
+
+        let all_optional = self.extra_struct_fields.iter().all(|field| self.extra_struct_optional_fields.contains(&field.name));
+        if all_optional {
+            let field_names = self.extra_struct_fields.iter().map(|field| &field.name);
+            let computed = self.gen_computed_assignments(&quote!(&#data_name), &quote!(&#extra_name), None);
+            let empty_extra = match self.tagged_array {
+                Some(..) => quote!(::std::vec::Vec::new()),
+                None => quote! {
+                    #extra_struct_type {
+                        #( #field_names: None, )*
+                    }
+                },
+            };
+            out.extend(quote! {
+                /// Wraps the data without any nests, to be populated later via [`enrich_with`](Self::enrich_with)
+                pub fn with_empty_extra(#data_name: #data_ident) -> Self {
+                    let #extra_name = #empty_extra;
+                    Self {
+                        #computed
+                        #extra_name,
+                        #data_name,
+                    }
+                }
+            });
+        }
+
+        quote! {
+            #[automatically_derived]
+            #[allow(private_interfaces)]
+            impl #wrapper_type {
+                #out
+            }
+        }
+    }
+
+    fn field_binding(field_name: &Ident) -> Ident {
+        format_ident!("nest_{field_name}")
+    }
 }
 impl ToTokens for GenToWrappedWith {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let data_ident = &self.data_ident;
-        let extra_struct_type = &self.extra_struct_ident;
-        let extra_struct_field_assignments = self.gen_extra_fields_assignments();
+        let extra_binding = self.gen_extra_binding(&quote!(&self), &[]);
+        let extra = self.gen_extra_value();
         let trait_name = self.variant.trait_name();
         let trait_fn = self.variant.trait_fn();
         let impl_bounds = self.gen_where_predicates();
         let transform_type = &self.transform_type;
         let transform_generic_bounds = self.transform_generic_bounds.as_ref().map(|params| quote!(<#params>)).unwrap_or_default();
         let associated_types = self.associated_types();
+        let impl_generics = self.impl_generics();
+        let transform_param = self.transform_param();
 
         let return_type = self.return_type();
+        let computed = self.gen_computed_assignments(&quote!(&self), &quote!(&extra), Some(&quote!(options)));
         let return_statement = self.variant.fallibility.map_return(quote! {
             Self::Wrapper {
-                extra: #extra_struct_type {
-                    #extra_struct_field_assignments
-                },
+                #computed
+                extra,
                 data: self
             }
         });
 
         tokens.extend(quote! {
             #[automatically_derived]
-            impl<T> ::shrinkwrap::#trait_name<T> for #data_ident
+            impl #impl_generics ::shrinkwrap::__private::#trait_name<#transform_param> for #data_ident
             where
                 #impl_bounds
             {
@@ -148,14 +713,54 @@ impl ToTokens for GenToWrappedWith {
 
                 fn #trait_fn(
                     self,
-                    transform: &T,
-                    options: &<T as ::shrinkwrap::Transform>::Options,
+                    transform: &#transform_param,
+                    options: &<#transform_param as ::shrinkwrap::__private::Transform2>::Options<'_>,
                 ) -> #return_type {
+                    #extra_binding
                     #return_statement
                 }
             }
         });
 
+        if self.accumulate_errors && self.variant.is_fallible() {
+            let accumulating_bindings = self.gen_extra_fields_bindings(&quote!(&self), true);
+            tokens.extend(quote! {
+                #[automatically_derived]
+                impl #impl_generics ::shrinkwrap::__private::TryToWrappedWithErrors<#transform_param> for #data_ident
+                where
+                    #impl_bounds
+                {
+                    fn try_to_wrapped_with_errors(
+                        self,
+                        transform: &#transform_param,
+                        options: &<#transform_param as ::shrinkwrap::__private::Transform2>::Options<'_>,
+                    ) -> Result<(Self::Wrapper, Vec<::shrinkwrap::__private::NestError<Self::Error>>), Self::Error> {
+                        let mut errors = Vec::new();
+                        #accumulating_bindings
+                        let extra = #extra;
+                        let wrapper = Self::Wrapper {
+                            #computed
+                            extra,
+                            data: self
+                        };
+                        Ok((wrapper, errors))
+                    }
+                }
+            });
+        }
+
+        if let Some(enrich) = &self.enrich {
+            tokens.extend(self.gen_enrich(enrich));
+        }
+
+        if let Some(batch) = &self.wrap_all {
+            tokens.extend(self.gen_wrap_all(batch));
+        }
+
+        if let Some(ident) = &self.transform_alias {
+            self.gen_transform_alias(ident).to_tokens(tokens);
+        }
+
         // add impl to allow calling wrap_data_with directly on Option
         let opt_helper_associated_types = self.opt_helper_associated_types();
         let opt_helper_return_statement  = self.map_opt_helper_return(quote! {
@@ -163,13 +768,13 @@ impl ToTokens for GenToWrappedWith {
         });
         tokens.extend(quote! {
             #[automatically_derived]
-            impl #transform_generic_bounds ::shrinkwrap::#trait_name<#transform_type> for Option<#data_ident> {
+            impl #transform_generic_bounds ::shrinkwrap::__private::#trait_name<#transform_type> for Option<#data_ident> {
                 #opt_helper_associated_types
 
                 fn #trait_fn(
                     self,
                     transform: &#transform_type,
-                    options: &<#transform_type as ::shrinkwrap::Transform>::Options,
+                    options: &<#transform_type as ::shrinkwrap::__private::Transform2>::Options<'_>,
                 ) -> #return_type {
                     #opt_helper_return_statement
                 }
@@ -0,0 +1,66 @@
+use super::*;
+
+// !- GenTopology
+
+/// A single nest of the generated topology
+#[derive(Debug, Clone)]
+pub(crate) struct GenNestNode {
+    pub(crate) id: String,
+    pub(crate) parent: Option<String>,
+    pub(crate) field_name: Ident,
+    pub(crate) optional: bool,
+    pub(crate) struct_name: Ident,
+    pub(crate) wrapper_name: Option<Ident>,
+}
+impl ToTokens for GenNestNode {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self { id, optional, .. } = &self;
+        let parent = quote_optional_str(self.parent.as_deref());
+        let field_name = self.field_name.to_string();
+        let struct_name = self.struct_name.to_string();
+        let wrapper_name = quote_optional_str(self.wrapper_name.as_ref().map(ToString::to_string).as_deref());
+
+        tokens.extend(quote! {
+            ::shrinkwrap::__private::NestNode {
+                id: #id,
+                parent: #parent,
+                field_name: #field_name,
+                optional: #optional,
+                struct_name: #struct_name,
+                wrapper_name: #wrapper_name,
+            }
+        });
+    }
+}
+
+fn quote_optional_str(value: Option<&str>) -> TokenStream {
+    match value {
+        Some(value) => quote!(::std::option::Option::Some(#value)),
+        None => quote!(::std::option::Option::None),
+    }
+}
+
+/// Generates the `WRAP_TOPOLOGY` constant on the origin data struct
+#[derive(Debug, Clone)]
+pub(crate) struct GenTopology {
+    /// Ident of the origin data struct
+    pub(crate) data_ident: Ident,
+
+    /// All nests, parents are always listed before their children
+    pub(crate) nodes: Vec<GenNestNode>,
+}
+impl ToTokens for GenTopology {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self { data_ident, nodes } = &self;
+
+        tokens.extend(quote! {
+            #[automatically_derived]
+            impl #data_ident {
+                /// Nests generated for this struct, parents are listed before their children
+                pub const WRAP_TOPOLOGY: &'static [::shrinkwrap::__private::NestNode] = &[
+                    #( #nodes, )*
+                ];
+            }
+        });
+    }
+}
@@ -0,0 +1,36 @@
+use super::*;
+
+// !- GenTransformAlias
+
+/// Generates the `transform_alias` trait, aliasing the bounds a transform must satisfy to wrap the data struct, along
+/// with its blanket impl
+#[derive(Debug, Clone)]
+pub(crate) struct GenTransformAlias {
+    /// Ident of the generated trait
+    pub(crate) ident: Ident,
+
+    /// Ident of the data struct wrapped via the aliased transforms
+    pub(crate) data_ident: Ident,
+
+    /// Bounds required of the transform type, without the bounded type
+    pub(crate) bounds: Vec<TokenStream>,
+}
+impl ToTokens for GenTransformAlias {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self { ident, data_ident, bounds } = &self;
+        let doc = format!(" Transforms which [`{data_ident}`] can be wrapped with, i.e. those providing each of its nests");
+
+        tokens.extend(quote! {
+            #[doc = #doc]
+            pub trait #ident
+            where
+                #( Self: #bounds, )*
+            {}
+            #[automatically_derived]
+            impl<T> #ident for T
+            where
+                #( T: #bounds, )*
+            {}
+        });
+    }
+}
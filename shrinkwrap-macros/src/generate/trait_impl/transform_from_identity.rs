@@ -0,0 +1,71 @@
+use super::*;
+
+// !- GenTransformToNestFromIdentity
+
+/// Generates `From<&Source>` for an identity nest, along with a [`shrinkwrap::transform_to_nest`] trait impl
+/// delegating to it
+#[derive(Debug, Clone)]
+pub(crate) struct GenTransformToNestFromIdentity {
+    /// The trait variant
+    pub(crate) variant: TransformToNestVariant,
+
+    /// The type of the user-defined struct implementing [`shrinkwrap::Transform`]
+    pub(crate) transform_type: Path,
+
+    /// Generic bounds for `transform_type`
+    pub(crate) transform_generic_bounds: Option<TokenStream>,
+
+    /// Ident of the source data struct (origin or parent nest)
+    pub(crate) data_ident: Ident,
+
+    /// Ident of the nest struct
+    pub(crate) nest_struct_ident: Ident,
+
+    /// (name, `cfg` attrs) of the fields included in the nest
+    pub(crate) fields: Vec<(Ident, Vec<Attribute>)>,
+
+    /// If set, the nest is always built as `Some`
+    pub(crate) optional: bool,
+}
+impl ToTokens for GenTransformToNestFromIdentity {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self { transform_type, data_ident, nest_struct_ident, .. } = &self;
+        let field_names = self.fields.iter().map(|(name, _)| name).collect::<Vec<_>>();
+        let field_cfgs = self.fields.iter().map(|(_, cfg)| cfg);
+        let trait_name = self.variant.trait_name();
+        let trait_fn = self.variant.trait_fn();
+        let transform_generic_bounds = self.transform_generic_bounds.as_ref().map(|params| quote!(<#params>)).unwrap_or_default();
+        let fallibility_associated_types = self.variant.fallibility_associated_types();
+
+        let projected = quote!(<#nest_struct_ident as ::std::convert::From<&#data_ident>>::from(data));
+        let (nest_full_type, nest_value) = match self.optional {
+            true => (quote!(Option<#nest_struct_ident>), quote!(Some(#projected))),
+            false => (quote!(#nest_struct_ident), projected),
+        };
+        let (return_type, return_value) = match self.variant.error_type() {
+            Some(error_type) => (quote!(Result<#nest_full_type, #error_type>), quote!(Ok(#nest_value))),
+            None => (nest_full_type.clone(), nest_value),
+        };
+
+        tokens.extend(quote! {
+            #[automatically_derived]
+            impl ::std::convert::From<&#data_ident> for #nest_struct_ident {
+                fn from(data: &#data_ident) -> Self {
+                    Self {
+                        #( #(#field_cfgs)* #field_names: ::std::clone::Clone::clone(&data.#field_names), )*
+                    }
+                }
+            }
+            #[automatically_derived]
+            #[doc(hidden)]
+            impl #transform_generic_bounds ::shrinkwrap::__private::#trait_name<#nest_full_type> for #transform_type {
+                type Data = #data_ident;
+                #fallibility_associated_types
+
+                fn #trait_fn(&self, data: &Self::Data, _options: &Self::Options<'_>) -> #return_type {
+                    #return_value
+                }
+            }
+        });
+    }
+}
@@ -0,0 +1,60 @@
+use super::*;
+
+// !- GenTransformToNestFromProvider
+
+/// Generates a [`shrinkwrap::transform_to_nest`] trait impl delegating to a nest's `NestProvider`
+#[derive(Debug, Clone)]
+pub(crate) struct GenTransformToNestFromProvider {
+    /// The trait variant
+    pub(crate) variant: TransformToNestVariant,
+
+    /// The type of the user-defined struct implementing [`shrinkwrap::Transform`]
+    pub(crate) transform_type: Path,
+
+    /// Generic bounds for `transform_type`
+    pub(crate) transform_generic_bounds: Option<TokenStream>,
+
+    /// Ident of the source data struct
+    pub(crate) data_ident: Ident,
+
+    /// Provider type implementing `shrinkwrap::providers::NestProvider`
+    pub(crate) provider: Path,
+
+    /// Ident of the nest type (alias of the provider)
+    pub(crate) nest_struct_ident: Ident,
+
+    /// If set, the nest is always provided as `Some`
+    pub(crate) optional: bool,
+}
+impl ToTokens for GenTransformToNestFromProvider {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self { transform_type, data_ident, provider, nest_struct_ident, .. } = &self;
+        let trait_name = self.variant.trait_name();
+        let trait_fn = self.variant.trait_fn();
+        let transform_generic_bounds = self.transform_generic_bounds.as_ref().map(|params| quote!(<#params>)).unwrap_or_default();
+        let fallibility_associated_types = self.variant.fallibility_associated_types();
+
+        let provided = quote!(<#provider as ::shrinkwrap::__private::NestProvider<#data_ident>>::provide(data));
+        let (nest_full_type, nest_value) = match self.optional {
+            true => (quote!(Option<#nest_struct_ident>), quote!(Some(#provided))),
+            false => (quote!(#nest_struct_ident), provided),
+        };
+        let (return_type, return_value) = match self.variant.error_type() {
+            Some(error_type) => (quote!(Result<#nest_full_type, #error_type>), quote!(Ok(#nest_value))),
+            None => (nest_full_type.clone(), nest_value),
+        };
+
+        tokens.extend(quote! {
+            #[automatically_derived]
+            #[doc(hidden)]
+            impl #transform_generic_bounds ::shrinkwrap::__private::#trait_name<#nest_full_type> for #transform_type {
+                type Data = #data_ident;
+                #fallibility_associated_types
+
+                fn #trait_fn(&self, data: &Self::Data, _options: &Self::Options<'_>) -> #return_type {
+                    #return_value
+                }
+            }
+        });
+    }
+}
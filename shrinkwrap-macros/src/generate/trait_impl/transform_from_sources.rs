@@ -0,0 +1,119 @@
+use super::*;
+
+// !- GenNestSources
+
+/// Generates the borrowed source view struct for a nest with `sources` enabled
+#[derive(Debug, Clone)]
+pub(crate) struct GenNestSources {
+    /// Ident of the source view struct
+    pub(crate) ident: Ident,
+
+    /// Ident of the source data struct (origin or parent nest)
+    pub(crate) data_ident: Ident,
+
+    /// (field name, source field type, `cfg` attrs) for each field included in the nest
+    pub(crate) fields: Vec<(Ident, Type, Vec<Attribute>)>,
+}
+impl ToTokens for GenNestSources {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self { ident, data_ident, .. } = &self;
+        let doc = format!("Borrowed view of the [`{data_ident}`] fields used to build a nest");
+        let field_names = self.fields.iter().map(|(name, ..)| name).collect::<Vec<_>>();
+        let field_types = self.fields.iter().map(|(_, ty, _)| ty);
+        let field_cfgs = self.fields.iter().map(|(.., cfg)| cfg).collect::<Vec<_>>();
+
+        // nests without any (unconditional) fields still need to make use of the lifetime
+        let (marker_field, marker_value) = match self.fields.iter().all(|(.., cfg)| !cfg.is_empty()) {
+            true => (
+                quote!(#[doc(hidden)] pub __marker: ::std::marker::PhantomData<&'a #data_ident>,),
+                quote!(__marker: ::std::marker::PhantomData,),
+            ),
+            false => (quote!(), quote!()),
+        };
+
+        tokens.extend(quote! {
+            #[automatically_derived]
+            #[doc = #doc]
+            #[derive(::std::fmt::Debug, ::std::clone::Clone, ::std::marker::Copy)]
+            pub struct #ident<'a> {
+                #( #(#field_cfgs)* pub #field_names: &'a #field_types, )*
+                #marker_field
+            }
+            #[automatically_derived]
+            impl<'a> #ident<'a> {
+                pub fn from_data(data: &'a #data_ident) -> Self {
+                    Self {
+                        #( #(#field_cfgs)* #field_names: &data.#field_names, )*
+                        #marker_value
+                    }
+                }
+            }
+        });
+    }
+}
+
+// !- GenTransformToNestFromSources
+
+/// Generates a [`shrinkwrap::transform_to_nest`] trait impl delegating to `TransformFromSources`
+#[derive(Debug, Clone)]
+pub(crate) struct GenTransformToNestFromSources {
+    /// The trait variant
+    pub(crate) variant: TransformToNestVariant,
+
+    /// The type of the user-defined struct implementing [`shrinkwrap::Transform`]
+    pub(crate) transform_type: Path,
+
+    /// Generic bounds for `transform_type`
+    pub(crate) transform_generic_bounds: Option<TokenStream>,
+
+    /// Ident of the source data struct
+    pub(crate) data_ident: Ident,
+
+    /// Ident of the source view struct
+    pub(crate) sources_ident: Ident,
+
+    /// Full type of the nest (wrapped in Option for optional nests)
+    pub(crate) nest_full_type: Type,
+}
+impl ToTokens for GenTransformToNestFromSources {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self { transform_type, data_ident, sources_ident, nest_full_type, .. } = &self;
+        let trait_name = self.variant.trait_name();
+        let trait_fn = self.variant.trait_fn();
+        let transform_generic_bounds = self.transform_generic_bounds.as_ref().map(|params| quote!(<#params>)).unwrap_or_default();
+        let fallibility_associated_types = self.variant.fallibility_associated_types();
+        let sources_trait_name = self.variant.fallibility().trait_name(format_ident!("TransformFromSources2"));
+        let sources_trait_fn = self.variant.fallibility().trait_fn(format_ident!("transform_from_sources"));
+
+        let (sources_bound, return_type) = match self.variant.fallibility() {
+            Fallibility::Infallible => (
+                quote!(for<'a> Self: ::shrinkwrap::__private::#sources_trait_name<#sources_ident<'a>, #nest_full_type>),
+                quote!(#nest_full_type),
+            ),
+            Fallibility::Fallible { error_type } => (
+                quote!(for<'a> Self: ::shrinkwrap::__private::#sources_trait_name<#sources_ident<'a>, #nest_full_type, Error = #error_type>),
+                quote!(Result<#nest_full_type, #error_type>),
+            ),
+        };
+
+        tokens.extend(quote! {
+            #[automatically_derived]
+            #[doc(hidden)]
+            impl #transform_generic_bounds ::shrinkwrap::__private::#trait_name<#nest_full_type> for #transform_type
+            where
+                #sources_bound
+            {
+                type Data = #data_ident;
+                #fallibility_associated_types
+
+                fn #trait_fn(&self, data: &Self::Data, options: &Self::Options<'_>) -> #return_type {
+                    <Self as ::shrinkwrap::__private::#sources_trait_name<#sources_ident<'_>, #nest_full_type>>::#sources_trait_fn(
+                        self,
+                        #sources_ident::from_data(data),
+                        options,
+                    )
+                }
+            }
+        });
+    }
+}
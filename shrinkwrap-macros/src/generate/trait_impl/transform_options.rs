@@ -0,0 +1,51 @@
+use super::*;
+
+// !- GenTransformOptions
+
+/// Generates the `TransformOptions` impl of a transform's options struct, along with a `DeprecatedNestGate` impl
+/// forwarding to it
+#[derive(Debug, Clone)]
+pub(crate) struct GenTransformOptions {
+    /// Ident of the options struct
+    pub(crate) ident: Ident,
+
+    pub(crate) generics: syn::Generics,
+
+    /// (nest ID, gate field name) for each gated nest, in field order
+    pub(crate) gates: Vec<(String, Ident)>,
+
+    /// Additionally implement `DeprecatedNestGate`
+    pub(crate) deprecated_gate: bool,
+}
+impl ToTokens for GenTransformOptions {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self { ident, generics, gates, deprecated_gate } = &self;
+        let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+        let nest_ids = gates.iter().map(|(nest_id, _)| nest_id).collect::<Vec<_>>();
+        let fields = gates.iter().map(|(_, field)| field);
+
+        tokens.extend(quote! {
+            #[automatically_derived]
+            impl #impl_generics ::shrinkwrap::__private::TransformOptions for #ident #type_generics #where_clause {
+                const GATED_NESTS: &'static [&'static str] = &[#( #nest_ids ),*];
+
+                fn enabled(&self, nest: &str) -> bool {
+                    match nest {
+                        #( #nest_ids => self.#fields, )*
+                        _ => true,
+                    }
+                }
+            }
+        });
+        if *deprecated_gate {
+            tokens.extend(quote! {
+                #[automatically_derived]
+                impl #impl_generics ::shrinkwrap::__private::DeprecatedNestGate for #ident #type_generics #where_clause {
+                    fn emit_deprecated(&self, nest: &str) -> bool {
+                        ::shrinkwrap::__private::TransformOptions::enabled(self, nest)
+                    }
+                }
+            });
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use super::*;
+
+// !- GenTransformToAutoParent
+
+/// Generates a [`shrinkwrap::transform_to_nest`] trait impl into the wrapper of an `auto_parent` nest, built purely
+/// from the transforms of its children
+#[derive(Debug, Clone)]
+pub(crate) struct GenTransformToAutoParent {
+    /// The trait variant
+    pub(crate) variant: TransformToNestVariant,
+
+    /// The type of the user-defined struct implementing [`shrinkwrap::Transform`]
+    pub(crate) transform_type: Path,
+
+    /// Generic bounds for `transform_type`
+    pub(crate) transform_generic_bounds: Option<TokenStream>,
+
+    /// Ident of the source data struct, shared by the nest and its children
+    pub(crate) data_ident: Ident,
+
+    /// Wrapper struct type for the nest
+    pub(crate) nest_wrapper_ident: Ident,
+
+    /// Struct type for the (empty) nest
+    pub(crate) nest_ident: Ident,
+
+    /// Struct type for the nest's extra struct
+    pub(crate) extra_ident: Ident,
+
+    /// Field names of the nest's wrapper
+    pub(crate) data_name: Ident,
+    pub(crate) extra_name: Ident,
+
+    /// Fields of the nest's extra struct, one per child
+    pub(crate) children: Vec<GenStructField>,
+
+    /// Names of the optional children
+    pub(crate) optional_children: Vec<Ident>,
+
+    /// Whether or not the nest is optional, optional nests are `None` when all children are `None`
+    pub(crate) optional: bool,
+}
+impl ToTokens for GenTransformToAutoParent {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self { transform_type, data_ident, nest_wrapper_ident, nest_ident, extra_ident, data_name, extra_name, .. } = &self;
+        let trait_name = self.variant.trait_name();
+        let trait_fn = self.variant.trait_fn();
+        let trait_suffix = self.variant.trait_fn_call_suffix();
+        let transform_generic_bounds = self.transform_generic_bounds.as_ref().map(|params| quote!(<#params>)).unwrap_or_default();
+        let fallibility_associated_types = self.variant.fallibility_associated_types();
+
+        let bindings = self.children.iter().map(|child| {
+            let GenStructField { name, ty, .. } = child;
+            let binding = format_ident!("nest_{name}");
+            quote! {
+                let #binding = <Self as ::shrinkwrap::__private::#trait_name<#ty>>::#trait_fn(self, data, options) #trait_suffix;
+            }
+        });
+        let names = self.children.iter().map(|child| &child.name).collect::<Vec<_>>();
+        let binding_names = names.iter().map(|name| format_ident!("nest_{name}")).collect::<Vec<_>>();
+        let wrapper = quote! {
+            #nest_wrapper_ident {
+                #extra_name: #extra_ident {
+                    #( #names: #binding_names, )*
+                },
+                #data_name: #nest_ident {},
+            }
+        };
+
+        let all_optional = self.children.iter().all(|child| self.optional_children.contains(&child.name));
+        let (wrapper_type, empty_check, wrapper) = match self.optional {
+            true => (
+                quote!(Option<#nest_wrapper_ident>),
+                all_optional.then(|| {
+                    let empty = self.variant.fallibility().map_return(quote!(None));
+                    quote! {
+                        if #( #binding_names.is_none() )&&* {
+                            return #empty;
+                        }
+                    }
+                }),
+                quote!(Some(#wrapper)),
+            ),
+            false => (quote!(#nest_wrapper_ident), None, wrapper),
+        };
+        let return_type = match self.variant.error_type() {
+            Some(error_type) => quote!(Result<#wrapper_type, #error_type>),
+            None => wrapper_type.clone(),
+        };
+        let return_value = self.variant.fallibility().map_return(wrapper);
+
+        tokens.extend(quote! {
+            #[automatically_derived]
+            #[doc(hidden)]
+            impl #transform_generic_bounds ::shrinkwrap::__private::#trait_name<#wrapper_type> for #transform_type {
+                type Data = #data_ident;
+                #fallibility_associated_types
+
+                fn #trait_fn(&self, data: &Self::Data, options: &Self::Options<'_>) -> #return_type {
+                    #( #bindings )*
+                    #empty_check
+                    #return_value
+                }
+            }
+        });
+    }
+}
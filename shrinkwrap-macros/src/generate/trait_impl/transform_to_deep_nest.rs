@@ -27,6 +27,9 @@ pub(crate) struct GenTransformToDeepNest {
 
     /// Whether or not the destination nest is optional
     pub(crate) optional: bool,
+
+    /// Full type of the sibling nest required to build the nest (via `TransformToNestWithSiblings`), if any
+    pub(crate) sibling_type: Option<Type>,
 }
 impl GenTransformToDeepNest {
     fn nest_type(&self) -> TokenStream {
@@ -86,17 +89,44 @@ impl ToTokens for GenTransformToDeepNest {
         let wrap_data_with_name = self.variant.fallibility.trait_name(format_ident!("WrapDataWith"));
         let wrap_data_with_fn = self.variant.fallibility.trait_fn(format_ident!("wrap_data_with"));
 
+        if let Some(sibling_type) = &self.sibling_type {
+            let sibling_trait_name = self.variant.fallibility.trait_name(format_ident!("TransformToNestWithSiblings2"));
+            let sibling_trait_fn = self.variant.fallibility.trait_fn(format_ident!("transform_to_nest_with_siblings"));
+
+            tokens.extend(quote! {
+                #[automatically_derived]
+                #[doc(hidden)]
+                impl #transform_generic_bounds ::shrinkwrap::__private::#sibling_trait_name<#wrapper_type, #sibling_type> for #transform_type {
+                    #associated_types
+
+                    fn #sibling_trait_fn(
+                        &self,
+                        data: &Self::Data,
+                        sibling: &#sibling_type,
+                        options: &Self::Options<'_>,
+                    ) -> #return_type {
+                        use ::shrinkwrap::__private::{#sibling_trait_name, #wrap_data_with_name};
+
+                        let nest_data: #nest_type = <Self as #sibling_trait_name<#nest_type, #sibling_type>>::#sibling_trait_fn(self, data, sibling, options)#trait_suffix;
+                        #wrapper_call_type::#wrap_data_with_fn(nest_data, self, options)
+                    }
+                }
+            });
+            return;
+        }
+
         tokens.extend(quote! {
             #[automatically_derived]
-            impl #transform_generic_bounds ::shrinkwrap::#trait_name<#wrapper_type> for #transform_type {
+            #[doc(hidden)]
+            impl #transform_generic_bounds ::shrinkwrap::__private::#trait_name<#wrapper_type> for #transform_type {
                 #associated_types
 
                 fn #trait_fn(
                     &self,
                     data: &Self::Data,
-                    options: &Self::Options,
+                    options: &Self::Options<'_>,
                 ) -> #return_type {
-                    use ::shrinkwrap::{#to_nest_with_trait_name, #wrap_data_with_name};
+                    use ::shrinkwrap::__private::{#to_nest_with_trait_name, #wrap_data_with_name};
 
                     let nest_data: #nest_type = data.#to_nest_with_trait_fn(self, options)#trait_suffix;
                     #wrapper_call_type::#wrap_data_with_fn(nest_data, self, options)
@@ -6,7 +6,7 @@ use super::*;
 pub(crate) struct TransformToNestTrait;
 
 impl TransformTrait for TransformToNestTrait {
-    fn trait_name() -> Ident { format_ident!("TransformToNest") }
+    fn trait_name() -> Ident { format_ident!("TransformToNest2") }
     fn trait_fn() -> Ident { format_ident!("transform_to_nest") }
 }
 
@@ -42,7 +42,13 @@ pub(crate) struct GenTransformToNest {
 
     /// List of (nest_type, source field) types (only fields that are actually included in this nest).
     /// Must already be de-duplicated.
-    pub(crate) field_source_type_pairings: Vec<(Path, Type)>,
+    pub(crate) field_source_type_pairings: Vec<(Type, Type)>,
+
+    /// Fields mapped element-wise, their pairings above are of the element types
+    pub(crate) mapped_fields: Vec<Ident>,
+
+    /// Fields using `none_as`, their pairings above are of the `Some` value types
+    pub(crate) none_as_fields: Vec<(Ident, NoneAs)>,
 
     /// Struct type for the nest.
     pub(crate) nest_struct_ident: Ident,
@@ -61,10 +67,10 @@ impl GenTransformToNest {
         for (field_value_type, source_type) in &self.field_source_type_pairings {
             tokens.extend(match &self.variant.fallibility {
                 Fallibility::Infallible => quote! {
-                    Self: ::shrinkwrap::BuildNestValue<#source_type, #field_value_type>,
+                    Self: ::shrinkwrap::__private::BuildNestValue2<#source_type, #field_value_type>,
                 },
                 Fallibility::Fallible { error_type } => quote! {
-                    Self: ::shrinkwrap::TryBuildNestValue<#source_type, #field_value_type, Error = #error_type>,
+                    Self: ::shrinkwrap::__private::TryBuildNestValue2<#source_type, #field_value_type, Error = #error_type>,
                 }
             });
         }
@@ -92,14 +98,41 @@ impl GenTransformToNest {
 
     fn field_assignments(&self) -> TokenStream {
         let mut tokens = TokenStream::default();
-        let build_value_trait = BuildNestValueVariant::from(self.variant.fallibility.clone());
+        let build_value_trait = self.build_value_trait();
+        let build_value_trait_name = build_value_trait.trait_name();
         let build_value_trait_fn = build_value_trait.trait_fn();
+        let build_value = quote!(::shrinkwrap::__private::#build_value_trait_name::#build_value_trait_fn);
         let build_value_call_suffix = build_value_trait.trait_fn_call_suffix();
 
         for field in &self.nest_fields {
             let field_name = &field.name;
-            let field_tokens = quote! {
-                #field_name: self.#build_value_trait_fn(&data.#field_name, options)#build_value_call_suffix,
+            let cfg = cfg_attrs(&field.attrs);
+            tokens.extend(quote!(#(#cfg)*));
+            if let Some((_, none_as)) = self.none_as_fields.iter().find(|(name, _)| name == field_name) {
+                let mut value = quote!(data.#field_name.as_ref().map(|value| #build_value(self, value, options)));
+                if self.variant.is_fallible() {
+                    value.extend(quote!(.transpose()?));
+                }
+                if *none_as == NoneAs::Default {
+                    value.extend(quote!(.unwrap_or_default()));
+                }
+                tokens.extend(quote!(#field_name: #value,));
+                continue;
+            }
+            let field_tokens = match self.mapped_fields.contains(field_name) {
+                true if self.variant.is_fallible() => quote! {
+                    #field_name: data.#field_name.iter()
+                        .map(|element| #build_value(self, element, options))
+                        .collect::<Result<_, _>>()?,
+                },
+                true => quote! {
+                    #field_name: data.#field_name.iter()
+                        .map(|element| #build_value(self, element, options))
+                        .collect(),
+                },
+                false => quote! {
+                    #field_name: #build_value(self, &data.#field_name, options)#build_value_call_suffix,
+                },
             };
             tokens.extend(field_tokens);
         }
@@ -147,7 +180,6 @@ impl ToTokens for GenTransformToNest {
         let transform_generic_bounds = &self.transform_generic_bounds;
         let associated_types = self.associated_types();
         let field_assignments = self.field_assignments();
-        let build_value_trait_name = self.build_value_trait().trait_name();
 
         let nest_definition = quote! {
             #nest_ident {
@@ -159,15 +191,13 @@ impl ToTokens for GenTransformToNest {
 
         tokens.extend(quote! {
             #[automatically_derived]
-            impl #transform_generic_bounds ::shrinkwrap::#trait_name<#nest_full_type> for #transform_type
+            impl #transform_generic_bounds ::shrinkwrap::__private::#trait_name<#nest_full_type> for #transform_type
             where
                 #trait_bounds
             {
                 #associated_types
 
-                fn #trait_fn(&self, data: &Self::Data, options: &Self::Options) -> #return_type {
-                    use ::shrinkwrap::#build_value_trait_name;
-
+                fn #trait_fn(&self, data: &Self::Data, options: &Self::Options<'_>) -> #return_type {
                     #return_statement
                 }
             }
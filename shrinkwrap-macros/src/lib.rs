@@ -1,15 +1,53 @@
-use proc_macro_error2::proc_macro_error;
-
 mod generate;
 mod model;
 mod parse;
+mod preset;
+mod transform_options;
 mod util;
 mod wrap;
+#[cfg(test)]
+mod tests;
 
+use preset::{apply_preset_impl, preset_attr_impl};
+use transform_options::derive_transform_options_impl;
 use wrap::derive_wrap_impl;
 
-#[proc_macro_derive(Wrap, attributes(shrinkwrap, shrinkwrap_attr))]
-#[proc_macro_error]
+#[proc_macro_derive(Wrap, attributes(shrinkwrap, shrinkwrap_attr, shrinkwrap_preset))]
 pub fn derive_wrap(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     derive_wrap_impl(input)
 }
+
+/// Inert derive registering the `shrinkwrap`, `shrinkwrap_attr`, and
+/// `shrinkwrap_preset` helper attributes without generating anything.
+///
+/// Allows shrinkwrap attributes to remain in place when `Wrap` is only
+/// conditionally derived, e.g. via `cfg_attr`.
+#[proc_macro_derive(WrapHelpers, attributes(shrinkwrap, shrinkwrap_attr, shrinkwrap_preset))]
+pub fn derive_wrap_helpers(_input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    proc_macro::TokenStream::new()
+}
+
+/// Implements `shrinkwrap::TransformOptions` (and `shrinkwrap::DeprecatedNestGate`) for a transform's options
+/// struct, gating nests via its `bool` fields named `with_{nest_id}`.
+///
+/// Fields can gate other nests via `#[shrinkwrap(nest = "..")]`, or be excluded via `#[shrinkwrap(skip)]`. The
+/// `DeprecatedNestGate` impl is skipped via `#[shrinkwrap(no_deprecated_gate)]` on the struct.
+#[proc_macro_derive(TransformOptions, attributes(shrinkwrap))]
+pub fn derive_transform_options(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    derive_transform_options_impl(input)
+}
+
+/// Applies a preset declared via `shrinkwrap::define_preset!`, e.g.
+/// `#[shrinkwrap::preset(my_crate::org_defaults)]`.
+///
+/// Must be placed before `#[derive(Wrap)]`.
+#[proc_macro_attribute]
+pub fn preset(attr: proc_macro::TokenStream, item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    preset_attr_impl(attr, item)
+}
+
+#[doc(hidden)]
+#[proc_macro]
+pub fn __apply_preset(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    apply_preset_impl(input)
+}
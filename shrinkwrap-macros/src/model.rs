@@ -1,13 +1,17 @@
-use proc_macro_error2::abort_call_site;
-use proc_macro2::TokenStream;
-use quote::ToTokens;
+use heck::AsUpperCamelCase;
+use proc_macro2::{Span, TokenStream};
+use quote::{ToTokens, format_ident, quote};
 use std::rc::Rc;
 use std::collections::HashMap;
-use syn::{Attribute, Ident, Path, Type, parse_quote};
+use syn::{Attribute, Ident, LitStr, Path, Type, ext::IdentExt, parse_quote};
 
 use crate::{
-    generate::structs::{Derives, Doc, GenStruct, GenStructField, GenVisibility},
-    parse::ParsedField,
+    generate::structs::{Derives, Doc, GenEnum, GenEnumVariant, GenStruct, GenStructField, GenVisibility},
+    parse::{
+        ParsedField,
+        serde_attrs::{RenameRule, SerdeContainerAttrs, SerdeFieldAttrs},
+        types::{ExtraExclusiveOpts, NestEncode, NoneAs, WrapperComputedOpts},
+    },
 };
 
 // !- Primary model struct
@@ -15,7 +19,6 @@ use crate::{
 #[derive(Debug, Clone)]
 pub(crate) struct ModelTree {
     /// Origin model
-    #[allow(dead_code)]
     pub origin: Rc<OriginData>,
 
     /// Generated wrapper for the origin struct
@@ -36,7 +39,7 @@ impl ModelTree {
                 parents,
             }
         } else {
-            abort_call_site!("ModelTree wrapper must wrap origin data");
+            panic!("Internal macro error - ModelTree wrapper must wrap origin data");
         }
     }
 }
@@ -120,6 +123,9 @@ pub(crate) struct OriginData {
 
     /// All origin fields
     pub fields: Vec<OriginDataField>,
+
+    /// Serde container attributes declared on the origin struct
+    pub serde: SerdeContainerAttrs,
 }
 #[derive(Debug, Clone)]
 #[allow(dead_code)]
@@ -129,12 +135,16 @@ pub(crate) struct OriginDataField {
 
     /// The field's full type
     pub ty: Type,
+
+    /// Serde attributes declared on the field
+    pub serde: SerdeFieldAttrs,
 }
 impl From<&ParsedField> for OriginDataField {
     fn from(field: &ParsedField) -> Self {
         Self {
             name: field.name.clone(),
             ty: field.ty.clone(),
+            serde: field.serde.clone(),
         }
     }
 }
@@ -162,7 +172,9 @@ pub(crate) struct Wrapper {
     pub data_name: Ident,
     /// Field-level docs for the data field
     pub data_doc: Doc,
-    /// Flag for data flattening. If enabled, #[serde(flatten)] will be added to
+    /// List of custom attributes to apply to the data field
+    pub data_attrs: Vec<Attribute>,
+    /// Flag for data flattening. If enabled, #[serde(flatten)] is included in
     /// the data field's attributes
     pub data_flatten: bool,
     /// The data object
@@ -172,8 +184,16 @@ pub(crate) struct Wrapper {
     pub extra_name: Ident,
     /// Field-level docs for the extra field
     pub extra_doc: Doc,
+    /// List of custom attributes to apply to the extra field
+    pub extra_attrs: Vec<Attribute>,
     /// The extra object
     pub extra: Rc<Extra>,
+
+    /// Fields computed from the data and extra structs, only set for the primary wrapper
+    pub computed: Vec<WrapperComputedOpts>,
+
+    /// Name and type of the field holding the echoed transform options, only set for the primary wrapper
+    pub echo_options: Option<(Ident, Type)>,
 }
 impl ToTokens for Wrapper {
     fn to_tokens(&self, tokens: &mut TokenStream) {
@@ -216,6 +236,9 @@ pub(crate) struct NestData {
     /// Nest ID
     pub id: String,
 
+    /// Span of the nest ID, used for diagnostics
+    pub id_span: Span,
+
     /// Nest struct name / ident
     pub ident: Ident,
 
@@ -233,13 +256,38 @@ pub(crate) struct NestData {
 
     /// Info pertaining to auto-derivation of `TransformToNest` via `build_nest_value`
     pub derive_to_nest: Option<NestAutoDeriveToNest>,
+
+    /// Whether a `{Nest}Sources` view and a `TransformToNest` impl delegating to `TransformFromSources` are generated
+    pub sources: bool,
+
+    /// Provider type used in place of a generated struct, the nest is emitted as a type alias
+    pub provider: Option<Path>,
+
+    /// Map type used in place of a generated struct (`layout = "map"`), the nest is emitted as a type alias
+    pub map: Option<Type>,
+
+    /// Whether the nest is a projection of its source data, built via a generated `From<&Source>` impl
+    pub identity: bool,
+
+    /// Whether the nest is built for all items at once when wrapping collections
+    pub batch: bool,
+
+    /// Whether the nest is generated purely from its children, which are built from `source_ident`
+    pub auto_parent: bool,
+
+    /// Ident of the data the nest is built from, the parent nest (or origin) unless chained from an auto parent
+    pub source_ident: Ident,
 }
 impl NestData {
+    /// Whether the nest is emitted as a type alias (`provider` or `layout = "map"`) rather than a generated struct
+    pub(crate) fn is_alias(&self) -> bool {
+        self.provider.is_some() || self.map.is_some()
+    }
     /// Vec<(nest_field_type, source_field_type)>
-    pub(crate) fn nest_source_type_pairings(&self) -> Vec<(Path, Type)> {
+    pub(crate) fn nest_source_type_pairings(&self) -> Vec<(Type, Type)> {
         let mut pairs = Vec::new();
         for field in &self.fields {
-            let pair = (field.ty.clone(), field.source_type.clone());
+            let pair = field.element_types.clone().unwrap_or_else(|| (field.ty.clone(), field.source_type.clone()));
             if !pairs.contains(&pair) {
                 pairs.push(pair)
             }
@@ -249,7 +297,16 @@ impl NestData {
 }
 impl ToTokens for NestData {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        GenStruct::from(self).to_tokens(tokens);
+        let aliased = match (&self.provider, &self.map) {
+            (Some(provider), _) => quote!(#provider),
+            (None, Some(map)) => quote!(#map),
+            (None, None) => return GenStruct::from(self).to_tokens(tokens),
+        };
+        let Self { ident, doc, .. } = &self;
+        tokens.extend(quote! {
+            #doc
+            pub type #ident = #aliased;
+        });
     }
 }
 impl RecursiveToTokens for NestData {
@@ -273,11 +330,17 @@ pub(crate) struct NestDataField {
     pub name: Ident,
 
     /// The field's full type
-    pub ty: Path,
+    pub ty: Type,
 
     /// The fields source type
     pub source_type: Type,
 
+    /// (nest element type, source element type) for fields mapped element-wise, or using `none_as`
+    pub element_types: Option<(Type, Type)>,
+
+    /// Handling of `None` for optional source fields
+    pub none_as: Option<NoneAs>,
+
     /// List of custom attributes to apply to the field (field docs handled here
     /// as opposed to a dedicated attr type)
     pub attrs: Vec<Attribute>,
@@ -335,7 +398,6 @@ impl DataVariant {
             Self::Nest(n) => &n.ident,
         }
     }
-    #[allow(dead_code)]
     pub(crate) fn is_origin(&self) -> bool {
         match self {
             Self::Origin(..) => true,
@@ -380,10 +442,48 @@ pub(crate) struct Extra {
 
     /// Extra struct fields - each will be either `NestData` or a `Wrapper` (for sub-nests)
     pub fields: Vec<ExtraField>,
+
+    /// Generates an enum with a variant per field rather than a struct, only set for the primary extra
+    pub exclusive: Option<ExtraExclusiveOpts>,
+
+    /// Tag field name if generated as a `Vec` of tagged nests rather than a struct, only set for the primary extra
+    pub tagged_array: Option<LitStr>,
+
+    /// Optional fields default to `None` when missing during deserialization
+    pub default_optional: bool,
+}
+impl Extra {
+    /// Whether every nest of the extra is optional
+    pub(crate) fn all_optional(&self) -> bool {
+        self.fields.iter().all(|field| field.optional)
+    }
+    /// Whether any nest of the extra is serialized under its alias as well (see `GenSerialize`)
+    pub(crate) fn emits_aliases(&self) -> bool {
+        self.fields.iter().any(|field| field.emit_alias)
+    }
+}
+impl Extra {
+    /// Ident of the tagged enum used by `tagged_array` extras
+    pub(crate) fn item_ident(&self) -> Ident {
+        format_ident!("{}Item", self.ident)
+    }
 }
 impl ToTokens for Extra {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        GenStruct::from(self).to_tokens(tokens);
+        if self.tagged_array.is_some() {
+            let Self { ident, doc, .. } = &self;
+            let item_ident = self.item_ident();
+            GenEnum::from(self).to_tokens(tokens);
+            tokens.extend(quote! {
+                #doc
+                pub type #ident = ::std::vec::Vec<#item_ident>;
+            });
+            return;
+        }
+        match &self.exclusive {
+            Some(..) => GenEnum::from(self).to_tokens(tokens),
+            None => GenStruct::from(self).to_tokens(tokens),
+        }
     }
 }
 impl RecursiveToTokens for Extra {
@@ -408,9 +508,63 @@ pub(crate) struct ExtraField {
 
     /// Whether or not this field is optional
     pub optional: bool,
+
+    /// Name of the sibling field (within the same extra struct) required to build this field
+    pub needs: Option<Ident>,
+
+    /// Whether the field is serialized at the root of the parent wrapper instead (see `GenSerializePromoted`)
+    pub promote: bool,
+
+    /// Name of the sibling field holding the parent nest this field is built from, for nests hoisted from a parent
+    /// using `no_intermediate_wrapper`
+    pub from_parent: Option<Ident>,
+
+    /// Whether the field is only optional as its hoisted parent is, i.e. the nest is built (non-optionally) whenever
+    /// the parent is present
+    pub lifted: bool,
+
+    /// Name of the sibling field superseding this field, for nests deprecated via `deprecates`
+    pub superseded_by: Option<Ident>,
+
+    /// Name of the sibling field superseded by this field via `deprecates`
+    pub supersedes: Option<Ident>,
+
+    /// Whether the field is only built when enabled by the transform options, for deprecated nests gated via
+    /// `gate_deprecated`
+    pub gated: bool,
+
+    /// Alternate serialization format of the field, set via `encode`
+    pub encode: Option<NestEncode>,
+
+    /// Previous serialized key of the field, set via `alias`
+    pub alias: Option<String>,
+
+    /// Whether the field is also serialized under its `alias` while aliases are enabled at runtime
+    pub emit_alias: bool,
+
+    /// Additional attributes for the field
+    pub attrs: Vec<Attribute>,
 }
 impl ExtraField {
-    pub(crate) fn ty(&self) -> Path {
+    /// ID of the nest held by the field
+    pub(crate) fn nest_id(&self) -> &str {
+        match &self.object {
+            ExtraChildVariant::Nest(nest) => &nest.id,
+            ExtraChildVariant::Wrapper(wrapper) => match &wrapper.data {
+                DataVariant::Nest(nest) => &nest.id,
+                DataVariant::Origin(..) => unreachable!("nested wrappers always wrap nest data"),
+            },
+        }
+    }
+    /// Name of the field's variant within an `exclusive` extra enum
+    pub(crate) fn variant_name(&self) -> Ident {
+        format_ident!("{}", AsUpperCamelCase(self.name.unraw().to_string()).to_string(), span = self.name.span())
+    }
+    /// Whether the nest's transform builds `Option<Nest>`, rather than the field being lifted from its parent
+    pub(crate) fn transform_optional(&self) -> bool {
+        self.optional && !self.lifted
+    }
+    pub(crate) fn ty(&self) -> Type {
         let ident = self.object.ident();
         if self.optional {
             parse_quote!(Option<#ident>)
@@ -465,27 +619,35 @@ impl From<&Wrapper> for GenStruct {
             vis: GenVisibility::Public,
             name: source.extra_name.clone(),
             ty: parse_quote!(#extra_ident),
-            attrs: Vec::new(),
+            attrs: source.extra_attrs.clone(),
             doc: source.extra_doc.clone(),
         };
 
-        // if flatten is enabled, add #[serde(flatten)] to data field
-        let data_attrs = if source.data_flatten {
-            vec![parse_quote!(#[serde(flatten)])]
-        } else {
-            Vec::new()
-        };
         let data_field = GenStructField {
             vis: GenVisibility::Public,
             name: source.data_name.clone(),
             ty: parse_quote!(#data_ident),
-            attrs: data_attrs,
+            attrs: source.data_attrs.clone(),
             doc: source.data_doc.clone(),
         };
-        let fields = vec![
+        let mut fields = vec![
             extra_field,
             data_field,
         ];
+        fields.extend(source.computed.iter().map(|computed| GenStructField {
+            vis: GenVisibility::Public,
+            name: computed.field.clone(),
+            ty: computed.ty.clone(),
+            attrs: Vec::new(),
+            doc: Doc::default(),
+        }));
+        fields.extend(source.echo_options.iter().map(|(name, ty)| GenStructField {
+            vis: GenVisibility::Public,
+            name: name.clone(),
+            ty: ty.clone(),
+            attrs: Vec::new(),
+            doc: Doc::from(Some(" Subset of the transform options used to build the wrapper".to_string())),
+        }));
         Self {
             vis: GenVisibility::Public,
             ty: parse_quote!(#ident),
@@ -527,7 +689,14 @@ impl From<&NestDataField> for GenStructField {
 impl From<&Extra> for GenStruct {
     fn from(source: &Extra) -> Self {
         let ident = source.ident.clone();
-        let fields = source.fields.iter().map(GenStructField::from).collect();
+        let mut fields = source.fields.iter().map(GenStructField::from).collect::<Vec<_>>();
+        if source.default_optional {
+            for (field, extra_field) in fields.iter_mut().zip(&source.fields) {
+                if extra_field.optional {
+                    field.attrs.push(parse_quote!(#[serde(default)]));
+                }
+            }
+        }
 
         Self {
             vis: GenVisibility::Public,
@@ -539,13 +708,72 @@ impl From<&Extra> for GenStruct {
         }
     }
 }
+impl From<&Extra> for GenEnum {
+    fn from(source: &Extra) -> Self {
+        // variants are explicitly renamed, a container `rename_all` is applied to the rename instead
+        let rename_all = SerdeContainerAttrs::from_attrs(&source.attrs).rename_all;
+        if let Some(tag) = &source.tagged_array {
+            let mut attrs = source.attrs.clone();
+            attrs.push(parse_quote!(#[serde(tag = #tag)]));
+            let item_ident = source.item_ident();
+            return Self {
+                vis: GenVisibility::Public,
+                ty: parse_quote!(#item_ident),
+                derives: source.derives.clone(),
+                attrs,
+                doc: Some(format!("A single nest of [`{}`]", source.ident)).into(),
+                variants: source.fields.iter().map(|field| GenEnumVariant::from_extra_field(field, rename_all)).collect(),
+            };
+        }
+        let ident = source.ident.clone();
+        let mut attrs = source.attrs.clone();
+        match source.exclusive.as_ref().map(|exclusive| (&exclusive.tag, &exclusive.content)) {
+            Some((Some(tag), Some(content))) => attrs.push(parse_quote!(#[serde(tag = #tag, content = #content)])),
+            Some((Some(tag), None)) => attrs.push(parse_quote!(#[serde(tag = #tag)])),
+            _ => attrs.push(parse_quote!(#[serde(untagged)])),
+        }
+        let mut variants = source.fields.iter().map(|field| GenEnumVariant::from_extra_field(field, rename_all)).collect::<Vec<_>>();
+        variants.push(GenEnumVariant {
+            name: format_ident!("None"),
+            ty: None,
+            attrs: vec![parse_quote!(#[serde(rename = "none")])],
+            doc: Some("No nest is populated").into(),
+        });
+
+        Self {
+            vis: GenVisibility::Public,
+            ty: parse_quote!(#ident),
+            derives: source.derives.clone(),
+            attrs,
+            doc: source.doc.clone(),
+            variants,
+        }
+    }
+}
+impl GenEnumVariant {
+    fn from_extra_field(source: &ExtraField, rename_all: Option<RenameRule>) -> Self {
+        let ident = source.object.ident();
+        let name = source.name.unraw().to_string();
+        let rename = rename_all.map(|rule| rule.apply_to_field(&name)).unwrap_or(name);
+        let alias = source.alias.as_ref().map(|alias| parse_quote!(#[serde(alias = #alias)]));
+        Self {
+            name: source.variant_name(),
+            ty: Some(parse_quote!(#ident)),
+            attrs: std::iter::once(parse_quote!(#[serde(rename = #rename)])).chain(alias).collect(),
+            doc: Doc::default(),
+        }
+    }
+}
 impl From<&ExtraField> for GenStructField {
     fn from(source: &ExtraField) -> Self {
         Self {
             vis: GenVisibility::Public,
             name: source.name.clone(),
             ty: source.ty(),
-            attrs: Vec::default(),
+            attrs: source.promote.then(|| parse_quote!(#[serde(skip_serializing)]))
+                .into_iter()
+                .chain(source.attrs.iter().cloned())
+                .collect(),
             doc: Doc::default(),
         }
     }
@@ -1,16 +1,24 @@
-use darling::util::SpannedValue;
-use proc_macro_error2::{OptionExt, abort, emit_error};
+use darling::util::{Override, SpannedValue};
 use proc_macro2::Span;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use syn::{Attribute, Ident, Path, Type};
 
+use crate::util::{cfg_attrs, span_error};
+
+pub mod serde_attrs;
+use serde_attrs::{SerdeContainerAttrs, SerdeFieldAttrs};
+
+pub mod type_values;
+
 pub mod types;
 use types::{
     DeriveItemFieldOpts,
     ExtractedFieldAttribute,
     ExtractedStructAttribute,
     FieldProxyAttribute,
+    FieldValidation,
     NestOpts,
+    NoneAs,
     StructClass,
     StructFieldNestAssignment,
     StructProxyAttribute,
@@ -22,18 +30,26 @@ use types::{
 pub(crate) struct StructAttrResolver {
     /// Attributes with further nest ID + class filtering
     pub attrs: Vec<ExtractedStructAttribute>,
+
+    /// Serde container attributes declared on the origin struct
+    pub origin_serde: SerdeContainerAttrs,
 }
 impl StructAttrResolver {
-    pub(crate) fn from_attrs(source_attrs: Vec<&Attribute>) -> Self {
+    pub(crate) fn from_attrs(source_attrs: Vec<&Attribute>) -> darling::Result<Self> {
+        let mut errors = darling::Error::accumulator();
         let mut attrs = Vec::new();
+        let mut serde_attrs = Vec::new();
         for attr in source_attrs {
-            if let Some(extracted) = StructProxyAttribute::maybe_extract_from(attr) {
-                attrs.push(extracted);
+            match errors.handle(StructProxyAttribute::maybe_extract_from(attr)) {
+                Some(Some(extracted)) => attrs.push(extracted),
+                Some(None) if attr.path().is_ident("serde") => serde_attrs.push(attr.clone()),
+                _ => {},
             }
         }
-        Self {
-            attrs
-        }
+        errors.finish_with(Self {
+            attrs,
+            origin_serde: SerdeContainerAttrs::from_attrs(&serde_attrs),
+        })
     }
     pub(crate) fn resolve(&self, nest_id: Option<&str>, class: StructClass) -> Vec<Attribute> {
         match nest_id {
@@ -75,7 +91,22 @@ pub(crate) struct FieldResolver {
     nest_fields: HashMap<String, Vec<Ident>>,
 
     /// (Nest ID, field name ident) -> field type **override** for nest
-    nest_field_type: HashMap<(String, Ident), Path>,
+    nest_field_type: HashMap<(String, Ident), Type>,
+
+    /// (Nest ID, field name ident) -> `skip_empty` **override** for nest
+    nest_field_skip_empty: HashMap<(String, Ident), Override<Path>>,
+
+    /// (Nest ID, field name ident) pairs mapped element-wise
+    nest_field_map_elements: HashSet<(String, Ident)>,
+
+    /// (Nest ID, field name ident) -> `none_as` handling for nest
+    nest_field_none_as: HashMap<(String, Ident), NoneAs>,
+
+    /// (Nest ID, field name ident) -> field docs **override** for nest
+    nest_field_doc: HashMap<(String, Ident), String>,
+
+    /// (Nest ID, field name ident) -> `validator` rules for nest
+    nest_field_validation: HashMap<(String, Ident), FieldValidation>,
 }
 impl FieldResolver {
     pub(crate) fn new(fields: Vec<ParsedField>) -> Self {
@@ -84,6 +115,11 @@ impl FieldResolver {
             field_map: HashMap::with_capacity(fields.len()),
             nest_fields: HashMap::with_capacity(5),
             nest_field_type: HashMap::with_capacity(2*fields.len()),
+            nest_field_skip_empty: HashMap::new(),
+            nest_field_map_elements: HashSet::new(),
+            nest_field_none_as: HashMap::new(),
+            nest_field_doc: HashMap::new(),
+            nest_field_validation: HashMap::new(),
         };
         for field in fields {
             resolver.insert_field(field);
@@ -91,24 +127,34 @@ impl FieldResolver {
         resolver
     }
 
-    pub(crate) fn from_opt_fields(field_opts: Vec<DeriveItemFieldOpts>) -> Self {
+    pub(crate) fn from_opt_fields(field_opts: Vec<DeriveItemFieldOpts>) -> darling::Result<Self> {
+        let mut errors = darling::Error::accumulator();
         let mut fields = Vec::new();
         for field in field_opts {
             let mut attrs = Vec::new();
             for field_attr in &field.attrs {
-                if let Some(extracted) = FieldProxyAttribute::maybe_extract_from(field_attr) {
+                if let Some(Some(extracted)) = errors.handle(FieldProxyAttribute::maybe_extract_from(field_attr)) {
                     attrs.push(extracted);
                 }
             }
+            let Some(name) = field.ident else {
+                errors.push(darling::Error::custom("Only named structs are supported"));
+                continue;
+            };
             let parsed_field = ParsedField {
-                name: field.ident.unwrap_or_else(|| abort!(Span::call_site(), "Only named structs are supported")),
+                name,
+                serde: SerdeFieldAttrs::from_attrs(&field.attrs),
+                cfg: cfg_attrs(&field.attrs),
                 ty: field.ty,
                 nest_assignments: field.nest,
+                id_key: field.id_key.is_present(),
+                field_doc: field.field_doc,
+                unmapped: field.unmapped.is_present(),
                 attrs,
             };
             fields.push(parsed_field);
         }
-        Self::new(fields)
+        errors.finish_with(Self::new(fields))
     }
 
     pub(crate) fn insert_field(&mut self, field: ParsedField) {
@@ -118,6 +164,21 @@ impl FieldResolver {
             let field_type_pair = (nest_assignment.id.clone().into_inner(), field.name.clone());
 
             // add type override to nest field type map
+            if let Some(skip_empty) = &nest_assignment.skip_empty {
+                self.nest_field_skip_empty.insert(field_type_pair.clone(), skip_empty.clone());
+            }
+            if nest_assignment.map_elements.is_present() {
+                self.nest_field_map_elements.insert(field_type_pair.clone());
+            }
+            if let Some(none_as) = &nest_assignment.none_as {
+                self.nest_field_none_as.insert(field_type_pair.clone(), **none_as);
+            }
+            if let Some(validate) = &nest_assignment.validate {
+                self.nest_field_validation.insert(field_type_pair.clone(), (**validate).clone());
+            }
+            if let Some(doc) = &nest_assignment.doc {
+                self.nest_field_doc.insert(field_type_pair.clone(), doc.clone());
+            }
             if let Some(custom_type) = &nest_assignment.ty {
                 self.nest_field_type.insert(field_type_pair, custom_type.clone());
             }
@@ -125,45 +186,82 @@ impl FieldResolver {
         self.origin_fields.push(field.name.clone());
     }
     /// Checks that a parent nests' fields are a superset of the child fields
-    pub(crate) fn validate_parent_field_propagation(&self, nest_hierarchy: &NestHierarchy) -> bool {
-        let mut has_error = false;
+    pub(crate) fn validate_parent_field_propagation(&self, nest_hierarchy: &NestHierarchy) -> darling::Result<()> {
+        let mut errors = darling::Error::accumulator();
         for (nest_id, field_idents) in &self.nest_fields {
             let nest_opts = nest_hierarchy.get_nest_opts(nest_id);
             if let Some(parent_id) = &nest_opts.chain_from {
                 let parent_decl_span = parent_id.span();
-                let parent_id = parent_id.clone().into_inner();
+                let mut parent_id = parent_id.clone().into_inner();
+
+                // auto parents don't hold fields, their children are built from the data the auto parent is built from
+                while nest_hierarchy.get_nest_opts(&parent_id).auto_parent.is_present() {
+                    match &nest_hierarchy.get_nest_opts(&parent_id).chain_from {
+                        Some(grandparent_id) => parent_id = grandparent_id.clone().into_inner(),
+                        None => break,
+                    }
+                }
+                if nest_hierarchy.get_nest_opts(&parent_id).auto_parent.is_present() {
+                    // built from the origin, which includes every field
+                    continue;
+                }
                 let parent_fields: Vec<_> = self.nest_fields(&parent_id).into_iter().map(|field| &field.name).collect();
 
                 for nest_field in field_idents {
                     if !parent_fields.contains(&nest_field) {
-                        emit_error!(parent_decl_span, "Parent of `{}` nest configured here.", nest_id);
-                        emit_error!(nest_field, "Parent nest `{}` does not include field `{}` required by child nest `{}`.", parent_id, nest_field, nest_id);
-                        has_error = true;
+                        errors.push(span_error(parent_decl_span, format!("Parent of `{nest_id}` nest configured here.")));
+                        errors.push(darling::Error::custom(format!(
+                            "Parent nest `{parent_id}` does not include field `{nest_field}` required by child nest `{nest_id}`."
+                        )).with_span(nest_field));
                     }
                 }
             }
         }
 
-        !has_error
+        errors.finish()
     }
 
-    pub(crate) fn nest_field_type_override(&self, nest_id: String, field_name: Ident) -> Option<&Path> {
+    pub(crate) fn nest_field_type_override(&self, nest_id: String, field_name: Ident) -> Option<&Type> {
         self.nest_field_type.get(&(nest_id, field_name))
     }
 
+    pub(crate) fn nest_field_skip_empty_override(&self, nest_id: String, field_name: Ident) -> Option<&Override<Path>> {
+        self.nest_field_skip_empty.get(&(nest_id, field_name))
+    }
+
+    pub(crate) fn nest_field_maps_elements(&self, nest_id: String, field_name: Ident) -> bool {
+        self.nest_field_map_elements.contains(&(nest_id, field_name))
+    }
+
+    pub(crate) fn nest_field_none_as(&self, nest_id: String, field_name: Ident) -> Option<NoneAs> {
+        self.nest_field_none_as.get(&(nest_id, field_name)).copied()
+    }
+
+    pub(crate) fn nest_field_validation(&self, nest_id: String, field_name: Ident) -> Option<&FieldValidation> {
+        self.nest_field_validation.get(&(nest_id, field_name))
+    }
+
+    /// Docs of the field within the nest, either the nest-specific docs or the field's `field_doc`
+    pub(crate) fn nest_field_doc(&self, nest_id: String, field_name: Ident) -> Option<&str> {
+        match self.nest_field_doc.get(&(nest_id, field_name.clone())) {
+            Some(doc) => Some(doc),
+            None => self.field_map.get(&field_name).and_then(|field| field.field_doc.as_deref()),
+        }
+    }
+
     pub(crate) fn nest_fields(&self, nest_id: &str) -> Vec<&ParsedField> {
         self.nest_fields
         .get(nest_id)
         .cloned()
         .unwrap_or_default()
         .iter().map(|ident|
-            self.field_map.get(ident).expect_or_abort(format!("field missing from field_map: {ident}").as_str())
+            self.field_map.get(ident).unwrap_or_else(|| panic!("field missing from field_map: {ident}"))
         ).collect()
     }
 
     pub(crate) fn origin_fields(&self) -> Vec<&ParsedField> {
         self.origin_fields.iter().map(|ident|
-            self.field_map.get(ident).expect_or_abort(format!("field missing from field_map: {ident}").as_str())
+            self.field_map.get(ident).unwrap_or_else(|| panic!("field missing from field_map: {ident}"))
         ).collect()
     }
 
@@ -199,20 +297,23 @@ pub(crate) struct NestHierarchy {
 
     /// Map of parent ID to (first) span occurence
     parent_span: HashMap<String, Span>,
+
+    /// Map of nest ID to its position among all nest declarations
+    declaration_index: HashMap<String, usize>,
 }
 #[allow(dead_code)]
 impl NestHierarchy {
     pub(crate) fn new() -> Self {
         Self::default()
     }
-    pub(crate) fn from_nest_opts(nest_opts_list: Vec<SpannedValue<NestOpts>>) -> Self {
+    pub(crate) fn from_nest_opts(nest_opts_list: Vec<SpannedValue<NestOpts>>) -> darling::Result<Self> {
         let mut nest_hierarchy = Self::new();
         for nest_opts in nest_opts_list {
-            nest_hierarchy.insert(nest_opts.into_inner())
+            nest_hierarchy.insert(nest_opts.into_inner())?;
         }
-        nest_hierarchy.validate_post_insert();
+        nest_hierarchy.validate_post_insert()?;
 
-        nest_hierarchy
+        Ok(nest_hierarchy)
     }
 
     pub(crate) fn all_nest_ids(&self) -> Vec<String> {
@@ -228,32 +329,40 @@ impl NestHierarchy {
     pub(crate) fn get_nest_opts(&self, nest_id: &str) -> &NestOpts {
         self.nest_opts
             .get(nest_id)
-            .expect_or_abort(format!("Internal macro error - nest_opts map missing ID: {nest_id}").as_str())
+            .unwrap_or_else(|| panic!("Internal macro error - nest_opts map missing ID: {nest_id}"))
     }
-    pub(crate) fn get_children(&self, parent_id: Option<&str>) -> &Vec<String> {
-        let parent_id = parent_id.as_ref().map(|id| id.to_string());
+    /// IDs of the nests chained from `parent_id` (or the root nests if `None`), empty if there are none
+    pub(crate) fn get_children(&self, parent_id: Option<&str>) -> &[String] {
         self.parent_children
-            .get(&parent_id)
-            .expect_or_abort(format!("Internal macro error - parent_children map missing ID: {}", parent_id.unwrap_or("[none]".to_string())).as_str())
+            .get(&parent_id.map(str::to_string))
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+    /// Sort key of the nest's field within its extra struct, i.e. its `order` followed by its declaration position
+    pub(crate) fn get_field_order(&self, nest_id: &str) -> (i32, usize) {
+        let index = *self.declaration_index
+            .get(nest_id)
+            .unwrap_or_else(|| panic!("Internal macro error - declaration_index map missing ID: {nest_id}"));
+        (self.get_nest_opts(nest_id).order, index)
     }
     pub(crate) fn get_nest_id_span(&self, nest_id: &str) -> Span {
         *self.nest_span
             .get(nest_id)
-            .expect_or_abort(format!("Internal macro error - nest_span map missing ID: {nest_id}").as_str())
+            .unwrap_or_else(|| panic!("Internal macro error - nest_span map missing ID: {nest_id}"))
     }
     pub(crate) fn get_parent_id_span(&self, parent_id: &str) -> Span {
         *self.parent_span
             .get(parent_id)
-            .expect_or_abort(format!("Internal macro error - parent_span map missing ID: {parent_id}").as_str())
+            .unwrap_or_else(|| panic!("Internal macro error - parent_span map missing ID: {parent_id}"))
     }
 
-    fn insert(&mut self, opts: NestOpts) {
+    fn insert(&mut self, opts: NestOpts) -> darling::Result<()> {
         // resolve plain id's
         let nest_id = opts.id.clone();
         let parent_id = opts.chain_from.clone();
 
         // validate insert, destructure nest id span/value
-        self.validate_insert(nest_id.clone(), parent_id.clone());
+        self.validate_insert(nest_id.clone(), parent_id.clone())?;
         let (nest_id_span, nest_id) = (nest_id.span(), nest_id.into_inner());
 
         // insert NestOpts
@@ -265,25 +374,23 @@ impl NestHierarchy {
         { let _ = self.parent_children.entry(Some(nest_id.clone())).or_default(); } // add leaf nodes with empty vec
 
         // add to span maps
+        self.declaration_index.insert(nest_id.clone(), self.declaration_index.len());
         self.nest_span.insert(nest_id, nest_id_span);
         if let Some(parent_id) = parent_id {
             // destructure parent id span/value
             let (parent_id_span, parent_id) = (parent_id.span(), parent_id.into_inner());
             self.parent_span.insert(parent_id, parent_id_span);
         }
+        Ok(())
     }
-    fn validate_insert(&self, nest_id: SpannedValue<String>, parent_id: Option<SpannedValue<String>>) {
+    fn validate_insert(&self, nest_id: SpannedValue<String>, parent_id: Option<SpannedValue<String>>) -> darling::Result<()> {
         let (nest_id_span, nest_id) = (nest_id.span(), nest_id.into_inner());
 
         if let Some(span) = self.nest_span.get(&nest_id) {
-            emit_error!(
-                span,
-                format!("First nest with ID `{nest_id}` defined here")
-            );
-            abort!(
-                &nest_id_span,
-                format!("Multiple nests exist with ID: {nest_id}")
-            );
+            return Err(darling::Error::multiple(vec![
+                span_error(*span, format!("First nest with ID `{nest_id}` defined here")),
+                span_error(nest_id_span, format!("Multiple nests exist with ID: {nest_id}")),
+            ]));
         }
 
         // TODO: detect loops with more than 2 nodes
@@ -297,35 +404,86 @@ impl NestHierarchy {
                 let nest2_id_span = nest_id_span;
                 let nest2_parent_span = parent_id_span;
 
-                emit_error!(nest1_id_span, format!("`{nest_id}`'s parent is defined here"));
-                emit_error!(nest1_parent_span, format!("`{nest_id}`'s parent (`{parent_id}`) also has a parent nest assigned, however it is conflicting as it cycles back to `{nest_id}`."));
-                emit_error!(nest2_id_span, format!("{nest_id} is defined here"));
-                emit_error!(nest2_parent_span, format!("The `{nest_id}` parent is assigned to `{parent_id}` here"));
-                abort!(
-                    &parent_id_span,
-                    format!("Chained nest parent loop detected")
-                );
+                return Err(darling::Error::multiple(vec![
+                    span_error(nest1_id_span, format!("`{nest_id}`'s parent is defined here")),
+                    span_error(nest1_parent_span, format!("`{nest_id}`'s parent (`{parent_id}`) also has a parent nest assigned, however it is conflicting as it cycles back to `{nest_id}`.")),
+                    span_error(nest2_id_span, format!("{nest_id} is defined here")),
+                    span_error(nest2_parent_span, format!("The `{nest_id}` parent is assigned to `{parent_id}` here")),
+                    span_error(parent_id_span, "Chained nest parent loop detected"),
+                ]));
             }
         }
+        Ok(())
     }
-    pub(crate) fn validate_post_insert(&self) {
-        let mut has_errors = false;
+    pub(crate) fn validate_post_insert(&self) -> darling::Result<()> {
+        let mut errors = darling::Error::accumulator();
         // check for any parent IDs that don't have an associated nest defined
         for parent_id in self.parent_children.keys() {
             if let Some(parent_id) = parent_id.as_ref()
                 && !self.nest_span.contains_key(parent_id)
                 && let Some(parent_span) = self.parent_span.get(parent_id)
             {
-                emit_error!(
-                    parent_span,
-                    format!("Nest with id `{parent_id}` does not exist, yet is referenced here")
-                );
-
-                has_errors = true;
+                errors.push(span_error(*parent_span, format!("Nest with id `{parent_id}` does not exist, yet is referenced here")));
             }
         }
-        if has_errors {
-            abort!(Span::call_site(), "Nest validation failed");
+        self.validate_needs(&mut errors);
+        self.validate_deprecates(&mut errors);
+        errors.finish()
+    }
+    /// Checks that deprecated nests exist, share a parent, and are only superseded once
+    fn validate_deprecates(&self, errors: &mut darling::error::Accumulator) {
+        // sorted for a deterministic error order
+        let mut nest_ids = self.nest_opts.keys().collect::<Vec<_>>();
+        nest_ids.sort();
+        let mut superseded = HashMap::new();
+        for nest_id in nest_ids {
+            let opts = &self.nest_opts[nest_id];
+            let Some(deprecates) = &opts.deprecates else {
+                continue;
+            };
+            let Some(deprecated_opts) = self.nest_opts.get(deprecates.as_str()) else {
+                errors.push(span_error(deprecates.span(), format!("Nest with id `{}` does not exist, yet is referenced here", deprecates.as_str())));
+                continue;
+            };
+            let parent_id = opts.chain_from.as_ref().map(|id| id.as_str());
+            let deprecated_parent_id = deprecated_opts.chain_from.as_ref().map(|id| id.as_str());
+            if parent_id != deprecated_parent_id {
+                errors.push(span_error(deprecates.span(), format!("Nest `{nest_id}` can only deprecate sibling nests (nests with the same `chain_from`)")));
+                continue;
+            }
+            if let Some(other_id) = superseded.insert(deprecates.as_str(), nest_id.as_str()) {
+                errors.push(span_error(deprecates.span(), format!("Nest `{}` is already deprecated by nest `{other_id}`", deprecates.as_str())));
+            }
+        }
+    }
+    /// Checks that sibling dependencies exist, share a parent, and are acyclic
+    fn validate_needs(&self, errors: &mut darling::error::Accumulator) {
+        for (nest_id, opts) in &self.nest_opts {
+            let Some(needs) = &opts.needs else {
+                continue;
+            };
+            let Some(sibling_opts) = self.nest_opts.get(needs.as_str()) else {
+                errors.push(span_error(needs.span(), format!("Nest with id `{}` does not exist, yet is referenced here", needs.as_str())));
+                continue;
+            };
+            let parent_id = opts.chain_from.as_ref().map(|id| id.as_str());
+            let sibling_parent_id = sibling_opts.chain_from.as_ref().map(|id| id.as_str());
+            if parent_id != sibling_parent_id {
+                errors.push(span_error(needs.span(), format!("Nest `{nest_id}` can only depend on sibling nests (nests with the same `chain_from`)")));
+                continue;
+            }
+
+            // follow the dependency chain back to the current nest
+            let mut visited = vec![nest_id.as_str()];
+            let mut next = Some(needs.as_str());
+            while let Some(dependency_id) = next {
+                if visited.contains(&dependency_id) {
+                    errors.push(span_error(needs.span(), format!("Sibling dependency cycle detected for nest `{nest_id}`")));
+                    break;
+                }
+                visited.push(dependency_id);
+                next = self.nest_opts.get(dependency_id).and_then(|opts| opts.needs.as_ref()).map(|id| id.as_str());
+            }
         }
     }
 }
@@ -343,9 +501,24 @@ pub(crate) struct ParsedField {
     /// Attributes with further nest ID filtering
     pub attrs: Vec<ExtractedFieldAttribute>,
 
+    /// Serde attributes declared on the origin field
+    pub serde: SerdeFieldAttrs,
+
+    /// `#[cfg(..)]` attributes declared on the origin field, forwarded onto the field within each of its nests
+    pub cfg: Vec<Attribute>,
+
     /// Nest IDs which the field will be added to
     // pub nest_ids: HashSet<String>,
 
     /// List of ID + value type overrides
     pub nest_assignments: Vec<SpannedValue<StructFieldNestAssignment>>,
+
+    /// Field is the data's identity key
+    pub id_key: bool,
+
+    /// Docs of the field within its nests
+    pub field_doc: Option<String>,
+
+    /// Field is intentionally not assigned to any nest, see `audit`
+    pub unmapped: bool,
 }
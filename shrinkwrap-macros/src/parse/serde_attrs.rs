@@ -0,0 +1,433 @@
+#![doc = "Best-effort readers for `#[serde(..)]` attributes, used wherever the macro needs to know the serialized shape of a struct"]
+
+use darling::error::Accumulator;
+use syn::meta::ParseNestedMeta;
+use syn::spanned::Spanned;
+use syn::{Attribute, Expr, ExprLit, Ident, Lit, LitStr, Path, Token, Type};
+
+use crate::util::span_error;
+
+// !- Rename rules
+
+/// Mirror of serde's `rename_all` rules, only the subset applied to field names.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+}
+impl RenameRule {
+    pub(crate) fn from_str(rule: &str) -> Option<Self> {
+        match rule {
+            "lowercase" => Some(Self::Lower),
+            "UPPERCASE" => Some(Self::Upper),
+            "PascalCase" => Some(Self::Pascal),
+            "camelCase" => Some(Self::Camel),
+            "snake_case" => Some(Self::Snake),
+            "SCREAMING_SNAKE_CASE" => Some(Self::ScreamingSnake),
+            "kebab-case" => Some(Self::Kebab),
+            "SCREAMING-KEBAB-CASE" => Some(Self::ScreamingKebab),
+            _ => None,
+        }
+    }
+
+    /// The rule as accepted by serde's `rename_all`
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            Self::Lower => "lowercase",
+            Self::Upper => "UPPERCASE",
+            Self::Pascal => "PascalCase",
+            Self::Camel => "camelCase",
+            Self::Snake => "snake_case",
+            Self::ScreamingSnake => "SCREAMING_SNAKE_CASE",
+            Self::Kebab => "kebab-case",
+            Self::ScreamingKebab => "SCREAMING-KEBAB-CASE",
+        }
+    }
+
+    /// Applies the rule to a snake_case field name, matching serde's own field renaming
+    pub(crate) fn apply_to_field(&self, field: &str) -> String {
+        match self {
+            Self::Lower | Self::Snake => field.to_string(),
+            Self::Upper | Self::ScreamingSnake => field.to_ascii_uppercase(),
+            Self::Pascal => {
+                let mut pascal = String::new();
+                let mut capitalize = true;
+                for ch in field.chars() {
+                    if ch == '_' {
+                        capitalize = true;
+                    } else if capitalize {
+                        pascal.push(ch.to_ascii_uppercase());
+                        capitalize = false;
+                    } else {
+                        pascal.push(ch);
+                    }
+                }
+                pascal
+            }
+            Self::Camel => {
+                let pascal = Self::Pascal.apply_to_field(field);
+                let mut chars = pascal.chars();
+                match chars.next() {
+                    Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+                    None => pascal,
+                }
+            }
+            Self::Kebab => field.replace('_', "-"),
+            Self::ScreamingKebab => Self::ScreamingSnake.apply_to_field(field).replace('_', "-"),
+        }
+    }
+}
+
+// !- Container attributes
+
+/// Subset of serde container attributes relevant to the serialized shape
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SerdeContainerAttrs {
+    /// `rename_all = ".."` (or `rename_all(serialize = "..")`)
+    pub rename_all: Option<RenameRule>,
+}
+impl SerdeContainerAttrs {
+    pub(crate) fn from_attrs(attrs: &[Attribute]) -> Self {
+        let mut out = Self::default();
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("serde")) {
+            // unparseable serde attrs are left for serde to report
+            let _ = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename_all") {
+                    if let Some(rule) = parse_serialize_name(&meta)? {
+                        out.rename_all = RenameRule::from_str(&rule);
+                    }
+                } else {
+                    skip_meta_value(&meta)?;
+                }
+                Ok(())
+            });
+        }
+        out
+    }
+}
+
+// !- Field attributes
+
+/// Subset of serde field attributes relevant to the serialized shape
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SerdeFieldAttrs {
+    /// `rename = ".."` (or `rename(serialize = "..")`)
+    pub rename: Option<String>,
+    /// `skip` or `skip_serializing`
+    pub skip_serializing: bool,
+    /// `skip` or `skip_deserializing`
+    pub skip_deserializing: bool,
+    /// `skip_serializing_if = ".."`
+    pub skip_serializing_if: Option<Path>,
+    /// `default` or `default = ".."`
+    pub default: bool,
+    /// Path provided via `default = ".."`
+    pub default_fn: Option<Path>,
+    /// `flatten`
+    pub flatten: bool,
+    /// `with = ".."` or `serialize_with = ".."`
+    pub custom_serialize: bool,
+    /// `with = ".."` or `deserialize_with = ".."`
+    pub custom_deserialize: bool,
+}
+impl SerdeFieldAttrs {
+    pub(crate) fn from_attrs(attrs: &[Attribute]) -> Self {
+        let mut out = Self::default();
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("serde")) {
+            // unparseable serde attrs are left for serde to report
+            let _ = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    out.rename = parse_serialize_name(&meta)?;
+                } else if meta.path.is_ident("skip") {
+                    out.skip_serializing = true;
+                    out.skip_deserializing = true;
+                } else if meta.path.is_ident("skip_serializing") {
+                    out.skip_serializing = true;
+                } else if meta.path.is_ident("skip_deserializing") {
+                    out.skip_deserializing = true;
+                } else if meta.path.is_ident("skip_serializing_if") {
+                    let path: LitStr = meta.value()?.parse()?;
+                    out.skip_serializing_if = Some(path.parse()?);
+                } else if meta.path.is_ident("default") {
+                    out.default = true;
+                    if meta.input.peek(syn::Token![=]) {
+                        let path: LitStr = meta.value()?.parse()?;
+                        out.default_fn = Some(path.parse()?);
+                    }
+                } else if meta.path.is_ident("flatten") {
+                    out.flatten = true;
+                } else if meta.path.is_ident("with") {
+                    out.custom_serialize = true;
+                    out.custom_deserialize = true;
+                    skip_meta_value(&meta)?;
+                } else if meta.path.is_ident("serialize_with") {
+                    out.custom_serialize = true;
+                    skip_meta_value(&meta)?;
+                } else if meta.path.is_ident("deserialize_with") {
+                    out.custom_deserialize = true;
+                    skip_meta_value(&meta)?;
+                } else {
+                    skip_meta_value(&meta)?;
+                }
+                Ok(())
+            });
+        }
+        out
+    }
+
+    /// The key used for the field once serialized
+    pub(crate) fn serialized_name(&self, field_name: &str, container: &SerdeContainerAttrs) -> String {
+        if let Some(rename) = &self.rename {
+            rename.clone()
+        } else if let Some(rule) = container.rename_all {
+            rule.apply_to_field(field_name.trim_start_matches("r#"))
+        } else {
+            field_name.trim_start_matches("r#").to_string()
+        }
+    }
+}
+
+// !- Helpers
+
+/// Reads `key = ".."` or `key(serialize = "..")`, returning the serialize-side name
+fn parse_serialize_name(meta: &syn::meta::ParseNestedMeta) -> syn::Result<Option<String>> {
+    if meta.input.peek(syn::Token![=]) {
+        let name: LitStr = meta.value()?.parse()?;
+        Ok(Some(name.value()))
+    } else {
+        let mut name = None;
+        meta.parse_nested_meta(|inner| {
+            let value: LitStr = inner.value()?.parse()?;
+            if inner.path.is_ident("serialize") {
+                name = Some(value.value());
+            }
+            Ok(())
+        })?;
+        Ok(name)
+    }
+}
+
+/// Consumes the value (if any) of a meta item that isn't of interest
+pub(crate) fn skip_meta_value(meta: &syn::meta::ParseNestedMeta) -> syn::Result<()> {
+    if meta.input.peek(syn::Token![=]) {
+        let _: Expr = meta.value()?.parse()?;
+    } else if meta.input.peek(syn::token::Paren) {
+        meta.parse_nested_meta(|inner| skip_meta_value(&inner))?;
+    }
+    Ok(())
+}
+
+/// Checks if the last segment of a type path is `Option`
+pub(crate) fn is_option(ty: &Type) -> bool {
+    if let Type::Path(type_path) = ty && let Some(segment) = type_path.path.segments.last() {
+        segment.ident == "Option"
+    } else {
+        false
+    }
+}
+
+
+// !- Passthrough validation
+
+/// Position a passthrough attribute is applied to, serde accepts different keys for containers and fields
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum AttrPosition {
+    Container,
+    Field,
+}
+
+/// Expected value of a known serde/schemars key
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum KeyShape {
+    /// `key`
+    Flag,
+    /// `key = ".."`
+    Str,
+    /// `key = "path::to::item"`
+    PathStr,
+    /// `key` or `key = "path::to::item"`
+    OptionalPathStr,
+    /// `key = ".."` or `key(serialize = "..", deserialize = "..")`
+    Name,
+    /// A rename rule, as `key = ".."` or `key(serialize = "..", deserialize = "..")`
+    Rule,
+    /// Not validated
+    Any,
+}
+
+const SERDE_CONTAINER_KEYS: &[(&str, KeyShape)] = &[
+    ("rename", KeyShape::Name),
+    ("rename_all", KeyShape::Rule),
+    ("rename_all_fields", KeyShape::Rule),
+    ("deny_unknown_fields", KeyShape::Flag),
+    ("tag", KeyShape::Str),
+    ("content", KeyShape::Str),
+    ("untagged", KeyShape::Flag),
+    ("bound", KeyShape::Any),
+    ("default", KeyShape::OptionalPathStr),
+    ("remote", KeyShape::PathStr),
+    ("transparent", KeyShape::Flag),
+    ("from", KeyShape::Str),
+    ("try_from", KeyShape::Str),
+    ("into", KeyShape::Str),
+    ("crate", KeyShape::PathStr),
+    ("expecting", KeyShape::Str),
+];
+
+const SERDE_FIELD_KEYS: &[(&str, KeyShape)] = &[
+    ("rename", KeyShape::Name),
+    ("alias", KeyShape::Str),
+    ("default", KeyShape::OptionalPathStr),
+    ("flatten", KeyShape::Flag),
+    ("skip", KeyShape::Flag),
+    ("skip_serializing", KeyShape::Flag),
+    ("skip_deserializing", KeyShape::Flag),
+    ("skip_serializing_if", KeyShape::PathStr),
+    ("serialize_with", KeyShape::PathStr),
+    ("deserialize_with", KeyShape::PathStr),
+    ("with", KeyShape::PathStr),
+    ("borrow", KeyShape::Any),
+    ("bound", KeyShape::Any),
+    ("getter", KeyShape::PathStr),
+];
+
+/// Keys schemars accepts in addition to the serde keys, these are recognized but not validated
+const SCHEMARS_KEYS: &[&str] = &[
+    "title", "description", "example", "deprecated", "inline", "extend", "transform", "schema_with", "crate",
+    "length", "range", "regex", "contains", "email", "url", "required", "pattern", "inner",
+];
+
+/// Validates the shape of a passthrough `serde(..)`/`schemars(..)` attribute (keys and value types), reporting errors
+/// at the offending tokens rather than leaving them to the downstream derives.
+///
+/// Attributes with other paths are passed through unchecked, unless their path looks like a typo of `serde` or
+/// `schemars`.
+pub(crate) fn validate_passthrough_attr(attr: &Attribute, position: AttrPosition, errors: &mut Accumulator) {
+    let Some(ident) = attr.path().get_ident() else {
+        return;
+    };
+    let is_schemars = ident == "schemars";
+    if ident != "serde" && !is_schemars {
+        if let Some(known) = closest_match(&ident.to_string(), ["serde", "schemars"]) {
+            errors.push(span_error(ident.span(), format!("Unknown attribute `{ident}`, did you mean `{known}`?")));
+        }
+        return;
+    }
+
+    let keys = match position {
+        AttrPosition::Container => SERDE_CONTAINER_KEYS,
+        AttrPosition::Field => SERDE_FIELD_KEYS,
+    };
+    let result = attr.parse_nested_meta(|meta| {
+        let key = meta.path.get_ident().map(Ident::to_string).unwrap_or_default();
+        match keys.iter().find(|(known, _)| *known == key) {
+            Some((_, shape)) => validate_key_shape(&meta, &key, *shape, errors),
+            None if is_schemars && SCHEMARS_KEYS.contains(&key.as_str()) => skip_meta_value(&meta),
+            None => {
+                let mut candidates = keys.iter().map(|(known, _)| *known).collect::<Vec<_>>();
+                if is_schemars {
+                    candidates.extend(SCHEMARS_KEYS);
+                }
+                let position = match position {
+                    AttrPosition::Container => "struct",
+                    AttrPosition::Field => "field",
+                };
+                let message = match closest_match(&key, candidates) {
+                    Some(known) => format!("Unknown {ident} {position} attribute `{key}`, did you mean `{known}`?"),
+                    None => format!("Unknown {ident} {position} attribute `{key}`"),
+                };
+                errors.push(span_error(meta.path.span(), message));
+                skip_meta_value(&meta)
+            },
+        }
+    });
+    if let Err(error) = result {
+        errors.push(error.into());
+    }
+}
+
+fn validate_key_shape(meta: &ParseNestedMeta, key: &str, shape: KeyShape, errors: &mut Accumulator) -> syn::Result<()> {
+    let has_value = meta.input.peek(Token![=]);
+    let has_list = meta.input.peek(syn::token::Paren);
+    match shape {
+        KeyShape::Any => skip_meta_value(meta),
+        KeyShape::Flag => {
+            if has_value || has_list {
+                errors.push(span_error(meta.path.span(), format!("`{key}` is a flag and does not take a value")));
+            }
+            skip_meta_value(meta)
+        },
+        KeyShape::Str | KeyShape::PathStr | KeyShape::OptionalPathStr if !has_value => {
+            if shape != KeyShape::OptionalPathStr || has_list {
+                errors.push(span_error(meta.path.span(), format!("`{key}` expects a string value, e.g. `{key} = \"..\"`")));
+            }
+            skip_meta_value(meta)
+        },
+        KeyShape::Str | KeyShape::PathStr | KeyShape::OptionalPathStr => validate_str_value(meta, key, shape, errors),
+        KeyShape::Name | KeyShape::Rule if has_value => validate_str_value(meta, key, shape, errors),
+        KeyShape::Name | KeyShape::Rule if has_list => meta.parse_nested_meta(|inner| {
+            if inner.path.is_ident("serialize") || inner.path.is_ident("deserialize") {
+                validate_str_value(&inner, key, shape, errors)
+            } else {
+                errors.push(span_error(inner.path.span(), format!("`{key}` only accepts `serialize` and `deserialize`")));
+                skip_meta_value(&inner)
+            }
+        }),
+        KeyShape::Name | KeyShape::Rule => {
+            errors.push(span_error(meta.path.span(), format!("`{key}` expects a value, e.g. `{key} = \"..\"`")));
+            Ok(())
+        },
+    }
+}
+
+fn validate_str_value(meta: &ParseNestedMeta, key: &str, shape: KeyShape, errors: &mut Accumulator) -> syn::Result<()> {
+    let value: Expr = meta.value()?.parse()?;
+    let Expr::Lit(ExprLit { lit: Lit::Str(value), .. }) = &value else {
+        errors.push(span_error(value.span(), format!("`{key}` expects a string literal")));
+        return Ok(());
+    };
+    match shape {
+        KeyShape::PathStr | KeyShape::OptionalPathStr if value.parse::<Path>().is_err() => {
+            errors.push(span_error(value.span(), format!("`{key}` expects a path, e.g. `{key} = \"my_mod::my_fn\"`")));
+        },
+        KeyShape::Rule if RenameRule::from_str(&value.value()).is_none() => {
+            errors.push(span_error(value.span(), format!("Unknown rename rule `{}`", value.value())));
+        },
+        _ => {},
+    }
+    Ok(())
+}
+
+/// The closest of `candidates` within a couple of edits of `name`
+fn closest_match<'a>(name: &str, candidates: impl IntoIterator<Item = &'a str>) -> Option<&'a str> {
+    candidates.into_iter()
+        .map(|candidate| (edit_distance(name, candidate), candidate))
+        .filter(|(distance, _)| *distance <= 2)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Levenshtein distance, with adjacent transpositions counted as a single edit
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a = a.chars().collect::<Vec<_>>();
+    let b = b.chars().collect::<Vec<_>>();
+    let mut rows = vec![(0..=b.len()).collect::<Vec<_>>()];
+    for i in 1..=a.len() {
+        let mut row = vec![i; b.len() + 1];
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            row[j] = (rows[i - 1][j] + 1).min(row[j - 1] + 1).min(rows[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                row[j] = row[j].min(rows[i - 2][j - 2] + 1);
+            }
+        }
+        rows.push(row);
+    }
+    rows[a.len()][b.len()]
+}
@@ -0,0 +1,105 @@
+#![doc = "Quoting of unquoted generic types within `#[shrinkwrap(..)]` attributes, prior to parsing them via darling"]
+
+use proc_macro2::{Delimiter, Group, Spacing, TokenStream, TokenTree};
+use syn::{Data, DeriveInput, LitStr, Meta};
+
+/// Options holding a type, see `parse_type`
+const TYPE_KEYS: &[&str] = &["field_type", "key_type", "ty", "type", "value"];
+
+/// Rewrites unquoted type values containing generics (e.g. `field_type = BTreeMap<String, String>`) as string
+/// literals within the `shrinkwrap` (and `shrinkwrap_preset`) attributes of the struct and its fields, `None` if
+/// there are none.
+///
+/// Such values aren't valid expressions, their generic arguments would otherwise be split into separate meta items.
+/// Values with unbalanced angle brackets (e.g. comparisons) are left as-is.
+pub(crate) fn quote_type_values(input: &DeriveInput) -> Option<DeriveInput> {
+    let mut output = input.clone();
+    let mut rewritten = false;
+    let field_attrs = match &mut output.data {
+        Data::Struct(data) => data.fields.iter_mut().flat_map(|field| &mut field.attrs).collect(),
+        _ => Vec::new(),
+    };
+    for attr in output.attrs.iter_mut().chain(field_attrs) {
+        if !attr.path().is_ident("shrinkwrap") && !attr.path().is_ident("shrinkwrap_preset") {
+            continue;
+        }
+        if let Meta::List(list) = &mut attr.meta && let Some(tokens) = quote_stream(list.tokens.clone()) {
+            list.tokens = tokens;
+            rewritten = true;
+        }
+    }
+    rewritten.then_some(output)
+}
+
+/// Rewrites the type values within `tokens` (recursing into nested option lists), `None` if there are none
+fn quote_stream(tokens: TokenStream) -> Option<TokenStream> {
+    let tokens = tokens.into_iter().collect::<Vec<_>>();
+    let mut out = Vec::with_capacity(tokens.len());
+    let mut rewritten = false;
+    let mut index = 0;
+    while index < tokens.len() {
+        let token = &tokens[index];
+        let at_key = index == 0 || is_punct(&tokens[index - 1], ',');
+        if at_key
+            && let TokenTree::Ident(ident) = token
+            && TYPE_KEYS.contains(&ident.to_string().as_str())
+            && let Some(TokenTree::Punct(eq)) = tokens.get(index + 1)
+            && eq.as_char() == '=' && eq.spacing() == Spacing::Alone
+            && let Some(len) = generic_value_len(&tokens[index + 2..])
+        {
+            let value = &tokens[index + 2..index + 2 + len];
+            let lit = LitStr::new(&value.iter().cloned().collect::<TokenStream>().to_string(), value[0].span());
+            out.extend([token.clone(), tokens[index + 1].clone(), TokenTree::Literal(lit.token())]);
+            rewritten = true;
+            index += 2 + len;
+            continue;
+        }
+        match token {
+            TokenTree::Group(group) if group.delimiter() == Delimiter::Parenthesis => match quote_stream(group.stream()) {
+                Some(stream) => {
+                    let mut nested = Group::new(group.delimiter(), stream);
+                    nested.set_span(group.span());
+                    out.push(TokenTree::Group(nested));
+                    rewritten = true;
+                },
+                None => out.push(token.clone()),
+            },
+            _ => out.push(token.clone()),
+        }
+        index += 1;
+    }
+    rewritten.then(|| out.into_iter().collect())
+}
+
+/// Number of tokens of the value at the start of `tokens` (up to the next top-level comma), if it contains generics
+/// with balanced angle brackets
+fn generic_value_len(tokens: &[TokenTree]) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut generic = false;
+    let mut len = 0;
+    for (index, token) in tokens.iter().enumerate() {
+        if let TokenTree::Punct(punct) = token {
+            match punct.as_char() {
+                ',' if depth == 0 => break,
+                '<' => {
+                    depth += 1;
+                    generic = true;
+                },
+                // the arrow of `fn(..) -> T`
+                '>' if index > 0 && is_joint_punct(&tokens[index - 1], '-') => {},
+                '>' => depth = depth.checked_sub(1)?,
+                _ => {},
+            }
+        }
+        len += 1;
+    }
+    (generic && depth == 0).then_some(len)
+}
+
+fn is_punct(token: &TokenTree, ch: char) -> bool {
+    matches!(token, TokenTree::Punct(punct) if punct.as_char() == ch)
+}
+
+fn is_joint_punct(token: &TokenTree, ch: char) -> bool {
+    matches!(token, TokenTree::Punct(punct) if punct.as_char() == ch && punct.spacing() == Spacing::Joint)
+}
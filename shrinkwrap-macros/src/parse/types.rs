@@ -2,26 +2,62 @@
 
 
 use darling::ast::Data;
+use darling::error::Accumulator;
 use darling::util::{Flag, Override, PathList, SpannedValue};
 use darling::{FromDeriveInput, FromField, FromMeta};
 use heck::AsUpperCamelCase;
-use proc_macro_error2::{OptionExt, abort, emit_error};
 use proc_macro2::{Span, TokenStream};
-use quote::format_ident;
+use quote::{ToTokens, format_ident, quote};
 use std::collections::{HashMap, HashSet};
-use syn::{Attribute, Ident, LitStr, Meta, Path, Type, parse_quote, spanned::Spanned};
+use syn::{Attribute, Ident, LitStr, Meta, Path, Type, ext::IdentExt, parse_quote, spanned::Spanned};
+
+use crate::parse::serde_attrs::{AttrPosition, validate_passthrough_attr};
+use crate::util::span_error;
 
 // !- Statics & Consts
 
 static FORWARD_ATTR: &str = "shrinkwrap_attr";
 
+// !- Parsing helpers
+
+/// Parses a type from either a string literal or an expression which is also
+/// valid as a type (paths, arrays, tuples, turbofish generics, etc.)
+///
+/// Unquoted generic types are quoted beforehand, see `type_values`
+pub(crate) fn parse_type(meta: &Meta) -> darling::Result<Type> {
+    let expr = &meta.require_name_value()?.value;
+    if let syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(lit), .. }) = expr {
+        return lit.parse().map_err(|err| darling::Error::from(err).with_span(lit));
+    }
+    syn::parse2(quote::ToTokens::to_token_stream(expr))
+        .map_err(|_| darling::Error::custom("Expected a type, consider quoting it as a string (e.g. \"&'static str\")").with_span(expr))
+}
+pub(crate) fn parse_optional_type(meta: &Meta) -> darling::Result<Option<Type>> {
+    parse_type(meta).map(Some)
+}
+
+/// Whether `attr` is `#[repr(C)]` or `#[repr(transparent)]`, possibly alongside other representation hints
+fn is_repr_c(attr: &Attribute) -> bool {
+    let mut found = false;
+    if attr.path().is_ident("repr") {
+        let _ = attr.parse_nested_meta(|meta| {
+            found |= meta.path.is_ident("C") || meta.path.is_ident("transparent");
+            if meta.input.peek(syn::token::Paren) {
+                meta.input.parse::<proc_macro2::Group>()?;
+            }
+            Ok(())
+        });
+    }
+    found
+}
+
 // !- Derive entrypoint
 
 /// Root derive options
 #[derive(Debug, Clone, FromDeriveInput)]
 #[darling(
     attributes(shrinkwrap),
-    forward_attrs(allow, doc, cfg, shrinkwrap_attr),
+    forward_attrs(allow, doc, cfg, repr, serde, shrinkwrap_attr),
     supports(struct_named)
 )]
 pub(crate) struct DeriveItemOpts {
@@ -42,34 +78,107 @@ pub(crate) struct DeriveItemOpts {
     pub global_opts: GlobalOpts,
 }
 impl DeriveItemOpts {
-    pub(crate) fn validate(&self) -> bool {
-        let wrapper_errors = self.wrapper_opts.validate();
-        let extra_errors = self.extra_opts.validate();
+    /// Moves the inline `nest_here` declarations of fields into struct-level nests and field assignments, merging
+    /// duplicate declarations
+    pub(crate) fn declare_field_nests(&mut self) -> darling::Result<()> {
+        let mut errors = darling::Error::accumulator();
+        let Data::Struct(data) = &mut self.data else {
+            return Ok(());
+        };
+        for field in &mut data.fields {
+            for decl in std::mem::take(&mut field.nest_here) {
+                let span = decl.span();
+                let decl = decl.into_inner();
+                let nest_id = decl.id.as_str();
+                match self.nest_opts.iter().find(|nest| nest.id_str() == nest_id) {
+                    Some(nest) => {
+                        let declared = nest.field_type.as_ref().map(|ty| ty.to_token_stream().to_string());
+                        let conflicting = decl.field_type.as_ref()
+                            .is_some_and(|ty| declared.as_ref() != Some(&ty.to_token_stream().to_string()));
+                        if conflicting {
+                            errors.push(span_error(nest.id.span(), format!("Nest `{nest_id}` first declared here")));
+                            errors.push(span_error(span, format!("Nest `{nest_id}` is already declared with a different `field_type`")));
+                        }
+                    }
+                    None => match &decl.field_type {
+                        Some(field_type) => {
+                            let id = LitStr::new(nest_id, decl.id.span());
+                            let meta: Meta = parse_quote!(nest(id = #id));
+                            if let Some(mut nest) = errors.handle(NestOpts::from_meta(&meta)) {
+                                nest.id = decl.id.clone();
+                                nest.field_type = Some(field_type.clone());
+                                self.nest_opts.push(SpannedValue::new(nest, span));
+                            }
+                        }
+                        None => errors.push(span_error(span, format!("`field_type` is required as nest `{nest_id}` is not declared elsewhere"))),
+                    },
+                }
+                let assignment = StructFieldNestAssignment { id: decl.id, ty: None, skip_empty: None, map_elements: Flag::default(), none_as: None, doc: None, validate: None };
+                field.nest.push(SpannedValue::new(assignment, span));
+            }
+        }
+        errors.finish()
+    }
 
-        let mut nest_errors = 0;
-        for nest in &self.nest_opts {
-            nest_errors += nest.validate(nest.span());
+    /// Assigns every field to the nests using `all_fields` (other than excluded or already assigned fields)
+    pub(crate) fn assign_all_fields(&mut self) {
+        let Data::Struct(data) = &mut self.data else {
+            return;
+        };
+        // invalid nests are reported during validation
+        for nest in self.nest_opts.iter().filter(|nest| nest.all_fields.is_present() && nest.all_fields_valid()) {
+            for field in &mut data.fields {
+                let excluded = field.ident.as_ref().is_some_and(|ident| nest.exclude.iter().any(|exclude| ident == &exclude.value()));
+                if excluded || field.unmapped.is_present() || field.nest.iter().any(|assignment| assignment.id.as_str() == nest.id_str()) {
+                    continue;
+                }
+                let id = SpannedValue::new(nest.id.as_str().to_string(), nest.all_fields.span());
+                let assignment = StructFieldNestAssignment { id, ty: None, skip_empty: None, map_elements: Flag::default(), none_as: None, doc: None, validate: None };
+                field.nest.push(SpannedValue::new(assignment, nest.all_fields.span()));
+            }
         }
+    }
 
-        let mut nest_field_errors = 0;
+    pub(crate) fn validate(&self) -> darling::Result<()> {
+        let mut errors = darling::Error::accumulator();
+
+        self.wrapper_opts.validate(&mut errors);
+        self.extra_opts.validate(&mut errors);
+        for nest in &self.nest_opts {
+            nest.validate(nest.span(), &mut errors);
+        }
         if let Data::Struct(data) = &self.data {
             for field in &data.fields {
-                nest_field_errors += field.validate();
+                field.validate(&mut errors);
             }
         }
+        self.validate_self(&mut errors);
 
-        let self_errors = self.validate_self();
-        let total_errors = wrapper_errors
-                           + extra_errors
-                           + nest_errors
-                           + nest_field_errors
-                           + self_errors;
-
-        total_errors == 0
+        errors.finish()
     }
-    fn validate_self(&self) -> usize {
-        let mut errors = 0;
-
+    /// Validates the serde/schemars attributes passed through to generated structs and fields, see `validate_serde`.
+    ///
+    /// Malformed passthrough attributes are skipped, they're reported when the attributes are resolved.
+    fn validate_passthrough_attrs(&self, errors: &mut Accumulator) {
+        for attr in &self.attrs {
+            if let Ok(Some(extracted)) = StructProxyAttribute::maybe_extract_from(attr) {
+                let classes = &extracted.classes;
+                let position = match [StructClass::Wrapper, StructClass::Extra, StructClass::Nest].into_iter().any(|class| classes.contains(class)) {
+                    true => AttrPosition::Container,
+                    false => AttrPosition::Field,
+                };
+                validate_passthrough_attr(&extracted.attr, position, errors);
+            }
+        }
+        if let Data::Struct(data) = &self.data {
+            for attr in data.fields.iter().flat_map(|field| &field.attrs) {
+                if let Ok(Some(extracted)) = FieldProxyAttribute::maybe_extract_from(attr) {
+                    validate_passthrough_attr(&extracted.attr, AttrPosition::Field, errors);
+                }
+            }
+        }
+    }
+    fn validate_self(&self, errors: &mut Accumulator) {
         let all_nest_ids = self.nest_opts.iter().map(|nest| nest.id_str().to_string()).collect::<Vec<_>>();
         // validate field nest id's exist
         if let Data::Struct(data) = &self.data {
@@ -77,38 +186,487 @@ impl DeriveItemOpts {
                 for nest in &field.nest {
                     let nest_id = nest.id.clone().into_inner();
                     if !all_nest_ids.contains(&nest_id) {
-                        emit_error!(nest.id.span(), "Nest `{}` is not defined", nest_id);
-                        errors += 1;
+                        errors.push(span_error(nest.id.span(), format!("Nest `{nest_id}` is not defined")));
                     }
                 }
             }
+
+            // validate unmapped fields aren't assigned to nests, and are only marked when audited
+            for field in data.fields.iter().filter(|field| field.unmapped.is_present()) {
+                if !self.global_opts.audit.is_present() {
+                    errors.push(span_error(field.unmapped.span(), "`unmapped` requires the `audit` flag"));
+                }
+                for nest in &field.nest {
+                    errors.push(span_error(field.unmapped.span(), "Field marked `unmapped` here"));
+                    errors.push(span_error(nest.id.span(), format!("Fields marked `unmapped` cannot be assigned to nest `{}`", nest.id.as_str())));
+                }
+            }
+
+            // validate at most a single identity key field
+            let mut id_keys = data.fields.iter().filter(|field| field.id_key.is_present());
+            if let Some(first) = id_keys.next() {
+                for field in id_keys {
+                    errors.push(span_error(first.id_key.span(), "`id_key` first set here"));
+                    errors.push(span_error(field.id_key.span(), "`id_key` can only be set on a single field"));
+                }
+            }
         } else {
-            emit_error!(Span::call_site(), "Only named structs are supported");
-            errors += 1;
+            errors.push(darling::Error::custom("Only named structs are supported"));
         }
 
-        // validate for conflicting optional/derive to nest option_field
+        if self.global_opts.validate_serde.is_present() {
+            self.validate_passthrough_attrs(errors);
+        }
+
+        // validate runtime transforms can be dispatched to via distinct variants
+        let transforms = &self.global_opts.transforms;
+        if let Some(first) = transforms.first() {
+            if transforms.len() < 2 {
+                errors.push(span_error(first.span(), "`transforms` requires at least two transforms"));
+            }
+            if let Some(params) = &self.global_opts.transform_generic_params {
+                errors.push(span_error(params.span(), "`transforms` cannot be used with `transform_generic_params`"));
+            }
+            let mut variants: Vec<&Ident> = Vec::new();
+            for path in transforms.iter() {
+                let Some(variant) = path.segments.last().map(|segment| &segment.ident) else {
+                    continue;
+                };
+                if variants.contains(&variant) {
+                    errors.push(span_error(path.span(), format!("Transforms must have distinct names, `{variant}` is listed multiple times")));
+                }
+                variants.push(variant);
+            }
+        }
+
+        // validate the aliased bounds are those of a blanket impl
+        if self.global_opts.transform_alias.is_present() {
+            let span = self.global_opts.transform_alias.span();
+            if self.global_opts.transform_generic_params.is_some() {
+                errors.push(span_error(span, "`transform_alias` cannot be used with `transform_generic_params`"));
+            }
+            if self.global_opts.concrete_impls.is_present() {
+                errors.push(span_error(span, "`transform_alias` cannot be used with `concrete_impls`"));
+            }
+        }
+
+        // validate provider and map nests have no assigned fields and are not chained from
+        for nest in self.nest_opts.iter().filter(|nest| nest.provider.is_some() || nest.map_layout_span().is_some()) {
+            let reason = match nest.provider.is_some() {
+                true => "a `provider`",
+                false => "`layout = \"map\"`",
+            };
+            if let Data::Struct(data) = &self.data {
+                for assignment in data.fields.iter().flat_map(|field| &field.nest).filter(|assignment| assignment.id.as_str() == nest.id_str()) {
+                    errors.push(span_error(assignment.id.span(), format!("Fields cannot be assigned to nest `{}` as it uses {reason}", nest.id_str())));
+                }
+            }
+            let chained = self.nest_opts.iter()
+                .filter_map(|child| child.chain_from.as_ref())
+                .filter(|chain_from| chain_from.as_str() == nest.id_str());
+            for chain_from in chained {
+                errors.push(span_error(chain_from.span(), format!("Nests cannot be chained from nest `{}` as it uses {reason}", nest.id_str())));
+            }
+        }
+
+        // validate field selectors start at an origin field, and don't collide with assigned fields
+        if let Data::Struct(data) = &self.data {
+            for nest in self.nest_opts.iter().filter(|nest| !nest.fields.is_empty()) {
+                let mut names = data.fields.iter()
+                    .filter(|field| field.nest.iter().any(|assignment| assignment.id.as_str() == nest.id_str()))
+                    .filter_map(|field| field.ident.clone())
+                    .collect::<Vec<_>>();
+                for selector in &nest.fields {
+                    if !data.fields.iter().any(|field| field.ident.as_ref() == Some(&selector.path[0])) {
+                        errors.push(span_error(selector.span, format!("Field `{}` is not defined", selector.path[0])));
+                    }
+                    if names.contains(&selector.name) {
+                        errors.push(span_error(selector.span, format!(
+                            "Field `{}` is already included in nest `{}`, rename it via `\"{} as ..\"`",
+                            selector.name, nest.id_str(), selector.path_str(),
+                        )));
+                    }
+                    names.push(selector.name.clone());
+                }
+            }
+        }
+
+        // validate excluded fields exist, and aren't assigned to the nest excluding them
+        if let Data::Struct(data) = &self.data {
+            for nest in self.nest_opts.iter().filter(|nest| nest.all_fields.is_present()) {
+                for exclude in &nest.exclude {
+                    let field = data.fields.iter().find(|field| field.ident.as_ref().is_some_and(|ident| ident == &exclude.value()));
+                    match field {
+                        Some(field) => {
+                            for assignment in field.nest.iter().filter(|assignment| assignment.id.as_str() == nest.id_str()) {
+                                errors.push(span_error(exclude.span(), format!("Field `{}` excluded here", exclude.value())));
+                                errors.push(span_error(assignment.id.span(), format!("Field `{}` cannot be assigned to nest `{}` as it is excluded", exclude.value(), nest.id_str())));
+                            }
+                        },
+                        None => errors.push(span_error(exclude.span(), format!("Field `{}` is not defined", exclude.value()))),
+                    }
+                }
+            }
+        }
+
+        // validate auto parents only contain their children
+        for nest in self.nest_opts.iter().filter(|nest| nest.auto_parent.is_present()) {
+            if let Data::Struct(data) = &self.data {
+                for assignment in data.fields.iter().flat_map(|field| &field.nest).filter(|assignment| assignment.id.as_str() == nest.id_str()) {
+                    errors.push(span_error(assignment.id.span(), format!("Fields cannot be assigned to nest `{}` as it uses `auto_parent`", nest.id_str())));
+                }
+            }
+            let mut children = self.nest_opts.iter()
+                .filter(|child| child.chain_from.as_ref().is_some_and(|id| id.as_str() == nest.id_str()))
+                .peekable();
+            if children.peek().is_none() {
+                errors.push(span_error(nest.auto_parent.span(), format!("`auto_parent` requires nests to be chained from nest `{}`", nest.id_str())));
+            }
+            for needs in children.filter_map(|child| child.needs.as_ref()) {
+                errors.push(span_error(needs.span(), format!("`needs` cannot be used for nests chained from nest `{}` as it uses `auto_parent`", nest.id_str())));
+            }
+        }
+
+        // validate field types aren't overridden or mapped for identity nests
+        for nest in self.nest_opts.iter().filter(|nest| nest.identity.is_present()) {
+            if let Data::Struct(data) = &self.data {
+                let assignments = data.fields.iter().flat_map(|field| &field.nest)
+                    .filter(|assignment| assignment.id.as_str() == nest.id_str());
+                for assignment in assignments {
+                    if assignment.ty.is_some() {
+                        errors.push(span_error(assignment.span(), format!("Field types cannot be overridden for nest `{}` as it uses `identity`", nest.id_str())));
+                    }
+                    if assignment.map_elements.is_present() {
+                        errors.push(span_error(assignment.map_elements.span(), format!("Fields cannot be mapped for nest `{}` as it uses `identity`", nest.id_str())));
+                    }
+                    if let Some(none_as) = &assignment.none_as {
+                        errors.push(span_error(none_as.span(), format!("`none_as` cannot be used for nest `{}` as it uses `identity`", nest.id_str())));
+                    }
+                }
+            }
+        }
+
+        // validate field validation rules are only used for nests deriving `Validate`
+        if let Data::Struct(data) = &self.data {
+            for assignment in data.fields.iter().flat_map(|field| &field.nest) {
+                let Some(validate) = &assignment.validate else {
+                    continue;
+                };
+                if validate.is_empty() {
+                    errors.push(span_error(validate.span(), "`validate` requires a `length`, `range`, or `custom` rule"));
+                }
+                let bounds = [("length", &validate.length), ("range", &validate.range)];
+                for (name, _) in bounds.into_iter().filter(|(_, bounds)| bounds.as_ref().is_some_and(|bounds| bounds.min.is_none() && bounds.max.is_none())) {
+                    errors.push(span_error(validate.span(), format!("`{name}` requires a `min` and/or `max`")));
+                }
+                let nest = self.nest_opts.iter().find(|nest| nest.id_str() == assignment.id.as_str());
+                if nest.is_some_and(|nest| !nest.validate.is_present()) {
+                    errors.push(span_error(validate.span(), format!("Field validation requires the `validate` flag on nest `{}`", assignment.id.as_str())));
+                }
+            }
+        }
+
+        // validate schema annotations are only used alongside schema derivation
+        if !self.global_opts.schema() {
+            let annotations = self.wrapper_opts.annotate.iter()
+                .chain(self.extra_opts.annotate.iter())
+                .chain(self.nest_opts.iter().flat_map(|nest| nest.annotate.iter()));
+            for annotation in annotations {
+                errors.push(darling::Error::custom("`annotate` requires the `schema` (or `inline`) flag").with_span(&annotation.key));
+            }
+        }
+
+        if !self.global_opts.schema() {
+            for nest in self.nest_opts.iter().filter(|nest| nest.schema_skip.is_present()) {
+                errors.push(span_error(nest.schema_skip.span(), "`schema_skip` requires the `schema` (or `inline`) flag"));
+            }
+        }
+
+        if let Some(feature) = self.global_opts.schema_feature() && feature.value().is_empty() {
+            errors.push(span_error(feature.span(), "Schema feature cannot be empty"));
+        }
+
+        // validate schema naming
+        if let Some(schema_naming) = &self.global_opts.schema_naming {
+            let span = schema_naming.prefix.as_ref().or(schema_naming.suffix.as_ref()).map(LitStr::span).unwrap_or_else(|| self.ident.span());
+            if !self.global_opts.schema() {
+                errors.push(span_error(span, "`schema_naming` requires the `schema` flag"));
+            } else if self.global_opts.inline() {
+                errors.push(span_error(span, "`schema_naming` cannot be used with `inline`, inlined structs are not named in schemas"));
+            }
+            if schema_naming.prefix.is_none() && schema_naming.suffix.is_none() {
+                errors.push(span_error(span, "`schema_naming` requires a `prefix` and/or `suffix`"));
+            }
+        }
+
+        if let Some(http) = self.global_opts.http() {
+            http.validate(errors);
+        }
+
+        // validate impl assertions are only used with concrete transforms
+        if self.global_opts.assert_optional_impls.is_present() && self.global_opts.transform_generic_params.is_some() {
+            errors.push(span_error(
+                self.global_opts.assert_optional_impls.span(),
+                "`assert_optional_impls` cannot be used alongside `transform_generic_params`",
+            ));
+        }
+
+        // validate computed and integrity fields aren't used alongside generated wrapper shapes
+        if self.global_opts.json.is_present() {
+            for computed in &self.wrapper_opts.computed {
+                errors.push(darling::Error::custom("computed fields are not supported with `json`").with_span(&computed.field));
+            }
+            if let Some(integrity) = &self.wrapper_opts.integrity {
+                errors.push(darling::Error::custom("`integrity` is not supported with `json`").with_span(&integrity.field));
+            }
+            if let Some(field) = self.wrapper_opts.echo_options_field() {
+                errors.push(darling::Error::custom("`echo_options` is not supported with `json`").with_span(&field));
+            }
+        }
+
+        // validate echoed options are of the declared transform
+        if let (Some(field), Some(params)) = (self.wrapper_opts.echo_options_field(), &self.global_opts.transform_generic_params) {
+            errors.push(span_error(params.span(), "`transform_generic_params` defined here"));
+            errors.push(darling::Error::custom("`echo_options` cannot be used with `transform_generic_params`").with_span(&field));
+        }
+
+        // validate ffi envelopes only wrap C-compatible data
+        if self.global_opts.ffi.is_present() {
+            if !self.attrs.iter().any(is_repr_c) {
+                errors.push(span_error(self.global_opts.ffi.span(), "`ffi` requires the data struct to be `#[repr(C)]` (or `#[repr(transparent)]`)"));
+            }
+            for computed in &self.wrapper_opts.computed {
+                errors.push(darling::Error::custom("computed fields are not supported with `ffi`").with_span(&computed.field));
+            }
+            if let Some(field) = self.wrapper_opts.echo_options_field() {
+                errors.push(darling::Error::custom("`echo_options` is not supported with `ffi`").with_span(&field));
+            }
+        }
+
+        // validate exclusive extras only contain optional, independent root nests
+        if let Some(exclusive_span) = self.extra_opts.exclusive_span() {
+            let conflicts = [
+                ("csv", self.global_opts.csv.is_some()),
+                ("json", self.global_opts.json.is_present()),
+                ("wrapper(enrich)", self.wrapper_opts.enrich.is_present()),
+                ("wrapper(delegate_accessors)", self.wrapper_opts.delegate_accessors.is_present()),
+                ("wrapper(sort_keys)", self.wrapper_opts.sort_keys()),
+                ("wrapper(binary_safe)", self.wrapper_opts.binary_safe()),
+                ("wrapper(integrity)", self.wrapper_opts.integrity.is_some()),
+                ("fallible(accumulate_errors)", self.global_opts.fallible.as_ref().is_some_and(|fallible| fallible.accumulate_errors.is_present())),
+            ];
+            for (name, _) in conflicts.into_iter().filter(|(_, present)| *present) {
+                errors.push(span_error(exclusive_span, format!("`exclusive` cannot be used with `{name}`")));
+            }
+            let all_optional = self.global_opts.all_optional.is_present();
+            for nest in self.nest_opts.iter().filter(|nest| nest.is_root_nest()) {
+                if AsUpperCamelCase(nest.field_name().to_string()).to_string() == "None" {
+                    errors.push(span_error(nest.id.span(), "`none` is reserved for the empty variant of an `exclusive` extra, set a different `field_name`"));
+                }
+                if !nest.is_optional(all_optional) {
+                    errors.push(span_error(nest.id.span(), format!("Nest `{}` must be optional as the extra is `exclusive`", nest.id_str())));
+                }
+                if let Some(needs) = &nest.needs {
+                    errors.push(span_error(needs.span(), "`needs` cannot be used for root nests of an `exclusive` extra"));
+                }
+                if nest.batch.is_present() {
+                    errors.push(span_error(nest.batch.span(), "`batch` cannot be used for root nests of an `exclusive` extra"));
+                }
+            }
+        }
+
+        // validate tagged array extras are only used alongside helpers which don't depend on the extra's fields
+        if let Some(tagged_array_span) = self.extra_opts.tagged_array_span() {
+            let conflicts = [
+                ("csv", self.global_opts.csv.is_some()),
+                ("json", self.global_opts.json.is_present()),
+                ("wrapper(delegate_accessors)", self.wrapper_opts.delegate_accessors.is_present()),
+                ("wrapper(sort_keys)", self.wrapper_opts.sort_keys()),
+                ("wrapper(binary_safe)", self.wrapper_opts.binary_safe()),
+                ("wrapper(integrity)", self.wrapper_opts.integrity.is_some()),
+            ];
+            for (name, _) in conflicts.into_iter().filter(|(_, present)| *present) {
+                errors.push(span_error(tagged_array_span, format!("`layout = \"tagged_array\"` cannot be used with `{name}`")));
+            }
+        }
+
+        // validate defaulted extras are only used alongside struct extras
+        if let Some(default_extras_span) = self.wrapper_opts.default_extras_span() {
+            let conflicts = [
+                ("extra(exclusive)", self.extra_opts.exclusive_span().is_some()),
+                ("extra(layout = \"tagged_array\")", self.extra_opts.tagged_array_span().is_some()),
+            ];
+            for (name, _) in conflicts.into_iter().filter(|(_, present)| *present) {
+                errors.push(span_error(default_extras_span, format!("`default_extras` cannot be used with `{name}`")));
+            }
+        }
+
+        // validate the extra serializes as a map for gRPC metadata
+        if self.global_opts.grpc.is_present() {
+            let conflicts = [
+                ("extra(exclusive)", self.extra_opts.exclusive_span().is_some()),
+                ("extra(layout = \"tagged_array\")", self.extra_opts.tagged_array_span().is_some()),
+            ];
+            for (name, _) in conflicts.into_iter().filter(|(_, present)| *present) {
+                errors.push(span_error(self.global_opts.grpc.span(), format!("`grpc` cannot be used with `{name}`")));
+            }
+        }
+
+        // validate each generated type is a struct with wasm-bindgen compatible fields
+        if self.global_opts.wasm_bindgen.is_present() {
+            let conflicts = [
+                ("extra(exclusive)", self.extra_opts.exclusive_span().is_some()),
+                ("extra(layout = \"tagged_array\")", self.extra_opts.tagged_array_span().is_some()),
+                ("layout = \"map\"", self.nest_opts.iter().any(|nest| nest.map_layout_span().is_some())),
+            ];
+            for (name, _) in conflicts.into_iter().filter(|(_, present)| *present) {
+                errors.push(span_error(self.global_opts.wasm_bindgen.span(), format!("`wasm_bindgen` cannot be used with `{name}`")));
+            }
+        }
+
+        // validate the debug wrapper can list the nests of the primary extra
+        if self.global_opts.debug_wrapper.is_present() {
+            let conflicts = [
+                ("extra(exclusive)", self.extra_opts.exclusive_span().is_some()),
+                ("extra(layout = \"tagged_array\")", self.extra_opts.tagged_array_span().is_some()),
+            ];
+            for (name, _) in conflicts.into_iter().filter(|(_, present)| *present) {
+                errors.push(span_error(self.global_opts.debug_wrapper.span(), format!("`debug_wrapper` cannot be used with `{name}`")));
+            }
+        }
+
+        // validate promoted nests are only used alongside the derived wrapper serde impls
+        for nest in self.nest_opts.iter().filter(|nest| nest.promote.is_present()) {
+            let conflicts = [
+                ("json", self.global_opts.json.is_present()),
+                ("wrapper(sort_keys)", self.wrapper_opts.sort_keys()),
+                ("wrapper(binary_safe)", self.wrapper_opts.binary_safe()),
+                ("wrapper(integrity)", self.wrapper_opts.integrity.is_some()),
+                ("wrapper(deserialize)", self.wrapper_opts.deserialize()),
+                ("extra(exclusive)", self.extra_opts.exclusive_span().is_some()),
+                ("extra(layout = \"tagged_array\")", self.extra_opts.tagged_array_span().is_some()),
+            ];
+            for (name, _) in conflicts.into_iter().filter(|(_, present)| *present) {
+                errors.push(span_error(nest.promote.span(), format!("`promote` cannot be used with `{name}`")));
+            }
+        }
+
+        // validate encoded root nests are held by an extra struct
+        for encode in self.nest_opts.iter().filter(|nest| nest.is_root_nest()).filter_map(|nest| nest.encode.as_ref()) {
+            let conflicts = [
+                ("extra(exclusive)", self.extra_opts.exclusive_span().is_some()),
+                ("extra(layout = \"tagged_array\")", self.extra_opts.tagged_array_span().is_some()),
+            ];
+            for (name, _) in conflicts.into_iter().filter(|(_, present)| *present) {
+                errors.push(span_error(encode.span(), format!("`encode` cannot be used on root nests with `{name}`")));
+            }
+        }
+        for nest in self.nest_opts.iter().filter(|nest| nest.is_root_nest() && nest.emit_alias.is_present()) {
+            let conflicts = [
+                ("extra(exclusive)", self.extra_opts.exclusive_span().is_some()),
+                ("extra(layout = \"tagged_array\")", self.extra_opts.tagged_array_span().is_some()),
+            ];
+            for (name, _) in conflicts.into_iter().filter(|(_, present)| *present) {
+                errors.push(span_error(nest.emit_alias.span(), format!("`emit_alias` cannot be used on root nests with `{name}`")));
+            }
+        }
+
+        // validate hoisted nests can be built from their parent nest within the parent extra
+        for nest in self.nest_opts.iter().filter(|nest| nest.no_intermediate_wrapper.is_present()) {
+            let span = nest.no_intermediate_wrapper.span();
+            let id = nest.id_str();
+            let parent = nest.chain_from.as_ref().and_then(|parent_id| self.nest_opts.iter().find(|parent| parent.id_str() == parent_id.as_str()));
+            if parent.is_some_and(|parent| parent.auto_parent.is_present()) {
+                errors.push(span_error(span, format!("`no_intermediate_wrapper` cannot be used for nest `{id}` as it is chained from an `auto_parent` nest")));
+            }
+            if nest.is_root_nest() {
+                let conflicts = [
+                    ("extra(exclusive)", self.extra_opts.exclusive_span().is_some()),
+                    ("extra(layout = \"tagged_array\")", self.extra_opts.tagged_array_span().is_some()),
+                ];
+                for (name, _) in conflicts.into_iter().filter(|(_, present)| *present) {
+                    errors.push(span_error(span, format!("`no_intermediate_wrapper` cannot be used on root nests with `{name}`")));
+                }
+            }
+            let children = self.nest_opts.iter()
+                .filter(|child| child.chain_from.as_ref().is_some_and(|parent_id| parent_id.as_str() == id))
+                .collect::<Vec<_>>();
+            if children.is_empty() {
+                errors.push(span_error(span, format!("`no_intermediate_wrapper` requires nests to be chained from nest `{id}`")));
+            }
+            let siblings = self.nest_opts.iter().filter(|sibling| sibling.chain_from.as_deref() == nest.chain_from.as_deref());
+            let sibling_names = siblings.map(|sibling| sibling.field_name().to_string()).collect::<Vec<_>>();
+            for child in children {
+                if let Some(needs) = &child.needs {
+                    errors.push(span_error(needs.span(), format!("`needs` cannot be used for nests chained from nest `{id}` as it uses `no_intermediate_wrapper`")));
+                }
+                if child.batch.is_present() {
+                    errors.push(span_error(child.batch.span(), format!("`batch` cannot be used for nests chained from nest `{id}` as it uses `no_intermediate_wrapper`")));
+                }
+                if sibling_names.contains(&child.field_name().to_string()) {
+                    errors.push(span_error(child.id.span(), format!("Field name of nest `{}` collides with a sibling of nest `{id}` (as it uses `no_intermediate_wrapper`), set a different `field_name`", child.id_str())));
+                }
+            }
+        }
+
+        // validate deprecated nests are held by extra structs, and gated nests can be skipped
         let all_optional = self.global_opts.all_optional.is_present();
         for nest in &self.nest_opts {
-            let nest_optional = all_optional || nest.optional();
-            // nest not optional, option_field set
-            if let Some(derive_to_nest) = &nest.derive_to_nest && let Some(option_field) = &derive_to_nest.options_field {
-                if !nest_optional {
-                    emit_error!(option_field, "options_field can only be used for optional nests");
+            // missing nests are reported by the nest hierarchy
+            let Some(deprecates) = &nest.deprecates else {
+                continue;
+            };
+            let Some(deprecated) = self.nest_opts.iter().find(|deprecated| deprecated.id_str() == deprecates.as_str()) else {
+                continue;
+            };
+            if deprecated.is_root_nest() {
+                let conflicts = [
+                    ("extra(exclusive)", self.extra_opts.exclusive_span().is_some()),
+                    ("extra(layout = \"tagged_array\")", self.extra_opts.tagged_array_span().is_some()),
+                ];
+                for (name, _) in conflicts.into_iter().filter(|(_, present)| *present) {
+                    errors.push(span_error(deprecates.span(), format!("`deprecates` cannot be used on root nests with `{name}`")));
+                }
+            }
+            if nest.gate_deprecated.is_present() {
+                let span = nest.gate_deprecated.span();
+                let id = deprecated.id_str();
+                if !deprecated.is_optional(all_optional) {
+                    errors.push(span_error(span, format!("`gate_deprecated` requires nest `{id}` to be optional")));
+                }
+                if deprecated.batch.is_present() {
+                    errors.push(span_error(span, format!("`gate_deprecated` cannot be used as nest `{id}` uses `batch`")));
+                }
+                let parent = deprecated.chain_from.as_ref().and_then(|parent_id| self.nest_opts.iter().find(|parent| parent.id_str() == parent_id.as_str()));
+                if parent.is_some_and(|parent| parent.auto_parent.is_present()) {
+                    errors.push(span_error(span, format!("`gate_deprecated` cannot be used as nest `{id}` is chained from an `auto_parent` nest")));
                 }
-                errors += 1;
             }
         }
 
-        errors
+        // validate for conflicting optional/derive to nest option_field
+        for nest in &self.nest_opts {
+            let nest_optional = nest.is_optional(all_optional);
+            // nest not optional, option_field set
+            if let Some(derive_to_nest) = &nest.derive_to_nest
+                && let Some(option_field) = &derive_to_nest.options_field
+                && !nest_optional
+            {
+                errors.push(darling::Error::custom("options_field can only be used for optional nests").with_span(option_field));
+            }
+        }
     }
 }
 
 // !- Struct field entrypoint
 
 /// Options for struct field attributes
+///
+/// `#[cfg(..)]` attributes of the field are forwarded onto the field within each of its nests, along with the field
+/// assignments of generated transforms (`derive_to_nest`, `identity`, `sources`). User-defined transforms must gate
+/// the field likewise.
 #[derive(Debug, Clone, FromField)]
-#[darling(attributes(shrinkwrap), forward_attrs(shrinkwrap_attr))]
+#[darling(attributes(shrinkwrap), forward_attrs(cfg, serde, shrinkwrap_attr))]
 pub(crate) struct DeriveItemFieldOpts {
     /// only None for tuple fields, therefore safe to unwrap
     pub ident: Option<Ident>,
@@ -118,26 +676,71 @@ pub(crate) struct DeriveItemFieldOpts {
     /// Nest assignments for field, can be provided multiple times
     #[darling(default, multiple)]
     pub nest: Vec<SpannedValue<StructFieldNestAssignment>>,
+
+    /// Declares a nest inline and assigns the field to it, e.g.
+    /// `nest_here(id = "text", field_type = String)`. Can be provided multiple
+    /// times.
+    ///
+    /// Equivalent to a struct-level `nest(id = .., field_type = ..)` alongside
+    /// a field-level `nest(id = ..)`. The same nest may be declared by several
+    /// fields (or at struct level), declarations are merged provided their
+    /// `field_type` matches.
+    #[darling(default, multiple)]
+    pub nest_here: Vec<SpannedValue<StructFieldNestDeclaration>>,
+
+    /// Marks the field as the identity key of the data, generating
+    /// `fn identity(&self) -> &K` on the primary wrapper.
+    ///
+    /// The key type should implement `Eq` + `Hash` for wrappers to be
+    /// deduplicated or keyed in collections. Only a single field may be marked.
+    #[darling(default)]
+    pub id_key: Flag,
+
+    /// Documentation for the field within each nest it is assigned to, in
+    /// place of the origin field's docs (which aren't carried over). Can be
+    /// overridden per nest, e.g. `nest(id = "text", doc = "..")`.
+    pub field_doc: Option<String>,
+
+    /// Marks the field as intentionally not assigned to any nest, omitting it
+    /// from the warnings of `audit`. The field is skipped by `all_fields`
+    /// nests.
+    pub unmapped: Flag,
 }
 impl DeriveItemFieldOpts {
-    fn validate(&self) -> usize {
-        let mut errors = 0;
-
+    fn validate(&self, errors: &mut Accumulator) {
         // check if nest ID has been assigned multiple times
         let mut ids_visited: HashMap<String, Span> = HashMap::new();
         for nest in &self.nest {
             let nest_id = nest.id.as_str();
             if let Some(existing_span) = ids_visited.get(nest_id) {
-                emit_error!(existing_span, "Nest ID `{}` first assigned here", nest_id);
-                let field_name = self.ident.clone().unwrap().to_string();
-                emit_error!(nest.span(), "Nest with ID `{}` is assigned to field `{}` multiple times.", nest_id, field_name);
-                errors += 1;
+                errors.push(span_error(*existing_span, format!("Nest ID `{nest_id}` first assigned here")));
+                let message = match &self.ident {
+                    Some(field_name) => format!("Nest with ID `{nest_id}` is assigned to field `{field_name}` multiple times."),
+                    None => format!("Nest with ID `{nest_id}` is assigned to the field multiple times."),
+                };
+                errors.push(span_error(nest.span(), message));
             }
 
             ids_visited.insert(nest_id.to_string(), nest.span());
+
+            if let Some(none_as) = &nest.none_as {
+                if nest.map_elements.is_present() {
+                    errors.push(span_error(nest.map_elements.span(), "`map_elements` defined here"));
+                    errors.push(span_error(none_as.span(), "`none_as` cannot be used with `map_elements`"));
+                }
+                if !is_option_type(&self.ty) {
+                    errors.push(span_error(none_as.span(), "`none_as` requires a field of type `Option<T>`"));
+                }
+            }
         }
+    }
+}
 
-        errors
+/// Whether `ty` is (syntactically) an `Option<T>`
+fn is_option_type(ty: &Type) -> bool {
+    match ty {
+        Type::Path(type_path) => type_path.qself.is_none() && type_path.path.segments.last().is_some_and(|segment| segment.ident == "Option"),
+        _ => false,
     }
 }
 
@@ -154,7 +757,167 @@ pub(crate) struct StructFieldNestAssignment {
     /// one of:
     /// - `#[shrinkwrap(nest(.., `**`field_type = X`**`))]`
     /// - `#[shrinkwrap(nest(.., derive_to_nest(`**`value = X`**`))]`
-    pub ty: Option<Path>
+    #[darling(default, with = parse_optional_type)]
+    pub ty: Option<Type>,
+
+    /// Skips serializing the field within this nest when empty, overriding
+    /// the nest's `skip_empty` setting.
+    ///
+    /// Either a flag, or the path of a `fn(&T) -> bool` predicate, e.g.
+    /// `skip_empty = "Vec::is_empty"`.
+    #[darling(default)]
+    pub skip_empty: Option<Override<Path>>,
+
+    /// Maps the field element-wise, for collection fields such as `Vec<T>`.
+    ///
+    /// The field is generated within the nest as `Vec<V>` (`V` being the
+    /// nest's field type). Nests using `derive_to_nest` build each element via
+    /// `BuildNestValue<T, V>`, manual `TransformToNest` impls are expected to
+    /// build a single value per element, in order.
+    #[darling(default)]
+    pub map_elements: Flag,
+
+    /// Handling of `None` for `Option<T>` fields, one of:
+    /// - `"null"`: the nest field is generated as `Option<V>`
+    /// - `"skip"`: as `"null"`, the field is skipped when serializing `None`
+    /// - `"default"`: the nest field remains `V`
+    ///
+    /// Nests using `derive_to_nest` build `Some` values via
+    /// `BuildNestValue<T, V>`, `None` is kept (or `V::default()` for
+    /// `"default"`). Manual `TransformToNest` impls are expected to return
+    /// `None` for `None` values, or a placeholder of their choosing for
+    /// `"default"`.
+    #[darling(default)]
+    pub none_as: Option<SpannedValue<NoneAs>>,
+
+    /// Documentation for the field within this nest, overriding `field_doc`
+    pub doc: Option<String>,
+
+    /// Validation rules for the field within this nest, emitted as
+    /// `#[validate(..)]` for the `validator::Validate` derive, e.g.
+    /// `validate(length(min = 1, max = 64))`. Requires the nest's `validate` flag.
+    #[darling(default)]
+    pub validate: Option<SpannedValue<FieldValidation>>,
+}
+
+/// Handling of `None` values of optional fields within a nest, see [`StructFieldNestAssignment::none_as`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, FromMeta)]
+pub(crate) enum NoneAs {
+    /// Generates an optional nest field, skipped when serializing `None`
+    #[darling(rename = "skip")]
+    Skip,
+    /// Generates an optional nest field, serialized as `null`
+    #[darling(rename = "null")]
+    Null,
+    /// Keeps the nest field type, `None` maps to the default value
+    #[darling(rename = "default")]
+    Default,
+}
+impl NoneAs {
+    /// Whether the nest field is generated as `Option<V>`
+    pub fn is_optional(self) -> bool {
+        matches!(self, Self::Skip | Self::Null)
+    }
+}
+
+/// Typed `validator` rules of a nest field, see [`StructFieldNestAssignment::validate`]
+#[derive(Debug, Clone, Default, FromMeta)]
+pub(crate) struct FieldValidation {
+    /// Length bounds of strings and collections, e.g. `length(min = 1, max = 64)`
+    pub length: Option<ValidationBounds>,
+
+    /// Bounds of numbers, e.g. `range(min = 0.0)`
+    pub range: Option<ValidationBounds>,
+
+    /// Path of a `fn(&T) -> Result<(), validator::ValidationError>`, e.g. `custom = crate::is_slug`
+    pub custom: Option<Path>,
+}
+impl FieldValidation {
+    pub fn is_empty(&self) -> bool {
+        self.length.is_none() && self.range.is_none() && self.custom.is_none()
+    }
+    pub fn to_attr(&self) -> Attribute {
+        let mut rules = Vec::new();
+        if let Some(length) = &self.length {
+            let bounds = length.bounds();
+            rules.push(quote!(length(#bounds)));
+        }
+        if let Some(range) = &self.range {
+            let bounds = range.bounds();
+            rules.push(quote!(range(#bounds)));
+        }
+        if let Some(custom) = &self.custom {
+            rules.push(quote!(custom(function = #custom)));
+        }
+        parse_quote!(#[validate(#(#rules),*)])
+    }
+}
+
+/// Inclusive bounds of a `length` or `range` validation
+#[derive(Debug, Clone, Default, FromMeta)]
+pub(crate) struct ValidationBounds {
+    pub min: Option<syn::Expr>,
+    pub max: Option<syn::Expr>,
+}
+impl ValidationBounds {
+    fn bounds(&self) -> TokenStream {
+        let min = self.min.as_ref().map(|min| quote!(min = #min));
+        let max = self.max.as_ref().map(|max| quote!(max = #max));
+        let bounds = min.into_iter().chain(max);
+        quote!(#(#bounds),*)
+    }
+}
+
+/// Inline nest declaration, see [`DeriveItemFieldOpts::nest_here`]
+#[derive(Debug, Clone, FromMeta)]
+pub(crate) struct StructFieldNestDeclaration {
+    /// ID of nest to declare and assign the field to
+    pub id: SpannedValue<String>,
+
+    /// Type of the nest's fields.
+    ///
+    /// **Optional** only if the nest is declared elsewhere (at struct level, or
+    /// on another field).
+    #[darling(default, with = parse_optional_type)]
+    pub field_type: Option<Type>,
+}
+
+// ! Meta types for struct classes
+
+/// A single schemars extension, emitted as `#[schemars(extend(key = value))]`
+#[derive(Debug, Clone, FromMeta)]
+pub(crate) struct SchemaAnnotation {
+    /// Extension key, e.g. `"x-internal"`
+    pub key: LitStr,
+
+    /// Extension value, any expression accepted by `serde_json::json!`.
+    ///
+    /// String literals are parsed as expressions, e.g. `value = "true"` or
+    /// `value = "[\"docs\"]"`
+    pub value: syn::Expr,
+}
+impl SchemaAnnotation {
+    pub fn to_attr(&self) -> Attribute {
+        let Self { key, value } = self;
+        parse_quote!(#[schemars(extend(#key = #value))])
+    }
+}
+
+/// A feature-gated derive list, emitted as `#[cfg_attr(feature = "..", derive(..))]`
+#[derive(Debug, Clone, FromMeta)]
+pub(crate) struct CfgDerive {
+    /// Cargo feature gating the derives, e.g. `"openapi"`
+    pub feature: LitStr,
+
+    /// Derives to apply when the feature is enabled, e.g. `paths(utoipa::ToSchema)`
+    pub paths: PathList,
+}
+impl CfgDerive {
+    pub fn to_attr(&self) -> Attribute {
+        let Self { feature, paths } = self;
+        let paths = paths.iter();
+        parse_quote!(#[cfg_attr(feature = #feature, derive(#(#paths),*))])
+    }
 }
 
 // !- Container option structs
@@ -171,12 +934,38 @@ pub(crate) struct GlobalOpts {
     #[darling(with = Self::parse_transform_generic_params, default)]
     pub transform_generic_params: Option<TokenStream>,
 
+    /// Transforms selectable at runtime, e.g. `transforms(Fast, Precise)`.
+    ///
+    /// Generates a `{Data}AnyTransform` enum with a variant per transform
+    /// (named by the last path segment), forwarding the nest traits to the
+    /// selected transform. Wrapping via the enum requires each transform to
+    /// provide the nests, with the same options (and error) type as the first.
+    ///
+    /// The declared `transform` is still used for the definition-site
+    /// assertions, and doesn't need to be listed.
+    #[darling(default)]
+    pub transforms: PathList,
+
+    /// Generates a `{Data}Transforms` trait aliasing the bounds a transform
+    /// must satisfy to wrap the data struct (`Transform2` along with the nest
+    /// traits), implemented for any such transform, e.g.
+    /// `fn render<T: MyDataTransforms>(transform: &T, ..)`.
+    ///
+    /// Cannot be used with `transform_generic_params` or `concrete_impls`.
+    pub transform_alias: Flag,
+
     #[darling(default)]
     pub fallible: Option<GlobalFallibleNestedOpts>,
 
     /// Enables auto-derivation of `schemars::JsonSchema` on all generated
     /// structs
-    schema: Flag,
+    ///
+    /// `schema(feature = "openapi")` gates the generated `JsonSchema` derives
+    /// and `#[schemars(..)]` attrs behind a feature of the consumer crate via
+    /// `#[cfg_attr(feature = "openapi", ..)]`, so schemars is only required
+    /// when the feature is enabled.
+    #[darling(default)]
+    schema: Option<Override<GlobalSchemaOpts>>,
 
     /// Implies `schema` flag.
     ///
@@ -185,22 +974,212 @@ pub(crate) struct GlobalOpts {
     /// primary wrapper (which also implies `schemars(rename)`).
     inline: Flag,
 
-    /// Equivalent to setting `optional` on all nests.
+    /// Namespaces the schema names of all generated structs via
+    /// `#[schemars(rename = "{prefix}{StructName}{suffix}")]`, e.g.
+    /// `schema_naming(prefix = "v1.")` names `MyDataNestedText` as
+    /// `v1.MyDataNestedText` within OpenAPI components.
+    ///
+    /// Requires the `schema` flag. Explicit `schemars(rename)` attrs passed
+    /// through to a struct take precedence.
+    #[darling(default)]
+    pub schema_naming: Option<GlobalSchemaNamingOpts>,
+
+    /// Equivalent to setting `optional` on all nests, except those marked
+    /// `required`.
     pub all_optional: Flag,
 
+    /// Derives property-testing/fuzzing traits on all generated structs, e.g.
+    /// `testing(arbitrary, proptest)`.
+    ///
+    /// The origin data struct (and all nest field types) must implement the
+    /// corresponding trait as well.
+    #[darling(default)]
+    pub testing: GlobalTestingOpts,
+
+    /// Naming of the serialized keys of generated structs, e.g.
+    /// `naming(inherit_rename_all)`
+    #[darling(default)]
+    pub naming: GlobalNamingOpts,
+
+    /// Rustdoc presentation of the generated structs, e.g. `docs(alias_only)`
+    #[darling(default)]
+    pub docs: GlobalDocsOpts,
+
+    /// Generates JSON contract helpers on the primary wrapper:
+    /// - `matches_shape(&serde_json::Value) -> Vec<ShapeMismatch>`
+    /// - `TryFrom<serde_json::Value>` (requires `wrapper(deserialize)`)
+    /// - `to_value_filtered(&self, &[&str]) -> Result<serde_json::Value, _>`
+    /// - `MAX_NEST_COUNT: usize`, the number of nests the wrapper may hold (at any depth)
+    ///
+    /// Requires the `json` feature of `shrinkwrap`.
+    pub json: Flag,
+
+    /// Generates a `{Data}WrapOptionsBuilder` for the transform's `Options`,
+    /// exposing a toggle for each gated nest (optional nests using
+    /// `derive_to_nest`, see `derive_to_nest(options_field)`), e.g.
+    /// `MyDataWrapOptionsBuilder::default().with_text(true).build()`.
+    ///
+    /// `Default` is only implemented for the builder if `Options` implements
+    /// `Default`, otherwise the builder is started from existing options via
+    /// `new`/`From`.
+    pub options_builder: Flag,
+
+    /// Generates CSV row projection helpers on the primary wrapper:
+    /// - `csv_headers() -> &'static [&'static str]` (const)
+    /// - `to_csv_record(&self) -> Result<Vec<String>, serde_json::Error>`
+    ///
+    /// Columns consist of the origin data fields followed by the fields of
+    /// each nest, named `{nest_path}.{field}` (e.g. `value.text.balance`).
+    /// All nests are included unless restricted, e.g. `csv(nests("text"))`.
+    ///
+    /// Requires the `csv` feature of `shrinkwrap`.
+    #[darling(default)]
+    csv: Option<Override<GlobalCsvOpts>>,
+
+    /// Implements `shrinkwrap::RowView` for each generated nest struct,
+    /// iterating over the nest's (serialized) field names along with their
+    /// values rendered as strings, e.g. for generic exporters.
+    ///
+    /// Values are rendered via `Display` by default, or via `Serialize` with
+    /// `row_view(render = "serialize")` (requires the `json` feature of
+    /// `shrinkwrap`), where strings are used as-is and `None` is empty.
+    #[darling(default)]
+    row_view: Option<Override<GlobalRowViewOpts>>,
+
+    /// Implements `From<Wrapper>` for `http::Response<B>` (for any body
+    /// `B: From<Vec<u8>>`) on the primary wrapper, serializing it as JSON.
+    ///
+    /// Defaults to `200 OK` with `Content-Type: application/json`, both can
+    /// be overridden, e.g. `http(content_type = "application/vnd.api+json", status = my_status)`,
+    /// where `status` is a `fn(&Wrapper) -> http::StatusCode`.
+    ///
+    /// Requires the `http` feature of `shrinkwrap`.
+    #[darling(default)]
+    http: Option<Override<GlobalHttpOpts>>,
+
+    /// Converts the primary extra struct into a `google.protobuf.Struct`, e.g.
+    /// for gRPC trailers/metadata:
+    /// - `TryFrom<&Extra>` for `prost_types::Struct`
+    /// - `to_grpc_metadata(&self) -> Result<prost_types::Struct, _>` on the
+    ///   primary wrapper
+    ///
+    /// Requires the `tonic` feature of `shrinkwrap`, which re-exports
+    /// `prost-types` as `shrinkwrap::grpc::prost_types`. Cannot be used with
+    /// `exclusive` or `tagged_array` extras.
+    pub grpc: Flag,
+
+    /// Generates a `WRAP_TOPOLOGY: &[NestNode]` constant on the data struct,
+    /// describing each nest (ID, parent nest, optionality, struct names).
+    ///
+    /// Requires the `topology` feature of `shrinkwrap`.
+    pub topology: Flag,
+
+    /// Generates a `#[repr(C)]` envelope of the primary wrapper for FFI
+    /// boundaries, `{Wrapper}Ffi`. The data struct is held inline, the extra
+    /// struct behind an owned opaque pointer (accessible via a method named
+    /// after the extra field, e.g. `extra()`).
+    ///
+    /// Converts to and from the serializable wrapper via `From`. Requires the
+    /// data struct to be `#[repr(C)]` (or `#[repr(transparent)]`), computed
+    /// wrapper fields are not supported.
+    pub ffi: Flag,
+
+    /// Applies `#[wasm_bindgen(getter_with_clone)]` to each generated struct,
+    /// exposing wrappers (and their extra and nest structs) to JS as classes
+    /// with a getter per field, without hand-written bindings.
+    ///
+    /// Requires the `wasm` feature of `shrinkwrap`, along with the data struct
+    /// being exported via `#[wasm_bindgen]` itself. Nest field types must be
+    /// supported by `wasm-bindgen`. Cannot be used with `exclusive` or
+    /// `tagged_array` extras, or `layout = "map"` nests.
+    pub wasm_bindgen: Flag,
+
+    /// Generates a `{Data}DebugWrapper` alongside the primary wrapper, holding
+    /// the primary wrapper along with a `diagnostics` object (serialized next
+    /// to the wrapper's fields) listing each nest of the primary extra struct
+    /// and whether it was built, e.g. for staging environments.
+    ///
+    /// Built via `{Data}DebugWrapper::wrap_with(data, transform, options)`
+    /// (or `try_wrap_with` when fallible). The time spent building each nest
+    /// is included when the `instrument` feature of `shrinkwrap` is enabled.
+    /// Cannot be used with `exclusive` or `tagged_array` extras.
+    pub debug_wrapper: Flag,
+
+    /// Path of a foreign type mirrored by the data struct (as with serde's
+    /// `remote`), e.g. `remote = "vendor_sdk::User"`, for wrapping types which
+    /// can't derive `Wrap` themselves.
+    ///
+    /// The mirror must declare the same fields as the remote type, all of which
+    /// must be visible. Generates `From` conversions in both directions, along
+    /// with `Mirror::wrap_remote(remote, transform, options)` (or
+    /// `try_wrap_remote` when fallible).
+    pub remote: Option<Path>,
+
+    /// Asserts that the transform implements the `Option<Nest>` variant of
+    /// the nest trait for each optional nest, e.g.
+    /// `TransformToNest<Option<MyDataNestedText>>`.
+    ///
+    /// Missing impls are reported at the nest definitions rather than as
+    /// unsatisfied bounds at `to_wrapped_with` call sites. Nests using
+    /// `derive_to_nest`, `sources`, `provider` or `identity` are skipped, as
+    /// their impls are generated.
+    pub assert_optional_impls: Flag,
+
+    /// Asserts that the generated wrapper, extra and nest structs are
+    /// `Send + Sync + 'static`, e.g. for wrappers shared across async tasks.
+    ///
+    /// A field type change making a generated struct thread-unsafe is then
+    /// reported at the derive rather than at a distant `tokio::spawn`.
+    pub assert_threadsafe: Flag,
+
+    /// Audits the mapping of origin fields to nests, e.g. to review fields
+    /// added to the data struct since it was last wrapped.
+    ///
+    /// Each field which isn't assigned to (or selected by) any nest is reported
+    /// via a deprecation warning, rather than an error, so that large codebases
+    /// can adopt it incrementally. Fields which are intentionally not nested
+    /// are marked via the field-level `unmapped` flag. The mapped fields are
+    /// listed in the primary wrapper's docs, along with a hidden
+    /// `WRAP_MAPPED_FIELDS` constant on the data struct.
+    pub audit: Flag,
+
+    /// Implements `ToWrappedWith` (and the related traits) for the declared
+    /// `transform` type only, rather than generically for any `T` providing
+    /// the nests, e.g. `impl ToWrappedWith<MyTransform> for MyData`.
+    ///
+    /// Intended for older toolchains, it also reduces monomorphization. The
+    /// data can then only be wrapped via the declared transform.
+    pub concrete_impls: Flag,
+
+    /// Validates the shape of `serde(..)` and `schemars(..)` attributes passed
+    /// through via `shrinkwrap_attr(attr(..))` at derive time, e.g. unknown
+    /// keys (`serde(renam = "..")`), mistyped values (`serde(skip = true)`)
+    /// and misspelled paths (`seder(rename = "..")`).
+    ///
+    /// Errors are reported at the attribute rather than by the downstream
+    /// derives on the generated structs. Other attributes are passed through
+    /// unchecked.
+    pub validate_serde: Flag,
+
+    /// Removes the `Debug` derive from every generated struct, a redacted
+    /// `Debug` impl is generated instead which prints field names with
+    /// `<redacted>` values. Intended for structs containing PII.
+    ///
+    /// Can also be set for a specific struct class, e.g. `wrapper(no_debug)`
+    pub no_debug: Flag,
+
     /// List of derives to apply to every generated struct: e.g. each wrapper,
     /// extra, nest.
     ///
     /// **Note**: Derive lists are merged. You are free to use both `derive_all`
-    /// as well as `derive` on specific struct types (wrapper, extra, nest).
-    ///
-    /// However, you will still receive an error if the same derive is included
-    /// multiple times. This applies to merged derive lists.
+    /// as well as `derive` on specific struct types (wrapper, extra, nest),
+    /// derives included multiple times are deduplicated (e.g. `PartialEq`
+    /// within both `derive_all` and `nest(derive(..))`).
     ///
     /// Regardless of user settings, every generated struct will always derive
-    /// the following (and therefore should not be manually included in either
-    /// a shrinkwrap `derive` attr, or the `derive_all` attr)
-    /// - [`Debug`](std::fmt::Debug)
+    /// the following (including them in either a shrinkwrap `derive` attr, or
+    /// the `derive_all` attr has no effect)
+    /// - [`Debug`](std::fmt::Debug) (unless `no_debug` is set)
     /// - [`Clone`](std::clone::Clone)
     /// - [`serde::Serialize`](serde::Serialize)
     #[darling(default)]
@@ -208,11 +1187,30 @@ pub(crate) struct GlobalOpts {
 }
 impl GlobalOpts {
     pub fn schema(&self) -> bool {
-        self.schema.is_present() || self.inline.is_present()
+        self.schema.is_some() || self.inline.is_present()
+    }
+    /// Consumer crate feature gating the generated schema derives/attrs, if any
+    pub fn schema_feature(&self) -> Option<&LitStr> {
+        match &self.schema {
+            Some(Override::Explicit(schema)) => schema.feature.as_ref(),
+            _ => None,
+        }
     }
     pub fn inline(&self) -> bool {
         self.inline.is_present()
     }
+    /// CSV options, `None` if CSV helpers are disabled
+    pub fn csv(&self) -> Option<GlobalCsvOpts> {
+        self.csv.clone().map(Override::unwrap_or_default)
+    }
+    /// Row view options, `None` if `RowView` impls are disabled
+    pub fn row_view(&self) -> Option<GlobalRowViewOpts> {
+        self.row_view.clone().map(Override::unwrap_or_default)
+    }
+    /// HTTP response options, `None` if response conversions are disabled
+    pub fn http(&self) -> Option<GlobalHttpOpts> {
+        self.http.clone().map(Override::unwrap_or_default)
+    }
     pub fn parse_transform_generic_params(
         meta: &syn::Meta,
     ) -> darling::Result<Option<TokenStream>> {
@@ -221,11 +1219,136 @@ impl GlobalOpts {
     }
 }
 
+/// Options for global testing attribute
+#[derive(Debug, Clone, Default, FromMeta)]
+pub(crate) struct GlobalTestingOpts {
+    /// Derives `arbitrary::Arbitrary`, requires the `arbitrary` crate (with
+    /// the `derive` feature)
+    pub arbitrary: Flag,
+
+    /// Derives `proptest_derive::Arbitrary`, requires both the `proptest` and
+    /// `proptest-derive` crates
+    pub proptest: Flag,
+}
+
+/// Options for global naming attribute
+#[derive(Debug, Clone, Default, FromMeta)]
+pub(crate) struct GlobalNamingOpts {
+    /// Applies the origin struct's `#[serde(rename_all = "..")]` to all
+    /// generated Extra and Nest structs, e.g. a `camelCase` origin serializes
+    /// nest `usd_value` as `usdValue` within `extra`, as well as the fields
+    /// within each nest.
+    ///
+    /// Explicit `rename_all` attrs passed through to a struct take precedence.
+    pub inherit_rename_all: Flag,
+}
+
+/// Options for global docs attribute
+#[derive(Debug, Clone, Default, FromMeta)]
+pub(crate) struct GlobalDocsOpts {
+    /// Places the generated wrapper, extra and nest structs in a
+    /// `#[doc(hidden)]` module (`__shrinkwrap_gen_{snake_case(Data)}`), each
+    /// exposed via a type alias of the same name, e.g.
+    /// `pub type MyDataWrapper = __shrinkwrap_gen_my_data::MyDataWrapper;`.
+    ///
+    /// Reduces rustdoc clutter while keeping the public names stable, the
+    /// aliases carry the struct docs. The module imports the items of the
+    /// parent module, therefore the data struct must not be declared within
+    /// a function body.
+    pub alias_only: Flag,
+}
+
+/// Options for global schema attribute
+#[derive(Debug, Clone, Default, FromMeta)]
+pub(crate) struct GlobalSchemaOpts {
+    /// Cargo feature of the consumer crate gating schema derives/attrs, e.g. `"openapi"`
+    pub feature: Option<LitStr>,
+}
+
+/// Options for global schema_naming attribute
+#[derive(Debug, Clone, Default, FromMeta)]
+pub(crate) struct GlobalSchemaNamingOpts {
+    /// Prepended to each generated struct name, e.g. `"v1."` or `"billing_"`
+    pub prefix: Option<LitStr>,
+
+    /// Appended to each generated struct name, e.g. `"V1"`
+    pub suffix: Option<LitStr>,
+}
+impl GlobalSchemaNamingOpts {
+    /// Schema name of the generated struct `ident`
+    pub fn schema_name(&self, ident: &Ident) -> String {
+        let prefix = self.prefix.as_ref().map(LitStr::value).unwrap_or_default();
+        let suffix = self.suffix.as_ref().map(LitStr::value).unwrap_or_default();
+        format!("{prefix}{ident}{suffix}")
+    }
+}
+
+/// Options for global csv attribute
+#[derive(Debug, Clone, Default, FromMeta)]
+pub(crate) struct GlobalCsvOpts {
+    /// Nest IDs to include as columns, defaults to all nests
+    pub nests: Option<NestIdSelection>,
+}
+impl GlobalCsvOpts {
+    pub fn includes(&self, nest_id: &str) -> bool {
+        match &self.nests {
+            Some(ids) => ids.iter().any(|id| id.value() == nest_id),
+            None => true,
+        }
+    }
+}
+
+/// Options for global row_view attribute
+#[derive(Debug, Clone, Default, FromMeta)]
+pub(crate) struct GlobalRowViewOpts {
+    /// How field values are rendered, defaults to `display`
+    #[darling(default)]
+    pub render: RowRender,
+}
+
+/// Rendering of the field values of a `RowView` impl
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, FromMeta)]
+pub(crate) enum RowRender {
+    /// Via `ToString` (i.e. `Display`)
+    #[default]
+    #[darling(rename = "display")]
+    Display,
+    /// Via `Serialize`, using the JSON representation of non-string values
+    #[darling(rename = "serialize")]
+    Serialize,
+}
+
+/// Options for global http attribute
+#[derive(Debug, Clone, Default, FromMeta)]
+pub(crate) struct GlobalHttpOpts {
+    /// Content type of the response, defaults to `application/json`
+    pub content_type: Option<LitStr>,
+
+    /// Path to a `fn(&Wrapper) -> http::StatusCode`, defaults to `200 OK`
+    pub status: Option<Path>,
+}
+impl GlobalHttpOpts {
+    fn validate(&self, errors: &mut Accumulator) {
+        // mirrors `http::HeaderValue::from_str`
+        let is_valid = |byte: u8| byte == b'\t' || (0x20..0x7f).contains(&byte);
+        if let Some(content_type) = &self.content_type
+            && !content_type.value().bytes().all(is_valid)
+        {
+            errors.push(darling::Error::custom("content_type must only contain printable ASCII characters").with_span(content_type));
+        }
+    }
+}
+
 /// Options for struct nest attribute
 #[derive(Debug, Clone, FromMeta)]
 pub(crate) struct GlobalFallibleNestedOpts {
     /// Error type used for Result returned by trait impls
     pub error: Path,
+
+    /// Additionally implements `TryToWrappedWithErrors`, which sets failed
+    /// optional nests to `None` and collects their errors, rather than
+    /// failing the whole wrapper
+    pub accumulate_errors: Flag,
 }
 
 // ! Wrapper
@@ -247,9 +1370,29 @@ pub(crate) struct WrapperOpts {
     #[darling(default)]
     pub derive: PathList,
 
+    /// Feature-gated derives to apply to all generated Wrapper structs, e.g.
+    /// `cfg_derive(feature = "openapi", paths(utoipa::ToSchema))`. Can be
+    /// provided multiple times
+    #[darling(default, multiple)]
+    pub cfg_derive: Vec<CfgDerive>,
+
+    /// Generates redacted `Debug` impls for all generated Wrapper structs in
+    /// place of the derive, see the global `no_debug` option
+    pub no_debug: Flag,
+
     /// Sets documentation for all generated Wrapper structs
     pub struct_doc: Option<String>,
 
+    /// Schema extensions (e.g. `x-` vendor properties) to add to all
+    /// generated Wrapper structs, can be provided multiple times
+    #[darling(default, multiple)]
+    pub annotate: Vec<SchemaAnnotation>,
+
+    /// Sets `#[serde(bound = "..")]` on all generated Wrapper structs,
+    /// replacing the bounds inferred by serde's derives, e.g.
+    /// `serde_bound = "T: Serialize + DeserializeOwned"`
+    pub serde_bound: Option<LitStr>,
+
     /// Field name for data struct, defaults to data
     #[darling(default = WrapperOpts::data_field_name_default)]
     pub data_field_name: Ident,
@@ -302,18 +1445,115 @@ pub(crate) struct WrapperOpts {
 
     /// Sets field-level documentation for extra field
     pub extra_field_doc: Option<String>,
+
+    /// Derives `serde::Deserialize` on all generated structs.
+    ///
+    /// The origin data struct (and any nest field types) must also implement
+    /// `Deserialize`. Optionally configured via `deserialize(deny_unknown_fields, default_extras)`.
+    deserialize: Option<Override<WrapperDeserializeOpts>>,
+
+    /// Replaces the derived `serde::Serialize` on all generated structs with
+    /// a generated impl that emits fields in lexicographic (serialized key)
+    /// order, regardless of declaration order.
+    ///
+    /// Flattened origin fields are sorted alongside the `extra` field.
+    /// Origin fields using `#[serde(flatten)]`, `#[serde(with)]` or
+    /// `#[serde(serialize_with)]` are not supported.
+    sort_keys: Flag,
+
+    /// Replaces `#[serde(flatten)]` on wrapper data fields with generated
+    /// `serde::Serialize` (and, alongside `deserialize`,
+    /// `serde::Deserialize`) impls which emit each data field directly.
+    ///
+    /// The serialized shape is unchanged for self-describing formats (e.g.
+    /// JSON), while remaining compatible with formats which don't support
+    /// `flatten`, such as MessagePack (`rmp-serde`), CBOR (`ciborium`) and
+    /// `bincode`.
+    ///
+    /// Origin fields using `#[serde(flatten)]`, `#[serde(with)]`,
+    /// `#[serde(serialize_with)]`, `#[serde(deserialize_with)]` or
+    /// `#[serde(skip_serializing_if)]` are not supported.
+    binary_safe: Flag,
+
+    /// Generates `enrich_with(&mut self, transform, options)` on the primary
+    /// wrapper, recomputing its `extra` struct in place from the wrapped data.
+    ///
+    /// If all nests are optional, a `with_empty_extra(data)` constructor is
+    /// generated as well, allowing the wrapper to be built before enrichment.
+    pub enrich: Flag,
+
+    /// Generates accessors on the primary wrapper delegating through its
+    /// `extra` struct, one per nest (e.g. `text(&self) -> &MyDataNestedText`)
+    /// and one per nest field (e.g. `balance_text(&self) -> &String`, named
+    /// `{field}_{nest_field_name}`).
+    ///
+    /// Accessors of optional nests return `Option<&T>`.
+    pub delegate_accessors: Flag,
+
+    /// Implements `Borrow<Data>` for the primary wrapper, along with a
+    /// `to_owned_data(&self) -> Data` helper, allowing wrappers to be used
+    /// wherever the data type is borrowed (e.g. `HashMap<Data, V>` lookups).
+    ///
+    /// As required by `Borrow`, any `Eq`, `Ord` or `Hash` impls of the wrapper
+    /// must be consistent with those of the data.
+    pub borrow: Flag,
+
+    /// Adds a field to the primary wrapper, computed from the data and extra
+    /// structs during construction, e.g.
+    /// `computed(field = "nest_count", type = usize, with = "crate::count_nests")`
+    /// where `with` is a `fn(&Data, &Extra) -> T`. Can be provided multiple times.
+    ///
+    /// Computed fields are recomputed by `enrich_with`. Not supported
+    /// alongside `sort_keys`, `binary_safe` or `integrity`.
+    #[darling(default, multiple)]
+    pub computed: Vec<WrapperComputedOpts>,
+
+    /// Embeds a subset of the transform options used into the primary
+    /// wrapper, e.g. `"options": {"locale": "en-CA"}`, so that clients and
+    /// caches can see which settings produced the payload. The field name
+    /// defaults to `options`, e.g. `echo_options(field = "settings")`.
+    ///
+    /// The options of the declared `transform` must implement
+    /// `shrinkwrap::SerializableOptions`, selecting the subset to serialize.
+    /// The options are re-echoed by `enrich_with`, whereas `with_empty_extra`
+    /// requires the echoed type to implement `Default`. Not supported
+    /// alongside `sort_keys`, `binary_safe`, `integrity` or
+    /// `deserialize(deny_unknown_fields)`.
+    echo_options: Option<Override<WrapperEchoOptionsOpts>>,
+
+    /// Adds a checksum of the serialized data block to the primary wrapper's
+    /// output, e.g. `integrity(algo = "sha256", field = "checksum")`. Requires
+    /// `flatten = false` and the `integrity` feature of `shrinkwrap`.
+    ///
+    /// The checksum is computed by a generated `serde::Serialize` impl (in
+    /// place of the derived impl, as with `sort_keys`), over the compact JSON
+    /// encoding of the data struct.
+    pub integrity: Option<WrapperIntegrityOpts>,
 }
 impl Default for WrapperOpts {
     fn default() -> Self {
         Self {
             struct_suffix: Self::struct_name_suffix_default(),
             derive: PathList::default(),
+            cfg_derive: Vec::new(),
+            no_debug: Flag::default(),
             struct_doc: None,
+            annotate: Vec::new(),
+            serde_bound: None,
             data_field_name: Self::data_field_name_default(),
             data_field_doc: None,
             flatten: None,
             extra_field_name: Self::extra_field_name_default(),
             extra_field_doc: None,
+            deserialize: None,
+            sort_keys: Flag::default(),
+            binary_safe: Flag::default(),
+            enrich: Flag::default(),
+            delegate_accessors: Flag::default(),
+            borrow: Flag::default(),
+            computed: Vec::new(),
+            echo_options: None,
+            integrity: None,
         }
     }
 }
@@ -336,22 +1576,196 @@ impl WrapperOpts {
     fn extra_field_name_default() -> Ident {
         format_ident!("extra")
     }
+    pub fn deserialize(&self) -> bool {
+        self.deserialize.is_some()
+    }
+    fn deserialize_opts(&self) -> Option<&WrapperDeserializeOpts> {
+        match self.deserialize.as_ref()? {
+            Override::Explicit(opts) => Some(opts),
+            Override::Inherit => None,
+        }
+    }
+    pub fn deny_unknown_fields(&self) -> bool {
+        self.deserialize_opts().is_some_and(|opts| opts.deny_unknown_fields.is_present())
+    }
+    pub fn default_extras(&self) -> bool {
+        self.deserialize_opts().is_some_and(|opts| opts.default_extras.is_present())
+    }
+    fn default_extras_span(&self) -> Option<Span> {
+        self.deserialize_opts().filter(|opts| opts.default_extras.is_present()).map(|opts| opts.default_extras.span())
+    }
+    pub fn sort_keys(&self) -> bool {
+        self.sort_keys.is_present()
+    }
+    /// Name of the wrapper field holding the echoed options, `None` if options aren't echoed
+    pub fn echo_options_field(&self) -> Option<Ident> {
+        self.echo_options.clone().map(|echo_options| echo_options.unwrap_or_default().field)
+    }
+    pub fn binary_safe(&self) -> bool {
+        self.binary_safe.is_present()
+    }
+    /// Whether `serde::Serialize` is generated by the macro rather than derived
+    pub fn manual_serialize(&self) -> bool {
+        self.sort_keys() || self.binary_safe() || self.integrity.is_some()
+    }
+    /// Whether `serde::Deserialize` is generated by the macro for wrappers rather than derived
+    ///
+    /// `deny_unknown_fields` isn't supported by serde alongside `flatten`, it is enforced by the generated impl instead
+    pub fn manual_deserialize(&self) -> bool {
+        (self.binary_safe() || self.deny_unknown_fields()) && self.deserialize() && self.flatten()
+    }
 
-    fn validate(&self) -> usize {
-        let mut errs = 0;
+    fn validate(&self, errors: &mut Accumulator) {
         if self.data_field_name == self.extra_field_name {
             let invalid_token = if self.data_field_name == Self::data_field_name_default() {
                 &self.extra_field_name
             } else {
                 &self.data_field_name
             };
-            emit_error!(invalid_token, "data_field_name must be different than extra_field_name");
-            errs += 1;
+            errors.push(darling::Error::custom("data_field_name must be different than extra_field_name").with_span(invalid_token));
+        }
+        if let Some(serde_bound) = self.serde_bound.as_ref().filter(|_| self.manual_serialize()) {
+            errors.push(
+                darling::Error::custom("serde_bound is not supported with sort_keys, binary_safe or integrity, `Serialize` is generated for the wrapper")
+                    .with_span(serde_bound),
+            );
+        }
+        for (index, computed) in self.computed.iter().enumerate() {
+            if self.manual_serialize() {
+                errors.push(
+                    darling::Error::custom("computed fields are not supported with sort_keys, binary_safe or integrity, `Serialize` is generated for the wrapper")
+                        .with_span(&computed.field),
+                );
+            }
+            if computed.field == self.data_field_name || computed.field == self.extra_field_name {
+                errors.push(darling::Error::custom(format!("`{}` is already used by the wrapper", computed.field)).with_span(&computed.field));
+            } else if self.computed[..index].iter().any(|other| other.field == computed.field) {
+                errors.push(darling::Error::custom(format!("Computed field `{}` is defined more than once", computed.field)).with_span(&computed.field));
+            }
+        }
+        if let Some(field) = self.echo_options_field() {
+            if self.manual_serialize() || self.manual_deserialize() {
+                errors.push(
+                    darling::Error::custom("echo_options is not supported with sort_keys, binary_safe, integrity or deny_unknown_fields, serde impls are generated for the wrapper")
+                        .with_span(&field),
+                );
+            }
+            if field == self.data_field_name || field == self.extra_field_name || self.computed.iter().any(|computed| computed.field == field) {
+                errors.push(darling::Error::custom(format!("`{field}` is already used by the wrapper")).with_span(&field));
+            }
+        }
+        if let Some(integrity) = &self.integrity {
+            if self.flatten() {
+                errors.push(
+                    darling::Error::custom("integrity requires `flatten = false`, the checksum covers the nested data block")
+                        .with_span(&integrity.field),
+                );
+            }
+            if integrity.field == self.data_field_name || integrity.field == self.extra_field_name {
+                errors.push(darling::Error::custom(format!("`{}` is already used by the wrapper", integrity.field)).with_span(&integrity.field));
+            }
+            integrity.validate(errors);
+        }
+    }
+}
+
+/// Options for wrapper integrity attribute
+#[derive(Debug, Clone, FromMeta)]
+pub(crate) struct WrapperIntegrityOpts {
+    /// Digest algorithm
+    #[darling(default)]
+    pub algo: IntegrityAlgo,
+
+    /// Serialized field name of the checksum, defaults to checksum
+    #[darling(default = WrapperIntegrityOpts::field_default)]
+    pub field: Ident,
+
+    /// Path to a `fn() -> impl AsRef<[u8]>` providing the secret key, required by `hmac-sha256`
+    pub key: Option<Path>,
+}
+impl WrapperIntegrityOpts {
+    fn field_default() -> Ident {
+        format_ident!("checksum")
+    }
+
+    fn validate(&self, errors: &mut Accumulator) {
+        match (&self.algo, &self.key) {
+            (IntegrityAlgo::HmacSha256, None) => {
+                errors.push(darling::Error::custom("`hmac-sha256` requires a `key`").with_span(&self.field));
+            },
+            (IntegrityAlgo::Sha256, Some(key)) => {
+                errors.push(darling::Error::custom("`key` is only used by `algo = \"hmac-sha256\"`").with_span(key));
+            },
+            _ => {},
         }
-        errs
     }
 }
 
+/// Digest algorithm of the wrapper integrity attribute
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, FromMeta)]
+pub(crate) enum IntegrityAlgo {
+    /// SHA-256 digest
+    #[default]
+    #[darling(rename = "sha256")]
+    Sha256,
+    /// HMAC-SHA256 signature, keyed via `key`
+    #[darling(rename = "hmac-sha256")]
+    HmacSha256,
+}
+
+/// Options for wrapper computed attribute
+#[derive(Debug, Clone, FromMeta)]
+pub(crate) struct WrapperComputedOpts {
+    /// Name of the wrapper field
+    pub field: Ident,
+
+    /// Type of the wrapper field
+    #[darling(rename = "type", with = parse_type)]
+    pub ty: Type,
+
+    /// Path to a `fn(&Data, &Extra) -> T` computing the field
+    pub with: Path,
+}
+
+/// Options for wrapper echo_options attribute
+#[derive(Debug, Clone, FromMeta)]
+pub(crate) struct WrapperEchoOptionsOpts {
+    /// Name of the wrapper field, defaults to options
+    #[darling(default = WrapperEchoOptionsOpts::field_default)]
+    pub field: Ident,
+}
+impl Default for WrapperEchoOptionsOpts {
+    fn default() -> Self {
+        Self { field: Self::field_default() }
+    }
+}
+impl WrapperEchoOptionsOpts {
+    fn field_default() -> Ident {
+        format_ident!("options")
+    }
+}
+
+/// Options for wrapper deserialize attribute
+#[derive(Debug, Clone, Default, FromMeta)]
+pub(crate) struct WrapperDeserializeOpts {
+    /// Rejects unknown keys when deserializing any generated struct.
+    ///
+    /// For wrappers with flattened data, `Deserialize` is generated for the
+    /// wrapper (as serde doesn't support `deny_unknown_fields` alongside
+    /// `flatten`), origin fields using `#[serde(with)]` or
+    /// `#[serde(deserialize_with)]` are then not supported.
+    pub deny_unknown_fields: Flag,
+
+    /// Missing optional nests deserialize as `None`, and a missing extra
+    /// struct deserializes as empty when all of its nests are optional
+    /// (`Default` is derived for such extra structs).
+    ///
+    /// Missing required nests fail with serde's `missing_field` error, naming
+    /// the nest's field. Cannot be used alongside `exclusive` or
+    /// `tagged_array` extras.
+    pub default_extras: Flag,
+}
+
 // ! Extra
 
 /// Options for struct extra attribute
@@ -369,15 +1783,62 @@ pub(crate) struct ExtraOpts {
     #[darling(default)]
     pub derive: PathList,
 
+    /// Feature-gated derives to apply to all generated Extra structs, can be
+    /// provided multiple times
+    #[darling(default, multiple)]
+    pub cfg_derive: Vec<CfgDerive>,
+
+    /// Generates redacted `Debug` impls for all generated Extra structs in
+    /// place of the derive
+    pub no_debug: Flag,
+
     /// Sets struct-level documentation for all generated Extra structs
     pub struct_doc: Option<String>,
+
+    /// Schema extensions (e.g. `x-` vendor properties) to add to all
+    /// generated Extra structs, can be provided multiple times
+    #[darling(default, multiple)]
+    pub annotate: Vec<SchemaAnnotation>,
+
+    /// Sets `#[serde(bound = "..")]` on all generated Extra structs
+    pub serde_bound: Option<LitStr>,
+
+    /// Generates the primary extra as an enum with a variant per root nest
+    /// (plus `None`), for responses where at most a single nest is populated.
+    ///
+    /// Root nests must be optional, the first populated nest (in declaration
+    /// order) is used. Serialized untagged by default, a tag can be set via
+    /// `exclusive(tag = "kind")`, or `exclusive(tag = "kind", content = "value")`
+    /// for adjacent tagging.
+    #[darling(default)]
+    exclusive: Option<SpannedValue<Override<ExtraExclusiveOpts>>>,
+
+    /// Serialized layout of the primary extra, either `"struct"` (default,
+    /// a field per root nest) or `"tagged_array"`.
+    ///
+    /// `tagged_array` generates the primary extra as a `Vec` of an
+    /// internally tagged enum with a variant per root nest, e.g.
+    /// `[{ "kind": "text", .. }]`. Optional nests which are `None` are
+    /// omitted from the array.
+    #[darling(default)]
+    layout: Option<SpannedValue<ExtraLayout>>,
+
+    /// Name of the tag field used by `layout = "tagged_array"`, defaults to `kind`
+    tag: Option<LitStr>,
 }
 impl Default for ExtraOpts {
     fn default() -> Self {
         Self {
             struct_suffix: Self::struct_name_suffix_default(),
             derive: PathList::default(),
+            cfg_derive: Vec::new(),
+            no_debug: Flag::default(),
             struct_doc: None,
+            annotate: Vec::new(),
+            serde_bound: None,
+            exclusive: None,
+            layout: None,
+            tag: None,
         }
     }
 }
@@ -388,17 +1849,82 @@ impl ExtraOpts {
     pub fn struct_name(&self, parent_data_ident: &Ident) -> Ident {
         format_ident!("{parent_data_ident}{}", &self.struct_suffix)
     }
+    /// Exclusive options, `None` if the primary extra is a struct
+    pub fn exclusive(&self) -> Option<ExtraExclusiveOpts> {
+        self.exclusive.as_ref().map(|exclusive| exclusive.as_ref().clone().unwrap_or_default())
+    }
+    fn exclusive_span(&self) -> Option<Span> {
+        self.exclusive.as_ref().map(SpannedValue::span)
+    }
+    /// Tag field name, `None` unless the primary extra uses `layout = "tagged_array"`
+    pub fn tagged_array(&self) -> Option<LitStr> {
+        self.tagged_array_span().map(|span| self.tag.clone().unwrap_or_else(|| LitStr::new("kind", span)))
+    }
+    fn tagged_array_span(&self) -> Option<Span> {
+        self.layout.as_ref().filter(|layout| ***layout == ExtraLayout::TaggedArray).map(SpannedValue::span)
+    }
 
-    fn validate(&self) -> usize {
-        let mut errs = 0;
+    fn validate(&self, errors: &mut Accumulator) {
         if self.struct_suffix.to_string().is_empty() {
-            emit_error!(self.struct_suffix, "struct_suffix cannot be empty");
-            errs += 1;
+            errors.push(darling::Error::custom("struct_suffix cannot be empty").with_span(&self.struct_suffix));
+        }
+        if let Some(exclusive) = self.exclusive()
+            && let Some(content) = &exclusive.content
+            && exclusive.tag.is_none()
+        {
+            errors.push(darling::Error::custom("`content` requires `tag` to be set").with_span(content));
+        }
+        if let Some(tag) = &self.tag && self.tagged_array().is_none() {
+            errors.push(darling::Error::custom("`tag` requires `layout = \"tagged_array\"`").with_span(tag));
+        }
+        if let Some(exclusive_span) = self.exclusive_span() && self.tagged_array().is_some() {
+            errors.push(span_error(exclusive_span, "`exclusive` cannot be used with `layout = \"tagged_array\"`"));
         }
-        errs
     }
 }
 
+/// Serialized layout of the primary extra
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, FromMeta)]
+pub(crate) enum ExtraLayout {
+    /// A struct with a field per root nest
+    #[default]
+    #[darling(rename = "struct")]
+    Struct,
+    /// An array of tagged root nests
+    #[darling(rename = "tagged_array")]
+    TaggedArray,
+}
+
+/// Layout of a nest
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, FromMeta)]
+pub(crate) enum NestLayout {
+    /// A struct with a field per assigned field
+    #[default]
+    #[darling(rename = "struct")]
+    Struct,
+    /// A `BTreeMap` of `key_type` to `field_type`
+    #[darling(rename = "map")]
+    Map,
+}
+
+/// Serialization format of a nest within its extra struct
+#[derive(Debug, Clone, Copy, PartialEq, Eq, FromMeta)]
+pub(crate) enum NestEncode {
+    /// A string holding the nest's JSON encoding
+    #[darling(rename = "json_string")]
+    JsonString,
+}
+
+/// Options for the extra exclusive attribute
+#[derive(Debug, Clone, Default, FromMeta)]
+pub(crate) struct ExtraExclusiveOpts {
+    /// Field name holding the variant name, i.e. `#[serde(tag = "..")]`
+    pub tag: Option<LitStr>,
+
+    /// Field name holding the nest, i.e. `#[serde(content = "..")]` - requires `tag`
+    pub content: Option<LitStr>,
+}
+
 // ! Nest
 
 #[derive(Debug, Clone, FromMeta)]
@@ -416,6 +1942,16 @@ pub(crate) struct NestOpts {
     /// Defaults to `self.id`
     pub field_name: Option<Ident>,
 
+    /// Position of the nest's field within its extra struct (and therefore
+    /// its serialized key order), relative to its sibling nests, including
+    /// any nests hoisted via `no_intermediate_wrapper`.
+    ///
+    /// Fields are ordered by `order` (defaulting to `0`), ties retain the
+    /// nest declaration order, e.g. `order = -1` places the nest first. For
+    /// `exclusive` extras this also sets the order nests are attempted in.
+    #[darling(default)]
+    pub order: i32,
+
     /// sets the name of the nests' generated struct - defaults to
     /// `{SourceStructName}Nested{UpperCamel(field_name || "{self.id}")}`
     pub rename: Option<Ident>,
@@ -425,10 +1961,35 @@ pub(crate) struct NestOpts {
     #[darling(default)]
     pub derive: PathList,
 
+    /// Feature-gated derives to apply to the nest struct, e.g.
+    /// `cfg_derive(feature = "openapi", paths(utoipa::ToSchema))`. Can be
+    /// provided multiple times
+    #[darling(default, multiple)]
+    pub cfg_derive: Vec<CfgDerive>,
+
+    /// Generates a redacted `Debug` impl for the nest struct in place of the
+    /// derive
+    pub no_debug: Flag,
+
+    /// Derives `validator::Validate` on the nest struct, requires the
+    /// `validate` feature of `shrinkwrap` (which re-exports `validator`).
+    ///
+    /// Rules are set per field, e.g. `nest(id = "text", validate(length(max = 64)))`,
+    /// allowing transform outputs to be validated (via `nest.validate()`)
+    /// before they are returned.
+    pub validate: Flag,
+
     /// Sets the type for the fields in the nested struct.
     ///
     /// Cannot be used alongside `derive_to_nest` within the same nest.
-    pub field_type: Option<Path>,
+    ///
+    /// Any type is supported, e.g. `[f32; 3]`, `(f32, String)`, including
+    /// generics (`BTreeMap<String, String>`, `Vec::<String>`) and qualified
+    /// paths (`<T as Trait>::Output`). Other types which aren't valid
+    /// expressions (e.g. references) must be quoted, e.g.
+    /// `field_type = "&'static str"`.
+    #[darling(default, with = parse_optional_type)]
+    pub field_type: Option<Type>,
 
     /// Derive `TransformToNest`/`TryTransformToNest` automatically.
     /// Cannot be used alongside `field_type` within the same nest.
@@ -437,17 +1998,232 @@ pub(crate) struct NestOpts {
     /// Optional Nest ID, allows for embedding  this nest within another nest
     pub chain_from: Option<SpannedValue<String>>,
 
+    /// Optional Nest ID of a sibling nest (sharing the same `chain_from`)
+    /// whose computed value is required to build this nest.
+    ///
+    /// The sibling is computed first, this nest is then built via
+    /// `TransformToNestWithSiblings<Nest, Sibling>` (or the `Try` variant)
+    /// rather than `TransformToNest<Nest>`.
+    pub needs: Option<SpannedValue<String>>,
+
+    /// Optional Nest ID of a sibling nest (sharing the same `chain_from`)
+    /// superseded by this nest, e.g. `nest(id = "text_v2", deprecates = "text")`,
+    /// for emitting both versions of a nest during a migration.
+    ///
+    /// A `#[deprecated]` accessor is generated for the superseded nest on its
+    /// extra struct, e.g. `extra.text()`. Alongside `schema`, the superseded
+    /// nest is marked `deprecated` (and `x-superseded-by`) within the extra's
+    /// schema, and this nest `x-supersedes`.
+    ///
+    /// Cannot be used for root nests of `exclusive` / `tagged_array` extras.
+    pub deprecates: Option<SpannedValue<String>>,
+
+    /// Only builds the nest superseded via `deprecates` when the transform's
+    /// options return true from `shrinkwrap::DeprecatedNestGate::emit_deprecated`,
+    /// allowing the old nest to be phased out at runtime.
+    ///
+    /// The superseded nest must be optional, and cannot use `batch` or be
+    /// chained from an `auto_parent` nest.
+    pub gate_deprecated: Flag,
+
+    /// Generates a borrowed `{NestStruct}Sources<'a>` view of the source
+    /// fields included in this nest, along with a `TransformToNest` impl
+    /// delegating to `TransformFromSources<{NestStruct}Sources<'a>, Nest>`
+    /// (or the `Try` variant).
+    ///
+    /// Allows the transform to be written against the exact source field
+    /// types of the nest instead of the full source struct.
+    ///
+    /// Cannot be used alongside `derive_to_nest` or `needs` within the same nest.
+    pub sources: Flag,
+
+    /// Builds the nest via a provider (implementing `shrinkwrap::providers::NestProvider`)
+    /// instead of a user-defined transform, e.g. `provider = shrinkwrap::providers::Timestamps`.
+    ///
+    /// The provider type is used in place of a generated nest struct (via a type alias), therefore
+    /// no fields may be assigned to the nest.
+    ///
+    /// Cannot be used alongside `field_type`, `derive_to_nest`, `sources`, or `needs` within the same nest.
+    pub provider: Option<Path>,
+
+    /// Nest fields keep the type of their source field (the origin field, or
+    /// the parent nest's field when chained), making the nest a plain
+    /// projection of its source data.
+    ///
+    /// Generates `From<&Source>` for the nest struct (providing
+    /// `shrinkwrap::ToNest`) along with a `TransformToNest` impl delegating
+    /// to it, optional nests are always `Some`.
+    ///
+    /// Cannot be used alongside `field_type`, `derive_to_nest`, `sources`, `needs`, or `provider` within the same nest.
+    pub identity: Flag,
+
+    /// Builds the nest for a whole collection at once via
+    /// `BatchTransformToNest<Nest>` (or the `Try` variant), when wrapping via
+    /// `to_wrapped_all_with`. A `TransformToNest` impl is still required for
+    /// wrapping single items, and is used for every item of the collection
+    /// when the batch impl doesn't return exactly one nest per item.
+    ///
+    /// Only supported for root nests. Cannot be used alongside `needs`,
+    /// `derive_to_nest`, `sources`, `provider`, or `identity` within the same nest.
+    pub batch: Flag,
+
+    /// Includes fields of nested (non-`Wrap`) struct fields of the origin
+    /// data, selected by path, e.g. `fields("profile.bio", "profile.avatar_url as avatar")`.
+    ///
+    /// Nest fields are named by the last path segment unless renamed via
+    /// `as`, and use the nest's `field_type`. The path isn't followed by
+    /// generated code, the transform reads the selected field itself.
+    ///
+    /// Only supported for root nests. Cannot be used alongside `derive_to_nest`,
+    /// `sources`, `provider`, or `identity` within the same nest.
+    #[darling(default, with = NestFieldSelector::parse_list)]
+    pub fields: Vec<NestFieldSelector>,
+
+    /// Assigns every origin field to the nest, except those listed via
+    /// `exclude`, e.g. `nest(id = "text", all_fields, exclude("id", "internal"))`.
+    ///
+    /// Fields may still be assigned explicitly, e.g. to override their type
+    /// via `nest(id = "text", ty = ..)`.
+    ///
+    /// Only supported for root nests. Cannot be used alongside `provider`,
+    /// `layout = "map"`, or `auto_parent` within the same nest.
+    pub all_fields: Flag,
+
+    /// Origin fields excluded from an `all_fields` nest
+    #[darling(default)]
+    pub exclude: Vec<LitStr>,
+
+    /// Generates the nest purely from its children, for container nests
+    /// without any fields of their own (no user-defined transform is
+    /// required for the nest itself).
+    ///
+    /// Nests chained from this nest are built from this nest's source data
+    /// (the origin, or the parent nest when chained), i.e. their transforms
+    /// use `type Data = Source`. Optional nests are `None` when all of their
+    /// (optional) children are `None`.
+    ///
+    /// Cannot be used alongside `field_type`, `derive_to_nest`, `sources`,
+    /// `needs`, `provider`, `identity`, `batch`, or `fields` within the same nest.
+    pub auto_parent: Flag,
+
+    /// Holds the raw nest struct in the parent extra struct, rather than an
+    /// intermediate wrapper of the nest and the nests chained from it.
+    ///
+    /// The chained nests are hoisted into the parent extra struct alongside
+    /// this nest (their field names must therefore be unique within it), and
+    /// are still built from this nest, i.e. their transforms use
+    /// `type Data = ThisNest`. If this nest is optional, non-optional chained
+    /// nests are lifted to `Option` within the extra struct: they are present
+    /// whenever this nest is, and their transforms still build the nest itself
+    /// (rather than `Option<Nest>`).
+    ///
+    /// Cannot be used alongside `auto_parent` or `batch`, or on nests chained
+    /// from an `auto_parent` nest.
+    pub no_intermediate_wrapper: Flag,
+
+    /// Adds `#[serde(skip_serializing_if = "..")]` to every field of the
+    /// nest, omitting empty values from the serialized output.
+    ///
+    /// Without a predicate, `Option` fields are skipped when `None`, and
+    /// all other fields when their type's `is_empty` returns true (e.g.
+    /// `Vec::is_empty`, `String::is_empty`). A predicate can be provided
+    /// instead, e.g. `skip_empty = "crate::is_blank"`. Can be set (or
+    /// overridden) per field, e.g. `nest(id = "text", skip_empty)`.
+    ///
+    /// Fields are additionally marked `#[serde(default)]` alongside
+    /// `wrapper(deserialize)`.
+    #[darling(default)]
+    pub skip_empty: Option<Override<Path>>,
+
+    /// Serializes the nest's fields at the root of the wrapper rather than
+    /// within the extra struct, e.g. for legacy envelopes. The nest is still
+    /// held by the extra struct in Rust.
+    ///
+    /// `Serialize` is generated for the wrapper (via a borrowed view with
+    /// `#[serde(flatten)]` fields). Optional nests add no fields when `None`.
+    ///
+    /// Only supported for root nests. Cannot be used alongside `wrapper(sort_keys)`,
+    /// `wrapper(binary_safe)`, `wrapper(integrity)`, `wrapper(deserialize)`, `json`,
+    /// or `exclusive` / `tagged_array` extras.
+    pub promote: Flag,
+
+    /// Serializes the nest in an alternate format within its extra struct,
+    /// requires the `json` feature of `shrinkwrap`.
+    ///
+    /// `"json_string"` serializes the nest as a string holding its JSON
+    /// encoding (double-encoded), e.g. for legacy consumers expecting a JSON
+    /// blob. The string is decoded when deserializing (via `wrapper(deserialize)`),
+    /// and the nest is described as a string within the schema and JSON shape.
+    ///
+    /// Cannot be used alongside `promote`, or for root nests of `exclusive` /
+    /// `tagged_array` extras.
+    pub encode: Option<SpannedValue<NestEncode>>,
+
+    /// Previous serialized key of the nest within its extra struct, e.g.
+    /// `nest(id = "text", alias = "txt")` while migrating clients to a
+    /// renamed key. Emits `#[serde(alias = "..")]`, accepting the previous
+    /// key when deserializing, and lists it as a deprecated key under
+    /// `x-aliases` within the extra's schema.
+    pub alias: Option<SpannedValue<String>>,
+
+    /// Serializes the nest under both its key and its `alias` while aliases
+    /// are enabled at runtime, see `shrinkwrap::alias::with_aliases`. The
+    /// alias follows the nest's key, or is sorted alongside the other keys
+    /// with `wrapper(sort_keys)`.
+    ///
+    /// `Serialize` is generated for the extra struct. Cannot be used
+    /// alongside `promote`, or for root nests of `exclusive` / `tagged_array`
+    /// extras.
+    pub emit_alias: Flag,
+
+    /// Layout of the nest, either `"struct"` (default, a field per assigned
+    /// field) or `"map"`.
+    ///
+    /// `map` nests are keyed lookups (e.g. prices by currency), generated as
+    /// a `BTreeMap<key_type, field_type>` type alias rather than a struct,
+    /// e.g. `nest(id = "prices", layout = "map", key_type = String, field_type = f64)`.
+    /// Transforms implement `TransformToNest<BTreeMap<K, V>>`. No fields may
+    /// be assigned to (or chained from) the nest.
+    #[darling(default)]
+    layout: Option<SpannedValue<NestLayout>>,
+
+    /// Key type of `layout = "map"` nests
+    #[darling(default, with = parse_optional_type)]
+    pub key_type: Option<Type>,
+
     /// Sets the struct-level documentation for the generated Nest struct
     pub struct_doc: Option<String>,
 
+    /// Schema extensions (e.g. `x-` vendor properties) to add to the
+    /// generated Nest struct, can be provided multiple times
+    #[darling(default, multiple)]
+    pub annotate: Vec<SchemaAnnotation>,
+
+    /// Sets `#[serde(bound = "..")]` on the generated Nest struct
+    pub serde_bound: Option<LitStr>,
+
+    /// Excludes the nest from the schema, e.g. for nests only consumed by
+    /// internal services. The nest's field is skipped within the extra's
+    /// schema (`#[schemars(skip)]`), and `JsonSchema` isn't derived for the
+    /// nest's structs (including those of nests chained from it).
+    ///
+    /// Serialization is unaffected. Requires the `schema` (or `inline`) flag.
+    pub schema_skip: Flag,
+
     /// The parent extra struct will type the field for this nest with
     /// `Option<T>`, e.g, the generated extra struct would look like
     /// ```rust
+    /// # pub struct MyDataNestedText;
     /// pub struct MyDataExtra {
     ///     pub text: Option<MyDataNestedText>,
     /// }
     /// ```
     pub optional: Flag,
+
+    /// Keeps the nest required when `all_optional` is set.
+    ///
+    /// Cannot be used alongside `optional`.
+    pub required: Flag,
 }
 impl NestOpts {
     pub fn id_str(&self) -> &str {
@@ -504,6 +2280,24 @@ impl NestOpts {
     pub fn optional(&self) -> bool {
         self.optional.is_present()
     }
+    /// Whether the nest is optional, taking `all_optional` and `required` into account
+    pub fn is_optional(&self, all_optional: bool) -> bool {
+        !self.required.is_present() && (all_optional || self.optional())
+    }
+    /// Whether `all_fields` may assign fields to the nest, see [`Self::all_fields`]
+    fn all_fields_valid(&self) -> bool {
+        self.is_root_nest() && self.provider.is_none() && self.map_layout_span().is_none() && !self.auto_parent.is_present()
+    }
+    fn map_layout_span(&self) -> Option<Span> {
+        self.layout.as_ref().filter(|layout| *layout.as_ref() == NestLayout::Map).map(SpannedValue::span)
+    }
+    /// `BTreeMap` type used in place of a nest struct by `layout = "map"` nests
+    pub fn map_type(&self) -> Option<Type> {
+        self.map_layout_span()?;
+        let key_type = self.key_type.as_ref()?;
+        let value_type = self.field_type.as_ref()?;
+        Some(parse_quote!(::std::collections::BTreeMap<#key_type, #value_type>))
+    }
 
     pub fn derive_to_nest_options_field_name(&self) -> Option<Ident> {
         self.derive_to_nest.as_ref().map(|derive_to_nest| {
@@ -513,41 +2307,244 @@ impl NestOpts {
     }
 
     // scoped validation should have been done prior to any access, allow expect here
-    pub fn resolve_field_type(&self) -> &Path {
+    // identity nests have no default type, their fields use the source field types
+    pub fn resolve_field_type(&self) -> &Type {
         if let Some(field_type) = self.field_type.as_ref() {
             field_type
         } else {
             &self.derive_to_nest
                 .as_ref()
-                .expect_or_abort("Validated field_type XOR derive_transform(value)")
+                .expect("Validated field_type XOR derive_transform(value)")
                 .value
         }
     }
 
-    fn validate(&self, nest_span: Span) -> usize {
-        let mut errs = 0;
-
-        if self.id.is_empty() {
-            // emit_error!(self.id, "Nest ID cannot be empty");
-            // emit_error!(self.id.to_token_stream(), "Nest ID cannot be empty");
-            emit_error!(self.id.span(), "Nest ID cannot be empty");
-            errs += 1;
+    fn validate(&self, nest_span: Span, errors: &mut Accumulator) {
+        // the ID is used within the nest struct name, and as the nest field name unless `field_name` is set
+        let id = self.id_str();
+        if id.is_empty() {
+            errors.push(span_error(self.id.span(), "Nest ID cannot be empty"));
+        } else if !id.chars().all(|c| c.is_alphanumeric() || c == '_') {
+            errors.push(span_error(self.id.span(), format!("Nest ID `{id}` can only contain alphanumeric characters and underscores")));
+        } else if self.field_name.is_none() && syn::parse_str::<Ident>(id).is_err() {
+            errors.push(span_error(self.id.span(), format!("Nest ID `{id}` is not a valid field name, set `field_name` to use it")));
         }
         if let Some(chain_from) = &self.chain_from && chain_from.as_str() == self.id.as_str() {
-            emit_error!(chain_from.span(), "Nest cannot be chained from itself");
-            errs += 1;
+            errors.push(span_error(chain_from.span(), "Nest cannot be chained from itself"));
+        }
+        if let Some(needs) = &self.needs && needs.as_str() == self.id.as_str() {
+            errors.push(span_error(needs.span(), "Nest cannot depend on itself"));
+        }
+        if let Some(deprecates) = &self.deprecates && deprecates.as_str() == self.id.as_str() {
+            errors.push(span_error(deprecates.span(), "Nest cannot deprecate itself"));
+        }
+        if self.gate_deprecated.is_present() && self.deprecates.is_none() {
+            errors.push(span_error(self.gate_deprecated.span(), "`gate_deprecated` requires `deprecates`"));
+        }
+        if let Some(needs) = &self.needs && let Some(derive_to_nest) = &self.derive_to_nest {
+            errors.push(span_error(derive_to_nest.span(), "`derive_to_nest` defined here"));
+            errors.push(span_error(needs.span(), "`needs` cannot be used with `derive_to_nest`"));
+        }
+        if self.sources.is_present() && let Some(derive_to_nest) = &self.derive_to_nest {
+            errors.push(span_error(derive_to_nest.span(), "`derive_to_nest` defined here"));
+            errors.push(span_error(self.sources.span(), "`sources` cannot be used with `derive_to_nest`"));
+        }
+        if self.sources.is_present() && let Some(needs) = &self.needs {
+            errors.push(span_error(needs.span(), "`needs` defined here"));
+            errors.push(span_error(self.sources.span(), "`sources` cannot be used with `needs`"));
         }
         if let Some(field_type) = &self.field_type && let Some(derive_to_nest) = &self.derive_to_nest {
-            emit_error!(derive_to_nest.span(), "`derive_to_nest` defined here");
-            emit_error!(field_type, "`field_type` cannot be used with `derive_to_nest`");
-            errs += 1;
+            errors.push(span_error(derive_to_nest.span(), "`derive_to_nest` defined here"));
+            errors.push(darling::Error::custom("`field_type` cannot be used with `derive_to_nest`").with_span(field_type));
+        }
+        if self.batch.is_present() {
+            let conflicts = [
+                ("chain_from", self.chain_from.is_some()),
+                ("needs", self.needs.is_some()),
+                ("derive_to_nest", self.derive_to_nest.is_some()),
+                ("sources", self.sources.is_present()),
+                ("provider", self.provider.is_some()),
+                ("identity", self.identity.is_present()),
+            ];
+            for (name, _) in conflicts.into_iter().filter(|(_, present)| *present) {
+                errors.push(span_error(self.batch.span(), format!("`batch` cannot be used with `{name}`")));
+            }
+        }
+        if let Some(map_span) = self.map_layout_span() {
+            let conflicts = [
+                ("derive_to_nest", self.derive_to_nest.is_some()),
+                ("sources", self.sources.is_present()),
+                ("provider", self.provider.is_some()),
+                ("identity", self.identity.is_present()),
+                ("auto_parent", self.auto_parent.is_present()),
+                ("fields", !self.fields.is_empty()),
+                ("skip_empty", self.skip_empty.is_some()),
+                ("derive", !self.derive.is_empty()),
+                ("cfg_derive", !self.cfg_derive.is_empty()),
+                ("no_debug", self.no_debug.is_present()),
+                ("validate", self.validate.is_present()),
+                ("annotate", !self.annotate.is_empty()),
+                ("serde_bound", self.serde_bound.is_some()),
+            ];
+            for (name, _) in conflicts.into_iter().filter(|(_, present)| *present) {
+                errors.push(span_error(map_span, format!("`layout = \"map\"` cannot be used with `{name}`")));
+            }
+            if self.key_type.is_none() {
+                errors.push(span_error(map_span, "`layout = \"map\"` requires `key_type`"));
+            }
+        } else if let Some(key_type) = &self.key_type {
+            errors.push(darling::Error::custom("`key_type` requires `layout = \"map\"`").with_span(key_type));
+        }
+        if self.promote.is_present() && let Some(chain_from) = &self.chain_from {
+            errors.push(span_error(chain_from.span(), "`chain_from` defined here"));
+            errors.push(span_error(self.promote.span(), "`promote` can only be used for root nests"));
+        }
+        if let Some(encode) = &self.encode && self.promote.is_present() {
+            errors.push(span_error(self.promote.span(), "`promote` defined here"));
+            errors.push(span_error(encode.span(), "`encode` cannot be used with `promote`"));
         }
-        if self.field_type.is_none() && self.derive_to_nest.is_none() {
-            emit_error!(nest_span, "Either `field_type` or `derive_to_nest` must be configured");
-            errs += 1;
+        if let Some(alias) = &self.alias {
+            if alias.is_empty() {
+                errors.push(span_error(alias.span(), "Nest alias cannot be empty"));
+            } else if self.field_name.as_ref().map_or_else(|| alias.as_str() == self.id_str(), |name| name == alias.as_str()) {
+                errors.push(span_error(alias.span(), "Nest alias cannot match its field name"));
+            }
+        }
+        if self.emit_alias.is_present() {
+            if self.alias.is_none() {
+                errors.push(span_error(self.emit_alias.span(), "`emit_alias` requires `alias`"));
+            }
+            if self.promote.is_present() {
+                errors.push(span_error(self.emit_alias.span(), "`emit_alias` cannot be used with `promote`"));
+            }
+        }
+        if let Some(selector) = self.fields.first() {
+            let conflicts = [
+                ("chain_from", self.chain_from.is_some()),
+                ("derive_to_nest", self.derive_to_nest.is_some()),
+                ("sources", self.sources.is_present()),
+                ("provider", self.provider.is_some()),
+                ("identity", self.identity.is_present()),
+            ];
+            for (name, _) in conflicts.into_iter().filter(|(_, present)| *present) {
+                errors.push(span_error(selector.span, format!("`fields` cannot be used with `{name}`")));
+            }
+        }
+        if self.all_fields.is_present() {
+            let conflicts = [
+                ("chain_from", self.chain_from.is_some()),
+                ("provider", self.provider.is_some()),
+                ("layout = \"map\"", self.map_layout_span().is_some()),
+                ("auto_parent", self.auto_parent.is_present()),
+            ];
+            for (name, _) in conflicts.into_iter().filter(|(_, present)| *present) {
+                errors.push(span_error(self.all_fields.span(), format!("`all_fields` cannot be used with `{name}`")));
+            }
+        } else if let Some(exclude) = self.exclude.first() {
+            errors.push(span_error(exclude.span(), "`exclude` requires `all_fields`"));
         }
+        if self.required.is_present() && self.optional() {
+            errors.push(span_error(self.required.span(), "`required` cannot be used with `optional`"));
+        }
+        if self.no_intermediate_wrapper.is_present() {
+            let conflicts = [
+                ("auto_parent", self.auto_parent.is_present()),
+                ("batch", self.batch.is_present()),
+            ];
+            for (name, _) in conflicts.into_iter().filter(|(_, present)| *present) {
+                errors.push(span_error(self.no_intermediate_wrapper.span(), format!("`no_intermediate_wrapper` cannot be used with `{name}`")));
+            }
+        }
+        if self.auto_parent.is_present() {
+            let conflicts = [
+                ("field_type", self.field_type.is_some()),
+                ("derive_to_nest", self.derive_to_nest.is_some()),
+                ("sources", self.sources.is_present()),
+                ("needs", self.needs.is_some()),
+                ("provider", self.provider.is_some()),
+                ("identity", self.identity.is_present()),
+                ("batch", self.batch.is_present()),
+                ("fields", !self.fields.is_empty()),
+                ("skip_empty", self.skip_empty.is_some()),
+            ];
+            for (name, _) in conflicts.into_iter().filter(|(_, present)| *present) {
+                errors.push(span_error(self.auto_parent.span(), format!("`auto_parent` cannot be used with `{name}`")));
+            }
+        } else if let Some(provider) = &self.provider {
+            let conflicts = [
+                ("field_type", self.field_type.is_some()),
+                ("derive_to_nest", self.derive_to_nest.is_some()),
+                ("sources", self.sources.is_present()),
+                ("needs", self.needs.is_some()),
+                ("derive", !self.derive.is_empty()),
+                ("cfg_derive", !self.cfg_derive.is_empty()),
+                ("no_debug", self.no_debug.is_present()),
+                ("validate", self.validate.is_present()),
+                ("annotate", !self.annotate.is_empty()),
+                ("serde_bound", self.serde_bound.is_some()),
+                ("skip_empty", self.skip_empty.is_some()),
+            ];
+            let conflicts = conflicts.into_iter().chain([("identity", self.identity.is_present())]);
+            for (name, _) in conflicts.filter(|(_, present)| *present) {
+                errors.push(darling::Error::custom(format!("`provider` cannot be used with `{name}`")).with_span(provider));
+            }
+        } else if self.identity.is_present() {
+            let conflicts = [
+                ("field_type", self.field_type.is_some()),
+                ("derive_to_nest", self.derive_to_nest.is_some()),
+                ("sources", self.sources.is_present()),
+                ("needs", self.needs.is_some()),
+            ];
+            for (name, _) in conflicts.into_iter().filter(|(_, present)| *present) {
+                errors.push(span_error(self.identity.span(), format!("`identity` cannot be used with `{name}`")));
+            }
+        } else if self.field_type.is_none() && self.derive_to_nest.is_none() {
+            errors.push(span_error(nest_span, "Either `field_type`, `derive_to_nest`, `provider`, or `identity` must be configured"));
+        }
+    }
+}
+
+// ! Nest field selector
+
+/// A field within a nested struct field of the origin data, e.g. `"profile.bio"` or `"profile.bio as profile_bio"`
+#[derive(Debug, Clone)]
+pub(crate) struct NestFieldSelector {
+    /// Field path from the origin data, at least two segments
+    pub path: Vec<Ident>,
 
-        errs
+    /// Name of the field within the nest, defaults to the last path segment
+    pub name: Ident,
+
+    pub span: Span,
+}
+impl NestFieldSelector {
+    /// The path as written, e.g. `profile.bio`
+    pub fn path_str(&self) -> String {
+        self.path.iter().map(Ident::to_string).collect::<Vec<_>>().join(".")
+    }
+    pub(crate) fn parse_list(meta: &Meta) -> darling::Result<Vec<Self>> {
+        let mut errors = darling::Error::accumulator();
+        let selectors = Vec::<LitStr>::from_meta(meta)?.iter()
+            .filter_map(|selector| errors.handle(Self::parse(&selector.value(), selector.span())))
+            .collect();
+        errors.finish_with(selectors)
+    }
+    fn parse(selector: &str, span: Span) -> darling::Result<Self> {
+        let parse_ident = |value: &str| {
+            syn::parse_str::<Ident>(value.trim())
+                .map(|mut ident| { ident.set_span(span); ident })
+                .map_err(|_| span_error(span, format!("`{}` is not a valid field name", value.trim())))
+        };
+        let (path, rename) = match selector.split_once(" as ") {
+            Some((path, rename)) => (path, Some(parse_ident(rename)?)),
+            None => (selector, None),
+        };
+        let path = path.split('.').map(parse_ident).collect::<darling::Result<Vec<_>>>()?;
+        if path.len() < 2 {
+            return Err(span_error(span, "Field selectors must contain a nested field, e.g. `profile.bio`"));
+        }
+        let name = rename.unwrap_or_else(|| path.last().unwrap().clone());
+        Ok(Self { path, name, span })
     }
 }
 
@@ -562,7 +2559,8 @@ pub(crate) struct DeriveToNest {
     /// done in cases of deep nesting).
     ///
     /// Type must implement `NestValueType`.
-    pub value: Path,
+    #[darling(with = parse_type)]
+    pub value: Type,
 
     /// Only compatible with `optional` nests. Defaults to `"with_"` + nest `field_name`
     /// attr (as `snake_case`) if unset and nest is optional.
@@ -602,6 +2600,10 @@ pub(crate) enum StructClass {
     Wrapper,
     Nest,
     Extra,
+    /// The `data` field of a wrapper, only applied when explicitly selected
+    DataField,
+    /// The `extra` field of a wrapper, only applied when explicitly selected
+    ExtraField,
 }
 impl StructClass {
     pub(crate) fn key(&self) -> String {
@@ -609,6 +2611,8 @@ impl StructClass {
             Self::Wrapper => "wrapper",
             Self::Nest => "nest",
             Self::Extra => "extra",
+            Self::DataField => "data_field",
+            Self::ExtraField => "extra_field",
         }
         .into()
     }
@@ -622,6 +2626,8 @@ impl TryFrom<&syn::Path> for StructClass {
                 "wrapper" => Some(Self::Wrapper),
                 "extra" => Some(Self::Extra),
                 "nest" => Some(Self::Nest),
+                "data_field" => Some(Self::DataField),
+                "extra_field" => Some(Self::ExtraField),
                 _ => None,
             };
             if let Some(class) = class_type {
@@ -629,7 +2635,7 @@ impl TryFrom<&syn::Path> for StructClass {
             }
         }
         Err(darling::Error::custom(
-            "Invalid class type specified. Valid types: [wrapper, extra, nest]",
+            "Invalid class type specified. Valid types: [wrapper, extra, nest, data_field, extra_field]",
         )
         .with_span(&value))
     }
@@ -678,28 +2684,20 @@ impl TryFrom<PathList> for StructClassSelection {
 // !- Attribute passthrough
 
 /// Receives tokens in the form of `attr(serde(rename_all="snake_case"))`
-fn extract_passthrough_attr_meta(meta: &Meta) -> Attribute {
+fn extract_passthrough_attr_meta(meta: &Meta) -> darling::Result<Attribute> {
     match meta.require_list() {
         Ok(list) => {
             if let Ok(path) = list.path.require_ident() && path == "attr" {
                 let inner_attr = &list.tokens;
-                parse_quote!(#[#inner_attr])
+                Ok(parse_quote!(#[#inner_attr]))
             } else {
-                abort!(
-                    list.path.span(),
-                    format!(
-                        "Unexpected key for passthrough attributes `attr` group. Expected `attr`"
-                    )
-                )
+                Err(darling::Error::custom("Unexpected key for passthrough attributes `attr` group. Expected `attr`").with_span(&list.path))
             }
         },
         Err(error) => {
-            abort!(
-                meta.span(),
-                format!(
-                    "Unexpected attr meta type. Expected a list `(that,looks,like,this).\nOriginal error: {error}`"
-                )
-            );
+            Err(darling::Error::custom(format!(
+                "Unexpected attr meta type. Expected a list `(that,looks,like,this).\nOriginal error: {error}`"
+            )).with_span(meta))
         }
     }
 }
@@ -715,30 +2713,31 @@ pub(crate) struct StructProxyAttribute {
     pub limit: StructRestriction,
 }
 impl StructProxyAttribute {
-    pub(crate) fn maybe_from_attribute(attr: &Attribute) -> Option<Self> {
+    pub(crate) fn maybe_from_attribute(attr: &Attribute) -> darling::Result<Option<Self>> {
         let forward_ident = format_ident!("{FORWARD_ATTR}");
         if attr.path().get_ident() == Some(&forward_ident) {
-            let proxy_attr = match Self::from_meta(&attr.meta) {
-                Ok(proxy) => proxy,
-                Err(error) => abort!(error.span(), error),
-            };
+            let proxy_attr = Self::from_meta(&attr.meta)?;
             let limit = &proxy_attr.limit;
             if limit.origin.is_present() && let Some(nests) = &limit.nests {
-                emit_error!(nests.span(), "Conflicting `nests` attribute defined here");
-                abort!(limit.origin.span(), "`nests` and `origin` cannot be set simultaneously");
+                return Err(darling::Error::multiple(vec![
+                    span_error(nests.span(), "Conflicting `nests` attribute defined here"),
+                    span_error(limit.origin.span(), "`nests` and `origin` cannot be set simultaneously"),
+                ]));
             }
             if limit.origin.is_present() && let Some(class) = &limit.class && class.contains(StructClass::Nest) {
-                emit_error!(class.span(), "Conflicting `class` attribute defined here. Option 1) remove `nest` from `class` list.");
-                abort!(limit.origin.span(), "`class(nest)` and `origin` cannot be set simultaneously. Option 2) remove the `origin` flag.");
+                return Err(darling::Error::multiple(vec![
+                    span_error(class.span(), "Conflicting `class` attribute defined here. Option 1) remove `nest` from `class` list."),
+                    span_error(limit.origin.span(), "`class(nest)` and `origin` cannot be set simultaneously. Option 2) remove the `origin` flag."),
+                ]));
             }
 
-            Some(proxy_attr)
+            Ok(Some(proxy_attr))
         } else {
-            None
+            Ok(None)
         }
     }
-    pub(crate) fn maybe_extract_from(attr: &Attribute) -> Option<ExtractedStructAttribute> {
-        Self::maybe_from_attribute(attr).map(ExtractedStructAttribute::from)
+    pub(crate) fn maybe_extract_from(attr: &Attribute) -> darling::Result<Option<ExtractedStructAttribute>> {
+        Self::maybe_from_attribute(attr)?.map(ExtractedStructAttribute::try_from).transpose()
     }
 }
 
@@ -757,6 +2756,11 @@ pub(crate) struct StructRestriction {
 
     /// Type of generated structs to restrict assignment to.
     ///
+    /// `data_field` and `extra_field` target the `data`/`extra` fields of
+    /// wrapper structs rather than a struct itself, e.g.
+    /// `class(data_field)` with `attr(schemars(title = "User"))`. They are
+    /// never included unless listed explicitly.
+    ///
     /// If the `origin` restriction flag is provided, `class` cannot contain `nest`
     #[darling(default, with=StructClassSelection::parse_input)]
     pub class: Option<SpannedValue<StructClassSelection>>,
@@ -802,8 +2806,10 @@ impl ExtractedStructAttribute {
         }
     }
 }
-impl From<StructProxyAttribute> for ExtractedStructAttribute {
-    fn from(proxy_attr: StructProxyAttribute) -> Self {
+impl TryFrom<StructProxyAttribute> for ExtractedStructAttribute {
+    type Error = darling::Error;
+
+    fn try_from(proxy_attr: StructProxyAttribute) -> darling::Result<Self> {
         let limit = proxy_attr.limit;
         let sources = if let Some(ids) = limit.nests {
             let nest_ids = ids.into_inner().into_iter().map(|id| id.value()).collect();
@@ -814,11 +2820,11 @@ impl From<StructProxyAttribute> for ExtractedStructAttribute {
             None
         };
 
-        Self {
-            attr: extract_passthrough_attr_meta(&proxy_attr.attr),
+        Ok(Self {
+            attr: extract_passthrough_attr_meta(&proxy_attr.attr)?,
             sources,
             classes: limit.class.unwrap_or_default().into_inner(),
-        }
+        })
     }
 }
 
@@ -832,20 +2838,17 @@ pub(crate) struct FieldProxyAttribute {
     pub limit: SpannedValue<FieldAttrRestriction>,
 }
 impl FieldProxyAttribute {
-    pub(crate) fn maybe_from_attribute(attr: &Attribute) -> Option<Self> {
+    pub(crate) fn maybe_from_attribute(attr: &Attribute) -> darling::Result<Option<Self>> {
         let forward_ident = format_ident!("{FORWARD_ATTR}");
 
         if attr.path().get_ident() == Some(&forward_ident) {
-             match Self::from_meta(&attr.meta) {
-                Ok(proxy) => Some(proxy),
-                Err(error) => abort!(error.span(), error),
-            }
+            Self::from_meta(&attr.meta).map(Some)
         } else {
-            None
+            Ok(None)
         }
     }
-    pub(crate) fn maybe_extract_from(attr: &Attribute) -> Option<ExtractedFieldAttribute> {
-        Self::maybe_from_attribute(attr).map(ExtractedFieldAttribute::from)
+    pub(crate) fn maybe_extract_from(attr: &Attribute) -> darling::Result<Option<ExtractedFieldAttribute>> {
+        Self::maybe_from_attribute(attr)?.map(ExtractedFieldAttribute::try_from).transpose()
     }
 }
 
@@ -870,12 +2873,82 @@ impl ExtractedFieldAttribute {
         }
     }
 }
-impl From<FieldProxyAttribute> for ExtractedFieldAttribute {
-    fn from(proxy_attr: FieldProxyAttribute) -> Self {
+impl TryFrom<FieldProxyAttribute> for ExtractedFieldAttribute {
+    type Error = darling::Error;
+
+    fn try_from(proxy_attr: FieldProxyAttribute) -> darling::Result<Self> {
         let nests = proxy_attr.limit.into_inner().nests.map(|ids| ids.into_iter().map(|id| id.value()).collect());
-        Self {
-            attr: extract_passthrough_attr_meta(&proxy_attr.attr),
+        Ok(Self {
+            attr: extract_passthrough_attr_meta(&proxy_attr.attr)?,
             nests,
+        })
+    }
+}
+
+// !- TransformOptions derive entrypoint
+
+/// Options for `#[derive(TransformOptions)]`, deriving nest gates from the fields of a transform's options struct
+#[derive(Debug, Clone, FromDeriveInput)]
+#[darling(attributes(shrinkwrap), supports(struct_named))]
+pub(crate) struct TransformOptionsOpts {
+    pub ident: Ident,
+    pub generics: syn::Generics,
+    pub data: Data<(), TransformOptionsFieldOpts>,
+
+    /// Skips the `DeprecatedNestGate` impl, e.g. to implement it manually
+    pub no_deprecated_gate: Flag,
+}
+impl TransformOptionsOpts {
+    /// Resolves the (nest ID, field name) of each gate, in field order
+    pub(crate) fn gates(&self) -> darling::Result<Vec<(String, Ident)>> {
+        let mut errors = darling::Error::accumulator();
+        let mut gates: Vec<(String, Ident)> = Vec::new();
+        let fields = self.data.as_ref().take_struct().map(|fields| fields.fields).unwrap_or_default();
+        for field in fields {
+            let Some(nest_id) = field.gate_nest_id(&mut errors) else {
+                continue;
+            };
+            let name = field.ident.clone().expect("Named structs only");
+            if let Some((_, existing)) = gates.iter().find(|(id, _)| *id == nest_id) {
+                errors.push(span_error(existing.span(), format!("Nest `{nest_id}` first gated here")));
+                errors.push(span_error(name.span(), format!("Nest `{nest_id}` is gated by multiple fields")));
+                continue;
+            }
+            gates.push((nest_id, name));
+        }
+        if gates.is_empty() {
+            errors.push(span_error(self.ident.span(), "No nest gates found, expected a `bool` field named `with_{nest_id}` or a field marked `#[shrinkwrap(nest = \"..\")]`"));
+        }
+        errors.finish_with(gates)
+    }
+}
+
+/// Options for the fields of `#[derive(TransformOptions)]`
+#[derive(Debug, Clone, FromField)]
+#[darling(attributes(shrinkwrap))]
+pub(crate) struct TransformOptionsFieldOpts {
+    pub ident: Option<Ident>,
+    pub ty: Type,
+
+    /// ID of the nest gated by the field, for fields not named
+    /// `with_{nest_id}`. The field must be a `bool`.
+    pub nest: Option<SpannedValue<String>>,
+
+    /// Excludes a `bool` field named `with_{..}` from the gates
+    pub skip: Flag,
+}
+impl TransformOptionsFieldOpts {
+    /// ID of the nest gated by the field, if any
+    fn gate_nest_id(&self, errors: &mut Accumulator) -> Option<String> {
+        if let Some(nest) = &self.nest {
+            if self.skip.is_present() {
+                errors.push(span_error(self.skip.span(), "`skip` cannot be used with `nest`"));
+                return None;
+            }
+            return Some(nest.as_str().to_string());
         }
+        let is_bool = matches!(&self.ty, Type::Path(type_path) if type_path.qself.is_none() && type_path.path.is_ident("bool"));
+        let nest_id = self.ident.as_ref()?.unraw().to_string().strip_prefix("with_")?.to_string();
+        (is_bool && !self.skip.is_present()).then_some(nest_id)
     }
 }
@@ -0,0 +1,104 @@
+use proc_macro::TokenStream;
+use quote::{ToTokens, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{Attribute, DeriveInput, Meta, MetaList, Path, Token, parenthesized, parse_macro_input, parse_quote};
+
+/// Options which may be provided multiple times, preset and own values are combined rather than overridden
+const REPEATABLE_OPTS: &[&str] = &["nest", "cfg_derive", "annotate", "computed"];
+
+// !- Attribute macro
+
+/// Forwards the item to the preset's `macro_rules` (declared via `shrinkwrap::define_preset!`)
+pub(crate) fn preset_attr_impl(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let preset = parse_macro_input!(attr as Path);
+    let item = proc_macro2::TokenStream::from(item);
+
+    quote!(#preset! { #item }).into()
+}
+
+// !- Preset application
+
+/// Input of `__apply_preset!`, in the form of `(preset options) item`
+struct ApplyPresetInput {
+    opts: proc_macro2::TokenStream,
+    item: DeriveInput,
+}
+impl Parse for ApplyPresetInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let opts;
+        parenthesized!(opts in input);
+        Ok(Self {
+            opts: opts.parse()?,
+            item: input.parse()?,
+        })
+    }
+}
+
+/// Appends the preset options to the item as a `shrinkwrap_preset` helper attribute
+pub(crate) fn apply_preset_impl(input: TokenStream) -> TokenStream {
+    let ApplyPresetInput { opts, mut item } = parse_macro_input!(input as ApplyPresetInput);
+    // appended after `#[derive(..)]`, helper attributes can't precede the derive registering them
+    item.attrs.push(parse_quote!(#[shrinkwrap_preset(#opts)]));
+
+    item.into_token_stream().into()
+}
+
+// !- Option merging
+
+/// Merges any `shrinkwrap_preset` options into the struct's own `shrinkwrap` options, `None` if no preset is applied.
+///
+/// Own options replace preset options of the same name, apart from nested option lists (e.g. `wrapper(..)`) which are
+/// merged recursively, and repeatable options (e.g. `nest`) which are combined.
+pub(crate) fn merge_preset_attrs(input: &DeriveInput) -> darling::Result<Option<DeriveInput>> {
+    let (presets, own): (Vec<&Attribute>, Vec<&Attribute>) = input.attrs.iter()
+        .filter(|attr| attr.path().is_ident("shrinkwrap_preset") || attr.path().is_ident("shrinkwrap"))
+        .partition(|attr| attr.path().is_ident("shrinkwrap_preset"));
+    if presets.is_empty() {
+        return Ok(None);
+    }
+
+    let mut errors = darling::Error::accumulator();
+    let mut parse_metas = |attrs: Vec<&Attribute>| -> Vec<Meta> {
+        attrs.into_iter()
+            .filter_map(|attr| errors.handle(attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated).map_err(darling::Error::from)))
+            .flatten()
+            .collect()
+    };
+    let preset_metas = parse_metas(presets);
+    let own_metas = parse_metas(own);
+    errors.finish()?;
+
+    let merged = merge_metas(preset_metas, own_metas);
+    let mut output = input.clone();
+    output.attrs.retain(|attr| !attr.path().is_ident("shrinkwrap_preset") && !attr.path().is_ident("shrinkwrap"));
+    output.attrs.push(parse_quote!(#[shrinkwrap(#( #merged ),*)]));
+
+    Ok(Some(output))
+}
+
+fn merge_metas(preset: Vec<Meta>, mut own: Vec<Meta>) -> Vec<Meta> {
+    let mut merged = Vec::new();
+    for preset_meta in preset {
+        let repeatable = REPEATABLE_OPTS.iter().any(|key| preset_meta.path().is_ident(key));
+        let own_index = own.iter().position(|own_meta| own_meta.path() == preset_meta.path());
+        match (own_index, repeatable) {
+            (None, _) | (_, true) => merged.push(preset_meta),
+            (Some(index), false) => {
+                if let (Meta::List(preset_list), Meta::List(own_list)) = (&preset_meta, &own[index])
+                    && let (Some(preset_nested), Some(own_nested)) = (nested_metas(preset_list), nested_metas(own_list))
+                {
+                    let nested = merge_metas(preset_nested, own_nested);
+                    own[index] = Meta::List(MetaList { tokens: quote!(#( #nested ),*), ..own_list.clone() });
+                }
+            },
+        }
+    }
+    merged.extend(own);
+    merged
+}
+
+/// Nested metas of an option list, `None` for lists of other values (e.g. `nests("a", "b")`)
+fn nested_metas(list: &MetaList) -> Option<Vec<Meta>> {
+    list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated).ok().map(|metas| metas.into_iter().collect())
+}
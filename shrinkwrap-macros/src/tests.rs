@@ -0,0 +1,1332 @@
+//! Snapshot tests of the generated output
+//!
+//! Each test expands a derive input and compares the formatted output against its snapshot in `tests/snapshots`.
+//! Missing snapshots fail the test. To write new snapshots, or after intentional codegen changes, run the tests with
+//! `SHRINKWRAP_UPDATE_SNAPSHOTS=1` to (over)write the snapshots, and review the diff.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::path::PathBuf;
+
+use crate::transform_options::derive_transform_options_tokens;
+use crate::util::{ExpandStage, expand_selects_struct};
+use crate::wrap::{derive_wrap_tokens, derive_wrap_trace};
+
+const UPDATE_ENV: &str = "SHRINKWRAP_UPDATE_SNAPSHOTS";
+
+type DeriveFn = fn(&syn::DeriveInput, &mut TokenStream) -> darling::Result<()>;
+
+/// Expands `input` as the `derive` would, errors are included as `compile_error!` invocations
+fn expand(input: TokenStream, derive: DeriveFn) -> String {
+    let input = syn::parse2(input).expect("Test input must be a valid derive input");
+    let mut out = TokenStream::new();
+    if let Err(errors) = derive(&input, &mut out) {
+        out.extend(errors.write_errors());
+    }
+    let file = syn::parse2(out).expect("Generated output must be a valid file");
+    prettyplease::unparse(&file)
+}
+
+/// Renders the `SHRINKWRAP_TRACE` output of `input`
+fn trace(input: TokenStream) -> String {
+    let input = syn::parse2(input).expect("Test input must be a valid derive input");
+    derive_wrap_trace(&input).expect("Test input must be a valid `Wrap` input")
+}
+
+/// Asserts the `Wrap` expansion of `input` matches the snapshot `tests/snapshots/{name}.expanded.rs`
+fn assert_snapshot(name: &str, input: TokenStream) {
+    assert_derive_snapshot(name, input, derive_wrap_tokens);
+}
+
+/// Asserts the expansion of `input` via `derive` matches the snapshot `tests/snapshots/{name}.expanded.rs`
+fn assert_derive_snapshot(name: &str, input: TokenStream, derive: DeriveFn) {
+    let actual = expand(input, derive);
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/snapshots").join(format!("{name}.expanded.rs"));
+
+    if std::env::var_os(UPDATE_ENV).is_some() {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, actual).unwrap();
+        return;
+    }
+    let expected = std::fs::read_to_string(&path)
+        .unwrap_or_else(|err| panic!("snapshot missing, run with {UPDATE_ENV}=1 ({}: {err})", path.display()));
+    assert!(
+        expected == actual,
+        "Expansion of `{name}` does not match {}, set `{UPDATE_ENV}=1` to update\n\n{actual}",
+        path.display(),
+    );
+}
+
+// !- Wrapper layout
+
+#[test]
+fn flatten() {
+    assert_snapshot("flatten", quote! {
+        #[shrinkwrap(transform = MyTransform)]
+        #[shrinkwrap(nest(id = "text", field_type = String))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"))]
+            pub balance: i64,
+        }
+    });
+}
+
+#[test]
+fn flatten_disabled() {
+    assert_snapshot("flatten_disabled", quote! {
+        #[shrinkwrap(transform = MyTransform, wrapper(flatten = false))]
+        #[shrinkwrap(nest(id = "text", field_type = String))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"))]
+            pub balance: i64,
+        }
+    });
+}
+
+#[test]
+fn deserialize_options() {
+    assert_snapshot("deserialize_options", quote! {
+        #[derive(Serialize, Deserialize)]
+        #[shrinkwrap(transform = MyTransform, wrapper(deserialize(deny_unknown_fields, default_extras)))]
+        #[shrinkwrap(nest(id = "text", field_type = String, optional))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"))]
+            pub balance: i64,
+        }
+    });
+}
+
+// !- Nests
+
+#[test]
+fn optional_nests() {
+    assert_snapshot("optional_nests", quote! {
+        #[shrinkwrap(transform = MyTransform, fallible(error = MyError))]
+        #[shrinkwrap(nest(id = "text", field_type = String, optional))]
+        #[shrinkwrap(nest(id = "value", field_type = f64))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"), nest(id = "value"))]
+            pub balance: i64,
+        }
+    });
+}
+
+#[test]
+fn required_nests() {
+    assert_snapshot("required_nests", quote! {
+        #[shrinkwrap(transform = MyTransform, all_optional)]
+        #[shrinkwrap(nest(id = "text", field_type = String, required))]
+        #[shrinkwrap(nest(id = "value", field_type = f64))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"), nest(id = "value"))]
+            pub balance: i64,
+        }
+    });
+}
+
+#[test]
+fn required_optional_conflict() {
+    assert_snapshot("required_optional_conflict", quote! {
+        #[shrinkwrap(transform = MyTransform, all_optional)]
+        #[shrinkwrap(nest(id = "text", field_type = String, optional, required))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"))]
+            pub balance: i64,
+        }
+    });
+}
+
+#[test]
+fn deep_nesting() {
+    assert_snapshot("deep_nesting", quote! {
+        #[shrinkwrap(transform = MyTransform, all_optional)]
+        #[shrinkwrap(nest(id = "value", field_type = f64))]
+        #[shrinkwrap(nest(id = "value_text", field_name = "text", field_type = String, chain_from = "value"))]
+        #[shrinkwrap(nest(id = "value_text_short", field_name = "short", field_type = String, chain_from = "value_text"))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "value"), nest(id = "value_text"), nest(id = "value_text_short"))]
+            pub balance: i64,
+        }
+    });
+}
+
+#[test]
+fn no_intermediate_wrapper() {
+    assert_snapshot("no_intermediate_wrapper", quote! {
+        #[shrinkwrap(transform = MyTransform, fallible(error = MyError))]
+        #[shrinkwrap(nest(id = "value", field_type = f64, optional, no_intermediate_wrapper))]
+        #[shrinkwrap(nest(id = "value_text", field_type = String, chain_from = "value", optional))]
+        #[shrinkwrap(nest(id = "value_cents", field_type = i64, chain_from = "value", optional))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "value"), nest(id = "value_text"), nest(id = "value_cents"))]
+            pub balance: i64,
+        }
+    });
+}
+
+#[test]
+fn no_intermediate_wrapper_lifted() {
+    assert_snapshot("no_intermediate_wrapper_lifted", quote! {
+        #[shrinkwrap(transform = MyTransform)]
+        #[shrinkwrap(nest(id = "value", field_type = f64, optional, no_intermediate_wrapper))]
+        #[shrinkwrap(nest(id = "value_text", field_type = String, chain_from = "value"))]
+        #[shrinkwrap(nest(id = "value_cents", field_type = i64, chain_from = "value", optional))]
+        #[shrinkwrap(nest(id = "value_label", field_type = String, chain_from = "value_text"))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "value"), nest(id = "value_text"), nest(id = "value_cents"), nest(id = "value_label"))]
+            pub balance: i64,
+        }
+    });
+}
+
+#[test]
+fn extra_field_order() {
+    assert_snapshot("extra_field_order", quote! {
+        #[shrinkwrap(transform = MyTransform)]
+        #[shrinkwrap(nest(id = "value", field_type = f64, no_intermediate_wrapper))]
+        #[shrinkwrap(nest(id = "cents", field_type = i64))]
+        #[shrinkwrap(nest(id = "value_text", field_type = String, chain_from = "value"))]
+        #[shrinkwrap(nest(id = "raw", field_type = i64, order = -1))]
+        #[shrinkwrap(nest(id = "debug", field_type = String, chain_from = "cents", order = 1))]
+        #[shrinkwrap(nest(id = "label", field_type = String, chain_from = "cents"))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "value"), nest(id = "cents"), nest(id = "value_text"), nest(id = "raw"), nest(id = "debug"), nest(id = "label"))]
+            pub balance: i64,
+        }
+    });
+}
+
+#[test]
+fn deprecated_nests() {
+    assert_snapshot("deprecated_nests", quote! {
+        #[serde(rename_all = "camelCase")]
+        #[shrinkwrap(transform = MyTransform, schema, naming(inherit_rename_all))]
+        #[shrinkwrap(nest(id = "text", field_type = String, optional))]
+        #[shrinkwrap(nest(id = "text_v2", field_type = String, deprecates = "text", gate_deprecated))]
+        #[shrinkwrap(nest(id = "value", field_type = f64))]
+        #[shrinkwrap(nest(id = "value_text", field_name = "text", field_type = String, chain_from = "value"))]
+        #[shrinkwrap(nest(id = "value_text_v2", field_name = "text_v2", field_type = String, chain_from = "value", deprecates = "value_text"))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"), nest(id = "text_v2"), nest(id = "value"), nest(id = "value_text"), nest(id = "value_text_v2"))]
+            pub account_balance: i64,
+        }
+    });
+}
+
+#[test]
+fn nest_field_docs() {
+    assert_snapshot("nest_field_docs", quote! {
+        #[shrinkwrap(transform = MyTransform)]
+        #[shrinkwrap(nest(id = "text", field_type = String))]
+        #[shrinkwrap(nest(id = "cents", field_type = i64))]
+        pub struct MyData {
+            /// Origin docs are not carried over
+            #[shrinkwrap(field_doc = " Balance, formatted for display")]
+            #[shrinkwrap(nest(id = "text"), nest(id = "cents", doc = " Balance, in cents"))]
+            pub balance: f64,
+            #[shrinkwrap(nest(id = "text"))]
+            pub owner: String,
+        }
+    });
+}
+
+#[test]
+fn validate_nests() {
+    assert_snapshot("validate_nests", quote! {
+        #[shrinkwrap(transform = MyTransform)]
+        #[shrinkwrap(nest(id = "text", field_type = String, validate))]
+        #[shrinkwrap(nest(id = "value", field_type = f64))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text", validate(length(min = 1, max = 32), custom = crate::is_currency)), nest(id = "value"))]
+            pub balance: i64,
+            #[shrinkwrap(nest(id = "text", ty = u8, validate(range(max = 100))))]
+            pub percent: i64,
+        }
+    });
+}
+
+#[test]
+fn remote() {
+    assert_snapshot("remote", quote! {
+        #[shrinkwrap(transform = MyTransform, fallible(error = MyError), remote = "vendor_sdk::User")]
+        #[shrinkwrap(nest(id = "text", field_type = String))]
+        pub struct UserDef {
+            pub id: u64,
+            #[shrinkwrap(nest(id = "text"))]
+            pub balance: i64,
+        }
+    });
+}
+
+#[test]
+fn json_helpers() {
+    assert_snapshot("json_helpers", quote! {
+        #[serde(rename_all = "camelCase")]
+        #[shrinkwrap(transform = MyTransform, json)]
+        #[shrinkwrap(nest(id = "usd_value", field_type = f64))]
+        #[shrinkwrap(nest(id = "usd_text", field_name = "text", field_type = String, chain_from = "usd_value"))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "usd_value"), nest(id = "usd_text"))]
+            pub account_balance: i64,
+        }
+    });
+}
+
+#[test]
+fn auto_parent() {
+    assert_snapshot("auto_parent", quote! {
+        #[shrinkwrap(transform = MyTransform)]
+        #[shrinkwrap(nest(id = "usd_value", auto_parent, optional))]
+        #[shrinkwrap(nest(id = "usd_text", field_name = "text", field_type = String, chain_from = "usd_value", optional))]
+        #[shrinkwrap(nest(id = "usd_cents", field_name = "cents", field_type = i64, chain_from = "usd_value", optional))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "usd_text"), nest(id = "usd_cents"))]
+            pub balance: f64,
+        }
+    });
+}
+
+#[test]
+fn tagged_array_extra() {
+    assert_snapshot("tagged_array_extra", quote! {
+        #[shrinkwrap(transform = MyTransform, wrapper(enrich))]
+        #[shrinkwrap(extra(layout = "tagged_array"))]
+        #[shrinkwrap(nest(id = "text", field_type = String))]
+        #[shrinkwrap(nest(id = "value", field_type = f64, optional))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"), nest(id = "value"))]
+            pub balance: i64,
+        }
+    });
+}
+
+#[test]
+fn map_elements() {
+    assert_snapshot("map_elements", quote! {
+        #[shrinkwrap(transform = MyTransform, fallible(error = MyError))]
+        #[shrinkwrap(nest(id = "text", derive_to_nest(value = TextValue)))]
+        #[shrinkwrap(nest(id = "value", field_type = f64))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"))]
+            pub balance: i64,
+
+            #[shrinkwrap(nest(id = "text", map_elements), nest(id = "value", map_elements))]
+            pub transactions: Vec<Transaction>,
+        }
+    });
+}
+
+#[test]
+fn none_as() {
+    assert_snapshot("none_as", quote! {
+        #[shrinkwrap(transform = MyTransform, fallible(error = MyError))]
+        #[shrinkwrap(nest(id = "text", derive_to_nest(value = TextValue)))]
+        #[shrinkwrap(nest(id = "value", field_type = f64))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text", none_as = "skip"), nest(id = "value", none_as = "null"))]
+            pub shipped_at: Option<Timestamp>,
+
+            #[shrinkwrap(nest(id = "text", none_as = "default"), nest(id = "value", none_as = "default"))]
+            pub delivered_at: Option<Timestamp>,
+        }
+    });
+}
+
+#[test]
+fn map_layout() {
+    assert_snapshot("map_layout", quote! {
+        #[shrinkwrap(transform = MyTransform)]
+        #[shrinkwrap(nest(id = "prices", layout = "map", key_type = String, field_type = f64, optional))]
+        #[shrinkwrap(nest(id = "text", field_type = String))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"))]
+            pub balance: i64,
+        }
+    });
+}
+
+#[test]
+fn generic_field_types() {
+    assert_snapshot("generic_field_types", quote! {
+        #[shrinkwrap(transform = MyTransform)]
+        #[shrinkwrap(nest(id = "labels", field_type = std::collections::BTreeMap<String, String>, skip_empty, optional))]
+        #[shrinkwrap(nest(id = "assoc", field_type = <Vec<u8> as IntoIterator>::Item))]
+        #[shrinkwrap(nest(id = "prices", layout = "map", key_type = Box<str>, field_type = Vec::<f64>))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "labels", ty = HashMap<String, Vec<String>>), nest(id = "assoc"))]
+            pub balance: i64,
+            #[shrinkwrap(nest(id = "labels", none_as = "null"), nest(id = "assoc"))]
+            pub owner: Option<String>,
+        }
+    });
+}
+
+#[test]
+fn promote() {
+    assert_snapshot("promote", quote! {
+        #[shrinkwrap(transform = MyTransform)]
+        #[shrinkwrap(wrapper(computed(field = "total", type = i64, with = "crate::total")))]
+        #[shrinkwrap(nest(id = "legacy", field_type = String, promote, optional))]
+        #[shrinkwrap(nest(id = "text", field_type = String))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"), nest(id = "legacy"))]
+            pub balance_usd: i64,
+        }
+    });
+}
+
+#[test]
+fn all_fields() {
+    assert_snapshot("all_fields", quote! {
+        #[shrinkwrap(transform = MyTransform)]
+        #[shrinkwrap(nest(id = "text", field_type = String, all_fields, exclude("id", "internal")))]
+        #[shrinkwrap(nest(id = "value", field_type = f64))]
+        pub struct MyData {
+            pub id: i64,
+            #[shrinkwrap(nest(id = "value"))]
+            pub balance: i64,
+            #[shrinkwrap(nest(id = "text", ty = "Option<String>"))]
+            pub label: Option<i64>,
+            pub internal: bool,
+            pub created_at: i64,
+        }
+    });
+}
+
+#[test]
+fn nest_encode() {
+    assert_snapshot("nest_encode", quote! {
+        #[shrinkwrap(transform = MyTransform, schema, json, wrapper(deserialize))]
+        #[shrinkwrap(nest(id = "text", field_type = String, encode = "json_string"))]
+        #[shrinkwrap(nest(id = "value", field_type = f64, optional, encode = "json_string"))]
+        #[shrinkwrap(nest(id = "value_text", field_type = String, chain_from = "value", encode = "json_string"))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"), nest(id = "value"), nest(id = "value_text"))]
+            pub balance: i64,
+        }
+    });
+}
+
+#[test]
+fn nest_alias() {
+    assert_snapshot("nest_alias", quote! {
+        #[shrinkwrap(transform = MyTransform, schema, wrapper(deserialize))]
+        #[shrinkwrap(nest(id = "text", field_type = String, alias = "txt"))]
+        #[shrinkwrap(nest(id = "value", field_type = f64, optional, alias = "val", emit_alias))]
+        #[shrinkwrap(nest(id = "value_text", field_type = String, chain_from = "value", alias = "valueText", emit_alias))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"), nest(id = "value"), nest(id = "value_text"))]
+            pub balance: i64,
+        }
+    });
+}
+
+#[test]
+fn schema_skip() {
+    assert_snapshot("schema_skip", quote! {
+        #[shrinkwrap(transform = MyTransform, schema, wrapper(deserialize))]
+        #[shrinkwrap(nest(id = "text", field_type = String))]
+        #[shrinkwrap(nest(id = "value", field_type = f64, optional, schema_skip, annotate(key = "x-internal", value = "true")))]
+        #[shrinkwrap(nest(id = "value_text", field_type = String, chain_from = "value"))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"), nest(id = "value"), nest(id = "value_text"))]
+            pub balance: i64,
+        }
+    });
+}
+
+#[test]
+fn row_view() {
+    assert_snapshot("row_view", quote! {
+        #[shrinkwrap(transform = MyTransform, row_view)]
+        #[shrinkwrap_attr(attr(serde(rename_all = "camelCase")), limit(nests("text"), class(nest)))]
+        #[shrinkwrap(nest(id = "text", field_type = String))]
+        #[shrinkwrap(nest(id = "value", field_type = f64, optional))]
+        #[shrinkwrap(nest(id = "value_text", field_type = String, chain_from = "value"))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"), nest(id = "value"), nest(id = "value_text"))]
+            pub total_balance: i64,
+        }
+    });
+}
+
+#[test]
+fn row_view_serialize() {
+    assert_snapshot("row_view_serialize", quote! {
+        #[shrinkwrap(transform = MyTransform, row_view(render = "serialize"))]
+        #[shrinkwrap(nest(id = "value", field_type = "Option<f64>"))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "value"))]
+            pub balance: i64,
+            #[shrinkwrap(nest(id = "value"))]
+            pub limit: i64,
+        }
+    });
+}
+
+#[test]
+fn schema_feature() {
+    assert_snapshot("schema_feature", quote! {
+        #[shrinkwrap(transform = MyTransform, schema(feature = "openapi"), schema_naming(prefix = "v1."))]
+        #[shrinkwrap(wrapper(sort_keys))]
+        #[shrinkwrap_attr(attr(serde(rename_all = "camelCase")), limit(nests("text"), class(nest)))]
+        #[shrinkwrap(nest(id = "text", field_type = String, annotate(key = "x-internal", value = "true")))]
+        #[shrinkwrap(nest(id = "value", field_type = f64, optional, schema_skip))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"), nest(id = "value"))]
+            pub total_balance: i64,
+        }
+    });
+}
+
+#[test]
+fn cfg_fields() {
+    assert_snapshot("cfg_fields", quote! {
+        #[shrinkwrap(transform = MyTransform)]
+        #[shrinkwrap(nest(id = "text", derive_to_nest(value = TextValue)))]
+        #[shrinkwrap(nest(id = "copy", identity))]
+        #[shrinkwrap(nest(id = "value", field_type = f64, sources))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"), nest(id = "copy"), nest(id = "value"))]
+            pub balance: i64,
+
+            #[cfg(feature = "audit")]
+            #[shrinkwrap(nest(id = "text"), nest(id = "copy"), nest(id = "value"))]
+            pub audited_at: i64,
+        }
+    });
+}
+
+#[test]
+fn audit() {
+    assert_snapshot("audit", quote! {
+        #[shrinkwrap(transform = MyTransform, audit)]
+        #[shrinkwrap(nest(id = "text", field_type = String))]
+        #[shrinkwrap(nest(id = "bio", field_type = String, fields("profile.bio")))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"))]
+            pub balance: i64,
+            pub profile: Profile,
+            pub currency: String,
+            #[shrinkwrap(unmapped)]
+            pub id: u64,
+        }
+    });
+}
+
+#[test]
+fn audit_errors() {
+    assert_snapshot("audit_errors", quote! {
+        #[shrinkwrap(transform = MyTransform)]
+        #[shrinkwrap(nest(id = "text", field_type = String))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"), unmapped)]
+            pub balance: i64,
+        }
+    });
+}
+
+#[test]
+fn any_transform() {
+    assert_snapshot("any_transform", quote! {
+        #[shrinkwrap(transform = Fast, transforms(Fast, precise::Precise))]
+        #[shrinkwrap(nest(id = "text", field_type = String))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"))]
+            pub balance: i64,
+        }
+    });
+}
+
+#[test]
+fn any_transform_fallible() {
+    assert_snapshot("any_transform_fallible", quote! {
+        #[shrinkwrap(transform = Fast, transforms(Fast, Precise), fallible(error = MyError))]
+        #[shrinkwrap(nest(id = "text", field_type = String))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"))]
+            pub balance: i64,
+        }
+    });
+}
+
+#[test]
+fn any_transform_errors() {
+    assert_snapshot("any_transform_errors", quote! {
+        #[shrinkwrap(transform = Fast, transforms(Fast, other::Fast))]
+        #[shrinkwrap(nest(id = "text", field_type = String))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"))]
+            pub balance: i64,
+        }
+    });
+}
+
+#[test]
+fn transform_alias() {
+    assert_snapshot("transform_alias", quote! {
+        #[shrinkwrap(transform = MyTransform, transform_alias, fallible(error = MyError))]
+        #[shrinkwrap(nest(id = "text", field_type = String, optional))]
+        #[shrinkwrap(nest(id = "text_v2", field_type = String, deprecates = "text", gate_deprecated))]
+        #[shrinkwrap(nest(id = "value", field_type = f64))]
+        #[shrinkwrap(nest(id = "value_text", field_type = String, chain_from = "value"))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"), nest(id = "text_v2"), nest(id = "value"), nest(id = "value_text"))]
+            pub balance: i64,
+        }
+    });
+}
+
+#[test]
+fn transform_alias_errors() {
+    assert_snapshot("transform_alias_errors", quote! {
+        #[shrinkwrap(transform = "MyTransform<T>", transform_generic_params(T: Clone), transform_alias, concrete_impls)]
+        #[shrinkwrap(nest(id = "text", field_type = String))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"))]
+            pub balance: i64,
+        }
+    });
+}
+
+#[test]
+fn assert_threadsafe() {
+    assert_snapshot("assert_threadsafe", quote! {
+        #[shrinkwrap(transform = MyTransform, assert_threadsafe)]
+        #[shrinkwrap(nest(id = "text", field_type = String))]
+        #[shrinkwrap(nest(id = "value", field_type = f64, optional))]
+        #[shrinkwrap(nest(id = "value_text", field_type = String, chain_from = "value"))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"), nest(id = "value"), nest(id = "value_text"))]
+            pub balance: i64,
+        }
+    });
+}
+
+#[test]
+fn docs_alias_only() {
+    assert_snapshot("docs_alias_only", quote! {
+        #[shrinkwrap(transform = MyTransform, docs(alias_only))]
+        #[shrinkwrap(nest(id = "text", field_type = String, struct_doc = "Text variants"))]
+        #[shrinkwrap(nest(id = "value", field_type = f64, optional))]
+        #[shrinkwrap(nest(id = "value_text", field_type = String, chain_from = "value"))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"), nest(id = "value"), nest(id = "value_text"))]
+            pub balance: i64,
+        }
+    });
+}
+
+#[test]
+fn concrete_impls() {
+    assert_snapshot("concrete_impls", quote! {
+        #[shrinkwrap(transform = MyTransform, concrete_impls, wrapper(enrich))]
+        #[shrinkwrap(nest(id = "text", field_type = String, optional))]
+        #[shrinkwrap(nest(id = "value", field_type = f64))]
+        #[shrinkwrap(nest(id = "value_text", field_type = String, chain_from = "value", field_name = "text"))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"), nest(id = "value"), nest(id = "value_text"))]
+            pub balance: i64,
+        }
+    });
+}
+
+#[test]
+fn ffi() {
+    assert_snapshot("ffi", quote! {
+        #[repr(C)]
+        #[shrinkwrap(transform = MyTransform, ffi)]
+        #[shrinkwrap(nest(id = "text", field_type = String))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"))]
+            pub balance: i64,
+        }
+    });
+}
+
+#[test]
+fn wasm_bindgen() {
+    assert_snapshot("wasm_bindgen", quote! {
+        #[shrinkwrap(transform = MyTransform, wasm_bindgen)]
+        #[shrinkwrap(nest(id = "value", field_type = f64, optional))]
+        #[shrinkwrap(nest(id = "value_text", field_type = String, chain_from = "value"))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "value"), nest(id = "value_text"))]
+            pub balance: i64,
+        }
+    });
+}
+
+#[test]
+fn wasm_bindgen_errors() {
+    assert_snapshot("wasm_bindgen_errors", quote! {
+        #[shrinkwrap(transform = MyTransform, wasm_bindgen)]
+        #[shrinkwrap(extra(exclusive))]
+        #[shrinkwrap(nest(id = "text", field_type = String, optional))]
+        #[shrinkwrap(nest(id = "labels", layout = "map", key_type = String, field_type = String, optional))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"))]
+            pub balance: i64,
+        }
+    });
+}
+
+#[test]
+fn debug_wrapper() {
+    assert_snapshot("debug_wrapper", quote! {
+        #[shrinkwrap(transform = MyTransform, fallible(error = MyError), debug_wrapper)]
+        #[shrinkwrap(nest(id = "text", field_type = String))]
+        #[shrinkwrap(nest(id = "cents", field_type = i64, optional))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"), nest(id = "cents"))]
+            pub balance: i64,
+        }
+    });
+}
+
+#[test]
+fn debug_wrapper_errors() {
+    assert_snapshot("debug_wrapper_errors", quote! {
+        #[shrinkwrap(transform = MyTransform, debug_wrapper)]
+        #[shrinkwrap(extra(exclusive))]
+        #[shrinkwrap(nest(id = "text", field_type = String, optional))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"))]
+            pub balance: i64,
+        }
+    });
+}
+
+#[test]
+fn grpc() {
+    assert_snapshot("grpc", quote! {
+        #[shrinkwrap(transform = MyTransform, grpc)]
+        #[shrinkwrap(nest(id = "text", field_type = String))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"))]
+            pub balance: i64,
+        }
+    });
+}
+
+#[test]
+fn grpc_errors() {
+    assert_snapshot("grpc_errors", quote! {
+        #[shrinkwrap(transform = MyTransform, grpc)]
+        #[shrinkwrap(extra(layout = "tagged_array"))]
+        #[shrinkwrap(nest(id = "text", field_type = String, optional))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"))]
+            pub balance: i64,
+        }
+    });
+}
+
+#[test]
+fn field_selectors() {
+    assert_snapshot("field_selectors", quote! {
+        #[shrinkwrap(transform = MyTransform)]
+        #[shrinkwrap(nest(id = "text", field_type = String, fields("profile.bio", "profile.avatar_url as avatar")))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"))]
+            pub balance: i64,
+            pub profile: Profile,
+        }
+    });
+}
+
+#[test]
+fn skip_empty() {
+    assert_snapshot("skip_empty", quote! {
+        #[shrinkwrap(transform = MyTransform)]
+        #[shrinkwrap(nest(id = "tags", field_type = "Vec<String>", skip_empty))]
+        #[shrinkwrap(nest(id = "text", field_type = String))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "tags"), nest(id = "text", skip_empty))]
+            pub balance: i64,
+            #[shrinkwrap(nest(id = "tags", ty = "Option<Vec<String>>"), nest(id = "text", skip_empty = "crate::is_blank"))]
+            pub hostname: String,
+        }
+    });
+}
+
+// !- Wrapper methods
+
+#[test]
+fn delegate_accessors() {
+    assert_snapshot("delegate_accessors", quote! {
+        #[shrinkwrap(transform = MyTransform, wrapper(delegate_accessors))]
+        #[shrinkwrap(nest(id = "text", field_type = String))]
+        #[shrinkwrap(nest(id = "value", field_type = f64, optional))]
+        #[shrinkwrap(nest(id = "value_text", field_name = "text", field_type = String, chain_from = "value"))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"), nest(id = "value"), nest(id = "value_text"))]
+            pub balance: i64,
+        }
+    });
+}
+
+#[test]
+fn borrow_data() {
+    assert_snapshot("borrow_data", quote! {
+        #[shrinkwrap(transform = MyTransform, wrapper(borrow))]
+        #[shrinkwrap(nest(id = "text", field_type = String))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"))]
+            pub balance: i64,
+        }
+    });
+}
+
+#[test]
+fn computed_fields() {
+    assert_snapshot("computed_fields", quote! {
+        #[shrinkwrap(transform = MyTransform, wrapper(enrich, computed(field = "nest_count", type = usize, with = "crate::count_nests")))]
+        #[shrinkwrap(nest(id = "text", field_type = String, optional))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"))]
+            pub balance: i64,
+        }
+    });
+}
+
+#[test]
+fn echo_options() {
+    assert_snapshot("echo_options", quote! {
+        #[shrinkwrap(transform = MyTransform, wrapper(enrich, echo_options))]
+        #[shrinkwrap(nest(id = "text", field_type = String, optional))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"))]
+            pub balance: i64,
+        }
+    });
+}
+
+#[test]
+fn echo_options_errors() {
+    assert_snapshot("echo_options_errors", quote! {
+        #[shrinkwrap(transform = MyTransform, wrapper(sort_keys, echo_options(field = data)))]
+        #[shrinkwrap(nest(id = "text", field_type = String))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"))]
+            pub balance: i64,
+        }
+    });
+}
+
+#[test]
+fn integrity() {
+    assert_snapshot("integrity", quote! {
+        #[shrinkwrap(transform = MyTransform, wrapper(flatten = false, integrity(algo = "hmac-sha256", key = "crate::signing_key")))]
+        #[shrinkwrap(nest(id = "text", field_type = String))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"))]
+            pub balance: i64,
+        }
+    });
+}
+
+// !- Attributes
+
+#[test]
+fn derive_all_merged() {
+    assert_snapshot("derive_all_merged", quote! {
+        #[shrinkwrap(transform = MyTransform, derive_all(PartialEq, Clone, utoipa::ToSchema))]
+        #[shrinkwrap(wrapper(derive(PartialEq, std::cmp::Eq)), extra(derive(utoipa::ToSchema, Eq)))]
+        #[shrinkwrap(nest(id = "text", field_type = String, derive(std::cmp::PartialEq, Hash)))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"))]
+            pub balance: i64,
+        }
+    });
+}
+
+#[test]
+fn passthrough_attrs() {
+    assert_snapshot("passthrough_attrs", quote! {
+        /// Docs on origin `MyData`
+        #[shrinkwrap(transform = MyTransform, derive_all(PartialEq))]
+        #[shrinkwrap(nest(id = "text", field_type = String))]
+        #[shrinkwrap(nest(id = "value", field_type = f64))]
+        #[shrinkwrap_attr(attr(serde(rename_all = "camelCase")), limit(nests("value"), class(nest)))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"), nest(id = "value"))]
+            #[shrinkwrap_attr(attr(doc = "Formatted balance"), limit(nests("text")))]
+            pub account_balance: i64,
+        }
+    });
+}
+
+#[test]
+fn cfg_derive() {
+    assert_snapshot("cfg_derive", quote! {
+        #[shrinkwrap(transform = MyTransform)]
+        #[shrinkwrap(nest(id = "text", field_type = String, cfg_derive(feature = "openapi", paths(utoipa::ToSchema))))]
+        #[shrinkwrap(nest(id = "value", field_type = f64))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"), nest(id = "value"))]
+            pub balance: i64,
+        }
+    });
+}
+
+#[test]
+fn testing_derives() {
+    assert_snapshot("testing_derives", quote! {
+        #[shrinkwrap(transform = MyTransform, testing(arbitrary, proptest))]
+        #[shrinkwrap(nest(id = "text", field_type = String))]
+        #[shrinkwrap(nest(id = "value", field_type = f64, optional))]
+        #[shrinkwrap(nest(id = "value_text", field_type = String, chain_from = "value"))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"), nest(id = "value"), nest(id = "value_text"))]
+            pub balance: i64,
+        }
+    });
+}
+
+#[test]
+fn inherit_rename_all() {
+    assert_snapshot("inherit_rename_all", quote! {
+        #[serde(rename_all = "camelCase")]
+        #[shrinkwrap(transform = MyTransform, all_optional, naming(inherit_rename_all))]
+        #[shrinkwrap(extra(exclusive(tag = "kind")))]
+        #[shrinkwrap(nest(id = "usd_text", field_type = String))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "usd_text"))]
+            pub account_balance: i64,
+        }
+    });
+}
+
+#[test]
+fn schema_naming() {
+    assert_snapshot("schema_naming", quote! {
+        #[shrinkwrap(transform = MyTransform, schema, schema_naming(prefix = "billing.", suffix = "V1"))]
+        #[shrinkwrap(nest(id = "text", field_type = String))]
+        #[shrinkwrap(nest(id = "masked", field_type = String, chain_from = "text"))]
+        #[shrinkwrap_attr(attr(schemars(rename = "billing.MaskedText")), limit(nests("masked"), class(nest)))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"), nest(id = "masked"))]
+            pub balance: i64,
+        }
+    });
+}
+
+#[test]
+fn no_debug() {
+    assert_snapshot("no_debug", quote! {
+        #[shrinkwrap(transform = MyTransform, all_optional, no_debug)]
+        #[shrinkwrap(extra(exclusive))]
+        #[shrinkwrap(nest(id = "text", field_type = String))]
+        #[shrinkwrap(nest(id = "masked", field_type = String, chain_from = "text"))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"), nest(id = "masked"))]
+            pub email: String,
+        }
+    });
+}
+
+#[test]
+fn preset() {
+    assert_snapshot("preset", quote! {
+        #[shrinkwrap(wrapper(struct_suffix = Response), extra(struct_doc = "Own docs"))]
+        #[shrinkwrap(nest(id = "text", field_type = String))]
+        #[shrinkwrap_preset(transform = MyTransform, wrapper(struct_suffix = Envelope), extra(struct_suffix = Meta))]
+        #[shrinkwrap_preset(nest(id = "value", field_type = f64))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"), nest(id = "value"))]
+            pub balance: i64,
+        }
+    });
+}
+
+#[test]
+fn nest_here() {
+    assert_snapshot("nest_here", quote! {
+        #[shrinkwrap(transform = MyTransform)]
+        #[shrinkwrap(nest(id = "value", field_type = f64))]
+        pub struct MyData {
+            #[shrinkwrap(nest_here(id = "text", field_type = String), nest_here(id = "value"))]
+            pub balance: i64,
+            #[shrinkwrap(nest_here(id = "text", field_type = String))]
+            pub sessions: i64,
+        }
+    });
+}
+
+#[test]
+fn nest_here_conflict() {
+    assert_snapshot("nest_here_conflict", quote! {
+        #[shrinkwrap(transform = MyTransform)]
+        pub struct MyData {
+            #[shrinkwrap(nest_here(id = "text", field_type = String))]
+            pub balance: i64,
+            #[shrinkwrap(nest_here(id = "text", field_type = f64))]
+            pub sessions: i64,
+            #[shrinkwrap(nest_here(id = "value"))]
+            pub uptime_sec: i64,
+        }
+    });
+}
+
+#[test]
+fn errors() {
+    assert_snapshot("errors", quote! {
+        #[shrinkwrap(transform = MyTransform)]
+        #[shrinkwrap(nest(id = "text"))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "missing"))]
+            pub balance: i64,
+        }
+    });
+}
+
+// !- Previously panicking inputs
+
+#[test]
+fn schema_naming_errors() {
+    assert_snapshot("schema_naming_errors", quote! {
+        #[shrinkwrap(transform = MyTransform, schema_naming(prefix = "v1."))]
+        #[shrinkwrap(nest(id = "text", field_type = String))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"))]
+            pub balance: i64,
+        }
+    });
+}
+
+#[test]
+fn ffi_errors() {
+    assert_snapshot("ffi_errors", quote! {
+        #[repr(align(8))]
+        #[shrinkwrap(transform = MyTransform, ffi)]
+        #[shrinkwrap(wrapper(computed(field = "count", type = usize, with = "count_nests")))]
+        #[shrinkwrap(nest(id = "text", field_type = String))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"))]
+            pub balance: i64,
+        }
+    });
+}
+
+#[test]
+fn no_intermediate_wrapper_errors() {
+    assert_snapshot("no_intermediate_wrapper_errors", quote! {
+        #[shrinkwrap(transform = MyTransform)]
+        #[shrinkwrap(nest(id = "value", field_type = f64, optional, no_intermediate_wrapper))]
+        #[shrinkwrap(nest(id = "text", field_type = String, chain_from = "value"))]
+        #[shrinkwrap(nest(id = "value_leaf", field_name = "leaf", field_type = String, chain_from = "value", optional))]
+        #[shrinkwrap(nest(id = "leaf", field_type = String, no_intermediate_wrapper))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "value"), nest(id = "text"), nest(id = "value_leaf"), nest(id = "leaf"))]
+            pub balance: i64,
+        }
+    });
+}
+
+#[test]
+fn deprecated_nest_errors() {
+    assert_snapshot("deprecated_nest_errors", quote! {
+        #[shrinkwrap(transform = MyTransform)]
+        #[shrinkwrap(extra(exclusive))]
+        #[shrinkwrap(nest(id = "text", field_type = String, optional))]
+        #[shrinkwrap(nest(id = "text_v2", field_type = String, optional, deprecates = "text"))]
+        #[shrinkwrap(nest(id = "value", field_type = f64, optional))]
+        #[shrinkwrap(nest(id = "value_text", field_type = String, chain_from = "value"))]
+        #[shrinkwrap(nest(id = "value_text_v2", field_type = String, chain_from = "value", deprecates = "value_text", gate_deprecated))]
+        #[shrinkwrap(nest(id = "cents", field_type = i64, optional, deprecates = "cents", gate_deprecated))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"), nest(id = "text_v2"), nest(id = "value"), nest(id = "value_text"), nest(id = "value_text_v2"), nest(id = "cents"))]
+            pub balance: i64,
+        }
+    });
+}
+
+#[test]
+fn deprecated_nest_hierarchy_errors() {
+    assert_snapshot("deprecated_nest_hierarchy_errors", quote! {
+        #[shrinkwrap(transform = MyTransform)]
+        #[shrinkwrap(nest(id = "text", field_type = String))]
+        #[shrinkwrap(nest(id = "text_v2", field_type = String, deprecates = "text"))]
+        #[shrinkwrap(nest(id = "text_v3", field_type = String, deprecates = "text"))]
+        #[shrinkwrap(nest(id = "value", field_type = f64))]
+        #[shrinkwrap(nest(id = "value_text", field_type = String, chain_from = "value", deprecates = "text"))]
+        #[shrinkwrap(nest(id = "cents", field_type = i64, deprecates = "missing"))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"), nest(id = "text_v2"), nest(id = "text_v3"), nest(id = "value"), nest(id = "value_text"), nest(id = "cents"))]
+            pub balance: i64,
+        }
+    });
+}
+
+#[test]
+fn validate_nest_errors() {
+    assert_snapshot("validate_nest_errors", quote! {
+        #[shrinkwrap(transform = MyTransform)]
+        #[shrinkwrap(nest(id = "text", field_type = String, validate))]
+        #[shrinkwrap(nest(id = "value", field_type = f64))]
+        #[shrinkwrap(nest(id = "prices", layout = "map", key_type = String, field_type = f64, validate))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text", validate()), nest(id = "value", validate(range(min = 0.0))))]
+            pub balance: i64,
+            #[shrinkwrap(nest(id = "text", validate(length())))]
+            pub owner: String,
+        }
+    });
+}
+
+#[test]
+fn validate_serde_attrs() {
+    assert_snapshot("validate_serde_attrs", quote! {
+        #[shrinkwrap(transform = MyTransform, schema, validate_serde)]
+        #[shrinkwrap(nest(id = "text", field_type = String))]
+        #[shrinkwrap_attr(attr(serde(rename_all = "camelCase", deny_unknown_fields)), limit(class(nest)))]
+        #[shrinkwrap_attr(attr(schemars(title = "Data", description = "Data with nests")), limit(origin))]
+        #[shrinkwrap_attr(attr(serde(rename = "meta")), limit(class(extra_field)))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"))]
+            #[shrinkwrap_attr(attr(serde(rename(serialize = "bal"), alias = "b")))]
+            pub balance: i64,
+        }
+    });
+}
+
+#[test]
+fn validate_serde_attr_errors() {
+    assert_snapshot("validate_serde_attr_errors", quote! {
+        #[shrinkwrap(transform = MyTransform, validate_serde)]
+        #[shrinkwrap(nest(id = "text", field_type = String))]
+        #[shrinkwrap_attr(attr(seder(rename_all = "camelCase")), limit(class(nest)))]
+        #[shrinkwrap_attr(attr(serde(rename_all = "camel", tag = 1, untagged = true)), limit(class(wrapper)))]
+        #[shrinkwrap_attr(attr(schemars(titel = "Data", rename)), limit(origin))]
+        #[shrinkwrap_attr(attr(serde(skip_serializing_if = "not a path")), limit(class(extra_field)))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"))]
+            #[shrinkwrap_attr(attr(serde(renam = "bal", with, flatten(inner))))]
+            #[shrinkwrap_attr(attr(serde(rename(serialize = "bal", other = "b"))))]
+            pub balance: i64,
+        }
+    });
+}
+
+#[test]
+fn all_fields_errors() {
+    assert_snapshot("all_fields_errors", quote! {
+        #[shrinkwrap(transform = MyTransform)]
+        #[shrinkwrap(nest(id = "text", field_type = String, all_fields, exclude("id", "missing")))]
+        #[shrinkwrap(nest(id = "value", field_type = f64, exclude("id")))]
+        #[shrinkwrap(nest(id = "value_text", field_type = String, chain_from = "value", all_fields))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"), nest(id = "value"))]
+            pub id: i64,
+            pub balance: i64,
+        }
+    });
+}
+
+#[test]
+fn nest_encode_errors() {
+    assert_snapshot("nest_encode_errors", quote! {
+        #[shrinkwrap(transform = MyTransform)]
+        #[shrinkwrap(extra(exclusive))]
+        #[shrinkwrap(nest(id = "text", field_type = String, optional, encode = "json_string"))]
+        #[shrinkwrap(nest(id = "legacy", field_type = String, optional, promote, encode = "json_string"))]
+        #[shrinkwrap(nest(id = "value", field_type = f64, optional))]
+        #[shrinkwrap(nest(id = "value_text", field_type = String, chain_from = "value", encode = "json_string"))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"), nest(id = "legacy"), nest(id = "value"), nest(id = "value_text"))]
+            pub balance: i64,
+        }
+    });
+}
+
+#[test]
+fn nest_alias_errors() {
+    assert_snapshot("nest_alias_errors", quote! {
+        #[shrinkwrap(transform = MyTransform)]
+        #[shrinkwrap(extra(exclusive))]
+        #[shrinkwrap(nest(id = "text", field_type = String, optional, alias = "text"))]
+        #[shrinkwrap(nest(id = "legacy", field_type = String, optional, emit_alias))]
+        #[shrinkwrap(nest(id = "value", field_type = f64, optional, alias = "val", emit_alias, promote))]
+        #[shrinkwrap(nest(id = "value_text", field_type = String, chain_from = "value", alias = "", emit_alias, encode = "json_string"))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"), nest(id = "legacy"), nest(id = "value"), nest(id = "value_text"))]
+            pub balance: i64,
+        }
+    });
+}
+
+#[test]
+fn schema_skip_requires_schema() {
+    assert_snapshot("schema_skip_requires_schema", quote! {
+        #[shrinkwrap(transform = MyTransform)]
+        #[shrinkwrap(nest(id = "text", field_type = String, schema_skip))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"))]
+            pub balance: i64,
+        }
+    });
+}
+
+#[test]
+fn schema_feature_empty() {
+    assert_snapshot("schema_feature_empty", quote! {
+        #[shrinkwrap(transform = MyTransform, schema(feature = ""))]
+        #[shrinkwrap(nest(id = "text", field_type = String))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"))]
+            pub balance: i64,
+        }
+    });
+}
+
+#[test]
+fn no_nests() {
+    assert_snapshot("no_nests", quote! {
+        #[shrinkwrap(transform = MyTransform)]
+        #[shrinkwrap(extra(exclusive))]
+        pub struct MyData {
+            pub balance: i64,
+        }
+    });
+}
+
+#[test]
+fn invalid_nest_ids() {
+    assert_snapshot("invalid_nest_ids", quote! {
+        #[shrinkwrap(transform = MyTransform)]
+        #[shrinkwrap(nest(id = "my-text", field_type = String))]
+        #[shrinkwrap(nest(id = "type", field_type = String))]
+        #[shrinkwrap(nest(id = "1st", field_type = String))]
+        #[shrinkwrap(nest(id = "2nd", field_type = String, field_name = second))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "my-text"), nest(id = "type"), nest(id = "1st"), nest(id = "2nd"))]
+            pub balance: i64,
+        }
+    });
+}
+
+// !- TransformOptions derive
+
+#[test]
+fn transform_options() {
+    assert_derive_snapshot("transform_options", quote! {
+        pub struct MyTransformOpts<'a> {
+            pub with_text: bool,
+            #[shrinkwrap(nest = "value_cents")]
+            pub cents: bool,
+            #[shrinkwrap(skip)]
+            pub with_debug: bool,
+            pub with_locale: &'a str,
+        }
+    }, derive_transform_options_tokens);
+}
+
+#[test]
+fn transform_options_errors() {
+    assert_derive_snapshot("transform_options_errors", quote! {
+        pub struct MyTransformOpts {
+            pub with_text: bool,
+            #[shrinkwrap(nest = "text")]
+            pub text: bool,
+            #[shrinkwrap(nest = "value", skip)]
+            pub value: bool,
+        }
+    }, derive_transform_options_tokens);
+}
+
+// !- Trace
+
+#[test]
+fn trace_chained_nests() {
+    let trace = trace(quote! {
+        #[shrinkwrap(transform = MyTransform, derive_all(PartialEq))]
+        #[shrinkwrap(nest(id = "text", field_type = String))]
+        #[shrinkwrap(nest(id = "value", field_type = f64, optional))]
+        #[shrinkwrap(nest(id = "value_text", field_name = "text", field_type = String, chain_from = "value"))]
+        #[shrinkwrap_attr(attr(serde(rename_all = "camelCase")), limit(nests("value"), class(nest)))]
+        pub struct MyData {
+            #[shrinkwrap(nest(id = "text"), nest(id = "value"), nest(id = "value_text"))]
+            #[shrinkwrap_attr(attr(doc = "Balance"), limit(nests("text")))]
+            pub balance: i64,
+        }
+    });
+    assert_eq!(trace, r#"Structs
+  class    struct                    derives
+  wrapper  MyDataWrapper             ::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize, PartialEq
+  extra    MyDataExtra               ::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize, PartialEq
+  nest     MyDataNestedText          ::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize, PartialEq
+  wrapper  MyDataNestedValueWrapper  ::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize, PartialEq
+  nest     MyDataNestedValue         ::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize, PartialEq
+  extra    MyDataNestedValueExtra    ::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize, PartialEq
+  nest     MyDataNestedValueText     ::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize, PartialEq
+
+Nests
+  id          field  struct                 extra                   optional
+  text        text   MyDataNestedText       MyDataExtra             no
+  value       value  MyDataNestedValue      MyDataExtra             yes
+  value_text  text   MyDataNestedValueText  MyDataNestedValueExtra  no
+
+Attributes
+  target                         attribute
+  MyDataWrapper.data             #[serde(flatten)]
+  MyDataNestedText.balance       #[doc = "Balance"]
+  MyDataNestedValueWrapper.data  #[serde(flatten)]
+  MyDataNestedValue              #[serde(rename_all = "camelCase")]
+"#);
+}
+
+#[test]
+fn trace_empty_tables() {
+    let trace = trace(quote! {
+        #[shrinkwrap(transform = MyTransform, wrapper(flatten = false))]
+        pub struct MyData {
+            pub balance: i64,
+        }
+    });
+    assert_eq!(trace, r#"Structs
+  class    struct         derives
+  wrapper  MyDataWrapper  ::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize
+  extra    MyDataExtra    ::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize
+
+Nests
+  (none)
+
+Attributes
+  (none)
+"#);
+}
+
+// !- Expand filters
+
+#[test]
+fn expand_selected_structs() {
+    assert!(expand_selects_struct("MyData", "MyData"));
+    assert!(expand_selects_struct("Other, MyData ,Third", "MyData"));
+    assert!(!expand_selects_struct("Other,Third", "MyData"));
+    assert!(!expand_selects_struct("MyDataWrapper", "MyData"));
+    assert!(!expand_selects_struct("Other,", "MyData"));
+}
+
+#[test]
+fn expand_selects_all_structs_if_empty() {
+    assert!(expand_selects_struct("", "MyData"));
+    assert!(expand_selects_struct("  ", "MyData"));
+}
+
+#[test]
+fn expand_selected_stages() {
+    let known = |value| ExpandStage::from_env_value(value, |unknown| panic!("`{unknown}` must be a known stage"));
+    assert_eq!(known("structs"), ExpandStage::Structs);
+    assert_eq!(known(" impls "), ExpandStage::Impls);
+    assert_eq!(known("all"), ExpandStage::All);
+    assert_eq!(known(""), ExpandStage::All);
+}
+
+#[test]
+fn expand_unknown_stage_falls_back_to_all() {
+    let mut reported = None;
+    let stage = ExpandStage::from_env_value(" Structs ", |unknown| reported = Some(unknown.to_string()));
+    assert_eq!(stage, ExpandStage::All);
+    assert_eq!(reported.as_deref(), Some("Structs"));
+}
@@ -0,0 +1,23 @@
+use darling::FromDeriveInput;
+use proc_macro::TokenStream;
+use syn::{DeriveInput, parse_macro_input};
+
+use crate::generate::generate_transform_options;
+use crate::parse::types::TransformOptionsOpts;
+
+pub(crate) fn derive_transform_options_impl(input: TokenStream) -> TokenStream {
+    let options_struct = parse_macro_input!(input as DeriveInput);
+
+    let mut out = proc_macro2::TokenStream::default();
+    if let Err(errors) = derive_transform_options_tokens(&options_struct, &mut out) {
+        out.extend(errors.write_errors());
+    }
+
+    out.into()
+}
+
+pub(crate) fn derive_transform_options_tokens(options_struct: &DeriveInput, tokens: &mut proc_macro2::TokenStream) -> darling::Result<()> {
+    let args = TransformOptionsOpts::from_derive_input(options_struct)?;
+
+    generate_transform_options(args, tokens)
+}
@@ -11,9 +11,11 @@ mod expand_no_op;
 use expand_no_op as expand;
 
 #[allow(unused_imports)]
-pub(crate) use expand::{expand_debug, expand_to_tokens, expand_tokens, expand_tokens_unfmt};
+pub(crate) use expand::{expand_debug, expand_stage, expand_to_tokens, expand_trace, expand_tokens, expand_tokens_unfmt};
 
-use syn::{Path, PathArguments, GenericArgument};
+use proc_macro2::Span;
+use std::fmt::{self, Display};
+use syn::{Attribute, Path, PathArguments, GenericArgument};
 
 pub(crate) fn extract_path_generics(path: &Path) -> Vec<&GenericArgument> {
     if let Some(path_base) = path.segments.last() &&
@@ -22,3 +24,57 @@ pub(crate) fn extract_path_generics(path: &Path) -> Vec<&GenericArgument> {
     }
     vec![]
 }
+
+/// The `#[cfg(..)]` attributes within `attrs`, forwarded from origin fields onto the nest fields (and field assignments)
+/// built from them
+pub(crate) fn cfg_attrs(attrs: &[Attribute]) -> Vec<Attribute> {
+    attrs.iter().filter(|attr| attr.path().is_ident("cfg")).cloned().collect()
+}
+
+/// Builds an error located at `span`, for spans which aren't attached to a token (e.g. [`SpannedValue`](darling::util::SpannedValue))
+pub(crate) fn span_error(span: Span, message: impl Display) -> darling::Error {
+    syn::Error::new(span, message).into()
+}
+
+/// Stage of generation dumped by [`expand_stage`], selected via `SHRINKWRAP_EXPAND_STAGE`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ExpandStage {
+    /// Generated structs
+    Structs,
+    /// Generated trait impls + helpers
+    Impls,
+    /// The full derive output
+    All,
+}
+impl ExpandStage {
+    /// Parses a `SHRINKWRAP_EXPAND_STAGE` value, empty and unknown values fallback to [`ExpandStage::All`]
+    ///
+    /// Unknown values are passed to `on_unknown`.
+    pub(crate) fn from_env_value(value: &str, on_unknown: impl FnOnce(&str)) -> Self {
+        match value.trim() {
+            "structs" => Self::Structs,
+            "impls" => Self::Impls,
+            "all" | "" => Self::All,
+            unknown => {
+                on_unknown(unknown);
+                Self::All
+            },
+        }
+    }
+}
+impl Display for ExpandStage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Structs => "structs",
+            Self::Impls => "impls",
+            Self::All => "all",
+        })
+    }
+}
+
+/// Whether `struct_name` is included by the `SHRINKWRAP_EXPAND` value `names`, a comma separated list of struct names
+///
+/// All structs are included if `names` is empty.
+pub(crate) fn expand_selects_struct(names: &str, struct_name: &str) -> bool {
+    names.trim().is_empty() || names.split(',').any(|name| name.trim() == struct_name)
+}
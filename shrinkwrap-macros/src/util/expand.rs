@@ -1,3 +1,15 @@
+//! Dumps of the derive output to stderr, only compiled with the `expand` feature (`shrinkwrap/expand`). Without it,
+//! the no-op fns of `expand_no_op` are used instead and the env vars below have no effect.
+//!
+//! Output is filtered via env vars:
+//! - `SHRINKWRAP_EXPAND`: comma separated list of origin struct names to dump, all structs are dumped if unset
+//! - `SHRINKWRAP_EXPAND_STAGE`: stage of generation to dump, one of `structs`, `impls` or `all` (default)
+//!
+//! Setting `SHRINKWRAP_TRACE=1` additionally dumps the resolved configuration of each selected struct (generated
+//! structs with their derives, the final nest list, and the attributes routed to each struct and field).
+
+use super::{ExpandStage, expand_selects_struct};
+
 // all
 const T_RESET: &str = "\x1b[0m";
 // style
@@ -27,10 +39,47 @@ pub(crate) fn expand_debug<T: std::fmt::Debug>(
     eprintln!("{T_BOLD}{T_C_BLUE}------------------------------------------------{T_RESET}");
 }
 
+/// Dumps the tokens generated for `stage` of `struct_name`'s derive, if selected by the env filters
+pub(crate) fn expand_stage(tokens: &proc_macro2::TokenStream, struct_name: &syn::Ident, stage: ExpandStage) {
+    if selected_stage() != stage || !selected_struct(&struct_name.to_string()) {
+        return;
+    }
+    let label = match stage {
+        ExpandStage::All => format!("Full shrinkwrap derive ({struct_name})"),
+        stage => format!("Shrinkwrap derive {stage} ({struct_name})"),
+    };
+    expand_tokens(tokens, &label);
+}
+
+/// Dumps the resolved derive configuration of `struct_name` rendered by `trace`, if enabled via `SHRINKWRAP_TRACE`
+/// and selected by `SHRINKWRAP_EXPAND`
+pub(crate) fn expand_trace(trace: impl FnOnce() -> String, struct_name: &syn::Ident) {
+    let enabled = std::env::var("SHRINKWRAP_TRACE").is_ok_and(|value| !matches!(value.trim(), "" | "0" | "false"));
+    if !enabled || !selected_struct(&struct_name.to_string()) {
+        return;
+    }
+    eprintln!("\n{T_BOLD}{T_C_BLUE}------------------------------------------------{T_RESET}");
+    eprintln!("{T_BOLD}{T_C_BLUE}Shrinkwrap trace ({struct_name}):{T_RESET} \n{}", trace());
+    eprintln!("{T_BOLD}{T_C_BLUE}------------------------------------------------{T_RESET}");
+}
+
+/// Whether `struct_name` is included by `SHRINKWRAP_EXPAND`
+fn selected_struct(struct_name: &str) -> bool {
+    expand_selects_struct(&std::env::var("SHRINKWRAP_EXPAND").unwrap_or_default(), struct_name)
+}
+
+/// The stage selected by `SHRINKWRAP_EXPAND_STAGE`, unknown stages fallback to [`ExpandStage::All`]
+fn selected_stage() -> ExpandStage {
+    let stage = std::env::var("SHRINKWRAP_EXPAND_STAGE").unwrap_or_default();
+    ExpandStage::from_env_value(&stage, |unknown| {
+        eprintln!("{T_BOLD}{T_C_RED}Unknown SHRINKWRAP_EXPAND_STAGE `{unknown}`{T_RESET}, expected one of: structs, impls, all");
+    })
+}
+
 /// Dumps token stream to stderr if the `expand` feature is enabled. Otherwise this is a no-op
 ///
 /// Attempts to format generated rust code, if valid. Otherwise the output is provided unformatted.
-pub(crate) fn expand_tokens(tokens: &proc_macro2::TokenStream, fn_name: &'static str) {
+pub(crate) fn expand_tokens(tokens: &proc_macro2::TokenStream, fn_name: &str) {
     eprintln!("\n{T_BOLD}{T_C_BLUE}------------------------------------------------{T_RESET}");
     match syn::parse_file(tokens.to_string().as_str()) {
         Ok(tokens_file) => {
@@ -82,7 +131,7 @@ pub(crate) fn expand_to_tokens<T: quote::ToTokens>(
 /// Dumps token stream to stderr if the `expand` feature is enabled. Otherwise this is a no-op
 ///
 /// Attempts to format generated rust code, if valid. Otherwise the output is provided unformatted.
-pub(crate) fn expand_tokens_unfmt(tokens: &proc_macro2::TokenStream, fn_name: &'static str) {
+pub(crate) fn expand_tokens_unfmt(tokens: &proc_macro2::TokenStream, fn_name: &str) {
     eprintln!("\n{T_BOLD}{T_C_BLUE}------------------------------------------------{T_RESET}");
     eprintln!(
         "{T_BOLD}{T_C_BLUE}{fn_name}{T_C_RESET} unformatted: \n{}",
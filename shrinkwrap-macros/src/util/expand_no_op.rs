@@ -6,11 +6,13 @@ pub(crate) fn expand_debug<T: std::fmt::Debug>(
     _fn_name: &'static str,
 ) {
 }
-pub(crate) fn expand_tokens(_tokens: &proc_macro2::TokenStream, _fn_name: &'static str) {}
+pub(crate) fn expand_stage(_tokens: &proc_macro2::TokenStream, _struct_name: &syn::Ident, _stage: super::ExpandStage) {}
+pub(crate) fn expand_trace(_trace: impl FnOnce() -> String, _struct_name: &syn::Ident) {}
+pub(crate) fn expand_tokens(_tokens: &proc_macro2::TokenStream, _fn_name: &str) {}
 pub(crate) fn expand_to_tokens<T: quote::ToTokens>(
     _t: &T,
     _type_name: &'static str,
     _fn_name: &'static str,
 ) {
 }
-pub(crate) fn expand_tokens_unfmt(_tokens: &proc_macro2::TokenStream, _fn_name: &'static str) {}
+pub(crate) fn expand_tokens_unfmt(_tokens: &proc_macro2::TokenStream, _fn_name: &str) {}
@@ -3,23 +3,45 @@ use proc_macro::TokenStream;
 use syn::{DeriveInput, parse_macro_input};
 
 use crate::generate::generate;
+#[cfg(test)]
+use crate::generate::generate_trace;
+use crate::parse::type_values::quote_type_values;
 use crate::parse::types::DeriveItemOpts;
-use crate::util::expand_tokens;
+use crate::preset::merge_preset_attrs;
+use crate::util::{ExpandStage, expand_stage};
 
 pub(crate) fn derive_wrap_impl(input: TokenStream) -> TokenStream {
     let origin_struct = parse_macro_input!(input as DeriveInput);
 
-    let args = match DeriveItemOpts::from_derive_input(&origin_struct) {
-        Ok(v) => v,
-        Err(e) => {
-            return TokenStream::from(e.write_errors());
-        }
-    };
-    args.validate();
-
+    // errors are only converted at this boundary, any items generated alongside them are
+    // retained so that downstream code (and IDE analysis) can still resolve them
     let mut out = proc_macro2::TokenStream::default();
-    generate(args, &mut out);
-    expand_tokens(&out, "Full shrinkwrap derive");
+    if let Err(errors) = derive_wrap_tokens(&origin_struct, &mut out) {
+        out.extend(errors.write_errors());
+    }
+    expand_stage(&out, &origin_struct.ident, ExpandStage::All);
 
     out.into()
 }
+
+pub(crate) fn derive_wrap_tokens(origin_struct: &DeriveInput, tokens: &mut proc_macro2::TokenStream) -> darling::Result<()> {
+    generate(parse_wrap_opts(origin_struct)?, tokens)
+}
+
+/// Renders the `SHRINKWRAP_TRACE` output for `origin_struct`, in place of the derive output
+#[cfg(test)]
+pub(crate) fn derive_wrap_trace(origin_struct: &DeriveInput) -> darling::Result<String> {
+    generate_trace(parse_wrap_opts(origin_struct)?)
+}
+
+/// Parses and validates the derive options of `origin_struct`
+fn parse_wrap_opts(origin_struct: &DeriveInput) -> darling::Result<DeriveItemOpts> {
+    let quoted = quote_type_values(origin_struct);
+    let origin_struct = quoted.as_ref().unwrap_or(origin_struct);
+    let merged = merge_preset_attrs(origin_struct)?;
+    let mut args = DeriveItemOpts::from_derive_input(merged.as_ref().unwrap_or(origin_struct))?;
+    args.declare_field_nests()?;
+    args.assign_all_fields();
+    args.validate()?;
+    Ok(args)
+}
@@ -0,0 +1,89 @@
+const _: () = {
+    type AssertedTransform = MyTransform;
+    fn assert_transform<T: ?Sized + ::shrinkwrap::__private::ImplementsTransform>() {}
+    let _ = assert_transform::<AssertedTransform>;
+};
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataWrapper {
+    pub extra: MyDataExtra,
+    #[serde(flatten)]
+    pub data: MyData,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataExtra {
+    pub text: MyDataNestedText,
+    pub value: MyDataNestedValue,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedText {
+    pub balance: String,
+    pub label: Option<String>,
+    pub created_at: String,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedValue {
+    pub balance: f64,
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::ToWrappedWith<T> for MyData
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TransformToNest2<MyDataNestedText, Data = MyData>,
+    T: ::shrinkwrap::__private::TransformToNest2<MyDataNestedValue, Data = MyData>,
+{
+    type Wrapper = MyDataWrapper;
+    fn to_wrapped_with(
+        self,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        let nest_text = transform.transform_to_nest(&self, options);
+        let nest_value = transform.transform_to_nest(&self, options);
+        let extra = MyDataExtra {
+            text: nest_text,
+            value: nest_value,
+        };
+        Self::Wrapper { extra, data: self }
+    }
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::WrapAllWith<T> for MyData
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TransformToNest2<MyDataNestedText, Data = MyData>,
+    T: ::shrinkwrap::__private::TransformToNest2<MyDataNestedValue, Data = MyData>,
+{
+    fn wrap_all_with(
+        data: Vec<Self>,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Vec<Self::Wrapper> {
+        let wrappers = data
+            .into_iter()
+            .map(|data| {
+                let nest_text = transform.transform_to_nest(&data, options);
+                let nest_value = transform.transform_to_nest(&data, options);
+                let extra = MyDataExtra {
+                    text: nest_text,
+                    value: nest_value,
+                };
+                Self::Wrapper { extra, data }
+            });
+        wrappers.collect()
+    }
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::ToWrappedWith<MyTransform> for Option<MyData> {
+    type Wrapper = Option<MyDataWrapper>;
+    fn to_wrapped_with(
+        self,
+        transform: &MyTransform,
+        options: &<MyTransform as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        self.map(|data| data.to_wrapped_with(transform, options))
+    }
+}
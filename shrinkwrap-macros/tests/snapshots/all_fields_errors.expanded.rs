@@ -0,0 +1,15 @@
+::core::compile_error! {
+    "`exclude` requires `all_fields`"
+}
+::core::compile_error! {
+    "`all_fields` cannot be used with `chain_from`"
+}
+::core::compile_error! {
+    "Field `id` excluded here"
+}
+::core::compile_error! {
+    "Field `id` cannot be assigned to nest `text` as it is excluded"
+}
+::core::compile_error! {
+    "Field `missing` is not defined"
+}
@@ -0,0 +1,3 @@
+::core::compile_error! {
+    "Transforms must have distinct names, `Fast` is listed multiple times"
+}
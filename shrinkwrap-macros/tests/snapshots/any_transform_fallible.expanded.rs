@@ -0,0 +1,172 @@
+const _: () = {
+    type AssertedTransform = Fast;
+    fn assert_transform<T: ?Sized + ::shrinkwrap::__private::ImplementsTransform>() {}
+    let _ = assert_transform::<AssertedTransform>;
+};
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataWrapper {
+    pub extra: MyDataExtra,
+    #[serde(flatten)]
+    pub data: MyData,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataExtra {
+    pub text: MyDataNestedText,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedText {
+    pub balance: String,
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::TryToWrappedWith<T> for MyData
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TryTransformToNest2<
+        MyDataNestedText,
+        Data = MyData,
+        Error = MyError,
+    >,
+{
+    type Wrapper = MyDataWrapper;
+    type Error = MyError;
+    fn try_to_wrapped_with(
+        self,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Result<Self::Wrapper, Self::Error> {
+        let nest_text = transform.try_transform_to_nest(&self, options)?;
+        let extra = MyDataExtra { text: nest_text };
+        Ok(Self::Wrapper { extra, data: self })
+    }
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::TryWrapAllWith<T> for MyData
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TryTransformToNest2<
+        MyDataNestedText,
+        Data = MyData,
+        Error = MyError,
+    >,
+{
+    fn try_wrap_all_with(
+        data: Vec<Self>,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Result<Vec<Self::Wrapper>, Self::Error> {
+        let wrappers = data
+            .into_iter()
+            .map(|data| {
+                let nest_text = transform.try_transform_to_nest(&data, options)?;
+                let extra = MyDataExtra { text: nest_text };
+                Ok(Self::Wrapper { extra, data })
+            });
+        wrappers.collect::<Result<Vec<_>, _>>()
+    }
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::TryToWrappedWith<Fast> for Option<MyData> {
+    type Wrapper = Option<MyDataWrapper>;
+    type Error = MyError;
+    fn try_to_wrapped_with(
+        self,
+        transform: &Fast,
+        options: &<Fast as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Result<Self::Wrapper, Self::Error> {
+        self.map(|data| data.try_to_wrapped_with(transform, options)).transpose()
+    }
+}
+/// Transform selected at runtime, dispatching to one of `Fast`, `Precise`
+pub enum MyDataAnyTransform {
+    Fast(Fast),
+    Precise(Precise),
+}
+#[automatically_derived]
+impl ::std::convert::From<Fast> for MyDataAnyTransform {
+    fn from(transform: Fast) -> Self {
+        Self::Fast(transform)
+    }
+}
+#[automatically_derived]
+impl ::std::convert::From<Precise> for MyDataAnyTransform {
+    fn from(transform: Precise) -> Self {
+        Self::Precise(transform)
+    }
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::Transform2 for MyDataAnyTransform {
+    type Options<'a> = <Fast as ::shrinkwrap::__private::Transform2>::Options<'a>;
+}
+#[automatically_derived]
+impl<N> ::shrinkwrap::__private::TransformToNest2<N> for MyDataAnyTransform
+where
+    Fast: ::shrinkwrap::__private::TransformToNest2<N>,
+    Precise: ::shrinkwrap::__private::TransformToNest2<
+            N,
+            Data = <Fast as ::shrinkwrap::__private::TransformToNest2<N>>::Data,
+        >
+        + for<'a> ::shrinkwrap::__private::Transform2<
+            Options<'a> = <Fast as ::shrinkwrap::__private::Transform2>::Options<'a>,
+        >,
+{
+    type Data = <Fast as ::shrinkwrap::__private::TransformToNest2<N>>::Data;
+    fn transform_to_nest(&self, data: &Self::Data, options: &Self::Options<'_>) -> N {
+        match self {
+            Self::Fast(transform) => {
+                ::shrinkwrap::__private::TransformToNest2::transform_to_nest(
+                    transform,
+                    data,
+                    options,
+                )
+            }
+            Self::Precise(transform) => {
+                ::shrinkwrap::__private::TransformToNest2::transform_to_nest(
+                    transform,
+                    data,
+                    options,
+                )
+            }
+        }
+    }
+}
+#[automatically_derived]
+impl<N> ::shrinkwrap::__private::TryTransformToNest2<N> for MyDataAnyTransform
+where
+    Fast: ::shrinkwrap::__private::TryTransformToNest2<N>,
+    Precise: ::shrinkwrap::__private::TryTransformToNest2<
+            N,
+            Data = <Fast as ::shrinkwrap::__private::TryTransformToNest2<N>>::Data,
+            Error = <Fast as ::shrinkwrap::__private::TryTransformToNest2<N>>::Error,
+        >
+        + for<'a> ::shrinkwrap::__private::Transform2<
+            Options<'a> = <Fast as ::shrinkwrap::__private::Transform2>::Options<'a>,
+        >,
+{
+    type Data = <Fast as ::shrinkwrap::__private::TryTransformToNest2<N>>::Data;
+    type Error = <Fast as ::shrinkwrap::__private::TryTransformToNest2<N>>::Error;
+    fn try_transform_to_nest(
+        &self,
+        data: &Self::Data,
+        options: &Self::Options<'_>,
+    ) -> ::std::result::Result<N, Self::Error> {
+        match self {
+            Self::Fast(transform) => {
+                ::shrinkwrap::__private::TryTransformToNest2::try_transform_to_nest(
+                    transform,
+                    data,
+                    options,
+                )
+            }
+            Self::Precise(transform) => {
+                ::shrinkwrap::__private::TryTransformToNest2::try_transform_to_nest(
+                    transform,
+                    data,
+                    options,
+                )
+            }
+        }
+    }
+}
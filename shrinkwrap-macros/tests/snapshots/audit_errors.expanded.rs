@@ -0,0 +1,9 @@
+::core::compile_error! {
+    "`unmapped` requires the `audit` flag"
+}
+::core::compile_error! {
+    "Field marked `unmapped` here"
+}
+::core::compile_error! {
+    "Fields marked `unmapped` cannot be assigned to nest `text`"
+}
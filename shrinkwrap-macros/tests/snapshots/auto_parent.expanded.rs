@@ -0,0 +1,130 @@
+const _: () = {
+    type AssertedTransform = MyTransform;
+    fn assert_transform<T: ?Sized + ::shrinkwrap::__private::ImplementsTransform>() {}
+    let _ = assert_transform::<AssertedTransform>;
+};
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataWrapper {
+    pub extra: MyDataExtra,
+    #[serde(flatten)]
+    pub data: MyData,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataExtra {
+    pub usd_value: Option<MyDataNestedUsdValueWrapper>,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedUsdValueWrapper {
+    pub extra: MyDataNestedUsdValueExtra,
+    #[serde(flatten)]
+    pub data: MyDataNestedUsdValue,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedUsdValue {}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedUsdValueExtra {
+    pub text: Option<MyDataNestedUsdValueText>,
+    pub cents: Option<MyDataNestedUsdValueCents>,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedUsdValueText {
+    pub balance: String,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedUsdValueCents {
+    pub balance: i64,
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::ToWrappedWith<T> for MyData
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TransformToNest2<
+        Option<MyDataNestedUsdValueWrapper>,
+        Data = MyData,
+    >,
+{
+    type Wrapper = MyDataWrapper;
+    fn to_wrapped_with(
+        self,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        let nest_usd_value = transform.transform_to_nest(&self, options);
+        let extra = MyDataExtra {
+            usd_value: nest_usd_value,
+        };
+        Self::Wrapper { extra, data: self }
+    }
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::WrapAllWith<T> for MyData
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TransformToNest2<
+        Option<MyDataNestedUsdValueWrapper>,
+        Data = MyData,
+    >,
+{
+    fn wrap_all_with(
+        data: Vec<Self>,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Vec<Self::Wrapper> {
+        let wrappers = data
+            .into_iter()
+            .map(|data| {
+                let nest_usd_value = transform.transform_to_nest(&data, options);
+                let extra = MyDataExtra {
+                    usd_value: nest_usd_value,
+                };
+                Self::Wrapper { extra, data }
+            });
+        wrappers.collect()
+    }
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::ToWrappedWith<MyTransform> for Option<MyData> {
+    type Wrapper = Option<MyDataWrapper>;
+    fn to_wrapped_with(
+        self,
+        transform: &MyTransform,
+        options: &<MyTransform as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        self.map(|data| data.to_wrapped_with(transform, options))
+    }
+}
+#[automatically_derived]
+#[doc(hidden)]
+impl ::shrinkwrap::__private::TransformToNest2<Option<MyDataNestedUsdValueWrapper>>
+for MyTransform {
+    type Data = MyData;
+    fn transform_to_nest(
+        &self,
+        data: &Self::Data,
+        options: &Self::Options<'_>,
+    ) -> Option<MyDataNestedUsdValueWrapper> {
+        let nest_text = <Self as ::shrinkwrap::__private::TransformToNest2<
+            Option<MyDataNestedUsdValueText>,
+        >>::transform_to_nest(self, data, options);
+        let nest_cents = <Self as ::shrinkwrap::__private::TransformToNest2<
+            Option<MyDataNestedUsdValueCents>,
+        >>::transform_to_nest(self, data, options);
+        if nest_text.is_none() && nest_cents.is_none() {
+            return None;
+        }
+        Some(MyDataNestedUsdValueWrapper {
+            extra: MyDataNestedUsdValueExtra {
+                text: nest_text,
+                cents: nest_cents,
+            },
+            data: MyDataNestedUsdValue {},
+        })
+    }
+}
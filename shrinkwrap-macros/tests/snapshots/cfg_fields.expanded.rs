@@ -0,0 +1,196 @@
+const _: () = {
+    type AssertedTransform = MyTransform;
+    fn assert_transform<T: ?Sized + ::shrinkwrap::__private::ImplementsTransform>() {}
+    let _ = assert_transform::<AssertedTransform>;
+};
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataWrapper {
+    pub extra: MyDataExtra,
+    #[serde(flatten)]
+    pub data: MyData,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataExtra {
+    pub text: MyDataNestedText,
+    pub copy: MyDataNestedCopy,
+    pub value: MyDataNestedValue,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedText {
+    pub balance: TextValue,
+    #[cfg(feature = "audit")]
+    pub audited_at: TextValue,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedCopy {
+    pub balance: i64,
+    #[cfg(feature = "audit")]
+    pub audited_at: i64,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedValue {
+    pub balance: f64,
+    #[cfg(feature = "audit")]
+    pub audited_at: f64,
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::ToWrappedWith<T> for MyData
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TransformToNest2<MyDataNestedText, Data = MyData>,
+    T: ::shrinkwrap::__private::TransformToNest2<MyDataNestedCopy, Data = MyData>,
+    T: ::shrinkwrap::__private::TransformToNest2<MyDataNestedValue, Data = MyData>,
+{
+    type Wrapper = MyDataWrapper;
+    fn to_wrapped_with(
+        self,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        let nest_text = transform.transform_to_nest(&self, options);
+        let nest_copy = transform.transform_to_nest(&self, options);
+        let nest_value = transform.transform_to_nest(&self, options);
+        let extra = MyDataExtra {
+            text: nest_text,
+            copy: nest_copy,
+            value: nest_value,
+        };
+        Self::Wrapper { extra, data: self }
+    }
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::WrapAllWith<T> for MyData
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TransformToNest2<MyDataNestedText, Data = MyData>,
+    T: ::shrinkwrap::__private::TransformToNest2<MyDataNestedCopy, Data = MyData>,
+    T: ::shrinkwrap::__private::TransformToNest2<MyDataNestedValue, Data = MyData>,
+{
+    fn wrap_all_with(
+        data: Vec<Self>,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Vec<Self::Wrapper> {
+        let wrappers = data
+            .into_iter()
+            .map(|data| {
+                let nest_text = transform.transform_to_nest(&data, options);
+                let nest_copy = transform.transform_to_nest(&data, options);
+                let nest_value = transform.transform_to_nest(&data, options);
+                let extra = MyDataExtra {
+                    text: nest_text,
+                    copy: nest_copy,
+                    value: nest_value,
+                };
+                Self::Wrapper { extra, data }
+            });
+        wrappers.collect()
+    }
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::ToWrappedWith<MyTransform> for Option<MyData> {
+    type Wrapper = Option<MyDataWrapper>;
+    fn to_wrapped_with(
+        self,
+        transform: &MyTransform,
+        options: &<MyTransform as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        self.map(|data| data.to_wrapped_with(transform, options))
+    }
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::TransformToNest2<MyDataNestedText> for MyTransform
+where
+    Self: ::shrinkwrap::__private::BuildNestValue2<i64, TextValue>,
+{
+    type Data = MyData;
+    fn transform_to_nest(
+        &self,
+        data: &Self::Data,
+        options: &Self::Options<'_>,
+    ) -> MyDataNestedText {
+        MyDataNestedText {
+            balance: ::shrinkwrap::__private::BuildNestValue2::build_nest_value(
+                self,
+                &data.balance,
+                options,
+            ),
+            #[cfg(feature = "audit")]
+            audited_at: ::shrinkwrap::__private::BuildNestValue2::build_nest_value(
+                self,
+                &data.audited_at,
+                options,
+            ),
+        }
+    }
+}
+#[automatically_derived]
+impl ::std::convert::From<&MyData> for MyDataNestedCopy {
+    fn from(data: &MyData) -> Self {
+        Self {
+            balance: ::std::clone::Clone::clone(&data.balance),
+            #[cfg(feature = "audit")]
+            audited_at: ::std::clone::Clone::clone(&data.audited_at),
+        }
+    }
+}
+#[automatically_derived]
+#[doc(hidden)]
+impl ::shrinkwrap::__private::TransformToNest2<MyDataNestedCopy> for MyTransform {
+    type Data = MyData;
+    fn transform_to_nest(
+        &self,
+        data: &Self::Data,
+        _options: &Self::Options<'_>,
+    ) -> MyDataNestedCopy {
+        <MyDataNestedCopy as ::std::convert::From<&MyData>>::from(data)
+    }
+}
+#[automatically_derived]
+///Borrowed view of the [`MyData`] fields used to build a nest
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::std::marker::Copy)]
+pub struct MyDataNestedValueSources<'a> {
+    pub balance: &'a i64,
+    #[cfg(feature = "audit")]
+    pub audited_at: &'a i64,
+}
+#[automatically_derived]
+impl<'a> MyDataNestedValueSources<'a> {
+    pub fn from_data(data: &'a MyData) -> Self {
+        Self {
+            balance: &data.balance,
+            #[cfg(feature = "audit")]
+            audited_at: &data.audited_at,
+        }
+    }
+}
+#[automatically_derived]
+#[doc(hidden)]
+impl ::shrinkwrap::__private::TransformToNest2<MyDataNestedValue> for MyTransform
+where
+    for<'a> Self: ::shrinkwrap::__private::TransformFromSources2<
+        MyDataNestedValueSources<'a>,
+        MyDataNestedValue,
+    >,
+{
+    type Data = MyData;
+    fn transform_to_nest(
+        &self,
+        data: &Self::Data,
+        options: &Self::Options<'_>,
+    ) -> MyDataNestedValue {
+        <Self as ::shrinkwrap::__private::TransformFromSources2<
+            MyDataNestedValueSources<'_>,
+            MyDataNestedValue,
+        >>::transform_from_sources(
+            self,
+            MyDataNestedValueSources::from_data(data),
+            options,
+        )
+    }
+}
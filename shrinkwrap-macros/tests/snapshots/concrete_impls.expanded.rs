@@ -0,0 +1,192 @@
+const _: () = {
+    type AssertedTransform = MyTransform;
+    fn assert_transform<T: ?Sized + ::shrinkwrap::__private::ImplementsTransform>() {}
+    let _ = assert_transform::<AssertedTransform>;
+};
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataWrapper {
+    pub extra: MyDataExtra,
+    #[serde(flatten)]
+    pub data: MyData,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataExtra {
+    pub text: Option<MyDataNestedText>,
+    pub value: MyDataNestedValueWrapper,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedText {
+    pub balance: String,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedValueWrapper {
+    pub extra: MyDataNestedValueExtra,
+    #[serde(flatten)]
+    pub data: MyDataNestedValue,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedValue {
+    pub balance: f64,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedValueExtra {
+    pub text: MyDataNestedValueText,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedValueText {
+    pub balance: String,
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::ToWrappedWith<MyTransform> for MyData
+where
+    MyTransform: ::shrinkwrap::__private::Transform2,
+    MyTransform: ::shrinkwrap::__private::TransformToNest2<
+        Option<MyDataNestedText>,
+        Data = MyData,
+    >,
+    MyTransform: ::shrinkwrap::__private::TransformToNest2<
+        MyDataNestedValueWrapper,
+        Data = MyData,
+    >,
+{
+    type Wrapper = MyDataWrapper;
+    fn to_wrapped_with(
+        self,
+        transform: &MyTransform,
+        options: &<MyTransform as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        let nest_text = transform.transform_to_nest(&self, options);
+        let nest_value = transform.transform_to_nest(&self, options);
+        let extra = MyDataExtra {
+            text: nest_text,
+            value: nest_value,
+        };
+        Self::Wrapper { extra, data: self }
+    }
+}
+#[automatically_derived]
+#[allow(private_interfaces)]
+impl MyDataWrapper {
+    /// Recomputes the `extra` struct in place from the wrapped data
+    pub fn enrich_with(
+        &mut self,
+        transform: &MyTransform,
+        options: &<MyTransform as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    )
+    where
+        MyTransform: ::shrinkwrap::__private::Transform2,
+        MyTransform: ::shrinkwrap::__private::TransformToNest2<
+            Option<MyDataNestedText>,
+            Data = MyData,
+        >,
+        MyTransform: ::shrinkwrap::__private::TransformToNest2<
+            MyDataNestedValueWrapper,
+            Data = MyData,
+        >,
+    {
+        let nest_text = transform.transform_to_nest(&self.data, options);
+        let nest_value = transform.transform_to_nest(&self.data, options);
+        self.extra = MyDataExtra {
+            text: nest_text,
+            value: nest_value,
+        };
+    }
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::WrapAllWith<MyTransform> for MyData
+where
+    MyTransform: ::shrinkwrap::__private::Transform2,
+    MyTransform: ::shrinkwrap::__private::TransformToNest2<
+        Option<MyDataNestedText>,
+        Data = MyData,
+    >,
+    MyTransform: ::shrinkwrap::__private::TransformToNest2<
+        MyDataNestedValueWrapper,
+        Data = MyData,
+    >,
+{
+    fn wrap_all_with(
+        data: Vec<Self>,
+        transform: &MyTransform,
+        options: &<MyTransform as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Vec<Self::Wrapper> {
+        let wrappers = data
+            .into_iter()
+            .map(|data| {
+                let nest_text = transform.transform_to_nest(&data, options);
+                let nest_value = transform.transform_to_nest(&data, options);
+                let extra = MyDataExtra {
+                    text: nest_text,
+                    value: nest_value,
+                };
+                Self::Wrapper { extra, data }
+            });
+        wrappers.collect()
+    }
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::ToWrappedWith<MyTransform> for Option<MyData> {
+    type Wrapper = Option<MyDataWrapper>;
+    fn to_wrapped_with(
+        self,
+        transform: &MyTransform,
+        options: &<MyTransform as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        self.map(|data| data.to_wrapped_with(transform, options))
+    }
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::ToWrappedWith<MyTransform> for MyDataNestedValue
+where
+    MyTransform: ::shrinkwrap::__private::Transform2,
+    MyTransform: ::shrinkwrap::__private::TransformToNest2<
+        MyDataNestedValueText,
+        Data = MyDataNestedValue,
+    >,
+{
+    type Wrapper = MyDataNestedValueWrapper;
+    fn to_wrapped_with(
+        self,
+        transform: &MyTransform,
+        options: &<MyTransform as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        let nest_text = transform.transform_to_nest(&self, options);
+        let extra = MyDataNestedValueExtra {
+            text: nest_text,
+        };
+        Self::Wrapper { extra, data: self }
+    }
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::ToWrappedWith<MyTransform> for Option<MyDataNestedValue> {
+    type Wrapper = Option<MyDataNestedValueWrapper>;
+    fn to_wrapped_with(
+        self,
+        transform: &MyTransform,
+        options: &<MyTransform as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        self.map(|data| data.to_wrapped_with(transform, options))
+    }
+}
+#[automatically_derived]
+#[doc(hidden)]
+impl ::shrinkwrap::__private::TransformToNest2<MyDataNestedValueWrapper>
+for MyTransform {
+    type Data = MyData;
+    fn transform_to_nest(
+        &self,
+        data: &Self::Data,
+        options: &Self::Options<'_>,
+    ) -> MyDataNestedValueWrapper {
+        use ::shrinkwrap::__private::{ToNestWith, WrapDataWith};
+        let nest_data: MyDataNestedValue = data.to_nest_with(self, options);
+        MyDataNestedValueWrapper::wrap_data_with(nest_data, self, options)
+    }
+}
@@ -0,0 +1,167 @@
+const _: () = {
+    type AssertedTransform = MyTransform;
+    fn assert_transform<T: ?Sized + ::shrinkwrap::__private::ImplementsTransform>() {}
+    let _ = assert_transform::<AssertedTransform>;
+};
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataWrapper {
+    pub extra: MyDataExtra,
+    #[serde(flatten)]
+    pub data: MyData,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataExtra {
+    pub text: MyDataNestedText,
+    pub cents: Option<MyDataNestedCents>,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedText {
+    pub balance: String,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedCents {
+    pub balance: i64,
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::TryToWrappedWith<T> for MyData
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TryTransformToNest2<
+        MyDataNestedText,
+        Data = MyData,
+        Error = MyError,
+    >,
+    T: ::shrinkwrap::__private::TryTransformToNest2<
+        Option<MyDataNestedCents>,
+        Data = MyData,
+        Error = MyError,
+    >,
+{
+    type Wrapper = MyDataWrapper;
+    type Error = MyError;
+    fn try_to_wrapped_with(
+        self,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Result<Self::Wrapper, Self::Error> {
+        let nest_text = ::shrinkwrap::__private::timed_nest(
+            "text",
+            || transform.try_transform_to_nest(&self, options),
+        )?;
+        let nest_cents = ::shrinkwrap::__private::timed_nest(
+            "cents",
+            || transform.try_transform_to_nest(&self, options),
+        )?;
+        let extra = MyDataExtra {
+            text: nest_text,
+            cents: nest_cents,
+        };
+        Ok(Self::Wrapper { extra, data: self })
+    }
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::TryWrapAllWith<T> for MyData
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TryTransformToNest2<
+        MyDataNestedText,
+        Data = MyData,
+        Error = MyError,
+    >,
+    T: ::shrinkwrap::__private::TryTransformToNest2<
+        Option<MyDataNestedCents>,
+        Data = MyData,
+        Error = MyError,
+    >,
+{
+    fn try_wrap_all_with(
+        data: Vec<Self>,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Result<Vec<Self::Wrapper>, Self::Error> {
+        let wrappers = data
+            .into_iter()
+            .map(|data| {
+                let nest_text = ::shrinkwrap::__private::timed_nest(
+                    "text",
+                    || transform.try_transform_to_nest(&data, options),
+                )?;
+                let nest_cents = ::shrinkwrap::__private::timed_nest(
+                    "cents",
+                    || transform.try_transform_to_nest(&data, options),
+                )?;
+                let extra = MyDataExtra {
+                    text: nest_text,
+                    cents: nest_cents,
+                };
+                Ok(Self::Wrapper { extra, data })
+            });
+        wrappers.collect::<Result<Vec<_>, _>>()
+    }
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::TryToWrappedWith<MyTransform> for Option<MyData> {
+    type Wrapper = Option<MyDataWrapper>;
+    type Error = MyError;
+    fn try_to_wrapped_with(
+        self,
+        transform: &MyTransform,
+        options: &<MyTransform as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Result<Self::Wrapper, Self::Error> {
+        self.map(|data| data.try_to_wrapped_with(transform, options)).transpose()
+    }
+}
+#[automatically_derived]
+/// [`MyDataWrapper`] along with its wrapping diagnostics, see `shrinkwrap::diagnostics`
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataDebugWrapper {
+    /// The primary wrapper
+    #[serde(flatten)]
+    pub wrapper: MyDataWrapper,
+    /// Diagnostics of the wrapping
+    pub diagnostics: ::shrinkwrap::__private::WrapDiagnostics,
+}
+#[automatically_derived]
+impl MyDataDebugWrapper {
+    /// Wraps `data`, recording diagnostics of each nest within [`MyDataWrapper`]'s extra struct
+    pub fn try_wrap_with<T>(
+        data: MyData,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> ::std::result::Result<
+        Self,
+        <MyData as ::shrinkwrap::__private::TryToWrappedWith<T>>::Error,
+    >
+    where
+        T: ::shrinkwrap::__private::Transform2,
+        MyData: ::shrinkwrap::__private::TryToWrappedWith<T, Wrapper = MyDataWrapper>,
+    {
+        let (wrapper, timings) = ::shrinkwrap::__private::collect_timings(|| {
+            ::shrinkwrap::__private::TryToWrappedWith::try_to_wrapped_with(
+                data,
+                transform,
+                options,
+            )
+        });
+        let wrapper = wrapper?;
+        let diagnostics = ::shrinkwrap::__private::WrapDiagnostics::new(
+            [("text", true), ("cents", wrapper.extra.cents.is_some())],
+            &timings,
+        );
+        ::std::result::Result::Ok(Self { wrapper, diagnostics })
+    }
+    /// Discards the diagnostics
+    pub fn into_wrapper(self) -> MyDataWrapper {
+        self.wrapper
+    }
+}
+#[automatically_derived]
+impl ::std::convert::From<MyDataDebugWrapper> for MyDataWrapper {
+    fn from(debug_wrapper: MyDataDebugWrapper) -> Self {
+        debug_wrapper.wrapper
+    }
+}
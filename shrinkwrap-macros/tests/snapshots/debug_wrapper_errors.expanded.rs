@@ -0,0 +1,3 @@
+::core::compile_error! {
+    "`debug_wrapper` cannot be used with `extra(exclusive)`"
+}
@@ -0,0 +1,208 @@
+const _: () = {
+    type AssertedTransform = MyTransform;
+    fn assert_transform<T: ?Sized + ::shrinkwrap::__private::ImplementsTransform>() {}
+    let _ = assert_transform::<AssertedTransform>;
+};
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataWrapper {
+    pub extra: MyDataExtra,
+    #[serde(flatten)]
+    pub data: MyData,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataExtra {
+    pub value: Option<MyDataNestedValueWrapper>,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedValueWrapper {
+    pub extra: MyDataNestedValueExtra,
+    #[serde(flatten)]
+    pub data: MyDataNestedValue,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedValue {
+    pub balance: f64,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedValueExtra {
+    pub text: Option<MyDataNestedValueTextWrapper>,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedValueTextWrapper {
+    pub extra: MyDataNestedValueTextExtra,
+    #[serde(flatten)]
+    pub data: MyDataNestedValueText,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedValueText {
+    pub balance: String,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedValueTextExtra {
+    pub short: Option<MyDataNestedValueTextShort>,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedValueTextShort {
+    pub balance: String,
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::ToWrappedWith<T> for MyData
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TransformToNest2<
+        Option<MyDataNestedValueWrapper>,
+        Data = MyData,
+    >,
+{
+    type Wrapper = MyDataWrapper;
+    fn to_wrapped_with(
+        self,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        let nest_value = transform.transform_to_nest(&self, options);
+        let extra = MyDataExtra { value: nest_value };
+        Self::Wrapper { extra, data: self }
+    }
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::WrapAllWith<T> for MyData
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TransformToNest2<
+        Option<MyDataNestedValueWrapper>,
+        Data = MyData,
+    >,
+{
+    fn wrap_all_with(
+        data: Vec<Self>,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Vec<Self::Wrapper> {
+        let wrappers = data
+            .into_iter()
+            .map(|data| {
+                let nest_value = transform.transform_to_nest(&data, options);
+                let extra = MyDataExtra { value: nest_value };
+                Self::Wrapper { extra, data }
+            });
+        wrappers.collect()
+    }
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::ToWrappedWith<MyTransform> for Option<MyData> {
+    type Wrapper = Option<MyDataWrapper>;
+    fn to_wrapped_with(
+        self,
+        transform: &MyTransform,
+        options: &<MyTransform as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        self.map(|data| data.to_wrapped_with(transform, options))
+    }
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::ToWrappedWith<T> for MyDataNestedValue
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TransformToNest2<
+        Option<MyDataNestedValueTextWrapper>,
+        Data = MyDataNestedValue,
+    >,
+{
+    type Wrapper = MyDataNestedValueWrapper;
+    fn to_wrapped_with(
+        self,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        let nest_text = transform.transform_to_nest(&self, options);
+        let extra = MyDataNestedValueExtra {
+            text: nest_text,
+        };
+        Self::Wrapper { extra, data: self }
+    }
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::ToWrappedWith<MyTransform> for Option<MyDataNestedValue> {
+    type Wrapper = Option<MyDataNestedValueWrapper>;
+    fn to_wrapped_with(
+        self,
+        transform: &MyTransform,
+        options: &<MyTransform as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        self.map(|data| data.to_wrapped_with(transform, options))
+    }
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::ToWrappedWith<T> for MyDataNestedValueText
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TransformToNest2<
+        Option<MyDataNestedValueTextShort>,
+        Data = MyDataNestedValueText,
+    >,
+{
+    type Wrapper = MyDataNestedValueTextWrapper;
+    fn to_wrapped_with(
+        self,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        let nest_short = transform.transform_to_nest(&self, options);
+        let extra = MyDataNestedValueTextExtra {
+            short: nest_short,
+        };
+        Self::Wrapper { extra, data: self }
+    }
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::ToWrappedWith<MyTransform>
+for Option<MyDataNestedValueText> {
+    type Wrapper = Option<MyDataNestedValueTextWrapper>;
+    fn to_wrapped_with(
+        self,
+        transform: &MyTransform,
+        options: &<MyTransform as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        self.map(|data| data.to_wrapped_with(transform, options))
+    }
+}
+#[automatically_derived]
+#[doc(hidden)]
+impl ::shrinkwrap::__private::TransformToNest2<Option<MyDataNestedValueWrapper>>
+for MyTransform {
+    type Data = MyData;
+    fn transform_to_nest(
+        &self,
+        data: &Self::Data,
+        options: &Self::Options<'_>,
+    ) -> Option<MyDataNestedValueWrapper> {
+        use ::shrinkwrap::__private::{ToNestWith, WrapDataWith};
+        let nest_data: Option<MyDataNestedValue> = data.to_nest_with(self, options);
+        Option::<MyDataNestedValueWrapper>::wrap_data_with(nest_data, self, options)
+    }
+}
+#[automatically_derived]
+#[doc(hidden)]
+impl ::shrinkwrap::__private::TransformToNest2<Option<MyDataNestedValueTextWrapper>>
+for MyTransform {
+    type Data = MyDataNestedValue;
+    fn transform_to_nest(
+        &self,
+        data: &Self::Data,
+        options: &Self::Options<'_>,
+    ) -> Option<MyDataNestedValueTextWrapper> {
+        use ::shrinkwrap::__private::{ToNestWith, WrapDataWith};
+        let nest_data: Option<MyDataNestedValueText> = data.to_nest_with(self, options);
+        Option::<MyDataNestedValueTextWrapper>::wrap_data_with(nest_data, self, options)
+    }
+}
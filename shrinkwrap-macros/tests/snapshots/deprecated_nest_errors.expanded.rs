@@ -0,0 +1,12 @@
+::core::compile_error! {
+    "Nest cannot deprecate itself"
+}
+::core::compile_error! {
+    "`deprecates` cannot be used on root nests with `extra(exclusive)`"
+}
+::core::compile_error! {
+    "`gate_deprecated` requires nest `value_text` to be optional"
+}
+::core::compile_error! {
+    "`deprecates` cannot be used on root nests with `extra(exclusive)`"
+}
@@ -0,0 +1,9 @@
+::core::compile_error! {
+    "Nest with id `missing` does not exist, yet is referenced here"
+}
+::core::compile_error! {
+    "Nest `text` is already deprecated by nest `text_v2`"
+}
+::core::compile_error! {
+    "Nest `value_text` can only deprecate sibling nests (nests with the same `chain_from`)"
+}
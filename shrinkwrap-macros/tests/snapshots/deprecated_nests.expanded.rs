@@ -0,0 +1,282 @@
+const _: () = {
+    type AssertedTransform = MyTransform;
+    fn assert_transform<T: ?Sized + ::shrinkwrap::__private::ImplementsTransform>() {}
+    let _ = assert_transform::<AssertedTransform>;
+};
+#[automatically_derived]
+#[derive(
+    ::std::fmt::Debug,
+    ::std::clone::Clone,
+    ::serde::Serialize,
+    ::schemars::JsonSchema
+)]
+pub struct MyDataWrapper {
+    pub extra: MyDataExtra,
+    #[serde(flatten)]
+    pub data: MyData,
+}
+#[automatically_derived]
+#[derive(
+    ::std::fmt::Debug,
+    ::std::clone::Clone,
+    ::serde::Serialize,
+    ::schemars::JsonSchema
+)]
+#[serde(rename_all = "camelCase")]
+pub struct MyDataExtra {
+    #[schemars(extend("deprecated" = true, "x-superseded-by" = "textV2"))]
+    pub text: Option<MyDataNestedText>,
+    #[schemars(extend("x-supersedes" = "text"))]
+    pub text_v2: MyDataNestedTextV2,
+    pub value: MyDataNestedValueWrapper,
+}
+#[automatically_derived]
+#[derive(
+    ::std::fmt::Debug,
+    ::std::clone::Clone,
+    ::serde::Serialize,
+    ::schemars::JsonSchema
+)]
+#[serde(rename_all = "camelCase")]
+pub struct MyDataNestedText {
+    pub account_balance: String,
+}
+#[automatically_derived]
+#[derive(
+    ::std::fmt::Debug,
+    ::std::clone::Clone,
+    ::serde::Serialize,
+    ::schemars::JsonSchema
+)]
+#[serde(rename_all = "camelCase")]
+pub struct MyDataNestedTextV2 {
+    pub account_balance: String,
+}
+#[automatically_derived]
+#[derive(
+    ::std::fmt::Debug,
+    ::std::clone::Clone,
+    ::serde::Serialize,
+    ::schemars::JsonSchema
+)]
+pub struct MyDataNestedValueWrapper {
+    pub extra: MyDataNestedValueExtra,
+    #[serde(flatten)]
+    pub data: MyDataNestedValue,
+}
+#[automatically_derived]
+#[derive(
+    ::std::fmt::Debug,
+    ::std::clone::Clone,
+    ::serde::Serialize,
+    ::schemars::JsonSchema
+)]
+#[serde(rename_all = "camelCase")]
+pub struct MyDataNestedValue {
+    pub account_balance: f64,
+}
+#[automatically_derived]
+#[derive(
+    ::std::fmt::Debug,
+    ::std::clone::Clone,
+    ::serde::Serialize,
+    ::schemars::JsonSchema
+)]
+#[serde(rename_all = "camelCase")]
+pub struct MyDataNestedValueExtra {
+    #[schemars(extend("deprecated" = true, "x-superseded-by" = "textV2"))]
+    pub text: MyDataNestedValueText,
+    #[schemars(extend("x-supersedes" = "text"))]
+    pub text_v2: MyDataNestedValueTextV2,
+}
+#[automatically_derived]
+#[derive(
+    ::std::fmt::Debug,
+    ::std::clone::Clone,
+    ::serde::Serialize,
+    ::schemars::JsonSchema
+)]
+#[serde(rename_all = "camelCase")]
+pub struct MyDataNestedValueText {
+    pub account_balance: String,
+}
+#[automatically_derived]
+#[derive(
+    ::std::fmt::Debug,
+    ::std::clone::Clone,
+    ::serde::Serialize,
+    ::schemars::JsonSchema
+)]
+#[serde(rename_all = "camelCase")]
+pub struct MyDataNestedValueTextV2 {
+    pub account_balance: String,
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::ToWrappedWith<T> for MyData
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TransformToNest2<
+        Option<MyDataNestedText>,
+        Data = MyData,
+    >,
+    T: ::shrinkwrap::__private::TransformToNest2<MyDataNestedTextV2, Data = MyData>,
+    T: ::shrinkwrap::__private::TransformToNest2<
+        MyDataNestedValueWrapper,
+        Data = MyData,
+    >,
+    for<'options> <T as ::shrinkwrap::__private::Transform2>::Options<
+        'options,
+    >: ::shrinkwrap::__private::DeprecatedNestGate,
+{
+    type Wrapper = MyDataWrapper;
+    fn to_wrapped_with(
+        self,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        let nest_text = if ::shrinkwrap::__private::DeprecatedNestGate::emit_deprecated(
+            options,
+            "text",
+        ) {
+            transform.transform_to_nest(&self, options)
+        } else {
+            ::std::option::Option::None
+        };
+        let nest_text_v2 = transform.transform_to_nest(&self, options);
+        let nest_value = transform.transform_to_nest(&self, options);
+        let extra = MyDataExtra {
+            text: nest_text,
+            text_v2: nest_text_v2,
+            value: nest_value,
+        };
+        Self::Wrapper { extra, data: self }
+    }
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::WrapAllWith<T> for MyData
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TransformToNest2<
+        Option<MyDataNestedText>,
+        Data = MyData,
+    >,
+    T: ::shrinkwrap::__private::TransformToNest2<MyDataNestedTextV2, Data = MyData>,
+    T: ::shrinkwrap::__private::TransformToNest2<
+        MyDataNestedValueWrapper,
+        Data = MyData,
+    >,
+    for<'options> <T as ::shrinkwrap::__private::Transform2>::Options<
+        'options,
+    >: ::shrinkwrap::__private::DeprecatedNestGate,
+{
+    fn wrap_all_with(
+        data: Vec<Self>,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Vec<Self::Wrapper> {
+        let wrappers = data
+            .into_iter()
+            .map(|data| {
+                let nest_text = if ::shrinkwrap::__private::DeprecatedNestGate::emit_deprecated(
+                    options,
+                    "text",
+                ) {
+                    transform.transform_to_nest(&data, options)
+                } else {
+                    ::std::option::Option::None
+                };
+                let nest_text_v2 = transform.transform_to_nest(&data, options);
+                let nest_value = transform.transform_to_nest(&data, options);
+                let extra = MyDataExtra {
+                    text: nest_text,
+                    text_v2: nest_text_v2,
+                    value: nest_value,
+                };
+                Self::Wrapper { extra, data }
+            });
+        wrappers.collect()
+    }
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::ToWrappedWith<MyTransform> for Option<MyData> {
+    type Wrapper = Option<MyDataWrapper>;
+    fn to_wrapped_with(
+        self,
+        transform: &MyTransform,
+        options: &<MyTransform as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        self.map(|data| data.to_wrapped_with(transform, options))
+    }
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::ToWrappedWith<T> for MyDataNestedValue
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TransformToNest2<
+        MyDataNestedValueText,
+        Data = MyDataNestedValue,
+    >,
+    T: ::shrinkwrap::__private::TransformToNest2<
+        MyDataNestedValueTextV2,
+        Data = MyDataNestedValue,
+    >,
+{
+    type Wrapper = MyDataNestedValueWrapper;
+    fn to_wrapped_with(
+        self,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        let nest_text = transform.transform_to_nest(&self, options);
+        let nest_text_v2 = transform.transform_to_nest(&self, options);
+        let extra = MyDataNestedValueExtra {
+            text: nest_text,
+            text_v2: nest_text_v2,
+        };
+        Self::Wrapper { extra, data: self }
+    }
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::ToWrappedWith<MyTransform> for Option<MyDataNestedValue> {
+    type Wrapper = Option<MyDataNestedValueWrapper>;
+    fn to_wrapped_with(
+        self,
+        transform: &MyTransform,
+        options: &<MyTransform as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        self.map(|data| data.to_wrapped_with(transform, options))
+    }
+}
+#[automatically_derived]
+#[doc(hidden)]
+impl ::shrinkwrap::__private::TransformToNest2<MyDataNestedValueWrapper>
+for MyTransform {
+    type Data = MyData;
+    fn transform_to_nest(
+        &self,
+        data: &Self::Data,
+        options: &Self::Options<'_>,
+    ) -> MyDataNestedValueWrapper {
+        use ::shrinkwrap::__private::{ToNestWith, WrapDataWith};
+        let nest_data: MyDataNestedValue = data.to_nest_with(self, options);
+        MyDataNestedValueWrapper::wrap_data_with(nest_data, self, options)
+    }
+}
+#[automatically_derived]
+#[allow(private_interfaces)]
+impl MyDataExtra {
+    ///Returns the `text` nest
+    #[deprecated(note = "superseded by `text_v2`")]
+    pub fn text(&self) -> Option<&MyDataNestedText> {
+        self.text.as_ref()
+    }
+}
+#[automatically_derived]
+#[allow(private_interfaces)]
+impl MyDataNestedValueExtra {
+    ///Returns the `text` nest
+    #[deprecated(note = "superseded by `text_v2`")]
+    pub fn text(&self) -> &MyDataNestedValueText {
+        &self.text
+    }
+}
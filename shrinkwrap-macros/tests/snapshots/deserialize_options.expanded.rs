@@ -0,0 +1,187 @@
+const _: () = {
+    type AssertedTransform = MyTransform;
+    fn assert_transform<T: ?Sized + ::shrinkwrap::__private::ImplementsTransform>() {}
+    let _ = assert_transform::<AssertedTransform>;
+};
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataWrapper {
+    #[serde(default)]
+    pub extra: MyDataExtra,
+    #[serde(flatten)]
+    pub data: MyData,
+}
+#[automatically_derived]
+#[derive(
+    ::std::fmt::Debug,
+    ::std::clone::Clone,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::std::default::Default
+)]
+#[serde(deny_unknown_fields)]
+pub struct MyDataExtra {
+    #[serde(default)]
+    pub text: Option<MyDataNestedText>,
+}
+#[automatically_derived]
+#[derive(
+    ::std::fmt::Debug,
+    ::std::clone::Clone,
+    ::serde::Serialize,
+    ::serde::Deserialize
+)]
+#[serde(deny_unknown_fields)]
+pub struct MyDataNestedText {
+    pub balance: String,
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::ToWrappedWith<T> for MyData
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TransformToNest2<
+        Option<MyDataNestedText>,
+        Data = MyData,
+    >,
+{
+    type Wrapper = MyDataWrapper;
+    fn to_wrapped_with(
+        self,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        let nest_text = transform.transform_to_nest(&self, options);
+        let extra = MyDataExtra { text: nest_text };
+        Self::Wrapper { extra, data: self }
+    }
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::WrapAllWith<T> for MyData
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TransformToNest2<
+        Option<MyDataNestedText>,
+        Data = MyData,
+    >,
+{
+    fn wrap_all_with(
+        data: Vec<Self>,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Vec<Self::Wrapper> {
+        let wrappers = data
+            .into_iter()
+            .map(|data| {
+                let nest_text = transform.transform_to_nest(&data, options);
+                let extra = MyDataExtra { text: nest_text };
+                Self::Wrapper { extra, data }
+            });
+        wrappers.collect()
+    }
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::ToWrappedWith<MyTransform> for Option<MyData> {
+    type Wrapper = Option<MyDataWrapper>;
+    fn to_wrapped_with(
+        self,
+        transform: &MyTransform,
+        options: &<MyTransform as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        self.map(|data| data.to_wrapped_with(transform, options))
+    }
+}
+#[automatically_derived]
+impl<'de> ::serde::Deserialize<'de> for MyDataWrapper {
+    fn deserialize<__D>(deserializer: __D) -> ::std::result::Result<Self, __D::Error>
+    where
+        __D: ::serde::Deserializer<'de>,
+    {
+        struct __Visitor;
+        impl<'de> ::serde::de::Visitor<'de> for __Visitor {
+            type Value = MyDataWrapper;
+            fn expecting(
+                &self,
+                formatter: &mut ::std::fmt::Formatter,
+            ) -> ::std::fmt::Result {
+                formatter.write_str("struct MyDataWrapper")
+            }
+            fn visit_seq<__A>(
+                self,
+                mut seq: __A,
+            ) -> ::std::result::Result<Self::Value, __A::Error>
+            where
+                __A: ::serde::de::SeqAccess<'de>,
+            {
+                let __field_extra = seq
+                    .next_element::<MyDataExtra>()?
+                    .ok_or_else(|| ::serde::de::Error::invalid_length(0usize, &self))?;
+                let __field_balance = seq
+                    .next_element::<i64>()?
+                    .ok_or_else(|| ::serde::de::Error::invalid_length(1usize, &self))?;
+                ::std::result::Result::Ok(MyDataWrapper {
+                    extra: __field_extra,
+                    data: MyData { balance: __field_balance },
+                })
+            }
+            fn visit_map<__A>(
+                self,
+                mut map: __A,
+            ) -> ::std::result::Result<Self::Value, __A::Error>
+            where
+                __A: ::serde::de::MapAccess<'de>,
+            {
+                let mut __field_extra: ::std::option::Option<MyDataExtra> = ::std::option::Option::None;
+                let mut __field_balance: ::std::option::Option<i64> = ::std::option::Option::None;
+                while let ::std::option::Option::Some(key) = map
+                    .next_key::<::std::string::String>()?
+                {
+                    match key.as_str() {
+                        "extra" => {
+                            if __field_extra.is_some() {
+                                return ::std::result::Result::Err(
+                                    ::serde::de::Error::duplicate_field("extra"),
+                                );
+                            }
+                            __field_extra = ::std::option::Option::Some(
+                                map.next_value()?,
+                            );
+                        }
+                        "balance" => {
+                            if __field_balance.is_some() {
+                                return ::std::result::Result::Err(
+                                    ::serde::de::Error::duplicate_field("balance"),
+                                );
+                            }
+                            __field_balance = ::std::option::Option::Some(
+                                map.next_value()?,
+                            );
+                        }
+                        _ => {
+                            return ::std::result::Result::Err(
+                                ::serde::de::Error::unknown_field(&key, FIELDS),
+                            );
+                        }
+                    }
+                }
+                let __field_extra = match __field_extra {
+                    ::std::option::Option::Some(value) => value,
+                    ::std::option::Option::None => ::std::default::Default::default(),
+                };
+                let __field_balance = match __field_balance {
+                    ::std::option::Option::Some(value) => value,
+                    ::std::option::Option::None => {
+                        return ::std::result::Result::Err(
+                            ::serde::de::Error::missing_field("balance"),
+                        );
+                    }
+                };
+                ::std::result::Result::Ok(MyDataWrapper {
+                    extra: __field_extra,
+                    data: MyData { balance: __field_balance },
+                })
+            }
+        }
+        const FIELDS: &[&str] = &["extra", "balance"];
+        deserializer.deserialize_struct("MyDataWrapper", FIELDS, __Visitor)
+    }
+}
@@ -0,0 +1,172 @@
+const _: () = {
+    type AssertedTransform = MyTransform;
+    fn assert_transform<T: ?Sized + ::shrinkwrap::__private::ImplementsTransform>() {}
+    let _ = assert_transform::<AssertedTransform>;
+};
+#[doc(hidden)]
+pub mod __shrinkwrap_gen_my_data {
+    #[allow(unused_imports)]
+    use super::*;
+    #[automatically_derived]
+    #[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+    pub struct MyDataWrapper {
+        pub extra: MyDataExtra,
+        #[serde(flatten)]
+        pub data: MyData,
+    }
+    #[automatically_derived]
+    #[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+    pub struct MyDataExtra {
+        pub text: MyDataNestedText,
+        pub value: Option<MyDataNestedValueWrapper>,
+    }
+    #[automatically_derived]
+    ///Text variants
+    #[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+    pub struct MyDataNestedText {
+        pub balance: String,
+    }
+    #[automatically_derived]
+    #[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+    pub struct MyDataNestedValueWrapper {
+        pub extra: MyDataNestedValueExtra,
+        #[serde(flatten)]
+        pub data: MyDataNestedValue,
+    }
+    #[automatically_derived]
+    #[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+    pub struct MyDataNestedValue {
+        pub balance: f64,
+    }
+    #[automatically_derived]
+    #[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+    pub struct MyDataNestedValueExtra {
+        pub value_text: MyDataNestedValueValueText,
+    }
+    #[automatically_derived]
+    #[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+    pub struct MyDataNestedValueValueText {
+        pub balance: String,
+    }
+}
+pub type MyDataWrapper = __shrinkwrap_gen_my_data::MyDataWrapper;
+pub type MyDataExtra = __shrinkwrap_gen_my_data::MyDataExtra;
+///Text variants
+pub type MyDataNestedText = __shrinkwrap_gen_my_data::MyDataNestedText;
+pub type MyDataNestedValueWrapper = __shrinkwrap_gen_my_data::MyDataNestedValueWrapper;
+pub type MyDataNestedValue = __shrinkwrap_gen_my_data::MyDataNestedValue;
+pub type MyDataNestedValueExtra = __shrinkwrap_gen_my_data::MyDataNestedValueExtra;
+pub type MyDataNestedValueValueText = __shrinkwrap_gen_my_data::MyDataNestedValueValueText;
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::ToWrappedWith<T> for MyData
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TransformToNest2<MyDataNestedText, Data = MyData>,
+    T: ::shrinkwrap::__private::TransformToNest2<
+        Option<MyDataNestedValueWrapper>,
+        Data = MyData,
+    >,
+{
+    type Wrapper = MyDataWrapper;
+    fn to_wrapped_with(
+        self,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        let nest_text = transform.transform_to_nest(&self, options);
+        let nest_value = transform.transform_to_nest(&self, options);
+        let extra = MyDataExtra {
+            text: nest_text,
+            value: nest_value,
+        };
+        Self::Wrapper { extra, data: self }
+    }
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::WrapAllWith<T> for MyData
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TransformToNest2<MyDataNestedText, Data = MyData>,
+    T: ::shrinkwrap::__private::TransformToNest2<
+        Option<MyDataNestedValueWrapper>,
+        Data = MyData,
+    >,
+{
+    fn wrap_all_with(
+        data: Vec<Self>,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Vec<Self::Wrapper> {
+        let wrappers = data
+            .into_iter()
+            .map(|data| {
+                let nest_text = transform.transform_to_nest(&data, options);
+                let nest_value = transform.transform_to_nest(&data, options);
+                let extra = MyDataExtra {
+                    text: nest_text,
+                    value: nest_value,
+                };
+                Self::Wrapper { extra, data }
+            });
+        wrappers.collect()
+    }
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::ToWrappedWith<MyTransform> for Option<MyData> {
+    type Wrapper = Option<MyDataWrapper>;
+    fn to_wrapped_with(
+        self,
+        transform: &MyTransform,
+        options: &<MyTransform as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        self.map(|data| data.to_wrapped_with(transform, options))
+    }
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::ToWrappedWith<T> for MyDataNestedValue
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TransformToNest2<
+        MyDataNestedValueValueText,
+        Data = MyDataNestedValue,
+    >,
+{
+    type Wrapper = MyDataNestedValueWrapper;
+    fn to_wrapped_with(
+        self,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        let nest_value_text = transform.transform_to_nest(&self, options);
+        let extra = MyDataNestedValueExtra {
+            value_text: nest_value_text,
+        };
+        Self::Wrapper { extra, data: self }
+    }
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::ToWrappedWith<MyTransform> for Option<MyDataNestedValue> {
+    type Wrapper = Option<MyDataNestedValueWrapper>;
+    fn to_wrapped_with(
+        self,
+        transform: &MyTransform,
+        options: &<MyTransform as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        self.map(|data| data.to_wrapped_with(transform, options))
+    }
+}
+#[automatically_derived]
+#[doc(hidden)]
+impl ::shrinkwrap::__private::TransformToNest2<Option<MyDataNestedValueWrapper>>
+for MyTransform {
+    type Data = MyData;
+    fn transform_to_nest(
+        &self,
+        data: &Self::Data,
+        options: &Self::Options<'_>,
+    ) -> Option<MyDataNestedValueWrapper> {
+        use ::shrinkwrap::__private::{ToNestWith, WrapDataWith};
+        let nest_data: Option<MyDataNestedValue> = data.to_nest_with(self, options);
+        Option::<MyDataNestedValueWrapper>::wrap_data_with(nest_data, self, options)
+    }
+}
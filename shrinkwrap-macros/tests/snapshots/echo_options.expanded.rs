@@ -0,0 +1,144 @@
+const _: () = {
+    type AssertedTransform = MyTransform;
+    fn assert_transform<T: ?Sized + ::shrinkwrap::__private::ImplementsTransform>() {}
+    let _ = assert_transform::<AssertedTransform>;
+};
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataWrapper {
+    pub extra: MyDataExtra,
+    #[serde(flatten)]
+    pub data: MyData,
+    /// Subset of the transform options used to build the wrapper
+    pub options: <<MyTransform as ::shrinkwrap::__private::Transform2>::Options<
+        'static,
+    > as ::shrinkwrap::__private::SerializableOptions>::Echo,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataExtra {
+    pub text: Option<MyDataNestedText>,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedText {
+    pub balance: String,
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::ToWrappedWith<T> for MyData
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TransformToNest2<
+        Option<MyDataNestedText>,
+        Data = MyData,
+    >,
+    for<'options> <T as ::shrinkwrap::__private::Transform2>::Options<
+        'options,
+    >: ::shrinkwrap::__private::SerializableOptions<
+        Echo = <<MyTransform as ::shrinkwrap::__private::Transform2>::Options<
+            'static,
+        > as ::shrinkwrap::__private::SerializableOptions>::Echo,
+    >,
+{
+    type Wrapper = MyDataWrapper;
+    fn to_wrapped_with(
+        self,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        let nest_text = transform.transform_to_nest(&self, options);
+        let extra = MyDataExtra { text: nest_text };
+        Self::Wrapper {
+            options: ::shrinkwrap::__private::SerializableOptions::echo_options(options),
+            extra,
+            data: self,
+        }
+    }
+}
+#[automatically_derived]
+#[allow(private_interfaces)]
+impl MyDataWrapper {
+    /// Recomputes the `extra` struct in place from the wrapped data
+    pub fn enrich_with<T>(
+        &mut self,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    )
+    where
+        T: ::shrinkwrap::__private::Transform2,
+        T: ::shrinkwrap::__private::TransformToNest2<
+            Option<MyDataNestedText>,
+            Data = MyData,
+        >,
+        for<'options> <T as ::shrinkwrap::__private::Transform2>::Options<
+            'options,
+        >: ::shrinkwrap::__private::SerializableOptions<
+            Echo = <<MyTransform as ::shrinkwrap::__private::Transform2>::Options<
+                'static,
+            > as ::shrinkwrap::__private::SerializableOptions>::Echo,
+        >,
+    {
+        let nest_text = transform.transform_to_nest(&self.data, options);
+        self.extra = MyDataExtra { text: nest_text };
+        self.options = ::shrinkwrap::__private::SerializableOptions::echo_options(
+            options,
+        );
+    }
+    /// Wraps the data without any nests, to be populated later via [`enrich_with`](Self::enrich_with)
+    pub fn with_empty_extra(data: MyData) -> Self {
+        let extra = MyDataExtra { text: None };
+        Self {
+            options: ::std::default::Default::default(),
+            extra,
+            data,
+        }
+    }
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::WrapAllWith<T> for MyData
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TransformToNest2<
+        Option<MyDataNestedText>,
+        Data = MyData,
+    >,
+    for<'options> <T as ::shrinkwrap::__private::Transform2>::Options<
+        'options,
+    >: ::shrinkwrap::__private::SerializableOptions<
+        Echo = <<MyTransform as ::shrinkwrap::__private::Transform2>::Options<
+            'static,
+        > as ::shrinkwrap::__private::SerializableOptions>::Echo,
+    >,
+{
+    fn wrap_all_with(
+        data: Vec<Self>,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Vec<Self::Wrapper> {
+        let wrappers = data
+            .into_iter()
+            .map(|data| {
+                let nest_text = transform.transform_to_nest(&data, options);
+                let extra = MyDataExtra { text: nest_text };
+                Self::Wrapper {
+                    options: ::shrinkwrap::__private::SerializableOptions::echo_options(
+                        options,
+                    ),
+                    extra,
+                    data,
+                }
+            });
+        wrappers.collect()
+    }
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::ToWrappedWith<MyTransform> for Option<MyData> {
+    type Wrapper = Option<MyDataWrapper>;
+    fn to_wrapped_with(
+        self,
+        transform: &MyTransform,
+        options: &<MyTransform as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        self.map(|data| data.to_wrapped_with(transform, options))
+    }
+}
@@ -0,0 +1,6 @@
+::core::compile_error! {
+    "echo_options is not supported with sort_keys, binary_safe, integrity or deny_unknown_fields, serde impls are generated for the wrapper"
+}
+::core::compile_error! {
+    "`data` is already used by the wrapper"
+}
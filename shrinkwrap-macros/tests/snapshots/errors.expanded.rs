@@ -0,0 +1,6 @@
+::core::compile_error! {
+    "Either `field_type`, `derive_to_nest`, `provider`, or `identity` must be configured"
+}
+::core::compile_error! {
+    "Nest `missing` is not defined"
+}
@@ -0,0 +1,200 @@
+const _: () = {
+    type AssertedTransform = MyTransform;
+    fn assert_transform<T: ?Sized + ::shrinkwrap::__private::ImplementsTransform>() {}
+    let _ = assert_transform::<AssertedTransform>;
+};
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataWrapper {
+    pub extra: MyDataExtra,
+    #[serde(flatten)]
+    pub data: MyData,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataExtra {
+    pub raw: MyDataNestedRaw,
+    pub value: MyDataNestedValue,
+    pub cents: MyDataNestedCentsWrapper,
+    pub value_text: MyDataNestedValueValueText,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedRaw {
+    pub balance: i64,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedValue {
+    pub balance: f64,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedCentsWrapper {
+    pub extra: MyDataNestedCentsExtra,
+    #[serde(flatten)]
+    pub data: MyDataNestedCents,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedCents {
+    pub balance: i64,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedCentsExtra {
+    pub label: MyDataNestedCentsLabel,
+    pub debug: MyDataNestedCentsDebug,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedCentsLabel {
+    pub balance: String,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedCentsDebug {
+    pub balance: String,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedValueValueText {
+    pub balance: String,
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::ToWrappedWith<T> for MyData
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TransformToNest2<MyDataNestedRaw, Data = MyData>,
+    T: ::shrinkwrap::__private::TransformToNest2<MyDataNestedValue, Data = MyData>,
+    T: ::shrinkwrap::__private::TransformToNest2<
+        MyDataNestedCentsWrapper,
+        Data = MyData,
+    >,
+    T: ::shrinkwrap::__private::TransformToNest2<
+        MyDataNestedValueValueText,
+        Data = MyDataNestedValue,
+    >,
+{
+    type Wrapper = MyDataWrapper;
+    fn to_wrapped_with(
+        self,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        let nest_raw = transform.transform_to_nest(&self, options);
+        let nest_value = transform.transform_to_nest(&self, options);
+        let nest_cents = transform.transform_to_nest(&self, options);
+        let nest_value_text = transform.transform_to_nest(&nest_value, options);
+        let extra = MyDataExtra {
+            raw: nest_raw,
+            value: nest_value,
+            cents: nest_cents,
+            value_text: nest_value_text,
+        };
+        Self::Wrapper { extra, data: self }
+    }
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::WrapAllWith<T> for MyData
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TransformToNest2<MyDataNestedRaw, Data = MyData>,
+    T: ::shrinkwrap::__private::TransformToNest2<MyDataNestedValue, Data = MyData>,
+    T: ::shrinkwrap::__private::TransformToNest2<
+        MyDataNestedCentsWrapper,
+        Data = MyData,
+    >,
+    T: ::shrinkwrap::__private::TransformToNest2<
+        MyDataNestedValueValueText,
+        Data = MyDataNestedValue,
+    >,
+{
+    fn wrap_all_with(
+        data: Vec<Self>,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Vec<Self::Wrapper> {
+        let wrappers = data
+            .into_iter()
+            .map(|data| {
+                let nest_raw = transform.transform_to_nest(&data, options);
+                let nest_value = transform.transform_to_nest(&data, options);
+                let nest_cents = transform.transform_to_nest(&data, options);
+                let nest_value_text = transform.transform_to_nest(&nest_value, options);
+                let extra = MyDataExtra {
+                    raw: nest_raw,
+                    value: nest_value,
+                    cents: nest_cents,
+                    value_text: nest_value_text,
+                };
+                Self::Wrapper { extra, data }
+            });
+        wrappers.collect()
+    }
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::ToWrappedWith<MyTransform> for Option<MyData> {
+    type Wrapper = Option<MyDataWrapper>;
+    fn to_wrapped_with(
+        self,
+        transform: &MyTransform,
+        options: &<MyTransform as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        self.map(|data| data.to_wrapped_with(transform, options))
+    }
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::ToWrappedWith<T> for MyDataNestedCents
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TransformToNest2<
+        MyDataNestedCentsLabel,
+        Data = MyDataNestedCents,
+    >,
+    T: ::shrinkwrap::__private::TransformToNest2<
+        MyDataNestedCentsDebug,
+        Data = MyDataNestedCents,
+    >,
+{
+    type Wrapper = MyDataNestedCentsWrapper;
+    fn to_wrapped_with(
+        self,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        let nest_label = transform.transform_to_nest(&self, options);
+        let nest_debug = transform.transform_to_nest(&self, options);
+        let extra = MyDataNestedCentsExtra {
+            label: nest_label,
+            debug: nest_debug,
+        };
+        Self::Wrapper { extra, data: self }
+    }
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::ToWrappedWith<MyTransform> for Option<MyDataNestedCents> {
+    type Wrapper = Option<MyDataNestedCentsWrapper>;
+    fn to_wrapped_with(
+        self,
+        transform: &MyTransform,
+        options: &<MyTransform as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        self.map(|data| data.to_wrapped_with(transform, options))
+    }
+}
+#[automatically_derived]
+#[doc(hidden)]
+impl ::shrinkwrap::__private::TransformToNest2<MyDataNestedCentsWrapper>
+for MyTransform {
+    type Data = MyData;
+    fn transform_to_nest(
+        &self,
+        data: &Self::Data,
+        options: &Self::Options<'_>,
+    ) -> MyDataNestedCentsWrapper {
+        use ::shrinkwrap::__private::{ToNestWith, WrapDataWith};
+        let nest_data: MyDataNestedCents = data.to_nest_with(self, options);
+        MyDataNestedCentsWrapper::wrap_data_with(nest_data, self, options)
+    }
+}
@@ -0,0 +1,110 @@
+const _: () = {
+    type AssertedTransform = MyTransform;
+    fn assert_transform<T: ?Sized + ::shrinkwrap::__private::ImplementsTransform>() {}
+    let _ = assert_transform::<AssertedTransform>;
+};
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataWrapper {
+    pub extra: MyDataExtra,
+    #[serde(flatten)]
+    pub data: MyData,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataExtra {
+    pub text: MyDataNestedText,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedText {
+    pub balance: String,
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::ToWrappedWith<T> for MyData
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TransformToNest2<MyDataNestedText, Data = MyData>,
+{
+    type Wrapper = MyDataWrapper;
+    fn to_wrapped_with(
+        self,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        let nest_text = transform.transform_to_nest(&self, options);
+        let extra = MyDataExtra { text: nest_text };
+        Self::Wrapper { extra, data: self }
+    }
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::WrapAllWith<T> for MyData
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TransformToNest2<MyDataNestedText, Data = MyData>,
+{
+    fn wrap_all_with(
+        data: Vec<Self>,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Vec<Self::Wrapper> {
+        let wrappers = data
+            .into_iter()
+            .map(|data| {
+                let nest_text = transform.transform_to_nest(&data, options);
+                let extra = MyDataExtra { text: nest_text };
+                Self::Wrapper { extra, data }
+            });
+        wrappers.collect()
+    }
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::ToWrappedWith<MyTransform> for Option<MyData> {
+    type Wrapper = Option<MyDataWrapper>;
+    fn to_wrapped_with(
+        self,
+        transform: &MyTransform,
+        options: &<MyTransform as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        self.map(|data| data.to_wrapped_with(transform, options))
+    }
+}
+#[automatically_derived]
+/// `#[repr(C)]` envelope of [`MyDataWrapper`] for FFI boundaries, the extra struct is held behind an owned opaque pointer
+#[repr(C)]
+pub struct MyDataWrapperFfi {
+    /// The wrapped data
+    pub data: MyData,
+    /// Non-null, owned pointer to the extra struct, opaque to foreign code
+    extra: ::std::boxed::Box<MyDataExtra>,
+}
+#[automatically_derived]
+#[allow(private_interfaces)]
+impl MyDataWrapperFfi {
+    /// Returns the extra struct
+    pub fn extra(&self) -> &MyDataExtra {
+        &self.extra
+    }
+    /// Converts the envelope back into the serializable wrapper
+    pub fn into_wrapper(self) -> MyDataWrapper {
+        MyDataWrapper {
+            data: self.data,
+            extra: *self.extra,
+        }
+    }
+}
+#[automatically_derived]
+impl ::std::convert::From<MyDataWrapper> for MyDataWrapperFfi {
+    fn from(wrapper: MyDataWrapper) -> Self {
+        Self {
+            data: wrapper.data,
+            extra: ::std::boxed::Box::new(wrapper.extra),
+        }
+    }
+}
+#[automatically_derived]
+impl ::std::convert::From<MyDataWrapperFfi> for MyDataWrapper {
+    fn from(envelope: MyDataWrapperFfi) -> Self {
+        envelope.into_wrapper()
+    }
+}
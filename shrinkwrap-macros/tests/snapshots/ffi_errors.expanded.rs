@@ -0,0 +1,6 @@
+::core::compile_error! {
+    "`ffi` requires the data struct to be `#[repr(C)]` (or `#[repr(transparent)]`)"
+}
+::core::compile_error! {
+    "computed fields are not supported with `ffi`"
+}
@@ -0,0 +1,105 @@
+const _: () = {
+    type AssertedTransform = MyTransform;
+    fn assert_transform<T: ?Sized + ::shrinkwrap::__private::ImplementsTransform>() {}
+    let _ = assert_transform::<AssertedTransform>;
+};
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataWrapper {
+    pub extra: MyDataExtra,
+    #[serde(flatten)]
+    pub data: MyData,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataExtra {
+    pub labels: Option<MyDataNestedLabels>,
+    pub assoc: MyDataNestedAssoc,
+    pub prices: MyDataNestedPrices,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedLabels {
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub balance: HashMap<String, Vec<String>>,
+    #[serde(skip_serializing_if = "::std::option::Option::is_none")]
+    pub owner: ::std::option::Option<std::collections::BTreeMap<String, String>>,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedAssoc {
+    pub balance: <Vec<u8> as IntoIterator>::Item,
+    pub owner: <Vec<u8> as IntoIterator>::Item,
+}
+pub type MyDataNestedPrices = ::std::collections::BTreeMap<Box<str>, Vec<f64>>;
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::ToWrappedWith<T> for MyData
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TransformToNest2<
+        Option<MyDataNestedLabels>,
+        Data = MyData,
+    >,
+    T: ::shrinkwrap::__private::TransformToNest2<MyDataNestedAssoc, Data = MyData>,
+    T: ::shrinkwrap::__private::TransformToNest2<MyDataNestedPrices, Data = MyData>,
+{
+    type Wrapper = MyDataWrapper;
+    fn to_wrapped_with(
+        self,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        let nest_labels = transform.transform_to_nest(&self, options);
+        let nest_assoc = transform.transform_to_nest(&self, options);
+        let nest_prices = transform.transform_to_nest(&self, options);
+        let extra = MyDataExtra {
+            labels: nest_labels,
+            assoc: nest_assoc,
+            prices: nest_prices,
+        };
+        Self::Wrapper { extra, data: self }
+    }
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::WrapAllWith<T> for MyData
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TransformToNest2<
+        Option<MyDataNestedLabels>,
+        Data = MyData,
+    >,
+    T: ::shrinkwrap::__private::TransformToNest2<MyDataNestedAssoc, Data = MyData>,
+    T: ::shrinkwrap::__private::TransformToNest2<MyDataNestedPrices, Data = MyData>,
+{
+    fn wrap_all_with(
+        data: Vec<Self>,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Vec<Self::Wrapper> {
+        let wrappers = data
+            .into_iter()
+            .map(|data| {
+                let nest_labels = transform.transform_to_nest(&data, options);
+                let nest_assoc = transform.transform_to_nest(&data, options);
+                let nest_prices = transform.transform_to_nest(&data, options);
+                let extra = MyDataExtra {
+                    labels: nest_labels,
+                    assoc: nest_assoc,
+                    prices: nest_prices,
+                };
+                Self::Wrapper { extra, data }
+            });
+        wrappers.collect()
+    }
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::ToWrappedWith<MyTransform> for Option<MyData> {
+    type Wrapper = Option<MyDataWrapper>;
+    fn to_wrapped_with(
+        self,
+        transform: &MyTransform,
+        options: &<MyTransform as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        self.map(|data| data.to_wrapped_with(transform, options))
+    }
+}
@@ -0,0 +1,129 @@
+const _: () = {
+    type AssertedTransform = MyTransform;
+    fn assert_transform<T: ?Sized + ::shrinkwrap::__private::ImplementsTransform>() {}
+    let _ = assert_transform::<AssertedTransform>;
+};
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataWrapper {
+    pub extra: MyDataExtra,
+    #[serde(flatten)]
+    pub data: MyData,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataExtra {
+    pub text: MyDataNestedText,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedText {
+    pub balance: String,
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::ToWrappedWith<T> for MyData
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TransformToNest2<MyDataNestedText, Data = MyData>,
+{
+    type Wrapper = MyDataWrapper;
+    fn to_wrapped_with(
+        self,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        let nest_text = transform.transform_to_nest(&self, options);
+        let extra = MyDataExtra { text: nest_text };
+        Self::Wrapper { extra, data: self }
+    }
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::WrapAllWith<T> for MyData
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TransformToNest2<MyDataNestedText, Data = MyData>,
+{
+    fn wrap_all_with(
+        data: Vec<Self>,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Vec<Self::Wrapper> {
+        let wrappers = data
+            .into_iter()
+            .map(|data| {
+                let nest_text = transform.transform_to_nest(&data, options);
+                let extra = MyDataExtra { text: nest_text };
+                Self::Wrapper { extra, data }
+            });
+        wrappers.collect()
+    }
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::ToWrappedWith<MyTransform> for Option<MyData> {
+    type Wrapper = Option<MyDataWrapper>;
+    fn to_wrapped_with(
+        self,
+        transform: &MyTransform,
+        options: &<MyTransform as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        self.map(|data| data.to_wrapped_with(transform, options))
+    }
+}
+#[automatically_derived]
+impl ::std::convert::TryFrom<&MyDataExtra>
+for ::shrinkwrap::__private::prost_types::Struct {
+    type Error = ::shrinkwrap::__private::Error;
+    fn try_from(extra: &MyDataExtra) -> ::std::result::Result<Self, Self::Error> {
+        fn to_value(
+            kind: ::shrinkwrap::__private::ProtoKind,
+        ) -> ::shrinkwrap::__private::prost_types::Value {
+            use ::shrinkwrap::__private::prost_types::value::Kind;
+            use ::shrinkwrap::__private::ProtoKind;
+            let kind = match kind {
+                ProtoKind::Null => {
+                    Kind::NullValue(
+                        ::shrinkwrap::__private::prost_types::NullValue::NullValue as i32,
+                    )
+                }
+                ProtoKind::Number(number) => Kind::NumberValue(number),
+                ProtoKind::String(string) => Kind::StringValue(string),
+                ProtoKind::Bool(boolean) => Kind::BoolValue(boolean),
+                ProtoKind::Struct(fields) => {
+                    Kind::StructValue(::shrinkwrap::__private::prost_types::Struct {
+                        fields: fields
+                            .into_iter()
+                            .map(|(key, value)| (key, to_value(value)))
+                            .collect(),
+                    })
+                }
+                ProtoKind::List(values) => {
+                    Kind::ListValue(::shrinkwrap::__private::prost_types::ListValue {
+                        values: values.into_iter().map(to_value).collect(),
+                    })
+                }
+            };
+            ::shrinkwrap::__private::prost_types::Value {
+                kind: ::std::option::Option::Some(kind),
+            }
+        }
+        let fields = ::shrinkwrap::__private::proto_fields(extra)?;
+        ::std::result::Result::Ok(Self {
+            fields: fields
+                .into_iter()
+                .map(|(key, value)| (key, to_value(value)))
+                .collect(),
+        })
+    }
+}
+#[automatically_derived]
+impl MyDataWrapper {
+    /// Converts the extra struct into a `google.protobuf.Struct`, e.g. for gRPC trailers/metadata
+    pub fn to_grpc_metadata(
+        &self,
+    ) -> ::std::result::Result<
+        ::shrinkwrap::__private::prost_types::Struct,
+        ::shrinkwrap::__private::Error,
+    > {
+        ::shrinkwrap::__private::prost_types::Struct::try_from(&self.extra)
+    }
+}
@@ -0,0 +1,3 @@
+::core::compile_error! {
+    "`grpc` cannot be used with `extra(layout = \"tagged_array\")`"
+}
@@ -0,0 +1,125 @@
+const _: () = {
+    type AssertedTransform = MyTransform;
+    fn assert_transform<T: ?Sized + ::shrinkwrap::__private::ImplementsTransform>() {}
+    let _ = assert_transform::<AssertedTransform>;
+};
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone)]
+pub struct MyDataWrapper {
+    pub extra: MyDataExtra,
+    pub data: MyData,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone)]
+pub struct MyDataExtra {
+    pub text: MyDataNestedText,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone)]
+pub struct MyDataNestedText {
+    pub balance: String,
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::ToWrappedWith<T> for MyData
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TransformToNest2<MyDataNestedText, Data = MyData>,
+{
+    type Wrapper = MyDataWrapper;
+    fn to_wrapped_with(
+        self,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        let nest_text = transform.transform_to_nest(&self, options);
+        let extra = MyDataExtra { text: nest_text };
+        Self::Wrapper { extra, data: self }
+    }
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::WrapAllWith<T> for MyData
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TransformToNest2<MyDataNestedText, Data = MyData>,
+{
+    fn wrap_all_with(
+        data: Vec<Self>,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Vec<Self::Wrapper> {
+        let wrappers = data
+            .into_iter()
+            .map(|data| {
+                let nest_text = transform.transform_to_nest(&data, options);
+                let extra = MyDataExtra { text: nest_text };
+                Self::Wrapper { extra, data }
+            });
+        wrappers.collect()
+    }
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::ToWrappedWith<MyTransform> for Option<MyData> {
+    type Wrapper = Option<MyDataWrapper>;
+    fn to_wrapped_with(
+        self,
+        transform: &MyTransform,
+        options: &<MyTransform as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        self.map(|data| data.to_wrapped_with(transform, options))
+    }
+}
+#[automatically_derived]
+impl ::serde::Serialize for MyDataWrapper {
+    fn serialize<__S>(
+        &self,
+        serializer: __S,
+    ) -> ::std::result::Result<__S::Ok, __S::Error>
+    where
+        __S: ::serde::Serializer,
+    {
+        use ::serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("MyDataWrapper", 3usize)?;
+        state.serialize_field("extra", &self.extra)?;
+        state.serialize_field("data", &self.data)?;
+        state
+            .serialize_field(
+                "checksum",
+                &::shrinkwrap::__private::integrity_signature(
+                        crate::signing_key().as_ref(),
+                        &self.data,
+                    )
+                    .map_err(<__S::Error as ::serde::ser::Error>::custom)?,
+            )?;
+        state.end()
+    }
+}
+#[automatically_derived]
+impl ::serde::Serialize for MyDataExtra {
+    fn serialize<__S>(
+        &self,
+        serializer: __S,
+    ) -> ::std::result::Result<__S::Ok, __S::Error>
+    where
+        __S: ::serde::Serializer,
+    {
+        use ::serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("MyDataExtra", 1usize)?;
+        state.serialize_field("text", &self.text)?;
+        state.end()
+    }
+}
+#[automatically_derived]
+impl ::serde::Serialize for MyDataNestedText {
+    fn serialize<__S>(
+        &self,
+        serializer: __S,
+    ) -> ::std::result::Result<__S::Ok, __S::Error>
+    where
+        __S: ::serde::Serializer,
+    {
+        use ::serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("MyDataNestedText", 1usize)?;
+        state.serialize_field("balance", &self.balance)?;
+        state.end()
+    }
+}
@@ -0,0 +1,9 @@
+::core::compile_error! {
+    "Nest ID `my-text` can only contain alphanumeric characters and underscores"
+}
+::core::compile_error! {
+    "Nest ID `type` is not a valid field name, set `field_name` to use it"
+}
+::core::compile_error! {
+    "Nest ID `1st` is not a valid field name, set `field_name` to use it"
+}
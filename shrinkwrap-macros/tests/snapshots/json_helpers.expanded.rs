@@ -0,0 +1,309 @@
+const _: () = {
+    type AssertedTransform = MyTransform;
+    fn assert_transform<T: ?Sized + ::shrinkwrap::__private::ImplementsTransform>() {}
+    let _ = assert_transform::<AssertedTransform>;
+};
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataWrapper {
+    pub extra: MyDataExtra,
+    #[serde(flatten)]
+    pub data: MyData,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataExtra {
+    pub usd_value: MyDataNestedUsdValueWrapper,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedUsdValueWrapper {
+    pub extra: MyDataNestedUsdValueExtra,
+    #[serde(flatten)]
+    pub data: MyDataNestedUsdValue,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedUsdValue {
+    pub account_balance: f64,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedUsdValueExtra {
+    pub text: MyDataNestedUsdValueText,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedUsdValueText {
+    pub account_balance: String,
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::ToWrappedWith<T> for MyData
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TransformToNest2<
+        MyDataNestedUsdValueWrapper,
+        Data = MyData,
+    >,
+{
+    type Wrapper = MyDataWrapper;
+    fn to_wrapped_with(
+        self,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        let nest_usd_value = transform.transform_to_nest(&self, options);
+        let extra = MyDataExtra {
+            usd_value: nest_usd_value,
+        };
+        Self::Wrapper { extra, data: self }
+    }
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::WrapAllWith<T> for MyData
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TransformToNest2<
+        MyDataNestedUsdValueWrapper,
+        Data = MyData,
+    >,
+{
+    fn wrap_all_with(
+        data: Vec<Self>,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Vec<Self::Wrapper> {
+        let wrappers = data
+            .into_iter()
+            .map(|data| {
+                let nest_usd_value = transform.transform_to_nest(&data, options);
+                let extra = MyDataExtra {
+                    usd_value: nest_usd_value,
+                };
+                Self::Wrapper { extra, data }
+            });
+        wrappers.collect()
+    }
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::ToWrappedWith<MyTransform> for Option<MyData> {
+    type Wrapper = Option<MyDataWrapper>;
+    fn to_wrapped_with(
+        self,
+        transform: &MyTransform,
+        options: &<MyTransform as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        self.map(|data| data.to_wrapped_with(transform, options))
+    }
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::ToWrappedWith<T> for MyDataNestedUsdValue
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TransformToNest2<
+        MyDataNestedUsdValueText,
+        Data = MyDataNestedUsdValue,
+    >,
+{
+    type Wrapper = MyDataNestedUsdValueWrapper;
+    fn to_wrapped_with(
+        self,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        let nest_text = transform.transform_to_nest(&self, options);
+        let extra = MyDataNestedUsdValueExtra {
+            text: nest_text,
+        };
+        Self::Wrapper { extra, data: self }
+    }
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::ToWrappedWith<MyTransform>
+for Option<MyDataNestedUsdValue> {
+    type Wrapper = Option<MyDataNestedUsdValueWrapper>;
+    fn to_wrapped_with(
+        self,
+        transform: &MyTransform,
+        options: &<MyTransform as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        self.map(|data| data.to_wrapped_with(transform, options))
+    }
+}
+#[automatically_derived]
+#[doc(hidden)]
+impl ::shrinkwrap::__private::TransformToNest2<MyDataNestedUsdValueWrapper>
+for MyTransform {
+    type Data = MyData;
+    fn transform_to_nest(
+        &self,
+        data: &Self::Data,
+        options: &Self::Options<'_>,
+    ) -> MyDataNestedUsdValueWrapper {
+        use ::shrinkwrap::__private::{ToNestWith, WrapDataWith};
+        let nest_data: MyDataNestedUsdValue = data.to_nest_with(self, options);
+        MyDataNestedUsdValueWrapper::wrap_data_with(nest_data, self, options)
+    }
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::JsonShape for MyDataWrapper {
+    const SHAPE: ::shrinkwrap::__private::Shape = ::shrinkwrap::__private::Shape {
+        name: "MyDataWrapper",
+        nest: ::std::option::Option::None,
+        keys: &[
+            ::shrinkwrap::__private::ShapeKey {
+                name: "extra",
+                required: true,
+                nullable: false,
+                shape: ::std::option::Option::Some(
+                    &<MyDataExtra as ::shrinkwrap::__private::JsonShape>::SHAPE,
+                ),
+            },
+            ::shrinkwrap::__private::ShapeKey {
+                name: "accountBalance",
+                required: true,
+                nullable: false,
+                shape: ::std::option::Option::None,
+            },
+        ],
+        open: false,
+    };
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::JsonShape for MyDataExtra {
+    const SHAPE: ::shrinkwrap::__private::Shape = ::shrinkwrap::__private::Shape {
+        name: "MyDataExtra",
+        nest: ::std::option::Option::None,
+        keys: &[
+            ::shrinkwrap::__private::ShapeKey {
+                name: "usd_value",
+                required: true,
+                nullable: false,
+                shape: ::std::option::Option::Some(
+                    &<MyDataNestedUsdValueWrapper as ::shrinkwrap::__private::JsonShape>::SHAPE,
+                ),
+            },
+        ],
+        open: false,
+    };
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::JsonShape for MyDataNestedUsdValueWrapper {
+    const SHAPE: ::shrinkwrap::__private::Shape = ::shrinkwrap::__private::Shape {
+        name: "MyDataNestedUsdValueWrapper",
+        nest: ::std::option::Option::Some("usd_value"),
+        keys: &[
+            ::shrinkwrap::__private::ShapeKey {
+                name: "extra",
+                required: true,
+                nullable: false,
+                shape: ::std::option::Option::Some(
+                    &<MyDataNestedUsdValueExtra as ::shrinkwrap::__private::JsonShape>::SHAPE,
+                ),
+            },
+            ::shrinkwrap::__private::ShapeKey {
+                name: "account_balance",
+                required: true,
+                nullable: false,
+                shape: ::std::option::Option::None,
+            },
+        ],
+        open: false,
+    };
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::JsonShape for MyDataNestedUsdValueExtra {
+    const SHAPE: ::shrinkwrap::__private::Shape = ::shrinkwrap::__private::Shape {
+        name: "MyDataNestedUsdValueExtra",
+        nest: ::std::option::Option::Some("usd_value"),
+        keys: &[
+            ::shrinkwrap::__private::ShapeKey {
+                name: "text",
+                required: true,
+                nullable: false,
+                shape: ::std::option::Option::Some(
+                    &<MyDataNestedUsdValueText as ::shrinkwrap::__private::JsonShape>::SHAPE,
+                ),
+            },
+        ],
+        open: false,
+    };
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::JsonShape for MyDataNestedUsdValue {
+    const SHAPE: ::shrinkwrap::__private::Shape = ::shrinkwrap::__private::Shape {
+        name: "MyDataNestedUsdValue",
+        nest: ::std::option::Option::Some("usd_value"),
+        keys: &[
+            ::shrinkwrap::__private::ShapeKey {
+                name: "account_balance",
+                required: true,
+                nullable: false,
+                shape: ::std::option::Option::None,
+            },
+        ],
+        open: false,
+    };
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::JsonShape for MyDataNestedUsdValueText {
+    const SHAPE: ::shrinkwrap::__private::Shape = ::shrinkwrap::__private::Shape {
+        name: "MyDataNestedUsdValueText",
+        nest: ::std::option::Option::Some("usd_text"),
+        keys: &[
+            ::shrinkwrap::__private::ShapeKey {
+                name: "account_balance",
+                required: true,
+                nullable: false,
+                shape: ::std::option::Option::None,
+            },
+        ],
+        open: false,
+    };
+}
+#[automatically_derived]
+impl MyDataWrapper {
+    /// Number of nests the wrapper may hold, at any depth
+    pub const MAX_NEST_COUNT: usize = 2usize;
+    /// Compares a JSON value against the serialized shape of this wrapper, reporting any unknown or missing keys (per nest)
+    pub fn matches_shape(
+        value: &::shrinkwrap::__private::JsonValue,
+    ) -> ::std::vec::Vec<::shrinkwrap::__private::ShapeMismatch> {
+        ::shrinkwrap::__private::check_shape(
+            value,
+            &<Self as ::shrinkwrap::__private::JsonShape>::SHAPE,
+        )
+    }
+    /// Serializes this wrapper as a JSON value, retaining only the nests selected by ID (along with their
+    /// parents and children), see `shrinkwrap::filter`
+    pub fn to_value_filtered(
+        &self,
+        nests: &[&str],
+    ) -> ::std::result::Result<
+        ::shrinkwrap::__private::JsonValue,
+        ::shrinkwrap::__private::Error,
+    > {
+        const NESTS: ::shrinkwrap::__private::NestTree = ::shrinkwrap::__private::NestTree {
+            extra: ::std::option::Option::Some("extra"),
+            nests: &[
+                ::shrinkwrap::__private::NestKey {
+                    id: "usd_value",
+                    key: "usd_value",
+                    children: ::std::option::Option::Some(
+                        &::shrinkwrap::__private::NestTree {
+                            extra: ::std::option::Option::Some("extra"),
+                            nests: &[
+                                ::shrinkwrap::__private::NestKey {
+                                    id: "usd_text",
+                                    key: "text",
+                                    children: ::std::option::Option::None,
+                                },
+                            ],
+                        },
+                    ),
+                },
+            ],
+        };
+        ::shrinkwrap::__private::to_value_filtered(self, &NESTS, nests)
+    }
+}
@@ -0,0 +1,141 @@
+const _: () = {
+    type AssertedTransform = MyTransform;
+    fn assert_transform<T: ?Sized + ::shrinkwrap::__private::ImplementsTransform>() {}
+    let _ = assert_transform::<AssertedTransform>;
+};
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataWrapper {
+    pub extra: MyDataExtra,
+    #[serde(flatten)]
+    pub data: MyData,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataExtra {
+    pub text: MyDataNestedText,
+    pub value: MyDataNestedValue,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedText {
+    pub balance: TextValue,
+    pub transactions: ::std::vec::Vec<TextValue>,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedValue {
+    pub transactions: ::std::vec::Vec<f64>,
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::TryToWrappedWith<T> for MyData
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TryTransformToNest2<
+        MyDataNestedText,
+        Data = MyData,
+        Error = MyError,
+    >,
+    T: ::shrinkwrap::__private::TryTransformToNest2<
+        MyDataNestedValue,
+        Data = MyData,
+        Error = MyError,
+    >,
+{
+    type Wrapper = MyDataWrapper;
+    type Error = MyError;
+    fn try_to_wrapped_with(
+        self,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Result<Self::Wrapper, Self::Error> {
+        let nest_text = transform.try_transform_to_nest(&self, options)?;
+        let nest_value = transform.try_transform_to_nest(&self, options)?;
+        let extra = MyDataExtra {
+            text: nest_text,
+            value: nest_value,
+        };
+        Ok(Self::Wrapper { extra, data: self })
+    }
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::TryWrapAllWith<T> for MyData
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TryTransformToNest2<
+        MyDataNestedText,
+        Data = MyData,
+        Error = MyError,
+    >,
+    T: ::shrinkwrap::__private::TryTransformToNest2<
+        MyDataNestedValue,
+        Data = MyData,
+        Error = MyError,
+    >,
+{
+    fn try_wrap_all_with(
+        data: Vec<Self>,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Result<Vec<Self::Wrapper>, Self::Error> {
+        let wrappers = data
+            .into_iter()
+            .map(|data| {
+                let nest_text = transform.try_transform_to_nest(&data, options)?;
+                let nest_value = transform.try_transform_to_nest(&data, options)?;
+                let extra = MyDataExtra {
+                    text: nest_text,
+                    value: nest_value,
+                };
+                Ok(Self::Wrapper { extra, data })
+            });
+        wrappers.collect::<Result<Vec<_>, _>>()
+    }
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::TryToWrappedWith<MyTransform> for Option<MyData> {
+    type Wrapper = Option<MyDataWrapper>;
+    type Error = MyError;
+    fn try_to_wrapped_with(
+        self,
+        transform: &MyTransform,
+        options: &<MyTransform as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Result<Self::Wrapper, Self::Error> {
+        self.map(|data| data.try_to_wrapped_with(transform, options)).transpose()
+    }
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::TryTransformToNest2<MyDataNestedText> for MyTransform
+where
+    Self: ::shrinkwrap::__private::TryBuildNestValue2<i64, TextValue, Error = MyError>,
+    Self: ::shrinkwrap::__private::TryBuildNestValue2<
+        <Vec<Transaction> as ::std::iter::IntoIterator>::Item,
+        TextValue,
+        Error = MyError,
+    >,
+{
+    type Data = MyData;
+    type Error = MyError;
+    fn try_transform_to_nest(
+        &self,
+        data: &Self::Data,
+        options: &Self::Options<'_>,
+    ) -> Result<MyDataNestedText, MyError> {
+        Ok(MyDataNestedText {
+            balance: ::shrinkwrap::__private::TryBuildNestValue2::try_build_nest_value(
+                self,
+                &data.balance,
+                options,
+            )?,
+            transactions: data
+                .transactions
+                .iter()
+                .map(|element| ::shrinkwrap::__private::TryBuildNestValue2::try_build_nest_value(
+                    self,
+                    element,
+                    options,
+                ))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
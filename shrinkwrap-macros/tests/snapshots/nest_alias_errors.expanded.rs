@@ -0,0 +1,21 @@
+::core::compile_error! {
+    "Nest alias cannot match its field name"
+}
+::core::compile_error! {
+    "`emit_alias` requires `alias`"
+}
+::core::compile_error! {
+    "`emit_alias` cannot be used with `promote`"
+}
+::core::compile_error! {
+    "Nest alias cannot be empty"
+}
+::core::compile_error! {
+    "`promote` cannot be used with `extra(exclusive)`"
+}
+::core::compile_error! {
+    "`emit_alias` cannot be used on root nests with `extra(exclusive)`"
+}
+::core::compile_error! {
+    "`emit_alias` cannot be used on root nests with `extra(exclusive)`"
+}
@@ -0,0 +1,400 @@
+const _: () = {
+    type AssertedTransform = MyTransform;
+    fn assert_transform<T: ?Sized + ::shrinkwrap::__private::ImplementsTransform>() {}
+    let _ = assert_transform::<AssertedTransform>;
+};
+#[automatically_derived]
+#[derive(
+    ::std::fmt::Debug,
+    ::std::clone::Clone,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::schemars::JsonSchema
+)]
+pub struct MyDataWrapper {
+    pub extra: MyDataExtra,
+    #[serde(flatten)]
+    pub data: MyData,
+}
+#[automatically_derived]
+#[derive(
+    ::std::fmt::Debug,
+    ::std::clone::Clone,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::schemars::JsonSchema
+)]
+pub struct MyDataExtra {
+    #[serde(with = "::shrinkwrap::__private::json_string")]
+    #[schemars(with = "String")]
+    pub text: MyDataNestedText,
+    #[serde(with = "::shrinkwrap::__private::json_string_option")]
+    #[schemars(with = "Option<String>")]
+    pub value: Option<MyDataNestedValueWrapper>,
+}
+#[automatically_derived]
+#[derive(
+    ::std::fmt::Debug,
+    ::std::clone::Clone,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::schemars::JsonSchema
+)]
+pub struct MyDataNestedText {
+    pub balance: String,
+}
+#[automatically_derived]
+#[derive(
+    ::std::fmt::Debug,
+    ::std::clone::Clone,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::schemars::JsonSchema
+)]
+pub struct MyDataNestedValueWrapper {
+    pub extra: MyDataNestedValueExtra,
+    #[serde(flatten)]
+    pub data: MyDataNestedValue,
+}
+#[automatically_derived]
+#[derive(
+    ::std::fmt::Debug,
+    ::std::clone::Clone,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::schemars::JsonSchema
+)]
+pub struct MyDataNestedValue {
+    pub balance: f64,
+}
+#[automatically_derived]
+#[derive(
+    ::std::fmt::Debug,
+    ::std::clone::Clone,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::schemars::JsonSchema
+)]
+pub struct MyDataNestedValueExtra {
+    #[serde(with = "::shrinkwrap::__private::json_string")]
+    #[schemars(with = "String")]
+    pub value_text: MyDataNestedValueValueText,
+}
+#[automatically_derived]
+#[derive(
+    ::std::fmt::Debug,
+    ::std::clone::Clone,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::schemars::JsonSchema
+)]
+pub struct MyDataNestedValueValueText {
+    pub balance: String,
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::ToWrappedWith<T> for MyData
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TransformToNest2<MyDataNestedText, Data = MyData>,
+    T: ::shrinkwrap::__private::TransformToNest2<
+        Option<MyDataNestedValueWrapper>,
+        Data = MyData,
+    >,
+{
+    type Wrapper = MyDataWrapper;
+    fn to_wrapped_with(
+        self,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        let nest_text = transform.transform_to_nest(&self, options);
+        let nest_value = transform.transform_to_nest(&self, options);
+        let extra = MyDataExtra {
+            text: nest_text,
+            value: nest_value,
+        };
+        Self::Wrapper { extra, data: self }
+    }
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::WrapAllWith<T> for MyData
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TransformToNest2<MyDataNestedText, Data = MyData>,
+    T: ::shrinkwrap::__private::TransformToNest2<
+        Option<MyDataNestedValueWrapper>,
+        Data = MyData,
+    >,
+{
+    fn wrap_all_with(
+        data: Vec<Self>,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Vec<Self::Wrapper> {
+        let wrappers = data
+            .into_iter()
+            .map(|data| {
+                let nest_text = transform.transform_to_nest(&data, options);
+                let nest_value = transform.transform_to_nest(&data, options);
+                let extra = MyDataExtra {
+                    text: nest_text,
+                    value: nest_value,
+                };
+                Self::Wrapper { extra, data }
+            });
+        wrappers.collect()
+    }
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::ToWrappedWith<MyTransform> for Option<MyData> {
+    type Wrapper = Option<MyDataWrapper>;
+    fn to_wrapped_with(
+        self,
+        transform: &MyTransform,
+        options: &<MyTransform as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        self.map(|data| data.to_wrapped_with(transform, options))
+    }
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::ToWrappedWith<T> for MyDataNestedValue
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TransformToNest2<
+        MyDataNestedValueValueText,
+        Data = MyDataNestedValue,
+    >,
+{
+    type Wrapper = MyDataNestedValueWrapper;
+    fn to_wrapped_with(
+        self,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        let nest_value_text = transform.transform_to_nest(&self, options);
+        let extra = MyDataNestedValueExtra {
+            value_text: nest_value_text,
+        };
+        Self::Wrapper { extra, data: self }
+    }
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::ToWrappedWith<MyTransform> for Option<MyDataNestedValue> {
+    type Wrapper = Option<MyDataNestedValueWrapper>;
+    fn to_wrapped_with(
+        self,
+        transform: &MyTransform,
+        options: &<MyTransform as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        self.map(|data| data.to_wrapped_with(transform, options))
+    }
+}
+#[automatically_derived]
+#[doc(hidden)]
+impl ::shrinkwrap::__private::TransformToNest2<Option<MyDataNestedValueWrapper>>
+for MyTransform {
+    type Data = MyData;
+    fn transform_to_nest(
+        &self,
+        data: &Self::Data,
+        options: &Self::Options<'_>,
+    ) -> Option<MyDataNestedValueWrapper> {
+        use ::shrinkwrap::__private::{ToNestWith, WrapDataWith};
+        let nest_data: Option<MyDataNestedValue> = data.to_nest_with(self, options);
+        Option::<MyDataNestedValueWrapper>::wrap_data_with(nest_data, self, options)
+    }
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::JsonShape for MyDataWrapper {
+    const SHAPE: ::shrinkwrap::__private::Shape = ::shrinkwrap::__private::Shape {
+        name: "MyDataWrapper",
+        nest: ::std::option::Option::None,
+        keys: &[
+            ::shrinkwrap::__private::ShapeKey {
+                name: "extra",
+                required: true,
+                nullable: false,
+                shape: ::std::option::Option::Some(
+                    &<MyDataExtra as ::shrinkwrap::__private::JsonShape>::SHAPE,
+                ),
+            },
+            ::shrinkwrap::__private::ShapeKey {
+                name: "balance",
+                required: true,
+                nullable: false,
+                shape: ::std::option::Option::None,
+            },
+        ],
+        open: false,
+    };
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::JsonShape for MyDataExtra {
+    const SHAPE: ::shrinkwrap::__private::Shape = ::shrinkwrap::__private::Shape {
+        name: "MyDataExtra",
+        nest: ::std::option::Option::None,
+        keys: &[
+            ::shrinkwrap::__private::ShapeKey {
+                name: "text",
+                required: true,
+                nullable: false,
+                shape: ::std::option::Option::None,
+            },
+            ::shrinkwrap::__private::ShapeKey {
+                name: "value",
+                required: false,
+                nullable: true,
+                shape: ::std::option::Option::None,
+            },
+        ],
+        open: false,
+    };
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::JsonShape for MyDataNestedText {
+    const SHAPE: ::shrinkwrap::__private::Shape = ::shrinkwrap::__private::Shape {
+        name: "MyDataNestedText",
+        nest: ::std::option::Option::Some("text"),
+        keys: &[
+            ::shrinkwrap::__private::ShapeKey {
+                name: "balance",
+                required: true,
+                nullable: false,
+                shape: ::std::option::Option::None,
+            },
+        ],
+        open: false,
+    };
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::JsonShape for MyDataNestedValueWrapper {
+    const SHAPE: ::shrinkwrap::__private::Shape = ::shrinkwrap::__private::Shape {
+        name: "MyDataNestedValueWrapper",
+        nest: ::std::option::Option::Some("value"),
+        keys: &[
+            ::shrinkwrap::__private::ShapeKey {
+                name: "extra",
+                required: true,
+                nullable: false,
+                shape: ::std::option::Option::Some(
+                    &<MyDataNestedValueExtra as ::shrinkwrap::__private::JsonShape>::SHAPE,
+                ),
+            },
+            ::shrinkwrap::__private::ShapeKey {
+                name: "balance",
+                required: true,
+                nullable: false,
+                shape: ::std::option::Option::None,
+            },
+        ],
+        open: false,
+    };
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::JsonShape for MyDataNestedValueExtra {
+    const SHAPE: ::shrinkwrap::__private::Shape = ::shrinkwrap::__private::Shape {
+        name: "MyDataNestedValueExtra",
+        nest: ::std::option::Option::Some("value"),
+        keys: &[
+            ::shrinkwrap::__private::ShapeKey {
+                name: "value_text",
+                required: true,
+                nullable: false,
+                shape: ::std::option::Option::None,
+            },
+        ],
+        open: false,
+    };
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::JsonShape for MyDataNestedValue {
+    const SHAPE: ::shrinkwrap::__private::Shape = ::shrinkwrap::__private::Shape {
+        name: "MyDataNestedValue",
+        nest: ::std::option::Option::Some("value"),
+        keys: &[
+            ::shrinkwrap::__private::ShapeKey {
+                name: "balance",
+                required: true,
+                nullable: false,
+                shape: ::std::option::Option::None,
+            },
+        ],
+        open: false,
+    };
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::JsonShape for MyDataNestedValueValueText {
+    const SHAPE: ::shrinkwrap::__private::Shape = ::shrinkwrap::__private::Shape {
+        name: "MyDataNestedValueValueText",
+        nest: ::std::option::Option::Some("value_text"),
+        keys: &[
+            ::shrinkwrap::__private::ShapeKey {
+                name: "balance",
+                required: true,
+                nullable: false,
+                shape: ::std::option::Option::None,
+            },
+        ],
+        open: false,
+    };
+}
+#[automatically_derived]
+impl MyDataWrapper {
+    /// Number of nests the wrapper may hold, at any depth
+    pub const MAX_NEST_COUNT: usize = 3usize;
+    /// Compares a JSON value against the serialized shape of this wrapper, reporting any unknown or missing keys (per nest)
+    pub fn matches_shape(
+        value: &::shrinkwrap::__private::JsonValue,
+    ) -> ::std::vec::Vec<::shrinkwrap::__private::ShapeMismatch> {
+        ::shrinkwrap::__private::check_shape(
+            value,
+            &<Self as ::shrinkwrap::__private::JsonShape>::SHAPE,
+        )
+    }
+    /// Serializes this wrapper as a JSON value, retaining only the nests selected by ID (along with their
+    /// parents and children), see `shrinkwrap::filter`
+    pub fn to_value_filtered(
+        &self,
+        nests: &[&str],
+    ) -> ::std::result::Result<
+        ::shrinkwrap::__private::JsonValue,
+        ::shrinkwrap::__private::Error,
+    > {
+        const NESTS: ::shrinkwrap::__private::NestTree = ::shrinkwrap::__private::NestTree {
+            extra: ::std::option::Option::Some("extra"),
+            nests: &[
+                ::shrinkwrap::__private::NestKey {
+                    id: "text",
+                    key: "text",
+                    children: ::std::option::Option::None,
+                },
+                ::shrinkwrap::__private::NestKey {
+                    id: "value",
+                    key: "value",
+                    children: ::std::option::Option::Some(
+                        &::shrinkwrap::__private::NestTree {
+                            extra: ::std::option::Option::Some("extra"),
+                            nests: &[
+                                ::shrinkwrap::__private::NestKey {
+                                    id: "value_text",
+                                    key: "value_text",
+                                    children: ::std::option::Option::None,
+                                },
+                            ],
+                        },
+                    ),
+                },
+            ],
+        };
+        ::shrinkwrap::__private::to_value_filtered(self, &NESTS, nests)
+    }
+}
+#[automatically_derived]
+impl ::std::convert::TryFrom<::shrinkwrap::__private::JsonValue> for MyDataWrapper {
+    type Error = ::shrinkwrap::__private::Error;
+    fn try_from(
+        value: ::shrinkwrap::__private::JsonValue,
+    ) -> ::std::result::Result<Self, Self::Error> {
+        ::shrinkwrap::__private::from_json_value(value)
+    }
+}
@@ -0,0 +1,15 @@
+::core::compile_error! {
+    "`promote` defined here"
+}
+::core::compile_error! {
+    "`encode` cannot be used with `promote`"
+}
+::core::compile_error! {
+    "`promote` cannot be used with `extra(exclusive)`"
+}
+::core::compile_error! {
+    "`encode` cannot be used on root nests with `extra(exclusive)`"
+}
+::core::compile_error! {
+    "`encode` cannot be used on root nests with `extra(exclusive)`"
+}
@@ -0,0 +1,9 @@
+::core::compile_error! {
+    "Nest `text` first declared here"
+}
+::core::compile_error! {
+    "Nest `text` is already declared with a different `field_type`"
+}
+::core::compile_error! {
+    "`field_type` is required as nest `value` is not declared elsewhere"
+}
@@ -0,0 +1,212 @@
+const _: () = {
+    type AssertedTransform = MyTransform;
+    fn assert_transform<T: ?Sized + ::shrinkwrap::__private::ImplementsTransform>() {}
+    let _ = assert_transform::<AssertedTransform>;
+};
+#[automatically_derived]
+#[derive(::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataWrapper {
+    pub extra: MyDataExtra,
+    #[serde(flatten)]
+    pub data: MyData,
+}
+#[automatically_derived]
+#[derive(::std::clone::Clone, ::serde::Serialize)]
+#[serde(untagged)]
+pub enum MyDataExtra {
+    #[serde(rename = "text")]
+    Text(MyDataNestedTextWrapper),
+    ///No nest is populated
+    #[serde(rename = "none")]
+    None,
+}
+#[automatically_derived]
+#[derive(::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedTextWrapper {
+    pub extra: MyDataNestedTextExtra,
+    #[serde(flatten)]
+    pub data: MyDataNestedText,
+}
+#[automatically_derived]
+#[derive(::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedText {
+    pub email: String,
+}
+#[automatically_derived]
+#[derive(::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedTextExtra {
+    pub masked: Option<MyDataNestedTextMasked>,
+}
+#[automatically_derived]
+#[derive(::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedTextMasked {
+    pub email: String,
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::ToWrappedWith<T> for MyData
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TransformToNest2<
+        Option<MyDataNestedTextWrapper>,
+        Data = MyData,
+    >,
+{
+    type Wrapper = MyDataWrapper;
+    fn to_wrapped_with(
+        self,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        let extra = 'exclusive: {
+            let nest: Option<MyDataNestedTextWrapper> = transform
+                .transform_to_nest(&self, options);
+            if let Some(nest) = nest {
+                break 'exclusive MyDataExtra::Text(nest);
+            }
+            MyDataExtra::None
+        };
+        Self::Wrapper { extra, data: self }
+    }
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::WrapAllWith<T> for MyData
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TransformToNest2<
+        Option<MyDataNestedTextWrapper>,
+        Data = MyData,
+    >,
+{
+    fn wrap_all_with(
+        data: Vec<Self>,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Vec<Self::Wrapper> {
+        let wrappers = data
+            .into_iter()
+            .map(|data| {
+                let extra = 'exclusive: {
+                    let nest: Option<MyDataNestedTextWrapper> = transform
+                        .transform_to_nest(&data, options);
+                    if let Some(nest) = nest {
+                        break 'exclusive MyDataExtra::Text(nest);
+                    }
+                    MyDataExtra::None
+                };
+                Self::Wrapper { extra, data }
+            });
+        wrappers.collect()
+    }
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::ToWrappedWith<MyTransform> for Option<MyData> {
+    type Wrapper = Option<MyDataWrapper>;
+    fn to_wrapped_with(
+        self,
+        transform: &MyTransform,
+        options: &<MyTransform as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        self.map(|data| data.to_wrapped_with(transform, options))
+    }
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::ToWrappedWith<T> for MyDataNestedText
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TransformToNest2<
+        Option<MyDataNestedTextMasked>,
+        Data = MyDataNestedText,
+    >,
+{
+    type Wrapper = MyDataNestedTextWrapper;
+    fn to_wrapped_with(
+        self,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        let nest_masked = transform.transform_to_nest(&self, options);
+        let extra = MyDataNestedTextExtra {
+            masked: nest_masked,
+        };
+        Self::Wrapper { extra, data: self }
+    }
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::ToWrappedWith<MyTransform> for Option<MyDataNestedText> {
+    type Wrapper = Option<MyDataNestedTextWrapper>;
+    fn to_wrapped_with(
+        self,
+        transform: &MyTransform,
+        options: &<MyTransform as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        self.map(|data| data.to_wrapped_with(transform, options))
+    }
+}
+#[automatically_derived]
+#[doc(hidden)]
+impl ::shrinkwrap::__private::TransformToNest2<Option<MyDataNestedTextWrapper>>
+for MyTransform {
+    type Data = MyData;
+    fn transform_to_nest(
+        &self,
+        data: &Self::Data,
+        options: &Self::Options<'_>,
+    ) -> Option<MyDataNestedTextWrapper> {
+        use ::shrinkwrap::__private::{ToNestWith, WrapDataWith};
+        let nest_data: Option<MyDataNestedText> = data.to_nest_with(self, options);
+        Option::<MyDataNestedTextWrapper>::wrap_data_with(nest_data, self, options)
+    }
+}
+#[automatically_derived]
+impl ::std::fmt::Debug for MyDataWrapper {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        f.debug_struct("MyDataWrapper")
+            .field("extra", &::std::format_args!("<redacted>"))
+            .field("data", &::std::format_args!("<redacted>"))
+            .finish()
+    }
+}
+#[automatically_derived]
+impl ::std::fmt::Debug for MyDataExtra {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match self {
+            Self::Text(..) => {
+                f.debug_tuple("Text").field(&::std::format_args!("<redacted>")).finish()
+            }
+            Self::None => f.write_str("None"),
+        }
+    }
+}
+#[automatically_derived]
+impl ::std::fmt::Debug for MyDataNestedTextWrapper {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        f.debug_struct("MyDataNestedTextWrapper")
+            .field("extra", &::std::format_args!("<redacted>"))
+            .field("data", &::std::format_args!("<redacted>"))
+            .finish()
+    }
+}
+#[automatically_derived]
+impl ::std::fmt::Debug for MyDataNestedText {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        f.debug_struct("MyDataNestedText")
+            .field("email", &::std::format_args!("<redacted>"))
+            .finish()
+    }
+}
+#[automatically_derived]
+impl ::std::fmt::Debug for MyDataNestedTextExtra {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        f.debug_struct("MyDataNestedTextExtra")
+            .field("masked", &::std::format_args!("<redacted>"))
+            .finish()
+    }
+}
+#[automatically_derived]
+impl ::std::fmt::Debug for MyDataNestedTextMasked {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        f.debug_struct("MyDataNestedTextMasked")
+            .field("email", &::std::format_args!("<redacted>"))
+            .finish()
+    }
+}
@@ -0,0 +1,145 @@
+const _: () = {
+    type AssertedTransform = MyTransform;
+    fn assert_transform<T: ?Sized + ::shrinkwrap::__private::ImplementsTransform>() {}
+    let _ = assert_transform::<AssertedTransform>;
+};
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataWrapper {
+    pub extra: MyDataExtra,
+    #[serde(flatten)]
+    pub data: MyData,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataExtra {
+    pub value: Option<MyDataNestedValue>,
+    pub value_text: Option<MyDataNestedValueValueText>,
+    pub value_cents: Option<MyDataNestedValueValueCents>,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedValue {
+    pub balance: f64,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedValueValueText {
+    pub balance: String,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedValueValueCents {
+    pub balance: i64,
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::TryToWrappedWith<T> for MyData
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TryTransformToNest2<
+        Option<MyDataNestedValue>,
+        Data = MyData,
+        Error = MyError,
+    >,
+    T: ::shrinkwrap::__private::TryTransformToNest2<
+        Option<MyDataNestedValueValueText>,
+        Data = MyDataNestedValue,
+        Error = MyError,
+    >,
+    T: ::shrinkwrap::__private::TryTransformToNest2<
+        Option<MyDataNestedValueValueCents>,
+        Data = MyDataNestedValue,
+        Error = MyError,
+    >,
+{
+    type Wrapper = MyDataWrapper;
+    type Error = MyError;
+    fn try_to_wrapped_with(
+        self,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Result<Self::Wrapper, Self::Error> {
+        let nest_value = transform.try_transform_to_nest(&self, options)?;
+        let nest_value_text = ::std::option::Option::as_ref(&nest_value)
+            .map(|parent: &MyDataNestedValue| {
+                transform.try_transform_to_nest(parent, options)
+            })
+            .transpose()
+            .map(::std::option::Option::flatten)?;
+        let nest_value_cents = ::std::option::Option::as_ref(&nest_value)
+            .map(|parent: &MyDataNestedValue| {
+                transform.try_transform_to_nest(parent, options)
+            })
+            .transpose()
+            .map(::std::option::Option::flatten)?;
+        let extra = MyDataExtra {
+            value: nest_value,
+            value_text: nest_value_text,
+            value_cents: nest_value_cents,
+        };
+        Ok(Self::Wrapper { extra, data: self })
+    }
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::TryWrapAllWith<T> for MyData
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TryTransformToNest2<
+        Option<MyDataNestedValue>,
+        Data = MyData,
+        Error = MyError,
+    >,
+    T: ::shrinkwrap::__private::TryTransformToNest2<
+        Option<MyDataNestedValueValueText>,
+        Data = MyDataNestedValue,
+        Error = MyError,
+    >,
+    T: ::shrinkwrap::__private::TryTransformToNest2<
+        Option<MyDataNestedValueValueCents>,
+        Data = MyDataNestedValue,
+        Error = MyError,
+    >,
+{
+    fn try_wrap_all_with(
+        data: Vec<Self>,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Result<Vec<Self::Wrapper>, Self::Error> {
+        let wrappers = data
+            .into_iter()
+            .map(|data| {
+                let nest_value = transform.try_transform_to_nest(&data, options)?;
+                let nest_value_text = ::std::option::Option::as_ref(&nest_value)
+                    .map(|parent: &MyDataNestedValue| {
+                        transform.try_transform_to_nest(parent, options)
+                    })
+                    .transpose()
+                    .map(::std::option::Option::flatten)?;
+                let nest_value_cents = ::std::option::Option::as_ref(&nest_value)
+                    .map(|parent: &MyDataNestedValue| {
+                        transform.try_transform_to_nest(parent, options)
+                    })
+                    .transpose()
+                    .map(::std::option::Option::flatten)?;
+                let extra = MyDataExtra {
+                    value: nest_value,
+                    value_text: nest_value_text,
+                    value_cents: nest_value_cents,
+                };
+                Ok(Self::Wrapper { extra, data })
+            });
+        wrappers.collect::<Result<Vec<_>, _>>()
+    }
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::TryToWrappedWith<MyTransform> for Option<MyData> {
+    type Wrapper = Option<MyDataWrapper>;
+    type Error = MyError;
+    fn try_to_wrapped_with(
+        self,
+        transform: &MyTransform,
+        options: &<MyTransform as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Result<Self::Wrapper, Self::Error> {
+        self.map(|data| data.try_to_wrapped_with(transform, options)).transpose()
+    }
+}
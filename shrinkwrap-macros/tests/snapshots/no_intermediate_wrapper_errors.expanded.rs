@@ -0,0 +1,6 @@
+::core::compile_error! {
+    "Field name of nest `value_leaf` collides with a sibling of nest `value` (as it uses `no_intermediate_wrapper`), set a different `field_name`"
+}
+::core::compile_error! {
+    "`no_intermediate_wrapper` requires nests to be chained from nest `leaf`"
+}
@@ -0,0 +1,195 @@
+const _: () = {
+    type AssertedTransform = MyTransform;
+    fn assert_transform<T: ?Sized + ::shrinkwrap::__private::ImplementsTransform>() {}
+    let _ = assert_transform::<AssertedTransform>;
+};
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataWrapper {
+    pub extra: MyDataExtra,
+    #[serde(flatten)]
+    pub data: MyData,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataExtra {
+    pub value: Option<MyDataNestedValue>,
+    pub value_text: Option<MyDataNestedValueValueTextWrapper>,
+    pub value_cents: Option<MyDataNestedValueValueCents>,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedValue {
+    pub balance: f64,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedValueValueTextWrapper {
+    pub extra: MyDataNestedValueValueTextExtra,
+    #[serde(flatten)]
+    pub data: MyDataNestedValueValueText,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedValueValueText {
+    pub balance: String,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedValueValueTextExtra {
+    pub value_label: MyDataNestedValueValueTextValueLabel,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedValueValueTextValueLabel {
+    pub balance: String,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedValueValueCents {
+    pub balance: i64,
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::ToWrappedWith<T> for MyData
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TransformToNest2<
+        Option<MyDataNestedValue>,
+        Data = MyData,
+    >,
+    T: ::shrinkwrap::__private::TransformToNest2<
+        MyDataNestedValueValueTextWrapper,
+        Data = MyDataNestedValue,
+    >,
+    T: ::shrinkwrap::__private::TransformToNest2<
+        Option<MyDataNestedValueValueCents>,
+        Data = MyDataNestedValue,
+    >,
+{
+    type Wrapper = MyDataWrapper;
+    fn to_wrapped_with(
+        self,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        let nest_value = transform.transform_to_nest(&self, options);
+        let nest_value_text = ::std::option::Option::as_ref(&nest_value)
+            .map(|parent: &MyDataNestedValue| {
+                transform.transform_to_nest(parent, options)
+            });
+        let nest_value_cents = ::std::option::Option::as_ref(&nest_value)
+            .and_then(|parent: &MyDataNestedValue| {
+                transform.transform_to_nest(parent, options)
+            });
+        let extra = MyDataExtra {
+            value: nest_value,
+            value_text: nest_value_text,
+            value_cents: nest_value_cents,
+        };
+        Self::Wrapper { extra, data: self }
+    }
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::WrapAllWith<T> for MyData
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TransformToNest2<
+        Option<MyDataNestedValue>,
+        Data = MyData,
+    >,
+    T: ::shrinkwrap::__private::TransformToNest2<
+        MyDataNestedValueValueTextWrapper,
+        Data = MyDataNestedValue,
+    >,
+    T: ::shrinkwrap::__private::TransformToNest2<
+        Option<MyDataNestedValueValueCents>,
+        Data = MyDataNestedValue,
+    >,
+{
+    fn wrap_all_with(
+        data: Vec<Self>,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Vec<Self::Wrapper> {
+        let wrappers = data
+            .into_iter()
+            .map(|data| {
+                let nest_value = transform.transform_to_nest(&data, options);
+                let nest_value_text = ::std::option::Option::as_ref(&nest_value)
+                    .map(|parent: &MyDataNestedValue| {
+                        transform.transform_to_nest(parent, options)
+                    });
+                let nest_value_cents = ::std::option::Option::as_ref(&nest_value)
+                    .and_then(|parent: &MyDataNestedValue| {
+                        transform.transform_to_nest(parent, options)
+                    });
+                let extra = MyDataExtra {
+                    value: nest_value,
+                    value_text: nest_value_text,
+                    value_cents: nest_value_cents,
+                };
+                Self::Wrapper { extra, data }
+            });
+        wrappers.collect()
+    }
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::ToWrappedWith<MyTransform> for Option<MyData> {
+    type Wrapper = Option<MyDataWrapper>;
+    fn to_wrapped_with(
+        self,
+        transform: &MyTransform,
+        options: &<MyTransform as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        self.map(|data| data.to_wrapped_with(transform, options))
+    }
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::ToWrappedWith<T> for MyDataNestedValueValueText
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TransformToNest2<
+        MyDataNestedValueValueTextValueLabel,
+        Data = MyDataNestedValueValueText,
+    >,
+{
+    type Wrapper = MyDataNestedValueValueTextWrapper;
+    fn to_wrapped_with(
+        self,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        let nest_value_label = transform.transform_to_nest(&self, options);
+        let extra = MyDataNestedValueValueTextExtra {
+            value_label: nest_value_label,
+        };
+        Self::Wrapper { extra, data: self }
+    }
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::ToWrappedWith<MyTransform>
+for Option<MyDataNestedValueValueText> {
+    type Wrapper = Option<MyDataNestedValueValueTextWrapper>;
+    fn to_wrapped_with(
+        self,
+        transform: &MyTransform,
+        options: &<MyTransform as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        self.map(|data| data.to_wrapped_with(transform, options))
+    }
+}
+#[automatically_derived]
+#[doc(hidden)]
+impl ::shrinkwrap::__private::TransformToNest2<MyDataNestedValueValueTextWrapper>
+for MyTransform {
+    type Data = MyDataNestedValue;
+    fn transform_to_nest(
+        &self,
+        data: &Self::Data,
+        options: &Self::Options<'_>,
+    ) -> MyDataNestedValueValueTextWrapper {
+        use ::shrinkwrap::__private::{ToNestWith, WrapDataWith};
+        let nest_data: MyDataNestedValueValueText = data.to_nest_with(self, options);
+        MyDataNestedValueValueTextWrapper::wrap_data_with(nest_data, self, options)
+    }
+}
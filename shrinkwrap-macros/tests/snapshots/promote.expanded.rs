@@ -0,0 +1,131 @@
+const _: () = {
+    type AssertedTransform = MyTransform;
+    fn assert_transform<T: ?Sized + ::shrinkwrap::__private::ImplementsTransform>() {}
+    let _ = assert_transform::<AssertedTransform>;
+};
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone)]
+pub struct MyDataWrapper {
+    pub extra: MyDataExtra,
+    pub data: MyData,
+    pub total: i64,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataExtra {
+    #[serde(skip_serializing)]
+    pub legacy: Option<MyDataNestedLegacy>,
+    pub text: MyDataNestedText,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedLegacy {
+    pub balance_usd: String,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedText {
+    pub balance_usd: String,
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::ToWrappedWith<T> for MyData
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TransformToNest2<
+        Option<MyDataNestedLegacy>,
+        Data = MyData,
+    >,
+    T: ::shrinkwrap::__private::TransformToNest2<MyDataNestedText, Data = MyData>,
+{
+    type Wrapper = MyDataWrapper;
+    fn to_wrapped_with(
+        self,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        let nest_legacy = transform.transform_to_nest(&self, options);
+        let nest_text = transform.transform_to_nest(&self, options);
+        let extra = MyDataExtra {
+            legacy: nest_legacy,
+            text: nest_text,
+        };
+        Self::Wrapper {
+            total: crate::total(&self, &extra),
+            extra,
+            data: self,
+        }
+    }
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::WrapAllWith<T> for MyData
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TransformToNest2<
+        Option<MyDataNestedLegacy>,
+        Data = MyData,
+    >,
+    T: ::shrinkwrap::__private::TransformToNest2<MyDataNestedText, Data = MyData>,
+{
+    fn wrap_all_with(
+        data: Vec<Self>,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Vec<Self::Wrapper> {
+        let wrappers = data
+            .into_iter()
+            .map(|data| {
+                let nest_legacy = transform.transform_to_nest(&data, options);
+                let nest_text = transform.transform_to_nest(&data, options);
+                let extra = MyDataExtra {
+                    legacy: nest_legacy,
+                    text: nest_text,
+                };
+                Self::Wrapper {
+                    total: crate::total(&data, &extra),
+                    extra,
+                    data,
+                }
+            });
+        wrappers.collect()
+    }
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::ToWrappedWith<MyTransform> for Option<MyData> {
+    type Wrapper = Option<MyDataWrapper>;
+    fn to_wrapped_with(
+        self,
+        transform: &MyTransform,
+        options: &<MyTransform as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        self.map(|data| data.to_wrapped_with(transform, options))
+    }
+}
+#[automatically_derived]
+impl ::serde::Serialize for MyDataWrapper {
+    fn serialize<__S>(
+        &self,
+        serializer: __S,
+    ) -> ::std::result::Result<__S::Ok, __S::Error>
+    where
+        __S: ::serde::Serializer,
+    {
+        #[derive(::serde::Serialize)]
+        struct MyDataWrapper<'a> {
+            extra: &'a MyDataExtra,
+            #[serde(flatten)]
+            data: &'a MyData,
+            total: &'a i64,
+            #[serde(flatten)]
+            promoted_legacy: &'a Option<MyDataNestedLegacy>,
+        }
+        ::serde::Serialize::serialize(
+            &MyDataWrapper {
+                extra: &self.extra,
+                data: &self.data,
+                total: &self.total,
+                promoted_legacy: &self.extra.legacy,
+            },
+            serializer,
+        )
+    }
+}
@@ -0,0 +1,121 @@
+const _: () = {
+    type AssertedTransform = MyTransform;
+    fn assert_transform<T: ?Sized + ::shrinkwrap::__private::ImplementsTransform>() {}
+    let _ = assert_transform::<AssertedTransform>;
+};
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct UserDefWrapper {
+    pub extra: UserDefExtra,
+    #[serde(flatten)]
+    pub data: UserDef,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct UserDefExtra {
+    pub text: UserDefNestedText,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct UserDefNestedText {
+    pub balance: String,
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::TryToWrappedWith<T> for UserDef
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TryTransformToNest2<
+        UserDefNestedText,
+        Data = UserDef,
+        Error = MyError,
+    >,
+{
+    type Wrapper = UserDefWrapper;
+    type Error = MyError;
+    fn try_to_wrapped_with(
+        self,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Result<Self::Wrapper, Self::Error> {
+        let nest_text = transform.try_transform_to_nest(&self, options)?;
+        let extra = UserDefExtra { text: nest_text };
+        Ok(Self::Wrapper { extra, data: self })
+    }
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::TryWrapAllWith<T> for UserDef
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TryTransformToNest2<
+        UserDefNestedText,
+        Data = UserDef,
+        Error = MyError,
+    >,
+{
+    fn try_wrap_all_with(
+        data: Vec<Self>,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Result<Vec<Self::Wrapper>, Self::Error> {
+        let wrappers = data
+            .into_iter()
+            .map(|data| {
+                let nest_text = transform.try_transform_to_nest(&data, options)?;
+                let extra = UserDefExtra { text: nest_text };
+                Ok(Self::Wrapper { extra, data })
+            });
+        wrappers.collect::<Result<Vec<_>, _>>()
+    }
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::TryToWrappedWith<MyTransform> for Option<UserDef> {
+    type Wrapper = Option<UserDefWrapper>;
+    type Error = MyError;
+    fn try_to_wrapped_with(
+        self,
+        transform: &MyTransform,
+        options: &<MyTransform as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Result<Self::Wrapper, Self::Error> {
+        self.map(|data| data.try_to_wrapped_with(transform, options)).transpose()
+    }
+}
+#[automatically_derived]
+impl ::std::convert::From<vendor_sdk::User> for UserDef {
+    fn from(remote: vendor_sdk::User) -> Self {
+        Self {
+            id: remote.id,
+            balance: remote.balance,
+        }
+    }
+}
+#[automatically_derived]
+impl ::std::convert::From<UserDef> for vendor_sdk::User {
+    fn from(mirror: UserDef) -> Self {
+        Self {
+            id: mirror.id,
+            balance: mirror.balance,
+        }
+    }
+}
+#[automatically_derived]
+impl UserDef {
+    /// Wraps a [`vendor_sdk::User`] via its mirror
+    pub fn try_wrap_remote<T>(
+        remote: vendor_sdk::User,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> ::std::result::Result<
+        <Self as ::shrinkwrap::__private::TryToWrappedWith<T>>::Wrapper,
+        <Self as ::shrinkwrap::__private::TryToWrappedWith<T>>::Error,
+    >
+    where
+        T: ::shrinkwrap::__private::Transform2,
+        Self: ::shrinkwrap::__private::TryToWrappedWith<T>,
+    {
+        ::shrinkwrap::__private::TryToWrappedWith::try_to_wrapped_with(
+            Self::from(remote),
+            transform,
+            options,
+        )
+    }
+}
@@ -0,0 +1,3 @@
+::core::compile_error! {
+    "`required` cannot be used with `optional`"
+}
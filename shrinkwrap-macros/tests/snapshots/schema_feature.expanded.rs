@@ -0,0 +1,164 @@
+const _: () = {
+    type AssertedTransform = MyTransform;
+    fn assert_transform<T: ?Sized + ::shrinkwrap::__private::ImplementsTransform>() {}
+    let _ = assert_transform::<AssertedTransform>;
+};
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone)]
+#[cfg_attr(feature = "openapi", derive(::schemars::JsonSchema))]
+#[cfg_attr(feature = "openapi", schemars(rename = "v1.MyDataWrapper"))]
+pub struct MyDataWrapper {
+    pub extra: MyDataExtra,
+    #[cfg_attr(feature = "openapi", serde(flatten))]
+    pub data: MyData,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone)]
+#[cfg_attr(feature = "openapi", derive(::schemars::JsonSchema))]
+#[cfg_attr(feature = "openapi", schemars(rename = "v1.MyDataExtra"))]
+pub struct MyDataExtra {
+    pub text: MyDataNestedText,
+    #[cfg_attr(feature = "openapi", schemars(skip))]
+    pub value: Option<MyDataNestedValue>,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone)]
+#[cfg_attr(feature = "openapi", derive(::schemars::JsonSchema))]
+#[cfg_attr(feature = "openapi", schemars(extend("x-internal" = true)))]
+#[cfg_attr(feature = "openapi", schemars(rename = "v1.MyDataNestedText"))]
+#[cfg_attr(feature = "openapi", serde(rename_all = "camelCase"))]
+pub struct MyDataNestedText {
+    pub total_balance: String,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone)]
+pub struct MyDataNestedValue {
+    pub total_balance: f64,
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::ToWrappedWith<T> for MyData
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TransformToNest2<MyDataNestedText, Data = MyData>,
+    T: ::shrinkwrap::__private::TransformToNest2<
+        Option<MyDataNestedValue>,
+        Data = MyData,
+    >,
+{
+    type Wrapper = MyDataWrapper;
+    fn to_wrapped_with(
+        self,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        let nest_text = transform.transform_to_nest(&self, options);
+        let nest_value = transform.transform_to_nest(&self, options);
+        let extra = MyDataExtra {
+            text: nest_text,
+            value: nest_value,
+        };
+        Self::Wrapper { extra, data: self }
+    }
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::WrapAllWith<T> for MyData
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TransformToNest2<MyDataNestedText, Data = MyData>,
+    T: ::shrinkwrap::__private::TransformToNest2<
+        Option<MyDataNestedValue>,
+        Data = MyData,
+    >,
+{
+    fn wrap_all_with(
+        data: Vec<Self>,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Vec<Self::Wrapper> {
+        let wrappers = data
+            .into_iter()
+            .map(|data| {
+                let nest_text = transform.transform_to_nest(&data, options);
+                let nest_value = transform.transform_to_nest(&data, options);
+                let extra = MyDataExtra {
+                    text: nest_text,
+                    value: nest_value,
+                };
+                Self::Wrapper { extra, data }
+            });
+        wrappers.collect()
+    }
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::ToWrappedWith<MyTransform> for Option<MyData> {
+    type Wrapper = Option<MyDataWrapper>;
+    fn to_wrapped_with(
+        self,
+        transform: &MyTransform,
+        options: &<MyTransform as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        self.map(|data| data.to_wrapped_with(transform, options))
+    }
+}
+#[automatically_derived]
+impl ::serde::Serialize for MyDataWrapper {
+    fn serialize<__S>(
+        &self,
+        serializer: __S,
+    ) -> ::std::result::Result<__S::Ok, __S::Error>
+    where
+        __S: ::serde::Serializer,
+    {
+        use ::serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("MyDataWrapper", 2usize)?;
+        state.serialize_field("extra", &self.extra)?;
+        state.serialize_field("total_balance", &self.data.total_balance)?;
+        state.end()
+    }
+}
+#[automatically_derived]
+impl ::serde::Serialize for MyDataExtra {
+    fn serialize<__S>(
+        &self,
+        serializer: __S,
+    ) -> ::std::result::Result<__S::Ok, __S::Error>
+    where
+        __S: ::serde::Serializer,
+    {
+        use ::serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("MyDataExtra", 2usize)?;
+        state.serialize_field("text", &self.text)?;
+        state.serialize_field("value", &self.value)?;
+        state.end()
+    }
+}
+#[automatically_derived]
+impl ::serde::Serialize for MyDataNestedText {
+    fn serialize<__S>(
+        &self,
+        serializer: __S,
+    ) -> ::std::result::Result<__S::Ok, __S::Error>
+    where
+        __S: ::serde::Serializer,
+    {
+        use ::serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("MyDataNestedText", 1usize)?;
+        state.serialize_field("total_balance", &self.total_balance)?;
+        state.end()
+    }
+}
+#[automatically_derived]
+impl ::serde::Serialize for MyDataNestedValue {
+    fn serialize<__S>(
+        &self,
+        serializer: __S,
+    ) -> ::std::result::Result<__S::Ok, __S::Error>
+    where
+        __S: ::serde::Serializer,
+    {
+        use ::serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("MyDataNestedValue", 1usize)?;
+        state.serialize_field("total_balance", &self.total_balance)?;
+        state.end()
+    }
+}
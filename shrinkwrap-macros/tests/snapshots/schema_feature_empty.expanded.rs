@@ -0,0 +1,3 @@
+::core::compile_error! {
+    "Schema feature cannot be empty"
+}
@@ -0,0 +1,3 @@
+::core::compile_error! {
+    "`schema_naming` requires the `schema` flag"
+}
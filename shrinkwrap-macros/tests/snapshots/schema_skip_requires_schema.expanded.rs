@@ -0,0 +1,3 @@
+::core::compile_error! {
+    "`schema_skip` requires the `schema` (or `inline`) flag"
+}
@@ -0,0 +1,135 @@
+const _: () = {
+    type AssertedTransform = MyTransform;
+    fn assert_transform<T: ?Sized + ::shrinkwrap::__private::ImplementsTransform>() {}
+    let _ = assert_transform::<AssertedTransform>;
+};
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataWrapper {
+    pub extra: MyDataExtra,
+    #[serde(flatten)]
+    pub data: MyData,
+}
+#[automatically_derived]
+///A single nest of [`MyDataExtra`]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+#[serde(tag = "kind")]
+pub enum MyDataExtraItem {
+    #[serde(rename = "text")]
+    Text(MyDataNestedText),
+    #[serde(rename = "value")]
+    Value(MyDataNestedValue),
+}
+pub type MyDataExtra = ::std::vec::Vec<MyDataExtraItem>;
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedText {
+    pub balance: String,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedValue {
+    pub balance: f64,
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::ToWrappedWith<T> for MyData
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TransformToNest2<MyDataNestedText, Data = MyData>,
+    T: ::shrinkwrap::__private::TransformToNest2<
+        Option<MyDataNestedValue>,
+        Data = MyData,
+    >,
+{
+    type Wrapper = MyDataWrapper;
+    fn to_wrapped_with(
+        self,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        let nest_text = transform.transform_to_nest(&self, options);
+        let nest_value = transform.transform_to_nest(&self, options);
+        let extra = {
+            let mut extra = ::std::vec::Vec::with_capacity(2usize);
+            extra.push(MyDataExtraItem::Text(nest_text));
+            if let Some(nest) = nest_value {
+                extra.push(MyDataExtraItem::Value(nest));
+            }
+            extra
+        };
+        Self::Wrapper { extra, data: self }
+    }
+}
+#[automatically_derived]
+#[allow(private_interfaces)]
+impl MyDataWrapper {
+    /// Recomputes the `extra` struct in place from the wrapped data
+    pub fn enrich_with<T>(
+        &mut self,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    )
+    where
+        T: ::shrinkwrap::__private::Transform2,
+        T: ::shrinkwrap::__private::TransformToNest2<MyDataNestedText, Data = MyData>,
+        T: ::shrinkwrap::__private::TransformToNest2<
+            Option<MyDataNestedValue>,
+            Data = MyData,
+        >,
+    {
+        let nest_text = transform.transform_to_nest(&self.data, options);
+        let nest_value = transform.transform_to_nest(&self.data, options);
+        self.extra = {
+            let mut extra = ::std::vec::Vec::with_capacity(2usize);
+            extra.push(MyDataExtraItem::Text(nest_text));
+            if let Some(nest) = nest_value {
+                extra.push(MyDataExtraItem::Value(nest));
+            }
+            extra
+        };
+    }
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::WrapAllWith<T> for MyData
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TransformToNest2<MyDataNestedText, Data = MyData>,
+    T: ::shrinkwrap::__private::TransformToNest2<
+        Option<MyDataNestedValue>,
+        Data = MyData,
+    >,
+{
+    fn wrap_all_with(
+        data: Vec<Self>,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Vec<Self::Wrapper> {
+        let wrappers = data
+            .into_iter()
+            .map(|data| {
+                let nest_text = transform.transform_to_nest(&data, options);
+                let nest_value = transform.transform_to_nest(&data, options);
+                let extra = {
+                    let mut extra = ::std::vec::Vec::with_capacity(2usize);
+                    extra.push(MyDataExtraItem::Text(nest_text));
+                    if let Some(nest) = nest_value {
+                        extra.push(MyDataExtraItem::Value(nest));
+                    }
+                    extra
+                };
+                Self::Wrapper { extra, data }
+            });
+        wrappers.collect()
+    }
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::ToWrappedWith<MyTransform> for Option<MyData> {
+    type Wrapper = Option<MyDataWrapper>;
+    fn to_wrapped_with(
+        self,
+        transform: &MyTransform,
+        options: &<MyTransform as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Self::Wrapper {
+        self.map(|data| data.to_wrapped_with(transform, options))
+    }
+}
@@ -0,0 +1,270 @@
+const _: () = {
+    type AssertedTransform = MyTransform;
+    fn assert_transform<T: ?Sized + ::shrinkwrap::__private::ImplementsTransform>() {}
+    let _ = assert_transform::<AssertedTransform>;
+};
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataWrapper {
+    pub extra: MyDataExtra,
+    #[serde(flatten)]
+    pub data: MyData,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataExtra {
+    pub text: Option<MyDataNestedText>,
+    pub text_v2: MyDataNestedTextV2,
+    pub value: MyDataNestedValueWrapper,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedText {
+    pub balance: String,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedTextV2 {
+    pub balance: String,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedValueWrapper {
+    pub extra: MyDataNestedValueExtra,
+    #[serde(flatten)]
+    pub data: MyDataNestedValue,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedValue {
+    pub balance: f64,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedValueExtra {
+    pub value_text: MyDataNestedValueValueText,
+}
+#[automatically_derived]
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::serde::Serialize)]
+pub struct MyDataNestedValueValueText {
+    pub balance: String,
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::TryToWrappedWith<T> for MyData
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TryTransformToNest2<
+        Option<MyDataNestedText>,
+        Data = MyData,
+        Error = MyError,
+    >,
+    T: ::shrinkwrap::__private::TryTransformToNest2<
+        MyDataNestedTextV2,
+        Data = MyData,
+        Error = MyError,
+    >,
+    T: ::shrinkwrap::__private::TryTransformToNest2<
+        MyDataNestedValueWrapper,
+        Data = MyData,
+        Error = MyError,
+    >,
+    for<'options> <T as ::shrinkwrap::__private::Transform2>::Options<
+        'options,
+    >: ::shrinkwrap::__private::DeprecatedNestGate,
+{
+    type Wrapper = MyDataWrapper;
+    type Error = MyError;
+    fn try_to_wrapped_with(
+        self,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Result<Self::Wrapper, Self::Error> {
+        let nest_text = if ::shrinkwrap::__private::DeprecatedNestGate::emit_deprecated(
+            options,
+            "text",
+        ) {
+            transform.try_transform_to_nest(&self, options)
+        } else {
+            ::std::result::Result::Ok(::std::option::Option::None)
+        }?;
+        let nest_text_v2 = transform.try_transform_to_nest(&self, options)?;
+        let nest_value = transform.try_transform_to_nest(&self, options)?;
+        let extra = MyDataExtra {
+            text: nest_text,
+            text_v2: nest_text_v2,
+            value: nest_value,
+        };
+        Ok(Self::Wrapper { extra, data: self })
+    }
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::TryWrapAllWith<T> for MyData
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TryTransformToNest2<
+        Option<MyDataNestedText>,
+        Data = MyData,
+        Error = MyError,
+    >,
+    T: ::shrinkwrap::__private::TryTransformToNest2<
+        MyDataNestedTextV2,
+        Data = MyData,
+        Error = MyError,
+    >,
+    T: ::shrinkwrap::__private::TryTransformToNest2<
+        MyDataNestedValueWrapper,
+        Data = MyData,
+        Error = MyError,
+    >,
+    for<'options> <T as ::shrinkwrap::__private::Transform2>::Options<
+        'options,
+    >: ::shrinkwrap::__private::DeprecatedNestGate,
+{
+    fn try_wrap_all_with(
+        data: Vec<Self>,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Result<Vec<Self::Wrapper>, Self::Error> {
+        let wrappers = data
+            .into_iter()
+            .map(|data| {
+                let nest_text = if ::shrinkwrap::__private::DeprecatedNestGate::emit_deprecated(
+                    options,
+                    "text",
+                ) {
+                    transform.try_transform_to_nest(&data, options)
+                } else {
+                    ::std::result::Result::Ok(::std::option::Option::None)
+                }?;
+                let nest_text_v2 = transform.try_transform_to_nest(&data, options)?;
+                let nest_value = transform.try_transform_to_nest(&data, options)?;
+                let extra = MyDataExtra {
+                    text: nest_text,
+                    text_v2: nest_text_v2,
+                    value: nest_value,
+                };
+                Ok(Self::Wrapper { extra, data })
+            });
+        wrappers.collect::<Result<Vec<_>, _>>()
+    }
+}
+/// Transforms which [`MyData`] can be wrapped with, i.e. those providing each of its nests
+pub trait MyDataTransforms
+where
+    Self: ::shrinkwrap::__private::Transform2,
+    Self: ::shrinkwrap::__private::TryTransformToNest2<
+        Option<MyDataNestedText>,
+        Data = MyData,
+        Error = MyError,
+    >,
+    Self: ::shrinkwrap::__private::TryTransformToNest2<
+        MyDataNestedTextV2,
+        Data = MyData,
+        Error = MyError,
+    >,
+    Self: ::shrinkwrap::__private::TryTransformToNest2<
+        MyDataNestedValueWrapper,
+        Data = MyData,
+        Error = MyError,
+    >,
+    Self: for<'options> ::shrinkwrap::__private::Transform2<
+        Options<'options>: ::shrinkwrap::__private::DeprecatedNestGate,
+    >,
+{}
+#[automatically_derived]
+impl<T> MyDataTransforms for T
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TryTransformToNest2<
+        Option<MyDataNestedText>,
+        Data = MyData,
+        Error = MyError,
+    >,
+    T: ::shrinkwrap::__private::TryTransformToNest2<
+        MyDataNestedTextV2,
+        Data = MyData,
+        Error = MyError,
+    >,
+    T: ::shrinkwrap::__private::TryTransformToNest2<
+        MyDataNestedValueWrapper,
+        Data = MyData,
+        Error = MyError,
+    >,
+    T: for<'options> ::shrinkwrap::__private::Transform2<
+        Options<'options>: ::shrinkwrap::__private::DeprecatedNestGate,
+    >,
+{}
+#[automatically_derived]
+impl ::shrinkwrap::__private::TryToWrappedWith<MyTransform> for Option<MyData> {
+    type Wrapper = Option<MyDataWrapper>;
+    type Error = MyError;
+    fn try_to_wrapped_with(
+        self,
+        transform: &MyTransform,
+        options: &<MyTransform as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Result<Self::Wrapper, Self::Error> {
+        self.map(|data| data.try_to_wrapped_with(transform, options)).transpose()
+    }
+}
+#[automatically_derived]
+impl<T> ::shrinkwrap::__private::TryToWrappedWith<T> for MyDataNestedValue
+where
+    T: ::shrinkwrap::__private::Transform2,
+    T: ::shrinkwrap::__private::TryTransformToNest2<
+        MyDataNestedValueValueText,
+        Data = MyDataNestedValue,
+        Error = MyError,
+    >,
+{
+    type Wrapper = MyDataNestedValueWrapper;
+    type Error = MyError;
+    fn try_to_wrapped_with(
+        self,
+        transform: &T,
+        options: &<T as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Result<Self::Wrapper, Self::Error> {
+        let nest_value_text = transform.try_transform_to_nest(&self, options)?;
+        let extra = MyDataNestedValueExtra {
+            value_text: nest_value_text,
+        };
+        Ok(Self::Wrapper { extra, data: self })
+    }
+}
+#[automatically_derived]
+impl ::shrinkwrap::__private::TryToWrappedWith<MyTransform>
+for Option<MyDataNestedValue> {
+    type Wrapper = Option<MyDataNestedValueWrapper>;
+    type Error = MyError;
+    fn try_to_wrapped_with(
+        self,
+        transform: &MyTransform,
+        options: &<MyTransform as ::shrinkwrap::__private::Transform2>::Options<'_>,
+    ) -> Result<Self::Wrapper, Self::Error> {
+        self.map(|data| data.try_to_wrapped_with(transform, options)).transpose()
+    }
+}
+#[automatically_derived]
+#[doc(hidden)]
+impl ::shrinkwrap::__private::TryTransformToNest2<MyDataNestedValueWrapper>
+for MyTransform {
+    type Data = MyData;
+    type Error = MyError;
+    fn try_transform_to_nest(
+        &self,
+        data: &Self::Data,
+        options: &Self::Options<'_>,
+    ) -> Result<MyDataNestedValueWrapper, Self::Error> {
+        use ::shrinkwrap::__private::{TryToNestWith, TryWrapDataWith};
+        let nest_data: MyDataNestedValue = data.try_to_nest_with(self, options)?;
+        MyDataNestedValueWrapper::try_wrap_data_with(nest_data, self, options)
+    }
+}
+#[automatically_derived]
+#[allow(private_interfaces)]
+impl MyDataExtra {
+    ///Returns the `text` nest
+    #[deprecated(note = "superseded by `text_v2`")]
+    pub fn text(&self) -> Option<&MyDataNestedText> {
+        self.text.as_ref()
+    }
+}
@@ -0,0 +1,6 @@
+::core::compile_error! {
+    "`transform_alias` cannot be used with `transform_generic_params`"
+}
+::core::compile_error! {
+    "`transform_alias` cannot be used with `concrete_impls`"
+}
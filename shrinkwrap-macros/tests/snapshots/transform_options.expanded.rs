@@ -0,0 +1,17 @@
+#[automatically_derived]
+impl<'a> ::shrinkwrap::__private::TransformOptions for MyTransformOpts<'a> {
+    const GATED_NESTS: &'static [&'static str] = &["text", "value_cents"];
+    fn enabled(&self, nest: &str) -> bool {
+        match nest {
+            "text" => self.with_text,
+            "value_cents" => self.cents,
+            _ => true,
+        }
+    }
+}
+#[automatically_derived]
+impl<'a> ::shrinkwrap::__private::DeprecatedNestGate for MyTransformOpts<'a> {
+    fn emit_deprecated(&self, nest: &str) -> bool {
+        ::shrinkwrap::__private::TransformOptions::enabled(self, nest)
+    }
+}
@@ -0,0 +1,9 @@
+::core::compile_error! {
+    "Nest `text` first gated here"
+}
+::core::compile_error! {
+    "Nest `text` is gated by multiple fields"
+}
+::core::compile_error! {
+    "`skip` cannot be used with `nest`"
+}
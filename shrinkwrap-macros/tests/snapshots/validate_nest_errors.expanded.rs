@@ -0,0 +1,12 @@
+::core::compile_error! {
+    "`layout = \"map\"` cannot be used with `validate`"
+}
+::core::compile_error! {
+    "`validate` requires a `length`, `range`, or `custom` rule"
+}
+::core::compile_error! {
+    "Field validation requires the `validate` flag on nest `value`"
+}
+::core::compile_error! {
+    "`length` requires a `min` and/or `max`"
+}
@@ -0,0 +1,33 @@
+::core::compile_error! {
+    "Unknown attribute `seder`, did you mean `serde`?"
+}
+::core::compile_error! {
+    "Unknown rename rule `camel`"
+}
+::core::compile_error! {
+    "`tag` expects a string literal"
+}
+::core::compile_error! {
+    "`untagged` is a flag and does not take a value"
+}
+::core::compile_error! {
+    "Unknown schemars struct attribute `titel`, did you mean `title`?"
+}
+::core::compile_error! {
+    "`rename` expects a value, e.g. `rename = \"..\"`"
+}
+::core::compile_error! {
+    "`skip_serializing_if` expects a path, e.g. `skip_serializing_if = \"my_mod::my_fn\"`"
+}
+::core::compile_error! {
+    "Unknown serde field attribute `renam`, did you mean `rename`?"
+}
+::core::compile_error! {
+    "`with` expects a string value, e.g. `with = \"..\"`"
+}
+::core::compile_error! {
+    "`flatten` is a flag and does not take a value"
+}
+::core::compile_error! {
+    "`rename` only accepts `serialize` and `deserialize`"
+}
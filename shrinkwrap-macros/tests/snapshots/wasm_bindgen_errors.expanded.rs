@@ -0,0 +1,6 @@
+::core::compile_error! {
+    "`wasm_bindgen` cannot be used with `extra(exclusive)`"
+}
+::core::compile_error! {
+    "`wasm_bindgen` cannot be used with `layout = \"map\"`"
+}
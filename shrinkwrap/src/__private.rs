@@ -0,0 +1,116 @@
+//! Items referenced by `Wrap` generated code, not part of the public API.
+//!
+//! Generated code only names items via this module, allowing internal machinery to change (or be moved) without
+//! affecting the public paths. Nothing within is covered by semver.
+
+pub use crate::{
+    BatchTransformToNest2,
+    BuildNestValue2,
+    DeprecatedNestGate,
+    Error,
+    NestError,
+    RowView,
+    SerializableOptions,
+    ToNestWith,
+    Transform,
+    Transform2,
+    TransformFromSources2,
+    TransformOptions,
+    TransformToNest2,
+    TransformToNestWithSiblings2,
+    ToWrappedWith,
+    TryBatchTransformToNest2,
+    TryBuildNestValue2,
+    TryToNestWith,
+    TryToWrappedWith,
+    TryToWrappedWithErrors,
+    TryTransformFromSources2,
+    TryTransformToNest2,
+    TryTransformToNestWithSiblings2,
+    TryWrapAllWith,
+    TryWrapDataWith,
+    WrapAllWith,
+    WrapDataWith,
+};
+
+pub use crate::alias::enabled as aliases_enabled;
+
+pub use crate::batch::batch_nests;
+
+pub use crate::diagnostics::{WrapDiagnostics, collect_timings, timed_nest};
+
+pub use shrinkwrap_macros::__apply_preset as apply_preset;
+
+pub use crate::assert_impls::{
+    ImplementsOptionalNest,
+    ImplementsOptionalNestWithSiblings,
+    ImplementsThreadsafe,
+    ImplementsTransform,
+    TryImplementsOptionalNest,
+    TryImplementsOptionalNestWithSiblings,
+};
+
+#[cfg(feature = "http")]
+pub use crate::http::json_response as http_json_response;
+#[cfg(feature = "http")]
+pub use http::{Response as HttpResponse, StatusCode as HttpStatusCode};
+
+#[cfg(feature = "integrity")]
+pub use crate::integrity::{checksum as integrity_checksum, signature as integrity_signature};
+
+#[cfg(feature = "providers")]
+pub use crate::providers::NestProvider;
+
+#[cfg(any(feature = "csv", feature = "json"))]
+pub use crate::render::value as render_value;
+
+#[cfg(feature = "tonic")]
+pub use crate::grpc::{ProtoKind, proto_fields};
+#[cfg(feature = "tonic")]
+pub use prost_types;
+
+#[cfg(feature = "topology")]
+pub use crate::topology::NestNode;
+
+#[cfg(feature = "validate")]
+pub use validator;
+
+#[cfg(feature = "wasm")]
+pub use wasm_bindgen;
+
+#[cfg(feature = "json")]
+pub use crate::encode::{json_string, json_string_option};
+#[cfg(feature = "json")]
+pub use crate::filter::{NestKey, NestTree, to_value_filtered};
+#[cfg(feature = "json")]
+pub use crate::shape::{JsonShape, Shape, ShapeKey, ShapeMismatch, check as check_shape};
+#[cfg(feature = "json")]
+pub use serde_json::Value as JsonValue;
+
+/// Panics with the differences between `left` and `right` unless their JSON encodings are equal, used by
+/// `assert_wrapped_eq!` and `assert_nest_eq!`
+#[cfg(feature = "diff")]
+#[track_caller]
+pub fn assert_json_eq<L, R>(left: &L, right: &R, summary: &str, message: Option<core::fmt::Arguments<'_>>)
+where
+    L: serde::Serialize + ?Sized,
+    R: serde::Serialize + ?Sized,
+{
+    let left = serde_json::to_value(left).unwrap_or_else(|err| panic!("failed to serialize `left`: {err}"));
+    let right = serde_json::to_value(right).unwrap_or_else(|err| panic!("failed to serialize `right`: {err}"));
+
+    let diff = crate::diff::diff_values(&left, &right);
+    if diff.is_empty() {
+        return;
+    }
+    match message {
+        Some(message) => panic!("assertion `left == right` failed: {message}\n{diff}"),
+        None => panic!("assertion `left == right` failed: {summary}\n{diff}"),
+    }
+}
+
+/// Deserializes a wrapper from a JSON value, used by generated `TryFrom<serde_json::Value>` impls
+#[cfg(feature = "json")]
+pub fn from_json_value<W: serde::de::DeserializeOwned>(value: JsonValue) -> Result<W, crate::Error> {
+    Ok(serde_json::from_value(value)?)
+}
@@ -0,0 +1,87 @@
+//! Dual emission of aliased nest keys, used via `nest(.., alias = "..", emit_alias)` of the [`Wrap`](crate::Wrap)
+//! derive.
+//!
+//! `alias` records the previous serialized key of a renamed nest, which is accepted when deserializing. Alongside
+//! `emit_alias`, the nest is serialized under both its key and its alias while aliases are enabled for the current
+//! thread (via [`with_aliases`] or [`enable`]), allowing clients to migrate to the new key over a transition period.
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use shrinkwrap::{ToWrappedWith, Transform, TransformToNest, Wrap};
+//!
+//! #[derive(Debug, Clone, Serialize, Deserialize, Wrap)]
+//! #[shrinkwrap(transform = MyTransform, wrapper(deserialize))]
+//! #[shrinkwrap(nest(id = "text", field_type = String, alias = "txt", emit_alias))]
+//! pub struct Account {
+//!     #[shrinkwrap(nest(id = "text"))]
+//!     balance: f64,
+//! }
+//!
+//! struct MyTransform;
+//! impl Transform for MyTransform {
+//!     type Options = ();
+//! }
+//! impl TransformToNest<AccountNestedText> for MyTransform {
+//!     type Data = Account;
+//!
+//!     fn transform_to_nest(&self, data: &Account, _: &()) -> AccountNestedText {
+//!         AccountNestedText { balance: format!("${:.2}", data.balance) }
+//!     }
+//! }
+//!
+//! let wrapper = Account { balance: 2.5 }.to_wrapped_with(&MyTransform, &());
+//! let json = serde_json::to_string(&wrapper).unwrap();
+//! assert_eq!(json, r#"{"extra":{"text":{"balance":"$2.50"}},"balance":2.5}"#);
+//!
+//! // e.g. for clients which haven't migrated yet
+//! let json = shrinkwrap::alias::with_aliases(|| serde_json::to_string(&wrapper).unwrap());
+//! assert_eq!(json, r#"{"extra":{"text":{"balance":"$2.50"},"txt":{"balance":"$2.50"}},"balance":2.5}"#);
+//!
+//! // the previous key is accepted when deserializing
+//! let wrapper: AccountWrapper = serde_json::from_str(r#"{"extra":{"txt":{"balance":"$2.50"}},"balance":2.5}"#).unwrap();
+//! assert_eq!(wrapper.extra.text.balance, "$2.50");
+//! ```
+
+use std::cell::Cell;
+use std::fmt;
+use std::marker::PhantomData;
+
+thread_local! {
+    static ENABLED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Enables aliases for the current thread while `f` runs, restoring the previous state afterwards (including when `f`
+/// panics).
+pub fn with_aliases<R>(f: impl FnOnce() -> R) -> R {
+    let _guard = enable();
+    f()
+}
+
+/// Enables aliases for the current thread until the returned guard is dropped.
+pub fn enable() -> AliasGuard {
+    AliasGuard { previous: ENABLED.replace(true), _not_send: PhantomData }
+}
+
+/// Whether aliases are enabled for the current thread.
+pub fn enabled() -> bool {
+    ENABLED.get()
+}
+
+/// Restores the previous alias state of the current thread when dropped.
+#[must_use = "aliases are disabled as soon as the guard is dropped"]
+pub struct AliasGuard {
+    previous: bool,
+
+    /// The state is thread-local, the guard must be dropped on the thread that created it
+    _not_send: PhantomData<*const ()>,
+}
+impl fmt::Debug for AliasGuard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AliasGuard").field("previous", &self.previous).finish()
+    }
+}
+impl Drop for AliasGuard {
+    fn drop(&mut self) {
+        ENABLED.set(self.previous);
+    }
+}
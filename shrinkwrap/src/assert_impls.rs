@@ -0,0 +1,66 @@
+//! Definition-site impl assertions, generated via the `assert_optional_impls` option of the [`Wrap`](crate::Wrap) derive.
+//!
+//! Each trait is implemented for any transform providing the `Option<Nest>` variant of the corresponding nest trait.
+//! Missing impls are reported against the assertion trait itself (rather than the nest trait), allowing the error to
+//! point out that optional nests must target `Option<Nest>`.
+//!
+//! [`ImplementsTransform`] is asserted for every (non-generic) transform type regardless of options, reporting a
+//! missing `Transform` impl once at the `transform` attribute rather than throughout the generated impls.
+//!
+//! [`ImplementsThreadsafe`] is asserted for each generated struct via the `assert_threadsafe` flag.
+
+use crate::{Transform2, TransformToNest2, TransformToNestWithSiblings2, TryTransformToNest2, TryTransformToNestWithSiblings2};
+
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not implement `TransformToNest<Option<{N}>>`, required by optional nest `{N}`",
+    label = "optional nest is missing a `TransformToNest<Option<{N}>>` impl",
+    note = "optional nests are built from `impl TransformToNest<Option<{N}>>`, an impl of `TransformToNest<{N}>` is not used"
+)]
+pub trait ImplementsOptionalNest<N> {}
+#[diagnostic::do_not_recommend]
+impl<T: TransformToNest2<Option<N>>, N> ImplementsOptionalNest<N> for T {}
+
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not implement `TryTransformToNest<Option<{N}>>`, required by optional nest `{N}`",
+    label = "optional nest is missing a `TryTransformToNest<Option<{N}>>` impl",
+    note = "optional nests are built from `impl TryTransformToNest<Option<{N}>>`, an impl of `TryTransformToNest<{N}>` is not used"
+)]
+pub trait TryImplementsOptionalNest<N> {}
+#[diagnostic::do_not_recommend]
+impl<T: TryTransformToNest2<Option<N>>, N> TryImplementsOptionalNest<N> for T {}
+
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not implement `TransformToNestWithSiblings<Option<{N}>, {S}>`, required by optional nest `{N}`",
+    label = "optional nest is missing a `TransformToNestWithSiblings<Option<{N}>, {S}>` impl",
+    note = "optional nests are built from `impl TransformToNestWithSiblings<Option<{N}>, {S}>`, an impl of `TransformToNestWithSiblings<{N}, {S}>` is not used"
+)]
+pub trait ImplementsOptionalNestWithSiblings<N, S> {}
+#[diagnostic::do_not_recommend]
+impl<T: TransformToNestWithSiblings2<Option<N>, S>, N, S> ImplementsOptionalNestWithSiblings<N, S> for T {}
+
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not implement `TryTransformToNestWithSiblings<Option<{N}>, {S}>`, required by optional nest `{N}`",
+    label = "optional nest is missing a `TryTransformToNestWithSiblings<Option<{N}>, {S}>` impl",
+    note = "optional nests are built from `impl TryTransformToNestWithSiblings<Option<{N}>, {S}>`, an impl of `TryTransformToNestWithSiblings<{N}, {S}>` is not used"
+)]
+pub trait TryImplementsOptionalNestWithSiblings<N, S> {}
+#[diagnostic::do_not_recommend]
+impl<T: TryTransformToNestWithSiblings2<Option<N>, S>, N, S> TryImplementsOptionalNestWithSiblings<N, S> for T {}
+
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not implement `Transform`, required by `#[shrinkwrap(transform = ..)]`",
+    label = "transform type must implement `shrinkwrap::Transform`",
+    note = "add `impl shrinkwrap::Transform for {Self} {{ type Options = ..; }}`, or `shrinkwrap::Transform2` for borrowing options"
+)]
+pub trait ImplementsTransform {}
+#[diagnostic::do_not_recommend]
+impl<T: Transform2 + ?Sized> ImplementsTransform for T {}
+
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not `Send + Sync + 'static`, required by `#[shrinkwrap(assert_threadsafe)]`",
+    label = "generated struct must be `Send + Sync + 'static`",
+    note = "a field of the data or of a nest is not thread-safe, e.g. `Rc<T>`, `RefCell<T>`, or a borrowed type"
+)]
+pub trait ImplementsThreadsafe {}
+#[diagnostic::do_not_recommend]
+impl<T: Send + Sync + 'static + ?Sized> ImplementsThreadsafe for T {}
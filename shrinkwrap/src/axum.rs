@@ -0,0 +1,260 @@
+//! [Axum](https://docs.rs/axum) integration, enabled via the `axum` feature.
+//!
+//! Handlers return data as a [`WrappedJson`] (or [`TryWrappedJson`]) response, which is wrapped and serialized by the
+//! [`wrap_responses`] middleware using the [`WrapContext`] (transform + options) stored in the request's extensions.
+//! Services can therefore standardize on always wrapping responses, without per-handler transform calls.
+//!
+//! ```
+//! use axum::{Extension, Router, middleware, routing::get};
+//! use shrinkwrap::axum::{WrapContext, WrappedJson, wrap_responses};
+//! # use serde::Serialize;
+//! # use shrinkwrap::{Transform, TransformToNest, Wrap};
+//! #
+//! # #[derive(Debug, Clone, Serialize, Wrap)]
+//! # #[shrinkwrap(transform = MyTransform)]
+//! # #[shrinkwrap(nest(id = "text", field_type = String))]
+//! # pub struct Account {
+//! #     #[shrinkwrap(nest(id = "text"))]
+//! #     balance: i64,
+//! # }
+//! # pub struct MyTransform;
+//! # impl Transform for MyTransform {
+//! #     type Options = ();
+//! # }
+//! # impl TransformToNest<AccountNestedText> for MyTransform {
+//! #     type Data = Account;
+//! #
+//! #     fn transform_to_nest(&self, data: &Account, _: &()) -> AccountNestedText {
+//! #         AccountNestedText { balance: data.balance.to_string() }
+//! #     }
+//! # }
+//!
+//! async fn account() -> WrappedJson<Account, MyTransform> {
+//!     WrappedJson::new(Account { balance: 1250 })
+//! }
+//!
+//! let app: Router = Router::new()
+//!     .route("/account", get(account))
+//!     .layer(middleware::from_fn(wrap_responses))
+//!     .layer(Extension(WrapContext::new(MyTransform, ())));
+//! ```
+
+use axum::Json;
+use axum::extract::Request;
+use axum::http::{Extensions, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use serde::Serialize;
+use std::any::type_name;
+use std::cell::Cell;
+use std::collections::HashSet;
+use std::fmt;
+use std::future::{Future, poll_fn};
+use std::marker::PhantomData;
+use std::pin::pin;
+use std::sync::{Arc, Mutex};
+
+use crate::{ToWrappedWith, Transform, TryToWrappedWith};
+
+thread_local! {
+    /// Whether the current thread is polling a handler within [`wrap_responses`], see [`WrapScope`]
+    static IN_WRAP_SCOPE: Cell<bool> = const { Cell::new(false) };
+}
+
+// !- WrapContext
+
+/// The transform and options used to wrap responses, read from the request's extensions by [`wrap_responses`].
+///
+/// Typically added for all routes via `Extension(WrapContext::new(..))`, per-request options (e.g. parsed from
+/// headers) can be set by inserting an updated context from a preceding middleware, see [`WrapContext::with_options`].
+pub struct WrapContext<T: Transform> {
+    transform: Arc<T>,
+    options: Arc<T::Options>,
+}
+impl<T: Transform> WrapContext<T> {
+    pub fn new(transform: T, options: T::Options) -> Self {
+        Self { transform: Arc::new(transform), options: Arc::new(options) }
+    }
+
+    /// A context sharing the same transform, with different options
+    pub fn with_options(&self, options: T::Options) -> Self {
+        Self { transform: self.transform.clone(), options: Arc::new(options) }
+    }
+
+    pub fn transform(&self) -> &T {
+        &self.transform
+    }
+
+    pub fn options(&self) -> &T::Options {
+        &self.options
+    }
+}
+impl<T: Transform> Clone for WrapContext<T> {
+    fn clone(&self) -> Self {
+        Self { transform: self.transform.clone(), options: self.options.clone() }
+    }
+}
+impl<T: Transform> fmt::Debug for WrapContext<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WrapContext").field("transform", &type_name::<T>()).finish_non_exhaustive()
+    }
+}
+
+// !- Responses
+
+/// Response wrapping `D` with the transform `T`, serialized as JSON.
+///
+/// Rendered by the [`wrap_responses`] middleware, the status and headers set alongside the response (e.g. via
+/// `(StatusCode::CREATED, WrappedJson::new(data))`) are retained.
+pub struct WrappedJson<D, T>(pub D, PhantomData<fn() -> T>);
+
+impl<D, T> WrappedJson<D, T> {
+    pub fn new(data: D) -> Self {
+        Self(data, PhantomData)
+    }
+}
+impl<D, T> From<D> for WrappedJson<D, T> {
+    fn from(data: D) -> Self {
+        Self::new(data)
+    }
+}
+impl<D: fmt::Debug, T> fmt::Debug for WrappedJson<D, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("WrappedJson").field(&self.0).finish()
+    }
+}
+impl<D, T> IntoResponse for WrappedJson<D, T>
+where
+    T: Transform + Send + Sync + 'static,
+    T::Options: Send + Sync,
+    D: ToWrappedWith<T> + Send + 'static,
+    D::Wrapper: Serialize,
+{
+    fn into_response(self) -> Response {
+        let data = self.0;
+        DeferredWrap::placeholder(move |context: &WrapContext<T>| {
+            Json(data.to_wrapped_with(context.transform(), context.options())).into_response()
+        })
+    }
+}
+
+/// Fallible variant of [`WrappedJson`], transform errors are rendered via their own [`IntoResponse`] impl.
+pub struct TryWrappedJson<D, T>(pub D, PhantomData<fn() -> T>);
+
+impl<D, T> TryWrappedJson<D, T> {
+    pub fn new(data: D) -> Self {
+        Self(data, PhantomData)
+    }
+}
+impl<D, T> From<D> for TryWrappedJson<D, T> {
+    fn from(data: D) -> Self {
+        Self::new(data)
+    }
+}
+impl<D: fmt::Debug, T> fmt::Debug for TryWrappedJson<D, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("TryWrappedJson").field(&self.0).finish()
+    }
+}
+impl<D, T> IntoResponse for TryWrappedJson<D, T>
+where
+    T: Transform + Send + Sync + 'static,
+    T::Options: Send + Sync,
+    D: TryToWrappedWith<T> + Send + 'static,
+    D::Wrapper: Serialize,
+    D::Error: IntoResponse,
+{
+    fn into_response(self) -> Response {
+        let data = self.0;
+        DeferredWrap::placeholder(move |context: &WrapContext<T>| {
+            match data.try_to_wrapped_with(context.transform(), context.options()) {
+                Ok(wrapper) => Json(wrapper).into_response(),
+                Err(error) => error.into_response(),
+            }
+        })
+    }
+}
+
+// !- Middleware
+
+/// Renders [`WrappedJson`]/[`TryWrappedJson`] responses, using the [`WrapContext`] from the request's extensions.
+///
+/// Install via [`axum::middleware::from_fn`], the [`WrapContext`] must be added by a layer wrapping this middleware
+/// (i.e. a later `.layer(..)` call). Responses fail with `500 Internal Server Error` if the context is missing, or if
+/// they aren't created within this middleware (e.g. without it installed, or behind a layer moving the handler onto
+/// another task such as `tower::buffer`).
+pub async fn wrap_responses(request: Request, next: Next) -> Response {
+    let extensions = request.extensions().clone();
+    let mut run = pin!(next.run(request));
+    let mut response = poll_fn(|cx| {
+        let _scope = WrapScope::enter();
+        run.as_mut().poll(cx)
+    }).await;
+
+    let Some(render) = response.extensions_mut().remove::<DeferredWrap>().and_then(|deferred| deferred.take()) else {
+        return response;
+    };
+    let (parts, _) = response.into_parts();
+    let mut rendered = render(&extensions);
+    // the placeholder is `200 OK`, any other status was set alongside the response
+    if rendered.status().is_success() {
+        *rendered.status_mut() = parts.status;
+    }
+    // headers set alongside the response are kept unless rendered, including each value of repeated headers
+    let rendered_names = rendered.headers().keys().cloned().collect::<HashSet<_>>();
+    for name in parts.headers.keys().filter(|name| !rendered_names.contains(*name)) {
+        for value in parts.headers.get_all(name) {
+            rendered.headers_mut().append(name.clone(), value.clone());
+        }
+    }
+    rendered
+}
+
+type RenderFn = Box<dyn FnOnce(&Extensions) -> Response + Send>;
+
+/// Response extension holding the pending wrap of a response's data
+#[derive(Clone)]
+struct DeferredWrap(Arc<Mutex<Option<RenderFn>>>);
+
+impl DeferredWrap {
+    /// Placeholder response carrying the deferred render, the status set alongside it is applied to the rendered
+    /// response
+    fn placeholder<T>(render: impl FnOnce(&WrapContext<T>) -> Response + Send + 'static) -> Response
+    where
+        T: Transform + Send + Sync + 'static,
+        T::Options: Send + Sync,
+    {
+        let render: RenderFn = Box::new(move |extensions: &Extensions| match extensions.get::<WrapContext<T>>() {
+            Some(context) => render(context),
+            None => {
+                let message = format!("missing `WrapContext<{}>` request extension", type_name::<T>());
+                (StatusCode::INTERNAL_SERVER_ERROR, message).into_response()
+            },
+        });
+        if !IN_WRAP_SCOPE.get() {
+            return (StatusCode::INTERNAL_SERVER_ERROR, "wrapped responses require the `wrap_responses` middleware").into_response();
+        }
+        let mut response = Response::default();
+        response.extensions_mut().insert(Self(Arc::new(Mutex::new(Some(render)))));
+        response
+    }
+
+    fn take(self) -> Option<RenderFn> {
+        self.0.lock().ok()?.take()
+    }
+}
+
+/// Marks the current thread as polling within [`wrap_responses`] until dropped, restoring the previous state
+struct WrapScope {
+    previous: bool,
+}
+impl WrapScope {
+    fn enter() -> Self {
+        Self { previous: IN_WRAP_SCOPE.replace(true) }
+    }
+}
+impl Drop for WrapScope {
+    fn drop(&mut self) {
+        IN_WRAP_SCOPE.set(self.previous);
+    }
+}
@@ -0,0 +1,132 @@
+use crate::Error;
+use crate::transform::{NoOptions, Transform, Transform2};
+use crate::wrap::ToWrappedWith;
+
+/// Batched data -> nest conversion, for nests declaring `batch`.
+///
+/// Builds the nest for a whole collection of data at once, allowing lookups to be performed once per batch rather
+/// than once per item (e.g. a single query for all accounts of a list endpoint). Batch nests are computed in a single
+/// call by [`to_wrapped_all_with`](ToWrappedAllWith::to_wrapped_all_with), all other nests are computed per item.
+///
+/// A [`TransformToNest`](crate::TransformToNest) impl is still required for the nest, which is used when wrapping a
+/// single item, and for every item of the collection if the batch impl doesn't return exactly one nest per item.
+///
+/// # Examples
+///
+/// ```
+/// use shrinkwrap::{BatchTransformToNest, ToWrappedAllWith, Transform, TransformToNest, Wrap};
+/// use std::cell::Cell;
+///
+/// #[derive(Debug, Clone, serde::Serialize, Wrap)]
+/// #[shrinkwrap(transform = MyTransform)]
+/// #[shrinkwrap(nest(id = "text", field_type = String, batch))]
+/// pub struct Account {
+///     #[shrinkwrap(nest(id = "text"))]
+///     owner_id: u32,
+/// }
+///
+/// #[derive(Default)]
+/// struct MyTransform {
+///     lookups: Cell<usize>,
+/// }
+/// impl Transform for MyTransform {
+///     type Options = ();
+/// }
+/// impl TransformToNest<AccountNestedText> for MyTransform {
+///     type Data = Account;
+///
+///     fn transform_to_nest(&self, data: &Account, options: &()) -> AccountNestedText {
+///         self.transform_many(std::slice::from_ref(data), options).remove(0)
+///     }
+/// }
+/// impl BatchTransformToNest<AccountNestedText> for MyTransform {
+///     type Data = Account;
+///
+///     fn transform_many(&self, data: &[Account], _: &()) -> Vec<AccountNestedText> {
+///         // e.g. a single `WHERE id IN (..)` query for all owners
+///         self.lookups.set(self.lookups.get() + 1);
+///         data.iter().map(|account| AccountNestedText { owner_id: format!("user-{}", account.owner_id) }).collect()
+///     }
+/// }
+///
+/// let transform = MyTransform::default();
+/// let wrappers = vec![Account { owner_id: 1 }, Account { owner_id: 2 }].to_wrapped_all_with_t(&transform);
+/// assert_eq!(wrappers[1].extra.text.owner_id, "user-2");
+/// assert_eq!(transform.lookups.get(), 1);
+/// ```
+pub trait BatchTransformToNest<N>: Transform {
+    type Data;
+
+    /// Builds a nest for each item of `data`, must return exactly one nest per item, in the same order
+    fn transform_many(&self, data: &[Self::Data], options: &Self::Options) -> Vec<N>;
+}
+
+/// Variant of [`BatchTransformToNest`] for [`Transform2`] transforms, see [`TransformToNest2`](crate::TransformToNest2)
+pub trait BatchTransformToNest2<N>: Transform2 {
+    type Data;
+
+    /// Builds a nest for each item of `data`, must return exactly one nest per item, in the same order
+    fn transform_many(&self, data: &[Self::Data], options: &Self::Options<'_>) -> Vec<N>;
+}
+
+impl<N, T: BatchTransformToNest<N> + ?Sized> BatchTransformToNest2<N> for T {
+    type Data = T::Data;
+
+    fn transform_many(&self, data: &[Self::Data], options: &Self::Options<'_>) -> Vec<N> {
+        BatchTransformToNest::transform_many(self, data, options)
+    }
+}
+
+/// Converts a collection of data structs into wrappers, computing `batch` nests once for the whole collection.
+///
+/// Automatically implemented for data structs alongside [`ToWrappedWith`](crate::ToWrappedWith), the conversion is
+/// typically initiated via [`ToWrappedAllWith`] instead.
+pub trait WrapAllWith<T>: ToWrappedWith<T>
+where
+    T: Transform2,
+{
+    fn wrap_all_with(data: Vec<Self>, transform: &T, options: &T::Options<'_>) -> Vec<Self::Wrapper>;
+}
+
+/// Converts a collection of data structs into wrappers, see [`WrapAllWith`].
+///
+/// Automatically implemented for `Vec`s of data structs providing [`WrapAllWith`].
+pub trait ToWrappedAllWith<T>
+where
+    T: Transform2,
+{
+    type Wrapper;
+
+    fn to_wrapped_all_with(self, transform: &T, options: &T::Options<'_>) -> Vec<Self::Wrapper>;
+
+    /// Shorthand for [`to_wrapped_all_with`](Self::to_wrapped_all_with), for transforms without options
+    fn to_wrapped_all_with_t(self, transform: &T) -> Vec<Self::Wrapper>
+    where
+        Self: Sized,
+        T: NoOptions,
+    {
+        self.to_wrapped_all_with(transform, &())
+    }
+}
+impl<D, T> ToWrappedAllWith<T> for Vec<D>
+where
+    T: Transform2,
+    D: WrapAllWith<T>,
+{
+    type Wrapper = D::Wrapper;
+
+    fn to_wrapped_all_with(self, transform: &T, options: &T::Options<'_>) -> Vec<D::Wrapper> {
+        D::wrap_all_with(self, transform, options)
+    }
+}
+
+/// Checks the number of nests returned by a batch impl, used by generated `wrap_all_with` fns
+///
+/// Fails with [`Error::BatchLength`] if the number of nests doesn't match `len`, in which case generated fns fall back
+/// to computing the nest per item.
+pub fn batch_nests<N>(nests: Vec<N>, len: usize, nest: &'static str) -> Result<std::vec::IntoIter<N>, Error> {
+    if nests.len() != len {
+        return Err(Error::BatchLength { nest, expected: len, actual: nests.len() });
+    }
+    Ok(nests.into_iter())
+}
@@ -1,6 +1,6 @@
 use crate::{
     nest::NestValueType,
-    transform::Transform,
+    transform::{Transform, Transform2},
 };
 
 /// # Generic parameters
@@ -13,3 +13,20 @@ where
 {
     fn build_nest_value(&self, source: &T, options: &Self::Options) -> V;
 }
+
+/// Variant of [`BuildNestValue`] for [`Transform2`] transforms, see [`TransformToNest2`](crate::TransformToNest2)
+pub trait BuildNestValue2<T, V>: Transform2
+where
+    V: NestValueType
+{
+    fn build_nest_value(&self, source: &T, options: &Self::Options<'_>) -> V;
+}
+
+impl<T, V, X: BuildNestValue<T, V> + ?Sized> BuildNestValue2<T, V> for X
+where
+    V: NestValueType
+{
+    fn build_nest_value(&self, source: &T, options: &Self::Options<'_>) -> V {
+        BuildNestValue::build_nest_value(self, source, options)
+    }
+}
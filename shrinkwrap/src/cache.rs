@@ -0,0 +1,170 @@
+//! Caching of computed wrappers, enabled via the `cache` feature.
+//!
+//! For hot read-mostly endpoints even a fast transform adds latency at scale. A [`WrapCache`] stores each computed
+//! wrapper under a key extracted from its data (e.g. an ID and a version), subsequent lookups for the same key return
+//! the stored wrapper without invoking the transform. Entries are never evicted implicitly, they must be invalidated
+//! explicitly once the underlying data changes.
+//!
+//! The options are not part of the key, a cache should therefore only be shared across calls using equivalent
+//! options (or the options should be included in the extracted key).
+//!
+//! ## Thread safety
+//!
+//! `WrapCache` is `Send + Sync` whenever the key and wrapper types are, wrappers are handed out as `Arc`s. The lock is
+//! not held while wrapping, concurrent misses for the same key may therefore each invoke the transform, the first
+//! stored wrapper is retained.
+//!
+//! ```
+//! use serde::Serialize;
+//! use shrinkwrap::{Transform, TransformToNest, Wrap};
+//! use shrinkwrap::cache::WrapCache;
+//! use std::cell::Cell;
+//!
+//! #[derive(Debug, Clone, Serialize, Wrap)]
+//! #[shrinkwrap(transform = MyTransform)]
+//! #[shrinkwrap(nest(id = "text", field_type = String))]
+//! pub struct Account {
+//!     id: u64,
+//!     #[shrinkwrap(nest(id = "text"))]
+//!     balance: i64,
+//! }
+//!
+//! #[derive(Default)]
+//! struct MyTransform {
+//!     calls: Cell<usize>,
+//! }
+//! impl Transform for MyTransform {
+//!     type Options = ();
+//! }
+//! impl TransformToNest<AccountNestedText> for MyTransform {
+//!     type Data = Account;
+//!
+//!     fn transform_to_nest(&self, data: &Account, _: &()) -> AccountNestedText {
+//!         self.calls.set(self.calls.get() + 1);
+//!         AccountNestedText { balance: format!("${}", data.balance) }
+//!     }
+//! }
+//!
+//! let transform = MyTransform::default();
+//! let cache = WrapCache::new(|account: &Account| account.id);
+//!
+//! let first = cache.get_or_wrap(Account { id: 1, balance: 10 }, &transform, &());
+//! let second = cache.get_or_wrap(Account { id: 1, balance: 10 }, &transform, &());
+//! assert_eq!(second.extra.text.balance, "$10");
+//! assert_eq!(transform.calls.get(), 1);
+//!
+//! // the balance changed, the stale entry must be invalidated
+//! assert!(cache.invalidate(&1));
+//! let third = cache.get_or_wrap(Account { id: 1, balance: 20 }, &transform, &());
+//! assert_eq!(third.extra.text.balance, "$20");
+//! assert_eq!(transform.calls.get(), 2);
+//! # drop(first);
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use crate::{ToWrappedWith, Transform2};
+
+/// A cache of the wrappers of `D`, keyed by `K`, see the [module docs](self)
+pub struct WrapCache<D, T, K>
+where
+    T: Transform2,
+    D: ToWrappedWith<T>,
+{
+    entries: Mutex<HashMap<K, Arc<D::Wrapper>>>,
+    key: Box<dyn Fn(&D) -> K + Send + Sync>,
+}
+impl<D, T, K> WrapCache<D, T, K>
+where
+    T: Transform2,
+    D: ToWrappedWith<T>,
+    K: Eq + Hash,
+{
+    /// Creates an empty cache, entries are keyed by the result of `key`
+    pub fn new(key: impl Fn(&D) -> K + Send + Sync + 'static) -> Self {
+        Self { entries: Mutex::new(HashMap::new()), key: Box::new(key) }
+    }
+
+    /// Returns the cached wrapper for the key of `data`, wrapping (and storing) it if the key isn't cached yet
+    pub fn get_or_wrap(&self, data: D, transform: &T, options: &T::Options<'_>) -> Arc<D::Wrapper> {
+        let key = (self.key)(&data);
+        if let Some(wrapper) = self.lock().get(&key) {
+            return Arc::clone(wrapper);
+        }
+        let wrapper = Arc::new(data.to_wrapped_with(transform, options));
+        Arc::clone(self.lock().entry(key).or_insert(wrapper))
+    }
+
+    /// Returns the cached wrapper for `key`, if any
+    pub fn get(&self, key: &K) -> Option<Arc<D::Wrapper>> {
+        self.lock().get(key).cloned()
+    }
+
+    /// Removes the cached wrapper for `key`, returning whether it was cached
+    ///
+    /// ```
+    /// # use serde::Serialize;
+    /// # use shrinkwrap::{Transform, TransformToNest, Wrap};
+    /// use shrinkwrap::cache::WrapCache;
+    /// # #[derive(Debug, Clone, Serialize, Wrap)]
+    /// # #[shrinkwrap(transform = MyTransform)]
+    /// # #[shrinkwrap(nest(id = "text", field_type = String))]
+    /// # pub struct Account {
+    /// #     id: u64,
+    /// #     #[shrinkwrap(nest(id = "text"))]
+    /// #     balance: i64,
+    /// # }
+    /// # struct MyTransform;
+    /// # impl Transform for MyTransform {
+    /// #     type Options = ();
+    /// # }
+    /// # impl TransformToNest<AccountNestedText> for MyTransform {
+    /// #     type Data = Account;
+    /// #     fn transform_to_nest(&self, data: &Account, _: &()) -> AccountNestedText {
+    /// #         AccountNestedText { balance: data.balance.to_string() }
+    /// #     }
+    /// # }
+    ///
+    /// let cache = WrapCache::new(|account: &Account| account.id);
+    /// cache.get_or_wrap(Account { id: 1, balance: 10 }, &MyTransform, &());
+    /// assert!(cache.invalidate(&1));
+    /// assert!(!cache.invalidate(&1));
+    /// assert!(cache.get(&1).is_none());
+    /// ```
+    pub fn invalidate(&self, key: &K) -> bool {
+        self.lock().remove(key).is_some()
+    }
+
+    /// Removes all cached wrappers
+    pub fn invalidate_all(&self) {
+        self.lock().clear();
+    }
+
+    /// Number of cached wrappers
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Whether no wrappers are cached
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// Locks the entries, a poisoned lock is recovered as entries are only inserted and removed whole
+    fn lock(&self) -> MutexGuard<'_, HashMap<K, Arc<D::Wrapper>>> {
+        self.entries.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+impl<D, T, K> fmt::Debug for WrapCache<D, T, K>
+where
+    T: Transform2,
+    D: ToWrappedWith<T>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let len = self.entries.lock().map_or_else(|poisoned| poisoned.get_ref().len(), |entries| entries.len());
+        f.debug_struct("WrapCache").field("len", &len).finish_non_exhaustive()
+    }
+}
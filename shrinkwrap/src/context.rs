@@ -0,0 +1,135 @@
+//! Serialization context, enabled via the `context` feature.
+//!
+//! Allows serialization of nests to consult request-local state (e.g. a locale) at serialization time, rather than
+//! baking it into the nest at transform time. A context is set for the current thread via [`with`] or [`set`], and
+//! consulted by [`Contextual`] fields (or directly via [`with_current`]) while serializing.
+//!
+//! Contexts are keyed by type, multiple contexts of distinct types may be set at once. Setting a context of a type
+//! which is already set shadows it until the newer context is cleared.
+//!
+//! ```
+//! use serde::Serializer;
+//! use shrinkwrap::context::{self, Contextual, ContextualSerialize};
+//!
+//! struct Locale(&'static str);
+//!
+//! struct Price(f64);
+//! impl ContextualSerialize for Price {
+//!     type Context = Locale;
+//!
+//!     fn serialize_in_context<S: Serializer>(&self, locale: Option<&Locale>, serializer: S) -> Result<S::Ok, S::Error> {
+//!         match locale {
+//!             Some(Locale("de")) => serializer.serialize_str(&format!("{:.2} €", self.0).replace('.', ",")),
+//!             _ => serializer.serialize_str(&format!("€{:.2}", self.0)),
+//!         }
+//!     }
+//! }
+//!
+//! let price = Contextual(Price(12.5));
+//! assert_eq!(serde_json::to_string(&price).unwrap(), r#""€12.50""#);
+//!
+//! let localized = context::with(Locale("de"), || serde_json::to_string(&price).unwrap());
+//! assert_eq!(localized, r#""12,50 €""#);
+//! ```
+
+use serde::{Serialize, Serializer};
+use std::any::{Any, TypeId};
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+/// A context set for the current thread
+struct Entry {
+    /// Unique (per thread) ID of the guard owning the entry
+    guard: u64,
+    type_id: TypeId,
+    context: Rc<dyn Any>,
+}
+
+thread_local! {
+    static CONTEXTS: RefCell<Vec<Entry>> = const { RefCell::new(Vec::new()) };
+    static NEXT_GUARD: Cell<u64> = const { Cell::new(0) };
+}
+
+/// Sets `context` for the current thread while `f` runs, restoring any previous context of the same type afterwards
+/// (including when `f` panics).
+pub fn with<C: 'static, R>(context: C, f: impl FnOnce() -> R) -> R {
+    let _guard = set(context);
+    f()
+}
+
+/// Sets `context` for the current thread until the returned guard is dropped.
+pub fn set<C: 'static>(context: C) -> ContextGuard {
+    let guard = NEXT_GUARD.replace(NEXT_GUARD.get().wrapping_add(1));
+    CONTEXTS.with_borrow_mut(|contexts| contexts.push(Entry { guard, type_id: TypeId::of::<C>(), context: Rc::new(context) }));
+    ContextGuard { guard, _not_send: PhantomData }
+}
+
+/// Calls `f` with the current context of type `C`, if one is set for the current thread.
+///
+/// The context store isn't borrowed while `f` runs, contexts may therefore be set (or consulted) from within `f`.
+pub fn with_current<C: 'static, R>(f: impl FnOnce(Option<&C>) -> R) -> R {
+    let context = CONTEXTS.with_borrow(|contexts| {
+        contexts.iter().rev().find(|entry| entry.type_id == TypeId::of::<C>()).map(|entry| entry.context.clone())
+    });
+    f(context.as_deref().and_then(|context| context.downcast_ref()))
+}
+
+/// Returns a clone of the current context of type `C`, if one is set for the current thread.
+pub fn current<C: Clone + 'static>() -> Option<C> {
+    with_current(|context: Option<&C>| context.cloned())
+}
+
+/// Clears a context set via [`set`] when dropped, restoring the previous context of the same type (if any).
+#[must_use = "the context is cleared as soon as the guard is dropped"]
+pub struct ContextGuard {
+    /// ID of the entry to remove
+    guard: u64,
+
+    /// Contexts are thread-local, the guard must be dropped on the thread that created it
+    _not_send: PhantomData<*const ()>,
+}
+impl fmt::Debug for ContextGuard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ContextGuard").field("guard", &self.guard).finish()
+    }
+}
+impl Drop for ContextGuard {
+    fn drop(&mut self) {
+        // the context is dropped after the store is released, allowing contexts which consult other contexts when dropped
+        let removed = CONTEXTS.with_borrow_mut(|contexts| {
+            let index = contexts.iter().position(|entry| entry.guard == self.guard)?;
+            Some(contexts.remove(index))
+        });
+        drop(removed);
+    }
+}
+
+// !- Contextual serialization
+
+/// Serialization which depends on a context, see [`Contextual`].
+pub trait ContextualSerialize {
+    /// The context consulted while serializing
+    type Context: 'static;
+
+    /// Serializes `self`, `context` is `None` if no context of the type is set for the current thread
+    fn serialize_in_context<S: Serializer>(&self, context: Option<&Self::Context>, serializer: S) -> Result<S::Ok, S::Error>;
+}
+
+/// Serializes the inner value via [`ContextualSerialize`], using the current context.
+///
+/// Intended for use as a nest field type, e.g. `field_type = "Contextual<Price>"`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Contextual<T>(pub T);
+
+impl<T: ContextualSerialize> Serialize for Contextual<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        with_current(|context| self.0.serialize_in_context(context, serializer))
+    }
+}
+impl<T> From<T> for Contextual<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
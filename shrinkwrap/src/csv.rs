@@ -0,0 +1,32 @@
+//! CSV projection helpers, enabled via the `csv` feature.
+//!
+//! See the `csv` option of the [`Wrap`](crate::Wrap) derive for generated usage.
+
+use serde::Serialize;
+
+use crate::Error;
+
+/// Formats a single value for use within a CSV record, used by generated `to_csv_record` fns.
+///
+/// - strings are used as-is
+/// - numbers and booleans use their JSON representation
+/// - `null` (e.g. `None`) is represented by an empty value
+/// - arrays and objects are written as JSON
+///
+/// Fails if the value cannot be serialized, e.g. a map with non-string keys.
+///
+/// ```
+/// # fn main() -> Result<(), shrinkwrap::Error> {
+/// assert_eq!(shrinkwrap::csv::field("text")?, "text");
+/// assert_eq!(shrinkwrap::csv::field(&1.5)?, "1.5");
+/// assert_eq!(shrinkwrap::csv::field(&None::<i64>)?, "");
+/// assert_eq!(shrinkwrap::csv::field(&[1, 2])?, "[1,2]");
+///
+/// let keyed_by_tuple = std::collections::BTreeMap::from([((1, 2), "value")]);
+/// assert!(shrinkwrap::csv::field(&keyed_by_tuple).is_err());
+/// # Ok(())
+/// # }
+/// ```
+pub fn field<T: Serialize + ?Sized>(value: &T) -> Result<String, Error> {
+    Ok(crate::render::value(value)?)
+}
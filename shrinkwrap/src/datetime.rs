@@ -0,0 +1,151 @@
+//! Date/time formatting building blocks for [`chrono`], enabled via the `chrono` feature.
+//!
+//! [`FormatDateTime`] formats timestamps using a [`DateTimeFormat`], allowing text nests containing dates to share a
+//! canonical format across transforms rather than repeating format strings in each impl.
+//!
+//! ```
+//! use chrono::{DateTime, Utc};
+//! use serde::Serialize;
+//! use shrinkwrap::{ToWrappedWith, Transform, TransformToNest, Wrap};
+//! use shrinkwrap::datetime::{DateTimeFormat, FormatDateTime, Rfc3339};
+//!
+//! /// Custom format, shared by all transforms
+//! struct ShortDate;
+//! impl DateTimeFormat for ShortDate {
+//!     const PATTERN: &'static str = "%b %-d, %Y";
+//! }
+//!
+//! #[derive(Debug, Clone, Serialize, Wrap)]
+//! #[shrinkwrap(transform = MyTransform)]
+//! #[shrinkwrap(nest(id = "text", field_type = String))]
+//! pub struct MyData {
+//!     #[shrinkwrap(nest(id = "text"))]
+//!     created_at: DateTime<Utc>,
+//!     #[shrinkwrap(nest(id = "text"))]
+//!     updated_at: DateTime<Utc>,
+//! }
+//!
+//! struct MyTransform;
+//! impl Transform for MyTransform {
+//!     type Options = ();
+//! }
+//! impl TransformToNest<MyDataNestedText> for MyTransform {
+//!     type Data = MyData;
+//!
+//!     fn transform_to_nest(&self, data: &MyData, _: &()) -> MyDataNestedText {
+//!         MyDataNestedText {
+//!             created_at: FormatDateTime::<ShortDate>::format(&data.created_at),
+//!             updated_at: FormatDateTime::<Rfc3339>::format(&data.updated_at),
+//!         }
+//!     }
+//! }
+//!
+//! let timestamp = DateTime::from_timestamp(1754497944, 0).unwrap();
+//! let wrapper = MyData { created_at: timestamp, updated_at: timestamp }.to_wrapped_with(&MyTransform, &());
+//! assert_eq!(wrapper.extra.text.created_at, "Aug 6, 2025");
+//! assert_eq!(wrapper.extra.text.updated_at, "2025-08-06T16:32:24+00:00");
+//! ```
+
+use chrono::{DateTime, SecondsFormat, TimeZone};
+use std::fmt::Display;
+use std::marker::PhantomData;
+
+/// A date/time format, used via [`FormatDateTime`]
+///
+/// Custom formats only need to provide [`PATTERN`](Self::PATTERN), a [`chrono::format::strftime`] pattern. Formats
+/// which can't be expressed as a pattern override [`format`](Self::format) instead.
+pub trait DateTimeFormat {
+    /// `strftime` pattern used by the default [`format`](Self::format) impl
+    const PATTERN: &'static str = "";
+
+    /// Formats `value`
+    fn format<Tz: TimeZone>(value: &DateTime<Tz>) -> String
+    where
+        Tz::Offset: Display,
+    {
+        value.format(Self::PATTERN).to_string()
+    }
+}
+
+/// RFC 3339 / ISO 8601, with second precision, i.e. `2025-08-06T16:32:24+00:00`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Rfc3339;
+impl DateTimeFormat for Rfc3339 {
+    fn format<Tz: TimeZone>(value: &DateTime<Tz>) -> String
+    where
+        Tz::Offset: Display,
+    {
+        value.to_rfc3339_opts(SecondsFormat::Secs, false)
+    }
+}
+
+/// RFC 3339 / ISO 8601, with millisecond precision and `Z` for UTC, i.e. `2025-08-06T16:32:24.000Z`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Rfc3339Millis;
+impl DateTimeFormat for Rfc3339Millis {
+    fn format<Tz: TimeZone>(value: &DateTime<Tz>) -> String
+    where
+        Tz::Offset: Display,
+    {
+        value.to_rfc3339_opts(SecondsFormat::Millis, true)
+    }
+}
+
+/// RFC 2822, i.e. `Wed, 6 Aug 2025 16:32:24 +0000`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Rfc2822;
+impl DateTimeFormat for Rfc2822 {
+    fn format<Tz: TimeZone>(value: &DateTime<Tz>) -> String
+    where
+        Tz::Offset: Display,
+    {
+        value.to_rfc2822()
+    }
+}
+
+/// Calendar date, i.e. `2025-08-06`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Date;
+impl DateTimeFormat for Date {
+    const PATTERN: &'static str = "%Y-%m-%d";
+}
+
+/// Formats date/times using the format `F`
+///
+/// ```
+/// use chrono::DateTime;
+/// use shrinkwrap::datetime::{FormatDateTime, Rfc2822, Rfc3339Millis};
+///
+/// let timestamp = DateTime::from_timestamp(1754497944, 0).unwrap();
+/// assert_eq!(FormatDateTime::<Rfc2822>::format(&timestamp), "Wed, 6 Aug 2025 16:32:24 +0000");
+/// assert_eq!(FormatDateTime::<Rfc3339Millis>::format_timestamp(1754497944), Some("2025-08-06T16:32:24.000Z".into()));
+/// assert_eq!(FormatDateTime::<Rfc3339Millis>::format_opt(None::<&DateTime<chrono::Utc>>), None);
+/// ```
+pub struct FormatDateTime<F>(PhantomData<fn() -> F>);
+impl<F: DateTimeFormat> FormatDateTime<F> {
+    /// Formats `value`
+    pub fn format<Tz: TimeZone>(value: &DateTime<Tz>) -> String
+    where
+        Tz::Offset: Display,
+    {
+        F::format(value)
+    }
+
+    /// Formats `value` if present, for optional date fields
+    pub fn format_opt<Tz: TimeZone>(value: Option<&DateTime<Tz>>) -> Option<String>
+    where
+        Tz::Offset: Display,
+    {
+        value.map(F::format)
+    }
+
+    /// Formats a unix timestamp (seconds) as UTC, `None` if the timestamp is out of range
+    pub fn format_timestamp(secs: i64) -> Option<String> {
+        DateTime::from_timestamp(secs, 0).map(|value| F::format(&value))
+    }
+
+    /// Formats a unix timestamp (milliseconds) as UTC, `None` if the timestamp is out of range
+    pub fn format_timestamp_millis(millis: i64) -> Option<String> {
+        DateTime::from_timestamp_millis(millis).map(|value| F::format(&value))
+    }
+}
@@ -0,0 +1,65 @@
+/// Gates nests superseded via `nest(.., deprecates = "..", gate_deprecated)`, implemented by a transform's options.
+///
+/// The superseded nest is only built when [`emit_deprecated`](Self::emit_deprecated) returns true, and is `None`
+/// otherwise. Allows the old version of a nest to be phased out per request (e.g. by client version) while both
+/// versions are supported.
+///
+/// ```
+/// use serde::Serialize;
+/// use shrinkwrap::{DeprecatedNestGate, ToWrappedWith, Transform, TransformToNest, Wrap};
+///
+/// #[derive(Debug, Clone, Serialize, Wrap)]
+/// #[shrinkwrap(transform = MyTransform)]
+/// #[shrinkwrap(nest(id = "text", field_type = String, optional))]
+/// #[shrinkwrap(nest(id = "text_v2", field_type = String, deprecates = "text", gate_deprecated))]
+/// pub struct MyData {
+///     #[shrinkwrap(nest(id = "text"), nest(id = "text_v2"))]
+///     uptime_sec: i64,
+/// }
+///
+/// struct MyTransformOpts {
+///     legacy_clients: bool,
+/// }
+/// impl DeprecatedNestGate for MyTransformOpts {
+///     fn emit_deprecated(&self, _nest: &str) -> bool {
+///         self.legacy_clients
+///     }
+/// }
+///
+/// struct MyTransform;
+/// impl Transform for MyTransform {
+///     type Options = MyTransformOpts;
+/// }
+/// impl TransformToNest<Option<MyDataNestedText>> for MyTransform {
+///     type Data = MyData;
+///     fn transform_to_nest(&self, data: &MyData, _: &MyTransformOpts) -> Option<MyDataNestedText> {
+///         Some(MyDataNestedText { uptime_sec: format!("{}s", data.uptime_sec) })
+///     }
+/// }
+/// impl TransformToNest<MyDataNestedTextV2> for MyTransform {
+///     type Data = MyData;
+///     fn transform_to_nest(&self, data: &MyData, _: &MyTransformOpts) -> MyDataNestedTextV2 {
+///         MyDataNestedTextV2 { uptime_sec: format!("{} seconds", data.uptime_sec) }
+///     }
+/// }
+///
+/// let wrapped = MyData { uptime_sec: 10 }.to_wrapped_with(&MyTransform, &MyTransformOpts { legacy_clients: false });
+/// assert!(wrapped.extra.text.is_none());
+/// assert_eq!(wrapped.extra.text_v2.uptime_sec, "10 seconds");
+///
+/// let wrapped = MyData { uptime_sec: 10 }.to_wrapped_with(&MyTransform, &MyTransformOpts { legacy_clients: true });
+/// #[allow(deprecated)]
+/// let text = wrapped.extra.text().unwrap();
+/// assert_eq!(text.uptime_sec, "10s");
+/// ```
+pub trait DeprecatedNestGate {
+    /// Whether the deprecated nest with ID `nest` should be built
+    fn emit_deprecated(&self, nest: &str) -> bool;
+}
+
+/// Option-less transforms always emit deprecated nests
+impl DeprecatedNestGate for () {
+    fn emit_deprecated(&self, _nest: &str) -> bool {
+        true
+    }
+}
@@ -0,0 +1,140 @@
+//! Wrapping diagnostics, held by the debug wrapper generated via the `debug_wrapper` flag of the [`Wrap`](crate::Wrap)
+//! derive.
+//!
+//! The debug wrapper (`{Data}DebugWrapper`) serializes as the primary wrapper along with a `diagnostics` object,
+//! listing each nest of the primary extra struct and whether it was built. When the `instrument` feature is enabled,
+//! the time spent building each nest (and the wrapper as a whole) is recorded as well. Timings are only recorded while
+//! building a debug wrapper, building the standard wrapper is unaffected.
+//!
+//! ```
+//! use serde::Serialize;
+//! use shrinkwrap::{Transform, TransformToNest, Wrap};
+//!
+//! #[derive(Debug, Clone, Serialize, Wrap)]
+//! #[shrinkwrap(transform = MyTransform, debug_wrapper)]
+//! #[shrinkwrap(nest(id = "text", field_type = String))]
+//! pub struct Account {
+//!     #[shrinkwrap(nest(id = "text"))]
+//!     balance: f64,
+//! }
+//!
+//! struct MyTransform;
+//! impl Transform for MyTransform {
+//!     type Options = ();
+//! }
+//! impl TransformToNest<AccountNestedText> for MyTransform {
+//!     type Data = Account;
+//!
+//!     fn transform_to_nest(&self, data: &Account, _: &()) -> AccountNestedText {
+//!         AccountNestedText { balance: format!("${:.2}", data.balance) }
+//!     }
+//! }
+//!
+//! let debug = AccountDebugWrapper::wrap_with(Account { balance: 2.5 }, &MyTransform, &());
+//! assert_eq!(debug.wrapper.extra.text.balance, "$2.50");
+//! assert_eq!(debug.diagnostics.nests[0].id, "text");
+//! assert!(debug.diagnostics.nests[0].present);
+//! ```
+
+use serde::Serialize;
+use std::time::Duration;
+#[cfg(feature = "instrument")]
+use std::{cell::RefCell, time::Instant};
+
+/// Diagnostics of a single wrapping, see the [module docs](self)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct WrapDiagnostics {
+    /// Each nest of the primary extra struct, in field order
+    pub nests: Vec<NestDiagnostics>,
+
+    /// Total time spent wrapping, in microseconds (`instrument` feature only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub elapsed_us: Option<u64>,
+}
+impl WrapDiagnostics {
+    /// Diagnostics of the nests given as `(id, present)` pairs, along with any of the recorded `timings`
+    pub fn new(nests: impl IntoIterator<Item = (&'static str, bool)>, timings: &NestTimings) -> Self {
+        Self {
+            nests: nests.into_iter()
+                .map(|(id, present)| NestDiagnostics { id, present, elapsed_us: timings.nest(id).map(as_micros) })
+                .collect(),
+            elapsed_us: timings.total().map(as_micros),
+        }
+    }
+}
+
+/// Diagnostics of a single nest within [`WrapDiagnostics`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct NestDiagnostics {
+    /// Nest ID
+    pub id: &'static str,
+
+    /// Whether the nest was built, always true for required nests
+    pub present: bool,
+
+    /// Time spent building the nest, in microseconds (`instrument` feature only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub elapsed_us: Option<u64>,
+}
+
+/// Build timings recorded by [`collect_timings`], empty unless the `instrument` feature is enabled
+#[derive(Debug, Clone, Default)]
+pub struct NestTimings {
+    nests: Vec<(&'static str, Duration)>,
+    total: Option<Duration>,
+}
+impl NestTimings {
+    /// Time spent building the nest with ID `nest`, if recorded
+    pub fn nest(&self, nest: &str) -> Option<Duration> {
+        self.nests.iter().find(|(id, _)| *id == nest).map(|(_, elapsed)| *elapsed)
+    }
+
+    /// Total time spent within [`collect_timings`], if recorded
+    pub fn total(&self) -> Option<Duration> {
+        self.total
+    }
+}
+
+#[cfg(feature = "instrument")]
+thread_local! {
+    /// Timings of the innermost active `collect_timings` call on this thread
+    static ACTIVE: RefCell<Option<Vec<(&'static str, Duration)>>> = const { RefCell::new(None) };
+}
+
+/// Runs `wrap`, recording the time spent building each nest via [`timed_nest`] (requires the `instrument` feature, no
+/// timings are recorded otherwise)
+pub fn collect_timings<R>(wrap: impl FnOnce() -> R) -> (R, NestTimings) {
+    #[cfg(feature = "instrument")]
+    {
+        let outer = ACTIVE.with(|active| active.replace(Some(Vec::new())));
+        let start = Instant::now();
+        let out = wrap();
+        let total = start.elapsed();
+        let nests = ACTIVE.with(|active| active.replace(outer)).unwrap_or_default();
+        (out, NestTimings { nests, total: Some(total) })
+    }
+    #[cfg(not(feature = "instrument"))]
+    (wrap(), NestTimings::default())
+}
+
+/// Builds the nest with ID `nest` via `build`, recording the time spent if called within [`collect_timings`]
+pub fn timed_nest<R>(nest: &'static str, build: impl FnOnce() -> R) -> R {
+    #[cfg(feature = "instrument")]
+    if ACTIVE.with(|active| active.borrow().is_some()) {
+        let start = Instant::now();
+        let out = build();
+        let elapsed = start.elapsed();
+        ACTIVE.with(|active| {
+            if let Some(nests) = active.borrow_mut().as_mut() {
+                nests.push((nest, elapsed));
+            }
+        });
+        return out;
+    }
+    let _ = nest;
+    build()
+}
+
+fn as_micros(duration: Duration) -> u64 {
+    u64::try_from(duration.as_micros()).unwrap_or(u64::MAX)
+}
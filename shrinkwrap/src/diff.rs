@@ -0,0 +1,246 @@
+//! Structured diffing of wrappers, enabled via the `diff` feature.
+//!
+//! [`diff_wrapped`] compares two wrappers of the same type via their JSON encoding, producing an [`EnvelopeDiff`] with
+//! one [`Change`] per differing value. Each change records the nest it belongs to, based on the position of the value
+//! beneath the wrapper's `extra` key, e.g. `$.extra.value.extra.text.balance` belongs to nest `value.text`. Changes
+//! to the origin data (or the primary wrapper itself) have no nest.
+//!
+//! Arrays are compared element-wise, so a collection of wrappers (e.g. from
+//! [`WrapAllWith`](crate::WrapAllWith)) is diffed item-by-item.
+//!
+//! ```
+//! use serde::Serialize;
+//! use shrinkwrap::{ToWrappedWith, Transform, TransformToNest, Wrap};
+//! use shrinkwrap::diff::{self, ChangeKind};
+//!
+//! #[derive(Debug, Clone, Serialize, Wrap)]
+//! #[shrinkwrap(transform = MyTransform)]
+//! #[shrinkwrap(nest(id = "text", field_type = String))]
+//! pub struct MyData {
+//!     #[shrinkwrap(nest(id = "text"))]
+//!     uptime_sec: i64,
+//!     hostname: String,
+//! }
+//! # struct MyTransform;
+//! # impl Transform for MyTransform {
+//! #     type Options = ();
+//! # }
+//! # impl TransformToNest<MyDataNestedText> for MyTransform {
+//! #     type Data = MyData;
+//! #     fn transform_to_nest(&self, data: &MyData, _: &()) -> MyDataNestedText {
+//! #         MyDataNestedText { uptime_sec: format!("{} sec", data.uptime_sec) }
+//! #     }
+//! # }
+//!
+//! let before = MyData { uptime_sec: 10, hostname: "web-1".to_string() }.to_wrapped_with_t(&MyTransform);
+//! let after = MyData { uptime_sec: 20, hostname: "web-1".to_string() }.to_wrapped_with_t(&MyTransform);
+//!
+//! let diff = diff::diff_wrapped(&before, &after).unwrap();
+//! assert_eq!(diff.changes().len(), 2);
+//! assert_eq!(diff.nests(), ["text"]);
+//!
+//! let change = diff.nest("text").next().unwrap();
+//! assert_eq!(change.path, "$.extra.text.uptime_sec");
+//! assert_eq!(change.kind, ChangeKind::Modified { old: "10 sec".into(), new: "20 sec".into() });
+//!
+//! assert_eq!(diff.to_string(), "\
+//! ~ $.extra.text.uptime_sec (nest `text`): \"10 sec\" -> \"20 sec\"
+//! ~ $.uptime_sec: 10 -> 20
+//! ");
+//! ```
+
+use serde::Serialize;
+use serde_json::Value;
+use std::fmt::{self, Display};
+
+use crate::Error;
+
+/// Key containing the nests of a wrapper, i.e. the default `wrapper(extra_field_name)`
+const EXTRA_KEY: &str = "extra";
+
+/// Compares two wrappers (or collections of wrappers) nest-by-nest and field-by-field, see the
+/// [module docs](self)
+///
+/// Fails if either wrapper can't be serialized to JSON (e.g. maps with non-string keys).
+pub fn diff_wrapped<W: Serialize + ?Sized>(a: &W, b: &W) -> Result<EnvelopeDiff, Error> {
+    Ok(diff_values(&serde_json::to_value(a)?, &serde_json::to_value(b)?))
+}
+
+/// Compares two JSON encoded wrappers, e.g. a stored snapshot against the current output
+///
+/// ```
+/// use serde_json::json;
+/// use shrinkwrap::diff::{diff_values, ChangeKind};
+///
+/// let diff = diff_values(
+///     &json!({ "extra": { "text": { "balance": "$1.00" } }, "balance": 100 }),
+///     &json!({ "extra": { "text": null }, "balance": 100, "currency": "USD" }),
+/// );
+/// assert_eq!(diff.to_string(), "\
+/// + $.currency: \"USD\"
+/// ~ $.extra.text (nest `text`): {\"balance\":\"$1.00\"} -> null
+/// ");
+/// assert!(matches!(diff.changes()[0].kind, ChangeKind::Added(_)));
+/// ```
+pub fn diff_values(a: &Value, b: &Value) -> EnvelopeDiff {
+    let mut changes = Vec::new();
+    diff_value(a, b, &Location::root(), &mut changes);
+    EnvelopeDiff { changes }
+}
+
+/// The differences between two wrappers, see [`diff_wrapped`]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EnvelopeDiff {
+    changes: Vec<Change>,
+}
+impl EnvelopeDiff {
+    /// Whether or not the wrappers are identical
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// All changes, ordered by key within each object
+    pub fn changes(&self) -> &[Change] {
+        &self.changes
+    }
+
+    /// Changes within nest `nest`, excluding the changes of nests chained from it (e.g. `value.text`)
+    pub fn nest<'a>(&'a self, nest: &'a str) -> impl Iterator<Item = &'a Change> + 'a {
+        self.changes.iter().filter(move |change| change.nest.as_deref() == Some(nest))
+    }
+
+    /// Changes to the origin data, or the primary wrapper itself
+    pub fn data(&self) -> impl Iterator<Item = &Change> {
+        self.changes.iter().filter(|change| change.nest.is_none())
+    }
+
+    /// Deduplicated nests containing at least one change, in order of their first change
+    pub fn nests(&self) -> Vec<&str> {
+        let mut nests: Vec<&str> = Vec::new();
+        for nest in self.changes.iter().filter_map(|change| change.nest.as_deref()) {
+            if !nests.contains(&nest) {
+                nests.push(nest);
+            }
+        }
+        nests
+    }
+}
+impl Display for EnvelopeDiff {
+    /// Writes one change per line
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for change in &self.changes {
+            writeln!(f, "{change}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A single differing value within an [`EnvelopeDiff`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change {
+    /// Path to the value, `$` denotes the root value
+    pub path: String,
+
+    /// Dot-separated IDs of the nest (and its parents) containing the value, `None` for the origin data and the
+    /// primary wrapper
+    pub nest: Option<String>,
+
+    /// The change itself
+    pub kind: ChangeKind,
+}
+impl Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let symbol = match self.kind {
+            ChangeKind::Added(_) => '+',
+            ChangeKind::Removed(_) => '-',
+            ChangeKind::Modified { .. } => '~',
+        };
+        write!(f, "{symbol} {}", self.path)?;
+        if let Some(nest) = &self.nest {
+            write!(f, " (nest `{nest}`)")?;
+        }
+        match &self.kind {
+            ChangeKind::Added(value) | ChangeKind::Removed(value) => write!(f, ": {value}"),
+            ChangeKind::Modified { old, new } => write!(f, ": {old} -> {new}"),
+        }
+    }
+}
+
+/// The kind of a [`Change`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChangeKind {
+    /// The value is only present in the second wrapper
+    Added(Value),
+    /// The value is only present in the first wrapper
+    Removed(Value),
+    /// The value differs between the wrappers, including `null` and non-`null` values (e.g. optional nests)
+    Modified { old: Value, new: Value },
+}
+
+/// Position of the value currently being compared
+struct Location {
+    path: String,
+    nest: Option<String>,
+    /// Whether the value is the `extra` object of a wrapper, i.e. its keys are nest IDs
+    in_extra: bool,
+}
+impl Location {
+    fn root() -> Self {
+        Self { path: "$".to_string(), nest: None, in_extra: false }
+    }
+
+    /// Location of the value at `key` of the current object
+    fn key(&self, key: &str) -> Self {
+        let path = format!("{}.{key}", self.path);
+        match self.in_extra {
+            true => {
+                let nest = match &self.nest {
+                    Some(parent) => format!("{parent}.{key}"),
+                    None => key.to_string(),
+                };
+                Self { path, nest: Some(nest), in_extra: false }
+            },
+            false => Self { path, nest: self.nest.clone(), in_extra: key == EXTRA_KEY },
+        }
+    }
+
+    /// Location of the value at `index` of the current array
+    fn index(&self, index: usize) -> Self {
+        Self { path: format!("{}[{index}]", self.path), nest: self.nest.clone(), in_extra: false }
+    }
+
+    fn change(&self, kind: ChangeKind) -> Change {
+        Change { path: self.path.clone(), nest: self.nest.clone(), kind }
+    }
+}
+
+fn diff_value(a: &Value, b: &Value, location: &Location, changes: &mut Vec<Change>) {
+    match (a, b) {
+        (Value::Object(a), Value::Object(b)) => {
+            let mut keys: Vec<&String> = a.keys().chain(b.keys().filter(|key| !a.contains_key(*key))).collect();
+            keys.sort();
+            for key in keys {
+                let child = &location.key(key);
+                match (a.get(key), b.get(key)) {
+                    (Some(a), Some(b)) => diff_value(a, b, child, changes),
+                    (Some(a), None) => changes.push(child.change(ChangeKind::Removed(a.clone()))),
+                    (None, Some(b)) => changes.push(child.change(ChangeKind::Added(b.clone()))),
+                    (None, None) => unreachable!("key is present in either object"),
+                }
+            }
+        },
+        (Value::Array(a), Value::Array(b)) => {
+            for index in 0..a.len().max(b.len()) {
+                let child = &location.index(index);
+                match (a.get(index), b.get(index)) {
+                    (Some(a), Some(b)) => diff_value(a, b, child, changes),
+                    (Some(a), None) => changes.push(child.change(ChangeKind::Removed(a.clone()))),
+                    (None, Some(b)) => changes.push(child.change(ChangeKind::Added(b.clone()))),
+                    (None, None) => unreachable!("index is within either array"),
+                }
+            }
+        },
+        (a, b) if a != b => changes.push(location.change(ChangeKind::Modified { old: a.clone(), new: b.clone() })),
+        _ => {},
+    }
+}
@@ -0,0 +1,102 @@
+//! Nest serialization formats, used via `nest(.., encode = "..")` of the [`Wrap`](crate::Wrap) derive (requires the
+//! `json` feature).
+//!
+//! `encode = "json_string"` serializes the nest as a string holding its JSON encoding (i.e. double-encoded), for
+//! legacy consumers expecting a JSON blob. Alongside `wrapper(deserialize)`, the nest is deserialized by decoding the
+//! string. Optional nests are serialized as `null` when `None`.
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use serde_json::json;
+//! use shrinkwrap::{ToWrappedWith, Transform, TransformToNest, Wrap};
+//!
+//! #[derive(Debug, Clone, Serialize, Deserialize, Wrap)]
+//! #[shrinkwrap(transform = MyTransform, wrapper(deserialize))]
+//! #[shrinkwrap(nest(id = "text", field_type = String, encode = "json_string"))]
+//! pub struct Account {
+//!     #[shrinkwrap(nest(id = "text"))]
+//!     balance: f64,
+//! }
+//!
+//! struct MyTransform;
+//! impl Transform for MyTransform {
+//!     type Options = ();
+//! }
+//! impl TransformToNest<AccountNestedText> for MyTransform {
+//!     type Data = Account;
+//!
+//!     fn transform_to_nest(&self, data: &Account, _: &()) -> AccountNestedText {
+//!         AccountNestedText { balance: format!("${:.2}", data.balance) }
+//!     }
+//! }
+//!
+//! let wrapper = Account { balance: 2.5 }.to_wrapped_with(&MyTransform, &());
+//! let value = serde_json::to_value(&wrapper).unwrap();
+//! assert_eq!(value, json!({ "extra": { "text": "{\"balance\":\"$2.50\"}" }, "balance": 2.5 }));
+//!
+//! let wrapper: AccountWrapper = serde_json::from_value(value).unwrap();
+//! assert_eq!(wrapper.extra.text.balance, "$2.50");
+//! ```
+
+/// Serde `with` module of `encode = "json_string"` nests
+pub mod json_string {
+    use serde::de::{DeserializeOwned, Error as _};
+    use serde::ser::Error as _;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Serializes `value` as a string holding its JSON encoding
+    pub fn serialize<T: Serialize, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        let encoded = serde_json::to_string(value).map_err(S::Error::custom)?;
+        serializer.serialize_str(&encoded)
+    }
+
+    /// Deserializes a value from a string holding its JSON encoding
+    pub fn deserialize<'de, T: DeserializeOwned, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        let encoded = String::deserialize(deserializer)?;
+        serde_json::from_str(&encoded).map_err(D::Error::custom)
+    }
+
+    /// Serializes the referenced value via [`serialize`], used by generated `Serialize` impls
+    pub struct Encoded<'a, T>(pub &'a T);
+
+    impl<T: Serialize> Serialize for Encoded<'_, T> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serialize(self.0, serializer)
+        }
+    }
+}
+
+/// Serde `with` module of optional `encode = "json_string"` nests, `None` is serialized as `null`
+pub mod json_string_option {
+    use serde::de::{DeserializeOwned, Error as _};
+    use serde::ser::Error as _;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Serializes `value` as a string holding its JSON encoding, or `null` when `None`
+    pub fn serialize<T: Serialize, S: Serializer>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(value) => {
+                let encoded = serde_json::to_string(value).map_err(S::Error::custom)?;
+                serializer.serialize_some(&encoded)
+            },
+            None => serializer.serialize_none(),
+        }
+    }
+
+    /// Deserializes a value from a string holding its JSON encoding, `null` is deserialized as `None`
+    pub fn deserialize<'de, T: DeserializeOwned, D: Deserializer<'de>>(deserializer: D) -> Result<Option<T>, D::Error> {
+        match Option::<String>::deserialize(deserializer)? {
+            Some(encoded) => serde_json::from_str(&encoded).map(Some).map_err(D::Error::custom),
+            None => Ok(None),
+        }
+    }
+
+    /// Serializes the referenced value via [`serialize`], used by generated `Serialize` impls
+    pub struct Encoded<'a, T>(pub &'a Option<T>);
+
+    impl<T: Serialize> Serialize for Encoded<'_, T> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serialize(self.0, serializer)
+        }
+    }
+}
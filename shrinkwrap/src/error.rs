@@ -0,0 +1,51 @@
+use std::fmt::{self, Display};
+
+/// Errors raised by shrinkwrap itself, as opposed to errors returned by user transforms (see
+/// [`TryTransformToNest::Error`](crate::TryTransformToNest::Error))
+///
+/// Returned by the fallible helpers of the optional features (e.g. `csv::field` or `integrity::checksum`) and the
+/// fallible fns generated for them (e.g. `to_csv_record`). New variants may be added as further fallible APIs are
+/// introduced.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// A batch impl returned a different number of nests than the number of items being wrapped
+    BatchLength {
+        /// Field name of the batch nest
+        nest: &'static str,
+        /// Number of items being wrapped
+        expected: usize,
+        /// Number of nests returned
+        actual: usize,
+    },
+    /// JSON serialization or deserialization failed
+    #[cfg(any(feature = "csv", feature = "diff", feature = "http", feature = "integrity", feature = "json", feature = "providers", feature = "tonic"))]
+    Json(serde_json::Error),
+}
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BatchLength { nest, expected, actual } => write!(
+                f,
+                "batch impl for nest `{nest}` must return one nest per item, expected {expected} nests but received {actual}"
+            ),
+            #[cfg(any(feature = "csv", feature = "diff", feature = "http", feature = "integrity", feature = "json", feature = "providers", feature = "tonic"))]
+            Self::Json(err) => write!(f, "json error: {err}"),
+        }
+    }
+}
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::BatchLength { .. } => None,
+            #[cfg(any(feature = "csv", feature = "diff", feature = "http", feature = "integrity", feature = "json", feature = "providers", feature = "tonic"))]
+            Self::Json(err) => Some(err),
+        }
+    }
+}
+#[cfg(any(feature = "csv", feature = "diff", feature = "http", feature = "integrity", feature = "json", feature = "providers", feature = "tonic"))]
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Self {
+        Self::Json(err)
+    }
+}
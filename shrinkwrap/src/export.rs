@@ -0,0 +1,119 @@
+//! Bulk export helpers, enabled via the `json` feature.
+
+use serde::Serialize;
+use serde_json::Error as JsonError;
+use std::fmt::{self, Debug, Display};
+use std::io::Write;
+
+use crate::{Error, ToWrappedWith, Transform2, TryToWrappedWith};
+
+/// Wraps each item and writes it to `writer` as a single line of JSON ([JSON Lines](https://jsonlines.org)).
+///
+/// Items are wrapped and written one at a time, the collection is never buffered in full.
+/// The writer is not buffered internally, wrap it in a [`BufWriter`](std::io::BufWriter) when writing to a file or socket.
+///
+/// Returns the number of lines written.
+///
+/// ```
+/// # use shrinkwrap::{Transform, TransformToNest, Wrap};
+/// #
+/// # #[derive(Debug, Clone, serde::Serialize, Wrap)]
+/// # #[shrinkwrap(transform = MyTransform)]
+/// # #[shrinkwrap(nest(id = "text", field_type = String))]
+/// # pub struct MyData {
+/// #     #[shrinkwrap(nest(id = "text"))]
+/// #     uptime_sec: i64,
+/// # }
+/// # struct MyTransform;
+/// # impl Transform for MyTransform {
+/// #     type Options = ();
+/// # }
+/// # impl TransformToNest<MyDataNestedText> for MyTransform {
+/// #     type Data = MyData;
+/// #     fn transform_to_nest(&self, data: &MyData, _: &()) -> MyDataNestedText {
+/// #         MyDataNestedText { uptime_sec: data.uptime_sec.to_string() }
+/// #     }
+/// # }
+/// #
+/// let records = vec![MyData { uptime_sec: 1 }, MyData { uptime_sec: 2 }];
+/// let mut out = Vec::new();
+/// let lines = shrinkwrap::export::to_ndjson_writer(records, &MyTransform, &(), &mut out).unwrap();
+///
+/// assert_eq!(lines, 2);
+/// assert_eq!(
+///     String::from_utf8(out).unwrap(),
+///     "{\"extra\":{\"text\":{\"uptime_sec\":\"1\"}},\"uptime_sec\":1}\n{\"extra\":{\"text\":{\"uptime_sec\":\"2\"}},\"uptime_sec\":2}\n",
+/// );
+/// ```
+pub fn to_ndjson_writer<I, T, W>(iter: I, transform: &T, options: &T::Options<'_>, mut writer: W) -> Result<usize, Error>
+where
+    I: IntoIterator,
+    I::Item: ToWrappedWith<T>,
+    <I::Item as ToWrappedWith<T>>::Wrapper: Serialize,
+    T: Transform2,
+    W: Write,
+{
+    let mut lines = 0;
+    for data in iter {
+        write_line(&data.to_wrapped_with(transform, options), &mut writer)?;
+        lines += 1;
+    }
+    writer.flush().map_err(JsonError::io)?;
+    Ok(lines)
+}
+
+/// Fallible variant of [`to_ndjson_writer`], wrapping each item via [`TryToWrappedWith`].
+///
+/// Writing stops at the first item which fails to wrap, any previously written lines are left in place.
+pub fn try_to_ndjson_writer<I, T, W>(
+    iter: I,
+    transform: &T,
+    options: &T::Options<'_>,
+    mut writer: W,
+) -> Result<usize, ExportError<<I::Item as TryToWrappedWith<T>>::Error>>
+where
+    I: IntoIterator,
+    I::Item: TryToWrappedWith<T>,
+    <I::Item as TryToWrappedWith<T>>::Wrapper: Serialize,
+    T: Transform2,
+    W: Write,
+{
+    let mut lines = 0;
+    for data in iter {
+        let wrapper = data.try_to_wrapped_with(transform, options).map_err(ExportError::Transform)?;
+        write_line(&wrapper, &mut writer).map_err(|err| ExportError::Json(err.into()))?;
+        lines += 1;
+    }
+    writer.flush().map_err(|err| ExportError::Json(JsonError::io(err).into()))?;
+    Ok(lines)
+}
+
+fn write_line<S: Serialize, W: Write>(value: &S, writer: &mut W) -> Result<(), JsonError> {
+    serde_json::to_writer(&mut *writer, value)?;
+    writer.write_all(b"\n").map_err(JsonError::io)
+}
+
+/// Error returned by [`try_to_ndjson_writer`].
+#[derive(Debug)]
+pub enum ExportError<E> {
+    /// An item failed to wrap
+    Transform(E),
+    /// Serialization or writing failed
+    Json(Error),
+}
+impl<E: Debug> Display for ExportError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Transform(err) => write!(f, "failed to wrap item: {err:?}"),
+            Self::Json(err) => write!(f, "failed to write item: {err}"),
+        }
+    }
+}
+impl<E: Debug> std::error::Error for ExportError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Transform(_) => None,
+            Self::Json(err) => Some(err),
+        }
+    }
+}
@@ -0,0 +1,113 @@
+//! Dynamic nest projection, enabled via the `json` feature.
+//!
+//! Serializes a wrapper while retaining only a selected set of nests, e.g. for REST filter layers where clients pick
+//! the nests they need per request. Nests are selected by nest ID, a selected nest is retained along with all nests
+//! beneath it, and the parents of a selected nest are retained with only the selected nests beneath them. The data is
+//! always retained. Unknown nest IDs are ignored.
+//!
+//! See the `json` flag of the [`Wrap`](crate::Wrap) derive for generated usage, i.e. `wrapper.to_value_filtered(&["text"])`.
+//!
+//! ```
+//! use serde::Serialize;
+//! use serde_json::json;
+//! use shrinkwrap::{ToWrappedWith, Transform, TransformToNest, Wrap};
+//!
+//! #[derive(Debug, Clone, Serialize, Wrap)]
+//! #[shrinkwrap(transform = MyTransform, json)]
+//! #[shrinkwrap(nest(id = "text", field_type = String))]
+//! #[shrinkwrap(nest(id = "cents", field_type = i64))]
+//! pub struct Account {
+//!     #[shrinkwrap(nest(id = "text"), nest(id = "cents"))]
+//!     balance: f64,
+//! }
+//!
+//! struct MyTransform;
+//! impl Transform for MyTransform {
+//!     type Options = ();
+//! }
+//! impl TransformToNest<AccountNestedText> for MyTransform {
+//!     type Data = Account;
+//!
+//!     fn transform_to_nest(&self, data: &Account, _: &()) -> AccountNestedText {
+//!         AccountNestedText { balance: format!("${:.2}", data.balance) }
+//!     }
+//! }
+//! impl TransformToNest<AccountNestedCents> for MyTransform {
+//!     type Data = Account;
+//!
+//!     fn transform_to_nest(&self, data: &Account, _: &()) -> AccountNestedCents {
+//!         AccountNestedCents { balance: (data.balance * 100.0).round() as i64 }
+//!     }
+//! }
+//!
+//! let wrapper = Account { balance: 2.5 }.to_wrapped_with(&MyTransform, &());
+//! let value = wrapper.to_value_filtered(&["text"]).unwrap();
+//! assert_eq!(value, json!({ "extra": { "text": { "balance": "$2.50" } }, "balance": 2.5 }));
+//! ```
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::Error;
+
+/// The serialized location of the nests within a wrapper, generated for the primary wrapper by the `json` flag
+#[derive(Debug, Clone, Copy)]
+pub struct NestTree {
+    /// Serialized key of the wrapper's extra struct, `None` if the extra struct is flattened into the wrapper
+    pub extra: Option<&'static str>,
+
+    /// Nests held by the extra struct
+    pub nests: &'static [NestKey],
+}
+impl NestTree {
+    /// Whether any of `ids` are nests within the tree (at any depth)
+    pub fn contains_any(&self, ids: &[&str]) -> bool {
+        self.nests.iter().any(|nest| ids.contains(&nest.id) || nest.children.is_some_and(|children| children.contains_any(ids)))
+    }
+}
+
+/// A single nest within a [`NestTree`]
+#[derive(Debug, Clone, Copy)]
+pub struct NestKey {
+    /// Nest ID
+    pub id: &'static str,
+
+    /// Serialized key of the nest within the extra struct
+    pub key: &'static str,
+
+    /// Nests chained from this nest, if any (i.e. the nest is serialized as a wrapper of its own)
+    pub children: Option<&'static NestTree>,
+}
+
+/// Serializes `wrapper`, retaining only the nests selected by `ids`, see the [module docs](self)
+pub fn to_value_filtered<W: Serialize + ?Sized>(wrapper: &W, tree: &NestTree, ids: &[&str]) -> Result<Value, Error> {
+    let mut value = serde_json::to_value(wrapper)?;
+    filter_nests(&mut value, tree, ids);
+    Ok(value)
+}
+
+/// Removes the nests not selected by `ids` from an already serialized wrapper, see the [module docs](self)
+pub fn filter_nests(value: &mut Value, tree: &NestTree, ids: &[&str]) {
+    let extra = match tree.extra {
+        Some(key) => value.get_mut(key),
+        None => Some(value),
+    };
+    let Some(extra) = extra.and_then(Value::as_object_mut) else {
+        return;
+    };
+    for nest in tree.nests {
+        if ids.contains(&nest.id) {
+            continue;
+        }
+        match nest.children.filter(|children| children.contains_any(ids)) {
+            Some(children) => {
+                if let Some(child) = extra.get_mut(nest.key) {
+                    filter_nests(child, children, ids);
+                }
+            },
+            None => {
+                extra.remove(nest.key);
+            },
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use crate::transform::{Transform, Transform2};
+
+/// Focused data -> nest conversion, receiving only the fields used by the nest.
+///
+/// Enabled per-nest via the `sources` flag, which generates a borrowed "source view" struct (`{NestStruct}Sources<'a>`) holding a reference to each source field included in the nest.
+/// A [`TransformToNest`](crate::TransformToNest) impl for the nest is then generated, delegating to this trait.
+///
+/// `N` is the nest's field type within the extra struct (e.g. `Option<MyDataNestedText>` for optional nests).
+///
+/// # Examples
+///
+/// ```
+/// # use shrinkwrap::{Transform, Wrap};
+/// #
+/// # struct MyTransform {}
+/// # type MyTransformOpts = ();
+/// # impl Transform for MyTransform {
+/// #     type Options = MyTransformOpts;
+/// # }
+/// #
+/// #[derive(Debug, Clone, serde::Serialize, Wrap)]
+/// #[shrinkwrap(transform = MyTransform)]
+/// #[shrinkwrap(nest(id = "text", field_type = String, sources))]
+/// pub struct MyData {
+///     #[shrinkwrap(nest(id = "text"))]
+///     uptime_sec: i64,
+///     internal_notes: Vec<String>,
+/// }
+///
+/// use shrinkwrap::TransformFromSources;
+///
+/// impl<'a> TransformFromSources<MyDataNestedTextSources<'a>, MyDataNestedText> for MyTransform {
+///     fn transform_from_sources(&self, sources: MyDataNestedTextSources<'a>, _: &MyTransformOpts) -> MyDataNestedText {
+///         MyDataNestedText {
+///             uptime_sec: sources.uptime_sec.to_string(),
+///         }
+///     }
+/// }
+/// ```
+pub trait TransformFromSources<S, N>: Transform {
+    fn transform_from_sources(&self, sources: S, options: &Self::Options) -> N;
+}
+
+/// Variant of [`TransformFromSources`] for [`Transform2`] transforms, see [`TransformToNest2`](crate::TransformToNest2)
+pub trait TransformFromSources2<S, N>: Transform2 {
+    fn transform_from_sources(&self, sources: S, options: &Self::Options<'_>) -> N;
+}
+
+impl<S, N, T: TransformFromSources<S, N> + ?Sized> TransformFromSources2<S, N> for T {
+    fn transform_from_sources(&self, sources: S, options: &Self::Options<'_>) -> N {
+        TransformFromSources::transform_from_sources(self, sources, options)
+    }
+}
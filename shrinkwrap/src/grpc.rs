@@ -0,0 +1,78 @@
+//! gRPC metadata conversion, enabled via the `tonic` feature.
+//!
+//! Represents an extra struct as a `google.protobuf.Struct`, e.g. to carry the nests of a response in gRPC
+//! trailers/metadata. See the `grpc` flag of the [`Wrap`](crate::Wrap) derive for the generated conversions into
+//! `prost_types::Struct`, i.e. `wrapper.to_grpc_metadata()`. `prost-types` is re-exported as [`prost_types`], the
+//! generated conversions don't require a direct dependency on it.
+//!
+//! Values are mapped as per the protobuf JSON mapping: numbers are represented as doubles, and `None` as null.
+//!
+//! ```
+//! use serde::Serialize;
+//! use shrinkwrap::grpc::{ProtoKind, proto_fields};
+//!
+//! #[derive(Serialize)]
+//! struct Extra {
+//!     text: Text,
+//!     cents: Option<i64>,
+//! }
+//! #[derive(Serialize)]
+//! struct Text {
+//!     balance: String,
+//! }
+//!
+//! let fields = proto_fields(&Extra { text: Text { balance: "$2.50".into() }, cents: None }).unwrap();
+//! assert_eq!(fields, vec![
+//!     ("cents".to_string(), ProtoKind::Null),
+//!     ("text".to_string(), ProtoKind::Struct(vec![("balance".to_string(), ProtoKind::String("$2.50".into()))])),
+//! ]);
+//! assert!(proto_fields(&vec![1, 2]).is_err());
+//! ```
+
+use serde::Serialize;
+use serde::ser::Error as _;
+use serde_json::{Error as JsonError, Value};
+
+use crate::Error;
+
+pub use prost_types;
+
+/// The kind of a `google.protobuf.Value`
+#[derive(Debug, Clone, PartialEq)]
+pub enum ProtoKind {
+    Null,
+    Number(f64),
+    String(String),
+    Bool(bool),
+    /// Fields of a nested `google.protobuf.Struct`, ordered by key
+    Struct(Vec<(String, ProtoKind)>),
+    List(Vec<ProtoKind>),
+}
+impl From<Value> for ProtoKind {
+    fn from(value: Value) -> Self {
+        match value {
+            Value::Null => Self::Null,
+            Value::Bool(boolean) => Self::Bool(boolean),
+            Value::Number(number) => Self::Number(number.as_f64().unwrap_or(f64::NAN)),
+            Value::String(string) => Self::String(string),
+            Value::Array(values) => Self::List(values.into_iter().map(Self::from).collect()),
+            Value::Object(fields) => Self::Struct(into_fields(fields)),
+        }
+    }
+}
+
+/// Serializes `value` into the fields of a `google.protobuf.Struct`, ordered by key
+///
+/// Errors if `value` fails to serialize, or doesn't serialize as a map (e.g. `tagged_array` extras).
+pub fn proto_fields<W: Serialize + ?Sized>(value: &W) -> Result<Vec<(String, ProtoKind)>, Error> {
+    match serde_json::to_value(value)? {
+        Value::Object(fields) => Ok(into_fields(fields)),
+        _ => Err(Error::Json(JsonError::custom("value must serialize as a map to be represented as a google.protobuf.Struct"))),
+    }
+}
+
+fn into_fields(fields: serde_json::Map<String, Value>) -> Vec<(String, ProtoKind)> {
+    let mut fields = fields.into_iter().map(|(key, value)| (key, ProtoKind::from(value))).collect::<Vec<_>>();
+    fields.sort_by(|a, b| a.0.cmp(&b.0));
+    fields
+}
@@ -0,0 +1,79 @@
+//! [`http`](::http) response conversions, enabled via the `http` feature.
+//!
+//! See the `http` option of the [`Wrap`](crate::Wrap) derive, which implements `From<Wrapper>` for
+//! `http::Response<B>` (for any body `B: From<Vec<u8>>`) on the primary wrapper. Allows wrappers to be returned from
+//! raw `tower` services, or any other `http` based stack, without a framework integration.
+//!
+//! ```
+//! use http::{Response, StatusCode, header};
+//! use serde::Serialize;
+//! use shrinkwrap::{ToWrappedWith, Transform, TransformToNest, Wrap};
+//!
+//! #[derive(Debug, Clone, Serialize, Wrap)]
+//! #[shrinkwrap(transform = MyTransform, http(content_type = "application/vnd.api+json", status = account_status))]
+//! #[shrinkwrap(nest(id = "text", field_type = String))]
+//! pub struct Account {
+//!     #[shrinkwrap(nest(id = "text"))]
+//!     balance: i64,
+//! }
+//!
+//! /// Responds with `402 Payment Required` for overdrawn accounts
+//! fn account_status(wrapper: &AccountWrapper) -> StatusCode {
+//!     match wrapper.data.balance < 0 {
+//!         true => StatusCode::PAYMENT_REQUIRED,
+//!         false => StatusCode::OK,
+//!     }
+//! }
+//! # struct MyTransform;
+//! # impl Transform for MyTransform {
+//! #     type Options = ();
+//! # }
+//! # impl TransformToNest<AccountNestedText> for MyTransform {
+//! #     type Data = Account;
+//! #     fn transform_to_nest(&self, data: &Account, _: &()) -> AccountNestedText {
+//! #         AccountNestedText { balance: data.balance.to_string() }
+//! #     }
+//! # }
+//!
+//! let response: Response<Vec<u8>> = Account { balance: -3 }.to_wrapped_with(&MyTransform, &()).into();
+//! assert_eq!(response.status(), StatusCode::PAYMENT_REQUIRED);
+//! assert_eq!(response.headers()[header::CONTENT_TYPE], "application/vnd.api+json");
+//! assert_eq!(response.body(), br#"{"extra":{"text":{"balance":"-3"}},"balance":-3}"#);
+//! ```
+
+use http::{HeaderValue, Response, StatusCode, header};
+use serde::Serialize;
+
+/// Default content type of generated response conversions
+pub const JSON_CONTENT_TYPE: &str = "application/json";
+
+/// Serializes `value` as JSON into a response with the given content type and status
+///
+/// Responds with an empty `500 Internal Server Error` if `value` fails to serialize, or if `content_type` isn't a
+/// valid header value.
+///
+/// ```
+/// use http::{Response, StatusCode};
+/// use shrinkwrap::http::{JSON_CONTENT_TYPE, json_response};
+///
+/// let response: Response<Vec<u8>> = json_response(&[1, 2], JSON_CONTENT_TYPE, StatusCode::CREATED);
+/// assert_eq!((response.status(), response.body().as_slice()), (StatusCode::CREATED, b"[1,2]".as_slice()));
+///
+/// let invalid: Response<Vec<u8>> = json_response(&[1, 2], "application/\njson", StatusCode::OK);
+/// assert_eq!(invalid.status(), StatusCode::INTERNAL_SERVER_ERROR);
+/// ```
+pub fn json_response<T, B>(value: &T, content_type: &str, status: StatusCode) -> Response<B>
+where
+    T: Serialize + ?Sized,
+    B: From<Vec<u8>>,
+{
+    let (Ok(body), Ok(content_type)) = (serde_json::to_vec(value), HeaderValue::from_str(content_type)) else {
+        let mut response = Response::new(B::from(Vec::new()));
+        *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+        return response;
+    };
+    let mut response = Response::new(B::from(body));
+    *response.status_mut() = status;
+    response.headers_mut().insert(header::CONTENT_TYPE, content_type);
+    response
+}
@@ -0,0 +1,177 @@
+//! Integrity checksums of serialized data, enabled via the `integrity` feature.
+//!
+//! See the `wrapper(integrity(..))` option of the [`Wrap`](crate::Wrap) derive, which adds a checksum field to the
+//! primary wrapper's serialized output. The checksum is computed while serializing, over the compact JSON encoding of
+//! the (non-flattened) data block, and therefore always matches the emitted `data` bytes of JSON output. Consumers
+//! verify payloads by recomputing the checksum over the raw `data` bytes via [`sha256_hex`] or [`hmac_sha256_hex`].
+//!
+//! - `algo = "sha256"` (default) emits a plain SHA-256 digest, guarding against corruption
+//! - `algo = "hmac-sha256"` emits an HMAC-SHA256 signature, with `key` being the path of a `fn() -> impl AsRef<[u8]>`
+//!   providing the secret key
+//!
+//! ```
+//! use serde::Serialize;
+//! use shrinkwrap::{ToWrappedWith, Transform, TransformToNest, Wrap};
+//! use shrinkwrap::integrity;
+//!
+//! #[derive(Debug, Clone, Serialize, Wrap)]
+//! #[shrinkwrap(transform = MyTransform, wrapper(flatten = false, integrity(field = "checksum")))]
+//! #[shrinkwrap(nest(id = "text", field_type = String))]
+//! pub struct MyData {
+//!     #[shrinkwrap(nest(id = "text"))]
+//!     uptime_sec: i64,
+//! }
+//! # struct MyTransform;
+//! # impl Transform for MyTransform {
+//! #     type Options = ();
+//! # }
+//! # impl TransformToNest<MyDataNestedText> for MyTransform {
+//! #     type Data = MyData;
+//! #     fn transform_to_nest(&self, data: &MyData, _: &()) -> MyDataNestedText {
+//! #         MyDataNestedText { uptime_sec: data.uptime_sec.to_string() }
+//! #     }
+//! # }
+//!
+//! let json = serde_json::to_string(&MyData { uptime_sec: 10 }.to_wrapped_with_t(&MyTransform)).unwrap();
+//! let checksum = integrity::sha256_hex(br#"{"uptime_sec":10}"#);
+//! assert_eq!(json, format!(r#"{{"extra":{{"text":{{"uptime_sec":"10"}}}},"data":{{"uptime_sec":10}},"checksum":"{checksum}"}}"#));
+//! ```
+
+use serde::Serialize;
+use std::fmt::Write;
+
+use crate::Error;
+
+/// Size of the SHA-256 block, in bytes
+const BLOCK_LEN: usize = 64;
+
+/// SHA-256 round constants
+const K: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+/// SHA-256 initial hash values
+const H: [u32; 8] = [0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19];
+
+/// SHA-256 digest (FIPS 180-4) of the concatenation of `parts`
+fn sha256_parts(parts: &[&[u8]]) -> [u8; 32] {
+    let len: usize = parts.iter().map(|part| part.len()).sum();
+    let mut message = Vec::with_capacity(len + BLOCK_LEN + 8);
+    for part in parts {
+        message.extend_from_slice(part);
+    }
+    message.push(0x80);
+    while message.len() % BLOCK_LEN != BLOCK_LEN - 8 {
+        message.push(0);
+    }
+    message.extend_from_slice(&((len as u64) * 8).to_be_bytes());
+
+    let mut state = H;
+    for block in message.chunks_exact(BLOCK_LEN) {
+        let mut w = [0u32; 64];
+        for (word, bytes) in w.iter_mut().zip(block.chunks_exact(4)) {
+            *word = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        }
+        for i in 16..64 {
+            let s0 = w[i - 15].rotate_right(7) ^ w[i - 15].rotate_right(18) ^ (w[i - 15] >> 3);
+            let s1 = w[i - 2].rotate_right(17) ^ w[i - 2].rotate_right(19) ^ (w[i - 2] >> 10);
+            w[i] = w[i - 16].wrapping_add(s0).wrapping_add(w[i - 7]).wrapping_add(s1);
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = state;
+        for (k, w) in K.iter().zip(w) {
+            let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+            let ch = (e & f) ^ (!e & g);
+            let t1 = h.wrapping_add(s1).wrapping_add(ch).wrapping_add(*k).wrapping_add(w);
+            let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+            let maj = (a & b) ^ (a & c) ^ (b & c);
+            let t2 = s0.wrapping_add(maj);
+            (h, g, f, e, d, c, b, a) = (g, f, e, d.wrapping_add(t1), c, b, a, t1.wrapping_add(t2));
+        }
+        for (value, add) in state.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+            *value = value.wrapping_add(add);
+        }
+    }
+
+    let mut digest = [0u8; 32];
+    for (bytes, value) in digest.chunks_exact_mut(4).zip(state) {
+        bytes.copy_from_slice(&value.to_be_bytes());
+    }
+    digest
+}
+
+/// Lowercase hex encoding of `bytes`
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::with_capacity(bytes.len() * 2), |mut out, byte| {
+        let _ = write!(out, "{byte:02x}");
+        out
+    })
+}
+
+/// SHA-256 digest of `bytes`
+///
+/// ```
+/// use shrinkwrap::integrity::sha256;
+///
+/// assert_eq!(sha256(b"abc")[..4], [0xba, 0x78, 0x16, 0xbf]);
+/// ```
+pub fn sha256(bytes: &[u8]) -> [u8; 32] {
+    sha256_parts(&[bytes])
+}
+
+/// Lowercase hex encoded SHA-256 digest of `bytes`
+///
+/// ```
+/// use shrinkwrap::integrity::sha256_hex;
+///
+/// assert_eq!(sha256_hex(b""), "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855");
+/// assert_eq!(sha256_hex(b"abc"), "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+/// ```
+pub fn sha256_hex(bytes: &[u8]) -> String {
+    to_hex(&sha256(bytes))
+}
+
+/// HMAC-SHA256 (RFC 2104) signature of `bytes` using `key`
+pub fn hmac_sha256(key: &[u8], bytes: &[u8]) -> [u8; 32] {
+    let mut block_key = [0u8; BLOCK_LEN];
+    match key.len() > BLOCK_LEN {
+        true => block_key[..32].copy_from_slice(&sha256(key)),
+        false => block_key[..key.len()].copy_from_slice(key),
+    }
+    let inner_pad = block_key.map(|byte| byte ^ 0x36);
+    let outer_pad = block_key.map(|byte| byte ^ 0x5c);
+    let inner = sha256_parts(&[&inner_pad, bytes]);
+    sha256_parts(&[&outer_pad, &inner])
+}
+
+/// Lowercase hex encoded HMAC-SHA256 signature of `bytes` using `key`
+///
+/// ```
+/// use shrinkwrap::integrity::hmac_sha256_hex;
+///
+/// assert_eq!(
+///     hmac_sha256_hex(b"key", b"The quick brown fox jumps over the lazy dog"),
+///     "f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8",
+/// );
+/// ```
+pub fn hmac_sha256_hex(key: &[u8], bytes: &[u8]) -> String {
+    to_hex(&hmac_sha256(key, bytes))
+}
+
+/// Hex encoded SHA-256 digest of the compact JSON encoding of `value`, as emitted by `wrapper(integrity(..))`
+pub fn checksum<T: Serialize + ?Sized>(value: &T) -> Result<String, Error> {
+    Ok(sha256_hex(&serde_json::to_vec(value)?))
+}
+
+/// Hex encoded HMAC-SHA256 signature of the compact JSON encoding of `value`, as emitted by
+/// `wrapper(integrity(algo = "hmac-sha256", ..))`
+pub fn signature<T: Serialize + ?Sized>(key: &[u8], value: &T) -> Result<String, Error> {
+    Ok(hmac_sha256_hex(key, &serde_json::to_vec(value)?))
+}
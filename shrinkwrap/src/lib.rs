@@ -1,21 +1,223 @@
+#[doc(hidden)]
+pub mod __private;
+pub mod alias;
+mod assert_impls;
+#[cfg(feature = "axum")]
+pub mod axum;
+mod batch;
 mod build_nest_value;
+#[cfg(feature = "cache")]
+pub mod cache;
+#[cfg(feature = "context")]
+pub mod context;
+#[cfg(feature = "csv")]
+pub mod csv;
+#[cfg(feature = "chrono")]
+pub mod datetime;
+mod deprecation;
+pub mod diagnostics;
+#[cfg(feature = "diff")]
+pub mod diff;
+#[cfg(feature = "json")]
+pub mod encode;
+mod error;
+#[cfg(feature = "json")]
+pub mod export;
+#[cfg(feature = "json")]
+pub mod filter;
+mod from_sources;
+#[cfg(feature = "tonic")]
+pub mod grpc;
+#[cfg(feature = "http")]
+pub mod http;
+#[cfg(feature = "integrity")]
+pub mod integrity;
+mod mapped_options;
 mod nest;
+#[cfg(feature = "pool")]
+pub mod pool;
+pub mod prelude;
+#[cfg(feature = "providers")]
+pub mod providers;
+#[cfg(any(feature = "csv", feature = "json"))]
+mod render;
+mod row_view;
+mod serializable_options;
+#[cfg(feature = "json")]
+pub mod shape;
+#[cfg(feature = "json")]
+pub mod size;
+#[cfg(feature = "stream")]
+pub mod stream;
+#[cfg(feature = "diff")]
+pub mod testing;
+#[cfg(feature = "topology")]
+pub mod topology;
 mod to_nest;
+mod to_nest_with_siblings;
 mod transform;
+mod transform_options;
+mod try_batch;
 mod try_build_nest_value;
+mod try_from_sources;
 mod try_to_nest;
+mod try_to_nest_with_siblings;
 mod try_wrap;
+mod try_wrap_map;
 mod wrap;
+mod wrap_map;
 
 pub use crate::{
-    build_nest_value::BuildNestValue,
+    batch::{BatchTransformToNest, BatchTransformToNest2, ToWrappedAllWith, WrapAllWith},
+    build_nest_value::{BuildNestValue, BuildNestValue2},
+    deprecation::DeprecatedNestGate,
+    error::Error,
+    from_sources::{TransformFromSources, TransformFromSources2},
+    mapped_options::MappedOptionsTransform,
     nest::NestValueType,
-    to_nest::{ToNestWith, TransformToNest},
-    transform::Transform,
-    try_build_nest_value::TryBuildNestValue,
-    try_to_nest::{TryToNestWith, TryTransformToNest},
-    try_wrap::{TryWrapDataWith, TryToWrappedWith},
+    row_view::RowView,
+    serializable_options::SerializableOptions,
+    to_nest::{ToNest, ToNestWith, TransformToNest, TransformToNest2},
+    to_nest_with_siblings::{TransformToNestWithSiblings, TransformToNestWithSiblings2},
+    transform::{NoOptions, Transform, Transform2, ValidatedTransform},
+    transform_options::TransformOptions,
+    try_batch::{TryBatchTransformToNest, TryBatchTransformToNest2, TryToWrappedAllWith, TryWrapAllWith},
+    try_build_nest_value::{TryBuildNestValue, TryBuildNestValue2},
+    try_from_sources::{TryTransformFromSources, TryTransformFromSources2},
+    try_to_nest::{TryToNestWith, TryTransformToNest, TryTransformToNest2},
+    try_to_nest_with_siblings::{TryTransformToNestWithSiblings, TryTransformToNestWithSiblings2},
+    try_wrap::{NestError, TryWrapDataWith, TryToWrappedWith, TryToWrappedWithErrors},
+    try_wrap_map::TryWrapMapWith,
     wrap::{ToWrappedWith, WrapDataWith},
+    wrap_map::{MapEntry, WrapMapWith},
 };
 
 pub use shrinkwrap_macros::Wrap;
+
+/// The `validator` crate, whose `Validate` trait is derived for nests declaring `validate`
+#[cfg(feature = "validate")]
+pub use validator;
+
+/// Inert companion to [`Wrap`], registers the `shrinkwrap`, `shrinkwrap_attr`, and `shrinkwrap_preset` helper attributes without generating anything.
+///
+/// Use when `Wrap` is derived conditionally, so that field-level attributes are still accepted when the derive is disabled.
+///
+/// ```
+/// # use shrinkwrap::{Transform, TransformToNest, Wrap, WrapHelpers};
+/// #
+/// #[derive(Debug, Clone, serde::Serialize, WrapHelpers)]
+/// #[cfg_attr(all(), derive(Wrap), shrinkwrap(transform = MyTransform), shrinkwrap(nest(id = "text", field_type = String)))]
+/// pub struct MyData {
+///     #[shrinkwrap(nest(id = "text"))]
+///     #[shrinkwrap_attr(attr(doc = "Uptime, in seconds"), limit(nests("text")))]
+///     uptime_sec: i64,
+/// }
+///
+/// #[derive(Debug, Clone, serde::Serialize, WrapHelpers)]
+/// #[cfg_attr(any(), derive(Wrap), shrinkwrap(transform = MyTransform), shrinkwrap(nest(id = "text", field_type = String)))]
+/// pub struct MyDisabledData {
+///     #[shrinkwrap(nest(id = "text"))]
+///     #[shrinkwrap_attr(attr(doc = "Uptime, in seconds"), limit(nests("text")))]
+///     uptime_sec: i64,
+/// }
+/// #
+/// # struct MyTransform;
+/// # impl Transform for MyTransform {
+/// #     type Options = ();
+/// # }
+/// # impl TransformToNest<MyDataNestedText> for MyTransform {
+/// #     type Data = MyData;
+/// #     fn transform_to_nest(&self, data: &MyData, _: &()) -> MyDataNestedText {
+/// #         MyDataNestedText { uptime_sec: data.uptime_sec.to_string() }
+/// #     }
+/// # }
+/// ```
+pub use shrinkwrap_macros::WrapHelpers;
+
+/// Derives [`TransformOptions`](trait@TransformOptions) for a transform's options struct, see the trait docs.
+///
+/// - `bool` fields named `with_{nest_id}` gate the nest with that ID
+/// - `#[shrinkwrap(nest = "..")]` gates the given nest by a field with another name
+/// - `#[shrinkwrap(skip)]` excludes a `with_{..}` field from the gates
+/// - `#[shrinkwrap(no_deprecated_gate)]` (on the struct) skips the [`DeprecatedNestGate`] impl
+pub use shrinkwrap_macros::TransformOptions;
+
+/// Applies a preset declared via [`define_preset!`], merging its options with the struct's own `shrinkwrap` options.
+///
+/// Own options replace preset options of the same name. Nested option lists (e.g. `wrapper(..)`) are merged
+/// recursively, and repeatable options (`nest`, `annotate`, `cfg_derive`, `computed`) are combined. Must be placed
+/// before `#[derive(Wrap)]`.
+///
+/// ```
+/// # use shrinkwrap::{Transform, TransformToNest, Wrap};
+/// shrinkwrap::define_preset! {
+///     org_defaults {
+///         transform = MyTransform,
+///         wrapper(struct_suffix = Envelope),
+///         extra(struct_suffix = Meta),
+///     }
+/// }
+///
+/// #[shrinkwrap::preset(org_defaults)]
+/// #[derive(Debug, Clone, serde::Serialize, Wrap)]
+/// #[shrinkwrap(nest(id = "text", field_type = String))]
+/// pub struct MyData {
+///     #[shrinkwrap(nest(id = "text"))]
+///     uptime_sec: i64,
+/// }
+///
+/// // `struct_doc` is merged into the preset's `extra(..)`, `struct_suffix` overrides the preset's
+/// #[shrinkwrap::preset(org_defaults)]
+/// #[derive(Debug, Clone, serde::Serialize, Wrap)]
+/// #[shrinkwrap(wrapper(struct_suffix = Response), extra(struct_doc = "Session variations"))]
+/// #[shrinkwrap(nest(id = "text", field_type = String))]
+/// pub struct MySession {
+///     #[shrinkwrap(nest(id = "text"))]
+///     active: i64,
+/// }
+///
+/// let _: Option<(MyDataEnvelope, MyDataMeta, MySessionResponse, MySessionMeta)> = None;
+/// #
+/// # struct MyTransform;
+/// # impl Transform for MyTransform {
+/// #     type Options = ();
+/// # }
+/// # impl TransformToNest<MyDataNestedText> for MyTransform {
+/// #     type Data = MyData;
+/// #     fn transform_to_nest(&self, data: &MyData, _: &()) -> MyDataNestedText {
+/// #         MyDataNestedText { uptime_sec: data.uptime_sec.to_string() }
+/// #     }
+/// # }
+/// # impl TransformToNest<MySessionNestedText> for MyTransform {
+/// #     type Data = MySession;
+/// #     fn transform_to_nest(&self, data: &MySession, _: &()) -> MySessionNestedText {
+/// #         MySessionNestedText { active: data.active.to_string() }
+/// #     }
+/// # }
+/// ```
+pub use shrinkwrap_macros::preset;
+
+/// Declares a preset of container options, applied to structs via [`preset`].
+///
+/// Expands to a `macro_rules!` macro named after the preset. Attributes are forwarded to it, to share a preset across
+/// crates mark it with `#[macro_export]` and apply it by path, e.g. `#[shrinkwrap::preset(org_presets::org_defaults)]`.
+/// Within the declaring crate, presets are applied by name (as with any `macro_rules!` macro).
+#[macro_export]
+macro_rules! define_preset {
+    ($(#[$attr:meta])* $name:ident { $($opts:tt)* }) => {
+        $crate::__define_preset! { ($) $(#[$attr])* $name { $($opts)* } }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __define_preset {
+    (($d:tt) $(#[$attr:meta])* $name:ident { $($opts:tt)* }) => {
+        $(#[$attr])*
+        macro_rules! $name {
+            ($d($d item:tt)*) => {
+                $crate::__private::apply_preset! { ($($opts)*) $d($d item)* }
+            };
+        }
+    };
+}
@@ -0,0 +1,173 @@
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::{
+    BatchTransformToNest, BatchTransformToNest2, Transform, Transform2, TransformToNest, TransformToNest2, TransformToNestWithSiblings,
+    TransformToNestWithSiblings2, TryBatchTransformToNest, TryBatchTransformToNest2, TryTransformToNest, TryTransformToNest2,
+    TryTransformToNestWithSiblings, TryTransformToNestWithSiblings2,
+};
+
+/// A transform `T` accepting options of type `B`, mapped to `T::Options` via `F` before each nest is built.
+///
+/// The mapped options may borrow from the `B` options, see [`Transform2`].
+///
+/// Implements the nest transform traits of `T` (infallible, fallible, with siblings, and batched), so it may be used
+/// anywhere `T` is accepted, e.g. [`to_wrapped_with`](crate::ToWrappedWith::to_wrapped_with). `F` is invoked once per
+/// nest (or per batch), it should therefore be a cheap projection.
+///
+/// Useful when a global options struct is held (e.g. by middleware) and each transform only consumes a projection of
+/// it.
+///
+/// ```
+/// use serde::Serialize;
+/// use shrinkwrap::{MappedOptionsTransform, ToWrappedWith, Transform, TransformToNest, Wrap};
+///
+/// #[derive(Debug, Clone, Serialize, Wrap)]
+/// #[shrinkwrap(transform = MyTransform)]
+/// #[shrinkwrap(nest(id = "text", field_type = String))]
+/// pub struct MyData {
+///     #[shrinkwrap(nest(id = "text"))]
+///     uptime_sec: i64,
+/// }
+///
+/// struct MyTransformOpts {
+///     suffix: &'static str,
+/// }
+/// struct MyTransform;
+/// impl Transform for MyTransform {
+///     type Options = MyTransformOpts;
+/// }
+/// impl TransformToNest<MyDataNestedText> for MyTransform {
+///     type Data = MyData;
+///
+///     fn transform_to_nest(&self, data: &MyData, options: &MyTransformOpts) -> MyDataNestedText {
+///         MyDataNestedText { uptime_sec: format!("{}{}", data.uptime_sec, options.suffix) }
+///     }
+/// }
+///
+/// /// Options held by the application, `MyTransform` only needs a projection of them
+/// struct AppOptions {
+///     verbose: bool,
+/// }
+///
+/// let transform = MappedOptionsTransform::new(MyTransform, |app: &AppOptions| MyTransformOpts {
+///     suffix: if app.verbose { " seconds" } else { "s" },
+/// });
+/// let wrapped = MyData { uptime_sec: 10 }.to_wrapped_with(&transform, &AppOptions { verbose: true });
+/// assert_eq!(wrapped.extra.text.uptime_sec, "10 seconds");
+/// ```
+pub struct MappedOptionsTransform<T, F, B> {
+    transform: T,
+    map: F,
+    options: PhantomData<fn(&B)>,
+}
+
+impl<T, F, B> MappedOptionsTransform<T, F, B>
+where
+    T: Transform2,
+    F: for<'b> Fn(&'b B) -> T::Options<'b>,
+{
+    /// Adapts `transform` to accept options of type `B`, mapped via `map`
+    pub fn new(transform: T, map: F) -> Self {
+        Self { transform, map, options: PhantomData }
+    }
+
+    /// The inner transform
+    pub fn transform(&self) -> &T {
+        &self.transform
+    }
+
+    /// Returns the inner transform
+    pub fn into_inner(self) -> T {
+        self.transform
+    }
+
+    fn map_options<'b>(&self, options: &'b B) -> T::Options<'b> {
+        (self.map)(options)
+    }
+}
+
+impl<T: fmt::Debug, F, B> fmt::Debug for MappedOptionsTransform<T, F, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MappedOptionsTransform").field("transform", &self.transform).finish_non_exhaustive()
+    }
+}
+
+impl<T, F, B> Transform for MappedOptionsTransform<T, F, B> {
+    type Options = B;
+}
+
+impl<N, T, F, B> TransformToNest<N> for MappedOptionsTransform<T, F, B>
+where
+    T: TransformToNest2<N>,
+    F: for<'b> Fn(&'b B) -> T::Options<'b>,
+{
+    type Data = T::Data;
+
+    fn transform_to_nest(&self, data: &Self::Data, options: &B) -> N {
+        self.transform.transform_to_nest(data, &self.map_options(options))
+    }
+}
+
+impl<N, T, F, B> TryTransformToNest<N> for MappedOptionsTransform<T, F, B>
+where
+    T: TryTransformToNest2<N>,
+    F: for<'b> Fn(&'b B) -> T::Options<'b>,
+{
+    type Data = T::Data;
+    type Error = T::Error;
+
+    fn try_transform_to_nest(&self, data: &Self::Data, options: &B) -> Result<N, Self::Error> {
+        self.transform.try_transform_to_nest(data, &self.map_options(options))
+    }
+}
+
+impl<N, S, T, F, B> TransformToNestWithSiblings<N, S> for MappedOptionsTransform<T, F, B>
+where
+    T: TransformToNestWithSiblings2<N, S>,
+    F: for<'b> Fn(&'b B) -> T::Options<'b>,
+{
+    type Data = T::Data;
+
+    fn transform_to_nest_with_siblings(&self, data: &Self::Data, sibling: &S, options: &B) -> N {
+        self.transform.transform_to_nest_with_siblings(data, sibling, &self.map_options(options))
+    }
+}
+
+impl<N, S, T, F, B> TryTransformToNestWithSiblings<N, S> for MappedOptionsTransform<T, F, B>
+where
+    T: TryTransformToNestWithSiblings2<N, S>,
+    F: for<'b> Fn(&'b B) -> T::Options<'b>,
+{
+    type Data = T::Data;
+    type Error = T::Error;
+
+    fn try_transform_to_nest_with_siblings(&self, data: &Self::Data, sibling: &S, options: &B) -> Result<N, Self::Error> {
+        self.transform.try_transform_to_nest_with_siblings(data, sibling, &self.map_options(options))
+    }
+}
+
+impl<N, T, F, B> BatchTransformToNest<N> for MappedOptionsTransform<T, F, B>
+where
+    T: BatchTransformToNest2<N>,
+    F: for<'b> Fn(&'b B) -> T::Options<'b>,
+{
+    type Data = T::Data;
+
+    fn transform_many(&self, data: &[Self::Data], options: &B) -> Vec<N> {
+        self.transform.transform_many(data, &self.map_options(options))
+    }
+}
+
+impl<N, T, F, B> TryBatchTransformToNest<N> for MappedOptionsTransform<T, F, B>
+where
+    T: TryBatchTransformToNest2<N>,
+    F: for<'b> Fn(&'b B) -> T::Options<'b>,
+{
+    type Data = T::Data;
+    type Error = T::Error;
+
+    fn try_transform_many(&self, data: &[Self::Data], options: &B) -> Result<Vec<N>, Self::Error> {
+        self.transform.try_transform_many(data, &self.map_options(options))
+    }
+}
@@ -0,0 +1,208 @@
+//! Pooling of reusable transform instances, enabled via the `pool` feature.
+//!
+//! Transforms holding expensive resources (e.g. compiled regex sets or templates) are costly to build per call, and
+//! often can't be shared across threads as-is (e.g. when holding a `RefCell` cache). A [`TransformPool`] hands out
+//! instances exclusively, building new ones via its factory only when all existing instances are in use. Instances
+//! are returned to the pool once their [`PooledTransform`] guard is dropped.
+//!
+//! ## Thread safety
+//!
+//! `TransformPool<T>` is `Send + Sync` whenever `T: Send`, the transform itself doesn't need to be `Sync` as each
+//! instance is used by a single thread at a time. The factory must be `Send + Sync`, as any thread checking out an
+//! instance may invoke it.
+//!
+//! ```
+//! use serde::Serialize;
+//! use shrinkwrap::{Transform, TransformToNest, Wrap};
+//! use shrinkwrap::pool::{self, TransformPool};
+//! use std::cell::RefCell;
+//!
+//! #[derive(Debug, Clone, Serialize, Wrap)]
+//! #[shrinkwrap(transform = MyTransform)]
+//! #[shrinkwrap(nest(id = "text", field_type = String))]
+//! pub struct MyData {
+//!     #[shrinkwrap(nest(id = "text"))]
+//!     uptime_sec: i64,
+//! }
+//!
+//! /// Not `Sync`, buffers are reused across calls
+//! struct MyTransform {
+//!     buffer: RefCell<String>,
+//! }
+//! impl Transform for MyTransform {
+//!     type Options = ();
+//! }
+//! impl TransformToNest<MyDataNestedText> for MyTransform {
+//!     type Data = MyData;
+//!
+//!     fn transform_to_nest(&self, data: &MyData, _: &()) -> MyDataNestedText {
+//!         let mut buffer = self.buffer.borrow_mut();
+//!         buffer.clear();
+//!         buffer.push_str(&data.uptime_sec.to_string());
+//!         buffer.push_str(" sec");
+//!         MyDataNestedText { uptime_sec: buffer.clone() }
+//!     }
+//! }
+//!
+//! let pool = TransformPool::new(|| MyTransform { buffer: RefCell::new(String::with_capacity(64)) });
+//! // `MyTransform` isn't `Sync`, the pool is
+//! fn assert_sync<S: Sync>(_: &S) {}
+//! assert_sync(&pool);
+//!
+//! let pool = &pool;
+//! let uptimes = std::thread::scope(|scope| {
+//!     let handles: Vec<_> = (0..4)
+//!         .map(|uptime_sec| scope.spawn(move || pool::wrap_with_pooled(MyData { uptime_sec }, pool, &())))
+//!         .collect();
+//!     handles.into_iter().map(|handle| handle.join().unwrap().extra.text.uptime_sec).collect::<Vec<_>>()
+//! });
+//! assert_eq!(uptimes, ["0 sec", "1 sec", "2 sec", "3 sec"]);
+//! assert!((1..=4).contains(&pool.idle()));
+//! ```
+
+use std::fmt;
+use std::ops::Deref;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use crate::{ToWrappedWith, Transform2, TryToWrappedWith, WrapAllWith};
+
+/// A pool of reusable transform instances, see the [module docs](self)
+pub struct TransformPool<T> {
+    idle: Mutex<Vec<T>>,
+    factory: Box<dyn Fn() -> T + Send + Sync>,
+    max_idle: Option<usize>,
+}
+impl<T> TransformPool<T> {
+    /// Creates an empty pool, instances are built via `factory` on demand
+    pub fn new(factory: impl Fn() -> T + Send + Sync + 'static) -> Self {
+        Self { idle: Mutex::new(Vec::new()), factory: Box::new(factory), max_idle: None }
+    }
+
+    /// Builds `count` instances upfront, rather than on first use
+    pub fn prefilled(mut self, count: usize) -> Self {
+        let idle = self.idle.get_mut().unwrap_or_else(PoisonError::into_inner);
+        idle.extend((0..count).map(|_| (self.factory)()));
+        self
+    }
+
+    /// Limits the number of idle instances retained by the pool, instances returned to a full pool are dropped
+    ///
+    /// ```
+    /// use shrinkwrap::Transform;
+    /// use shrinkwrap::pool::TransformPool;
+    ///
+    /// struct MyTransform;
+    /// impl Transform for MyTransform {
+    ///     type Options = ();
+    /// }
+    ///
+    /// let pool = TransformPool::new(|| MyTransform).with_max_idle(1);
+    /// let (first, second) = (pool.get(), pool.get());
+    /// drop((first, second));
+    /// assert_eq!(pool.idle(), 1);
+    /// ```
+    pub fn with_max_idle(mut self, max_idle: usize) -> Self {
+        self.idle.get_mut().unwrap_or_else(PoisonError::into_inner).truncate(max_idle);
+        self.max_idle = Some(max_idle);
+        self
+    }
+
+    /// Checks out an idle instance, building a new one if none are available
+    ///
+    /// ```
+    /// use shrinkwrap::Transform;
+    /// use shrinkwrap::pool::TransformPool;
+    ///
+    /// struct MyTransform;
+    /// impl Transform for MyTransform {
+    ///     type Options = ();
+    /// }
+    ///
+    /// let pool = TransformPool::new(|| MyTransform).prefilled(1);
+    /// let transform = pool.get();
+    /// assert_eq!(pool.idle(), 0);
+    /// drop(transform);
+    /// assert_eq!(pool.idle(), 1);
+    /// ```
+    pub fn get(&self) -> PooledTransform<'_, T> {
+        let transform = self.lock().pop().unwrap_or_else(|| (self.factory)());
+        PooledTransform { pool: self, transform: Some(transform) }
+    }
+
+    /// Number of idle instances currently held by the pool
+    pub fn idle(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Returns an instance to the pool
+    fn release(&self, transform: T) {
+        let mut idle = self.lock();
+        if self.max_idle.is_none_or(|max_idle| idle.len() < max_idle) {
+            idle.push(transform);
+        }
+    }
+
+    /// Locks the idle instances, a poisoned lock is recovered as instances are only pushed and popped
+    fn lock(&self) -> MutexGuard<'_, Vec<T>> {
+        self.idle.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+impl<T> fmt::Debug for TransformPool<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let idle = self.idle.lock().map_or_else(|poisoned| poisoned.get_ref().len(), |idle| idle.len());
+        f.debug_struct("TransformPool").field("idle", &idle).field("max_idle", &self.max_idle).finish_non_exhaustive()
+    }
+}
+
+/// A transform instance checked out from a [`TransformPool`], returned to the pool when dropped
+pub struct PooledTransform<'a, T> {
+    pool: &'a TransformPool<T>,
+    /// Only taken when dropped
+    transform: Option<T>,
+}
+impl<T> Deref for PooledTransform<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.transform.as_ref().expect("pooled transform is only taken on drop")
+    }
+}
+impl<T> Drop for PooledTransform<'_, T> {
+    fn drop(&mut self) {
+        if let Some(transform) = self.transform.take() {
+            self.pool.release(transform);
+        }
+    }
+}
+impl<T: fmt::Debug> fmt::Debug for PooledTransform<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("PooledTransform").field(&**self).finish()
+    }
+}
+
+/// Wraps `data` using a transform checked out from `pool`, see [`ToWrappedWith`]
+pub fn wrap_with_pooled<D, T>(data: D, pool: &TransformPool<T>, options: &T::Options<'_>) -> D::Wrapper
+where
+    T: Transform2,
+    D: ToWrappedWith<T>,
+{
+    data.to_wrapped_with(&pool.get(), options)
+}
+
+/// Wraps `data` using a transform checked out from `pool`, see [`TryToWrappedWith`]
+pub fn try_wrap_with_pooled<D, T>(data: D, pool: &TransformPool<T>, options: &T::Options<'_>) -> Result<D::Wrapper, D::Error>
+where
+    T: Transform2,
+    D: TryToWrappedWith<T>,
+{
+    data.try_to_wrapped_with(&pool.get(), options)
+}
+
+/// Wraps a collection of data structs using a single transform checked out from `pool`, see [`WrapAllWith`]
+pub fn wrap_all_with_pooled<D, T>(data: Vec<D>, pool: &TransformPool<T>, options: &T::Options<'_>) -> Vec<D::Wrapper>
+where
+    T: Transform2,
+    D: WrapAllWith<T>,
+{
+    D::wrap_all_with(data, &pool.get(), options)
+}
@@ -0,0 +1,87 @@
+//! Glob import of the [`Wrap`] derive along with all traits (and the [`MappedOptionsTransform`] adapter), e.g.
+//! `use shrinkwrap::prelude::*;`
+//!
+//! Traits of optional features are included when their feature is enabled. The [`Transform2`] variants of the nest
+//! traits (e.g. [`TransformToNest2`](crate::TransformToNest2)) are excluded, their methods share the names of the
+//! [`Transform`] based traits.
+//!
+//! ```
+//! use shrinkwrap::prelude::*;
+//!
+//! #[derive(Debug, Clone, serde::Serialize, Wrap)]
+//! #[shrinkwrap(transform = MyTransform)]
+//! #[shrinkwrap(nest(id = "text", field_type = String))]
+//! pub struct MyData {
+//!     #[shrinkwrap(nest(id = "text"))]
+//!     uptime_sec: i64,
+//! }
+//!
+//! struct MyTransform;
+//! impl Transform for MyTransform {
+//!     type Options = ();
+//! }
+//! impl TransformToNest<MyDataNestedText> for MyTransform {
+//!     type Data = MyData;
+//!
+//!     fn transform_to_nest(&self, data: &MyData, _: &()) -> MyDataNestedText {
+//!         MyDataNestedText { uptime_sec: data.uptime_sec.to_string() }
+//!     }
+//! }
+//!
+//! let wrapped = MyData { uptime_sec: 10 }.to_wrapped_with_t(&MyTransform);
+//! assert_eq!(wrapped.extra.text.uptime_sec, "10");
+//! ```
+
+pub use crate::{
+    BatchTransformToNest,
+    BuildNestValue,
+    DeprecatedNestGate,
+    MappedOptionsTransform,
+    NestValueType,
+    NoOptions,
+    RowView,
+    SerializableOptions,
+    ToNest,
+    ToNestWith,
+    ToWrappedAllWith,
+    ToWrappedWith,
+    Transform,
+    Transform2,
+    TransformFromSources,
+    TransformOptions,
+    TransformToNest,
+    TransformToNestWithSiblings,
+    TryBatchTransformToNest,
+    TryBuildNestValue,
+    TryToNestWith,
+    TryToWrappedAllWith,
+    TryToWrappedWith,
+    TryToWrappedWithErrors,
+    TryTransformFromSources,
+    TryTransformToNest,
+    TryTransformToNestWithSiblings,
+    TryWrapAllWith,
+    TryWrapDataWith,
+    TryWrapMapWith,
+    ValidatedTransform,
+    Wrap,
+    WrapAllWith,
+    WrapDataWith,
+    WrapHelpers,
+    WrapMapWith,
+};
+
+#[cfg(feature = "context")]
+pub use crate::context::ContextualSerialize;
+
+#[cfg(feature = "chrono")]
+pub use crate::datetime::DateTimeFormat;
+
+#[cfg(feature = "providers")]
+pub use crate::providers::NestProvider;
+
+#[cfg(feature = "json")]
+pub use crate::shape::JsonShape;
+
+#[cfg(feature = "stream")]
+pub use crate::stream::WrapStreamExt;
@@ -0,0 +1,169 @@
+//! Built-in nest providers, enabled via the `providers` feature.
+//!
+//! A provider builds a nest value directly from the nest's source data, without a user-defined `TransformToNest`
+//! impl. Providers are assigned to a nest via `provider`, i.e. `#[shrinkwrap(nest(id = "meta", provider = shrinkwrap::providers::Timestamps))]`,
+//! the provider type is then used for the nest in place of a generated struct.
+//!
+//! ```
+//! use serde::Serialize;
+//! use shrinkwrap::{ToWrappedWith, Transform, TransformToNest, Wrap};
+//! use shrinkwrap::providers::{Checksum, Timestamps};
+//!
+//! #[derive(Debug, Clone, Serialize, Wrap)]
+//! #[shrinkwrap(transform = MyTransform)]
+//! #[shrinkwrap(nest(id = "text", field_type = String))]
+//! #[shrinkwrap(nest(id = "meta", provider = shrinkwrap::providers::Timestamps))]
+//! #[shrinkwrap(nest(id = "checksum", provider = shrinkwrap::providers::Checksum))]
+//! pub struct MyData {
+//!     #[shrinkwrap(nest(id = "text"))]
+//!     balance: i64,
+//! }
+//!
+//! struct MyTransform;
+//! impl Transform for MyTransform {
+//!     type Options = ();
+//! }
+//! impl TransformToNest<MyDataNestedText> for MyTransform {
+//!     type Data = MyData;
+//!
+//!     fn transform_to_nest(&self, data: &MyData, _: &()) -> MyDataNestedText {
+//!         MyDataNestedText { balance: data.balance.to_string() }
+//!     }
+//! }
+//!
+//! let wrapper = MyData { balance: 3 }.to_wrapped_with(&MyTransform, &());
+//! assert!(wrapper.extra.meta.generated_at > 0);
+//! assert_eq!(wrapper.extra.meta.elapsed_ms, None);
+//! assert_eq!(wrapper.extra.checksum, Checksum::of(&MyData { balance: 3 }));
+//!
+//! // the time elapsed since a given start is recorded when wrapping within `Timestamps::since`
+//! let started_at = std::time::Instant::now();
+//! let wrapper = Timestamps::since(started_at, || MyData { balance: 3 }.to_wrapped_with(&MyTransform, &()));
+//! assert!(wrapper.extra.meta.elapsed_ms.is_some());
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::cell::Cell;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+/// Builds a nest value from the nest's source data (the origin data for root nests, or the parent nest otherwise).
+///
+/// Implement to define custom providers, which can then be assigned to a nest via `provider`.
+pub trait NestProvider<D: ?Sized>: Sized {
+    fn provide(data: &D) -> Self;
+}
+
+// !- Timestamps
+
+thread_local! {
+    /// Start of the innermost active `Timestamps::since` call on this thread
+    static STARTED_AT: Cell<Option<Instant>> = const { Cell::new(None) };
+}
+
+/// Records when the nest was generated, along with the time elapsed since the start given to [`Timestamps::since`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Timestamps {
+    /// Unix timestamp (milliseconds) of when the nest was generated
+    pub generated_at: u64,
+
+    /// Milliseconds elapsed between the start given to [`Timestamps::since`] and the nest being generated, `None` when
+    /// generated outside of it
+    pub elapsed_ms: Option<u64>,
+}
+impl Timestamps {
+    /// Timestamps for the current system time
+    pub fn now() -> Self {
+        let since_epoch = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+        let elapsed = STARTED_AT.with(Cell::get).map(|start| start.elapsed());
+        Self {
+            generated_at: as_millis(since_epoch.as_millis()),
+            elapsed_ms: elapsed.map(|elapsed| as_millis(elapsed.as_millis())),
+        }
+    }
+
+    /// Runs `wrap`, recording the time elapsed since `start` (e.g. the receipt of a request) within the `Timestamps`
+    /// provided on this thread
+    pub fn since<R>(start: Instant, wrap: impl FnOnce() -> R) -> R {
+        let outer = STARTED_AT.with(|started_at| started_at.replace(Some(start)));
+        let out = wrap();
+        STARTED_AT.with(|started_at| started_at.set(outer));
+        out
+    }
+}
+impl<D: ?Sized> NestProvider<D> for Timestamps {
+    fn provide(_: &D) -> Self {
+        Self::now()
+    }
+}
+
+fn as_millis(millis: u128) -> u64 {
+    u64::try_from(millis).unwrap_or(u64::MAX)
+}
+
+// !- Checksum
+
+/// Hash of the serialized (JSON) source data, as 16 hex digits (64-bit FNV-1a)
+///
+/// The checksum only depends on the serialized output, making it suitable for change detection (e.g. `ETag`s) as long
+/// as the source serializes deterministically. `HashMap` and `HashSet` iterate in a per-process random order, sources
+/// containing them should use `BTreeMap`/`BTreeSet` (or another ordered collection) instead. It must not be used for
+/// security purposes.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Checksum {
+    /// Hex-encoded hash of the source data, `None` if it failed to serialize (e.g. a map with non-string keys)
+    pub checksum: Option<String>,
+}
+impl Checksum {
+    /// Checksum of the serialized value
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use shrinkwrap::providers::Checksum;
+    ///
+    /// assert_eq!(Checksum::of(&[1, 2]), Checksum::of(&vec![1, 2]));
+    /// assert_ne!(Checksum::of(&[1, 2]), Checksum::of(&[2, 1]));
+    /// assert_eq!(Checksum::of("text").checksum.map(|checksum| checksum.len()), Some(16));
+    /// assert_eq!(Checksum::of(&BTreeMap::from([((1, 2), "value")])).checksum, None);
+    /// ```
+    pub fn of<T: Serialize + ?Sized>(value: &T) -> Self {
+        const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const PRIME: u64 = 0x0100_0000_01b3;
+
+        let checksum = serde_json::to_vec(value).ok().map(|bytes| {
+            let hash = bytes.iter().fold(OFFSET_BASIS, |hash, byte| (hash ^ u64::from(*byte)).wrapping_mul(PRIME));
+            format!("{hash:016x}")
+        });
+        Self { checksum }
+    }
+}
+impl<D: Serialize + ?Sized> NestProvider<D> for Checksum {
+    fn provide(data: &D) -> Self {
+        Self::of(data)
+    }
+}
+
+// !- JSON shapes
+
+#[cfg(feature = "json")]
+mod shapes {
+    use super::{Checksum, Timestamps};
+    use crate::shape::{JsonShape, Shape, ShapeKey};
+
+    const fn key(name: &'static str, nullable: bool) -> ShapeKey {
+        ShapeKey { name, required: true, nullable, shape: None }
+    }
+
+    impl JsonShape for Timestamps {
+        const SHAPE: Shape = Shape {
+            name: "Timestamps",
+            nest: None,
+            keys: &[key("generated_at", false), key("elapsed_ms", true)],
+            open: false,
+        };
+    }
+    impl JsonShape for Checksum {
+        const SHAPE: Shape = Shape { name: "Checksum", nest: None, keys: &[key("checksum", true)], open: false };
+    }
+}
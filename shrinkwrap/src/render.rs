@@ -0,0 +1,17 @@
+//! Rendering of field values as plain strings, shared by the `csv` and `row_view` options of the
+//! [`Wrap`](crate::Wrap) derive.
+
+use serde::Serialize;
+use serde_json::Value;
+
+/// Renders a value via `Serialize`: strings are used as-is, `null` (e.g. `None`) is empty, and other values use their
+/// JSON representation.
+///
+/// Fails if the value cannot be serialized, e.g. a map with non-string keys.
+pub fn value<T: Serialize + ?Sized>(value: &T) -> Result<String, serde_json::Error> {
+    Ok(match serde_json::to_value(value)? {
+        Value::String(text) => text,
+        Value::Null => String::new(),
+        value => value.to_string(),
+    })
+}
@@ -0,0 +1,47 @@
+/// Field-wise string view of a nest, implemented for each nest struct via the `row_view` option of the
+/// [`Wrap`](crate::Wrap) derive.
+///
+/// Allows generic exporters (e.g. CSV writers, key-value logs, or metrics labels) to consume any nest without
+/// per-type code. Fields are named by their serialized name, and rendered via `Display` by default, or via `Serialize`
+/// with `row_view(render = "serialize")` (requires the `json` feature) where strings are used as-is, `None` is empty,
+/// and other values use their JSON representation. Values which fail to serialize (e.g. maps with non-string keys) are
+/// rendered as `<error: {reason}>` rather than aborting the iteration.
+///
+/// ```
+/// use serde::Serialize;
+/// use shrinkwrap::{RowView, ToWrappedWith, Transform, TransformToNest, Wrap};
+///
+/// #[derive(Debug, Clone, Serialize, Wrap)]
+/// #[shrinkwrap(transform = MyTransform, row_view)]
+/// #[shrinkwrap(nest(id = "text", field_type = String))]
+/// pub struct Account {
+///     #[shrinkwrap(nest(id = "text"))]
+///     balance: f64,
+///     #[shrinkwrap(nest(id = "text"))]
+///     limit: f64,
+/// }
+///
+/// struct MyTransform;
+/// impl Transform for MyTransform {
+///     type Options = ();
+/// }
+/// impl TransformToNest<AccountNestedText> for MyTransform {
+///     type Data = Account;
+///
+///     fn transform_to_nest(&self, data: &Account, _: &()) -> AccountNestedText {
+///         AccountNestedText { balance: format!("${:.2}", data.balance), limit: format!("${:.2}", data.limit) }
+///     }
+/// }
+///
+/// /// e.g. `balance=$2.50 limit=$100.00`
+/// fn log_line(nest: &impl RowView) -> String {
+///     nest.fields().map(|(key, value)| format!("{key}={value}")).collect::<Vec<_>>().join(" ")
+/// }
+///
+/// let wrapper = Account { balance: 2.5, limit: 100.0 }.to_wrapped_with(&MyTransform, &());
+/// assert_eq!(log_line(&wrapper.extra.text), "balance=$2.50 limit=$100.00");
+/// ```
+pub trait RowView {
+    /// (serialized name, rendered value) of each field, in declaration order
+    fn fields(&self) -> impl Iterator<Item = (&'static str, String)>;
+}
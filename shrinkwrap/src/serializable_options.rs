@@ -0,0 +1,67 @@
+use serde::Serialize;
+
+/// Transform options echoed into the primary wrapper via `wrapper(echo_options)` of the [`Wrap`](crate::Wrap) derive.
+///
+/// Implemented for the options of the declared `transform`, selecting the subset of settings which should be visible
+/// to clients and caches (e.g. the locale), serialized under the `options` key of the wrapper (configurable via
+/// `wrapper(echo_options(field = ..))`). The echoed type must implement the derives of the wrapper, e.g. `Debug` and
+/// `Clone`.
+///
+/// ```
+/// use serde::Serialize;
+/// use serde_json::json;
+/// use shrinkwrap::{SerializableOptions, ToWrappedWith, Transform2, TransformToNest2, Wrap};
+///
+/// #[derive(Debug, Clone, Serialize, Wrap)]
+/// #[shrinkwrap(transform = MyTransform, wrapper(echo_options))]
+/// #[shrinkwrap(nest(id = "text", field_type = String))]
+/// pub struct Account {
+///     #[shrinkwrap(nest(id = "text"))]
+///     balance: f64,
+/// }
+///
+/// struct MyTransformOpts<'a> {
+///     locale: &'a str,
+///     api_key: &'a str,
+/// }
+///
+/// #[derive(Debug, Clone, Serialize)]
+/// struct EchoedOpts {
+///     locale: String,
+/// }
+///
+/// impl SerializableOptions for MyTransformOpts<'_> {
+///     type Echo = EchoedOpts;
+///
+///     fn echo_options(&self) -> EchoedOpts {
+///         EchoedOpts { locale: self.locale.to_string() }
+///     }
+/// }
+///
+/// struct MyTransform;
+/// impl Transform2 for MyTransform {
+///     type Options<'a> = MyTransformOpts<'a>;
+/// }
+/// impl TransformToNest2<AccountNestedText> for MyTransform {
+///     type Data = Account;
+///
+///     fn transform_to_nest(&self, data: &Account, options: &MyTransformOpts<'_>) -> AccountNestedText {
+///         AccountNestedText { balance: format!("${:.2} ({})", data.balance, options.locale) }
+///     }
+/// }
+///
+/// let options = MyTransformOpts { locale: "en-CA", api_key: "secret" };
+/// let wrapper = Account { balance: 2.5 }.to_wrapped_with(&MyTransform, &options);
+/// assert_eq!(serde_json::to_value(&wrapper).unwrap(), json!({
+///     "extra": { "text": { "balance": "$2.50 (en-CA)" } },
+///     "balance": 2.5,
+///     "options": { "locale": "en-CA" },
+/// }));
+/// ```
+pub trait SerializableOptions {
+    /// The echoed subset of the options
+    type Echo: Serialize;
+
+    /// Returns the subset of the options to embed into the wrapper
+    fn echo_options(&self) -> Self::Echo;
+}
@@ -0,0 +1,132 @@
+//! JSON contract helpers, enabled via the `json` feature.
+//!
+//! See the `json` flag of the [`Wrap`](crate::Wrap) derive for generated usage.
+
+use serde_json::Value;
+use std::fmt::{self, Display};
+
+/// The serialized (JSON) shape of a generated struct.
+///
+/// Implemented automatically for every generated struct when the `json` flag is set on the derive, i.e. `#[shrinkwrap(json)]`.
+pub trait JsonShape {
+    const SHAPE: Shape;
+}
+
+/// Nests using `layout = "map"` have no fixed keys
+impl<K, V> JsonShape for std::collections::BTreeMap<K, V> {
+    const SHAPE: Shape = Shape { name: "BTreeMap", nest: None, keys: &[], open: true };
+}
+
+/// Describes the set of keys expected in the JSON object for a struct.
+#[derive(Debug, Clone, Copy)]
+pub struct Shape {
+    /// Name of the struct the shape was generated for
+    pub name: &'static str,
+
+    /// Nest ID, `None` for the origin data and the primary wrapper
+    pub nest: Option<&'static str>,
+
+    /// Known keys
+    pub keys: &'static [ShapeKey],
+
+    /// If set, unknown keys are tolerated (e.g. when the struct contains a `#[serde(flatten)]` field of an unknown shape)
+    pub open: bool,
+}
+
+/// A single key within a [`Shape`].
+#[derive(Debug, Clone, Copy)]
+pub struct ShapeKey {
+    /// Serialized key name
+    pub name: &'static str,
+
+    /// Whether the key must be present
+    pub required: bool,
+
+    /// Whether the value may be `null` in place of a nested object
+    pub nullable: bool,
+
+    /// Shape of the value, `None` if the value is not a generated/known struct
+    pub shape: Option<&'static Shape>,
+}
+
+/// A difference between a JSON value and the expected [`Shape`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ShapeMismatch {
+    /// The object contains a key not present in the shape
+    UnknownKey {
+        path: String,
+        nest: Option<&'static str>,
+        key: String,
+    },
+    /// The object is missing a required key
+    MissingKey {
+        path: String,
+        nest: Option<&'static str>,
+        key: &'static str,
+    },
+    /// A value expected to be an object is something else
+    NotAnObject {
+        path: String,
+        nest: Option<&'static str>,
+    },
+}
+impl ShapeMismatch {
+    /// Path to the object containing the mismatch, `$` denotes the root value
+    pub fn path(&self) -> &str {
+        match self {
+            Self::UnknownKey { path, .. } | Self::MissingKey { path, .. } | Self::NotAnObject { path, .. } => path,
+        }
+    }
+    /// Nest ID of the object containing the mismatch, `None` for the primary wrapper / origin data
+    pub fn nest(&self) -> Option<&'static str> {
+        match self {
+            Self::UnknownKey { nest, .. } | Self::MissingKey { nest, .. } | Self::NotAnObject { nest, .. } => *nest,
+        }
+    }
+}
+impl Display for ShapeMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownKey { path, key, .. } => write!(f, "unknown key `{key}` at `{path}`"),
+            Self::MissingKey { path, key, .. } => write!(f, "missing key `{key}` at `{path}`"),
+            Self::NotAnObject { path, .. } => write!(f, "expected an object at `{path}`"),
+        }
+    }
+}
+
+/// Compares a JSON value against a shape, returning every mismatch found.
+///
+/// An empty list indicates that the value matches the shape.
+pub fn check(value: &Value, shape: &Shape) -> Vec<ShapeMismatch> {
+    let mut mismatches = Vec::new();
+    check_object(value, shape, "$".to_string(), &mut mismatches);
+    mismatches
+}
+
+fn check_object(value: &Value, shape: &Shape, path: String, mismatches: &mut Vec<ShapeMismatch>) {
+    let Some(object) = value.as_object() else {
+        mismatches.push(ShapeMismatch::NotAnObject { path, nest: shape.nest });
+        return;
+    };
+    for key in shape.keys {
+        match object.get(key.name) {
+            None if key.required => {
+                mismatches.push(ShapeMismatch::MissingKey { path: path.clone(), nest: shape.nest, key: key.name });
+            },
+            None => {},
+            Some(Value::Null) if key.nullable => {},
+            Some(child) => {
+                if let Some(child_shape) = key.shape {
+                    check_object(child, child_shape, format!("{path}.{}", key.name), mismatches);
+                }
+            },
+        }
+    }
+    if !shape.open {
+        for key in object.keys() {
+            if !shape.keys.iter().any(|known| known.name == key) {
+                mismatches.push(ShapeMismatch::UnknownKey { path: path.clone(), nest: shape.nest, key: key.clone() });
+            }
+        }
+    }
+}
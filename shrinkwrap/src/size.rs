@@ -0,0 +1,73 @@
+//! Payload size estimation, enabled via the `json` feature.
+//!
+//! Measures the serialized JSON size of a wrapper without allocating the output, e.g. for services enforcing payload
+//! budgets which drop optional nests before a response size limit is reached. See the `json` flag of the
+//! [`Wrap`](crate::Wrap) derive for the generated `MAX_NEST_COUNT` const, i.e. the number of nests a wrapper may hold.
+//!
+//! ```
+//! use serde::Serialize;
+//! use shrinkwrap::{ToWrappedWith, Transform, TransformToNest, Wrap};
+//!
+//! #[derive(Debug, Clone, Serialize, Wrap)]
+//! #[shrinkwrap(transform = MyTransform, json)]
+//! #[shrinkwrap(nest(id = "text", field_type = String))]
+//! #[shrinkwrap(nest(id = "history", field_type = Vec::<f64>, optional))]
+//! pub struct Account {
+//!     #[shrinkwrap(nest(id = "text"), nest(id = "history"))]
+//!     balance: f64,
+//! }
+//!
+//! struct MyTransform;
+//! impl Transform for MyTransform {
+//!     type Options = ();
+//! }
+//! impl TransformToNest<AccountNestedText> for MyTransform {
+//!     type Data = Account;
+//!
+//!     fn transform_to_nest(&self, data: &Account, _: &()) -> AccountNestedText {
+//!         AccountNestedText { balance: format!("${:.2}", data.balance) }
+//!     }
+//! }
+//! impl TransformToNest<Option<AccountNestedHistory>> for MyTransform {
+//!     type Data = Account;
+//!
+//!     fn transform_to_nest(&self, data: &Account, _: &()) -> Option<AccountNestedHistory> {
+//!         Some(AccountNestedHistory { balance: vec![data.balance; 100] })
+//!     }
+//! }
+//!
+//! const BUDGET: usize = 256;
+//! assert_eq!(AccountWrapper::MAX_NEST_COUNT, 2);
+//!
+//! let mut wrapper = Account { balance: 2.5 }.to_wrapped_with(&MyTransform, &());
+//! if shrinkwrap::size::estimate_size(&wrapper).unwrap() > BUDGET {
+//!     wrapper.extra.history = None;
+//! }
+//! let size = shrinkwrap::size::estimate_size(&wrapper).unwrap();
+//! assert_eq!(size, serde_json::to_vec(&wrapper).unwrap().len());
+//! assert!(size <= BUDGET);
+//! ```
+
+use serde::Serialize;
+use std::io::{self, Write};
+
+use crate::Error;
+
+/// Returns the size (in bytes) of `wrapper` serialized as compact JSON, without buffering the output
+pub fn estimate_size<W: Serialize + ?Sized>(wrapper: &W) -> Result<usize, Error> {
+    let mut counter = ByteCounter(0);
+    serde_json::to_writer(&mut counter, wrapper)?;
+    Ok(counter.0)
+}
+
+/// Sink counting the bytes written to it
+struct ByteCounter(usize);
+impl Write for ByteCounter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0 += buf.len();
+        Ok(buf.len())
+    }
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,149 @@
+//! Stream adapters for lazily wrapping data, enabled via the `stream` feature.
+//!
+//! ```
+//! use futures::{StreamExt, stream};
+//! use shrinkwrap::stream::WrapStreamExt;
+//! # use shrinkwrap::{Transform, TransformToNest, Wrap};
+//! #
+//! # #[derive(Debug, Clone, serde::Serialize, Wrap)]
+//! # #[shrinkwrap(transform = MyTransform)]
+//! # #[shrinkwrap(nest(id = "text", field_type = String))]
+//! # pub struct MyData {
+//! #     #[shrinkwrap(nest(id = "text"))]
+//! #     uptime_sec: i64,
+//! # }
+//! # struct MyTransform;
+//! # impl Transform for MyTransform {
+//! #     type Options = ();
+//! # }
+//! # impl TransformToNest<MyDataNestedText> for MyTransform {
+//! #     type Data = MyData;
+//! #     fn transform_to_nest(&self, data: &MyData, _: &()) -> MyDataNestedText {
+//! #         MyDataNestedText { uptime_sec: data.uptime_sec.to_string() }
+//! #     }
+//! # }
+//!
+//! let transform = MyTransform;
+//! let data = stream::iter([MyData { uptime_sec: 10 }, MyData { uptime_sec: 20 }]);
+//! let wrapped = futures::executor::block_on(data.wrap_with(&transform, &()).collect::<Vec<_>>());
+//!
+//! assert_eq!(wrapped[1].extra.text.uptime_sec, "20");
+//! ```
+
+use futures_core::Stream;
+use futures_util::stream::Buffered;
+use futures_util::StreamExt;
+use pin_project_lite::pin_project;
+use std::future::Future;
+use std::ops::Deref;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use crate::{ToWrappedWith, Transform, TryToWrappedWith};
+
+/// Extends any [`Stream`] of data structs with adapters converting each item into its wrapper.
+///
+/// Items are wrapped lazily, as they are polled. The transform and options may be provided as any
+/// pointer type (e.g. `&T`, `Arc<T>`), allowing for `'static` streams when required (e.g. response bodies).
+pub trait WrapStreamExt: Stream + Sized {
+    /// Wraps each item via [`ToWrappedWith`].
+    fn wrap_with<R, Q>(self, transform: R, options: Q) -> WrapWith<Self, R, Q>
+    where
+        R: Deref,
+        R::Target: Transform + Sized,
+        Q: Deref<Target = <R::Target as Transform>::Options>,
+        Self::Item: ToWrappedWith<R::Target>,
+    {
+        WrapWith { stream: self, transform, options }
+    }
+
+    /// Wraps each item via [`TryToWrappedWith`], yielding a `Result` per item.
+    fn try_wrap_with<R, Q>(self, transform: R, options: Q) -> TryWrapWith<Self, R, Q>
+    where
+        R: Deref,
+        R::Target: Transform + Sized,
+        Q: Deref<Target = <R::Target as Transform>::Options>,
+        Self::Item: TryToWrappedWith<R::Target>,
+    {
+        TryWrapWith { stream: self, transform, options }
+    }
+
+    /// Resolves up to `limit` item futures concurrently (in order), wrapping each resolved item via [`ToWrappedWith`].
+    ///
+    /// Used for streams where each data struct is loaded asynchronously.
+    fn buffered_wrap_with<R, Q>(self, limit: usize, transform: R, options: Q) -> WrapWith<Buffered<Self>, R, Q>
+    where
+        Self::Item: Future,
+        R: Deref,
+        R::Target: Transform + Sized,
+        Q: Deref<Target = <R::Target as Transform>::Options>,
+        <Self::Item as Future>::Output: ToWrappedWith<R::Target>,
+    {
+        WrapWith { stream: self.buffered(limit), transform, options }
+    }
+}
+impl<S: Stream> WrapStreamExt for S {}
+
+pin_project! {
+    /// Stream returned by [`WrapStreamExt::wrap_with`] and [`WrapStreamExt::buffered_wrap_with`].
+    #[must_use = "streams do nothing unless polled"]
+    pub struct WrapWith<S, R, Q> {
+        #[pin]
+        stream: S,
+        transform: R,
+        options: Q,
+    }
+}
+impl<S, R, Q> Stream for WrapWith<S, R, Q>
+where
+    S: Stream,
+    R: Deref,
+    R::Target: Transform + Sized,
+    Q: Deref<Target = <R::Target as Transform>::Options>,
+    S::Item: ToWrappedWith<R::Target>,
+{
+    type Item = <S::Item as ToWrappedWith<R::Target>>::Wrapper;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        this.stream
+            .poll_next(cx)
+            .map(|item| item.map(|data| data.to_wrapped_with(this.transform, this.options)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+pin_project! {
+    /// Stream returned by [`WrapStreamExt::try_wrap_with`].
+    #[must_use = "streams do nothing unless polled"]
+    pub struct TryWrapWith<S, R, Q> {
+        #[pin]
+        stream: S,
+        transform: R,
+        options: Q,
+    }
+}
+impl<S, R, Q> Stream for TryWrapWith<S, R, Q>
+where
+    S: Stream,
+    R: Deref,
+    R::Target: Transform + Sized,
+    Q: Deref<Target = <R::Target as Transform>::Options>,
+    S::Item: TryToWrappedWith<R::Target>,
+{
+    type Item = Result<<S::Item as TryToWrappedWith<R::Target>>::Wrapper, <S::Item as TryToWrappedWith<R::Target>>::Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        this.stream
+            .poll_next(cx)
+            .map(|item| item.map(|data| data.try_to_wrapped_with(this.transform, this.options)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
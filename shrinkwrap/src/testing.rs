@@ -0,0 +1,76 @@
+//! Assertion helpers for wrapper tests, enabled via the `diff` feature.
+//!
+//! [`assert_wrapped_eq!`](crate::assert_wrapped_eq) and [`assert_nest_eq!`](crate::assert_nest_eq) compare their
+//! operands via their JSON encoding, so a wrapper (or nest) can be compared against another one or against a
+//! `serde_json::json!` literal. On failure, the panic message lists each differing value (see
+//! [`diff`](crate::diff)) rather than dumping both structs, e.g.
+//!
+//! ```text
+//! assertion `left == right` failed: wrappers differ
+//! ~ $.extra.text.uptime_sec (nest `text`): "10 sec" -> "20 sec"
+//! ```
+//!
+//! Values present only in `left` are prefixed with `-`, values present only in `right` with `+`.
+//!
+//! ```
+//! use serde::Serialize;
+//! use serde_json::json;
+//! use shrinkwrap::{ToWrappedWith, Transform, TransformToNest, Wrap, assert_nest_eq, assert_wrapped_eq};
+//!
+//! #[derive(Debug, Clone, Serialize, Wrap)]
+//! #[shrinkwrap(transform = MyTransform)]
+//! #[shrinkwrap(nest(id = "text", field_type = String))]
+//! pub struct MyData {
+//!     #[shrinkwrap(nest(id = "text"))]
+//!     uptime_sec: i64,
+//! }
+//! # struct MyTransform;
+//! # impl Transform for MyTransform {
+//! #     type Options = ();
+//! # }
+//! # impl TransformToNest<MyDataNestedText> for MyTransform {
+//! #     type Data = MyData;
+//! #     fn transform_to_nest(&self, data: &MyData, _: &()) -> MyDataNestedText {
+//! #         MyDataNestedText { uptime_sec: format!("{} sec", data.uptime_sec) }
+//! #     }
+//! # }
+//!
+//! let wrapper = MyData { uptime_sec: 10 }.to_wrapped_with_t(&MyTransform);
+//! assert_wrapped_eq!(wrapper, json!({ "extra": { "text": { "uptime_sec": "10 sec" } }, "uptime_sec": 10 }));
+//! assert_nest_eq!(wrapper.extra.text, json!({ "uptime_sec": "10 sec" }), "text nest of uptime {}", 10);
+//!
+//! let other = MyData { uptime_sec: 20 }.to_wrapped_with_t(&MyTransform);
+//! let panic = std::panic::catch_unwind(|| assert_wrapped_eq!(wrapper, other)).unwrap_err();
+//! assert_eq!(panic.downcast_ref::<String>().unwrap(), "\
+//! assertion `left == right` failed: wrappers differ
+//! ~ $.extra.text.uptime_sec (nest `text`): \"10 sec\" -> \"20 sec\"
+//! ~ $.uptime_sec: 10 -> 20
+//! ");
+//! ```
+
+/// Asserts that two wrappers (or collections of wrappers) have the same JSON encoding, see the
+/// [`testing`](crate::testing) module
+///
+/// Either operand may be a `serde_json::Value`. Accepts an optional format message, as with `assert_eq!`.
+#[macro_export]
+macro_rules! assert_wrapped_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::__private::assert_json_eq(&$left, &$right, "wrappers differ", ::core::option::Option::None)
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {
+        $crate::__private::assert_json_eq(&$left, &$right, "wrappers differ", ::core::option::Option::Some(::core::format_args!($($arg)+)))
+    };
+}
+
+/// Asserts that two nests have the same JSON encoding, see the [`testing`](crate::testing) module
+///
+/// Either operand may be a `serde_json::Value`. Accepts an optional format message, as with `assert_eq!`.
+#[macro_export]
+macro_rules! assert_nest_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::__private::assert_json_eq(&$left, &$right, "nests differ", ::core::option::Option::None)
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {
+        $crate::__private::assert_json_eq(&$left, &$right, "nests differ", ::core::option::Option::Some(::core::format_args!($($arg)+)))
+    };
+}
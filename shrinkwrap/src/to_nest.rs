@@ -1,4 +1,4 @@
-use crate::transform::Transform;
+use crate::transform::{Transform, Transform2};
 
 /// Primary entrypoint for data -> nest conversions.
 ///
@@ -19,7 +19,7 @@ use crate::transform::Transform;
 /// # #[shrinkwrap(transform = MyTransform)]
 /// # #[shrinkwrap(nest(id = "text", field_type = String))]
 /// # pub struct MyData {
-/// #     #[shrinkwrap(nests("text"))]
+/// #     #[shrinkwrap(nest(id = "text"))]
 /// #     uptime_sec: i64,
 /// # }
 /// #
@@ -59,7 +59,7 @@ use crate::transform::Transform;
 /// # #[shrinkwrap(transform = MyTransform)]
 /// # #[shrinkwrap(nest(id = "text", field_type = String, optional))]
 /// # pub struct MyData {
-/// #     #[shrinkwrap(nests("text"))]
+/// #     #[shrinkwrap(nest(id = "text"))]
 /// #     uptime_sec: i64,
 /// # }
 /// use shrinkwrap::TransformToNest;
@@ -89,10 +89,11 @@ use crate::transform::Transform;
 /// #
 /// # #[derive(Debug, Clone, serde::Serialize, Wrap)]
 /// # #[shrinkwrap(transform = MyTransform)]
-/// # #[shrinkwrap(nest(id = "text", field_type = String))]
-/// # pub struct MyData {
-/// #     #[shrinkwrap(nests("text"))]
-/// #     uptime_sec: i64,
+/// # #[shrinkwrap(nest(id = "usd_value", field_type = f64))]
+/// # #[shrinkwrap(nest(id = "text", field_type = String, chain_from = "usd_value"))]
+/// # pub struct TestData {
+/// #     #[shrinkwrap(nest(id = "usd_value"), nest(id = "text"))]
+/// #     amount: f64,
 /// # }
 /// #
 /// # struct MyTransform {}
@@ -100,6 +101,12 @@ use crate::transform::Transform;
 /// # impl Transform for MyTransform {
 /// #     type Options = MyTransformOpts;
 /// # }
+/// # impl shrinkwrap::TransformToNest<TestDataNestedUsdValue> for MyTransform {
+/// #     type Data = TestData;
+/// #     fn transform_to_nest(&self, data: &TestData, _: &MyTransformOpts) -> TestDataNestedUsdValue {
+/// #         TestDataNestedUsdValue { amount: data.amount }
+/// #     }
+/// # }
 /// use shrinkwrap::TransformToNest;
 ///
 /// impl TransformToNest<TestDataNestedUsdValueText> for MyTransform {
@@ -122,23 +129,33 @@ use crate::transform::Transform;
 /// #
 /// # #[derive(Debug, Clone, serde::Serialize, Wrap)]
 /// # #[shrinkwrap(transform = MyTransform)]
-/// # #[shrinkwrap(nest(id = "text", field_type = String))]
-/// # pub struct MyData {
-/// #     #[shrinkwrap(nests("text"))]
-/// #     uptime_sec: i64,
+/// # #[shrinkwrap(nest(id = "usd_value", field_type = f64, optional))]
+/// # #[shrinkwrap(nest(id = "text", field_type = String, chain_from = "usd_value", optional))]
+/// # pub struct TestData {
+/// #     #[shrinkwrap(nest(id = "usd_value"), nest(id = "text"))]
+/// #     amount: f64,
 /// # }
 /// #
 /// # struct MyTransform {}
-/// # type MyTransformOpts = ();
+/// # struct MyTransformOpts {
+/// #     with_usd_value: bool,
+/// #     with_text: bool,
+/// # }
 /// # impl Transform for MyTransform {
 /// #     type Options = MyTransformOpts;
 /// # }
+/// # impl shrinkwrap::TransformToNest<Option<TestDataNestedUsdValue>> for MyTransform {
+/// #     type Data = TestData;
+/// #     fn transform_to_nest(&self, data: &TestData, options: &MyTransformOpts) -> Option<TestDataNestedUsdValue> {
+/// #         options.with_usd_value.then(|| TestDataNestedUsdValue { amount: data.amount })
+/// #     }
+/// # }
 /// use shrinkwrap::TransformToNest;
 ///
 /// impl TransformToNest<Option<TestDataNestedUsdValueText>> for MyTransform {
 ///     type Data = TestDataNestedUsdValue;
 ///
-///     fn transform_to_nest(&self, data: &TestDataNestedUsdValue, _: &MyTransformOpts) -> Option<TestDataNestedUsdValueText> {
+///     fn transform_to_nest(&self, data: &TestDataNestedUsdValue, options: &MyTransformOpts) -> Option<TestDataNestedUsdValueText> {
 ///         options.with_text.then(||
 ///             TestDataNestedUsdValueText {
 ///                 amount: format!("${:.2} USD", data.amount),
@@ -160,6 +177,22 @@ pub trait TransformToNest<N>: Transform {
     fn transform_to_nest(&self, data: &Self::Data, options: &Self::Options) -> N;
 }
 
+/// Variant of [`TransformToNest`] for [`Transform2`] transforms, whose options may borrow.
+///
+/// Implemented for every [`TransformToNest`], implement it directly only for transforms implementing [`Transform2`]
+/// (rather than [`Transform`]). The `Wrap` generated code is bound on this trait.
+pub trait TransformToNest2<N>: Transform2 {
+    type Data;
+    fn transform_to_nest(&self, data: &Self::Data, options: &Self::Options<'_>) -> N;
+}
+
+impl<N, T: TransformToNest<N> + ?Sized> TransformToNest2<N> for T {
+    type Data = T::Data;
+    fn transform_to_nest(&self, data: &Self::Data, options: &Self::Options<'_>) -> N {
+        TransformToNest::transform_to_nest(self, data, options)
+    }
+}
+
 /// Allows for converting a data struct (by reference) to a supported nest type.
 ///
 /// This is implemented automatically when [`TransformToNest`] is implemented on the corresponding types.
@@ -174,7 +207,7 @@ pub trait TransformToNest<N>: Transform {
 /// #[shrinkwrap(transform = MyTransform)]
 /// #[shrinkwrap(nest(id = "text", field_type = String))]
 /// pub struct MyData {
-///     #[shrinkwrap(nests("text"))]
+///     #[shrinkwrap(nest(id = "text"))]
 ///     uptime_sec: i64,
 /// }
 ///
@@ -203,19 +236,76 @@ pub trait TransformToNest<N>: Transform {
 /// let uptime_text = text_variants.uptime_sec;
 /// println!("Current uptime: {uptime_text}")
 /// ```
-pub trait ToNestWith<N, T: Transform>: Sized
+pub trait ToNestWith<N, T: Transform2>: Sized
 where
-    T: TransformToNest<N, Data = Self>,
+    T: TransformToNest2<N, Data = Self>,
 {
-    fn to_nest_with(&self, transform: &T, options: &T::Options) -> N;
+    fn to_nest_with(&self, transform: &T, options: &T::Options<'_>) -> N;
 }
 
 /// Blanket implementation providing `to_nest_with(transform)` for data structs that have a corresponding [`TransformToNest<Nest>`] impl.
 impl<D, N, T> ToNestWith<N, T> for D
 where
-    T: TransformToNest<N, Data = D>,
+    T: TransformToNest2<N, Data = D>,
 {
-    fn to_nest_with(&self, transform: &T, options: &T::Options) -> N {
+    fn to_nest_with(&self, transform: &T, options: &T::Options<'_>) -> N {
         transform.transform_to_nest(self, options)
     }
 }
+
+/// Allows for converting a data struct (by reference) to a nest without a transform.
+///
+/// Implemented for any nest providing `From<&Data>`, e.g. the impl generated for `identity` nests (whose fields keep
+/// the types of their source fields). Identity nests additionally receive a [`TransformToNest`] impl delegating to it,
+/// `to_nest()` and [`to_nest_with`](ToNestWith::to_nest_with) therefore always produce the same nest.
+///
+/// ## Examples
+///
+/// ```rust
+/// use serde::Serialize;
+/// use shrinkwrap::{ToNest, ToNestWith, Transform, TransformToNest, Wrap};
+///
+/// #[derive(Debug, Clone, Serialize, Wrap)]
+/// #[shrinkwrap(transform = MyTransform)]
+/// #[shrinkwrap(nest(id = "text", field_type = String))]
+/// #[shrinkwrap(nest(id = "summary", identity))]
+/// pub struct MyData {
+///     #[shrinkwrap(nest(id = "text"), nest(id = "summary"))]
+///     uptime_sec: i64,
+///     #[shrinkwrap(nest(id = "summary"))]
+///     hostname: String,
+///     build: u32,
+/// }
+///
+/// struct MyTransform;
+/// impl Transform for MyTransform {
+///     type Options = ();
+/// }
+/// # impl TransformToNest<MyDataNestedText> for MyTransform {
+/// #     type Data = MyData;
+/// #     fn transform_to_nest(&self, data: &MyData, _: &()) -> MyDataNestedText {
+/// #         MyDataNestedText { uptime_sec: data.uptime_sec.to_string() }
+/// #     }
+/// # }
+///
+/// let data = MyData { uptime_sec: 10, hostname: "web-1".into(), build: 7 };
+///
+/// let summary: MyDataNestedSummary = data.to_nest();
+/// assert_eq!((summary.uptime_sec, summary.hostname.as_str()), (10, "web-1"));
+///
+/// let summary: MyDataNestedSummary = data.to_nest_with(&MyTransform, &());
+/// assert_eq!(summary.uptime_sec, 10);
+/// ```
+pub trait ToNest<N> {
+    fn to_nest(&self) -> N;
+}
+
+/// Blanket implementation providing `to_nest()` for data structs whose nest implements `From<&Data>`.
+impl<D, N> ToNest<N> for D
+where
+    N: for<'a> From<&'a D>,
+{
+    fn to_nest(&self) -> N {
+        N::from(self)
+    }
+}
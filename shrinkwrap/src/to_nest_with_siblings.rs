@@ -0,0 +1,65 @@
+use crate::transform::{Transform, Transform2};
+
+/// Data -> nest conversion for nests which depend on the computed value of a sibling nest.
+///
+/// Implement this (instead of [`TransformToNest`](crate::TransformToNest)) for any nest declaring `needs = "{sibling_id}"`.
+///
+/// The sibling is always computed first, `S` is the sibling's field type within the extra struct (e.g. `Option<MyDataNestedText>` for optional siblings).
+///
+/// # Examples
+///
+/// ```
+/// # use shrinkwrap::{Transform, TransformToNest, Wrap};
+/// #
+/// # #[derive(Debug, Clone, serde::Serialize, Wrap)]
+/// # #[shrinkwrap(transform = MyTransform)]
+/// # #[shrinkwrap(nest(id = "text", field_type = String))]
+/// # #[shrinkwrap(nest(id = "shout", field_type = String, needs = "text"))]
+/// # pub struct MyData {
+/// #     #[shrinkwrap(nest(id = "text"), nest(id = "shout"))]
+/// #     uptime_sec: i64,
+/// # }
+/// #
+/// # struct MyTransform {}
+/// # type MyTransformOpts = ();
+/// # impl Transform for MyTransform {
+/// #     type Options = MyTransformOpts;
+/// # }
+/// # impl TransformToNest<MyDataNestedText> for MyTransform {
+/// #     type Data = MyData;
+/// #     fn transform_to_nest(&self, data: &MyData, _: &MyTransformOpts) -> MyDataNestedText {
+/// #         MyDataNestedText { uptime_sec: data.uptime_sec.to_string() }
+/// #     }
+/// # }
+/// use shrinkwrap::TransformToNestWithSiblings;
+///
+/// impl TransformToNestWithSiblings<MyDataNestedShout, MyDataNestedText> for MyTransform {
+///     type Data = MyData;
+///
+///     fn transform_to_nest_with_siblings(&self, _: &MyData, text: &MyDataNestedText, _: &MyTransformOpts) -> MyDataNestedShout {
+///         MyDataNestedShout {
+///             uptime_sec: text.uptime_sec.to_uppercase(),
+///         }
+///     }
+/// }
+/// ```
+pub trait TransformToNestWithSiblings<N, S>: Transform {
+    type Data;
+
+    fn transform_to_nest_with_siblings(&self, data: &Self::Data, sibling: &S, options: &Self::Options) -> N;
+}
+
+/// Variant of [`TransformToNestWithSiblings`] for [`Transform2`] transforms, see [`TransformToNest2`](crate::TransformToNest2)
+pub trait TransformToNestWithSiblings2<N, S>: Transform2 {
+    type Data;
+
+    fn transform_to_nest_with_siblings(&self, data: &Self::Data, sibling: &S, options: &Self::Options<'_>) -> N;
+}
+
+impl<N, S, T: TransformToNestWithSiblings<N, S> + ?Sized> TransformToNestWithSiblings2<N, S> for T {
+    type Data = T::Data;
+
+    fn transform_to_nest_with_siblings(&self, data: &Self::Data, sibling: &S, options: &Self::Options<'_>) -> N {
+        TransformToNestWithSiblings::transform_to_nest_with_siblings(self, data, sibling, options)
+    }
+}
@@ -0,0 +1,71 @@
+//! Nest topology, enabled via the `topology` feature.
+//!
+//! See the `topology` option of the [`Wrap`](crate::Wrap) derive, which generates a `WRAP_TOPOLOGY` constant on the
+//! data struct describing each of its nests. Allows documentation tooling to render the nest hierarchy of a response
+//! type without parsing source.
+//!
+//! ```
+//! use serde::Serialize;
+//! use shrinkwrap::{Transform, TransformToNest, Wrap};
+//! use shrinkwrap::topology;
+//!
+//! #[derive(Debug, Clone, Serialize, Wrap)]
+//! #[shrinkwrap(transform = MyTransform, topology)]
+//! #[shrinkwrap(nest(id = "value", field_type = f64))]
+//! #[shrinkwrap(nest(id = "value_text", field_name = "text", field_type = String, chain_from = "value", optional))]
+//! pub struct MyData {
+//!     #[shrinkwrap(nest(id = "value"), nest(id = "value_text"))]
+//!     balance: i64,
+//! }
+//! # struct MyTransform;
+//! # impl Transform for MyTransform {
+//! #     type Options = ();
+//! # }
+//! # impl TransformToNest<MyDataNestedValue> for MyTransform {
+//! #     type Data = MyData;
+//! #     fn transform_to_nest(&self, data: &MyData, _: &()) -> MyDataNestedValue {
+//! #         MyDataNestedValue { balance: data.balance as f64 }
+//! #     }
+//! # }
+//! # impl TransformToNest<Option<MyDataNestedValueText>> for MyTransform {
+//! #     type Data = MyDataNestedValue;
+//! #     fn transform_to_nest(&self, data: &MyDataNestedValue, _: &()) -> Option<MyDataNestedValueText> {
+//! #         Some(MyDataNestedValueText { balance: data.balance.to_string() })
+//! #     }
+//! # }
+//!
+//! let [value, text] = MyData::WRAP_TOPOLOGY else { panic!() };
+//! assert_eq!((value.id, value.parent, value.struct_name), ("value", None, "MyDataNestedValue"));
+//! assert_eq!(value.wrapper_name, Some("MyDataNestedValueWrapper"));
+//! assert_eq!((text.id, text.parent, text.field_name, text.optional), ("value_text", Some("value"), "text", true));
+//!
+//! let roots = topology::children(MyData::WRAP_TOPOLOGY, None).map(|node| node.id).collect::<Vec<_>>();
+//! assert_eq!(roots, ["value"]);
+//! ```
+
+/// A single nest within the topology of a data struct
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NestNode {
+    /// Nest ID
+    pub id: &'static str,
+
+    /// ID of the nest this nest is chained from, `None` for root nests
+    pub parent: Option<&'static str>,
+
+    /// Name of the nest's field within its parent's `extra` struct
+    pub field_name: &'static str,
+
+    /// Whether the nest's field is optional
+    pub optional: bool,
+
+    /// Name of the generated nest struct (or provider type alias)
+    pub struct_name: &'static str,
+
+    /// Name of the injected wrapper, for nests which have nests chained from them
+    pub wrapper_name: Option<&'static str>,
+}
+
+/// Returns the nests chained directly from `parent`, or the root nests if `parent` is `None`
+pub fn children<'a>(nodes: &'a [NestNode], parent: Option<&'a str>) -> impl Iterator<Item = &'a NestNode> + 'a {
+    nodes.iter().filter(move |node| node.parent == parent)
+}
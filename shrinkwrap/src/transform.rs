@@ -2,6 +2,8 @@
 ///
 /// Additionally provides the type for the options parameter used in [`TransformToNest`](crate::TransformToNest) and [`ToNestWith`](crate::ToNestWith)
 ///
+/// Options borrowing request-scoped data (e.g. a `&str` locale) require implementing [`Transform2`] instead.
+///
 /// ## Example impl
 ///
 /// ```
@@ -21,3 +23,144 @@
 pub trait Transform {
     type Options;
 }
+
+/// Marker trait for a transform impl whose options may borrow, e.g. `&str` locales or `&User` principals
+///
+/// Implemented for every [`Transform`], `Options<'a>` being `Transform::Options` regardless of the lifetime. The
+/// `Wrap` generated code is bound on this trait, threading the lifetime through to each nest.
+///
+/// Nests of a `Transform2` transform are built via the `2` variants of the nest traits, e.g.
+/// [`TransformToNest2`](crate::TransformToNest2), which are implemented for every [`Transform`] based impl.
+///
+/// ## Example impl
+///
+/// ```
+/// use serde::Serialize;
+/// use shrinkwrap::{ToWrappedWith, Transform2, TransformToNest2, Wrap};
+///
+/// #[derive(Debug, Clone, Serialize, Wrap)]
+/// #[shrinkwrap(transform = MyTransform)]
+/// #[shrinkwrap(nest(id = "text", field_type = String))]
+/// pub struct MyData {
+///     #[shrinkwrap(nest(id = "text"))]
+///     uptime_sec: i64,
+/// }
+///
+/// struct User {
+///     name: String,
+/// }
+/// struct MyTransformOpts<'a> {
+///     locale: &'a str,
+///     user: &'a User,
+/// }
+///
+/// struct MyTransform;
+/// impl Transform2 for MyTransform {
+///     type Options<'a> = MyTransformOpts<'a>;
+/// }
+/// impl TransformToNest2<MyDataNestedText> for MyTransform {
+///     type Data = MyData;
+///     fn transform_to_nest(&self, data: &MyData, options: &MyTransformOpts<'_>) -> MyDataNestedText {
+///         MyDataNestedText { uptime_sec: format!("{}s ({}, {})", data.uptime_sec, options.locale, options.user.name) }
+///     }
+/// }
+///
+/// let locale = String::from("de-DE");
+/// let user = User { name: "ada".to_string() };
+/// let options = MyTransformOpts { locale: &locale, user: &user };
+/// let wrapped = MyData { uptime_sec: 10 }.to_wrapped_with(&MyTransform, &options);
+/// assert_eq!(wrapped.extra.text.uptime_sec, "10s (de-DE, ada)");
+/// ```
+pub trait Transform2 {
+    type Options<'a>;
+}
+impl<T: Transform + ?Sized> Transform2 for T {
+    type Options<'a> = T::Options;
+}
+
+/// Marker for transforms without options (`type Options = ()`), automatically implemented.
+///
+/// Enables the option-less convenience methods, e.g. [`to_wrapped_with_t`](crate::ToWrappedWith::to_wrapped_with_t).
+///
+/// ```
+/// use shrinkwrap::{NoOptions, Transform};
+///
+/// struct MyTransform;
+/// impl Transform for MyTransform {
+///     type Options = ();
+/// }
+///
+/// fn assert_no_options<T: NoOptions>() {}
+/// assert_no_options::<MyTransform>();
+/// ```
+pub trait NoOptions: for<'a> Transform2<Options<'a> = ()> {}
+impl<T: ?Sized + for<'a> Transform2<Options<'a> = ()>> NoOptions for T {}
+
+/// Transform whose options are validated before wrapping, via
+/// [`to_wrapped_with_validated`](crate::ToWrappedWith::to_wrapped_with_validated) (or
+/// [`try_to_wrapped_with_validated`](crate::TryToWrappedWith::try_to_wrapped_with_validated)).
+///
+/// Invalid option combinations then fail fast with a domain error, rather than producing partially populated
+/// wrappers.
+///
+/// ```
+/// use serde::Serialize;
+/// use shrinkwrap::{ToWrappedWith, Transform, TransformToNest, ValidatedTransform, Wrap};
+///
+/// #[derive(Debug, Clone, Serialize, Wrap)]
+/// #[shrinkwrap(transform = MyTransform)]
+/// #[shrinkwrap(nest(id = "html", field_type = String, optional))]
+/// pub struct MyData {
+///     #[shrinkwrap(nest(id = "html"))]
+///     uptime_sec: i64,
+/// }
+///
+/// struct MyTransformOpts {
+///     with_html: bool,
+///     locale: Option<String>,
+/// }
+///
+/// #[derive(Debug, PartialEq)]
+/// enum OptionsError {
+///     MissingLocale,
+/// }
+///
+/// struct MyTransform;
+/// impl Transform for MyTransform {
+///     type Options = MyTransformOpts;
+/// }
+/// impl ValidatedTransform for MyTransform {
+///     type OptionsError = OptionsError;
+///
+///     fn validate_options(&self, options: &MyTransformOpts) -> Result<(), OptionsError> {
+///         match options.with_html && options.locale.is_none() {
+///             true => Err(OptionsError::MissingLocale),
+///             false => Ok(()),
+///         }
+///     }
+/// }
+/// impl TransformToNest<Option<MyDataNestedHtml>> for MyTransform {
+///     type Data = MyData;
+///     fn transform_to_nest(&self, data: &MyData, options: &MyTransformOpts) -> Option<MyDataNestedHtml> {
+///         let locale = options.locale.as_deref()?;
+///         options.with_html.then(|| MyDataNestedHtml { uptime_sec: format!("<b>{}s</b> ({locale})", data.uptime_sec) })
+///     }
+/// }
+///
+/// let options = MyTransformOpts { with_html: true, locale: None };
+/// let wrapped = MyData { uptime_sec: 10 }.to_wrapped_with_validated(&MyTransform, &options);
+/// assert_eq!(wrapped.unwrap_err(), OptionsError::MissingLocale);
+///
+/// let options = MyTransformOpts { with_html: true, locale: Some("en".into()) };
+/// let wrapped = MyData { uptime_sec: 10 }.to_wrapped_with_validated(&MyTransform, &options).unwrap();
+/// assert_eq!(wrapped.extra.html.unwrap().uptime_sec, "<b>10s</b> (en)");
+/// ```
+pub trait ValidatedTransform: Transform2 {
+    type OptionsError;
+
+    /// Validates `options` before any nest is built, all options are valid by default
+    fn validate_options(&self, options: &Self::Options<'_>) -> Result<(), Self::OptionsError> {
+        let _ = options;
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+/// Nest gates of a transform's options, derived via [`#[derive(TransformOptions)]`](macro@crate::TransformOptions).
+///
+/// Each `bool` field named `with_{nest_id}` gates the nest with that ID (matching the default `options_field` of
+/// `derive_to_nest`), other fields can be mapped via `#[shrinkwrap(nest = "..")]`. The derive also implements
+/// [`DeprecatedNestGate`](crate::DeprecatedNestGate), gating nests superseded via `gate_deprecated` by the same fields.
+///
+/// ```
+/// use serde::Serialize;
+/// use shrinkwrap::{ToWrappedWith, Transform, TransformOptions, TransformToNest, Wrap};
+///
+/// #[derive(Debug, Clone, Serialize, Wrap)]
+/// #[shrinkwrap(transform = MyTransform)]
+/// #[shrinkwrap(nest(id = "text", field_type = String, optional))]
+/// #[shrinkwrap(nest(id = "text_v2", field_type = String, deprecates = "text", gate_deprecated))]
+/// pub struct MyData {
+///     #[shrinkwrap(nest(id = "text"), nest(id = "text_v2"))]
+///     uptime_sec: i64,
+/// }
+///
+/// #[derive(TransformOptions)]
+/// struct MyTransformOpts {
+///     with_text: bool,
+///     #[shrinkwrap(nest = "text_v2")]
+///     modern_text: bool,
+///     locale: String,
+/// }
+///
+/// struct MyTransform;
+/// impl Transform for MyTransform {
+///     type Options = MyTransformOpts;
+/// }
+/// impl TransformToNest<Option<MyDataNestedText>> for MyTransform {
+///     type Data = MyData;
+///     fn transform_to_nest(&self, data: &MyData, _: &MyTransformOpts) -> Option<MyDataNestedText> {
+///         Some(MyDataNestedText { uptime_sec: format!("{}s", data.uptime_sec) })
+///     }
+/// }
+/// impl TransformToNest<MyDataNestedTextV2> for MyTransform {
+///     type Data = MyData;
+///     fn transform_to_nest(&self, data: &MyData, _: &MyTransformOpts) -> MyDataNestedTextV2 {
+///         MyDataNestedTextV2 { uptime_sec: format!("{} seconds", data.uptime_sec) }
+///     }
+/// }
+///
+/// assert_eq!(MyTransformOpts::GATED_NESTS, &["text", "text_v2"]);
+///
+/// let options = MyTransformOpts { with_text: false, modern_text: true, locale: "en".into() };
+/// assert!(!options.enabled("text"));
+/// assert!(options.enabled("text_v2"));
+/// assert!(options.enabled("ungated"));
+///
+/// let wrapped = MyData { uptime_sec: 10 }.to_wrapped_with(&MyTransform, &options);
+/// assert!(wrapped.extra.text.is_none());
+/// ```
+pub trait TransformOptions {
+    /// IDs of the nests gated by the options, in field order
+    const GATED_NESTS: &'static [&'static str];
+
+    /// Whether the nest with ID `nest` is enabled, always true for nests which aren't gated
+    fn enabled(&self, nest: &str) -> bool;
+}
@@ -0,0 +1,73 @@
+use crate::transform::{NoOptions, Transform, Transform2};
+use crate::try_wrap::TryToWrappedWith;
+
+/// Fallible variant of [`BatchTransformToNest`](crate::BatchTransformToNest), for nests declaring `batch`.
+///
+/// A single error fails the entire batch.
+pub trait TryBatchTransformToNest<N>: Transform {
+    type Data;
+    type Error;
+
+    /// Builds a nest for each item of `data`, must return exactly one nest per item, in the same order
+    fn try_transform_many(&self, data: &[Self::Data], options: &Self::Options) -> Result<Vec<N>, Self::Error>;
+}
+
+/// Fallible variant of [`BatchTransformToNest2`](crate::BatchTransformToNest2).
+pub trait TryBatchTransformToNest2<N>: Transform2 {
+    type Data;
+    type Error;
+
+    /// Builds a nest for each item of `data`, must return exactly one nest per item, in the same order
+    fn try_transform_many(&self, data: &[Self::Data], options: &Self::Options<'_>) -> Result<Vec<N>, Self::Error>;
+}
+
+impl<N, T: TryBatchTransformToNest<N> + ?Sized> TryBatchTransformToNest2<N> for T {
+    type Data = T::Data;
+    type Error = T::Error;
+
+    fn try_transform_many(&self, data: &[Self::Data], options: &Self::Options<'_>) -> Result<Vec<N>, Self::Error> {
+        TryBatchTransformToNest::try_transform_many(self, data, options)
+    }
+}
+
+/// Fallible variant of [`WrapAllWith`](crate::WrapAllWith).
+pub trait TryWrapAllWith<T>: TryToWrappedWith<T>
+where
+    T: Transform2,
+{
+    fn try_wrap_all_with(data: Vec<Self>, transform: &T, options: &T::Options<'_>) -> Result<Vec<Self::Wrapper>, Self::Error>;
+}
+
+/// Fallible variant of [`ToWrappedAllWith`](crate::ToWrappedAllWith).
+///
+/// Automatically implemented for `Vec`s of data structs providing [`TryWrapAllWith`].
+pub trait TryToWrappedAllWith<T>
+where
+    T: Transform2,
+{
+    type Wrapper;
+    type Error;
+
+    fn try_to_wrapped_all_with(self, transform: &T, options: &T::Options<'_>) -> Result<Vec<Self::Wrapper>, Self::Error>;
+
+    /// Shorthand for [`try_to_wrapped_all_with`](Self::try_to_wrapped_all_with), for transforms without options
+    fn try_to_wrapped_all_with_t(self, transform: &T) -> Result<Vec<Self::Wrapper>, Self::Error>
+    where
+        Self: Sized,
+        T: NoOptions,
+    {
+        self.try_to_wrapped_all_with(transform, &())
+    }
+}
+impl<D, T> TryToWrappedAllWith<T> for Vec<D>
+where
+    T: Transform2,
+    D: TryWrapAllWith<T>,
+{
+    type Wrapper = D::Wrapper;
+    type Error = D::Error;
+
+    fn try_to_wrapped_all_with(self, transform: &T, options: &T::Options<'_>) -> Result<Vec<D::Wrapper>, D::Error> {
+        D::try_wrap_all_with(self, transform, options)
+    }
+}
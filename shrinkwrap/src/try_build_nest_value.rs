@@ -1,6 +1,6 @@
 use crate::{
     nest::NestValueType,
-    transform::Transform,
+    transform::{Transform, Transform2},
 };
 
 /// Fallible version of [`BuildNestValue`](crate::BuildNestValue)
@@ -14,3 +14,26 @@ where
 
     fn try_build_nest_value(&self, source: &T, options: &Self::Options) -> Result<V, Self::Error>;
 }
+
+/// Fallible version of [`BuildNestValue2`](crate::BuildNestValue2)
+///
+/// See [`BuildNestValue2`](crate::BuildNestValue2) for more information
+pub trait TryBuildNestValue2<T, V>: Transform2
+where
+    V: NestValueType
+{
+    type Error;
+
+    fn try_build_nest_value(&self, source: &T, options: &Self::Options<'_>) -> Result<V, Self::Error>;
+}
+
+impl<T, V, X: TryBuildNestValue<T, V> + ?Sized> TryBuildNestValue2<T, V> for X
+where
+    V: NestValueType
+{
+    type Error = X::Error;
+
+    fn try_build_nest_value(&self, source: &T, options: &Self::Options<'_>) -> Result<V, Self::Error> {
+        TryBuildNestValue::try_build_nest_value(self, source, options)
+    }
+}
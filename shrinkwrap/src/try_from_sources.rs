@@ -0,0 +1,29 @@
+use std::fmt::Debug;
+
+use crate::transform::{Transform, Transform2};
+
+/// Fallible version of [`TransformFromSources`](crate::TransformFromSources)
+///
+/// See [`TransformFromSources`](crate::TransformFromSources) for more information
+pub trait TryTransformFromSources<S, N>: Transform {
+    type Error: Debug;
+
+    fn try_transform_from_sources(&self, sources: S, options: &Self::Options) -> Result<N, Self::Error>;
+}
+
+/// Fallible version of [`TransformFromSources2`](crate::TransformFromSources2)
+///
+/// See [`TransformFromSources2`](crate::TransformFromSources2) for more information
+pub trait TryTransformFromSources2<S, N>: Transform2 {
+    type Error: Debug;
+
+    fn try_transform_from_sources(&self, sources: S, options: &Self::Options<'_>) -> Result<N, Self::Error>;
+}
+
+impl<S, N, T: TryTransformFromSources<S, N> + ?Sized> TryTransformFromSources2<S, N> for T {
+    type Error = T::Error;
+
+    fn try_transform_from_sources(&self, sources: S, options: &Self::Options<'_>) -> Result<N, Self::Error> {
+        TryTransformFromSources::try_transform_from_sources(self, sources, options)
+    }
+}
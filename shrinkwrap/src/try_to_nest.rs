@@ -1,6 +1,6 @@
 use std::fmt::Debug;
 
-use crate::transform::Transform;
+use crate::transform::{Transform, Transform2};
 
 /// Fallible version of [`TransformToNest`](crate::TransformToNest)
 ///
@@ -12,22 +12,41 @@ pub trait TryTransformToNest<N>: Transform {
     fn try_transform_to_nest(&self, data: &Self::Data, options: &Self::Options) -> Result<N, Self::Error>;
 }
 
+/// Fallible version of [`TransformToNest2`](crate::TransformToNest2)
+///
+/// See [`TransformToNest2`](crate::TransformToNest2) for more information
+pub trait TryTransformToNest2<N>: Transform2 {
+    type Data;
+    type Error: Debug;
+
+    fn try_transform_to_nest(&self, data: &Self::Data, options: &Self::Options<'_>) -> Result<N, Self::Error>;
+}
+
+impl<N, T: TryTransformToNest<N> + ?Sized> TryTransformToNest2<N> for T {
+    type Data = T::Data;
+    type Error = T::Error;
+
+    fn try_transform_to_nest(&self, data: &Self::Data, options: &Self::Options<'_>) -> Result<N, Self::Error> {
+        TryTransformToNest::try_transform_to_nest(self, data, options)
+    }
+}
+
 /// Fallible version of [`ToNestWith`](crate::ToNestWith)
 ///
 /// See [`ToNestWith`](crate::ToNestWith) for more information
-pub trait TryToNestWith<N, T: Transform>: Sized
+pub trait TryToNestWith<N, T: Transform2>: Sized
 where
-    T: TryTransformToNest<N, Data = Self>,
+    T: TryTransformToNest2<N, Data = Self>,
 {
-    fn try_to_nest_with(&self, transform: &T, options: &T::Options) -> Result<N, T::Error>;
+    fn try_to_nest_with(&self, transform: &T, options: &T::Options<'_>) -> Result<N, T::Error>;
 }
 
 /// Blanket implementation providing [`try_to_nest_with`](crate::TryToNestWith::try_to_nest_with) for data structs that have a corresponding [`TryTransformToNest<Nest>`](crate::TryTransformToNest) impl.
 impl<D, N, T> TryToNestWith<N, T> for D
 where
-    T: TryTransformToNest<N, Data = D>,
+    T: TryTransformToNest2<N, Data = D>,
 {
-    fn try_to_nest_with(&self, transform: &T, options: &T::Options) -> Result<N, T::Error> {
+    fn try_to_nest_with(&self, transform: &T, options: &T::Options<'_>) -> Result<N, T::Error> {
         transform.try_transform_to_nest(self, options)
     }
 }
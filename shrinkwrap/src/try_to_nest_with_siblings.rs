@@ -0,0 +1,32 @@
+use std::fmt::Debug;
+
+use crate::transform::{Transform, Transform2};
+
+/// Fallible version of [`TransformToNestWithSiblings`](crate::TransformToNestWithSiblings)
+///
+/// See [`TransformToNestWithSiblings`](crate::TransformToNestWithSiblings) for more information
+pub trait TryTransformToNestWithSiblings<N, S>: Transform {
+    type Data;
+    type Error: Debug;
+
+    fn try_transform_to_nest_with_siblings(&self, data: &Self::Data, sibling: &S, options: &Self::Options) -> Result<N, Self::Error>;
+}
+
+/// Fallible version of [`TransformToNestWithSiblings2`](crate::TransformToNestWithSiblings2)
+///
+/// See [`TransformToNestWithSiblings2`](crate::TransformToNestWithSiblings2) for more information
+pub trait TryTransformToNestWithSiblings2<N, S>: Transform2 {
+    type Data;
+    type Error: Debug;
+
+    fn try_transform_to_nest_with_siblings(&self, data: &Self::Data, sibling: &S, options: &Self::Options<'_>) -> Result<N, Self::Error>;
+}
+
+impl<N, S, T: TryTransformToNestWithSiblings<N, S> + ?Sized> TryTransformToNestWithSiblings2<N, S> for T {
+    type Data = T::Data;
+    type Error = T::Error;
+
+    fn try_transform_to_nest_with_siblings(&self, data: &Self::Data, sibling: &S, options: &Self::Options<'_>) -> Result<N, Self::Error> {
+        TryTransformToNestWithSiblings::try_transform_to_nest_with_siblings(self, data, sibling, options)
+    }
+}
@@ -1,19 +1,39 @@
 use serde::Serialize;
-use std::fmt::Debug;
+use std::error::Error;
+use std::fmt::{self, Debug, Display};
 
-use crate::transform::Transform;
+use crate::transform::{NoOptions, Transform2, ValidatedTransform};
 
 /// `TryToWrappedWith` is automatically implemented for data structs when all top-level nests have a [`TryTransformToNest`](crate::TryTransformToNest) impl on each nest type within the group. All impls must be for the same transform type.
 ///
 /// Furthermore, any nests which are deeply nested require a [`TryTransformToNest`](crate::TryTransformToNest) converting from their respective data source (the parent nest).
 pub trait TryToWrappedWith<T>: Debug + Clone + Serialize
 where
-    T: Transform,
+    T: Transform2,
 {
     type Wrapper;
     type Error: Debug;
 
-    fn try_to_wrapped_with(self, transform: &T, options: &T::Options) -> Result<Self::Wrapper, Self::Error>;
+    fn try_to_wrapped_with(self, transform: &T, options: &T::Options<'_>) -> Result<Self::Wrapper, Self::Error>;
+
+    /// Shorthand for [`try_to_wrapped_with`](Self::try_to_wrapped_with), for transforms without options
+    fn try_to_wrapped_with_t(self, transform: &T) -> Result<Self::Wrapper, Self::Error>
+    where
+        T: NoOptions,
+    {
+        self.try_to_wrapped_with(transform, &())
+    }
+
+    /// Validates the options via [`ValidatedTransform::validate_options`] before wrapping, options errors are
+    /// converted into `Self::Error`
+    fn try_to_wrapped_with_validated(self, transform: &T, options: &T::Options<'_>) -> Result<Self::Wrapper, Self::Error>
+    where
+        T: ValidatedTransform,
+        Self::Error: From<T::OptionsError>,
+    {
+        transform.validate_options(options)?;
+        self.try_to_wrapped_with(transform, options)
+    }
 }
 
 /// Allows for converting a data struct into a wrapper.
@@ -23,17 +43,51 @@ where
 /// The call is initiated from the wrapper Type itself. Aside from that, it is identical to [`try_to_wrapped_with`](crate::TryToWrappedWith::try_to_wrapped_with).
 pub trait TryWrapDataWith<D, T>: Sized
 where
-    T: Transform,
+    T: Transform2,
     D: TryToWrappedWith<T>,
 {
-    fn try_wrap_data_with(data: D, transform: &T, options: &T::Options) -> Result<Self, D::Error>;
+    fn try_wrap_data_with(data: D, transform: &T, options: &T::Options<'_>) -> Result<Self, D::Error>;
 }
 impl<D, T> TryWrapDataWith<D, T> for <D as TryToWrappedWith<T>>::Wrapper
 where
-    T: Transform,
+    T: Transform2,
     D: TryToWrappedWith<T>,
 {
-    fn try_wrap_data_with(data: D, transform: &T, options: &<T as Transform>::Options) -> Result<Self, D::Error> {
+    fn try_wrap_data_with(data: D, transform: &T, options: &<T as Transform2>::Options<'_>) -> Result<Self, D::Error> {
         data.try_to_wrapped_with(transform, options)
     }
 }
+
+/// Failure of a single optional nest, collected by [`TryToWrappedWithErrors`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NestError<E> {
+    /// Name of the nest's field within `extra`
+    pub nest: &'static str,
+    pub error: E,
+}
+impl<E: Display> Display for NestError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "nest `{}`: {}", self.nest, self.error)
+    }
+}
+impl<E: Error + 'static> Error for NestError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Partial variant of [`TryToWrappedWith`], implemented when the `accumulate_errors` option is set via `fallible`.
+///
+/// Failed optional nests are set to `None` and their errors collected, rather than failing the whole wrapper. Errors in
+/// required nests still fail the wrapper.
+pub trait TryToWrappedWithErrors<T>: TryToWrappedWith<T>
+where
+    T: Transform2,
+{
+    #[allow(clippy::type_complexity)]
+    fn try_to_wrapped_with_errors(
+        self,
+        transform: &T,
+        options: &T::Options<'_>,
+    ) -> Result<(Self::Wrapper, Vec<NestError<Self::Error>>), Self::Error>;
+}
@@ -0,0 +1,96 @@
+use std::collections::{BTreeMap, HashMap};
+use std::hash::{BuildHasher, Hash};
+
+use crate::transform::{NoOptions, Transform2};
+use crate::try_batch::TryWrapAllWith;
+use crate::wrap_map::MapEntry;
+
+/// Fallible variant of [`WrapMapWith`](crate::WrapMapWith).
+///
+/// Automatically implemented for `BTreeMap`s and `HashMap`s of data structs providing [`TryWrapAllWith`].
+pub trait TryWrapMapWith<T>
+where
+    T: Transform2,
+{
+    type Key;
+    type Wrapper;
+    /// The map of wrappers, the same map type as the data
+    type Wrapped;
+    type Error;
+
+    fn try_wrap_map_with(self, transform: &T, options: &T::Options<'_>) -> Result<Self::Wrapped, Self::Error>;
+
+    /// Wraps each value into a [`MapEntry`], in the map's iteration order
+    #[allow(clippy::type_complexity)]
+    fn try_wrap_entries_with(self, transform: &T, options: &T::Options<'_>) -> Result<Vec<MapEntry<Self::Key, Self::Wrapper>>, Self::Error>;
+
+    /// Shorthand for [`try_wrap_map_with`](Self::try_wrap_map_with), for transforms without options
+    fn try_wrap_map_with_t(self, transform: &T) -> Result<Self::Wrapped, Self::Error>
+    where
+        Self: Sized,
+        T: NoOptions,
+    {
+        self.try_wrap_map_with(transform, &())
+    }
+
+    /// Shorthand for [`try_wrap_entries_with`](Self::try_wrap_entries_with), for transforms without options
+    #[allow(clippy::type_complexity)]
+    fn try_wrap_entries_with_t(self, transform: &T) -> Result<Vec<MapEntry<Self::Key, Self::Wrapper>>, Self::Error>
+    where
+        Self: Sized,
+        T: NoOptions,
+    {
+        self.try_wrap_entries_with(transform, &())
+    }
+}
+impl<K, D, T> TryWrapMapWith<T> for BTreeMap<K, D>
+where
+    K: Ord,
+    T: Transform2,
+    D: TryWrapAllWith<T>,
+{
+    type Key = K;
+    type Wrapper = D::Wrapper;
+    type Wrapped = BTreeMap<K, D::Wrapper>;
+    type Error = D::Error;
+
+    fn try_wrap_map_with(self, transform: &T, options: &T::Options<'_>) -> Result<Self::Wrapped, D::Error> {
+        Ok(try_wrap_values(self, transform, options)?.collect())
+    }
+    fn try_wrap_entries_with(self, transform: &T, options: &T::Options<'_>) -> Result<Vec<MapEntry<K, D::Wrapper>>, D::Error> {
+        Ok(try_wrap_values(self, transform, options)?.map(MapEntry::from).collect())
+    }
+}
+impl<K, D, S, T> TryWrapMapWith<T> for HashMap<K, D, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher + Default,
+    T: Transform2,
+    D: TryWrapAllWith<T>,
+{
+    type Key = K;
+    type Wrapper = D::Wrapper;
+    type Wrapped = HashMap<K, D::Wrapper, S>;
+    type Error = D::Error;
+
+    fn try_wrap_map_with(self, transform: &T, options: &T::Options<'_>) -> Result<Self::Wrapped, D::Error> {
+        Ok(try_wrap_values(self, transform, options)?.collect())
+    }
+    fn try_wrap_entries_with(self, transform: &T, options: &T::Options<'_>) -> Result<Vec<MapEntry<K, D::Wrapper>>, D::Error> {
+        Ok(try_wrap_values(self, transform, options)?.map(MapEntry::from).collect())
+    }
+}
+
+/// Wraps the values of `map` in a single batch, pairing each wrapper with its key
+fn try_wrap_values<K, D, T>(
+    map: impl IntoIterator<Item = (K, D)>,
+    transform: &T,
+    options: &T::Options<'_>,
+) -> Result<impl Iterator<Item = (K, D::Wrapper)>, D::Error>
+where
+    T: Transform2,
+    D: TryWrapAllWith<T>,
+{
+    let (keys, values): (Vec<K>, Vec<D>) = map.into_iter().unzip();
+    Ok(keys.into_iter().zip(D::try_wrap_all_with(values, transform, options)?))
+}
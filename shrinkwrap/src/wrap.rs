@@ -1,18 +1,35 @@
 use serde::Serialize;
 use std::fmt::Debug;
 
-use crate::transform::Transform;
+use crate::transform::{NoOptions, Transform2, ValidatedTransform};
 
 /// `ToWrappedWith` is automatically implemented for data structs when all top-level nests have a [`TransformToNest`](crate::TransformToNest) impl on each nest type within the group. All impls must be for the same transform type.
 ///
 /// Furthermore, any nests which are deeply nested require a [`TransformToNest`](crate::TransformToNest) converting from their respective data source (the parent nest).
 pub trait ToWrappedWith<T>: Debug + Clone + Serialize
 where
-    T: Transform,
+    T: Transform2,
 {
     type Wrapper;
 
-    fn to_wrapped_with(self, transform: &T, options: &T::Options) -> Self::Wrapper;
+    fn to_wrapped_with(self, transform: &T, options: &T::Options<'_>) -> Self::Wrapper;
+
+    /// Shorthand for [`to_wrapped_with`](Self::to_wrapped_with), for transforms without options
+    fn to_wrapped_with_t(self, transform: &T) -> Self::Wrapper
+    where
+        T: NoOptions,
+    {
+        self.to_wrapped_with(transform, &())
+    }
+
+    /// Validates the options via [`ValidatedTransform::validate_options`] before wrapping
+    fn to_wrapped_with_validated(self, transform: &T, options: &T::Options<'_>) -> Result<Self::Wrapper, T::OptionsError>
+    where
+        T: ValidatedTransform,
+    {
+        transform.validate_options(options)?;
+        Ok(self.to_wrapped_with(transform, options))
+    }
 }
 
 /// Allows for converting a data struct into a wrapper.
@@ -22,17 +39,17 @@ where
 /// The call is initiated from the wrapper Type itself. Aside from that, it is identical to [`to_wrapped_with`](crate::ToWrappedWith::to_wrapped_with).
 pub trait WrapDataWith<D, T>: Sized
 where
-    T: Transform,
+    T: Transform2,
     D: ToWrappedWith<T>,
 {
-    fn wrap_data_with(data: D, transform: &T, options: &T::Options) -> Self;
+    fn wrap_data_with(data: D, transform: &T, options: &T::Options<'_>) -> Self;
 }
 impl<D, T> WrapDataWith<D, T> for <D as ToWrappedWith<T>>::Wrapper
 where
-    T: Transform,
+    T: Transform2,
     D: ToWrappedWith<T>,
 {
-    fn wrap_data_with(data: D, transform: &T, options: &<T as Transform>::Options) -> Self {
+    fn wrap_data_with(data: D, transform: &T, options: &<T as Transform2>::Options<'_>) -> Self {
         data.to_wrapped_with(transform, options)
     }
 }
@@ -0,0 +1,146 @@
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::{BuildHasher, Hash};
+
+use crate::batch::WrapAllWith;
+use crate::transform::{NoOptions, Transform2};
+
+/// Converts a map of data structs (e.g. a `BTreeMap<String, Stats>` response body) into wrappers, keyed as before.
+///
+/// Each value is wrapped via its derived wrapper, computing `batch` nests once for the whole map (see
+/// [`WrapAllWith`]). Alternatively, [`wrap_entries_with`](Self::wrap_entries_with) wraps the map into a list of
+/// [`MapEntry`] envelopes, for consumers expecting an array.
+///
+/// Automatically implemented for `BTreeMap`s and `HashMap`s of data structs providing [`WrapAllWith`].
+///
+/// # Examples
+///
+/// ```
+/// use serde_json::json;
+/// use shrinkwrap::{Transform, TransformToNest, Wrap, WrapMapWith};
+/// use std::collections::BTreeMap;
+///
+/// #[derive(Debug, Clone, serde::Serialize, Wrap)]
+/// #[shrinkwrap(transform = MyTransform)]
+/// #[shrinkwrap(nest(id = "text", field_type = String))]
+/// pub struct Stats {
+///     #[shrinkwrap(nest(id = "text"))]
+///     uptime_sec: i64,
+/// }
+///
+/// struct MyTransform;
+/// impl Transform for MyTransform {
+///     type Options = ();
+/// }
+/// impl TransformToNest<StatsNestedText> for MyTransform {
+///     type Data = Stats;
+///
+///     fn transform_to_nest(&self, data: &Stats, _: &()) -> StatsNestedText {
+///         StatsNestedText { uptime_sec: format!("{}s", data.uptime_sec) }
+///     }
+/// }
+///
+/// let stats = BTreeMap::from([("api".to_string(), Stats { uptime_sec: 10 }), ("db".to_string(), Stats { uptime_sec: 20 })]);
+///
+/// let wrapped = stats.clone().wrap_map_with_t(&MyTransform);
+/// assert_eq!(wrapped["db"].extra.text.uptime_sec, "20s");
+///
+/// let entries = stats.wrap_entries_with_t(&MyTransform);
+/// assert_eq!(serde_json::to_value(&entries).unwrap(), json!([
+///     { "key": "api", "extra": { "text": { "uptime_sec": "10s" } }, "uptime_sec": 10 },
+///     { "key": "db", "extra": { "text": { "uptime_sec": "20s" } }, "uptime_sec": 20 },
+/// ]));
+/// ```
+pub trait WrapMapWith<T>
+where
+    T: Transform2,
+{
+    type Key;
+    type Wrapper;
+    /// The map of wrappers, the same map type as the data
+    type Wrapped;
+
+    fn wrap_map_with(self, transform: &T, options: &T::Options<'_>) -> Self::Wrapped;
+
+    /// Wraps each value into a [`MapEntry`], in the map's iteration order
+    fn wrap_entries_with(self, transform: &T, options: &T::Options<'_>) -> Vec<MapEntry<Self::Key, Self::Wrapper>>;
+
+    /// Shorthand for [`wrap_map_with`](Self::wrap_map_with), for transforms without options
+    fn wrap_map_with_t(self, transform: &T) -> Self::Wrapped
+    where
+        Self: Sized,
+        T: NoOptions,
+    {
+        self.wrap_map_with(transform, &())
+    }
+
+    /// Shorthand for [`wrap_entries_with`](Self::wrap_entries_with), for transforms without options
+    fn wrap_entries_with_t(self, transform: &T) -> Vec<MapEntry<Self::Key, Self::Wrapper>>
+    where
+        Self: Sized,
+        T: NoOptions,
+    {
+        self.wrap_entries_with(transform, &())
+    }
+}
+impl<K, D, T> WrapMapWith<T> for BTreeMap<K, D>
+where
+    K: Ord,
+    T: Transform2,
+    D: WrapAllWith<T>,
+{
+    type Key = K;
+    type Wrapper = D::Wrapper;
+    type Wrapped = BTreeMap<K, D::Wrapper>;
+
+    fn wrap_map_with(self, transform: &T, options: &T::Options<'_>) -> Self::Wrapped {
+        wrap_values(self, transform, options).collect()
+    }
+    fn wrap_entries_with(self, transform: &T, options: &T::Options<'_>) -> Vec<MapEntry<K, D::Wrapper>> {
+        wrap_values(self, transform, options).map(MapEntry::from).collect()
+    }
+}
+impl<K, D, S, T> WrapMapWith<T> for HashMap<K, D, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher + Default,
+    T: Transform2,
+    D: WrapAllWith<T>,
+{
+    type Key = K;
+    type Wrapper = D::Wrapper;
+    type Wrapped = HashMap<K, D::Wrapper, S>;
+
+    fn wrap_map_with(self, transform: &T, options: &T::Options<'_>) -> Self::Wrapped {
+        wrap_values(self, transform, options).collect()
+    }
+    fn wrap_entries_with(self, transform: &T, options: &T::Options<'_>) -> Vec<MapEntry<K, D::Wrapper>> {
+        wrap_values(self, transform, options).map(MapEntry::from).collect()
+    }
+}
+
+/// Wraps the values of `map` in a single batch, pairing each wrapper with its key
+fn wrap_values<K, D, T>(map: impl IntoIterator<Item = (K, D)>, transform: &T, options: &T::Options<'_>) -> impl Iterator<Item = (K, D::Wrapper)>
+where
+    T: Transform2,
+    D: WrapAllWith<T>,
+{
+    let (keys, values): (Vec<K>, Vec<D>) = map.into_iter().unzip();
+    keys.into_iter().zip(D::wrap_all_with(values, transform, options))
+}
+
+/// A single wrapped map entry, produced by [`WrapMapWith::wrap_entries_with`].
+///
+/// Serializes as the wrapper along with a `key` field.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MapEntry<K, W> {
+    pub key: K,
+
+    #[serde(flatten)]
+    pub wrapper: W,
+}
+impl<K, W> From<(K, W)> for MapEntry<K, W> {
+    fn from((key, wrapper): (K, W)) -> Self {
+        Self { key, wrapper }
+    }
+}
@@ -0,0 +1,104 @@
+//! Serialization of nests declaring `alias` and `emit_alias`, while aliases are enabled and disabled.
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use shrinkwrap::{ToWrappedWith, Transform, TransformToNest, Wrap, alias};
+
+// !- Data definition
+
+#[derive(Debug, Clone, Serialize, Deserialize, Wrap)]
+#[shrinkwrap(transform = MyTransform, wrapper(deserialize))]
+#[shrinkwrap(nest(id = "text", field_type = String, alias = "txt", emit_alias))]
+#[shrinkwrap(nest(id = "value", field_type = f64, optional, alias = "val", emit_alias))]
+#[shrinkwrap(nest(id = "cents", field_type = i64, alias = "minor_units"))]
+pub struct Account {
+    #[shrinkwrap(nest(id = "text"), nest(id = "value"), nest(id = "cents"))]
+    pub balance: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Wrap)]
+#[shrinkwrap(transform = MyTransform, wrapper(sort_keys))]
+#[shrinkwrap(nest(id = "text", field_type = String, alias = "a_text", emit_alias))]
+pub struct Sorted {
+    #[shrinkwrap(nest(id = "text"))]
+    pub balance: i64,
+}
+
+// !- Transform
+
+struct MyTransform;
+impl Transform for MyTransform {
+    type Options = bool;
+}
+impl TransformToNest<AccountNestedText> for MyTransform {
+    type Data = Account;
+
+    fn transform_to_nest(&self, data: &Account, _: &bool) -> AccountNestedText {
+        AccountNestedText { balance: data.balance.to_string() }
+    }
+}
+impl TransformToNest<Option<AccountNestedValue>> for MyTransform {
+    type Data = Account;
+
+    fn transform_to_nest(&self, data: &Account, with_value: &bool) -> Option<AccountNestedValue> {
+        with_value.then(|| AccountNestedValue { balance: data.balance as f64 / 2.0 })
+    }
+}
+impl TransformToNest<AccountNestedCents> for MyTransform {
+    type Data = Account;
+
+    fn transform_to_nest(&self, data: &Account, _: &bool) -> AccountNestedCents {
+        AccountNestedCents { balance: data.balance * 100 }
+    }
+}
+impl TransformToNest<SortedNestedText> for MyTransform {
+    type Data = Sorted;
+
+    fn transform_to_nest(&self, data: &Sorted, _: &bool) -> SortedNestedText {
+        SortedNestedText { balance: data.balance.to_string() }
+    }
+}
+
+// !- Tests
+
+#[test]
+fn aliases_are_only_emitted_while_enabled() {
+    let wrapper = Account { balance: 5 }.to_wrapped_with(&MyTransform, &true);
+    let text = json!({ "balance": "5" });
+    let value = json!({ "balance": 2.5 });
+    let cents = json!({ "balance": 500 });
+    assert_eq!(
+        serde_json::to_value(&wrapper).unwrap(),
+        json!({ "extra": { "text": text, "value": value, "cents": cents }, "balance": 5 })
+    );
+    assert_eq!(
+        alias::with_aliases(|| serde_json::to_value(&wrapper).unwrap()),
+        json!({ "extra": { "text": text, "txt": text, "value": value, "val": value, "cents": cents }, "balance": 5 })
+    );
+    assert!(!alias::enabled());
+}
+
+#[test]
+fn missing_optional_nests_emit_null_aliases() {
+    let wrapper = Account { balance: 5 }.to_wrapped_with(&MyTransform, &false);
+    let _guard = alias::enable();
+    let json = serde_json::to_value(&wrapper).unwrap();
+    assert_eq!(json["extra"]["value"], json!(null));
+    assert_eq!(json["extra"]["val"], json!(null));
+}
+
+#[test]
+fn aliases_are_accepted_when_deserializing() {
+    let json = json!({ "extra": { "txt": { "balance": "5" }, "val": null, "minor_units": { "balance": 500 } }, "balance": 5 });
+    let wrapper: AccountWrapper = serde_json::from_value(json).unwrap();
+    assert_eq!(wrapper.extra.text.balance, "5");
+    assert!(wrapper.extra.value.is_none());
+    assert_eq!(wrapper.extra.cents.balance, 500);
+}
+
+#[test]
+fn aliases_are_sorted_with_sort_keys() {
+    let wrapper = Sorted { balance: 5 }.to_wrapped_with(&MyTransform, &false);
+    let json = alias::with_aliases(|| serde_json::to_string(&wrapper).unwrap());
+    assert_eq!(json, r#"{"balance":5,"extra":{"a_text":{"balance":"5"},"text":{"balance":"5"}}}"#);
+}
@@ -0,0 +1,118 @@
+//! Batch nests via `to_wrapped_all_with`, including the per-item fallback for batch impls returning the wrong number
+//! of nests.
+
+use serde::Serialize;
+use serde_json::json;
+use shrinkwrap::{
+    BatchTransformToNest, ToWrappedAllWith, Transform, TransformToNest, TryBatchTransformToNest, TryToWrappedAllWith,
+    TryTransformToNest, Wrap,
+};
+use std::cell::Cell;
+
+// !- Data definition
+
+#[derive(Debug, Clone, Serialize, Wrap)]
+#[shrinkwrap(transform = MyTransform)]
+#[shrinkwrap(nest(id = "text", field_type = String, batch))]
+pub struct Account {
+    #[shrinkwrap(nest(id = "text"))]
+    pub owner_id: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Wrap)]
+#[shrinkwrap(transform = MyTransform, fallible(error = MyError))]
+#[shrinkwrap(nest(id = "text", field_type = String, batch))]
+pub struct Invoice {
+    #[shrinkwrap(nest(id = "text"))]
+    pub owner_id: u32,
+}
+
+// !- Transform
+
+#[derive(Debug, PartialEq)]
+pub struct MyError;
+
+#[derive(Default)]
+struct MyTransform {
+    batch_calls: Cell<usize>,
+    item_calls: Cell<usize>,
+}
+impl Transform for MyTransform {
+    /// Number of nests dropped from the end of each batch
+    type Options = usize;
+}
+impl TransformToNest<AccountNestedText> for MyTransform {
+    type Data = Account;
+
+    fn transform_to_nest(&self, data: &Account, _: &usize) -> AccountNestedText {
+        self.item_calls.set(self.item_calls.get() + 1);
+        AccountNestedText { owner_id: format!("item-{}", data.owner_id) }
+    }
+}
+impl BatchTransformToNest<AccountNestedText> for MyTransform {
+    type Data = Account;
+
+    fn transform_many(&self, data: &[Account], dropped: &usize) -> Vec<AccountNestedText> {
+        self.batch_calls.set(self.batch_calls.get() + 1);
+        let nests = data.iter().map(|data| AccountNestedText { owner_id: format!("batch-{}", data.owner_id) });
+        nests.take(data.len() - dropped).collect()
+    }
+}
+impl TryTransformToNest<InvoiceNestedText> for MyTransform {
+    type Data = Invoice;
+    type Error = MyError;
+
+    fn try_transform_to_nest(&self, data: &Invoice, _: &usize) -> Result<InvoiceNestedText, MyError> {
+        self.item_calls.set(self.item_calls.get() + 1);
+        match data.owner_id {
+            0 => Err(MyError),
+            owner_id => Ok(InvoiceNestedText { owner_id: format!("item-{owner_id}") }),
+        }
+    }
+}
+impl TryBatchTransformToNest<InvoiceNestedText> for MyTransform {
+    type Data = Invoice;
+    type Error = MyError;
+
+    fn try_transform_many(&self, data: &[Invoice], dropped: &usize) -> Result<Vec<InvoiceNestedText>, MyError> {
+        self.batch_calls.set(self.batch_calls.get() + 1);
+        let nests = data.iter().map(|data| InvoiceNestedText { owner_id: format!("batch-{}", data.owner_id) });
+        Ok(nests.take(data.len() - dropped).collect())
+    }
+}
+
+// !- Tests
+
+#[test]
+fn batch_nests_are_computed_once() {
+    let transform = MyTransform::default();
+    let wrappers = vec![Account { owner_id: 1 }, Account { owner_id: 2 }].to_wrapped_all_with(&transform, &0);
+    assert_eq!(
+        serde_json::to_value(&wrappers).unwrap(),
+        json!([
+            { "owner_id": 1, "extra": { "text": { "owner_id": "batch-1" } } },
+            { "owner_id": 2, "extra": { "text": { "owner_id": "batch-2" } } },
+        ])
+    );
+    assert_eq!((transform.batch_calls.get(), transform.item_calls.get()), (1, 0));
+}
+
+#[test]
+fn batch_length_mismatch_falls_back_to_per_item() {
+    let transform = MyTransform::default();
+    let wrappers = vec![Account { owner_id: 1 }, Account { owner_id: 2 }].to_wrapped_all_with(&transform, &1);
+    let texts: Vec<_> = wrappers.iter().map(|wrapper| wrapper.extra.text.owner_id.as_str()).collect();
+    assert_eq!(texts, ["item-1", "item-2"]);
+    assert_eq!((transform.batch_calls.get(), transform.item_calls.get()), (1, 2));
+}
+
+#[test]
+fn try_batch_length_mismatch_falls_back_to_per_item() {
+    let transform = MyTransform::default();
+    let wrappers = vec![Invoice { owner_id: 1 }, Invoice { owner_id: 2 }].try_to_wrapped_all_with(&transform, &1).unwrap();
+    let texts: Vec<_> = wrappers.iter().map(|wrapper| wrapper.extra.text.owner_id.as_str()).collect();
+    assert_eq!(texts, ["item-1", "item-2"]);
+
+    let result = vec![Invoice { owner_id: 1 }, Invoice { owner_id: 0 }].try_to_wrapped_all_with(&transform, &1);
+    assert_eq!(result.err(), Some(MyError));
+}
@@ -0,0 +1,168 @@
+//! Round-trip tests for `wrapper(binary_safe)` across self-describing and non-self-describing formats.
+
+use serde::{Deserialize, Serialize};
+use shrinkwrap::{ToWrappedWith, Transform, TransformToNest, Wrap};
+
+// !- Data definition
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Wrap)]
+#[shrinkwrap(transform = MyTransform)]
+#[shrinkwrap(wrapper(binary_safe, deserialize), derive_all(PartialEq))]
+#[shrinkwrap(nest(id = "text", field_type = String))]
+#[shrinkwrap(nest(id = "value", field_type = f32, optional))]
+#[shrinkwrap(nest(id = "value_text", field_name = "text", field_type = String, chain_from = "value"))]
+pub struct Account {
+    pub id: u32,
+
+    #[serde(rename = "bal")]
+    #[shrinkwrap(nest(id = "text"), nest(id = "value"), nest(id = "value_text"))]
+    pub balance: i64,
+
+    pub label: Option<String>,
+
+    #[serde(skip)]
+    pub cached: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Wrap)]
+#[shrinkwrap(transform = MyTransform)]
+#[shrinkwrap(wrapper(binary_safe, sort_keys, deserialize), derive_all(PartialEq))]
+#[shrinkwrap(nest(id = "text", field_type = String))]
+pub struct Sorted {
+    #[shrinkwrap(nest(id = "text"))]
+    pub zeta: i64,
+    pub alpha: bool,
+}
+
+// !- Transform
+
+struct MyTransform;
+impl Transform for MyTransform {
+    type Options = bool;
+}
+impl TransformToNest<AccountNestedText> for MyTransform {
+    type Data = Account;
+
+    fn transform_to_nest(&self, data: &Account, _: &bool) -> AccountNestedText {
+        AccountNestedText { balance: format!("{} credits", data.balance) }
+    }
+}
+impl TransformToNest<Option<AccountNestedValue>> for MyTransform {
+    type Data = Account;
+
+    fn transform_to_nest(&self, data: &Account, with_value: &bool) -> Option<AccountNestedValue> {
+        with_value.then(|| AccountNestedValue { balance: data.balance as f32 / 100.0 })
+    }
+}
+impl TransformToNest<AccountNestedValueText> for MyTransform {
+    type Data = AccountNestedValue;
+
+    fn transform_to_nest(&self, data: &AccountNestedValue, _: &bool) -> AccountNestedValueText {
+        AccountNestedValueText { balance: format!("${:.2}", data.balance) }
+    }
+}
+impl TransformToNest<SortedNestedText> for MyTransform {
+    type Data = Sorted;
+
+    fn transform_to_nest(&self, data: &Sorted, _: &bool) -> SortedNestedText {
+        SortedNestedText { zeta: data.zeta.to_string() }
+    }
+}
+
+// !- Helpers
+
+fn accounts() -> Vec<AccountWrapper> {
+    vec![
+        Account { id: 1, balance: 1250, label: Some("primary".into()), cached: 0 }.to_wrapped_with(&MyTransform, &true),
+        Account { id: 2, balance: -75, label: None, cached: 0 }.to_wrapped_with(&MyTransform, &false),
+    ]
+}
+
+fn sorted() -> SortedWrapper {
+    Sorted { zeta: 3, alpha: true }.to_wrapped_with(&MyTransform, &false)
+}
+
+fn bincode_round_trip<T: Serialize + for<'de> Deserialize<'de>>(value: &T) -> T {
+    let config = bincode::config::standard();
+    let bytes = bincode::serde::encode_to_vec(value, config).unwrap();
+    let (decoded, len) = bincode::serde::decode_from_slice(&bytes, config).unwrap();
+    assert_eq!(len, bytes.len());
+    decoded
+}
+
+fn cbor_round_trip<T: Serialize + for<'de> Deserialize<'de>>(value: &T) -> T {
+    let mut bytes = Vec::new();
+    ciborium::into_writer(value, &mut bytes).unwrap();
+    ciborium::from_reader(bytes.as_slice()).unwrap()
+}
+
+// !- Tests
+
+#[test]
+fn json_shape_is_unchanged() {
+    let json = serde_json::to_value(&accounts()[0]).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "extra": {
+                "text": { "balance": "1250 credits" },
+                "value": {
+                    "extra": { "text": { "balance": "$12.50" } },
+                    "balance": 12.5,
+                },
+            },
+            "id": 1,
+            "bal": 1250,
+            "label": "primary",
+        })
+    );
+    let decoded: AccountWrapper = serde_json::from_value(json).unwrap();
+    assert_eq!(decoded, accounts()[0]);
+}
+
+#[test]
+fn json_sorted_keys_round_trip() {
+    let json = serde_json::to_string(&sorted()).unwrap();
+    assert_eq!(json, r#"{"alpha":true,"extra":{"text":{"zeta":"3"}},"zeta":3}"#);
+    assert_eq!(serde_json::from_str::<SortedWrapper>(&json).unwrap(), sorted());
+}
+
+#[test]
+fn messagepack_round_trip() {
+    for account in accounts() {
+        // compact (array) struct encoding
+        let bytes = rmp_serde::to_vec(&account).unwrap();
+        assert_eq!(rmp_serde::from_slice::<AccountWrapper>(&bytes).unwrap(), account);
+
+        // named (map) struct encoding
+        let bytes = rmp_serde::to_vec_named(&account).unwrap();
+        assert_eq!(rmp_serde::from_slice::<AccountWrapper>(&bytes).unwrap(), account);
+    }
+    let bytes = rmp_serde::to_vec(&sorted()).unwrap();
+    assert_eq!(rmp_serde::from_slice::<SortedWrapper>(&bytes).unwrap(), sorted());
+}
+
+#[test]
+fn cbor_round_trip_all() {
+    for account in accounts() {
+        assert_eq!(cbor_round_trip(&account), account);
+    }
+    assert_eq!(cbor_round_trip(&sorted()), sorted());
+}
+
+#[test]
+fn bincode_round_trip_all() {
+    for account in accounts() {
+        assert_eq!(bincode_round_trip(&account), account);
+    }
+    assert_eq!(bincode_round_trip(&sorted()), sorted());
+}
+
+#[test]
+fn skipped_fields_are_defaulted() {
+    let mut account = accounts().remove(0);
+    account.data.cached = 42;
+
+    let decoded = bincode_round_trip(&account);
+    assert_eq!(decoded.data.cached, 0);
+}
@@ -0,0 +1,121 @@
+//! CSV projection of wrappers via the `csv` flag, including chained, optional, and excluded nests.
+
+use serde::Serialize;
+use shrinkwrap::{ToWrappedWith, Transform, TransformToNest, Wrap};
+use std::collections::BTreeMap;
+
+// !- Data definition
+
+#[derive(Debug, Clone, Serialize, Wrap)]
+#[shrinkwrap(transform = MyTransform, csv)]
+#[shrinkwrap(nest(id = "text", field_type = String, optional))]
+#[shrinkwrap(nest(id = "value", field_type = f64))]
+#[shrinkwrap(nest(id = "value_text", field_name = "text", field_type = String, chain_from = "value"))]
+pub struct Account {
+    pub id: u32,
+
+    #[serde(rename = "bal")]
+    #[shrinkwrap(nest(id = "text"), nest(id = "value"), nest(id = "value_text"))]
+    pub balance: i64,
+
+    pub label: Option<String>,
+
+    pub tags: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Wrap)]
+#[shrinkwrap(transform = MyTransform, csv(nests("value_text")))]
+#[shrinkwrap(nest(id = "text", field_type = String))]
+#[shrinkwrap(nest(id = "value", field_type = f64))]
+#[shrinkwrap(nest(id = "value_text", field_name = "text", field_type = String, chain_from = "value"))]
+pub struct Projected {
+    #[shrinkwrap(nest(id = "text"), nest(id = "value"), nest(id = "value_text"))]
+    pub balance: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Wrap)]
+#[shrinkwrap(transform = MyTransform, csv)]
+pub struct Keyed {
+    pub counts: BTreeMap<(u8, u8), u32>,
+}
+
+// !- Transform
+
+struct MyTransform;
+impl Transform for MyTransform {
+    type Options = bool;
+}
+impl TransformToNest<Option<AccountNestedText>> for MyTransform {
+    type Data = Account;
+
+    fn transform_to_nest(&self, data: &Account, with_text: &bool) -> Option<AccountNestedText> {
+        with_text.then(|| AccountNestedText { balance: format!("{} credits", data.balance) })
+    }
+}
+impl TransformToNest<AccountNestedValue> for MyTransform {
+    type Data = Account;
+
+    fn transform_to_nest(&self, data: &Account, _: &bool) -> AccountNestedValue {
+        AccountNestedValue { balance: data.balance as f64 / 100.0 }
+    }
+}
+impl TransformToNest<AccountNestedValueText> for MyTransform {
+    type Data = AccountNestedValue;
+
+    fn transform_to_nest(&self, data: &AccountNestedValue, _: &bool) -> AccountNestedValueText {
+        AccountNestedValueText { balance: format!("${:.2}", data.balance) }
+    }
+}
+impl TransformToNest<ProjectedNestedText> for MyTransform {
+    type Data = Projected;
+
+    fn transform_to_nest(&self, data: &Projected, _: &bool) -> ProjectedNestedText {
+        ProjectedNestedText { balance: data.balance.to_string() }
+    }
+}
+impl TransformToNest<ProjectedNestedValue> for MyTransform {
+    type Data = Projected;
+
+    fn transform_to_nest(&self, data: &Projected, _: &bool) -> ProjectedNestedValue {
+        ProjectedNestedValue { balance: data.balance as f64 / 100.0 }
+    }
+}
+impl TransformToNest<ProjectedNestedValueText> for MyTransform {
+    type Data = ProjectedNestedValue;
+
+    fn transform_to_nest(&self, data: &ProjectedNestedValue, _: &bool) -> ProjectedNestedValueText {
+        ProjectedNestedValueText { balance: format!("${:.2}", data.balance) }
+    }
+}
+
+// !- Tests
+
+#[test]
+fn records_match_headers() {
+    assert_eq!(AccountWrapper::csv_headers(), ["id", "bal", "label", "tags", "text.balance", "value.balance", "value.text.balance"]);
+
+    let account = Account { id: 1, balance: 1250, label: Some("main, primary".into()), tags: vec!["a".into(), "b".into()] };
+    let record = account.to_wrapped_with(&MyTransform, &true).to_csv_record().unwrap();
+    assert_eq!(record, ["1", "1250", "main, primary", r#"["a","b"]"#, "1250 credits", "12.5", "$12.50"]);
+}
+
+#[test]
+fn missing_values_are_empty() {
+    let account = Account { id: 2, balance: 75, label: None, tags: Vec::new() };
+    let record = account.to_wrapped_with(&MyTransform, &false).to_csv_record().unwrap();
+    assert_eq!(record, ["2", "75", "", "[]", "", "0.75", "$0.75"]);
+}
+
+#[test]
+fn records_are_restricted_to_selected_nests() {
+    assert_eq!(ProjectedWrapper::csv_headers(), ["balance", "value.text.balance"]);
+    let record = Projected { balance: 75 }.to_wrapped_with(&MyTransform, &false).to_csv_record().unwrap();
+    assert_eq!(record, ["75", "$0.75"]);
+}
+
+#[test]
+fn unserializable_values_fail() {
+    let keyed = Keyed { counts: BTreeMap::from([((1, 2), 3)]) };
+    let error = keyed.to_wrapped_with(&MyTransform, &false).to_csv_record().unwrap_err();
+    assert!(matches!(error, shrinkwrap::Error::Json(_)));
+}
@@ -0,0 +1,128 @@
+//! Serialization of `encode = "json_string"` nests alongside the manual `Serialize` impls of `wrapper(sort_keys)`,
+//! `wrapper(binary_safe)`, `wrapper(integrity)` and `emit_alias`.
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use shrinkwrap::{ToWrappedWith, Transform, TransformToNest, Wrap};
+
+// !- Data definition
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Wrap)]
+#[shrinkwrap(transform = MyTransform)]
+#[shrinkwrap(wrapper(sort_keys, deserialize), derive_all(PartialEq))]
+#[shrinkwrap(nest(id = "text", field_type = String, encode = "json_string"))]
+#[shrinkwrap(nest(id = "value", field_type = f64, optional, encode = "json_string"))]
+pub struct Sorted {
+    #[shrinkwrap(nest(id = "text"), nest(id = "value"))]
+    pub zeta: i64,
+    pub alpha: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Wrap)]
+#[shrinkwrap(transform = MyTransform)]
+#[shrinkwrap(wrapper(binary_safe, deserialize), derive_all(PartialEq))]
+#[shrinkwrap(nest(id = "text", field_type = String, encode = "json_string"))]
+pub struct Binary {
+    #[shrinkwrap(nest(id = "text"))]
+    pub zeta: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Wrap)]
+#[shrinkwrap(transform = MyTransform)]
+#[shrinkwrap(wrapper(flatten = false, integrity(field = "checksum")))]
+#[shrinkwrap(nest(id = "text", field_type = String, encode = "json_string"))]
+pub struct Signed {
+    #[shrinkwrap(nest(id = "text"))]
+    pub zeta: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Wrap)]
+#[shrinkwrap(transform = MyTransform)]
+#[shrinkwrap(nest(id = "text", field_type = String, encode = "json_string", alias = "txt", emit_alias))]
+pub struct Aliased {
+    #[shrinkwrap(nest(id = "text"))]
+    pub zeta: i64,
+}
+
+// !- Transform
+
+struct MyTransform;
+impl Transform for MyTransform {
+    type Options = bool;
+}
+impl TransformToNest<SortedNestedText> for MyTransform {
+    type Data = Sorted;
+
+    fn transform_to_nest(&self, data: &Sorted, _: &bool) -> SortedNestedText {
+        SortedNestedText { zeta: data.zeta.to_string() }
+    }
+}
+impl TransformToNest<Option<SortedNestedValue>> for MyTransform {
+    type Data = Sorted;
+
+    fn transform_to_nest(&self, data: &Sorted, with_value: &bool) -> Option<SortedNestedValue> {
+        with_value.then(|| SortedNestedValue { zeta: data.zeta as f64 / 2.0 })
+    }
+}
+impl TransformToNest<BinaryNestedText> for MyTransform {
+    type Data = Binary;
+
+    fn transform_to_nest(&self, data: &Binary, _: &bool) -> BinaryNestedText {
+        BinaryNestedText { zeta: data.zeta.to_string() }
+    }
+}
+impl TransformToNest<SignedNestedText> for MyTransform {
+    type Data = Signed;
+
+    fn transform_to_nest(&self, data: &Signed, _: &bool) -> SignedNestedText {
+        SignedNestedText { zeta: data.zeta.to_string() }
+    }
+}
+
+impl TransformToNest<AliasedNestedText> for MyTransform {
+    type Data = Aliased;
+
+    fn transform_to_nest(&self, data: &Aliased, _: &bool) -> AliasedNestedText {
+        AliasedNestedText { zeta: data.zeta.to_string() }
+    }
+}
+
+// !- Tests
+
+#[test]
+fn sort_keys_encodes_nests() {
+    let wrapper = Sorted { zeta: 5, alpha: true }.to_wrapped_with(&MyTransform, &true);
+    let json = serde_json::to_string(&wrapper).unwrap();
+    assert_eq!(json, r#"{"alpha":true,"extra":{"text":"{\"zeta\":\"5\"}","value":"{\"zeta\":2.5}"},"zeta":5}"#);
+    assert_eq!(serde_json::from_str::<SortedWrapper>(&json).unwrap(), wrapper);
+}
+
+#[test]
+fn sort_keys_encodes_missing_optional_nests_as_null() {
+    let wrapper = Sorted { zeta: 5, alpha: false }.to_wrapped_with(&MyTransform, &false);
+    let json = serde_json::to_value(&wrapper).unwrap();
+    assert_eq!(json, json!({ "alpha": false, "extra": { "text": "{\"zeta\":\"5\"}", "value": null }, "zeta": 5 }));
+}
+
+#[test]
+fn binary_safe_encodes_nests() {
+    let wrapper = Binary { zeta: 5 }.to_wrapped_with(&MyTransform, &false);
+    let json = serde_json::to_value(&wrapper).unwrap();
+    assert_eq!(json, json!({ "extra": { "text": "{\"zeta\":\"5\"}" }, "zeta": 5 }));
+    assert_eq!(serde_json::from_value::<BinaryWrapper>(json).unwrap(), wrapper);
+}
+
+#[test]
+fn integrity_encodes_nests() {
+    let wrapper = Signed { zeta: 5 }.to_wrapped_with(&MyTransform, &false);
+    let json = serde_json::to_value(&wrapper).unwrap();
+    let checksum = shrinkwrap::integrity::sha256_hex(br#"{"zeta":5}"#);
+    assert_eq!(json, json!({ "extra": { "text": "{\"zeta\":\"5\"}" }, "data": { "zeta": 5 }, "checksum": checksum }));
+}
+
+#[test]
+fn emit_alias_encodes_nests() {
+    let wrapper = Aliased { zeta: 5 }.to_wrapped_with(&MyTransform, &false);
+    let json = shrinkwrap::alias::with_aliases(|| serde_json::to_value(&wrapper).unwrap());
+    assert_eq!(json, json!({ "extra": { "text": "{\"zeta\":\"5\"}", "txt": "{\"zeta\":\"5\"}" }, "zeta": 5 }));
+}
@@ -0,0 +1,67 @@
+//! Conversion of extra structs into `google.protobuf.Struct` via the `grpc` flag, using the re-exported `prost-types`.
+
+use serde::Serialize;
+use shrinkwrap::grpc::prost_types::{NullValue, Struct, Value, value::Kind};
+use shrinkwrap::{ToWrappedWith, Transform, TransformToNest, Wrap};
+
+// !- Data definition
+
+#[derive(Debug, Clone, Serialize, Wrap)]
+#[shrinkwrap(transform = MyTransform, grpc)]
+#[shrinkwrap(nest(id = "text", field_type = String))]
+#[shrinkwrap(nest(id = "value", field_type = f64, optional))]
+pub struct Account {
+    #[shrinkwrap(nest(id = "text"), nest(id = "value"))]
+    pub balance: i64,
+}
+
+// !- Transform
+
+struct MyTransform;
+impl Transform for MyTransform {
+    type Options = bool;
+}
+impl TransformToNest<AccountNestedText> for MyTransform {
+    type Data = Account;
+
+    fn transform_to_nest(&self, data: &Account, _: &bool) -> AccountNestedText {
+        AccountNestedText { balance: format!("${:.2}", data.balance as f64 / 100.0) }
+    }
+}
+impl TransformToNest<Option<AccountNestedValue>> for MyTransform {
+    type Data = Account;
+
+    fn transform_to_nest(&self, data: &Account, with_value: &bool) -> Option<AccountNestedValue> {
+        with_value.then(|| AccountNestedValue { balance: data.balance as f64 / 100.0 })
+    }
+}
+
+// !- Tests
+
+fn field(kind: Kind) -> Value {
+    Value { kind: Some(kind) }
+}
+
+fn nest(balance: Kind) -> Value {
+    field(Kind::StructValue(Struct { fields: [("balance".to_string(), field(balance))].into() }))
+}
+
+#[test]
+fn to_grpc_metadata() {
+    let wrapper = Account { balance: 250 }.to_wrapped_with(&MyTransform, &true);
+    let metadata = wrapper.to_grpc_metadata().unwrap();
+    assert_eq!(metadata, Struct {
+        fields: [
+            ("text".to_string(), nest(Kind::StringValue("$2.50".into()))),
+            ("value".to_string(), nest(Kind::NumberValue(2.5))),
+        ]
+        .into(),
+    });
+}
+
+#[test]
+fn missing_optional_nests_are_null() {
+    let wrapper = Account { balance: 250 }.to_wrapped_with(&MyTransform, &false);
+    let metadata = Struct::try_from(&wrapper.extra).unwrap();
+    assert_eq!(metadata.fields["value"], field(Kind::NullValue(NullValue::NullValue as i32)));
+}
@@ -0,0 +1,91 @@
+//! Checksums and signatures emitted by `wrapper(integrity(..))`, verified against the emitted `data` bytes.
+
+use serde::Serialize;
+use serde_json::Value;
+use shrinkwrap::{ToWrappedWith, Transform, TransformToNest, Wrap, integrity};
+
+// !- Data definition
+
+#[derive(Debug, Clone, Serialize, Wrap)]
+#[shrinkwrap(transform = MyTransform)]
+#[shrinkwrap(wrapper(flatten = false, integrity(algo = "sha256")))]
+#[shrinkwrap(nest(id = "text", field_type = String))]
+pub struct Checked {
+    pub id: u32,
+    #[shrinkwrap(nest(id = "text"))]
+    pub total_cents: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Wrap)]
+#[shrinkwrap(transform = MyTransform)]
+#[shrinkwrap(wrapper(flatten = false, integrity(algo = "hmac-sha256", field = "signature", key = "crate::signing_key")))]
+#[shrinkwrap(nest(id = "text", field_type = String))]
+pub struct Signed {
+    pub id: u32,
+    #[shrinkwrap(nest(id = "text"))]
+    pub total_cents: i64,
+}
+
+fn signing_key() -> &'static [u8] {
+    b"secret"
+}
+
+// !- Transform
+
+struct MyTransform;
+impl Transform for MyTransform {
+    type Options = ();
+}
+impl TransformToNest<CheckedNestedText> for MyTransform {
+    type Data = Checked;
+
+    fn transform_to_nest(&self, data: &Checked, _: &()) -> CheckedNestedText {
+        CheckedNestedText { total_cents: format!("${:.2}", data.total_cents as f64 / 100.0) }
+    }
+}
+impl TransformToNest<SignedNestedText> for MyTransform {
+    type Data = Signed;
+
+    fn transform_to_nest(&self, data: &Signed, _: &()) -> SignedNestedText {
+        SignedNestedText { total_cents: format!("${:.2}", data.total_cents as f64 / 100.0) }
+    }
+}
+
+// !- Tests
+
+/// The raw bytes of the emitted `data` block, as received by consumers
+fn data_bytes(json: &str) -> String {
+    let value: Value = serde_json::from_str(json).unwrap();
+    serde_json::to_string(&value["data"]).unwrap()
+}
+
+#[test]
+fn checksum_matches_data_bytes() {
+    let wrapper = Checked { id: 7, total_cents: 250 }.to_wrapped_with(&MyTransform, &());
+    let json = serde_json::to_string(&wrapper).unwrap();
+    let checksum = integrity::sha256_hex(br#"{"id":7,"total_cents":250}"#);
+    assert_eq!(json, format!(r#"{{"extra":{{"text":{{"total_cents":"$2.50"}}}},"data":{{"id":7,"total_cents":250}},"checksum":"{checksum}"}}"#));
+    assert_eq!(checksum, integrity::sha256_hex(data_bytes(&json).as_bytes()));
+    assert_eq!(checksum, integrity::checksum(&wrapper.data).unwrap());
+}
+
+#[test]
+fn checksum_differs_by_data() {
+    let checksum = |total_cents| {
+        let json = serde_json::to_value(Checked { id: 7, total_cents }.to_wrapped_with(&MyTransform, &())).unwrap();
+        json["checksum"].as_str().unwrap().to_string()
+    };
+    assert_ne!(checksum(250), checksum(251));
+}
+
+#[test]
+fn signature_matches_data_bytes() {
+    let wrapper = Signed { id: 7, total_cents: 250 }.to_wrapped_with(&MyTransform, &());
+    let json = serde_json::to_string(&wrapper).unwrap();
+    let value: Value = serde_json::from_str(&json).unwrap();
+    let signature = value["signature"].as_str().unwrap();
+    assert_eq!(signature, integrity::hmac_sha256_hex(signing_key(), data_bytes(&json).as_bytes()));
+    assert_eq!(signature, integrity::signature(signing_key(), &wrapper.data).unwrap());
+    assert_ne!(signature, integrity::hmac_sha256_hex(b"other", data_bytes(&json).as_bytes()));
+    assert!(value.get("checksum").is_none());
+}
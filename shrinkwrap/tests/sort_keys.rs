@@ -0,0 +1,102 @@
+//! Serialized key order of `wrapper(sort_keys)`, across flattened data, serde renames, and chained nests.
+
+use serde::Serialize;
+use shrinkwrap::{ToWrappedWith, Transform, TransformToNest, Wrap};
+
+// !- Data definition
+
+#[derive(Debug, Clone, Serialize, Wrap)]
+#[shrinkwrap(transform = MyTransform)]
+#[shrinkwrap(wrapper(sort_keys))]
+#[shrinkwrap(nest(id = "text", field_type = String))]
+#[shrinkwrap(nest(id = "value", field_type = f64, optional))]
+#[shrinkwrap(nest(id = "value_text", field_name = "text", field_type = String, chain_from = "value"))]
+pub struct Account {
+    #[shrinkwrap(nest(id = "text"), nest(id = "value"), nest(id = "value_text"))]
+    pub zeta: i64,
+
+    #[serde(rename = "beta")]
+    #[shrinkwrap(nest(id = "text"))]
+    pub alpha: i64,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Wrap)]
+#[shrinkwrap(transform = MyTransform)]
+#[shrinkwrap(wrapper(sort_keys, flatten = false))]
+#[shrinkwrap(nest(id = "text", field_type = String))]
+pub struct Nested {
+    #[shrinkwrap(nest(id = "text"))]
+    pub zeta: i64,
+    pub alpha: bool,
+}
+
+// !- Transform
+
+struct MyTransform;
+impl Transform for MyTransform {
+    type Options = bool;
+}
+impl TransformToNest<AccountNestedText> for MyTransform {
+    type Data = Account;
+
+    fn transform_to_nest(&self, data: &Account, _: &bool) -> AccountNestedText {
+        AccountNestedText { zeta: data.zeta.to_string(), alpha: data.alpha.to_string() }
+    }
+}
+impl TransformToNest<Option<AccountNestedValue>> for MyTransform {
+    type Data = Account;
+
+    fn transform_to_nest(&self, data: &Account, with_value: &bool) -> Option<AccountNestedValue> {
+        with_value.then(|| AccountNestedValue { zeta: data.zeta as f64 / 2.0 })
+    }
+}
+impl TransformToNest<AccountNestedValueText> for MyTransform {
+    type Data = AccountNestedValue;
+
+    fn transform_to_nest(&self, data: &AccountNestedValue, _: &bool) -> AccountNestedValueText {
+        AccountNestedValueText { zeta: format!("{:.1}", data.zeta) }
+    }
+}
+impl TransformToNest<NestedNestedText> for MyTransform {
+    type Data = Nested;
+
+    fn transform_to_nest(&self, data: &Nested, _: &bool) -> NestedNestedText {
+        NestedNestedText { zeta: data.zeta.to_string() }
+    }
+}
+
+// !- Tests
+
+#[test]
+fn sorts_flattened_keys() {
+    let wrapper = Account { zeta: 5, alpha: 1, label: Some("main".into()) }.to_wrapped_with(&MyTransform, &true);
+    assert_eq!(
+        serde_json::to_string(&wrapper).unwrap(),
+        concat!(
+            r#"{"beta":1,"extra":{"text":{"alpha":"1","zeta":"5"},"value":{"extra":{"text":{"zeta":"2.5"}},"zeta":2.5}},"#,
+            r#""label":"main","zeta":5}"#,
+        )
+    );
+}
+
+#[test]
+fn sorts_keys_with_skipped_fields() {
+    let wrapper = Account { zeta: 5, alpha: 1, label: None }.to_wrapped_with(&MyTransform, &false);
+    assert_eq!(
+        serde_json::to_string(&wrapper).unwrap(),
+        r#"{"beta":1,"extra":{"text":{"alpha":"1","zeta":"5"},"value":null},"zeta":5}"#
+    );
+}
+
+#[test]
+fn sorts_wrapper_keys_of_nested_data() {
+    let wrapper = Nested { zeta: 5, alpha: true }.to_wrapped_with(&MyTransform, &false);
+    // the data block is serialized via the origin's own impl
+    assert_eq!(
+        serde_json::to_string(&wrapper).unwrap(),
+        r#"{"data":{"zeta":5,"alpha":true},"extra":{"text":{"zeta":"5"}}}"#
+    );
+}
@@ -0,0 +1,62 @@
+//! Validation of nests declaring `validate`, via the re-exported `validator` crate.
+
+use serde::Serialize;
+use shrinkwrap::validator::{Validate, ValidationError};
+use shrinkwrap::{ToWrappedWith, Transform, TransformToNest, Wrap};
+
+// !- Data definition
+
+#[derive(Debug, Clone, Serialize, Wrap)]
+#[shrinkwrap(transform = MyTransform)]
+#[shrinkwrap(nest(id = "text", field_type = String, validate))]
+pub struct Account {
+    #[shrinkwrap(nest(id = "text", validate(length(min = 1, max = 8), custom = crate::is_currency)))]
+    pub balance: i64,
+
+    #[shrinkwrap(nest(id = "text"))]
+    pub owner: String,
+}
+
+fn is_currency(value: &str) -> Result<(), ValidationError> {
+    match value.starts_with('$') {
+        true => Ok(()),
+        false => Err(ValidationError::new("currency")),
+    }
+}
+
+// !- Transform
+
+struct MyTransform;
+impl Transform for MyTransform {
+    /// Currency symbol
+    type Options = &'static str;
+}
+impl TransformToNest<AccountNestedText> for MyTransform {
+    type Data = Account;
+
+    fn transform_to_nest(&self, data: &Account, symbol: &&'static str) -> AccountNestedText {
+        AccountNestedText {
+            balance: format!("{symbol}{:.2}", data.balance as f64 / 100.0),
+            owner: data.owner.clone(),
+        }
+    }
+}
+
+// !- Tests
+
+#[test]
+fn valid_nests() {
+    let wrapper = Account { balance: 250, owner: String::new() }.to_wrapped_with(&MyTransform, &"$");
+    assert!(wrapper.extra.text.validate().is_ok());
+}
+
+#[test]
+fn invalid_nests() {
+    let wrapper = Account { balance: 250, owner: String::new() }.to_wrapped_with(&MyTransform, &"€");
+    let errors = wrapper.extra.text.validate().unwrap_err();
+    assert_eq!(errors.field_errors()["balance"][0].code, "currency");
+
+    let wrapper = Account { balance: 100_000_000, owner: String::new() }.to_wrapped_with(&MyTransform, &"$");
+    let errors = wrapper.extra.text.validate().unwrap_err();
+    assert_eq!(errors.field_errors()["balance"][0].code, "length");
+}